@@ -3,11 +3,15 @@ extends EditorDebuggerPlugin
 ## Bevy Debugger Message Handler
 ##
 ## This plugin captures debug messages from the running game and forwards
-## entity/component data to the Bevy Inspector Panel.
+## entity/component data to the Bevy Inspector Panel. Edits made in the panel
+## are sent back to the game through `send_to_game`.
 
 # Reference to the inspector panel (set by the main plugin)
 var inspector_panel = null
 
+# Scene dock badges, cleared when the game stops (set by the main plugin)
+var node_badges = null
+
 func _has_capture(prefix: String) -> bool:
 	return prefix == "bevy"
 
@@ -17,8 +21,34 @@ func _capture(message: String, data: Array, session_id: int) -> bool:
 			if inspector_panel and inspector_panel.has_method("update_entities"):
 				inspector_panel.update_entities(data)
 			return true
+		"bevy:resources":
+			if inspector_panel and inspector_panel.has_method("update_resources"):
+				inspector_panel.update_resources(data)
+			return true
+		"bevy:diagnostics":
+			if inspector_panel and inspector_panel.has_method("update_diagnostics") and not data.is_empty():
+				inspector_panel.update_diagnostics(data[0])
+			return true
+		"bevy:node_badges":
+			if node_badges and node_badges.has_method("update_badges"):
+				node_badges.update_badges(data)
+			return true
+		"bevy:types":
+			if inspector_panel and inspector_panel.has_method("update_types"):
+				inspector_panel.update_types(data)
+			return true
 		_:
 			return false
 
-func _setup_session(_session_id: int) -> void:
-	pass
+func _setup_session(session_id: int) -> void:
+	get_session(session_id).stopped.connect(_on_session_stopped)
+
+func _on_session_stopped() -> void:
+	if node_badges and node_badges.has_method("clear_badges"):
+		node_badges.clear_badges()
+
+## Send a `bevy:<command>` message to every running game session.
+func send_to_game(command: String, data: Array = []) -> void:
+	for session in get_sessions():
+		if session.is_active():
+			session.send_message("bevy:" + command, data)
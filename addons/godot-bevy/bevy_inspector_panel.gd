@@ -3,14 +3,51 @@ extends Panel
 ## Bevy Entity Inspector Panel
 ##
 ## Displays Bevy entities and their components in the editor when the game is running.
+## Entities can be spawned and despawned, and reflected components and resources
+## with a `Default` added or removed, without recompiling. Large worlds can be
+## searched and filtered, and entities of interest pinned to the top. The
+## Diagnostics tab shows frame time, schedule timings and sync counters.
+
+# Components from these crates don't count as "user" components for filtering
+const FRAMEWORK_PREFIXES: Array[String] = ["bevy_", "godot_bevy::"]
+
+enum Filter { ALL, USER_COMPONENTS, GODOT_NODES, ECS_ONLY }
+
+# Debugger plugin used to send edits to the game (set by the main plugin)
+var debugger = null
 
 # UI elements
 var entity_tree: Tree
+var resource_tree: Tree
+var diagnostics_tree: Tree
 var status_label: Label
+var search_edit: LineEdit
+var filter_option: OptionButton
+var pin_button: Button
+var despawn_button: Button
+var add_component_button: MenuButton
+var remove_component_button: Button
+var add_resource_button: MenuButton
+var remove_resource_button: Button
+
+# Type paths the game can construct from `Default`, from the "bevy:types" message
+var _component_types: PackedStringArray = []
+var _resource_types: PackedStringArray = []
+
+# Selection, by key, so it survives the periodic tree rebuild
+var _selected_key: String = ""
+var _selected_resource: String = ""
 
 # Track expanded state by entity_bits (persists across refreshes)
 var _expanded_entities: Dictionary = {}
 
+# Pinned entity_bits, in pinning order
+var _pinned: Array[int] = []
+
+# Last "bevy:entities" data, kept so search and filter changes apply immediately
+var _entities_by_id: Dictionary = {}
+var _children_by_parent: Dictionary = {}
+
 # Editor icons cache
 var _icon_entity: Texture2D
 var _icon_entity_godot: Texture2D
@@ -35,10 +72,14 @@ func _setup_ui() -> void:
 	name = "Bevy"
 	custom_minimum_size = Vector2(200, 200)
 
+	var tabs := TabContainer.new()
+	tabs.set_anchors_preset(Control.PRESET_FULL_RECT)
+	add_child(tabs)
+
 	var main_vbox := VBoxContainer.new()
-	main_vbox.set_anchors_preset(Control.PRESET_FULL_RECT)
+	main_vbox.name = "Entities"
 	main_vbox.add_theme_constant_override("separation", 4)
-	add_child(main_vbox)
+	tabs.add_child(main_vbox)
 
 	# Header
 	var header := HBoxContainer.new()
@@ -55,32 +96,338 @@ func _setup_ui() -> void:
 
 	main_vbox.add_child(header)
 
+	# Search and filter
+	var search_bar := HBoxContainer.new()
+	search_edit = LineEdit.new()
+	search_edit.placeholder_text = "Search name, component, node type"
+	search_edit.clear_button_enabled = true
+	search_edit.size_flags_horizontal = Control.SIZE_EXPAND_FILL
+	search_edit.text_changed.connect(func(_text): _rebuild_entity_tree())
+	search_bar.add_child(search_edit)
+
+	filter_option = OptionButton.new()
+	filter_option.add_item("All", Filter.ALL)
+	filter_option.add_item("User components", Filter.USER_COMPONENTS)
+	filter_option.add_item("Godot nodes", Filter.GODOT_NODES)
+	filter_option.add_item("ECS only", Filter.ECS_ONLY)
+	filter_option.tooltip_text = "Show only matching entities, as a flat list"
+	filter_option.item_selected.connect(func(_index): _rebuild_entity_tree())
+	search_bar.add_child(filter_option)
+	main_vbox.add_child(search_bar)
+
+	# Entity toolbar
+	var entity_toolbar := HBoxContainer.new()
+	var spawn_button := Button.new()
+	spawn_button.text = "Spawn"
+	spawn_button.tooltip_text = "Spawn an empty entity"
+	spawn_button.pressed.connect(func(): _send("spawn_entity"))
+	entity_toolbar.add_child(spawn_button)
+
+	despawn_button = Button.new()
+	despawn_button.text = "Despawn"
+	despawn_button.tooltip_text = "Despawn the selected entity (and free its Godot node)"
+	despawn_button.pressed.connect(_on_despawn_pressed)
+	entity_toolbar.add_child(despawn_button)
+
+	add_component_button = MenuButton.new()
+	add_component_button.text = "Add Component"
+	add_component_button.flat = false
+	add_component_button.get_popup().id_pressed.connect(_on_add_component)
+	entity_toolbar.add_child(add_component_button)
+
+	remove_component_button = Button.new()
+	remove_component_button.text = "Remove"
+	remove_component_button.tooltip_text = "Remove the selected component"
+	remove_component_button.pressed.connect(_on_remove_component_pressed)
+	entity_toolbar.add_child(remove_component_button)
+
+	pin_button = Button.new()
+	pin_button.text = "Pin"
+	pin_button.tooltip_text = "Keep the selected entity at the top of the list"
+	pin_button.pressed.connect(_on_pin_pressed)
+	entity_toolbar.add_child(pin_button)
+	main_vbox.add_child(entity_toolbar)
+
+	var split := VSplitContainer.new()
+	split.size_flags_vertical = Control.SIZE_EXPAND_FILL
+	main_vbox.add_child(split)
+
 	# Entity tree with hierarchy
 	entity_tree = Tree.new()
 	entity_tree.size_flags_horizontal = Control.SIZE_EXPAND_FILL
 	entity_tree.size_flags_vertical = Control.SIZE_EXPAND_FILL
 	entity_tree.hide_root = true
 	entity_tree.item_collapsed.connect(_on_item_collapsed)
-	main_vbox.add_child(entity_tree)
+	entity_tree.item_selected.connect(_on_entity_item_selected)
+	split.add_child(entity_tree)
+
+	# Resources
+	var resource_vbox := VBoxContainer.new()
+	resource_vbox.size_flags_vertical = Control.SIZE_EXPAND_FILL
+	var resource_toolbar := HBoxContainer.new()
+	var resource_title := Label.new()
+	resource_title.text = "Resources"
+	resource_toolbar.add_child(resource_title)
+	resource_toolbar.add_spacer(false)
+
+	add_resource_button = MenuButton.new()
+	add_resource_button.text = "Add"
+	add_resource_button.flat = false
+	add_resource_button.tooltip_text = "Insert a resource (resets it if already present)"
+	add_resource_button.get_popup().id_pressed.connect(_on_add_resource)
+	resource_toolbar.add_child(add_resource_button)
+
+	remove_resource_button = Button.new()
+	remove_resource_button.text = "Remove"
+	remove_resource_button.pressed.connect(_on_remove_resource_pressed)
+	resource_toolbar.add_child(remove_resource_button)
+	resource_vbox.add_child(resource_toolbar)
+
+	resource_tree = Tree.new()
+	resource_tree.size_flags_vertical = Control.SIZE_EXPAND_FILL
+	resource_tree.hide_root = true
+	resource_tree.item_selected.connect(_on_resource_item_selected)
+	resource_vbox.add_child(resource_tree)
+	split.add_child(resource_vbox)
+
+	# Diagnostics tab
+	diagnostics_tree = Tree.new()
+	diagnostics_tree.name = "Diagnostics"
+	diagnostics_tree.columns = 2
+	diagnostics_tree.hide_root = true
+	diagnostics_tree.set_column_expand(1, false)
+	diagnostics_tree.set_column_custom_minimum_width(1, 90)
+	tabs.add_child(diagnostics_tree)
+
+	_update_buttons()
 
 func _on_item_collapsed(item: TreeItem) -> void:
 	var entity_bits = item.get_metadata(0)
-	if entity_bits != null:
+	if entity_bits is int:
 		_expanded_entities[entity_bits] = not item.collapsed
 
-func update_entities(data: Array) -> void:
-	if not entity_tree:
+func _send(command: String, data: Array = []) -> void:
+	if debugger:
+		debugger.send_to_game(command, data)
+
+# Entity items carry their entity_bits; component items {"entity", "type"}.
+func _item_key(metadata) -> String:
+	if metadata is int:
+		return str(metadata)
+	if metadata is Dictionary:
+		return "%d/%s" % [metadata["entity"], metadata["type"]]
+	return ""
+
+func _selected_metadata():
+	var item: TreeItem = entity_tree.get_selected() if entity_tree else null
+	return item.get_metadata(0) if item else null
+
+func _update_buttons() -> void:
+	var metadata = _selected_metadata()
+	var has_session: bool = debugger != null
+	pin_button.disabled = not metadata is int
+	pin_button.text = "Unpin" if metadata is int and metadata in _pinned else "Pin"
+	despawn_button.disabled = not (has_session and metadata is int)
+	add_component_button.disabled = not (has_session and metadata is int) or _component_types.is_empty()
+	remove_component_button.disabled = not (has_session and metadata is Dictionary)
+	add_resource_button.disabled = not has_session or _resource_types.is_empty()
+	remove_resource_button.disabled = not has_session or _selected_resource.is_empty()
+
+func _on_entity_item_selected() -> void:
+	_selected_key = _item_key(_selected_metadata())
+	_update_buttons()
+
+func _on_resource_item_selected() -> void:
+	var item: TreeItem = resource_tree.get_selected()
+	var type_path = item.get_metadata(0) if item else null
+	_selected_resource = type_path if type_path is String else ""
+	_update_buttons()
+
+func _on_pin_pressed() -> void:
+	var metadata = _selected_metadata()
+	if not metadata is int:
+		return
+	if metadata in _pinned:
+		_pinned.erase(metadata)
+	else:
+		_pinned.append(metadata)
+	_rebuild_entity_tree()
+
+func _on_despawn_pressed() -> void:
+	var metadata = _selected_metadata()
+	if metadata is int:
+		_send("despawn_entity", [metadata])
+
+func _on_add_component(index: int) -> void:
+	var metadata = _selected_metadata()
+	if metadata is int and index < _component_types.size():
+		_send("insert_component", [metadata, _component_types[index]])
+
+func _on_remove_component_pressed() -> void:
+	var metadata = _selected_metadata()
+	if metadata is Dictionary:
+		_send("remove_component", [metadata["entity"], metadata["type"]])
+
+func _on_add_resource(index: int) -> void:
+	if index < _resource_types.size():
+		_send("insert_resource", [_resource_types[index]])
+
+func _on_remove_resource_pressed() -> void:
+	if not _selected_resource.is_empty():
+		_send("remove_resource", [_selected_resource])
+
+func update_types(data: Array) -> void:
+	# Data format: [component_type_paths, resource_type_paths]
+	if data.size() < 2:
+		return
+	_component_types = data[0]
+	_resource_types = data[1]
+	_fill_type_menu(add_component_button.get_popup(), _component_types)
+	_fill_type_menu(add_resource_button.get_popup(), _resource_types)
+	_update_buttons()
+
+func _fill_type_menu(popup: PopupMenu, type_paths: PackedStringArray) -> void:
+	popup.clear()
+	for i in range(type_paths.size()):
+		var type_path: String = type_paths[i]
+		var last_sep: int = type_path.rfind("::")
+		popup.add_item(type_path.substr(last_sep + 2) if last_sep >= 0 else type_path, i)
+		popup.set_item_tooltip(i, type_path)
+
+func update_diagnostics(report: Dictionary) -> void:
+	if not diagnostics_tree:
 		return
 
-	status_label.text = "%d entities" % data.size()
-	status_label.add_theme_color_override("font_color", Color(0.5, 0.9, 0.5))
+	diagnostics_tree.clear()
+	var tree_root: TreeItem = diagnostics_tree.create_item()
+
+	var frame: TreeItem = _add_diagnostics_section(tree_root, "Frame")
+	var frame_ms: float = report.get("frame_ms", 0.0)
+	_add_diagnostics_row(frame, "Frame time", "%.2f ms" % frame_ms)
+	if frame_ms > 0.0:
+		_add_diagnostics_row(frame, "FPS", "%.0f" % (1000.0 / frame_ms))
+	_add_diagnostics_row(frame, "Entities", str(report.get("entity_count", 0)))
+
+	# Schedules of the previous frame, in run order: [name, milliseconds]
+	var schedules: Array = report.get("schedules", [])
+	if not schedules.is_empty():
+		var section: TreeItem = _add_diagnostics_section(tree_root, "Schedules")
+		for entry in schedules:
+			_add_diagnostics_row(section, entry[0], "%.3f ms" % entry[1])
+
+	var sync: Dictionary = report.get("sync", {})
+	if not sync.is_empty():
+		var section: TreeItem = _add_diagnostics_section(tree_root, "Godot sync (per frame)")
+		for key in sync:
+			_add_diagnostics_row(section, String(key).capitalize(), str(sync[key]))
+
+	# Systems making the most Godot lookups (trace_tracy builds): [system, count]
+	var godot_lookups: Array = report.get("godot_lookups", [])
+	if not godot_lookups.is_empty():
+		var section: TreeItem = _add_diagnostics_section(tree_root, "Godot lookups per system")
+		for entry in godot_lookups:
+			_add_diagnostics_row(section, entry[0], str(entry[1]))
+
+	var network: Dictionary = report.get("network", {})
+	if not network.is_empty():
+		_add_network_sections(tree_root, network)
+
+	# Registered bevy_diagnostic measurements: [path, value, suffix]
+	var diagnostics: Array = report.get("diagnostics", [])
+	if not diagnostics.is_empty():
+		var section: TreeItem = _add_diagnostics_section(tree_root, "Bevy diagnostics")
+		for entry in diagnostics:
+			_add_diagnostics_row(section, entry[0], "%.2f%s" % [entry[1], entry[2]])
+
+func _add_network_sections(tree_root: TreeItem, network: Dictionary) -> void:
+	var section: TreeItem = _add_diagnostics_section(tree_root, "Network (per second)")
+	_add_diagnostics_row(section, "Sent", _format_bytes(network.get("sent_bytes_per_sec", 0.0)))
+	_add_diagnostics_row(section, "Received", _format_bytes(network.get("received_bytes_per_sec", 0.0)))
+	_add_diagnostics_row(section, "Packets sent", "%.0f" % network.get("sent_packets_per_sec", 0.0))
+	_add_diagnostics_row(section, "Packets received", "%.0f" % network.get("received_packets_per_sec", 0.0))
+	# [peer, milliseconds]
+	for entry in network.get("rtt", []):
+		_add_diagnostics_row(section, "RTT peer %d" % entry[0], "%.1f ms" % entry[1])
+
+	# [type name, sent msg/s, sent B/s, received msg/s, received B/s]
+	var channels: Array = network.get("channels", [])
+	if not channels.is_empty():
+		var rpcs: TreeItem = _add_diagnostics_section(tree_root, "RPCs (per second)")
+		for entry in channels:
+			var type_path: String = entry[0]
+			var last_sep: int = type_path.rfind("::")
+			var label: String = type_path.substr(last_sep + 2) if last_sep >= 0 else type_path
+			var channel: TreeItem = _add_diagnostics_section(rpcs, label)
+			channel.set_tooltip_text(0, type_path)
+			_add_diagnostics_row(channel, "Out", "%.0f msg, %s" % [entry[1], _format_bytes(entry[2])])
+			_add_diagnostics_row(channel, "In", "%.0f msg, %s" % [entry[3], _format_bytes(entry[4])])
+
+	var replication: Dictionary = network.get("replication", {})
+	if not replication.is_empty():
+		var counts: TreeItem = _add_diagnostics_section(tree_root, "Replication")
+		for key in replication:
+			_add_diagnostics_row(counts, String(key).capitalize(), str(replication[key]))
+
+func _format_bytes(bytes: float) -> String:
+	if bytes >= 1024.0 * 1024.0:
+		return "%.1f MB" % (bytes / (1024.0 * 1024.0))
+	if bytes >= 1024.0:
+		return "%.1f KB" % (bytes / 1024.0)
+	return "%.0f B" % bytes
+
+func _add_diagnostics_section(parent_item: TreeItem, title: String) -> TreeItem:
+	var item: TreeItem = diagnostics_tree.create_item(parent_item)
+	item.set_text(0, title)
+	item.set_selectable(0, false)
+	item.set_selectable(1, false)
+	item.set_custom_color(0, Color(0.7, 0.7, 0.7))
+	return item
+
+func _add_diagnostics_row(section: TreeItem, label: String, value: String) -> void:
+	var item: TreeItem = diagnostics_tree.create_item(section)
+	item.set_text(0, label)
+	item.set_text(1, value)
+	item.set_text_alignment(1, HORIZONTAL_ALIGNMENT_RIGHT)
+	item.set_custom_color(1, Color(0.6, 0.8, 1.0))
+
+func update_resources(data: Array) -> void:
+	if not resource_tree:
+		return
 
-	entity_tree.clear()
-	var tree_root: TreeItem = entity_tree.create_item()
+	resource_tree.clear()
+	var tree_root: TreeItem = resource_tree.create_item()
+	var still_present := false
+
+	# Data format: [{name, short_name, value?}], like components
+	for resource in data:
+		if not resource is Dictionary:
+			continue
+		var full_name: String = resource.get("name", "")
+		var item: TreeItem = resource_tree.create_item(tree_root)
+		var display_text: String = resource.get("short_name", full_name)
+		var value = resource.get("value", null)
+		if value != null:
+			var value_str: String = _format_value(value)
+			if value_str:
+				display_text = "%s: %s" % [display_text, value_str]
+		item.set_text(0, display_text)
+		item.set_tooltip_text(0, full_name)
+		item.set_metadata(0, full_name)
+		item.collapsed = true
+		if value is Dictionary and value.has("fields"):
+			_add_fields(item, value, resource_tree)
+		if full_name == _selected_resource:
+			item.select(0)
+			still_present = true
+
+	if not still_present:
+		_selected_resource = ""
+	_update_buttons()
 
+func update_entities(data: Array) -> void:
 	# Data format: [entity_bits, name, has_godot_node, parent_bits, components]
-	var entities_by_id: Dictionary = {}
-	var children_by_parent: Dictionary = {}
+	_entities_by_id = {}
+	_children_by_parent = {}
 
 	for entity_data in data:
 		if not (entity_data is Array and entity_data.size() >= 5):
@@ -92,25 +439,90 @@ func update_entities(data: Array) -> void:
 		var parent_bits: int = entity_data[3]
 		var components: Array = entity_data[4]
 
-		entities_by_id[entity_bits] = {
+		_entities_by_id[entity_bits] = {
 			"name": entity_name,
 			"has_godot_node": has_godot_node,
 			"parent_bits": parent_bits,
 			"components": components
 		}
 
-		if parent_bits == -1:
-			if not children_by_parent.has(-1):
-				children_by_parent[-1] = []
-			children_by_parent[-1].append(entity_bits)
-		else:
-			if not children_by_parent.has(parent_bits):
-				children_by_parent[parent_bits] = []
-			children_by_parent[parent_bits].append(entity_bits)
+		if not _children_by_parent.has(parent_bits):
+			_children_by_parent[parent_bits] = []
+		_children_by_parent[parent_bits].append(entity_bits)
 
-	# Build tree recursively starting from root entities (parent_bits == -1)
-	var tree_items: Dictionary = {}
-	_build_entity_tree(tree_root, -1, entities_by_id, children_by_parent, tree_items)
+	# Despawned entities never come back (their bits include the generation)
+	_pinned.assign(_pinned.filter(func(bits): return _entities_by_id.has(bits)))
+
+	_rebuild_entity_tree()
+
+func _rebuild_entity_tree() -> void:
+	if not entity_tree:
+		return
+
+	entity_tree.clear()
+	var tree_root: TreeItem = entity_tree.create_item()
+
+	if not _pinned.is_empty():
+		var pinned_item: TreeItem = entity_tree.create_item(tree_root)
+		pinned_item.set_text(0, "Pinned")
+		pinned_item.set_selectable(0, false)
+		pinned_item.set_custom_color(0, Color(0.7, 0.7, 0.7))
+		for entity_bits in _pinned:
+			_add_entity_item(pinned_item, entity_bits, _entities_by_id[entity_bits])
+
+	var query: String = search_edit.text.strip_edges().to_lower()
+	var filter: int = filter_option.get_selected_id()
+	if query.is_empty() and filter == Filter.ALL:
+		# Build tree recursively starting from root entities (parent_bits == -1)
+		var tree_items: Dictionary = {}
+		_build_entity_tree(tree_root, -1, _entities_by_id, _children_by_parent, tree_items)
+		status_label.text = "%d entities" % _entities_by_id.size()
+	else:
+		# A hierarchy of mostly hidden parents is hard to scan; list matches flat.
+		var shown := 0
+		for entity_bits in _entities_by_id:
+			var info: Dictionary = _entities_by_id[entity_bits]
+			if _matches_filter(info, filter) and _matches_search(entity_bits, info, query):
+				_add_entity_item(tree_root, entity_bits, info)
+				shown += 1
+		status_label.text = "%d / %d entities" % [shown, _entities_by_id.size()]
+
+	status_label.add_theme_color_override("font_color", Color(0.5, 0.9, 0.5))
+	_update_buttons()
+
+func _display_name(entity_bits: int, info: Dictionary) -> String:
+	return info["name"] if info["name"] else "Entity %d" % (entity_bits & 0xFFFFFFFF)
+
+# Matches the name, any component's short name, or a node type through its
+# marker component (searching "sprite2d" finds `Sprite2DMarker`).
+func _matches_search(entity_bits: int, info: Dictionary, query: String) -> bool:
+	if query.is_empty():
+		return true
+	if query in _display_name(entity_bits, info).to_lower():
+		return true
+	for component in info["components"]:
+		if component is Dictionary and query in String(component.get("short_name", "")).to_lower():
+			return true
+	return false
+
+func _matches_filter(info: Dictionary, filter: int) -> bool:
+	match filter:
+		Filter.USER_COMPONENTS:
+			for component in info["components"]:
+				if component is Dictionary and not _is_framework_type(component.get("name", "")):
+					return true
+			return false
+		Filter.GODOT_NODES:
+			return info["has_godot_node"]
+		Filter.ECS_ONLY:
+			return not info["has_godot_node"]
+	return true
+
+func _is_framework_type(type_path: String) -> bool:
+	for prefix in FRAMEWORK_PREFIXES:
+		if type_path.begins_with(prefix):
+			return true
+	return false
 
 func _build_entity_tree(parent_item: TreeItem, parent_bits: int, entities_by_id: Dictionary, children_by_parent: Dictionary, tree_items: Dictionary) -> void:
 	if not children_by_parent.has(parent_bits):
@@ -118,29 +530,9 @@ func _build_entity_tree(parent_item: TreeItem, parent_bits: int, entities_by_id:
 
 	for entity_bits in children_by_parent[parent_bits]:
 		var info: Dictionary = entities_by_id[entity_bits]
-		var entity_item: TreeItem = entity_tree.create_item(parent_item)
-
-		var display_name: String = info["name"] if info["name"] else "Entity %d" % (entity_bits & 0xFFFFFFFF)
-
-		entity_item.set_text(0, display_name)
-		entity_item.set_metadata(0, entity_bits)
+		var entity_item: TreeItem = _add_entity_item(parent_item, entity_bits, info)
 		tree_items[entity_bits] = entity_item
 
-		# Find the node type from marker components and set appropriate icon
-		var entity_icon: Texture2D = _get_entity_icon(info["components"], info["has_godot_node"])
-		if entity_icon:
-			entity_item.set_icon(0, entity_icon)
-
-		# Add components as children of entity
-		for component in info["components"]:
-			# Skip hierarchy components - already shown visually in the tree
-			if component is Dictionary:
-				var comp_short_name: String = component.get("short_name", "")
-				var comp_full_name: String = component.get("name", "")
-				if comp_short_name in ["ChildOf", "Children"] or "::ChildOf" in comp_full_name or "::Children" in comp_full_name:
-					continue
-			_add_component_item(entity_item, component)
-
 		# Recursively add child entities
 		_build_entity_tree(entity_item, entity_bits, entities_by_id, children_by_parent, tree_items)
 
@@ -150,7 +542,34 @@ func _build_entity_tree(parent_item: TreeItem, parent_bits: int, entities_by_id:
 			var is_expanded: bool = _expanded_entities.get(entity_bits, false)
 			entity_item.collapsed = not is_expanded
 
-func _add_component_item(parent_item: TreeItem, component) -> void:
+# An entity with its components, without child entities.
+func _add_entity_item(parent_item: TreeItem, entity_bits: int, info: Dictionary) -> TreeItem:
+	var entity_item: TreeItem = entity_tree.create_item(parent_item)
+	entity_item.set_text(0, _display_name(entity_bits, info))
+	entity_item.set_metadata(0, entity_bits)
+	if _selected_key == str(entity_bits):
+		entity_item.select(0)
+
+	# Find the node type from marker components and set appropriate icon
+	var entity_icon: Texture2D = _get_entity_icon(info["components"], info["has_godot_node"])
+	if entity_icon:
+		entity_item.set_icon(0, entity_icon)
+
+	# Add components as children of entity
+	for component in info["components"]:
+		# Skip hierarchy components - already shown visually in the tree
+		if component is Dictionary:
+			var comp_short_name: String = component.get("short_name", "")
+			var comp_full_name: String = component.get("name", "")
+			if comp_short_name in ["ChildOf", "Children"] or "::ChildOf" in comp_full_name or "::Children" in comp_full_name:
+				continue
+		_add_component_item(entity_item, entity_bits, component)
+
+	if info["components"].size() > 0:
+		entity_item.collapsed = not _expanded_entities.get(entity_bits, false)
+	return entity_item
+
+func _add_component_item(parent_item: TreeItem, entity_bits: int, component) -> void:
 	var comp_item: TreeItem = entity_tree.create_item(parent_item)
 
 	# Handle both old format (string) and new format (dictionary)
@@ -184,6 +603,10 @@ func _add_component_item(parent_item: TreeItem, component) -> void:
 	comp_item.set_text(0, display_text)
 	comp_item.set_tooltip_text(0, full_name)
 	comp_item.set_custom_color(0, Color(0.6, 0.8, 1.0))
+	var metadata := {"entity": entity_bits, "type": full_name}
+	comp_item.set_metadata(0, metadata)
+	if _selected_key == _item_key(metadata):
+		comp_item.select(0)
 
 	# Set component icon based on type
 	var icon: Texture2D = _get_component_icon(short_name)
@@ -284,26 +707,28 @@ func _get_component_icon(short_name: String) -> Texture2D:
 
 	return _icon_component
 
-func _add_fields(parent_item: TreeItem, value_dict: Dictionary) -> void:
+func _add_fields(parent_item: TreeItem, value_dict: Dictionary, tree: Tree = null) -> void:
 	var fields = value_dict.get("fields")
 	if fields == null:
 		return
+	if tree == null:
+		tree = entity_tree
 
 	if fields is Dictionary:
 		for field_name in fields:
 			var field_value = fields[field_name]
-			var field_item: TreeItem = entity_tree.create_item(parent_item)
+			var field_item: TreeItem = tree.create_item(parent_item)
 			var display: String = "%s: %s" % [field_name, _format_value(field_value)]
 			field_item.set_text(0, display)
 			field_item.set_custom_color(0, Color(0.8, 0.8, 0.6))
 
 			# Recurse for nested structs
 			if field_value is Dictionary and field_value.has("fields"):
-				_add_fields(field_item, field_value)
+				_add_fields(field_item, field_value, tree)
 	elif fields is Array:
 		for i in range(fields.size()):
 			var field_value = fields[i]
-			var field_item: TreeItem = entity_tree.create_item(parent_item)
+			var field_item: TreeItem = tree.create_item(parent_item)
 			var display: String = "[%d]: %s" % [i, _format_value(field_value)]
 			field_item.set_text(0, display)
 			field_item.set_custom_color(0, Color(0.8, 0.8, 0.6))
@@ -0,0 +1,93 @@
+@tool
+extends Node
+## Bevy Node Badges
+##
+## Marks the nodes of the edited scene that have an entity in the running game,
+## from the `bevy:node_badges` messages sent by `GodotNodeBadgesPlugin` (the
+## `editor_badges` feature). A marked node shows its entity after its name in
+## the Scene dock, and its components in the tooltip.
+
+# The Scene dock rebuilds its items whenever the scene changes, so the badges
+# are reapplied this often while the game runs
+const REFRESH_INTERVAL := 0.5
+
+const TOOLTIP_HEADER := "\n\nBevy entity "
+
+# Node path from the current scene -> {"entity": String, "components": PackedStringArray}
+var _badges: Dictionary = {}
+
+var _scene_tree: Tree = null
+var _elapsed := 0.0
+
+func _process(delta: float) -> void:
+	if _badges.is_empty():
+		return
+	_elapsed += delta
+	if _elapsed >= REFRESH_INTERVAL:
+		_elapsed = 0.0
+		_apply()
+
+func update_badges(data: Array) -> void:
+	# Data format: [[path, entity_bits, components], ...]
+	_badges = {}
+	for entry in data:
+		if not (entry is Array and entry.size() >= 3):
+			continue
+		var bits: int = entry[1]
+		_badges[String(entry[0])] = {
+			"entity": "%dv%d" % [bits & 0xFFFFFFFF, bits >> 32],
+			"components": entry[2],
+		}
+	_apply()
+
+func clear_badges() -> void:
+	_badges = {}
+	_apply()
+
+func _apply() -> void:
+	var tree := _find_scene_tree()
+	var scene_root := EditorInterface.get_edited_scene_root()
+	if tree and tree.get_root():
+		_apply_item(tree.get_root(), scene_root)
+
+func _apply_item(item: TreeItem, scene_root: Node) -> void:
+	var tooltip := item.get_tooltip_text(0)
+	var header := tooltip.find(TOOLTIP_HEADER)
+	if header != -1:
+		tooltip = tooltip.substr(0, header)
+
+	var badge = _badge_for(item, scene_root)
+	if badge:
+		item.set_suffix(0, "[%s]" % badge.entity)
+		item.set_tooltip_text(0, tooltip + TOOLTIP_HEADER + badge.entity + "\n" + "\n".join(badge.components))
+	elif header != -1:
+		item.set_suffix(0, "")
+		item.set_tooltip_text(0, tooltip)
+
+	for child in item.get_children():
+		_apply_item(child, scene_root)
+
+# Scene dock items carry the absolute path of their node in the editor
+func _badge_for(item: TreeItem, scene_root: Node):
+	if _badges.is_empty() or not scene_root:
+		return null
+	var path = item.get_metadata(0)
+	if not path is NodePath:
+		return null
+	var node := scene_root.get_node_or_null(path)
+	if not node:
+		return null
+	return _badges.get(String(scene_root.get_path_to(node)))
+
+func _find_scene_tree() -> Tree:
+	if is_instance_valid(_scene_tree):
+		return _scene_tree
+	_scene_tree = null
+	# Dialogs have scene tree editors too; the dock's is the one under SceneTreeDock
+	var base := EditorInterface.get_base_control()
+	for dock in base.find_children("*", "SceneTreeDock", true, false):
+		for editor in dock.find_children("*", "SceneTreeEditor", true, false):
+			for tree in editor.find_children("*", "Tree", true, false):
+				_scene_tree = tree
+				return _scene_tree
+	return null
@@ -8,7 +8,7 @@ extends Node
 ##
 ## Key responsibilities:
 ## - Intercepts scene tree events (node added, removed, renamed) via Godot signals
-## - Pre-analyzes node metadata (type, name, parent, collision signals, groups) on the
+## - Pre-analyzes node metadata (type, name, parent, collision signals, groups, transform) on the
 ##   GDScript side to minimize expensive FFI (Foreign Function Interface) calls
 ## - Forwards optimized event data to the Rust SceneTreeWatcher for Bevy entity creation
 ## - Provides initial scene tree analysis for bulk entity spawning during startup
@@ -87,8 +87,19 @@ func _on_node_added(node: Node):
         node_groups.append(group)
 
     # Forward to Rust watcher with pre-analyzed metadata
-    # Try newest API first (with groups), then fall back to older APIs
-    if rust_watcher.has_method("scene_tree_event_typed_metadata_groups"):
+    # Try newest API first (with transform), then fall back to older APIs
+    if rust_watcher.has_method("scene_tree_event_typed_metadata_transform"):
+        rust_watcher.scene_tree_event_typed_metadata_transform(
+            node,
+            "NodeAdded",
+            node_type,
+            node_name,
+            parent_id,
+            collision_mask,
+            node_groups,
+            _local_transform(node)
+        )
+    elif rust_watcher.has_method("scene_tree_event_typed_metadata_groups"):
         rust_watcher.scene_tree_event_typed_metadata_groups(
             node,
             "NodeAdded",
@@ -150,6 +161,13 @@ func _compute_collision_mask(node: Node) -> int:
     return mask
 
 
+func _local_transform(node: Node) -> Variant:
+    # Captured here so Rust can set the entity's Transform without reading the node
+    if node is Node3D or node is Node2D:
+        return node.transform
+    return null
+
+
 func analyze_initial_tree() -> Dictionary:
     """
     Analyze the entire initial scene tree and return node information with types.
@@ -160,7 +178,8 @@ func analyze_initial_tree() -> Dictionary:
         "node_names": PackedStringArray,
         "parent_ids": PackedInt64Array,
         "collision_masks": PackedInt64Array,
-        "groups": Array[PackedStringArray]  # Added in v2 - may not be present in older addons
+        "groups": Array[PackedStringArray],  # Added in v2 - may not be present in older addons
+        "transforms": Array  # Added in v3 - Transform2D/Transform3D, or null for other nodes
     }
     Used for optimized initial scene tree setup.
     """
@@ -170,9 +189,10 @@ func analyze_initial_tree() -> Dictionary:
     var parent_ids: PackedInt64Array = PackedInt64Array()
     var collision_masks: PackedInt64Array = PackedInt64Array()
     var groups: Array = []  # Array of PackedStringArrays
+    var transforms: Array = []
     var root: Window = get_tree().get_root()
     if root:
-        _analyze_node_recursive(root, instance_ids, node_types, node_names, parent_ids, collision_masks, groups)
+        _analyze_node_recursive(root, instance_ids, node_types, node_names, parent_ids, collision_masks, groups, transforms)
 
     return {
         "instance_ids": instance_ids,
@@ -180,7 +200,8 @@ func analyze_initial_tree() -> Dictionary:
         "node_names": node_names,
         "parent_ids": parent_ids,
         "collision_masks": collision_masks,
-        "groups": groups
+        "groups": groups,
+        "transforms": transforms
     }
 
 
@@ -191,7 +212,8 @@ func _analyze_node_recursive(
     node_names: PackedStringArray,
     parent_ids: PackedInt64Array,
     collision_masks: PackedInt64Array,
-    groups: Array
+    groups: Array,
+    transforms: Array
 ):
     """Recursively analyze nodes and collect type information into PackedArrays"""
     # Check if node is still valid before processing
@@ -223,7 +245,8 @@ func _analyze_node_recursive(
         parent_ids.append(parent_id)
         collision_masks.append(collision_mask)
         groups.append(node_groups)
+        transforms.append(_local_transform(node))
 
     # Recursively process children
     for child: Node in node.get_children():
-        _analyze_node_recursive(child, instance_ids, node_types, node_names, parent_ids, collision_masks, groups)
+        _analyze_node_recursive(child, instance_ids, node_types, node_names, parent_ids, collision_masks, groups, transforms)
@@ -6,11 +6,14 @@ const AUTOLOAD_NAME = "BevyAppSingleton"
 const AUTOLOAD_PATH = "res://addons/godot-bevy/bevy_app_singleton.tscn"
 const BEVY_DEBUGGER_SCRIPT = "res://addons/godot-bevy/bevy_debugger_plugin.gd"
 const BEVY_INSPECTOR_SCENE = "res://addons/godot-bevy/bevy_inspector_panel.tscn"
+const BEVY_NODE_BADGES_SCRIPT = "res://addons/godot-bevy/bevy_node_badges.gd"
+const GDEXTENSION_PATH = "res://rust.gdextension"
 
 var wizard_dialog: Window
 var _should_restart_after_build: bool = false
 var _bevy_debugger: EditorDebuggerPlugin = null
 var _bevy_inspector: Control = null
+var _bevy_node_badges: Node = null
 
 func _enable_plugin():
 	# Automatically register the BevyApp singleton when plugin is enabled
@@ -28,6 +31,7 @@ func _enter_tree():
 	# Add menu items
 	add_tool_menu_item("Setup godot-bevy Project", _on_setup_project)
 	add_tool_menu_item("Build Rust Project", _on_build_rust)
+	_sync_gdextension_icons()
 
 	# Create the Bevy Inspector panel (dock tab next to Scene)
 	var inspector_scene = load(BEVY_INSPECTOR_SCENE) as PackedScene
@@ -38,6 +42,14 @@ func _enter_tree():
 	else:
 		push_error("godot-bevy: Failed to load Bevy Inspector scene")
 
+	# Badges for nodes with entities in the Scene dock
+	var badges_script = load(BEVY_NODE_BADGES_SCRIPT)
+	if badges_script:
+		_bevy_node_badges = badges_script.new()
+		add_child(_bevy_node_badges)
+	else:
+		push_error("godot-bevy: Failed to load Bevy node badges script")
+
 	# Register the Bevy debugger plugin (for message capture)
 	var debugger_script = load(BEVY_DEBUGGER_SCRIPT)
 	if debugger_script:
@@ -45,6 +57,8 @@ func _enter_tree():
 		# Connect debugger to inspector panel
 		if _bevy_inspector:
 			_bevy_debugger.inspector_panel = _bevy_inspector
+			_bevy_inspector.debugger = _bevy_debugger
+		_bevy_debugger.node_badges = _bevy_node_badges
 		add_debugger_plugin(_bevy_debugger)
 		print("godot-bevy: Bevy Debugger plugin registered")
 	else:
@@ -63,6 +77,12 @@ func _exit_tree():
 		_bevy_inspector.free()
 		_bevy_inspector = null
 
+	# Remove the node badges, and the ones they put in the Scene dock
+	if is_instance_valid(_bevy_node_badges):
+		_bevy_node_badges.clear_badges()
+		_bevy_node_badges.queue_free()
+		_bevy_node_badges = null
+
 	# Remove the Bevy debugger plugin
 	if _bevy_debugger:
 		remove_debugger_plugin(_bevy_debugger)
@@ -240,6 +260,8 @@ func _build_rust_project(release_build: bool):
 		for line in output:
 			print("  ", line)
 
+		_sync_gdextension_icons()
+
 		# Restart editor if this was called from project setup
 		if _should_restart_after_build:
 			push_warning("Restarting editor to apply autoload changes...")
@@ -250,6 +272,20 @@ func _build_rust_project(release_build: bool):
 		for line in output:
 			print("  ", line)
 
+# Regenerate the [icons] section from `#[gdbevy(icon = ...)]` classes. Uses the
+# extension loaded before this build, so new icons show up after the next build or
+# restart.
+func _sync_gdextension_icons():
+	if not FileAccess.file_exists(GDEXTENSION_PATH):
+		return
+	if not ClassDB.class_has_method("BevyApp", "gdextension_with_icons"):
+		return
+	var current = FileAccess.get_file_as_string(GDEXTENSION_PATH)
+	var updated = ClassDB.class_call_static("BevyApp", "gdextension_with_icons", current)
+	if updated != current:
+		_save_file(GDEXTENSION_PATH, updated)
+		push_warning("godot-bevy: node icons updated; restart the editor to see them")
+
 func _save_file(path: String, content: String):
 	var file = FileAccess.open(path, FileAccess.WRITE)
 	if file:
@@ -12,8 +12,6 @@ use godot_bevy::prelude::*;
 /// Commands for UI operations
 #[derive(Message, Debug, Clone)]
 pub enum UICommand {
-    /// Set text on a UI element
-    SetText { target: UIElement, text: String },
     /// Set visibility of a UI element
     SetVisible { target: UIElement, visible: bool },
     /// Show a temporary message
@@ -58,7 +56,6 @@ pub enum AnimationCommand {
 #[derive(Debug, Clone, PartialEq)]
 pub enum UIElement {
     StartButton,
-    ScoreLabel,
     MessageLabel,
 }
 
@@ -66,7 +63,6 @@ pub enum UIElement {
 #[derive(Resource, Default)]
 pub struct UIHandles {
     pub start_button: Option<GodotNodeHandle>,
-    pub score_label: Option<GodotNodeHandle>,
     pub message_label: Option<GodotNodeHandle>,
 }
 
@@ -74,7 +70,6 @@ impl UIHandles {
     pub fn get_handle(&self, element: &UIElement) -> Option<GodotNodeHandle> {
         match element {
             UIElement::StartButton => self.start_button,
-            UIElement::ScoreLabel => self.score_label,
             UIElement::MessageLabel => self.message_label,
         }
     }
@@ -175,13 +170,6 @@ fn process_ui_commands(
 
     for command in ui_commands.read() {
         match command {
-            UICommand::SetText { target, text } => {
-                if let Some(handle) = ui_handles.get_handle(target)
-                    && let Some(mut label) = godot.try_get::<Label>(handle)
-                {
-                    label.set_text(text);
-                }
-            }
             UICommand::SetVisible { target, visible } => {
                 if let Some(handle) = ui_handles.get_handle(target)
                     && let Some(mut button) = godot.try_get::<Button>(handle)
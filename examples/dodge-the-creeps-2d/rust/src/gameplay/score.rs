@@ -1,8 +1,6 @@
 use bevy::{
     app::{App, Plugin, Update},
     ecs::{
-        change_detection::DetectChanges,
-        message::MessageWriter,
         resource::Resource,
         schedule::IntoScheduleConfigs,
         system::{Res, ResMut},
@@ -10,17 +8,15 @@ use bevy::{
     state::{condition::in_state, state::OnEnter},
     time::{Time, Timer, TimerMode},
 };
+use godot_bevy::prelude::UiBindingAppExt;
 
-use crate::{
-    GameState, Score,
-    commands::{UICommand, UIElement},
-};
+use crate::{GameState, Score};
 
 pub struct ScorePlugin;
 impl Plugin for ScorePlugin {
     fn build(&self, app: &mut App) {
         app.add_systems(OnEnter(GameState::Countdown), reset_score)
-            .add_systems(Update, update_score_counter)
+            .bind_label::<Score>("/root/Main/HUD/ScoreLabel", |score| score.0.to_string())
             .add_systems(Update, give_score.run_if(in_state(GameState::InGame)))
             .insert_resource(ScoreTimer(Timer::from_seconds(1.0, TimerMode::Repeating)));
     }
@@ -33,15 +29,6 @@ fn reset_score(mut score: ResMut<Score>) {
     score.0 = 0;
 }
 
-fn update_score_counter(score: Res<Score>, mut ui_commands: MessageWriter<UICommand>) {
-    if score.is_changed() {
-        ui_commands.write(UICommand::SetText {
-            target: UIElement::ScoreLabel,
-            text: score.0.to_string(),
-        });
-    }
-}
-
 fn give_score(time: Res<Time>, mut timer: ResMut<ScoreTimer>, mut score: ResMut<Score>) {
     timer.0.tick(time.delta());
     if timer.0.just_finished() {
@@ -69,7 +69,6 @@ fn init_menu_assets(
 
     // Initialize UI handles for command system
     ui_handles.start_button = Some(menu_ui.start_button);
-    ui_handles.score_label = Some(menu_ui.score_label);
     ui_handles.message_label = Some(menu_ui.message_label);
 }
 
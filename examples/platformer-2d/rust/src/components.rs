@@ -6,10 +6,10 @@
 
 use crate::level_manager::LevelId;
 use bevy::prelude::*;
-use godot_bevy::prelude::GodotNode;
+use godot_bevy::prelude::{AutoRegister, GodotNode};
 
 /// Component representing movement speed in pixels per second
-#[derive(Component, Debug, Clone, PartialEq, Reflect)]
+#[derive(Component, Debug, Clone, PartialEq, Reflect, AutoRegister)]
 #[reflect(Component)]
 pub struct Speed(pub f32);
 
@@ -20,7 +20,7 @@ impl Default for Speed {
 }
 
 /// Component representing jump velocity (negative for upward movement in Godot)
-#[derive(Component, Debug, Clone, PartialEq, Reflect)]
+#[derive(Component, Debug, Clone, PartialEq, Reflect, AutoRegister)]
 #[reflect(Component)]
 pub struct JumpVelocity(pub f32);
 
@@ -31,7 +31,7 @@ impl Default for JumpVelocity {
 }
 
 /// Component representing gravity in pixels per second squared
-#[derive(Component, Debug, Clone, PartialEq, Reflect)]
+#[derive(Component, Debug, Clone, PartialEq, Reflect, AutoRegister)]
 #[reflect(Component)]
 pub struct Gravity(pub f32);
 
@@ -43,7 +43,7 @@ impl Default for Gravity {
 
 /// Player marker + the `Player2D` Godot node with exported speed/jump/gravity,
 /// each inserted as a companion when the node enters the tree.
-#[derive(Component, GodotNode, Default, Debug, Clone, Reflect)]
+#[derive(Component, GodotNode, Default, Debug, Clone, Reflect, AutoRegister)]
 #[reflect(Component)]
 #[gdbevy(base = CharacterBody2D, class_name = Player2D)]
 #[gdbevy(
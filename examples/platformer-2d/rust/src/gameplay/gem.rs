@@ -1,7 +1,6 @@
 use crate::components::Gem;
 use crate::components::Player;
 use crate::gameplay::audio::PlaySfxMessage;
-use crate::gameplay::hud::HudUpdateMessage;
 use bevy::prelude::*;
 use godot::classes::Area2D;
 use godot_bevy::prelude::*;
@@ -57,9 +56,8 @@ fn on_collision_started(
     // Update gem count
     gems_collected.0 += 1;
 
-    // Trigger sound effect and HUD update with the new count
+    // Trigger sound effect; the HUD label is bound to `GemsCollected`
     commands.trigger(PlaySfxMessage::GemCollected);
-    commands.trigger(HudUpdateMessage::GemsChanged(gems_collected.0));
 
     debug!("Gem collected! Total: {}", gems_collected.0);
 }
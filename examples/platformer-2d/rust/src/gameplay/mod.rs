@@ -1,13 +1,12 @@
 use crate::GameState;
 use crate::level_manager::{CurrentLevel, LevelLoadedMessage};
-use crate::scene_management::SceneOperationMessage;
 use bevy::app::{App, Plugin};
 use bevy::prelude::*;
 use bevy::state::condition::in_state;
 use bevy::state::state::NextState;
 use gem::GemsCollected;
-use godot_bevy::prelude::{GodotActions, GodotInputSet};
-use hud::{HudHandles, HudUpdateMessage};
+use godot_bevy::prelude::{GodotActions, GodotInputSet, SceneManager};
+use hud::HudHandles;
 
 pub mod audio;
 pub mod door;
@@ -64,7 +63,7 @@ fn detect_return_to_menu_input(mut commands: Commands, actions: Res<GodotActions
 fn on_reset_level(
     _trigger: On<ResetLevelMessage>,
     mut gems_collected: ResMut<GemsCollected>,
-    mut scene_events: MessageWriter<SceneOperationMessage>,
+    mut scenes: ResMut<SceneManager>,
     mut hud_handles: ResMut<HudHandles>,
     current_level: Res<CurrentLevel>,
     mut commands: Commands,
@@ -77,11 +76,7 @@ fn on_reset_level(
     // Clear HUD handles since they'll be invalid after scene reload
     hud_handles.clear();
 
-    // Send HUD update with reset gem count
-    commands.trigger(HudUpdateMessage::GemsChanged(0));
-
-    // Request scene reload through centralized scene management
-    scene_events.write(SceneOperationMessage::reload());
+    scenes.reload();
 
     // Emit level loaded event with current level ID
     if let Some(level_id) = current_level.level_id {
@@ -93,7 +88,7 @@ fn on_reset_level(
 fn on_return_to_menu(
     _trigger: On<ReturnToMainMenuMessage>,
     mut gems_collected: ResMut<GemsCollected>,
-    mut scene_events: MessageWriter<SceneOperationMessage>,
+    mut scenes: ResMut<SceneManager>,
     mut hud_handles: ResMut<HudHandles>,
     mut current_level: ResMut<CurrentLevel>,
     mut next_state: ResMut<NextState<GameState>>,
@@ -112,8 +107,5 @@ fn on_return_to_menu(
     // Change to main menu state
     next_state.set(GameState::MainMenu);
 
-    // Request scene change through centralized scene management
-    scene_events.write(SceneOperationMessage::change_to_file(
-        "res://scenes/levels/main_menu.tscn",
-    ));
+    scenes.change_to("res://scenes/levels/main_menu.tscn");
 }
@@ -4,181 +4,69 @@ use bevy::app::{App, Plugin};
 use bevy::prelude::*;
 use godot::classes::AnimatedSprite2D;
 use godot::classes::CharacterBody2D;
-use godot::global::move_toward;
 use godot_bevy::prelude::*;
 
-/// System sets for player operations with better parallelization
-#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
-pub enum PlayerSystemSet {
-    /// Input detection (can run in parallel with other input systems)
-    InputDetection,
-    /// Physics and movement (runs after input detection)
-    Movement,
-    /// Animation updates (runs after movement)
-    Animation,
-}
-
-/// Event for player input state
-#[derive(Message, Debug, Clone)]
-pub struct PlayerInputMessage {
-    pub movement_direction: f32,
-    pub jump_pressed: bool,
-    pub is_on_floor: bool,
-}
-
-/// Event for player movement state changes
-#[derive(Message, Debug, Clone)]
-pub struct PlayerMovementMessage {
-    pub is_moving: bool,
-    pub is_on_floor: bool,
-    pub facing_left: bool,
-}
-
+/// Movement comes from godot-bevy's `Platformer2DControllerPlugin`; this plugin
+/// tunes it from the `Player` node's exported properties and drives the sprite.
 pub struct PlayerPlugin;
 
 impl Plugin for PlayerPlugin {
     fn build(&self, app: &mut App) {
-        app.add_message::<PlayerInputMessage>()
-            .add_message::<PlayerMovementMessage>()
+        app.add_plugins(Platformer2DControllerPlugin)
+            .add_observer(play_jump_sound)
             .add_systems(
                 FixedUpdate,
                 (
-                    // Input detection runs first and can run in parallel with other input systems
-                    detect_player_input.in_set(PlayerSystemSet::InputDetection),
-                    // Movement runs after input detection
-                    apply_player_movement.in_set(PlayerSystemSet::Movement),
-                    // Animation runs after movement
-                    update_player_animation.in_set(PlayerSystemSet::Animation),
-                )
-                    .chain(), // Ensure proper execution order
+                    configure_player_controller.before(PlatformerSet),
+                    update_player_animation.after(PlatformerSet),
+                ),
             );
     }
 }
 
-/// System that detects player input and converts it to events
-///
-/// Runs in InputDetection set and can execute in parallel with other input systems.
-/// Only reads input and writes events, enabling better parallelization.
-fn detect_player_input(
-    player: Query<&GodotNodeHandle, With<Player>>,
-    mut input_events: MessageWriter<PlayerInputMessage>,
-    actions: Res<GodotActions>,
-    mut godot: GodotAccess,
+/// Hands the player to the controller, using the speed, jump velocity and
+/// gravity set on the `Player` node in the editor.
+fn configure_player_controller(
+    mut commands: Commands,
+    players: Query<(Entity, &Speed, &JumpVelocity, &Gravity), Added<Player>>,
 ) {
-    if let Ok(handle) = player.single() {
-        // Use try_get to handle case where Godot node might be invalid during scene transitions
-        let Some(character_body) = godot.try_get::<CharacterBody2D>(*handle) else {
-            return; // Node is invalid, skip this frame
-        };
-
-        let movement_direction = actions.axis("move_left", "move_right");
-        let jump_pressed = actions.just_pressed("jump");
-        let is_on_floor = character_body.is_on_floor();
-
-        // Always send input events so movement system knows current input state,
-        // including when player releases keys (movement_direction = 0.0)
-        input_events.write(PlayerInputMessage {
-            movement_direction,
-            jump_pressed,
-            is_on_floor,
-        });
+    for (entity, speed, jump_velocity, gravity) in &players {
+        commands.entity(entity).insert((
+            PlatformerController2D {
+                speed: speed.0,
+                jump_velocity: jump_velocity.0,
+                gravity: gravity.0,
+                ..default()
+            },
+            PlatformerActions::default(),
+        ));
     }
 }
 
-/// System that handles player movement physics based on input events
-///
-/// Runs in Movement set after input detection. Handles all physics calculations
-/// and movement execution separately from input detection.
-fn apply_player_movement(
-    mut input_events: MessageReader<PlayerInputMessage>,
-    player: Query<(&GodotNodeHandle, &Speed, &JumpVelocity, &Gravity), With<Player>>,
-    time: Res<Time>,
-    mut commands: Commands,
-    mut movement_events: MessageWriter<PlayerMovementMessage>,
-    mut godot: GodotAccess,
-) {
-    if let Ok((handle, speed, jump_velocity, gravity)) = player.single() {
-        let Some(mut character_body) = godot.try_get::<CharacterBody2D>(*handle) else {
-            return; // Node is invalid, skip this frame
-        };
-
-        let mut velocity = character_body.get_velocity();
-        let mut movement_occurred = false;
-        let mut last_movement_direction = 0.0;
-
-        // Always apply gravity if not on floor
-        if !character_body.is_on_floor() {
-            velocity.y += gravity.0 * time.delta_secs();
-        }
-
-        // Process input events (should always have at least one per frame now)
-        let mut processed_input = false;
-        for input_event in input_events.read() {
-            processed_input = true;
-            last_movement_direction = input_event.movement_direction;
-
-            // Handle jumping
-            if input_event.jump_pressed && input_event.is_on_floor {
-                velocity.y = jump_velocity.0;
-                commands.trigger(PlaySfxMessage::PlayerJump);
-            }
-
-            // Handle horizontal movement
-            if input_event.movement_direction != 0.0 {
-                velocity.x = input_event.movement_direction * speed.0;
-                movement_occurred = true;
-            } else {
-                // Player released movement keys - apply deceleration
-                velocity.x = move_toward(velocity.x as f64, 0.0, speed.0 as f64 / 2.0) as f32;
-            }
-        }
-
-        // Fallback: if no input events, apply deceleration (shouldn't happen normally)
-        if !processed_input {
-            velocity.x = move_toward(velocity.x as f64, 0.0, speed.0 as f64 / 2.0) as f32;
-        }
-
-        // Always apply movement and physics
-        character_body.set_velocity(velocity);
-        character_body.move_and_slide();
-
-        // Send movement event for animation system
-        movement_events.write(PlayerMovementMessage {
-            is_moving: movement_occurred,
-            is_on_floor: character_body.is_on_floor(),
-            facing_left: last_movement_direction < 0.0,
-        });
-    }
+fn play_jump_sound(_jumped: On<PlatformerJumped>, mut commands: Commands) {
+    commands.trigger(PlaySfxMessage::PlayerJump);
 }
 
-/// System that updates player animations based on movement events
-///
-/// Runs in Animation set after movement. Handles all animation state
-/// separately from physics and input.
+/// Picks the sprite's animation from where the controller left the player.
 fn update_player_animation(
-    mut movement_events: MessageReader<PlayerMovementMessage>,
-    player: Query<&GodotNodeHandle, With<Player>>,
+    player: Query<(&GodotNodeHandle, &PlatformerState), With<Player>>,
     mut godot: GodotAccess,
 ) {
-    if let Ok(handle) = player.single() {
-        let Some(character_body) = godot.try_get::<CharacterBody2D>(*handle) else {
-            return; // Node is invalid, skip this frame
-        };
-
-        let mut sprite = character_body.get_node_as::<AnimatedSprite2D>("AnimatedSprite2D");
-
-        for movement_event in movement_events.read() {
-            // Update sprite direction
-            sprite.set_flip_h(movement_event.facing_left);
-
-            // Update animation based on state
-            if !movement_event.is_on_floor {
-                sprite.play_ex().name("jump").done();
-            } else if movement_event.is_moving {
-                sprite.play_ex().name("run").done();
-            } else {
-                sprite.play_ex().name("idle").done();
-            }
-        }
+    let Ok((handle, state)) = player.single() else {
+        return;
+    };
+    // Use try_get to handle case where Godot node might be invalid during scene transitions
+    let Some(character_body) = godot.try_get::<CharacterBody2D>(*handle) else {
+        return;
+    };
+
+    let mut sprite = character_body.get_node_as::<AnimatedSprite2D>("AnimatedSprite2D");
+    sprite.set_flip_h(state.facing_left);
+    if !state.on_floor {
+        sprite.play_ex().name("jump").done();
+    } else if state.moving {
+        sprite.play_ex().name("run").done();
+    } else {
+        sprite.play_ex().name("idle").done();
     }
 }
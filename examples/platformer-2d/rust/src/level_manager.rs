@@ -5,8 +5,6 @@ use godot_bevy::interop::signal_names::SceneTreeSignals;
 use godot_bevy::plugins::scene_tree::{SceneTreeMessage, SceneTreeMessageType};
 use godot_bevy::prelude::*;
 
-use crate::scene_management::SceneOperationMessage;
-
 /// Event fired when the Godot scene changes.
 /// This demonstrates using `connect_object` to listen to singleton signals.
 #[derive(Event, Debug, Clone)]
@@ -163,7 +161,7 @@ fn handle_level_scene_change(
     current_level: Res<CurrentLevel>,
     mut loading_state: ResMut<LevelLoadingState>,
     mut pending_level: ResMut<PendingLevel>,
-    mut scene_events: MessageWriter<SceneOperationMessage>,
+    mut scenes: ResMut<SceneManager>,
     mut assets: ResMut<Assets<GodotResource>>,
 ) {
     if let (Some(level_id), Some(handle)) = (current_level.level_id, &loading_state.loading_handle)
@@ -172,8 +170,7 @@ fn handle_level_scene_change(
         if let Some(_godot_resource) = assets.get_mut(handle) {
             info!("Requesting level scene change: {:?}", level_id);
 
-            // Request scene change through centralized scene management
-            scene_events.write(SceneOperationMessage::change_to_packed(handle.clone()));
+            scenes.change_to_handle(handle.clone());
 
             // Do NOT emit LevelLoadedMessage here!
             pending_level.level_id = Some(level_id);
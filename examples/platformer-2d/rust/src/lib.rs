@@ -7,7 +7,6 @@ mod components;
 mod gameplay;
 mod level_manager;
 mod main_menu;
-mod scene_management;
 
 #[bevy_app]
 fn build_app(app: &mut App) {
@@ -22,16 +21,11 @@ fn build_app(app: &mut App) {
                 .load_collection::<GameAudio>(),
         )
         .add_plugins((
-            scene_management::SceneManagementPlugin,
+            SceneManagerPlugin::default(),
             main_menu::MainMenuPlugin,
             level_manager::LevelManagerPlugin,
             gameplay::GameplayPlugin,
-        ))
-        // Register types for inspector reflection
-        .register_type::<components::Speed>()
-        .register_type::<components::JumpVelocity>()
-        .register_type::<components::Gravity>()
-        .register_type::<components::Player>();
+        ));
 }
 
 #[derive(Debug, Default, Clone, Eq, PartialEq, Hash, States)]
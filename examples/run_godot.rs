@@ -1,36 +1,364 @@
-#[cfg(not(feature = "itest"))]
-fn main() {
-    let runner = cargo_godot_lib::GodotRunner::create(
-        env!("CARGO_PKG_NAME"),
-        &std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../godot"),
-    );
-    if let Err(e) = runner.execute() {
-        eprintln!("{e}");
-        std::process::exit(1);
+//! Builds the `.gdextension` config and launches the example's Godot project.
+//!
+//! ```text
+//! cargo run -- [--scene res://levels/test.tscn] [--headless | --windowed]
+//!              [--env KEY=VALUE]... [-- user args...]
+//! ```
+//!
+//! Without `--scene`, Godot runs the project's main scene. Arguments after `--`
+//! are passed on to the game, where `OS.get_cmdline_user_args()` returns them.
+//! `--env` sets a variable for the Godot process, e.g. `--env RUST_LOG=debug`.
+//!
+//! ```text
+//! cargo run -- --export <preset> <output> [--target <triple>]...
+//! ```
+//!
+//! builds a release library for each target (the host when none is given),
+//! copies it under the project's `lib/` directory, rewrites `rust.gdextension` to
+//! point there and runs `godot --export-release`. The export stops before Godot
+//! runs if the preset's platform has no library to load.
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use cargo_godot_lib::gdextension_config::GdExtensionConfig;
+use cargo_godot_lib::godot_commands::run_godot_import_if_needed;
+use godot_bevy::utils::{GdExtensionFile, WebThreads};
+
+/// How to launch Godot, from the defaults and the command line.
+#[derive(Debug, Default)]
+struct LaunchOptions {
+    scene: Option<String>,
+    headless: bool,
+    env: Vec<(String, String)>,
+    /// Godot arguments that don't come from the options above.
+    godot_args: Vec<String>,
+    user_args: Vec<String>,
+    export: Option<ExportOptions>,
+}
+
+/// `--export`: a release export of `preset` to `output`, relative to the project.
+#[derive(Debug, Default)]
+struct ExportOptions {
+    preset: String,
+    output: String,
+    /// Rust target triples to build; empty means the host.
+    targets: Vec<String>,
+}
+
+impl LaunchOptions {
+    fn parse(mut self, args: impl IntoIterator<Item = String>) -> Result<Self, String> {
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--scene" => self.scene = Some(args.next().ok_or("--scene needs a .tscn path")?),
+                "--headless" => self.headless = true,
+                "--windowed" => self.headless = false,
+                "--env" => {
+                    let pair = args.next().ok_or("--env needs KEY=VALUE")?;
+                    let (key, value) = pair
+                        .split_once('=')
+                        .ok_or_else(|| format!("--env {pair}: expected KEY=VALUE"))?;
+                    self.env.push((key.to_string(), value.to_string()));
+                }
+                "--export" => {
+                    let export = self.export.get_or_insert_with(ExportOptions::default);
+                    export.preset = args.next().ok_or("--export needs a preset name")?;
+                    export.output = args.next().ok_or("--export needs an output path")?;
+                }
+                "--target" => {
+                    let target = args.next().ok_or("--target needs a target triple")?;
+                    self.export
+                        .get_or_insert_with(ExportOptions::default)
+                        .targets
+                        .push(target);
+                }
+                "--" => self.user_args.extend(args.by_ref()),
+                other => return Err(format!("unknown option {other}")),
+            }
+        }
+        if self
+            .export
+            .as_ref()
+            .is_some_and(|export| export.preset.is_empty())
+        {
+            return Err("--target is only used with --export".to_string());
+        }
+        Ok(self)
     }
+
+    fn godot_cli_arguments(&self) -> Vec<String> {
+        let mut args = Vec::new();
+        if self.headless {
+            args.push("--headless".to_string());
+        }
+        if let Some(scene) = &self.scene {
+            args.extend(["--scene".to_string(), scene.clone()]);
+        }
+        args.extend(self.godot_args.iter().cloned());
+        if !self.user_args.is_empty() {
+            args.push("--".to_string());
+            args.extend(self.user_args.iter().cloned());
+        }
+        args
+    }
+}
+
+#[cfg(not(feature = "itest"))]
+fn default_options() -> LaunchOptions {
+    LaunchOptions::default()
 }
 
+// Run with `cargo run --features itest` to run integration tests
 #[cfg(feature = "itest")]
+fn default_options() -> LaunchOptions {
+    LaunchOptions {
+        scene: Some("res://addons/godot-bevy/test/TestRunner.tscn".to_string()),
+        headless: true,
+        godot_args: vec!["--quit-after".to_string(), "10000".to_string()],
+        ..LaunchOptions::default()
+    }
+}
+
+/// The `.gdextension` platform a target builds for; `None` is the host.
+fn platform(target: Option<&str>) -> &'static str {
+    match target {
+        None => std::env::consts::OS,
+        Some(t) if t.contains("emscripten") => "web",
+        Some(t) if t.contains("android") => "android",
+        Some(t) if t.contains("apple-ios") => "ios",
+        Some(t) if t.contains("windows") => "windows",
+        Some(t) if t.contains("apple") => "macos",
+        Some(_) => "linux",
+    }
+}
+
+fn library_file(lib_name: &str, platform: &str) -> String {
+    match platform {
+        "windows" => format!("{lib_name}.dll"),
+        "macos" | "ios" => format!("lib{lib_name}.dylib"),
+        "web" => format!("{lib_name}.wasm"),
+        _ => format!("lib{lib_name}.so"),
+    }
+}
+
+/// Cargo's target directory: `CARGO_TARGET_DIR`, or the one this binary was built into.
+fn cargo_target_dir() -> Result<PathBuf, String> {
+    if let Some(dir) = std::env::var_os("CARGO_TARGET_DIR") {
+        return Ok(dir.into());
+    }
+    let exe =
+        std::env::current_exe().map_err(|e| format!("can't find the target directory: {e}"))?;
+    exe.ancestors()
+        .nth(2)
+        .map(Path::to_path_buf)
+        .ok_or_else(|| format!("can't find the target directory above {}", exe.display()))
+}
+
+/// The `platform=` of the export preset called `preset`, as a `.gdextension` platform.
+fn preset_platform(godot_project: &Path, preset: &str) -> Result<&'static str, String> {
+    let presets = std::fs::read_to_string(godot_project.join("export_presets.cfg"))
+        .map_err(|e| format!("can't read export_presets.cfg: {e}"))?;
+    let mut name = None;
+    for line in presets.lines() {
+        if line.starts_with('[') {
+            name = None;
+        } else if let Some(value) = line.strip_prefix("name=") {
+            name = Some(value.trim_matches('"'));
+        } else if let Some(value) = line
+            .strip_prefix("platform=")
+            .filter(|_| name == Some(preset))
+        {
+            return match value.trim_matches('"') {
+                "Linux/X11" | "Linux" => Ok("linux"),
+                "Windows Desktop" => Ok("windows"),
+                "macOS" => Ok("macos"),
+                "Web" => Ok("web"),
+                "Android" => Ok("android"),
+                "iOS" => Ok("ios"),
+                other => Err(format!("preset {preset}: unsupported platform {other}")),
+            };
+        }
+    }
+    Err(format!(
+        "no export preset named {preset} in export_presets.cfg"
+    ))
+}
+
+/// Every release library `gdextension` lists for `platform` should exist in the
+/// project; at least one must, or the exported game can't load the extension.
+fn validate_gdextension(
+    gdextension: &str,
+    godot_project: &Path,
+    platform: &str,
+) -> Result<(), String> {
+    let mut found = false;
+    let mut missing = Vec::new();
+    for line in gdextension.lines() {
+        let Some((key, path)) = line.split_once(" = ") else {
+            continue;
+        };
+        if !key.starts_with(&format!("{platform}.release")) {
+            continue;
+        }
+        let path = path.trim_matches('"');
+        if godot_project
+            .join(path.trim_start_matches("res://"))
+            .is_file()
+        {
+            found = true;
+        } else {
+            missing.push(format!("{key} = {path}"));
+        }
+    }
+    if !found {
+        return Err(format!(
+            "rust.gdextension has no {platform} release library to export; \
+             pass the matching --target"
+        ));
+    }
+    for entry in missing {
+        eprintln!("warning: {entry} doesn't exist and won't be exported");
+    }
+    Ok(())
+}
+
+fn export(export: &ExportOptions, godot_project: &Path) -> Result<(), String> {
+    let crate_name = env!("CARGO_PKG_NAME");
+    let lib_name = crate_name.replace('-', "_");
+    let preset_platform = preset_platform(godot_project, &export.preset)?;
+    let target_dir = cargo_target_dir()?;
+
+    let mut gdextension = GdExtensionFile::new(crate_name)
+        .target_dir("res://lib")
+        .reloadable(false);
+    let targets: Vec<Option<&str>> = if export.targets.is_empty() {
+        vec![None]
+    } else {
+        export.targets.iter().map(|t| Some(t.as_str())).collect()
+    };
+
+    for target in targets {
+        let cargo = std::env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+        let mut build = Command::new(cargo);
+        build.current_dir(env!("CARGO_MANIFEST_DIR")).args([
+            "build",
+            "--release",
+            "--lib",
+            "-p",
+            crate_name,
+        ]);
+        if let Some(target) = target {
+            build.args(["--target", target]);
+        }
+        let status = build
+            .status()
+            .map_err(|e| format!("failed to run cargo: {e}"))?;
+        if !status.success() {
+            return Err(format!(
+                "cargo build for {} failed",
+                target.unwrap_or("the host")
+            ));
+        }
+
+        // Desktop entries in the .gdextension have no target directory, mobile and web ones do.
+        let platform = platform(target);
+        let file = library_file(&lib_name, platform);
+        let (built, copied) = match target {
+            Some(target) if matches!(platform, "web" | "android" | "ios") => (
+                target_dir.join(target),
+                godot_project.join("lib").join(target),
+            ),
+            Some(target) => (target_dir.join(target), godot_project.join("lib")),
+            None => (target_dir.clone(), godot_project.join("lib")),
+        };
+        let copied = copied.join("release");
+        std::fs::create_dir_all(&copied).map_err(|e| format!("{}: {e}", copied.display()))?;
+        std::fs::copy(built.join("release").join(&file), copied.join(&file))
+            .map_err(|e| format!("copying {file}: {e}"))?;
+
+        gdextension = match platform {
+            "web" => gdextension.web(WebThreads::Threads),
+            "android" => gdextension.android(),
+            "ios" => gdextension.ios(),
+            _ => gdextension,
+        };
+    }
+
+    let contents = gdextension.build();
+    validate_gdextension(&contents, godot_project, preset_platform)?;
+    gdextension
+        .write(godot_project.join("rust.gdextension"))
+        .map_err(|e| format!("can't write rust.gdextension: {e}"))?;
+
+    if let Some(parent) = godot_project.join(&export.output).parent() {
+        std::fs::create_dir_all(parent).map_err(|e| format!("{}: {e}", parent.display()))?;
+    }
+    cargo_godot_lib::GodotRunner::create(crate_name, godot_project)
+        .write_gdextension_config(false)
+        .godot_cli_arguments(vec![
+            "--headless".to_string(),
+            "--export-release".to_string(),
+            export.preset.clone(),
+            export.output.clone(),
+        ])
+        .execute()
+        .map_err(|e| e.to_string())
+}
+
+/// The Godot binary: the `godot` or `GODOT` variable, else `godot` on the `PATH`.
+fn godot_binary() -> std::ffi::OsString {
+    std::env::var_os("godot")
+        .or_else(|| std::env::var_os("GODOT"))
+        .unwrap_or_else(|| "godot".into())
+}
+
+/// Write the `.gdextension` config, import the project if it never was, and run
+/// Godot. `GodotRunner` spawns Godot itself and can't pass it `--env` variables,
+/// so this takes the same steps with its own `Command`.
+fn run(options: &LaunchOptions, godot_project: &Path) -> Result<(), String> {
+    let target_dir = cargo_target_dir()?;
+    GdExtensionConfig::start(env!("CARGO_PKG_NAME"), godot_project, &target_dir)
+        .build()
+        .map_err(|e| e.to_string())?
+        .write()
+        .map_err(|e| format!("can't write the .gdextension config: {e}"))?;
+    run_godot_import_if_needed(godot_project, None).map_err(|e| e.to_string())?;
+
+    let status = Command::new(godot_binary())
+        .current_dir(godot_project)
+        .args(options.godot_cli_arguments())
+        .envs(options.env.iter().map(|(key, value)| (key, value)))
+        .status()
+        .map_err(|e| format!("failed to run Godot: {e}"))?;
+    if !status.success() {
+        return Err(format!("Godot exited with {status}"));
+    }
+    Ok(())
+}
+
 fn main() {
-    let runner = cargo_godot_lib::GodotRunner::create(
-        env!("CARGO_PKG_NAME"),
-        &std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../godot"),
-    );
+    let options = match default_options().parse(std::env::args().skip(1)) {
+        Ok(options) => options,
+        Err(e) => {
+            eprintln!("{e}");
+            std::process::exit(2);
+        }
+    };
+    let godot_project = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../godot");
+    if let Some(export_options) = &options.export {
+        if let Err(e) = export(export_options, &godot_project) {
+            eprintln!("{e}");
+            std::process::exit(1);
+        }
+        return;
+    }
 
-    // Run with `cargo run --features itest` to run integration tests
-    #[cfg(feature = "itest")]
-    let runner = runner.godot_cli_arguments(vec![
-        "--headless",
-        "--scene",
-        "res://addons/godot-bevy/test/TestRunner.tscn",
-        "--quit-after",
-        "10000",
-    ]);
-
-    if let Err(e) = runner.execute() {
+    if let Err(e) = run(&options, &godot_project) {
         eprintln!("{e}");
         std::process::exit(1);
     }
 
+    #[cfg(feature = "itest")]
     std::process::exit(godot_bevy_test::exit_code::read_and_cleanup_exit_code().unwrap_or(1));
 }
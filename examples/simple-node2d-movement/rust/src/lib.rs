@@ -1,14 +1,11 @@
 use bevy::ecs::system::Query;
 use bevy::prelude::{
-    App, Commands, Component, Entity, IntoScheduleConfigs, Res, Time, Update, Without,
+    App, Commands, Component, Entity, IntoScheduleConfigs, Name, Res, Time, Update, With, Without,
 };
 use bevy::transform::components::Transform;
 use godot::builtin::Vector2;
-use godot::classes::Sprite2D;
 use godot::global::godot_print;
-use godot_bevy::prelude::{
-    GodotAccess, GodotNodeHandle, GodotTransformSyncPlugin, Sprite2DMarker, bevy_app,
-};
+use godot_bevy::prelude::{GodotTransformSyncPlugin, Sprite2DMarker, bevy_app};
 use std::f32::consts::PI;
 
 // The build_app function runs at your game's startup.
@@ -64,28 +61,27 @@ struct Orbiter {
 #[derive(Debug, Component)]
 struct NodeInitialized;
 
+// Sprite2Ds that orbit_setup hasn't initialized yet.
+type UninitializedSprite = (With<Sprite2DMarker>, Without<NodeInitialized>);
+
 // This system initializes Sprite2Ds with the required components to allow the orbit_system to manipulate them.
 fn orbit_setup(
     // Bevy Commands allow us to modify the state of the world, such as adding components to entities.
     mut commands: Commands,
 
-    // Gather all Godot nodes without the `NodeInitialized` component.
+    // Gather all Sprite2Ds without the `NodeInitialized` component.
     // Also, include the Bevy entity identifier so we can add components to it.
-    uninitialized: Query<(Entity, &GodotNodeHandle, &Sprite2DMarker), Without<NodeInitialized>>,
-    mut godot: GodotAccess,
+    // The `Transform` and `Name` are already set from the node when its entity is
+    // created, so there is no need to ask Godot for them.
+    uninitialized: Query<(Entity, &Transform, &Name), UninitializedSprite>,
 ) {
-    for (entity, node_handle, _) in uninitialized.iter() {
-        let sprite_node = godot.get::<Sprite2D>(*node_handle);
-        // The GodotNodeHandle allows us to call Godot methods such as `get_name()`.
-        godot_print!(
-            "Initializing node: {:?}",
-            sprite_node.get_name().to_string()
-        );
+    for (entity, transform, name) in uninitialized.iter() {
+        godot_print!("Initializing node: {:?}", name.as_str());
         // Attach new components to the entity.
         commands
             .entity(entity)
             .insert(InitialPosition {
-                pos: sprite_node.get_transform().origin,
+                pos: Vector2::new(transform.translation.x, transform.translation.y),
             })
             .insert(Orbiter { angle: 0.0 })
             .insert(NodeInitialized);
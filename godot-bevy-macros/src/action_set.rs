@@ -0,0 +1,243 @@
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::parse::{Parse, ParseStream};
+use syn::{Data, DeriveInput, Error, Fields, Ident, LitFloat, LitStr, Token, parenthesized};
+
+/// `#[derive(GodotActionSet)]`: one Godot action per unit variant.
+pub fn action_set(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let item = &input.ident;
+    let Data::Enum(data) = &input.data else {
+        return Err(Error::new_spanned(
+            &input,
+            "GodotActionSet must be used on enums",
+        ));
+    };
+
+    let mut variants = Vec::new();
+    let mut names = Vec::new();
+    let mut bindings = Vec::new();
+    let mut deadzones = Vec::new();
+    for variant in &data.variants {
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(Error::new_spanned(
+                variant,
+                "GodotActionSet variants cannot have fields",
+            ));
+        }
+        let mut action = ActionAttr::default();
+        for attr in &variant.attrs {
+            if attr.path().is_ident("action") {
+                action.merge(attr.parse_args()?);
+            }
+        }
+        let ident = &variant.ident;
+        let name = action
+            .name
+            .map(|name| name.value())
+            .unwrap_or_else(|| snake_case(&ident.to_string()));
+        let deadzone = action.deadzone.map(|deadzone| quote!(#deadzone));
+
+        variants.push(ident);
+        names.push(quote!(#item::#ident => #name));
+        let list = action.bindings.iter().map(Binding::to_tokens);
+        bindings.push(quote!(#item::#ident => &[#(#list),*]));
+        if let Some(deadzone) = deadzone {
+            deadzones.push(quote!(#item::#ident => #deadzone));
+        }
+    }
+
+    let deadzone_fn = (!deadzones.is_empty()).then(|| {
+        quote! {
+            fn deadzone(self) -> f32 {
+                match self {
+                    #(#deadzones,)*
+                    #[allow(unreachable_patterns)]
+                    _ => 0.5,
+                }
+            }
+        }
+    });
+
+    Ok(quote! {
+        impl godot_bevy::plugins::input::GodotActionSet for #item {
+            fn variants() -> &'static [Self] {
+                &[#(#item::#variants),*]
+            }
+
+            fn action_name(self) -> &'static str {
+                match self {
+                    #(#names,)*
+                }
+            }
+
+            fn default_bindings(self) -> &'static [godot_bevy::plugins::input::ActionBinding] {
+                match self {
+                    #(#bindings,)*
+                }
+            }
+
+            #deadzone_fn
+        }
+    })
+}
+
+/// `MoveLeft` -> `move_left`, matching Godot's built-in `ui_*` style.
+fn snake_case(name: &str) -> String {
+    let mut out = String::new();
+    for (i, ch) in name.chars().enumerate() {
+        if ch.is_uppercase() {
+            if i > 0 {
+                out.push('_');
+            }
+            out.extend(ch.to_lowercase());
+        } else {
+            out.push(ch);
+        }
+    }
+    out
+}
+
+#[derive(Default)]
+struct ActionAttr {
+    name: Option<LitStr>,
+    deadzone: Option<LitFloat>,
+    bindings: Vec<Binding>,
+}
+
+impl ActionAttr {
+    fn merge(&mut self, other: ActionAttr) {
+        if other.name.is_some() {
+            self.name = other.name;
+        }
+        if other.deadzone.is_some() {
+            self.deadzone = other.deadzone;
+        }
+        self.bindings.extend(other.bindings);
+    }
+}
+
+enum Binding {
+    Key(Ident),
+    Mouse(Ident),
+    JoyButton(Ident),
+    JoyAxis(Ident, LitFloat, bool),
+}
+
+impl Binding {
+    fn to_tokens(&self) -> TokenStream2 {
+        let binding = quote!(godot_bevy::plugins::input::ActionBinding);
+        match self {
+            Binding::Key(key) => quote!(#binding::Key(godot::global::Key::#key)),
+            Binding::Mouse(button) => {
+                quote!(#binding::MouseButton(godot::global::MouseButton::#button))
+            }
+            Binding::JoyButton(button) => {
+                quote!(#binding::JoyButton(godot::global::JoyButton::#button))
+            }
+            Binding::JoyAxis(axis, value, negative) => {
+                let sign = negative.then(|| quote!(-));
+                quote!(#binding::JoyAxis(godot::global::JoyAxis::#axis, #sign #value))
+            }
+        }
+    }
+}
+
+impl Parse for ActionAttr {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut attr = ActionAttr::default();
+        while !input.is_empty() {
+            let key: Ident = input.parse()?;
+            if key == "joy_axis" {
+                let content;
+                parenthesized!(content in input);
+                let axis: Ident = content.parse()?;
+                content.parse::<Token![,]>()?;
+                let negative = content.parse::<Option<Token![-]>>()?.is_some();
+                let value: LitFloat = content.parse()?;
+                if value.base10_parse::<f32>()? != 1.0 {
+                    return Err(Error::new(
+                        value.span(),
+                        "joy_axis direction must be 1.0 or -1.0",
+                    ));
+                }
+                attr.bindings.push(Binding::JoyAxis(axis, value, negative));
+            } else {
+                input.parse::<Token![=]>()?;
+                if key == "name" {
+                    attr.name = Some(input.parse()?);
+                } else if key == "deadzone" {
+                    attr.deadzone = Some(input.parse()?);
+                } else if key == "key" {
+                    attr.bindings.push(Binding::Key(input.parse()?));
+                } else if key == "mouse" {
+                    attr.bindings.push(Binding::Mouse(input.parse()?));
+                } else if key == "joy_button" {
+                    attr.bindings.push(Binding::JoyButton(input.parse()?));
+                } else {
+                    return Err(Error::new(
+                        key.span(),
+                        format!(
+                            "unknown key `{key}`; expected `name`, `deadzone`, `key`, `mouse`, \
+                             `joy_button`, or `joy_axis(...)`"
+                        ),
+                    ));
+                }
+            }
+            if input.peek(Token![,]) {
+                input.parse::<Token![,]>()?;
+            } else {
+                break;
+            }
+        }
+        Ok(attr)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use syn::parse_quote;
+
+    #[test]
+    fn generates_names_bindings_and_deadzones() {
+        let di: DeriveInput = parse_quote! {
+            enum PlayerAction {
+                #[action(key = SPACE, joy_button = A)]
+                Jump,
+                #[action(key = A, joy_axis(LEFT_X, -1.0), deadzone = 0.2)]
+                MoveLeft,
+                #[action(name = "fire", mouse = LEFT)]
+                Shoot,
+            }
+        };
+        let out = action_set(di).unwrap().to_string();
+        assert!(out.contains("PlayerAction :: Jump => \"jump\""));
+        assert!(out.contains("PlayerAction :: MoveLeft => \"move_left\""));
+        assert!(out.contains("PlayerAction :: Shoot => \"fire\""));
+        assert!(out.contains("godot :: global :: Key :: SPACE"));
+        assert!(out.contains("godot :: global :: JoyAxis :: LEFT_X , - 1.0"));
+        assert!(out.contains("godot :: global :: MouseButton :: LEFT"));
+        assert!(out.contains("PlayerAction :: MoveLeft => 0.2"));
+    }
+
+    #[test]
+    fn rejects_fields_and_unknown_keys() {
+        let di: DeriveInput = parse_quote! {
+            enum PlayerAction { Move(f32) }
+        };
+        assert!(action_set(di).unwrap_err().to_string().contains("fields"));
+
+        let di: DeriveInput = parse_quote! {
+            enum PlayerAction {
+                #[action(button = A)]
+                Jump,
+            }
+        };
+        assert!(
+            action_set(di)
+                .unwrap_err()
+                .to_string()
+                .contains("unknown key")
+        );
+    }
+}
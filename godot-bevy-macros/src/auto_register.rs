@@ -0,0 +1,63 @@
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{DeriveInput, Error};
+
+/// `#[derive(AutoRegister)]`: submit a `register_type` call for the type, run by
+/// `GodotSceneTreePlugin` at app build.
+pub fn auto_register(input: DeriveInput) -> syn::Result<TokenStream2> {
+    if !input.generics.params.is_empty() {
+        return Err(Error::new_spanned(
+            &input.generics,
+            "AutoRegister can't register generic types; call `app.register_type::<T<...>>()` \
+             for each instantiation instead",
+        ));
+    }
+
+    let item = &input.ident;
+    let fn_name = format_ident!("__register_{}_type", item.to_string().to_lowercase());
+
+    Ok(quote! {
+        const _: () = {
+            fn #fn_name(app: &mut godot_bevy::bevy_app::App) {
+                app.register_type::<#item>();
+            }
+
+            godot_bevy::inventory::submit! {
+                godot_bevy::prelude::AutoRegisteredType {
+                    type_name: concat!(module_path!(), "::", stringify!(#item)),
+                    register_fn: #fn_name,
+                }
+            }
+        };
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use syn::parse_quote;
+
+    #[test]
+    fn submits_a_register_type_call() {
+        let di: DeriveInput = parse_quote! {
+            #[derive(Component, Reflect)]
+            struct Speed(f32);
+        };
+        let out = auto_register(di).unwrap().to_string();
+        assert!(out.contains("app . register_type :: < Speed > ()"));
+        assert!(out.contains("register_fn : __register_speed_type"));
+    }
+
+    #[test]
+    fn rejects_generic_types() {
+        let di: DeriveInput = parse_quote! {
+            struct Wrapper<T>(T);
+        };
+        assert!(
+            auto_register(di)
+                .unwrap_err()
+                .to_string()
+                .contains("generic")
+        );
+    }
+}
@@ -4,8 +4,8 @@ use syn::parse::{Parse, ParseStream};
 use syn::punctuated::Punctuated;
 use syn::spanned::Spanned;
 use syn::{
-    Attribute, Data, DeriveInput, Error, Expr, Field, Fields, Ident, Meta, Path, Token, Type,
-    braced, parenthesized, parse_quote,
+    Attribute, Data, DeriveInput, Error, Expr, Field, Fields, Ident, LitStr, Meta, Path, Token,
+    Type, braced, parenthesized, parse_quote,
 };
 
 /// The Godot class + Bevy components a single derive expands to.
@@ -15,6 +15,8 @@ use syn::{
 pub struct ClassPlan {
     pub godot_class: syn::Ident,
     pub base: syn::Ident,
+    /// Editor icon for the generated class, listed in the `.gdextension` `[icons]` section.
+    pub icon: Option<syn::LitStr>,
     pub emit_node_class: bool,
     pub trigger: Option<syn::Path>,
     pub primary: PrimaryPlan,
@@ -238,11 +240,12 @@ fn parse_one_require(input: ParseStream) -> syn::Result<RawRequire> {
     }
 }
 
-/// Struct-level `#[gdbevy(...)]` directives: `base`, `class_name`, and `require(...)`.
+/// Struct-level `#[gdbevy(...)]` directives: `base`, `class_name`, `icon`, and `require(...)`.
 #[derive(Default)]
 struct StructLevel {
     base: Option<Ident>,
     class_name: Option<Ident>,
+    icon: Option<LitStr>,
     requires: Vec<RawRequire>,
 }
 
@@ -265,11 +268,21 @@ impl Parse for StructLevel {
             } else if key == "class_name" {
                 input.parse::<Token![=]>()?;
                 sl.class_name = Some(input.parse()?);
+            } else if key == "icon" {
+                input.parse::<Token![=]>()?;
+                let icon: LitStr = input.parse()?;
+                if !icon.value().starts_with("res://") {
+                    return Err(Error::new(
+                        icon.span(),
+                        "`icon` must be a `res://` path, e.g. \"res://icons/orbiter.svg\"",
+                    ));
+                }
+                sl.icon = Some(icon);
             } else {
                 return Err(Error::new(
                     key.span(),
                     format!(
-                        "unknown key `{key}`; expected `base`, `class_name`, or `require(...)`"
+                        "unknown key `{key}`; expected `base`, `class_name`, `icon`, or `require(...)`"
                     ),
                 ));
             }
@@ -296,6 +309,9 @@ fn collect_struct_level(input: &DeriveInput) -> syn::Result<StructLevel> {
         if sl.class_name.is_some() {
             acc.class_name = sl.class_name;
         }
+        if sl.icon.is_some() {
+            acc.icon = sl.icon;
+        }
         acc.requires.extend(sl.requires);
     }
     Ok(acc)
@@ -572,6 +588,7 @@ pub fn parse_component_first(input: &DeriveInput) -> syn::Result<ClassPlan> {
     Ok(ClassPlan {
         godot_class,
         base,
+        icon: sl.icon,
         emit_node_class: true,
         trigger: Some(struct_ident.into()),
         primary,
@@ -584,13 +601,19 @@ pub fn parse_godot_first(input: &DeriveInput) -> syn::Result<ClassPlan> {
     if let Some(base) = &sl.base {
         return Err(Error::new(
             base.span(),
-            "`base`/`class_name` are only valid in component-first (`GodotNode`)",
+            "`base`/`class_name`/`icon` are only valid in component-first (`GodotNode`)",
         ));
     }
     if let Some(class_name) = &sl.class_name {
         return Err(Error::new(
             class_name.span(),
-            "`base`/`class_name` are only valid in component-first (`GodotNode`)",
+            "`base`/`class_name`/`icon` are only valid in component-first (`GodotNode`)",
+        ));
+    }
+    if let Some(icon) = &sl.icon {
+        return Err(Error::new(
+            icon.span(),
+            "`base`/`class_name`/`icon` are only valid in component-first (`GodotNode`)",
         ));
     }
 
@@ -605,6 +628,7 @@ pub fn parse_godot_first(input: &DeriveInput) -> syn::Result<ClassPlan> {
     Ok(ClassPlan {
         godot_class: input.ident.clone(),
         base: parse_quote!(Node),
+        icon: None,
         emit_node_class: false,
         trigger: None,
         primary: PrimaryPlan {
@@ -903,6 +927,44 @@ mod tests {
         );
     }
 
+    #[test]
+    fn cf_icon() {
+        let di: syn::DeriveInput = parse_quote! {
+            #[derive(Component, GodotNode, Default)]
+            #[gdbevy(base = Node3D, class_name = Orbiter3D, icon = "res://icons/orbiter.svg")]
+            struct Orbiter;
+        };
+        let plan = parse_component_first(&di).unwrap();
+        assert_eq!(plan.icon.unwrap().value(), "res://icons/orbiter.svg");
+
+        let di: syn::DeriveInput = parse_quote! {
+            #[derive(Component, GodotNode, Default)]
+            #[gdbevy(icon = "icons/orbiter.svg")]
+            struct Orbiter;
+        };
+        assert!(
+            parse_component_first(&di)
+                .unwrap_err()
+                .to_string()
+                .contains("res://")
+        );
+    }
+
+    #[test]
+    fn icon_on_gf() {
+        let di: syn::DeriveInput = parse_quote! {
+            #[derive(GodotClass, BevyComponents)]
+            #[gdbevy(icon = "res://icons/player.svg")]
+            struct PlayerNode { base: Base<Node2D> }
+        };
+        assert!(
+            parse_godot_first(&di)
+                .unwrap_err()
+                .to_string()
+                .contains("component-first")
+        );
+    }
+
     #[test]
     fn sync_key_is_reserved() {
         let di: syn::DeriveInput = parse_quote! {
@@ -4,7 +4,7 @@ use quote::{ToTokens, format_ident, quote};
 use std::collections::HashSet;
 use syn::parse::{Parse, ParseStream};
 use syn::punctuated::Punctuated;
-use syn::{Attribute, Data, DeriveInput, Expr, Ident, Path, Token, Type};
+use syn::{Attribute, Data, DeriveInput, Expr, Ident, LitStr, Path, Token, Type};
 
 /// Lower a `ClassPlan` to the Godot class, autosync registration, and required-components
 /// registrar. `input` is threaded through for two things the IR doesn't carry: the primary
@@ -14,6 +14,9 @@ pub fn emit(plan: &ClassPlan, input: &DeriveInput) -> TokenStream2 {
     if plan.emit_node_class {
         out.extend(emit_node_class(plan, input));
     }
+    if let Some(icon) = &plan.icon {
+        out.extend(emit_icon(plan, icon));
+    }
     out.extend(emit_autosync(plan));
     if let Some(trigger) = &plan.trigger {
         let sibling = collect_require_idents(&input.attrs);
@@ -74,6 +77,19 @@ fn export_field(m: &Mapping, ty: Option<Type>) -> TokenStream2 {
     }
 }
 
+/// Register the class's editor icon; `gdextension_icons_section` lists it under `[icons]`.
+fn emit_icon(plan: &ClassPlan, icon: &LitStr) -> TokenStream2 {
+    let class = &plan.godot_class;
+    quote! {
+        godot_bevy::inventory::submit! {
+            godot_bevy::prelude::GodotNodeIcon {
+                godot_class_id_fn: || <#class as godot::prelude::GodotClass>::class_id(),
+                icon_path: #icon,
+            }
+        }
+    }
+}
+
 /// The autosync `create_bundle_fn` + its `inventory::submit!`. Reads the editor-authored
 /// `#[export]` values off the node and inserts them as a direct component tuple.
 fn emit_autosync(plan: &ClassPlan) -> TokenStream2 {
@@ -355,6 +371,22 @@ mod tests {
         assert!(out.contains("AutoSyncBundleRegistry"));
         assert!(out.contains("Stunned :: default ()"));
         assert!(!out.contains("bevy_bundle"));
+        assert!(!out.contains("GodotNodeIcon"));
+    }
+
+    #[test]
+    fn cf_icon_is_registered() {
+        let di: syn::DeriveInput = parse_quote! {
+            #[derive(Component, GodotNode, Default)]
+            #[gdbevy(base = Node3D, class_name = Orbiter3D, icon = "res://icons/orbiter.svg")]
+            struct Orbiter;
+        };
+        let out = crate::godot_node::derive_godot_node_component(di)
+            .unwrap()
+            .to_string();
+        assert!(out.contains("GodotNodeIcon"));
+        assert!(out.contains("< Orbiter3D as godot :: prelude :: GodotClass > :: class_id ()"));
+        assert!(out.contains("icon_path : \"res://icons/orbiter.svg\""));
     }
 
     #[test]
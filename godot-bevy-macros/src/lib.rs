@@ -1,3 +1,5 @@
+mod action_set;
+mod auto_register;
 mod bevy_attr;
 mod emit;
 mod godot_node;
@@ -26,6 +28,25 @@ pub fn bevy_app(attr: TokenStream, item: TokenStream) -> TokenStream {
 
     let scene_tree_auto_despawn_children = config.scene_tree_auto_despawn_children;
 
+    // A named app is only registered; the unnamed `#[bevy_app]` stays the
+    // library's entry point, and a `BevyApp` node picks this one by `app_name`.
+    if let Some(app_name) = config.name {
+        return quote! {
+            godot_bevy::inventory::submit! {
+                godot_bevy::app::NamedBevyApp {
+                    name: #app_name,
+                    config: godot_bevy::app::BevyAppConfig {
+                        scene_tree_auto_despawn_children: #scene_tree_auto_despawn_children,
+                    },
+                    init_fn: #name,
+                }
+            }
+
+            #input_fn
+        }
+        .into();
+    }
+
     // Fully-qualified paths so a user's crate needs no `use godot::init::{...}`.
     let expanded = quote! {
         struct BevyExtensionLibrary;
@@ -41,9 +62,17 @@ pub fn bevy_app(attr: TokenStream, item: TokenStream) -> TokenStream {
                         #name,
                     );
                 }
+                if stage == godot::prelude::InitStage::Scene {
+                    godot_bevy::plugins::metrics::register_singleton();
+                    godot_bevy::plugins::resource_bridge::register_singleton();
+                }
             }
 
             fn on_stage_deinit(stage: godot::prelude::InitStage) {
+                if stage == godot::prelude::InitStage::Scene {
+                    godot_bevy::plugins::metrics::unregister_singleton();
+                    godot_bevy::plugins::resource_bridge::unregister_singleton();
+                }
                 if stage == godot::prelude::InitStage::Core {
                     godot_bevy::app::deinit();
                 }
@@ -57,12 +86,14 @@ pub fn bevy_app(attr: TokenStream, item: TokenStream) -> TokenStream {
 }
 
 struct BevyAppConfig {
+    name: Option<syn::LitStr>,
     scene_tree_auto_despawn_children: bool,
 }
 
 impl Default for BevyAppConfig {
     fn default() -> Self {
         Self {
+            name: None,
             scene_tree_auto_despawn_children: true,
         }
     }
@@ -71,7 +102,14 @@ impl Default for BevyAppConfig {
 fn parse_bevy_app_config(attr: TokenStream) -> Result<BevyAppConfig, Error> {
     let mut config = BevyAppConfig::default();
     let parser = syn::meta::parser(|meta| {
-        if meta.path.is_ident("scene_tree_auto_despawn_children") {
+        if meta.path.is_ident("name") {
+            let name = meta.value()?.parse::<syn::LitStr>()?;
+            if name.value().is_empty() {
+                return Err(Error::new(name.span(), "a bevy_app name can't be empty"));
+            }
+            config.name = Some(name);
+            Ok(())
+        } else if meta.path.is_ident("scene_tree_auto_despawn_children") {
             config.scene_tree_auto_despawn_children = meta.value()?.parse::<syn::LitBool>()?.value;
             Ok(())
         } else if meta.path.is_ident("scene_tree_add_child_relationship") {
@@ -87,6 +125,59 @@ fn parse_bevy_app_config(attr: TokenStream) -> Result<BevyAppConfig, Error> {
     Ok(config)
 }
 
+/// Declares a set of Godot input actions, one per unit variant, with default bindings.
+///
+/// ```ignore
+/// #[derive(GodotActionSet, Clone, Copy, PartialEq, Eq, Hash, Debug)]
+/// enum PlayerAction {
+///     #[action(key = SPACE, joy_button = A)]
+///     Jump,
+///     #[action(key = A, key = LEFT, joy_axis(LEFT_X, -1.0), deadzone = 0.2)]
+///     MoveLeft,
+///     #[action(name = "fire", mouse = LEFT)]
+///     Shoot,
+/// }
+/// ```
+///
+/// Register it with `app.add_action_set::<PlayerAction>()` to create the actions missing
+/// from Godot's Input Map at startup, and read it with `GodotActions` like any action name.
+///
+/// Keys on `#[action(...)]`, all optional and repeatable for bindings:
+/// - `name = "..."`: the Godot action name; defaults to the variant in snake_case.
+/// - `deadzone = 0.2`: the action's dead zone; defaults to Godot's 0.5.
+/// - `key = SPACE`: a `godot::global::Key`, matched by physical location.
+/// - `mouse = LEFT`: a `godot::global::MouseButton`.
+/// - `joy_button = A`: a `godot::global::JoyButton` on any gamepad.
+/// - `joy_axis(LEFT_X, -1.0)`: one direction of a `godot::global::JoyAxis` on any gamepad.
+#[proc_macro_derive(GodotActionSet, attributes(action))]
+pub fn derive_godot_action_set(item: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(item as DeriveInput);
+    action_set::action_set(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// Registers a reflected type with every godot-bevy app, replacing a
+/// hand-maintained list of `app.register_type::<T>()` calls.
+///
+/// ```ignore
+/// #[derive(Component, Reflect, AutoRegister)]
+/// #[reflect(Component)]
+/// struct Speed(f32);
+/// ```
+///
+/// The type must implement `Reflect` (and so `GetTypeRegistration`). Registration
+/// happens when `GodotSceneTreePlugin` is built, so the inspector and anything
+/// else reading the `AppTypeRegistry` see the type from the first frame. Generic
+/// types can't be registered this way; register each instantiation by hand.
+#[proc_macro_derive(AutoRegister)]
+pub fn derive_auto_register(item: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(item as DeriveInput);
+    auto_register::auto_register(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
 /// Derive this macro on a struct for easy access to a scene's nodes.
 ///
 /// Example:
@@ -105,8 +196,11 @@ fn parse_bevy_app_config(attr: TokenStream) -> Result<BevyAppConfig, Error> {
 /// See `godot_bevy::node_tree_view::find_node_by_pattern` for details on how nodes are found.
 ///
 /// Supported field types are:
-/// - `GodotNodeHandle`: `from_node()` returns `NodeTreeViewError` if the node is not found.
-/// - `Option<GodotNodeHandle>`: Filled with `None` if the node is not found.
+/// - `GodotNodeHandle`: `from_node()` returns `NodeTreeViewError::NodeNotFound` if the node is not found.
+/// - `Gd<T>` for any node class `T`: like `GodotNodeHandle`, but `from_node()` returns
+///   `NodeTreeViewError::TypeMismatch` if the node is not a `T`.
+/// - `Option<_>` of either: Filled with `None` if the node is not found.
+/// - `Vec<_>` of either: Every node matching a wildcard path such as `"Enemies/*"`, in tree order.
 ///
 /// For each field annotated with `#[node(<path>)]`, a companion string constant is generated
 /// containing that path. The constant name is `<UPPERCASE_FIELD_NAME>_PATH`, and it is defined
@@ -120,12 +214,20 @@ fn parse_bevy_app_config(attr: TokenStream) -> Result<BevyAppConfig, Error> {
 ///     animated_sprite: GodotNodeHandle,
 ///
 ///     #[node("Node2D/*/VisibleOnScreenNotifier2D")]
-///     visibility_notifier: GodotNodeHandle,
+///     visibility_notifier: Gd<VisibleOnScreenNotifier2D>,
+///
+///     #[node("Trail")]
+///     trail: Option<GodotNodeHandle>,
+///
+///     #[node("Hitboxes/*")]
+///     hitboxes: Vec<Gd<Area2D>>,
 /// }
 /// /// Generated companion string constants:
 /// impl MobNodes {
 ///     pub const ANIMATED_SPRITE_PATH: &'static str = "AnimatedSprite2D";
 ///     pub const VISIBILITY_NOTIFIER_PATH: &'static str = "Node2D/*/VisibleOnScreenNotifier2D";
+///     pub const TRAIL_PATH: &'static str = "Trail";
+///     pub const HITBOXES_PATH: &'static str = "Hitboxes/*";
 /// }
 /// ```
 #[proc_macro_derive(NodeTreeView, attributes(node))]
@@ -194,7 +296,7 @@ pub fn derive_node_tree_view(item: TokenStream) -> TokenStream {
 /// | `require(Marker)` | Insert `Marker::default()` — a pure marker component. |
 /// | `require(Comp { bevy_field: godot_field, … })` | Build `Comp` from existing Godot exports, mapping each Bevy field name to a Godot property name. |
 ///
-/// `base`, `class_name`, and generated-export forms (`require(prop: Comp, …)`) are
+/// `base`, `class_name`, `icon`, and generated-export forms (`require(prop: Comp, …)`) are
 /// **not** valid here — those are component-first (`GodotNode`) only.
 #[proc_macro_derive(BevyComponents, attributes(gdbevy))]
 pub fn derive_bevy_components_entry(item: TokenStream) -> TokenStream {
@@ -223,12 +325,17 @@ pub fn derive_bevy_components_entry(item: TokenStream) -> TokenStream {
 /// struct Player;
 /// ```
 ///
-/// ### `base` and `class_name`
+/// ### `base`, `class_name` and `icon`
 ///
 /// | Key | Default | Meaning |
 /// |-----|---------|---------|
 /// | `base = GodotBase` | `Node` | Godot class to extend (`CharacterBody2D`, `Area2D`, …). |
 /// | `class_name = Name` | `<Struct>BevyComponent` | Name of the generated `GodotClass` struct. Must differ from the component name. |
+/// | `icon = "res://…"` | none | Editor icon for the class, written to the `.gdextension` `[icons]` section (see below). |
+///
+/// Icons take effect once listed in the `.gdextension` file, which Godot reads at startup.
+/// The godot-bevy editor addon regenerates the section after each build; elsewhere, write
+/// the output of `godot_bevy::plugins::scene_tree::gdextension_with_icons` to the file.
 ///
 /// ### `require(...)`
 ///
@@ -120,92 +120,49 @@ fn create_get_node_expr(field: &Field) -> syn::Result<TokenStream2> {
 
     let field_ty = &field.ty;
     let span = field_ty.span();
+    let path = node_path.value();
 
-    // Check if the type is GodotNodeHandle or Option<GodotNodeHandle>
-    let (is_optional, _inner_type) = match get_option_inner_type(field_ty) {
-        Some(inner) => (true, inner),
-        None => (false, field_ty),
-    };
-
-    let path_value = node_path.value();
-
-    // Check if the path contains wildcards for pattern matching
-    if path_value.contains('*') {
-        create_pattern_matching_expr(&path_value, is_optional, span)
-    } else {
-        // Use existing direct path logic for non-pattern paths
-        create_direct_path_expr(&node_path, is_optional, span)
-    }
-}
-
-fn create_direct_path_expr(
-    node_path: &syn::LitStr,
-    is_optional: bool,
-    span: proc_macro2::Span,
-) -> syn::Result<TokenStream2> {
-    let expr = if is_optional {
+    let expr = if let Some(inner) = get_generic_inner_type(field_ty, "Option") {
         quote_spanned! { span =>
-            {
-                let base_node = &node;
-                base_node.has_node(#node_path)
-                    .then(|| {
-                        let node_ref = base_node.try_get_node_as::<godot::classes::Node>(#node_path)
-                            .ok_or_else(|| godot_bevy::node_tree_view::NodeTreeViewError::NodeNotFound(
-                                #node_path.to_string()
-                            ))?;
-                        godot_bevy::interop::GodotNodeHandle::from_instance_id(node_ref.instance_id())
-                    })
-            }
+            godot_bevy::node_tree_view::find_node(&node, #path)
+                .map(|found| {
+                    <#inner as godot_bevy::node_tree_view::NodeTreeViewField>::from_view_node(found, #path)
+                })
+                .transpose()?
         }
-    } else {
-        quote_spanned! { span =>
-            {
-                let base_node = &node;
-                let node_ref = base_node.try_get_node_as::<godot::classes::Node>(#node_path)
-                    .ok_or_else(|| godot_bevy::node_tree_view::NodeTreeViewError::NodeNotFound(
-                        #node_path.to_string()
-                    ))?;
-                godot_bevy::interop::GodotNodeHandle::from_instance_id(node_ref.instance_id())
-            }
+    } else if let Some(inner) = get_generic_inner_type(field_ty, "Vec") {
+        if !path.contains('*') {
+            return Err(Error::new_spanned(
+                node_path,
+                "NodeTreeView: Vec fields need a wildcard path, e.g. \"Enemies/*\"",
+            ));
         }
-    };
-    Ok(expr)
-}
-
-fn create_pattern_matching_expr(
-    path_pattern: &str,
-    is_optional: bool,
-    span: proc_macro2::Span,
-) -> syn::Result<TokenStream2> {
-    let expr = if is_optional {
         quote_spanned! { span =>
-            {
-                let base_node = &node;
-                godot_bevy::node_tree_view::find_node_by_pattern(base_node, #path_pattern)
-                    .map(|node_ref| godot_bevy::interop::GodotNodeHandle::from_instance_id(node_ref.instance_id()))
-            }
+            godot_bevy::node_tree_view::find_nodes_by_pattern(&node, #path)
+                .into_iter()
+                .map(|found| {
+                    <#inner as godot_bevy::node_tree_view::NodeTreeViewField>::from_view_node(found, #path)
+                })
+                .collect::<std::result::Result<std::vec::Vec<_>, _>>()?
         }
     } else {
         quote_spanned! { span =>
-            {
-                let base_node = &node;
-                let pattern = #path_pattern;
-                let node_ref = godot_bevy::node_tree_view::find_node_by_pattern(base_node, pattern)
-                    .ok_or_else(|| godot_bevy::node_tree_view::NodeTreeViewError::NodeNotFound(
-                        pattern.to_string()
-                    ))?;
-                godot_bevy::interop::GodotNodeHandle::from_instance_id(node_ref.instance_id())
-            }
+            <#field_ty as godot_bevy::node_tree_view::NodeTreeViewField>::from_view_node(
+                godot_bevy::node_tree_view::find_node(&node, #path).ok_or_else(|| {
+                    godot_bevy::node_tree_view::NodeTreeViewError::NodeNotFound(#path.to_string())
+                })?,
+                #path,
+            )?
         }
     };
     Ok(expr)
 }
 
-// Helper function to extract the inner type of an Option<T>
-fn get_option_inner_type(ty: &syn::Type) -> Option<&syn::Type> {
+// Helper function to extract the inner type of e.g. Option<T> or Vec<T>
+fn get_generic_inner_type<'a>(ty: &'a syn::Type, wrapper: &str) -> Option<&'a syn::Type> {
     if let syn::Type::Path(type_path) = ty
         && type_path.path.segments.len() == 1
-        && type_path.path.segments[0].ident == "Option"
+        && type_path.path.segments[0].ident == wrapper
         && let syn::PathArguments::AngleBracketed(ref args) = type_path.path.segments[0].arguments
         && args.args.len() == 1
         && let syn::GenericArgument::Type(ref inner_type) = args.args[0]
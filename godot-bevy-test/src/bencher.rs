@@ -5,20 +5,65 @@ use std::time::{Duration, Instant};
 
 const WARMUP_RUNS: usize = 5;
 const TEST_RUNS: usize = 21;
-const METRIC_COUNT: usize = 2;
+const METRIC_COUNT: usize = 5;
 
 thread_local! {
     static MEASURED_NS: Cell<Option<u128>> = const { Cell::new(None) };
 }
 
+/// How many times each benchmark runs. Read from `BENCHMARK_WARMUP` and
+/// `BENCHMARK_RUNS`, defaulting to 5 warm-up runs and 21 measured ones.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BenchConfig {
+    /// Untimed runs first, to fill caches and settle allocations.
+    pub warmup_runs: usize,
+    /// Timed runs; each is one sample.
+    pub test_runs: usize,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        Self {
+            warmup_runs: WARMUP_RUNS,
+            test_runs: TEST_RUNS,
+        }
+    }
+}
+
+impl BenchConfig {
+    pub fn from_env() -> Self {
+        let var = |name: &str| std::env::var(name).ok()?.trim().parse::<usize>().ok();
+        let default = Self::default();
+        Self {
+            warmup_runs: var("BENCHMARK_WARMUP").unwrap_or(default.warmup_runs),
+            test_runs: var("BENCHMARK_RUNS").unwrap_or(default.test_runs),
+        }
+    }
+}
+
 /// Result of running a benchmark
 pub struct BenchResult {
-    pub stats: [Duration; METRIC_COUNT],
+    /// Time per repetition of each timed run, in run order.
+    pub samples: Vec<Duration>,
+    pub min: Duration,
+    pub max: Duration,
+    pub mean: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+    /// Sample standard deviation.
+    pub stddev: Duration,
+}
+
+impl BenchResult {
+    /// The reported statistics, in [`metrics`] order.
+    pub fn stats(&self) -> [Duration; METRIC_COUNT] {
+        [self.min, self.median, self.mean, self.p95, self.stddev]
+    }
 }
 
 /// Get the metric names for benchmark output
 pub fn metrics() -> [&'static str; METRIC_COUNT] {
-    ["min", "median"]
+    ["min", "median", "mean", "p95", "stddev"]
 }
 
 /// Scope timing to the hot section of a benchmark.
@@ -36,14 +81,16 @@ pub fn measured<R>(f: impl FnOnce() -> R) -> R {
 }
 
 /// Run a benchmark function with warmup and multiple iterations
-pub fn run_benchmark(code: fn(), inner_repetitions: usize) -> BenchResult {
-    for _ in 0..WARMUP_RUNS {
+pub fn run_benchmark(code: fn(), inner_repetitions: usize, config: BenchConfig) -> BenchResult {
+    for _ in 0..config.warmup_runs {
         MEASURED_NS.with(|m| m.set(None));
         code();
     }
 
-    let mut times = Vec::with_capacity(TEST_RUNS);
-    for _ in 0..TEST_RUNS {
+    // At least one sample, or there is nothing to report.
+    let test_runs = config.test_runs.max(1);
+    let mut samples = Vec::with_capacity(test_runs);
+    for _ in 0..test_runs {
         MEASURED_NS.with(|m| m.set(None));
         let start = Instant::now();
         code();
@@ -55,18 +102,40 @@ pub fn run_benchmark(code: fn(), inner_repetitions: usize) -> BenchResult {
             None => wall,
         };
 
-        times.push(duration / inner_repetitions as u32);
+        samples.push(duration / inner_repetitions.max(1) as u32);
     }
-    times.sort();
 
-    calculate_stats(times)
+    calculate_stats(samples)
 }
 
-fn calculate_stats(times: Vec<Duration>) -> BenchResult {
-    let min = times[0];
-    let median = times[TEST_RUNS / 2];
+fn calculate_stats(samples: Vec<Duration>) -> BenchResult {
+    let mut sorted = samples.clone();
+    sorted.sort();
+    let n = sorted.len();
+
+    let median = if n % 2 == 1 {
+        sorted[n / 2]
+    } else {
+        (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+    };
+    // Nearest rank: the smallest sample at or above 95% of them.
+    let p95 = sorted[(n * 95).div_ceil(100).max(1) - 1];
+
+    let nanos: Vec<f64> = sorted.iter().map(|d| d.as_nanos() as f64).collect();
+    let mean = nanos.iter().sum::<f64>() / n as f64;
+    let variance = if n > 1 {
+        nanos.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1) as f64
+    } else {
+        0.0
+    };
 
     BenchResult {
-        stats: [min, median],
+        min: sorted[0],
+        max: sorted[n - 1],
+        mean: Duration::from_nanos(mean.round() as u64),
+        median,
+        p95,
+        stddev: Duration::from_nanos(variance.sqrt().round() as u64),
+        samples,
     }
 }
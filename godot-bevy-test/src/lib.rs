@@ -57,6 +57,7 @@
 pub mod bencher;
 pub mod exit_code;
 pub mod runner;
+pub mod snapshot;
 pub mod test_app;
 pub mod test_helpers;
 
@@ -73,11 +74,12 @@ pub use runner::__GODOT_ITEST;
 pub use runner::await_bevy_frame;
 pub use runner::{AsyncRustTestCase, RustBenchmark, RustTestCase, TestRunnerImpl};
 pub use runner::{await_frame, await_frames, await_physics_frame};
-pub use test_app::TestApp;
+pub use snapshot::{Snapshot, assert_golden};
+pub use test_app::{SceneFixture, TestApp};
 pub use test_helpers::Counter;
 
 // Re-export bencher types
-pub use bencher::{BenchResult, measured, metrics, run_benchmark};
+pub use bencher::{BenchConfig, BenchResult, measured, metrics, run_benchmark};
 
 // Re-export the macros
 pub use godot_bevy_test_macros::{bench, itest};
@@ -90,7 +92,8 @@ pub struct TestContext {
 
 /// Prelude for convenient imports
 pub mod prelude {
-    pub use crate::test_app::TestApp;
+    pub use crate::snapshot::{Snapshot, assert_golden};
+    pub use crate::test_app::{SceneFixture, TestApp};
     pub use crate::test_helpers::Counter;
     pub use crate::{TestContext, await_frame, await_frames, bench, itest};
 }
@@ -133,6 +133,12 @@ impl TestRunnerImpl {
             println!("  Filter: {FMT_CYAN}{filter}{FMT_END}");
         }
 
+        let config = bencher::BenchConfig::from_env();
+        println!(
+            "  Runs: {} warm-up, {} timed.",
+            config.warmup_runs, config.test_runs
+        );
+
         // Print header
         print!("\n{FMT_CYAN}");
         print!("{:60}", "");
@@ -142,7 +148,7 @@ impl TestRunnerImpl {
         println!("{FMT_END}");
 
         let clock = Instant::now();
-        self.run_rust_benchmarks(benchmarks);
+        self.run_rust_benchmarks(benchmarks, config);
         let elapsed = clock.elapsed();
 
         println!("\nBenchmarks completed in {:.2}s.", elapsed.as_secs_f32());
@@ -222,9 +228,10 @@ impl TestRunnerImpl {
         run_next_test(0, tests, ctx, state, start_time);
     }
 
-    fn run_rust_benchmarks(&self, benchmarks: Vec<RustBenchmark>) {
+    fn run_rust_benchmarks(&self, benchmarks: Vec<RustBenchmark>, config: bencher::BenchConfig) {
         // Check if we should output JSON (for CI)
         let output_json = std::env::var("BENCHMARK_JSON").is_ok();
+        let json_path = std::env::var("BENCHMARK_JSON_PATH").ok();
 
         let mut results = Vec::new();
         let mut last_file = None;
@@ -246,23 +253,23 @@ impl TestRunnerImpl {
             }
 
             // Run the benchmark
-            let result = bencher::run_benchmark(bench.function, bench.repetitions);
-
-            // Store result for JSON output
-            results.push((bench.name, result.stats[0], result.stats[1])); // min, median
+            let result = bencher::run_benchmark(bench.function, bench.repetitions, config);
 
             // Print results (human-readable mode)
             if !output_json {
-                for stat in result.stats {
+                for stat in result.stats() {
                     print!(" {stat:>12.2?}");
                 }
                 println!();
             }
+
+            // Store result for JSON output
+            results.push((bench.name, result));
         }
 
         // Output JSON if requested
-        if output_json {
-            output_json_results(results);
+        if output_json || json_path.is_some() {
+            output_json_results(results, config, json_path.as_deref(), output_json);
         }
     }
 }
@@ -445,24 +452,38 @@ fn finish_test_run(
     ctx.scene_tree.get_tree().quit();
 }
 
-fn output_json_results(results: Vec<(&str, std::time::Duration, std::time::Duration)>) {
-    use std::collections::HashMap;
-
-    let mut benchmarks = HashMap::new();
-
-    for (name, min, median) in results {
-        let mut entry = HashMap::new();
-        entry.insert("min_ns", min.as_nanos().to_string());
-        entry.insert("median_ns", median.as_nanos().to_string());
-        entry.insert("min_display", format!("{min:.2?}"));
-        entry.insert("median_display", format!("{median:.2?}"));
-
-        benchmarks.insert(name.to_string(), entry);
-    }
+fn output_json_results(
+    results: Vec<(&str, bencher::BenchResult)>,
+    config: bencher::BenchConfig,
+    path: Option<&str>,
+    to_stdout: bool,
+) {
+    let nanos = |d: std::time::Duration| d.as_nanos() as u64;
+    let benchmarks: serde_json::Map<String, serde_json::Value> = results
+        .into_iter()
+        .map(|(name, result)| {
+            let entry = serde_json::json!({
+                "min_ns": nanos(result.min),
+                "max_ns": nanos(result.max),
+                "mean_ns": nanos(result.mean),
+                "median_ns": nanos(result.median),
+                "p95_ns": nanos(result.p95),
+                "stddev_ns": nanos(result.stddev),
+                "min_display": format!("{:.2?}", result.min),
+                "median_display": format!("{:.2?}", result.median),
+                "samples_ns": result.samples.iter().map(|&d| nanos(d)).collect::<Vec<_>>(),
+            });
+            (name.to_string(), entry)
+        })
+        .collect();
 
     let output = serde_json::json!({
         "benchmarks": benchmarks,
         "timestamp": chrono::Utc::now().to_rfc3339(),
+        "config": {
+            "warmup_runs": config.warmup_runs,
+            "test_runs": config.test_runs,
+        },
         "environment": {
             "rust_debug": cfg!(debug_assertions),
             "godot_debug": godot::classes::Os::singleton().is_debug_build(),
@@ -470,19 +491,21 @@ fn output_json_results(results: Vec<(&str, std::time::Duration, std::time::Durat
     });
 
     // Write to file
-    if let Ok(path) = std::env::var("BENCHMARK_JSON_PATH")
+    if let Some(path) = path
         && let Ok(file) = std::fs::File::create(path)
     {
         let _ = serde_json::to_writer_pretty(file, &output);
     }
 
     // Also output to stdout with special markers for parsing
-    println!("===BENCHMARK_JSON_START===");
-    println!(
-        "{}",
-        serde_json::to_string_pretty(&output).unwrap_or_default()
-    );
-    println!("===BENCHMARK_JSON_END===");
+    if to_stdout {
+        println!("===BENCHMARK_JSON_START===");
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&output).unwrap_or_default()
+        );
+        println!("===BENCHMARK_JSON_END===");
+    }
 }
 
 // ANSI color codes for terminal output
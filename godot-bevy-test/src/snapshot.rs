@@ -0,0 +1,139 @@
+//! Golden-file snapshots of selected components
+//!
+//! A [`Snapshot`] lists the components to capture. [`TestApp::snapshot`] renders
+//! them as text, one line per entity, keyed by the entity's `Name` and sorted so
+//! the output doesn't depend on spawn order. [`assert_golden`] compares that text
+//! against a file checked into the repository:
+//!
+//! ```ignore
+//! let snapshot = Snapshot::new().component::<Health>().component::<Team>();
+//! app.assert_snapshot(
+//!     &snapshot,
+//!     concat!(env!("CARGO_MANIFEST_DIR"), "/golden/spawn_wave.txt"),
+//! );
+//! ```
+//!
+//! A missing golden file is written instead of compared. Run with
+//! `GODOT_BEVY_UPDATE_GOLDEN=1` to rewrite all of them after an intended change.
+//! Components are rendered with `Debug`; entities without a `Name` are keyed by
+//! their id, which is only stable if the test spawns them in a fixed order.
+//!
+//! [`TestApp::snapshot`]: crate::TestApp::snapshot
+
+use std::fmt::{Debug, Write as _};
+use std::path::Path;
+
+use bevy::prelude::*;
+
+/// Environment variable that makes [`assert_golden`] rewrite golden files.
+pub const UPDATE_GOLDEN_ENV: &str = "GODOT_BEVY_UPDATE_GOLDEN";
+
+/// Renders one component of every entity that has it, as sorted rows.
+type RenderRows = fn(&mut World) -> Vec<String>;
+
+/// The components a snapshot captures, in the order they are rendered.
+#[derive(Default)]
+pub struct Snapshot {
+    sections: Vec<(String, RenderRows)>,
+}
+
+impl Snapshot {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Capture every entity's `C`.
+    pub fn component<C: Component + Debug>(mut self) -> Self {
+        self.sections.push((
+            short_type_name(std::any::type_name::<C>()),
+            render_rows::<C>,
+        ));
+        self
+    }
+
+    pub(crate) fn render(&self, world: &mut World) -> String {
+        let mut text = String::new();
+        for (index, (name, rows)) in self.sections.iter().enumerate() {
+            if index > 0 {
+                text.push('\n');
+            }
+            let _ = writeln!(text, "[{name}]");
+            for row in rows(world) {
+                let _ = writeln!(text, "{row}");
+            }
+        }
+        text
+    }
+}
+
+fn render_rows<C: Component + Debug>(world: &mut World) -> Vec<String> {
+    let mut query = world.query::<(Entity, Option<&Name>, &C)>();
+    let mut rows: Vec<String> = query
+        .iter(world)
+        .map(|(entity, name, value)| match name {
+            Some(name) => format!("{name}: {value:?}"),
+            None => format!("{entity}: {value:?}"),
+        })
+        .collect();
+    rows.sort();
+    rows
+}
+
+/// `my_game::combat::Health<my_game::Team>` -> `Health<Team>`, so moving a type
+/// between modules doesn't invalidate golden files.
+fn short_type_name(full: &str) -> String {
+    let mut short = String::new();
+    let mut segment = String::new();
+    for ch in full.chars() {
+        if ch.is_alphanumeric() || ch == '_' || ch == ':' {
+            segment.push(ch);
+        } else {
+            short.push_str(segment.rsplit("::").next().unwrap_or_default());
+            segment.clear();
+            short.push(ch);
+        }
+    }
+    short.push_str(segment.rsplit("::").next().unwrap_or_default());
+    short
+}
+
+/// Compare `actual` with the golden file at `path`, panicking with the first
+/// differing line. Writes the file instead when it doesn't exist yet or
+/// [`UPDATE_GOLDEN_ENV`] is set.
+#[track_caller]
+pub fn assert_golden(path: impl AsRef<Path>, actual: &str) {
+    let path = path.as_ref();
+    let update = std::env::var_os(UPDATE_GOLDEN_ENV).is_some_and(|value| value != "0");
+    if update || !path.exists() {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .unwrap_or_else(|err| panic!("creating {}: {err}", parent.display()));
+        }
+        std::fs::write(path, actual)
+            .unwrap_or_else(|err| panic!("writing golden file {}: {err}", path.display()));
+        godot::global::godot_print!("Wrote golden file {}", path.display());
+        return;
+    }
+
+    let expected = std::fs::read_to_string(path)
+        .unwrap_or_else(|err| panic!("reading golden file {}: {err}", path.display()));
+    if expected == actual {
+        return;
+    }
+    let (line, expected_line, actual_line) = expected
+        .lines()
+        .map(Some)
+        .chain(std::iter::repeat(None))
+        .zip(actual.lines().map(Some).chain(std::iter::repeat(None)))
+        .enumerate()
+        .find(|(_, (expected, actual))| expected != actual)
+        .map(|(line, (expected, actual))| (line + 1, expected, actual))
+        .unwrap_or((0, None, None));
+    panic!(
+        "snapshot differs from {} at line {line}\n  expected: {}\n  actual:   {}\n\
+         Rerun with {UPDATE_GOLDEN_ENV}=1 to accept the new output.\n--- actual ---\n{actual}",
+        path.display(),
+        expected_line.unwrap_or("<end of file>"),
+        actual_line.unwrap_or("<end of output>"),
+    );
+}
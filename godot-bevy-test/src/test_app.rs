@@ -16,9 +16,13 @@
 //! and the initial population of entities is complete. Tests should NOT need any
 //! manual `await_frames()` calls.
 
+use std::fmt::Debug;
+
+use bevy::ecs::query::{QueryData, ROQueryItem};
 use bevy::prelude::*;
 use godot::obj::{Gd, NewAlloc};
 
+use crate::snapshot::{Snapshot, assert_golden};
 use crate::{TestContext, await_frame};
 
 /// A test app that provides Bevy-style API while running in Godot runtime
@@ -46,6 +50,28 @@ use crate::{TestContext, await_frame};
 pub struct TestApp {
     ctx: TestContext,
     bevy_app: Option<Gd<godot_bevy::BevyApp>>,
+    /// Holders of the scenes added by `with_scene`, freed on cleanup.
+    fixtures: Vec<Gd<godot::classes::Node>>,
+}
+
+/// A PackedScene instantiated for one test by [`TestApp::with_scene`].
+pub struct SceneFixture {
+    /// The instantiated scene's root node.
+    pub root: Gd<godot::classes::Node>,
+    /// The root node's entity.
+    pub entity: Entity,
+    /// Parent of `root`, one per fixture, so fixtures' node paths never clash.
+    holder: Gd<godot::classes::Node>,
+}
+
+impl SceneFixture {
+    /// A node of the scene, by path relative to its root.
+    pub fn node<T>(&self, path: &str) -> Gd<T>
+    where
+        T: godot::obj::Inherits<godot::classes::Node>,
+    {
+        self.root.get_node_as::<T>(path)
+    }
 }
 
 /// Wait for one frame boundary. With `test-frame-signal` this resolves on
@@ -112,6 +138,7 @@ impl TestApp {
         Self {
             ctx: ctx.clone(),
             bevy_app: Some(bevy_app),
+            fixtures: Vec::new(),
         }
     }
 
@@ -148,8 +175,10 @@ impl TestApp {
     where
         F: FnOnce(&World) -> R,
     {
-        let binding = self.bevy_app.as_ref().unwrap().bind();
-        let app = binding.get_app().expect("App should be initialized");
+        // get_app_mut, so an app with BetweenFramesPlugin gets its world back first.
+        let mut bevy_app = self.bevy_app.clone().unwrap();
+        let mut binding = bevy_app.bind_mut();
+        let app = binding.get_app_mut().expect("App should be initialized");
         f(app.world())
     }
 
@@ -188,6 +217,97 @@ impl TestApp {
         })
     }
 
+    /// Assert that `entity`'s `C` passes `check`, panicking with the value if it
+    /// doesn't or if the entity has no `C`.
+    ///
+    /// ```ignore
+    /// app.assert_component::<Transform>(entity, |t| t.translation.x > 0.0);
+    /// ```
+    #[track_caller]
+    pub fn assert_component<C>(&self, entity: Entity, check: impl FnOnce(&C) -> bool)
+    where
+        C: Component + Debug,
+    {
+        let failure = self.with_world(|world| match world.get::<C>(entity) {
+            Some(value) => (!check(value)).then(|| format!("{value:?} failed the check")),
+            None => Some("missing".to_string()),
+        });
+        if let Some(failure) = failure {
+            panic!("{} on {entity}: {failure}", std::any::type_name::<C>());
+        }
+    }
+
+    /// Run `f` on the single entity matching `D`, panicking if there are none or
+    /// several.
+    ///
+    /// ```ignore
+    /// let (x, health) = app.query_single::<(&Transform, &Health), _>(|(t, h)| (t.translation.x, h.0));
+    /// ```
+    #[track_caller]
+    pub fn query_single<D, R>(&mut self, f: impl FnOnce(ROQueryItem<'_, '_, D>) -> R) -> R
+    where
+        D: QueryData,
+    {
+        self.with_world_mut(|world| {
+            let mut query = world.query::<D>();
+            match query.single(world) {
+                Ok(item) => f(item),
+                Err(err) => panic!("query_single::<{}>: {err}", std::any::type_name::<D>()),
+            }
+        })
+    }
+
+    /// Run `f` on every entity matching `D`, collecting the results.
+    pub fn query_collect<D, R>(&mut self, f: impl FnMut(ROQueryItem<'_, '_, D>) -> R) -> Vec<R>
+    where
+        D: QueryData,
+    {
+        self.with_world_mut(|world| world.query::<D>().iter(world).map(f).collect())
+    }
+
+    /// Advance frames until `condition` holds, returning how many frames that
+    /// took. Panics after `timeout_frames` frames without it holding. The
+    /// condition is checked before the first frame too, so an already-true
+    /// condition returns 0 without waiting.
+    ///
+    /// ```ignore
+    /// app.wait_until(|world| world.resource::<Score>().0 >= 3, 120).await;
+    /// ```
+    #[track_caller]
+    pub fn wait_until<'a>(
+        &'a self,
+        mut condition: impl FnMut(&World) -> bool + 'a,
+        timeout_frames: u32,
+    ) -> impl Future<Output = u32> + 'a {
+        // Not an `async fn`, so `#[track_caller]` reports the test's line.
+        let caller = std::panic::Location::caller();
+        async move {
+            for frame in 0..=timeout_frames {
+                if self.with_world(&mut condition) {
+                    return frame;
+                }
+                if frame < timeout_frames {
+                    self.update().await;
+                }
+            }
+            panic!("wait_until at {caller}: condition still false after {timeout_frames} frames");
+        }
+    }
+
+    /// Render the components `spec` selects, for comparison with a golden file.
+    /// See [`snapshot`](crate::snapshot).
+    pub fn snapshot(&mut self, spec: &Snapshot) -> String {
+        self.with_world_mut(|world| spec.render(world))
+    }
+
+    /// Compare [`snapshot`](Self::snapshot) with the golden file at `path`. See
+    /// [`assert_golden`].
+    #[track_caller]
+    pub fn assert_snapshot(&mut self, spec: &Snapshot, path: impl AsRef<std::path::Path>) {
+        let actual = self.snapshot(spec);
+        assert_golden(path, &actual);
+    }
+
     /// Look up the Bevy entity for a Godot node by instance ID
     pub fn entity_for_node(&self, instance_id: godot::obj::InstanceId) -> Option<Entity> {
         self.with_world(|world| {
@@ -272,6 +392,84 @@ impl TestApp {
         panic!("Entity should exist for prebuilt node '{name}' after 3 frames");
     }
 
+    /// Instantiate the PackedScene at `path` for this test and return it once
+    /// its root has an entity.
+    ///
+    /// The scene goes under its own holder node, so two fixtures (or two copies
+    /// of one) don't collide. It's freed by [`free_scene`](Self::free_scene), or
+    /// else by [`cleanup`](Self::cleanup) or drop, so it never leaks into the
+    /// next test.
+    ///
+    /// ```ignore
+    /// let room = app.with_scene("res://fixtures/room.tscn").await;
+    /// let door = app.entity_for_node(room.node::<Node2D>("Door").instance_id());
+    /// ```
+    pub async fn with_scene(&mut self, path: &str) -> SceneFixture {
+        use godot::classes::{Node, PackedScene, ResourceLoader};
+        use godot::obj::Singleton;
+
+        let scene = ResourceLoader::singleton()
+            .load(path)
+            .and_then(|resource| resource.try_cast::<PackedScene>().ok())
+            .unwrap_or_else(|| panic!("with_scene: {path} is not a PackedScene"));
+        let root = scene
+            .instantiate()
+            .unwrap_or_else(|| panic!("with_scene: failed to instantiate {path}"));
+
+        let mut holder = Node::new_alloc();
+        holder.set_name(format!("SceneFixture{}", self.fixtures.len()).as_str());
+        holder.add_child(&root);
+        self.ctx.scene_tree.clone().add_child(&holder);
+        self.fixtures.push(holder.clone());
+
+        for _ in 0..3 {
+            self.update().await;
+            if let Some(entity) = self.entity_for_node(root.instance_id()) {
+                return SceneFixture {
+                    root,
+                    entity,
+                    holder,
+                };
+            }
+        }
+        panic!("Entity should exist for the root of {path} after 3 frames");
+    }
+
+    /// Free a scene added by [`with_scene`](Self::with_scene) before the test
+    /// ends, and wait until its entities are despawned.
+    pub async fn free_scene(&mut self, fixture: SceneFixture) {
+        let SceneFixture {
+            root,
+            entity,
+            holder,
+        } = fixture;
+        self.fixtures.retain(|fixture| *fixture != holder);
+        let instance_id = root.instance_id();
+        holder.free();
+
+        for _ in 0..3 {
+            self.update().await;
+            let despawned = self.with_world(|world| world.get_entity(entity).is_err());
+            if despawned && !self.has_entity_for_node(instance_id) {
+                return;
+            }
+        }
+        panic!("Entity {entity} of a freed scene should be despawned after 3 frames");
+    }
+
+    fn free_fixtures(&mut self) {
+        for holder in self.fixtures.drain(..) {
+            if holder.is_instance_valid() {
+                holder.free();
+            }
+        }
+    }
+
+    /// The `BevyApp` node, for calling its `#[func]`s the way GDScript would.
+    pub fn app_node(&self) -> Gd<godot_bevy::BevyApp> {
+        self.bevy_app.clone().expect("TestApp was cleaned up")
+    }
+
     /// Get the test context
     pub fn ctx(&self) -> &TestContext {
         &self.ctx
@@ -285,6 +483,8 @@ impl TestApp {
     pub async fn cleanup(&mut self) {
         if let Some(mut app) = self.bevy_app.take() {
             app.bind_mut().teardown();
+            // With the app gone, nothing can touch the fixtures' nodes anymore.
+            self.free_fixtures();
             // process() still runs and emits the signal even with app=None, so this
             // settles the frame boundary regardless of feature path.
             #[cfg(feature = "test-frame-signal")]
@@ -300,6 +500,7 @@ impl Drop for TestApp {
         if let Some(mut app) = self.bevy_app.take() {
             app.bind_mut().teardown();
         }
+        self.free_fixtures();
         // Guarantee scene-tree isolation between tests. A test that panics before
         // its own free (failed assert), or simply forgets one, would otherwise leak
         // its nodes into the next test's scene scan and create spurious entities --
@@ -1,6 +1,7 @@
 use crate::plugins::{
     collisions::CollisionMessageReader, input::InputEventReader, scene_tree::SceneTreeMessageReader,
 };
+use crate::settings::GodotBevySettings;
 use crate::watchers::collision_watcher::CollisionWatcher;
 use crate::watchers::input_watcher::GodotInputWatcher;
 use crate::watchers::scene_tree_watcher::SceneTreeWatcher;
@@ -29,6 +30,33 @@ impl Default for BevyAppConfig {
     }
 }
 
+/// A named app builder, constructed only by `#[bevy_app(name = "...")]` via
+/// `inventory::submit!` — `pub` for the macro path, not stable public API.
+#[doc(hidden)]
+pub struct NamedBevyApp {
+    pub name: &'static str,
+    pub config: BevyAppConfig,
+    pub init_fn: fn(&mut App),
+}
+
+crate::inventory::collect!(NamedBevyApp);
+
+/// The app registered as `#[bevy_app(name = "...")]` under `name`, or an error
+/// listing the names that are registered.
+fn named_app(name: &str) -> Result<&'static NamedBevyApp, String> {
+    crate::inventory::iter::<NamedBevyApp>()
+        .find(|app| app.name == name)
+        .ok_or_else(|| {
+            let registered: Vec<_> = crate::inventory::iter::<NamedBevyApp>()
+                .map(|app| app.name)
+                .collect();
+            format!(
+                "no app is named \"{name}\" (registered: {registered:?}). \
+                 Name one with #[bevy_app(name = \"{name}\")]."
+            )
+        })
+}
+
 /// Register a Bevy app builder with default configuration. See [`init_with_config`].
 pub fn init(init_fn: impl Fn(&mut App) + Send + Sync + 'static) {
     init_with_config(BevyAppConfig::default(), init_fn);
@@ -39,7 +67,10 @@ pub fn init(init_fn: impl Fn(&mut App) + Send + Sync + 'static) {
 /// Call this from your own `ExtensionLibrary::on_stage_init` during
 /// `InitStage::Core` when you can't use `#[bevy_app]` -- e.g. an existing gdext
 /// project that already defines an `ExtensionLibrary`. `#[bevy_app]` is sugar over
-/// this. Pair it with [`deinit`] in `on_stage_deinit`.
+/// this. Pair it with [`deinit`] in `on_stage_deinit`, and register the GDScript
+/// `BevyMetrics` and `GodotBridge` singletons during `InitStage::Scene` with
+/// [`metrics::register_singleton`](crate::plugins::metrics::register_singleton) and
+/// [`resource_bridge::register_singleton`](crate::plugins::resource_bridge::register_singleton).
 pub fn init_with_config(config: BevyAppConfig, init_fn: impl Fn(&mut App) + Send + Sync + 'static) {
     let _ = BEVY_APP_CONFIG.set(config);
     let _ = BEVY_INIT_FUNC.get_or_init(|| Box::new(init_fn));
@@ -84,6 +115,14 @@ pub struct BevyApp {
     // If set, this takes precedence over the global BEVY_INIT_FUNC
     #[allow(clippy::type_complexity)]
     instance_init_func: Option<Box<dyn Fn(&mut App) + Send + Sync>>,
+    /// Run the `#[bevy_app(name = "...")]` app with this name instead of the
+    /// `#[bevy_app]` one. Each `BevyApp` node has its own world and schedules.
+    #[export]
+    app_name: GString,
+    /// Editor settings applied over the app's configuration once it is built.
+    /// See [`crate::settings`].
+    #[export]
+    settings: Option<Gd<GodotBevySettings>>,
     // True after the startup schedules have run (lifetime flag, set once).
     started: bool,
     // True from the first physics callback of a frame until the end of process().
@@ -92,12 +131,33 @@ pub struct BevyApp {
     // Physics steps run in the current render frame; reported via bevy_frame_complete.
     #[cfg(feature = "test-frame-signal")]
     physics_steps_this_frame: u32,
+    // Set by pause_bevy/step_frames: Bevy frames are skipped while Godot keeps running.
+    paused: bool,
+    // Frames still to run while paused, from step_frames.
+    frames_to_step: u32,
+    // Runs the BetweenFrames schedule off the main thread, while it holds the world.
+    between_frames: Option<crate::plugins::between_frames::BetweenFramesWorker>,
+    // The event bridge's registry and sender, kept here while the world is away.
+    #[allow(clippy::type_complexity)]
+    parked_events: Option<(
+        Option<crate::plugins::event_bridge::GodotEventRegistry>,
+        Option<crate::plugins::event_bridge::GodotEventSender>,
+    )>,
     /// Tracks the Godot RenderingServer draw time.
     render_server_span: Option<tracing::span::EnteredSpan>,
 }
 
 impl BevyApp {
+    /// `None` with `BetweenFramesPlugin` between frames, while `BetweenFrames`
+    /// has the world; `get_app_mut` waits for it instead.
     pub fn get_app(&self) -> Option<&App> {
+        if self
+            .between_frames
+            .as_ref()
+            .is_some_and(|worker| worker.is_running())
+        {
+            return None;
+        }
         self.app.as_ref()
     }
 
@@ -105,6 +165,7 @@ impl BevyApp {
     /// `app.update()` directly is valid for testing but must not be mixed
     /// with the production driver in the same frame.
     pub fn get_app_mut(&mut self) -> Option<&mut App> {
+        self.join_between_frames();
         self.app.as_mut()
     }
 
@@ -132,13 +193,11 @@ impl BevyApp {
         T: bevy_ecs::event::Event + Clone + Send + 'static,
         for<'a> T::Trigger<'a>: Default,
     {
-        use crate::plugins::event_bridge::GodotEventSender;
-
-        let Some(bevy_app) = self.get_app() else {
+        let Some((_, sender)) = self.event_resources() else {
             tracing::warn!("BevyApp::send_event called with no live App; event dropped");
             return;
         };
-        let Some(sender) = bevy_app.world().get_resource::<GodotEventSender>() else {
+        let Some(sender) = sender else {
             tracing::warn!("BevyApp::send_event: no event channel; event dropped");
             return;
         };
@@ -153,7 +212,10 @@ impl BevyApp {
 
     /// Tear down the Bevy app and remove all watchers.
     pub fn teardown(&mut self) {
+        self.join_between_frames();
         self.app = None;
+        self.paused = false;
+        self.frames_to_step = 0;
         for name in &[
             "SceneTreeWatcher",
             "OptimizedSceneTreeWatcher",
@@ -168,21 +230,55 @@ impl BevyApp {
     }
 
     /// Initialize the Bevy app on an already-in-tree node.
-    /// No-ops if neither `set_instance_init_func()` nor `#[bevy_app]` has been set.
+    /// No-ops if there is no app to build: no `set_instance_init_func()`, no
+    /// `#[bevy_app]`, or an `app_name` nothing registered.
     pub fn initialize(&mut self) {
-        let has_init = self.instance_init_func.is_some() || BEVY_INIT_FUNC.get().is_some();
-        if !has_init {
+        if !self.has_init() {
             return;
         }
         self.teardown();
         self.do_initialize();
     }
 
+    /// Whether this node has an app to build: its `set_instance_init_func()`, the
+    /// named app its `app_name` picks, or else the `#[bevy_app]` one. An
+    /// `app_name` nothing registered is reported.
+    fn has_init(&self) -> bool {
+        if self.instance_init_func.is_some() {
+            return true;
+        }
+        if self.app_name.is_empty() {
+            return BEVY_INIT_FUNC.get().is_some();
+        }
+        match named_app(&self.app_name.to_string()) {
+            Ok(_) => true,
+            Err(err) => {
+                godot::global::godot_error!(
+                    "[godot-bevy] BevyApp at {}: {err}",
+                    self.base().get_path()
+                );
+                false
+            }
+        }
+    }
+
     fn do_initialize(&mut self) {
+        // has_init already reported an unknown name; never fall back to the
+        // `#[bevy_app]` app in its place.
+        let named = if self.instance_init_func.is_none() && !self.app_name.is_empty() {
+            match named_app(&self.app_name.to_string()) {
+                Ok(named) => Some(named),
+                Err(_) => return,
+            }
+        } else {
+            None
+        };
+
         // Reset per-app state so that re-initialization (e.g. the itest harness
         // calling teardown -> do_initialize) runs startup fresh.
         self.started = false;
         self.prefix_done_this_frame = false;
+        crate::interop::thread_check::record_main_thread();
 
         // process_mode = ALWAYS keeps both callbacks firing under SceneTree.paused; pause is
         // enforced in the schedules (the FixedMain gate), not by freezing Godot's callbacks.
@@ -190,24 +286,48 @@ impl BevyApp {
             .set_process_mode(godot::classes::node::ProcessMode::ALWAYS);
 
         let mut app = App::new();
+        // The scene tree plugin finds this node's watchers through it.
+        app.insert_non_send(
+            crate::plugins::scene_tree::plugin::SceneTreeRefImpl::for_app(
+                self.base().instance_id(),
+            ),
+        );
 
-        let config = BEVY_APP_CONFIG.get().copied().unwrap_or(BevyAppConfig {
-            scene_tree_auto_despawn_children: true,
-        });
+        let config = named
+            .map(|named| named.config)
+            .or_else(|| BEVY_APP_CONFIG.get().copied())
+            .unwrap_or(BevyAppConfig {
+                scene_tree_auto_despawn_children: true,
+            });
 
         app.add_plugins(crate::plugins::core::GodotBaseCorePlugin)
             .add_plugins(crate::plugins::scene_tree::GodotSceneTreePlugin {
                 auto_despawn_children: config.scene_tree_auto_despawn_children,
             });
 
-        if let Some(ref instance_func) = self.instance_init_func {
-            instance_func(&mut app);
-        } else if let Some(app_builder_func) = BEVY_INIT_FUNC.get() {
-            app_builder_func(&mut app);
+        let built = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            if let Some(ref instance_func) = self.instance_init_func {
+                instance_func(&mut app);
+            } else if let Some(named) = named {
+                (named.init_fn)(&mut app);
+            } else if let Some(app_builder_func) = BEVY_INIT_FUNC.get() {
+                app_builder_func(&mut app);
+            }
+        }));
+        if let Err(payload) = built {
+            crate::watchdog::explain_build_panic(&*payload);
+            std::panic::resume_unwind(payload);
+        }
+
+        if let Some(settings) = &self.settings {
+            settings.bind().apply(&mut app);
         }
 
         #[cfg(debug_assertions)]
-        log_plugin_diagnostics(&app);
+        {
+            log_plugin_diagnostics(&app);
+            crate::watchdog::check_plugin_requirements(&mut app);
+        }
 
         use crate::plugins::scene_tree::SceneTreeMessage;
         if app
@@ -244,6 +364,11 @@ impl BevyApp {
             app.cleanup();
         }
 
+        // `input` is overridden for `GodotInputReactPlugin`, which makes Godot enable
+        // it; keep it off for every other app.
+        let react = crate::plugins::input::react::input_react_enabled(app.world());
+        self.base_mut().set_process_input(react);
+
         // godot-bevy drives Main directly (prefix + N fixed steps + suffix); a
         // secondary SubApp would never be extracted or updated. Fail loud at build
         // time rather than silently skip.
@@ -252,7 +377,135 @@ impl BevyApp {
             "godot-bevy drives Main itself; a secondary SubApp would never be updated"
         );
 
+        self.add_event_signals(&app);
         self.app = Some(app);
+        self.base_mut()
+            .call_deferred("emit_signal", &["bevy_ready".to_variant()]);
+    }
+
+    /// The event bridge's registry and sender, from the world or, while
+    /// `BetweenFrames` holds it, from here. `None` without a live app.
+    fn event_resources(
+        &self,
+    ) -> Option<(
+        Option<&crate::plugins::event_bridge::GodotEventRegistry>,
+        Option<&crate::plugins::event_bridge::GodotEventSender>,
+    )> {
+        let app = self.app.as_ref()?;
+        Some(match &self.parked_events {
+            Some((registry, sender)) => (registry.as_ref(), sender.as_ref()),
+            None => (app.world().get_resource(), app.world().get_resource()),
+        })
+    }
+
+    /// Hand the world to the `BetweenFrames` thread until the next join, if the
+    /// app has `BetweenFramesPlugin`. The event bridge's resources stay here so
+    /// `send_event` keeps working meanwhile.
+    fn start_between_frames(&mut self) {
+        use crate::plugins::between_frames::{BetweenFramesEnabled, BetweenFramesWorker};
+        use crate::plugins::event_bridge::{GodotEventRegistry, GodotEventSender};
+
+        let Some(app) = self.app.as_mut() else {
+            return;
+        };
+        if !app.world().contains_resource::<BetweenFramesEnabled>() {
+            return;
+        }
+        let mut world = std::mem::take(app.world_mut());
+        self.parked_events = Some((
+            world.remove_resource::<GodotEventRegistry>(),
+            world.get_resource::<GodotEventSender>().cloned(),
+        ));
+        self.between_frames
+            .get_or_insert_with(BetweenFramesWorker::spawn)
+            .start(world);
+    }
+
+    /// Wait for `BetweenFrames` and put the world back. Runs before anything
+    /// here touches the world.
+    fn join_between_frames(&mut self) {
+        let Some((mut world, panic)) = self
+            .between_frames
+            .as_mut()
+            .and_then(|worker| worker.join())
+        else {
+            return;
+        };
+        if let Some((Some(registry), _)) = self.parked_events.take() {
+            world.insert_resource(registry);
+        }
+        if let Some(app) = self.app.as_mut() {
+            *app.world_mut() = world;
+        }
+        if let Some(panic) = panic {
+            self.app = None;
+            godot::global::godot_error!(
+                "godot-bevy: Bevy app panicked during BetweenFrames and was permanently torn down; \
+                 it will not recover this session. See the panic above."
+            );
+            std::panic::resume_unwind(panic);
+        }
+    }
+
+    /// Emit the `bevy_state_changed` signals queued this frame. Deferred, so
+    /// GDScript handlers run once this node is no longer bound.
+    fn emit_state_signals(&mut self) {
+        use crate::plugins::app_signals::PendingStateSignals;
+
+        let Some(names) = self.app.as_mut().and_then(|app| {
+            app.world_mut()
+                .get_resource_mut::<PendingStateSignals>()
+                .map(|mut pending| std::mem::take(&mut pending.0))
+        }) else {
+            return;
+        };
+        for name in names {
+            self.base_mut().call_deferred(
+                "emit_signal",
+                &["bevy_state_changed".to_variant(), name.to_variant()],
+            );
+        }
+    }
+
+    /// Declare the signals exposed with `expose_event_as_signal`, so scripts can
+    /// connect before the first one is emitted.
+    fn add_event_signals(&mut self, app: &App) {
+        use crate::plugins::app_signals::EventSignals;
+
+        let Some(signals) = app.world().get_resource::<EventSignals>() else {
+            return;
+        };
+        let mut payload = VarDictionary::new();
+        payload.set("name", "payload");
+        payload.set("type", VariantType::DICTIONARY.ord());
+        for name in &signals.names {
+            if !self.base().has_signal(name.as_str()) {
+                self.base_mut()
+                    .add_user_signal_ex(name.as_str())
+                    .arguments(&varray![&payload])
+                    .done();
+            }
+        }
+    }
+
+    /// Emit the signals of the exposed events triggered this frame, with their
+    /// fields as a `Dictionary`. Deferred, like `emit_state_signals`.
+    fn emit_event_signals(&mut self) {
+        use crate::plugins::app_signals::EventSignals;
+        use crate::plugins::meta_sync::fields_to_dict;
+
+        let Some(events) = self.app.as_mut().and_then(|app| {
+            app.world_mut()
+                .get_resource_mut::<EventSignals>()
+                .map(|mut signals| std::mem::take(&mut signals.pending))
+        }) else {
+            return;
+        };
+        for (name, value) in events {
+            let payload = fields_to_dict(value.as_partial_reflect()).unwrap_or_default();
+            self.base_mut()
+                .call_deferred("emit_signal", &[name.to_variant(), payload.to_variant()]);
+        }
     }
 
     fn register_scene_tree_watcher(&mut self, app: &mut App) {
@@ -371,14 +624,11 @@ impl BevyApp {
     /// can't make safe (it panics on entry) — see the book.
     #[func(rename = send_event)]
     fn gd_send_event(&self, name: GString, payload: Variant) {
-        use crate::plugins::event_bridge::{GodotEventRegistry, GodotEventSender};
-
-        let Some(app) = self.app.as_ref() else {
+        let Some((registry, sender)) = self.event_resources() else {
             tracing::warn!("BevyApp::send_event({name}) called with no live App; ignored");
             return;
         };
-        let world = app.world();
-        let Some(registry) = world.get_resource::<GodotEventRegistry>() else {
+        let Some(registry) = registry else {
             tracing::warn!("BevyApp::send_event: no events registered (call add_godot_event)");
             return;
         };
@@ -401,7 +651,7 @@ impl BevyApp {
             }
             return;
         };
-        let Some(sender) = world.get_resource::<GodotEventSender>() else {
+        let Some(sender) = sender else {
             tracing::warn!("BevyApp::send_event: no event channel; ignored");
             return;
         };
@@ -410,6 +660,62 @@ impl BevyApp {
         }
     }
 
+    /// Whether the Bevy app is built. `bevy_ready` fires once, so scripts that
+    /// connect late check this first.
+    #[func]
+    fn is_bevy_ready(&self) -> bool {
+        self.app.is_some()
+    }
+
+    /// Stop running Bevy frames; Godot keeps rendering and processing. For
+    /// tooling, cutscene scripts and frame-by-frame debugging.
+    #[func]
+    fn pause_bevy(&mut self) {
+        self.paused = true;
+        self.frames_to_step = 0;
+    }
+
+    /// Run Bevy frames again after `pause_bevy` or `step_frames`.
+    #[func]
+    fn resume_bevy(&mut self) {
+        self.paused = false;
+        self.frames_to_step = 0;
+    }
+
+    /// Pause, then run the next `frames` Bevy frames (with the physics steps of
+    /// their render frames) and stay paused. Calls add up.
+    #[func]
+    fn step_frames(&mut self, frames: i64) {
+        self.paused = true;
+        self.frames_to_step = self
+            .frames_to_step
+            .saturating_add(frames.clamp(0, u32::MAX as i64) as u32);
+    }
+
+    /// Whether Bevy is paused by `pause_bevy`/`step_frames`, including while stepping.
+    #[func]
+    fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Emitted once the Bevy app is built, before its first frame.
+    #[signal]
+    fn bevy_ready();
+
+    /// Emitted after a frame in which a state type registered with
+    /// `add_state_signal` entered `name` (the state's `Debug` form).
+    #[signal]
+    fn bevy_state_changed(name: GString);
+
+    /// Editor entry point: `contents` of a `.gdextension` file with its `[icons]`
+    /// section regenerated from the `#[gdbevy(icon = ...)]` classes. The addon calls
+    /// this after a build; static, so it works without an app.
+    #[func]
+    fn gdextension_with_icons(contents: GString) -> GString {
+        let gdextension = crate::plugins::scene_tree::gdextension_with_icons(&contents.to_string());
+        GString::from(gdextension.as_str())
+    }
+
     /// Emitted at the end of every render frame, after the Bevy suffix + clear_trackers.
     /// Carries the number of physics steps that ran this frame. Test harness only.
     #[cfg(feature = "test-frame-signal")]
@@ -424,10 +730,16 @@ impl INode for BevyApp {
             base,
             app: Default::default(),
             instance_init_func: None,
+            app_name: GString::new(),
+            settings: None,
             started: false,
             prefix_done_this_frame: false,
             #[cfg(feature = "test-frame-signal")]
             physics_steps_this_frame: 0,
+            paused: false,
+            frames_to_step: 0,
+            between_frames: None,
+            parked_events: None,
             render_server_span: None,
         }
     }
@@ -443,8 +755,15 @@ impl INode for BevyApp {
         #[cfg(debug_assertions)]
         self.register_optimized_bulk_operations();
 
-        let has_init = self.instance_init_func.is_some() || BEVY_INIT_FUNC.get().is_some();
-        if !has_init {
+        if !self.has_init() {
+            return;
+        }
+
+        // Per-instance apps (the itest harness) may run side by side; each
+        // registered app runs on one node at a time.
+        if self.instance_init_func.is_none()
+            && !crate::watchdog::claim_app(&self.app_name.to_string(), &self.to_gd())
+        {
             return;
         }
 
@@ -477,6 +796,11 @@ impl INode for BevyApp {
         if godot::classes::Engine::singleton().is_editor_hint() {
             return;
         }
+        self.join_between_frames();
+        // A pause requested after this frame's physics prefix still finishes the frame.
+        if self.frozen() && !self.prefix_done_this_frame {
+            return;
+        }
 
         let need_startup = !self.started;
         let need_prefix = !self.prefix_done_this_frame;
@@ -501,6 +825,14 @@ impl INode for BevyApp {
 
         self.started = true;
         self.prefix_done_this_frame = false;
+        if self.paused {
+            self.frames_to_step = self.frames_to_step.saturating_sub(1);
+        }
+        if matches!(result, Some(Ok(()))) {
+            self.emit_state_signals();
+            self.emit_event_signals();
+            self.start_between_frames();
+        }
 
         // Emit unconditionally: after suffix+clear, before resume_unwind, and even
         // when app == None. A panicking/torn-down frame still resumes its awaiter,
@@ -522,14 +854,21 @@ impl INode for BevyApp {
         }
     }
 
+    // Only `input`: also reacting in `unhandled_input` would run `InputReact` a
+    // second time for the same event, with `just_pressed` still set.
+    fn input(&mut self, _event: Gd<godot::classes::InputEvent>) {
+        self.react_to_input();
+    }
+
     #[tracing::instrument(skip_all)]
     fn physics_process(&mut self, delta: f32) {
         use crate::plugins::fixed_schedule::run_physics_step;
         use std::panic::{AssertUnwindSafe, catch_unwind, resume_unwind};
 
-        if godot::classes::Engine::singleton().is_editor_hint() {
+        if godot::classes::Engine::singleton().is_editor_hint() || self.frozen() {
             return;
         }
+        self.join_between_frames();
 
         #[cfg(feature = "test-frame-signal")]
         {
@@ -565,6 +904,38 @@ impl INode for BevyApp {
     }
 }
 
+impl BevyApp {
+    /// Paused with no frames left to step: Godot's callbacks skip the Bevy frame.
+    fn frozen(&self) -> bool {
+        self.paused && self.frames_to_step == 0
+    }
+
+    /// Run `InputReact` for an event the input watcher just queued. The watcher is
+    /// a child, so Godot delivers each event to it before reaching this node.
+    fn react_to_input(&mut self) {
+        use std::panic::{AssertUnwindSafe, catch_unwind, resume_unwind};
+
+        // Before the first frame, Startup hasn't run; the event waits for `First`.
+        // Paused, it waits for the next frame that runs.
+        if !self.started || self.frozen() {
+            return;
+        }
+        self.join_between_frames();
+        if let Some(app) = self.app.as_mut()
+            && let Err(e) = catch_unwind(AssertUnwindSafe(|| {
+                crate::plugins::input::react::run_input_react(app.world_mut());
+            }))
+        {
+            self.app = None;
+            godot::global::godot_error!(
+                "godot-bevy: Bevy app panicked during InputReact and was permanently torn down; \
+                 it will not recover this session. See the panic above."
+            );
+            resume_unwind(e);
+        }
+    }
+}
+
 #[cfg(feature = "trace_tracy")]
 impl BevyApp {
     fn on_frame_pre_draw(&mut self) {
@@ -576,3 +947,50 @@ impl BevyApp {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build_alpha(_app: &mut App) {}
+    fn build_beta(_app: &mut App) {}
+
+    crate::inventory::submit! {
+        NamedBevyApp {
+            name: "test_alpha",
+            config: BevyAppConfig { scene_tree_auto_despawn_children: true },
+            init_fn: build_alpha,
+        }
+    }
+
+    crate::inventory::submit! {
+        NamedBevyApp {
+            name: "test_beta",
+            config: BevyAppConfig { scene_tree_auto_despawn_children: false },
+            init_fn: build_beta,
+        }
+    }
+
+    #[test]
+    fn named_app_picks_the_app_with_that_name() {
+        let beta = named_app("test_beta").expect("test_beta is registered");
+        assert_eq!(beta.name, "test_beta");
+        assert!(!beta.config.scene_tree_auto_despawn_children);
+        assert_eq!(beta.init_fn as usize, build_beta as fn(&mut App) as usize);
+
+        let alpha = named_app("test_alpha").expect("test_alpha is registered");
+        assert_eq!(alpha.init_fn as usize, build_alpha as fn(&mut App) as usize);
+    }
+
+    #[test]
+    fn unknown_name_is_an_error_listing_registered_apps() {
+        let err = named_app("test_gamma")
+            .err()
+            .expect("test_gamma is not registered");
+        assert!(err.contains("no app is named \"test_gamma\""), "{err}");
+        assert!(
+            err.contains("test_alpha") && err.contains("test_beta"),
+            "{err}"
+        );
+    }
+}
@@ -1,5 +1,8 @@
 use bevy_ecs::prelude::Resource;
 use bevy_ecs::system::{NonSendMut, SystemParam};
+#[cfg(feature = "trace_tracy")]
+use bevy_ecs::system::{ResMut, SystemName};
+use bevy_transform::components::Transform;
 use godot::{
     classes::Node,
     obj::{Gd, Inherits, InstanceId, Singleton},
@@ -15,6 +18,10 @@ pub struct GodotMainThread;
 #[derive(SystemParam)]
 pub struct GodotAccess<'w> {
     _main_thread: NonSendMut<'w, GodotMainThread>,
+    #[cfg(feature = "trace_tracy")]
+    system: SystemName,
+    #[cfg(feature = "trace_tracy")]
+    lookups: Option<ResMut<'w, crate::plugins::core::GodotLookupStats>>,
 }
 
 impl<'w> std::fmt::Debug for GodotAccess<'w> {
@@ -25,7 +32,7 @@ impl<'w> std::fmt::Debug for GodotAccess<'w> {
 
 impl<'w> GodotAccess<'w> {
     pub fn try_get<T: Inherits<Node>>(&mut self, handle: GodotNodeHandle) -> Option<Gd<T>> {
-        Gd::try_from_instance_id(handle.instance_id()).ok()
+        self.try_get_instance_id(handle.instance_id())
     }
 
     pub fn get<T: Inherits<Node>>(&mut self, handle: GodotNodeHandle) -> Gd<T> {
@@ -41,6 +48,7 @@ impl<'w> GodotAccess<'w> {
         &mut self,
         instance_id: InstanceId,
     ) -> Option<Gd<T>> {
+        self.count_lookup();
         Gd::try_from_instance_id(instance_id).ok()
     }
 
@@ -55,6 +63,7 @@ impl<'w> GodotAccess<'w> {
 
     /// Access a Godot singleton. Requires main-thread access.
     pub fn singleton<T: Singleton>(&mut self) -> Gd<T> {
+        self.count_lookup();
         T::singleton()
     }
 
@@ -63,6 +72,17 @@ impl<'w> GodotAccess<'w> {
         GodotNode {
             godot: self,
             handle,
+            captured_transform: None,
+        }
+    }
+
+    /// Count the lookup against this system in `GodotLookupStats`. Compiles away
+    /// without `trace_tracy`.
+    #[inline]
+    fn count_lookup(&mut self) {
+        #[cfg(feature = "trace_tracy")]
+        if let Some(lookups) = self.lookups.as_mut() {
+            lookups.count(&self.system.name());
         }
     }
 }
@@ -71,6 +91,7 @@ impl<'w> GodotAccess<'w> {
 pub struct GodotNode<'a, 'w> {
     godot: &'a mut GodotAccess<'w>,
     handle: GodotNodeHandle,
+    captured_transform: Option<Transform>,
 }
 
 impl<'a, 'w> GodotNode<'a, 'w> {
@@ -89,4 +110,17 @@ impl<'a, 'w> GodotNode<'a, 'w> {
     pub fn get<T: Inherits<Node>>(&mut self) -> Gd<T> {
         self.godot.get(self.handle)
     }
+
+    /// The node's local transform as the scene tree watcher captured it when the
+    /// node was added. Stale if the node moved before its entity was created, so
+    /// prefer reading the node when it is still valid. `None` when nothing was
+    /// captured.
+    pub fn captured_transform(&self) -> Option<Transform> {
+        self.captured_transform
+    }
+
+    pub(crate) fn with_captured_transform(mut self, transform: Option<Transform>) -> Self {
+        self.captured_transform = transform;
+        self
+    }
 }
@@ -25,6 +25,22 @@ impl GodotNodeHandle {
     pub fn from_instance_id(instance_id: InstanceId) -> Self {
         Self { instance_id }
     }
+
+    /// The node as `T`, or `None` if it was freed or isn't a `T`. For code
+    /// without a [`GodotAccess`](crate::interop::GodotAccess), like exclusive
+    /// systems, observers and Godot callbacks. With the `strict-threading`
+    /// feature, panics when called off the main thread, e.g. from a task.
+    #[track_caller]
+    pub fn try_get<T: Inherits<Node>>(self) -> Option<Gd<T>> {
+        #[cfg(feature = "strict-threading")]
+        if !crate::interop::thread_check::on_main_thread() {
+            crate::interop::thread_check::assert_node_access(
+                &format!("node as {}", std::any::type_name::<T>()),
+                self.instance_id,
+            );
+        }
+        Gd::try_from_instance_id(self.instance_id).ok()
+    }
 }
 
 impl From<InstanceId> for GodotNodeHandle {
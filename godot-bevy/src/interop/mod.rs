@@ -10,6 +10,9 @@ pub use godot_resource_handle::*;
 pub mod node_markers;
 pub use node_markers::*;
 
+pub mod thread_check;
+pub use thread_check::{assert_main_thread, main_thread};
+
 pub mod signal_names;
 pub use signal_names::*;
 
@@ -0,0 +1,266 @@
+//! Strict-threading mode: catch Godot access from the wrong thread on the Rust side.
+//!
+//! Godot reports off-main-thread node access as a stream of engine errors that
+//! never say where they came from. Systems taking a `GodotAccess` or
+//! `SceneTreeRef` always run on the main thread, so the risk is code that holds
+//! only a `GodotNodeHandle`, like a task. With the `strict-threading` feature
+//! enabled, [`GodotNodeHandle::try_get`](crate::interop::GodotNodeHandle::try_get)
+//! and [`assert_main_thread`] check the calling thread first and panic with the
+//! node, its entity and the running system. Without the feature every check
+//! compiles to nothing.
+//!
+//! The feature turns on Bevy's per-system tracing spans, and `GodotBevyLogPlugin`
+//! records which system each thread is running from them. The system is left out
+//! of the message when that plugin isn't the subscriber, or its filter drops
+//! `info` spans.
+
+use std::thread::ThreadId;
+#[cfg(feature = "strict-threading")]
+use {godot::obj::InstanceId, std::sync::OnceLock};
+
+#[cfg(feature = "strict-threading")]
+static MAIN_THREAD: OnceLock<ThreadId> = OnceLock::new();
+
+/// Remember the calling thread as Godot's main thread. `BevyApp` calls this when
+/// it builds the app, which always happens inside a Godot callback.
+pub(crate) fn record_main_thread() {
+    #[cfg(feature = "strict-threading")]
+    MAIN_THREAD.get_or_init(|| std::thread::current().id());
+}
+
+/// Godot's main thread, once a `BevyApp` has been initialized. Always `None`
+/// without the `strict-threading` feature.
+pub fn main_thread() -> Option<ThreadId> {
+    #[cfg(feature = "strict-threading")]
+    return MAIN_THREAD.get().copied();
+    #[cfg(not(feature = "strict-threading"))]
+    None
+}
+
+/// Panic unless called on Godot's main thread (or before it is known).
+///
+/// `what` names the access for the panic message, e.g. "node as Node2D". A no-op
+/// without the `strict-threading` feature, so it can stay in release code.
+#[track_caller]
+#[inline]
+pub fn assert_main_thread(what: &str) {
+    #[cfg(feature = "strict-threading")]
+    check(what, None);
+    #[cfg(not(feature = "strict-threading"))]
+    let _ = what;
+}
+
+/// [`assert_main_thread`] for node access, naming the node and its entity.
+#[cfg(feature = "strict-threading")]
+#[track_caller]
+pub(crate) fn assert_node_access(what: &str, instance_id: InstanceId) {
+    check(what, Some(instance_id));
+}
+
+/// Whether the calling thread is Godot's main thread, or the main thread is not
+/// known yet (no `BevyApp` initialized, e.g. in unit tests).
+#[cfg(feature = "strict-threading")]
+pub(crate) fn on_main_thread() -> bool {
+    MAIN_THREAD
+        .get()
+        .is_none_or(|&main| main == std::thread::current().id())
+}
+
+#[cfg(feature = "strict-threading")]
+#[track_caller]
+fn check(what: &str, instance_id: Option<InstanceId>) {
+    if !on_main_thread() {
+        panic!("{}", message(what, instance_id));
+    }
+}
+
+#[cfg(feature = "strict-threading")]
+fn message(what: &str, instance_id: Option<InstanceId>) -> String {
+    let current = std::thread::current();
+    let mut message = format!(
+        "godot-bevy strict-threading: {what} accessed from thread {:?} ({}), \
+         but Godot objects may only be touched on the main thread",
+        current.id(),
+        current.name().unwrap_or("unnamed"),
+    );
+    if let Some(system) = systems::current() {
+        message.push_str(&format!("\n  system: {system}"));
+    }
+    if let Some(instance_id) = instance_id {
+        message.push_str(&format!("\n  node: {instance_id}"));
+        if let Some(entity) = entities::get(instance_id) {
+            message.push_str(&format!(" (entity {entity})"));
+        }
+    }
+    message.push_str(
+        "\n  fix: take a `GodotAccess` (or `SceneTreeRef`) parameter in the system, \
+         which schedules it on the main thread, or send a message to a system that does",
+    );
+    message
+}
+
+/// Instance id → entity lookup for panic messages, fed by the `GodotNodeHandle`
+/// hooks. `NodeEntityIndex` lives in the world, out of reach of a panicking accessor.
+#[cfg(feature = "strict-threading")]
+pub(crate) mod entities {
+    use bevy_ecs::entity::Entity;
+    use bevy_platform::collections::HashMap;
+    use godot::obj::InstanceId;
+    use parking_lot::Mutex;
+
+    static ENTITIES: Mutex<Option<HashMap<InstanceId, Entity>>> = Mutex::new(None);
+
+    pub(crate) fn insert(instance_id: InstanceId, entity: Entity) {
+        ENTITIES
+            .lock()
+            .get_or_insert_with(HashMap::default)
+            .insert(instance_id, entity);
+    }
+
+    pub(crate) fn remove(instance_id: InstanceId) {
+        if let Some(map) = ENTITIES.lock().as_mut() {
+            map.remove(&instance_id);
+        }
+    }
+
+    pub(super) fn get(instance_id: InstanceId) -> Option<Entity> {
+        ENTITIES.lock().as_ref()?.get(&instance_id).copied()
+    }
+}
+
+/// The system each thread is running, tracked from Bevy's `system` spans.
+#[cfg(feature = "strict-threading")]
+pub(crate) mod systems {
+    use std::cell::RefCell;
+    use std::sync::Arc;
+
+    thread_local! {
+        static RUNNING: RefCell<Vec<Arc<str>>> = const { RefCell::new(Vec::new()) };
+    }
+
+    /// The innermost system running on the calling thread, if known.
+    pub(crate) fn current() -> Option<Arc<str>> {
+        RUNNING.with(|running| running.borrow().last().cloned())
+    }
+
+    /// Tracing layer that keeps [`current`] up to date as system spans are entered
+    /// and exited. `GodotBevyLogPlugin` installs it.
+    #[cfg(feature = "godot_bevy_log")]
+    pub(crate) struct SystemSpanLayer;
+
+    #[cfg(feature = "godot_bevy_log")]
+    mod layer {
+        use super::{RUNNING, SystemSpanLayer};
+        use bevy_log::tracing::{Subscriber, field::Field, span};
+        use bevy_log::tracing_subscriber::{
+            Layer, field::Visit, layer::Context, registry::LookupSpan,
+        };
+        use std::sync::Arc;
+
+        struct SystemName(Arc<str>);
+
+        struct NameVisitor(Option<Arc<str>>);
+
+        impl Visit for NameVisitor {
+            fn record_str(&mut self, field: &Field, value: &str) {
+                if field.name() == "name" {
+                    self.0 = Some(value.into());
+                }
+            }
+
+            fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+                if field.name() == "name" {
+                    self.0 = Some(format!("{value:?}").into());
+                }
+            }
+        }
+
+        impl<S> Layer<S> for SystemSpanLayer
+        where
+            S: Subscriber + for<'a> LookupSpan<'a>,
+        {
+            fn on_new_span(
+                &self,
+                attrs: &span::Attributes<'_>,
+                id: &span::Id,
+                ctx: Context<'_, S>,
+            ) {
+                if attrs.metadata().name() != "system" {
+                    return;
+                }
+                let mut visitor = NameVisitor(None);
+                attrs.record(&mut visitor);
+                if let (Some(name), Some(span)) = (visitor.0, ctx.span(id)) {
+                    span.extensions_mut().insert(SystemName(name));
+                }
+            }
+
+            fn on_enter(&self, id: &span::Id, ctx: Context<'_, S>) {
+                if let Some(span) = ctx.span(id)
+                    && let Some(SystemName(name)) = span.extensions().get::<SystemName>()
+                {
+                    RUNNING.with(|running| running.borrow_mut().push(name.clone()));
+                }
+            }
+
+            fn on_exit(&self, id: &span::Id, ctx: Context<'_, S>) {
+                if let Some(span) = ctx.span(id)
+                    && span.extensions().get::<SystemName>().is_some()
+                {
+                    RUNNING.with(|running| running.borrow_mut().pop());
+                }
+            }
+        }
+    }
+}
+
+#[cfg(all(test, feature = "strict-threading"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn violation_message_names_thread_and_node() {
+        let id = InstanceId::from_i64(42);
+        let entity = bevy_ecs::entity::Entity::from_raw_u32(7).unwrap();
+        entities::insert(id, entity);
+
+        let text = std::thread::Builder::new()
+            .name("worker".into())
+            .spawn(move || message("node as Node2D", Some(id)))
+            .unwrap()
+            .join()
+            .unwrap();
+        entities::remove(id);
+
+        assert!(text.contains("node as Node2D"));
+        assert!(text.contains("(worker)"));
+        assert!(text.contains(&format!("entity {entity}")));
+    }
+
+    #[cfg(feature = "godot_bevy_log")]
+    #[test]
+    fn violation_message_names_running_system() {
+        use bevy_log::tracing_subscriber::layer::SubscriberExt;
+
+        let subscriber = bevy_log::tracing_subscriber::registry().with(systems::SystemSpanLayer);
+        let text = bevy_log::tracing::subscriber::with_default(subscriber, || {
+            // The span Bevy's executor enters around every system run.
+            let span = bevy_log::tracing::info_span!("system", name = "my_game::move_player");
+            let _entered = span.enter();
+            message("node as Node2D", None)
+        });
+
+        assert!(text.contains("system: my_game::move_player"), "{text}");
+        assert!(!message("node as Node2D", None).contains("system:"));
+    }
+
+    // Records a main thread for the whole test process. No other unit test
+    // reaches a checked accessor, since those need a running engine past the check.
+    #[test]
+    #[should_panic(expected = "godot-bevy strict-threading: node as")]
+    fn handle_access_off_the_main_thread_panics() {
+        std::thread::spawn(record_main_thread).join().unwrap();
+        crate::interop::GodotNodeHandle::from_instance_id(InstanceId::from_i64(42))
+            .try_get::<godot::classes::Node>();
+    }
+}
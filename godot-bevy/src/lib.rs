@@ -12,7 +12,9 @@ pub mod node_tree_view;
 pub mod plugins;
 pub mod prelude;
 pub mod profiling;
+pub mod settings;
 pub mod utils;
+mod watchdog;
 pub mod watchers;
 
 #[cfg(test)]
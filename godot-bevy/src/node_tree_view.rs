@@ -5,6 +5,12 @@ use thiserror::Error;
 pub enum NodeTreeViewError {
     #[error("Could not find node matching pattern: {0}")]
     NodeNotFound(String),
+    #[error("Node at {path} is a {actual}, expected {expected}")]
+    TypeMismatch {
+        path: String,
+        expected: String,
+        actual: String,
+    },
 }
 
 /// Trait for objects that can be created from a node reference.
@@ -19,6 +25,57 @@ pub trait NodeTreeView {
         Self: Sized;
 }
 
+/// A field type `#[derive(NodeTreeView)]` can fill from a found node.
+///
+/// Implemented for `GodotNodeHandle` and typed `Gd<T>` fields; the derive also
+/// accepts `Option<_>` and `Vec<_>` of either.
+pub trait NodeTreeViewField: Sized {
+    fn from_view_node(
+        node: godot::obj::Gd<godot::classes::Node>,
+        path: &str,
+    ) -> Result<Self, NodeTreeViewError>;
+}
+
+impl NodeTreeViewField for crate::interop::GodotNodeHandle {
+    fn from_view_node(
+        node: godot::obj::Gd<godot::classes::Node>,
+        _path: &str,
+    ) -> Result<Self, NodeTreeViewError> {
+        Ok(Self::from_instance_id(node.instance_id()))
+    }
+}
+
+impl<T> NodeTreeViewField for godot::obj::Gd<T>
+where
+    T: godot::obj::Inherits<godot::classes::Node>,
+{
+    fn from_view_node(
+        node: godot::obj::Gd<godot::classes::Node>,
+        path: &str,
+    ) -> Result<Self, NodeTreeViewError> {
+        let actual = node.get_class().to_string();
+        node.try_cast::<T>()
+            .map_err(|_| NodeTreeViewError::TypeMismatch {
+                path: path.to_string(),
+                expected: T::class_id().to_string(),
+                actual,
+            })
+    }
+}
+
+/// Find the node at `path` relative to `base_node`, or the first match if the
+/// path contains wildcards (see [`find_node_by_pattern`]).
+pub fn find_node(
+    base_node: &godot::obj::Gd<godot::classes::Node>,
+    path: &str,
+) -> Option<godot::obj::Gd<godot::classes::Node>> {
+    if path.contains('*') {
+        find_node_by_pattern(base_node, path)
+    } else {
+        base_node.try_get_node_as::<godot::classes::Node>(path)
+    }
+}
+
 /// Find a node by matching a pattern with wildcards.
 ///
 /// Supports patterns like:
@@ -29,8 +86,31 @@ pub fn find_node_by_pattern(
     base_node: &godot::obj::Gd<godot::classes::Node>,
     pattern: &str,
 ) -> Option<godot::obj::Gd<godot::classes::Node>> {
+    let (search_root, pattern_parts) = split_pattern(base_node, pattern)?;
+    let mut matches = Vec::new();
+    find_nodes_recursive(&search_root, &pattern_parts, 0, true, &mut matches);
+    matches.into_iter().next()
+}
+
+/// Find every node matching a pattern, in tree order. `Enemies/*` returns all
+/// children of `Enemies`. Same pattern syntax as [`find_node_by_pattern`].
+pub fn find_nodes_by_pattern(
+    base_node: &godot::obj::Gd<godot::classes::Node>,
+    pattern: &str,
+) -> Vec<godot::obj::Gd<godot::classes::Node>> {
+    let mut matches = Vec::new();
+    if let Some((search_root, pattern_parts)) = split_pattern(base_node, pattern) {
+        find_nodes_recursive(&search_root, &pattern_parts, 0, false, &mut matches);
+    }
+    matches
+}
+
+fn split_pattern<'a>(
+    base_node: &godot::obj::Gd<godot::classes::Node>,
+    pattern: &'a str,
+) -> Option<(godot::obj::Gd<godot::classes::Node>, Vec<&'a str>)> {
     // Handle absolute vs relative paths
-    let (search_root, pattern_parts) = if let Some(stripped) = pattern.strip_prefix('/') {
+    if let Some(stripped) = pattern.strip_prefix('/') {
         // Absolute path - start from scene tree root
         let scene_tree = base_node.get_tree();
         let root = scene_tree.get_root()?;
@@ -42,61 +122,51 @@ pub fn find_node_by_pattern(
             parts.remove(0);
         }
 
-        (root_as_node, parts)
+        Some((root_as_node, parts))
     } else {
         // Relative path - start from base node
         let parts: Vec<&str> = pattern.split('/').filter(|s| !s.is_empty()).collect();
-        (base_node.clone(), parts)
-    };
-
-    find_node_recursive(&search_root, &pattern_parts, 0)
+        Some((base_node.clone(), parts))
+    }
 }
 
-fn find_node_recursive(
+/// Pushes matches onto `matches`; with `first_only`, stops after the first.
+/// Returns whether the search should stop.
+fn find_nodes_recursive(
     current_node: &godot::obj::Gd<godot::classes::Node>,
     pattern_parts: &[&str],
     depth: usize,
-) -> Option<godot::obj::Gd<godot::classes::Node>> {
-    // If we've matched all pattern parts, we found our target
+    first_only: bool,
+    matches: &mut Vec<godot::obj::Gd<godot::classes::Node>>,
+) -> bool {
+    // If we've matched all pattern parts, we found a target
     if depth >= pattern_parts.len() {
-        return Some(current_node.clone());
+        matches.push(current_node.clone());
+        return first_only;
     }
 
     let pattern_part = pattern_parts[depth];
 
-    // If this pattern part is a wildcard
-    if pattern_part == "*" {
-        // Try all children
+    if pattern_part.contains('*') {
+        // "*" matches any child; "Level*" or "*Button" match by prefix/suffix
         for i in 0..current_node.get_child_count() {
             if let Some(child) = current_node.get_child(i)
-                && let Some(result) = find_node_recursive(&child, pattern_parts, depth + 1)
+                && matches_wildcard_pattern(&child.get_name().to_string(), pattern_part)
+                && find_nodes_recursive(&child, pattern_parts, depth + 1, first_only, matches)
             {
-                return Some(result);
-            }
-        }
-    } else if pattern_part.contains('*') {
-        // Handle prefix/suffix wildcards like "Level*" or "*Button"
-        for i in 0..current_node.get_child_count() {
-            if let Some(child) = current_node.get_child(i) {
-                let child_name = child.get_name().to_string();
-                if matches_wildcard_pattern(&child_name, pattern_part)
-                    && let Some(result) = find_node_recursive(&child, pattern_parts, depth + 1)
-                {
-                    return Some(result);
-                }
+                return true;
             }
         }
+        false
     } else {
         // Exact name match
-        if current_node.has_node(pattern_part) {
-            let child = current_node.get_node_as::<godot::classes::Node>(pattern_part);
-            if let Some(result) = find_node_recursive(&child, pattern_parts, depth + 1) {
-                return Some(result);
+        match current_node.try_get_node_as::<godot::classes::Node>(pattern_part) {
+            Some(child) => {
+                find_nodes_recursive(&child, pattern_parts, depth + 1, first_only, matches)
             }
+            None => false,
         }
     }
-
-    None
 }
 
 fn matches_wildcard_pattern(text: &str, pattern: &str) -> bool {
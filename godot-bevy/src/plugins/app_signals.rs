@@ -0,0 +1,185 @@
+//! Lifecycle signals on the `BevyApp` node, for GDScript that coordinates with
+//! the ECS: splash screens, loading overlays, menus driven from scripts.
+//!
+//! `BevyApp` emits `bevy_ready` once the Bevy app is built, and
+//! `bevy_state_changed(name)` each time a [`States`] type registered with
+//! [`AddStateSignalAppExt::add_state_signal`] enters a state:
+//!
+//! ```ignore
+//! #[derive(States, Debug, Clone, PartialEq, Eq, Hash, Default)]
+//! enum GameState { #[default] Loading, MainMenu, InGame }
+//!
+//! app.init_state::<GameState>().add_state_signal::<GameState>();
+//! ```
+//!
+//! ```gdscript
+//! func _ready():
+//!     var bevy = get_node("/root/BevyAppSingleton")
+//!     bevy.bevy_state_changed.connect(func(state): $Splash.visible = state == "Loading")
+//!     if bevy.is_bevy_ready():
+//!         _on_bevy_ready()
+//!     else:
+//!         bevy.bevy_ready.connect(_on_bevy_ready)
+//! ```
+//!
+//! `name` is the state's `Debug` form, `"InGame"` above. The initial state is
+//! reported too, once `Startup` has run. Both signals are emitted deferred, at
+//! the end of the Godot frame, so handlers may call back into `BevyApp`
+//! (`send_event`, say) without re-entering a running Bevy frame.
+//!
+//! Gameplay events can be forwarded the same way. Each event exposed with
+//! [`ExposeEventAppExt::expose_event_as_signal`] gets a signal of its own on
+//! `BevyApp`, emitted with the event's fields as a `Dictionary`:
+//!
+//! ```ignore
+//! #[derive(Event, Reflect, Clone)]
+//! struct GameOver { score: i64, won: bool }
+//!
+//! app.expose_event_as_signal::<GameOver>("game_over");
+//! ```
+//!
+//! ```gdscript
+//! bevy.game_over.connect(func(payload): $Results.show_score(payload.score))
+//! ```
+//!
+//! The payload converts fields as the meta sync plugin does (see
+//! [`meta_sync`](crate::plugins::meta_sync)); fields it can't convert, such as
+//! an `EntityEvent`'s entity, are left out.
+
+use std::fmt::Debug;
+
+use bevy_app::{App, Last};
+use bevy_ecs::event::Event;
+use bevy_ecs::message::MessageReader;
+use bevy_ecs::observer::On;
+use bevy_ecs::prelude::Resource;
+use bevy_ecs::system::ResMut;
+use bevy_reflect::Reflect;
+use bevy_state::state::{StateTransitionEvent, States};
+
+pub trait AddStateSignalAppExt {
+    /// Emit `bevy_state_changed` on the `BevyApp` node whenever `S` enters a
+    /// state. `S` must already be initialized with `init_state`/`insert_state`.
+    fn add_state_signal<S: States + Debug>(&mut self) -> &mut Self;
+}
+
+impl AddStateSignalAppExt for App {
+    fn add_state_signal<S: States + Debug>(&mut self) -> &mut Self {
+        self.init_resource::<PendingStateSignals>()
+            .add_systems(Last, queue_state_signals::<S>)
+    }
+}
+
+/// State names waiting for `BevyApp` to emit them after the frame.
+#[derive(Resource, Default, Debug)]
+pub(crate) struct PendingStateSignals(pub(crate) Vec<String>);
+
+fn queue_state_signals<S: States + Debug>(
+    mut transitions: MessageReader<StateTransitionEvent<S>>,
+    mut pending: ResMut<PendingStateSignals>,
+) {
+    for transition in transitions.read() {
+        // Setting the current state again runs no transition; nothing to report.
+        if transition.exited == transition.entered {
+            continue;
+        }
+        if let Some(entered) = &transition.entered {
+            pending.0.push(format!("{entered:?}"));
+        }
+    }
+}
+
+pub trait ExposeEventAppExt {
+    /// Emit the signal `signal(payload: Dictionary)` on the `BevyApp` node after
+    /// each frame in which `E` is triggered, once per trigger.
+    fn expose_event_as_signal<E: Event + Reflect>(&mut self, signal: &str) -> &mut Self;
+}
+
+impl ExposeEventAppExt for App {
+    fn expose_event_as_signal<E: Event + Reflect>(&mut self, signal: &str) -> &mut Self {
+        let signal = signal.to_owned();
+        self.world_mut()
+            .get_resource_or_init::<EventSignals>()
+            .names
+            .push(signal.clone());
+        self.add_observer(move |event: On<E>, mut signals: ResMut<EventSignals>| {
+            if let Ok(value) = event.event().reflect_clone() {
+                signals.pending.push((signal.clone(), value));
+            }
+        })
+    }
+}
+
+/// Signals exposed with [`ExposeEventAppExt::expose_event_as_signal`], and the
+/// events waiting for `BevyApp` to emit them after the frame.
+#[derive(Resource, Default)]
+pub(crate) struct EventSignals {
+    pub(crate) names: Vec<String>,
+    pub(crate) pending: Vec<(String, Box<dyn Reflect>)>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_state::app::{AppExtStates, StatesPlugin};
+    use bevy_state::state::NextState;
+
+    #[derive(States, Debug, Clone, PartialEq, Eq, Hash, Default)]
+    enum GameState {
+        #[default]
+        Loading,
+        InGame,
+    }
+
+    #[test]
+    fn entered_states_are_queued_by_name() {
+        let mut app = App::new();
+        app.add_plugins(StatesPlugin)
+            .init_state::<GameState>()
+            .add_state_signal::<GameState>();
+
+        app.update();
+        app.world_mut()
+            .resource_mut::<NextState<GameState>>()
+            .set(GameState::InGame);
+        app.update();
+        app.world_mut()
+            .resource_mut::<NextState<GameState>>()
+            .set(GameState::InGame);
+        app.update();
+
+        assert_eq!(
+            app.world().resource::<PendingStateSignals>().0,
+            ["Loading", "InGame"]
+        );
+    }
+
+    #[derive(Event, Reflect, Clone, Debug, PartialEq)]
+    struct GameOver {
+        score: i64,
+    }
+
+    #[test]
+    fn exposed_events_are_queued_per_trigger() {
+        let mut app = App::new();
+        app.expose_event_as_signal::<GameOver>("game_over");
+
+        app.world_mut().trigger(GameOver { score: 3 });
+        app.world_mut().trigger(GameOver { score: 5 });
+
+        let signals = app.world().resource::<EventSignals>();
+        assert_eq!(signals.names, ["game_over"]);
+        let pending: Vec<_> = signals
+            .pending
+            .iter()
+            .map(|(name, value)| (name.as_str(), value.downcast_ref::<GameOver>().cloned()))
+            .collect();
+        assert_eq!(
+            pending,
+            [
+                ("game_over", Some(GameOver { score: 3 })),
+                ("game_over", Some(GameOver { score: 5 })),
+            ]
+        );
+    }
+}
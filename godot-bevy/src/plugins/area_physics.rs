@@ -0,0 +1,360 @@
+//! Gravity and damping of `Area2D`/`Area3D` nodes as components, for water,
+//! wind and low-gravity zones implemented in systems.
+//!
+//! With [`GodotAreaPhysicsPlugin`], every mirrored area's entity gets an
+//! [`AreaGravityOverride`] and an [`AreaDampOverride`] read from the node.
+//! Editing them writes the node in `Last`, so Godot's own bodies feel the
+//! change too. When a body enters or leaves the area, [`EnteredArea`] or
+//! [`ExitedArea`] is triggered on the body's entity, carrying the area's values
+//! as of that moment:
+//!
+//! ```ignore
+//! fn enter_water(entered: On<EnteredArea>, mut swimmers: Query<&mut Swimmer>) {
+//!     if let Ok(mut swimmer) = swimmers.get_mut(entered.entity) {
+//!         swimmer.drag = entered.damp.linear;
+//!         swimmer.water = Some(entered.area);
+//!     }
+//! }
+//!
+//! fn gusts(time: Res<Time>, mut wind: Query<&mut AreaGravityOverride, With<WindZone>>) {
+//!     for mut wind in &mut wind {
+//!         wind.strength = 200.0 + 100.0 * time.elapsed_secs().sin();
+//!     }
+//! }
+//! ```
+//!
+//! Directions and points are `Vec3`; in 2D only `x` and `y` are used. Values
+//! changed from GDScript are read back when a body enters or leaves. Only
+//! physics bodies are reported, not other areas.
+
+use bevy_app::{App, Last, Plugin};
+use bevy_ecs::change_detection::DetectChangesMut;
+use bevy_ecs::component::Component;
+use bevy_ecs::entity::Entity;
+use bevy_ecs::event::EntityEvent;
+use bevy_ecs::observer::On;
+use bevy_ecs::query::{Added, Changed, Or, Without};
+use bevy_ecs::schedule::IntoScheduleConfigs;
+use bevy_ecs::system::{Commands, Query, Res};
+use bevy_ecs::world::Ref;
+use bevy_math::Vec3;
+use godot::builtin::{Variant, Vector2, Vector3};
+use godot::classes::{Area2D, Area3D, Node, area_2d, area_3d};
+use godot::obj::{EngineEnum, Gd, InstanceId};
+use godot::prelude::Callable;
+
+use crate::interop::{GodotAccess, GodotNodeHandle};
+use crate::plugins::event_bridge::GodotEventSender;
+use crate::plugins::scene_tree::NodeEntityIndex;
+
+/// Plugin mirroring area gravity and damping. See the [module docs](self).
+#[derive(Default)]
+pub struct GodotAreaPhysicsPlugin;
+
+impl Plugin for GodotAreaPhysicsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_observer(on_area_body_changed)
+            .add_systems(Last, (insert_area_overrides, apply_area_overrides).chain());
+    }
+}
+
+#[cfg(debug_assertions)]
+crate::inventory::submit! {
+    crate::watchdog::PluginRequirement::new::<AreaGravityOverride, GodotAreaPhysicsPlugin>()
+}
+
+/// How an area's value combines with the world's and other areas'. Mirrors
+/// Godot's `SpaceOverride`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AreaOverrideMode {
+    /// The area doesn't affect the value.
+    #[default]
+    Disabled,
+    /// Added to what was computed so far, in priority order.
+    Combine,
+    /// Added, then lower-priority areas are ignored.
+    CombineReplace,
+    /// Replaces what was computed so far, keeping lower-priority areas.
+    Replace,
+    /// Replaces the world's value, then lower-priority areas are added.
+    ReplaceCombine,
+}
+
+impl AreaOverrideMode {
+    const ALL: [Self; 5] = [
+        Self::Disabled,
+        Self::Combine,
+        Self::CombineReplace,
+        Self::Replace,
+        Self::ReplaceCombine,
+    ];
+
+    fn from_ord(ord: i32) -> Self {
+        usize::try_from(ord)
+            .ok()
+            .and_then(|index| Self::ALL.get(index).copied())
+            .unwrap_or_default()
+    }
+
+    fn ord(self) -> i32 {
+        self as i32
+    }
+}
+
+/// The gravity an area applies to bodies inside it.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct AreaGravityOverride {
+    pub mode: AreaOverrideMode,
+    /// Acceleration, in units per second squared.
+    pub strength: f32,
+    /// Direction of the pull, ignored when `point` is set.
+    pub direction: Vec3,
+    /// Pull towards this point, local to the area, instead of along
+    /// `direction`.
+    pub point: Option<Vec3>,
+}
+
+/// The damping an area applies to bodies inside it.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct AreaDampOverride {
+    pub linear_mode: AreaOverrideMode,
+    pub linear: f32,
+    pub angular_mode: AreaOverrideMode,
+    pub angular: f32,
+}
+
+/// A body entered an area. Triggered on the body's entity.
+#[derive(EntityEvent, Debug, Clone, Copy, PartialEq)]
+pub struct EnteredArea {
+    pub entity: Entity,
+    pub area: Entity,
+    pub gravity: AreaGravityOverride,
+    pub damp: AreaDampOverride,
+}
+
+/// A body left an area. Triggered on the body's entity.
+#[derive(EntityEvent, Debug, Clone, Copy, PartialEq)]
+pub struct ExitedArea {
+    pub entity: Entity,
+    pub area: Entity,
+    pub gravity: AreaGravityOverride,
+    pub damp: AreaDampOverride,
+}
+
+/// `body_entered` or `body_exited` from the area node.
+#[derive(EntityEvent, Debug, Clone, Copy)]
+struct AreaBodyChanged {
+    entity: Entity,
+    body: InstanceId,
+    entered: bool,
+}
+
+/// An `Area2D` or `Area3D`, which share their API but no base class.
+enum Area {
+    TwoD(Gd<Area2D>),
+    ThreeD(Gd<Area3D>),
+}
+
+impl Area {
+    fn get(godot: &mut GodotAccess, handle: GodotNodeHandle) -> Option<Self> {
+        godot
+            .try_get::<Area2D>(handle)
+            .map(Self::TwoD)
+            .or_else(|| godot.try_get::<Area3D>(handle).map(Self::ThreeD))
+    }
+
+    fn node(&self) -> Gd<Node> {
+        match self {
+            Self::TwoD(area) => area.clone().upcast(),
+            Self::ThreeD(area) => area.clone().upcast(),
+        }
+    }
+
+    fn read(&self) -> (AreaGravityOverride, AreaDampOverride) {
+        macro_rules! read {
+            ($area:expr, $to_vec3:expr) => {{
+                let gravity = AreaGravityOverride {
+                    mode: AreaOverrideMode::from_ord($area.get_gravity_space_override_mode().ord()),
+                    strength: $area.get_gravity(),
+                    direction: $to_vec3($area.get_gravity_direction()),
+                    point: $area
+                        .is_gravity_a_point()
+                        .then(|| $to_vec3($area.get_gravity_point_center())),
+                };
+                let damp = AreaDampOverride {
+                    linear_mode: AreaOverrideMode::from_ord(
+                        $area.get_linear_damp_space_override_mode().ord(),
+                    ),
+                    linear: $area.get_linear_damp(),
+                    angular_mode: AreaOverrideMode::from_ord(
+                        $area.get_angular_damp_space_override_mode().ord(),
+                    ),
+                    angular: $area.get_angular_damp(),
+                };
+                (gravity, damp)
+            }};
+        }
+        let from_vector2 = |v: Vector2| Vec3::new(v.x, v.y, 0.0);
+        let from_vector3 = |v: Vector3| Vec3::new(v.x, v.y, v.z);
+        match self {
+            Self::TwoD(area) => read!(area, from_vector2),
+            Self::ThreeD(area) => read!(area, from_vector3),
+        }
+    }
+
+    /// Write both overrides, skipping values the node already has.
+    fn apply(&mut self, gravity: &AreaGravityOverride, damp: &AreaDampOverride) {
+        macro_rules! apply {
+            ($area:expr, $mode:ty, $from_vec3:expr) => {{
+                let mode = |mode: AreaOverrideMode| <$mode>::from_ord(mode.ord());
+                if $area.get_gravity_space_override_mode() != mode(gravity.mode) {
+                    $area.set_gravity_space_override_mode(mode(gravity.mode));
+                }
+                if $area.get_gravity() != gravity.strength {
+                    $area.set_gravity(gravity.strength);
+                }
+                let direction = $from_vec3(gravity.direction);
+                if $area.get_gravity_direction() != direction {
+                    $area.set_gravity_direction(direction);
+                }
+                if $area.is_gravity_a_point() != gravity.point.is_some() {
+                    $area.set_gravity_is_point(gravity.point.is_some());
+                }
+                if let Some(point) = gravity.point.map($from_vec3)
+                    && $area.get_gravity_point_center() != point
+                {
+                    $area.set_gravity_point_center(point);
+                }
+                if $area.get_linear_damp_space_override_mode() != mode(damp.linear_mode) {
+                    $area.set_linear_damp_space_override_mode(mode(damp.linear_mode));
+                }
+                if $area.get_linear_damp() != damp.linear {
+                    $area.set_linear_damp(damp.linear);
+                }
+                if $area.get_angular_damp_space_override_mode() != mode(damp.angular_mode) {
+                    $area.set_angular_damp_space_override_mode(mode(damp.angular_mode));
+                }
+                if $area.get_angular_damp() != damp.angular {
+                    $area.set_angular_damp(damp.angular);
+                }
+            }};
+        }
+        let to_vector2 = |v: Vec3| Vector2::new(v.x, v.y);
+        let to_vector3 = |v: Vec3| Vector3::new(v.x, v.y, v.z);
+        match self {
+            Self::TwoD(area) => apply!(area, area_2d::SpaceOverride, to_vector2),
+            Self::ThreeD(area) => apply!(area, area_3d::SpaceOverride, to_vector3),
+        }
+    }
+}
+
+fn connect_body_signals(area: &Area, entity: Entity, sender: &GodotEventSender) {
+    let mut node = area.node();
+    for (signal, entered) in [("body_entered", true), ("body_exited", false)] {
+        let tx = sender.clone();
+        node.connect(
+            signal,
+            &Callable::from_fn("area_body_changed", move |args| {
+                if let Some(body) = args.first().and_then(|arg| arg.try_to::<Gd<Node>>().ok()) {
+                    tx.send(AreaBodyChanged {
+                        entity,
+                        body: body.instance_id(),
+                        entered,
+                    });
+                }
+                Variant::nil()
+            }),
+        );
+    }
+}
+
+fn insert_area_overrides(
+    added: Query<(Entity, &GodotNodeHandle), (Added<GodotNodeHandle>, Without<AreaDampOverride>)>,
+    sender: Res<GodotEventSender>,
+    mut commands: Commands,
+    mut godot: GodotAccess,
+) {
+    for (entity, handle) in &added {
+        if let Some(area) = Area::get(&mut godot, *handle) {
+            connect_body_signals(&area, entity, &sender);
+            let (gravity, damp) = area.read();
+            // A gravity override inserted from ECS is kept and written to the node.
+            commands.entity(entity).insert(damp).insert_if_new(gravity);
+        }
+    }
+}
+
+fn apply_area_overrides(
+    areas: Query<
+        (
+            &GodotNodeHandle,
+            Ref<AreaGravityOverride>,
+            Ref<AreaDampOverride>,
+        ),
+        Or<(Changed<AreaGravityOverride>, Changed<AreaDampOverride>)>,
+    >,
+    mut godot: GodotAccess,
+) {
+    for (handle, gravity, damp) in &areas {
+        if let Some(mut area) = Area::get(&mut godot, *handle) {
+            area.apply(&gravity, &damp);
+        }
+    }
+}
+
+fn on_area_body_changed(
+    changed: On<AreaBodyChanged>,
+    index: Res<NodeEntityIndex>,
+    mut areas: Query<(
+        &GodotNodeHandle,
+        &mut AreaGravityOverride,
+        &mut AreaDampOverride,
+    )>,
+    mut commands: Commands,
+    mut godot: GodotAccess,
+) {
+    let Some(body) = index.get(changed.body) else {
+        return;
+    };
+    let Ok((handle, mut gravity, mut damp)) = areas.get_mut(changed.entity) else {
+        return;
+    };
+    let Some(node) = Area::get(&mut godot, *handle) else {
+        return;
+    };
+    // Pick up edits made from GDScript, without pushing them back to the node.
+    let (read_gravity, read_damp) = node.read();
+    *gravity.bypass_change_detection() = read_gravity;
+    *damp.bypass_change_detection() = read_damp;
+
+    let area = changed.entity;
+    if changed.entered {
+        commands.trigger(EnteredArea {
+            entity: body,
+            area,
+            gravity: read_gravity,
+            damp: read_damp,
+        });
+    } else {
+        commands.trigger(ExitedArea {
+            entity: body,
+            area,
+            gravity: read_gravity,
+            damp: read_damp,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn override_modes_match_godot_order() {
+        for mode in AreaOverrideMode::ALL {
+            assert_eq!(AreaOverrideMode::from_ord(mode.ord()), mode);
+        }
+        assert_eq!(AreaOverrideMode::ReplaceCombine.ord(), 4);
+        assert_eq!(AreaOverrideMode::from_ord(9), AreaOverrideMode::Disabled);
+    }
+}
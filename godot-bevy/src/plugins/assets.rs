@@ -211,6 +211,7 @@ impl Reader for GodotFileReader {
 /// Read a whole Godot file into a byte vec via `FileAccess`. Open failure is the
 /// `None` branch (mapped to `NotFound`), distinct from an empty file (`Some`, length 0).
 fn read_godot_file(godot_path: &str) -> io::Result<Vec<u8>> {
+    crate::profile_scope!("read_godot_file");
     match FileAccess::open(godot_path, ModeFlags::READ) {
         Some(mut fa) => {
             let len = fa.get_length() as i64;
@@ -277,6 +278,7 @@ impl AssetLoader for GodotResourceAssetLoader {
         let godot_path = load_context.path().to_string();
 
         {
+            crate::profile_scope!("load_threaded_request");
             let mut resource_loader = ResourceLoader::singleton();
             let path_gstring = godot::builtin::GString::from(&godot_path);
             resource_loader.load_threaded_request(&path_gstring);
@@ -292,6 +294,7 @@ impl AssetLoader for GodotResourceAssetLoader {
             match status {
                 ThreadLoadStatus::LOADED => {
                     let resource = {
+                        crate::profile_scope!("load_threaded_get");
                         let mut resource_loader = ResourceLoader::singleton();
                         let path_gstring = godot::builtin::GString::from(&godot_path);
                         resource_loader.load_threaded_get(&path_gstring)
@@ -335,6 +338,7 @@ impl AssetLoader for GodotResourceAssetLoader {
         _settings: &(),
         load_context: &mut LoadContext<'_>,
     ) -> Result<Self::Asset, Self::Error> {
+        crate::profile_scope!("godot_resource_load");
         let godot_path = load_context.path().to_string();
         let path_gstring = godot::builtin::GString::from(&godot_path);
 
@@ -0,0 +1,228 @@
+//! Per-entity audio listeners, for split screen
+//!
+//! An [`AudioListener`] on a 2D or 3D node entity gets an `AudioListener2D`/`3D`
+//! node of its own. The node is made current in the listener's viewport and
+//! follows the entity's global position every frame:
+//!
+//! ```ignore
+//! // One SubViewport per player, both showing the main world.
+//! commands.entity(player_one).insert(AudioListener::in_viewport(left_viewport));
+//! commands.entity(player_two).insert(AudioListener::in_viewport(right_viewport));
+//! app.insert_resource(SplitScreenPanning::Reduced(0.3));
+//! ```
+//!
+//! Godot mixes a spatial sound once per viewport listener, so while two players
+//! share the speakers a sound to one player's left also pans for the other.
+//! [`SplitScreenPanning`] decides how much panning is left while more than one
+//! listener is active.
+
+use crate::interop::{GodotAccess, GodotNodeHandle};
+use crate::plugins::audio::AudioOutput;
+use crate::watchers::scene_tree_watcher::exclude_from_mirror;
+use bevy_ecs::component::Component;
+use bevy_ecs::entity::Entity;
+use bevy_ecs::lifecycle::Remove;
+use bevy_ecs::observer::On;
+use bevy_ecs::prelude::Resource;
+use bevy_ecs::query::{Added, Changed, Or};
+use bevy_ecs::system::{Commands, Local, Query, Res, ResMut};
+use godot::classes::{
+    AudioListener2D, AudioListener3D, AudioStreamPlayer2D, AudioStreamPlayer3D, Node, Node2D,
+    Node3D,
+};
+use godot::obj::{Gd, NewAlloc};
+use tracing::warn;
+
+/// Hear the world from this entity's node. Its listener is current in
+/// `viewport` (an entity whose node is a `Viewport`, such as a `SubViewport`),
+/// or in the node's own viewport when `None`.
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AudioListener {
+    pub viewport: Option<Entity>,
+}
+
+impl AudioListener {
+    pub fn in_viewport(viewport: Entity) -> Self {
+        Self {
+            viewport: Some(viewport),
+        }
+    }
+}
+
+/// The `AudioListener2D`/`3D` node made for an [`AudioListener`].
+#[derive(Component, Debug, Clone, Copy)]
+pub(crate) struct ListenerNode(GodotNodeHandle);
+
+/// How spatial sounds pan while more than one [`AudioListener`] is active. With
+/// a single listener sounds always pan fully.
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq)]
+pub enum SplitScreenPanning {
+    /// Pan by every listener, as Godot does.
+    #[default]
+    Full,
+    /// Scale panning by a factor from `0` to `1`.
+    Reduced(f32),
+    /// Play spatial sounds centered; only distance attenuation remains.
+    Centered,
+}
+
+impl SplitScreenPanning {
+    /// The `panning_strength` for spatial players while `listeners` are active.
+    pub fn strength(self, listeners: usize) -> f32 {
+        if listeners <= 1 {
+            return 1.0;
+        }
+        match self {
+            Self::Full => 1.0,
+            Self::Reduced(factor) => factor.clamp(0.0, 1.0),
+            Self::Centered => 0.0,
+        }
+    }
+}
+
+/// Make a listener node for each new or retargeted [`AudioListener`], once its
+/// entity has a node.
+pub(crate) fn assign_listener_nodes(
+    changed: Query<
+        (
+            Entity,
+            &AudioListener,
+            &GodotNodeHandle,
+            Option<&ListenerNode>,
+        ),
+        Or<(Changed<AudioListener>, Added<GodotNodeHandle>)>,
+    >,
+    viewports: Query<&GodotNodeHandle>,
+    mut commands: Commands,
+    mut godot: GodotAccess,
+) {
+    for (entity, listener, handle, old) in &changed {
+        if let Some(ListenerNode(old)) = old
+            && let Some(mut node) = godot.try_get::<Node>(*old)
+        {
+            node.queue_free();
+        }
+
+        let Some(node) = godot.try_get::<Node>(*handle) else {
+            continue;
+        };
+        let viewport = match listener.viewport {
+            Some(viewport) => viewports
+                .get(viewport)
+                .ok()
+                .and_then(|handle| godot.try_get::<Node>(*handle)),
+            None => node.get_viewport().map(Gd::upcast),
+        };
+        let Some(mut viewport) = viewport else {
+            warn!(
+                "AudioListener on {entity}: no viewport node for {:?}",
+                listener.viewport
+            );
+            continue;
+        };
+
+        let listener_node: Gd<Node> = if node.clone().try_cast::<Node3D>().is_ok() {
+            let mut listener = AudioListener3D::new_alloc();
+            listener.set_name(&format!("AudioListener{entity}"));
+            exclude_from_mirror(&mut listener);
+            viewport.add_child(&listener);
+            listener.make_current();
+            listener.upcast()
+        } else if node.clone().try_cast::<Node2D>().is_ok() {
+            let mut listener = AudioListener2D::new_alloc();
+            listener.set_name(&format!("AudioListener{entity}"));
+            exclude_from_mirror(&mut listener);
+            viewport.add_child(&listener);
+            listener.make_current();
+            listener.upcast()
+        } else {
+            warn!("AudioListener on {entity}: its node is neither a Node2D nor a Node3D");
+            continue;
+        };
+        commands
+            .entity(entity)
+            .insert(ListenerNode(GodotNodeHandle::new(listener_node)));
+    }
+}
+
+/// Move each listener node to its entity's global position (and, in 3D, facing).
+pub(crate) fn follow_listeners(
+    listeners: Query<(&GodotNodeHandle, &ListenerNode)>,
+    mut godot: GodotAccess,
+) {
+    for (handle, ListenerNode(listener)) in &listeners {
+        if let Some(node) = godot.try_get::<Node3D>(*handle) {
+            if let Some(mut listener) = godot.try_get::<AudioListener3D>(*listener) {
+                listener.set_global_transform(node.get_global_transform());
+            }
+        } else if let Some(node) = godot.try_get::<Node2D>(*handle)
+            && let Some(mut listener) = godot.try_get::<AudioListener2D>(*listener)
+        {
+            listener.set_global_position(node.get_global_position());
+        }
+    }
+}
+
+/// Apply [`SplitScreenPanning`] to the audio plugin's spatial players whenever
+/// the strength changes; new players pick it up when they're created.
+pub(crate) fn apply_listener_panning(
+    listeners: Query<&ListenerNode>,
+    panning: Res<SplitScreenPanning>,
+    mut output: ResMut<AudioOutput>,
+    mut applied: Local<Option<f32>>,
+    mut godot: GodotAccess,
+) {
+    let strength = panning.strength(listeners.iter().count());
+    if *applied == Some(strength) {
+        return;
+    }
+    *applied = Some(strength);
+    output.panning_strength = Some(strength);
+
+    for handle in output.playing_sounds.values() {
+        if let Some(mut player) = godot.try_get::<AudioStreamPlayer2D>(*handle) {
+            player.set_panning_strength(strength);
+        } else if let Some(mut player) = godot.try_get::<AudioStreamPlayer3D>(*handle) {
+            player.set_panning_strength(strength);
+        }
+    }
+}
+
+/// Free the listener node with its [`AudioListener`]; Godot falls back to the
+/// viewport's camera.
+pub(crate) fn on_audio_listener_removed(
+    removed: On<Remove, AudioListener>,
+    listeners: Query<&ListenerNode>,
+    mut commands: Commands,
+    mut godot: GodotAccess,
+) {
+    if let Ok(ListenerNode(listener)) = listeners.get(removed.entity) {
+        if let Some(mut node) = godot.try_get::<Node>(*listener) {
+            node.queue_free();
+        }
+        if let Ok(mut entity) = commands.get_entity(removed.entity) {
+            entity.remove::<ListenerNode>();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn panning_is_full_with_one_listener() {
+        for rule in [
+            SplitScreenPanning::Full,
+            SplitScreenPanning::Reduced(0.3),
+            SplitScreenPanning::Centered,
+        ] {
+            assert_eq!(rule.strength(0), 1.0);
+            assert_eq!(rule.strength(1), 1.0);
+        }
+        assert_eq!(SplitScreenPanning::Full.strength(2), 1.0);
+        assert_eq!(SplitScreenPanning::Reduced(0.3).strength(2), 0.3);
+        assert_eq!(SplitScreenPanning::Reduced(4.0).strength(3), 1.0);
+        assert_eq!(SplitScreenPanning::Centered.strength(2), 0.0);
+    }
+}
@@ -45,6 +45,7 @@
 
 pub mod channel;
 pub mod command;
+pub mod listener;
 pub mod output;
 pub mod player;
 pub mod plugin;
@@ -54,6 +55,7 @@ pub mod tween;
 // Re-export main types for convenience
 pub use channel::{AudioChannel, AudioChannelMarker, ChannelId, MainAudioTrack, PlayAudioCommand};
 pub use command::{AudioCommand, PlayCommand};
+pub use listener::{AudioListener, SplitScreenPanning};
 pub use output::{ActiveTween, AudioOutput, SoundId, TweenType};
 pub use player::AudioPlayerType;
 pub use plugin::{AudioApp, AudioError, GodotAudioChannels, GodotAudioPlugin};
@@ -2,8 +2,11 @@
 
 use crate::interop::{GodotAccess, GodotNodeHandle};
 use crate::plugins::audio::{AudioTween, ChannelId};
+use crate::plugins::node_pool::HelperNodePool;
 use bevy_ecs::prelude::Resource;
-use godot::classes::{AudioStreamPlayer, AudioStreamPlayer2D, AudioStreamPlayer3D, Node};
+use godot::classes::{
+    AudioStream, AudioStreamPlayer, AudioStreamPlayer2D, AudioStreamPlayer3D, Node,
+};
 use godot::obj::Gd;
 use std::collections::HashMap;
 use std::sync::atomic::{AtomicU32, Ordering};
@@ -29,6 +32,8 @@ pub struct AudioOutput {
     /// Track current volume for each sound for accurate fade-outs
     pub(crate) current_volumes: HashMap<SoundId, f32>,
     pub(crate) active_tweens: HashMap<SoundId, ActiveTween>,
+    /// `panning_strength` for new spatial players, set from `SplitScreenPanning`
+    pub(crate) panning_strength: Option<f32>,
 }
 
 /// Tracks an active tween for a specific sound
@@ -161,6 +166,17 @@ impl AudioPlayer {
         }
     }
 
+    /// Stop and drop the stream, so a pooled player holds no audio data.
+    pub(crate) fn reset(&mut self) {
+        self.stop();
+        self.set_stream_paused(false);
+        match self {
+            AudioPlayer::Stream(player) => player.set_stream(Option::<&Gd<AudioStream>>::None),
+            AudioPlayer::Stream2D(player) => player.set_stream(Option::<&Gd<AudioStream>>::None),
+            AudioPlayer::Stream3D(player) => player.set_stream(Option::<&Gd<AudioStream>>::None),
+        }
+    }
+
     pub(crate) fn into_node(self) -> Gd<Node> {
         match self {
             AudioPlayer::Stream(player) => player.upcast(),
@@ -221,7 +237,7 @@ fn resume_audio_player(godot: &mut GodotAccess, handle: GodotNodeHandle) {
     }
 }
 
-pub(crate) fn stop_and_free_audio_player(godot: &mut GodotAccess, handle: GodotNodeHandle) {
+fn stop_and_free_audio_player(godot: &mut GodotAccess, handle: GodotNodeHandle) {
     let Some(mut player) = try_get_audio_player(godot, handle) else {
         return;
     };
@@ -235,6 +251,21 @@ pub(crate) fn stop_and_free_audio_player(godot: &mut GodotAccess, handle: GodotN
     trace!("Removed and freed audio node from scene tree");
 }
 
+/// Like [`stop_and_free_audio_player`], but hands the player back to the pool.
+pub(crate) fn release_audio_player(
+    godot: &mut GodotAccess,
+    pool: &mut HelperNodePool,
+    handle: GodotNodeHandle,
+) {
+    let Some(mut player) = try_get_audio_player(godot, handle) else {
+        return;
+    };
+
+    player.reset();
+    pool.release(godot, player.into_node());
+    trace!("Returned audio node to the helper pool");
+}
+
 impl ActiveTween {
     pub fn new_fade_in(target_volume: f32, tween: AudioTween) -> Self {
         Self {
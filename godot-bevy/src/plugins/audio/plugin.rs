@@ -1,15 +1,19 @@
 //! Main audio plugin and systems
 use crate::interop::{GodotAccess, GodotNodeHandle};
 use crate::plugins::assets::GodotResource;
-use crate::plugins::audio::output::{
-    AudioPlayer, stop_and_free_audio_player, try_get_audio_player,
+use crate::plugins::audio::listener::{
+    apply_listener_panning, assign_listener_nodes, follow_listeners, on_audio_listener_removed,
 };
+use crate::plugins::audio::output::{AudioPlayer, release_audio_player, try_get_audio_player};
 use crate::plugins::audio::{
     ActiveTween, AudioChannel, AudioChannelMarker, AudioCommand, AudioOutput, AudioPlayerType,
-    AudioSettings, ChannelId, ChannelState, MainAudioTrack, PlayCommand, SoundId, TweenType,
+    AudioSettings, ChannelId, ChannelState, MainAudioTrack, PlayCommand, SoundId,
+    SplitScreenPanning, TweenType,
 };
+use crate::plugins::core::{SyncStats, audio_active};
+use crate::plugins::node_pool::HelperNodePool;
 use crate::plugins::scene_tree::SceneTreeRef;
-use bevy_app::{App, Plugin, Update};
+use bevy_app::{App, Last, Plugin, Update};
 use bevy_asset::Assets;
 use bevy_ecs::prelude::Resource;
 use bevy_ecs::schedule::{IntoScheduleConfigs, SystemSet};
@@ -17,7 +21,6 @@ use bevy_ecs::system::{Res, ResMut};
 use bevy_math::{Vec2, Vec3};
 use bevy_time::Time;
 use godot::classes::{AudioStream, AudioStreamPlayer, AudioStreamPlayer2D, AudioStreamPlayer3D};
-use godot::obj::NewAlloc;
 use std::collections::{HashMap, VecDeque};
 use thiserror::Error;
 use tracing::{trace, warn};
@@ -40,12 +43,29 @@ impl Plugin for GodotAudioPlugin {
             .add_audio_channel::<MainAudioTrack>()
             .configure_sets(
                 Update,
-                AudioSystemSet::ProcessCommands.after(AudioSystemSet::CollectCommands),
+                (
+                    AudioSystemSet::CollectCommands.run_if(audio_active),
+                    AudioSystemSet::ProcessCommands
+                        .after(AudioSystemSet::CollectCommands)
+                        .run_if(audio_active),
+                ),
             )
             .add_systems(
                 Update,
                 audio_main_thread.in_set(AudioSystemSet::ProcessCommands),
-            );
+            )
+            .init_resource::<SplitScreenPanning>()
+            .add_systems(
+                Last,
+                (
+                    assign_listener_nodes,
+                    follow_listeners,
+                    apply_listener_panning,
+                )
+                    .chain()
+                    .run_if(audio_active),
+            )
+            .add_observer(on_audio_listener_removed);
     }
 }
 
@@ -103,16 +123,21 @@ struct PendingSoundOps {
 }
 
 /// System that applies queued audio commands using Godot APIs.
+#[allow(clippy::too_many_arguments)]
 fn audio_main_thread(
     mut audio_channels: ResMut<GodotAudioChannels>,
     mut audio_output: ResMut<AudioOutput>,
     mut assets: ResMut<Assets<GodotResource>>,
+    mut pool: ResMut<HelperNodePool>,
     mut scene_tree: SceneTreeRef,
     time: Res<Time>,
+    stats: Option<ResMut<SyncStats>>,
     mut godot: GodotAccess,
 ) {
+    crate::profile_scope!("audio_main_thread");
     let mut pending_ops: HashMap<SoundId, PendingSoundOps> = HashMap::new();
     let mut pending_stops: Vec<(SoundId, GodotNodeHandle)> = Vec::new();
+    let queued = audio_channels.command_queue.len();
 
     while let Some(command) = audio_channels.command_queue.pop_front() {
         match command {
@@ -120,6 +145,7 @@ fn audio_main_thread(
                 if process_play_command(
                     &play_cmd,
                     &mut assets,
+                    &mut pool,
                     &mut scene_tree,
                     &mut audio_output,
                     &mut godot,
@@ -212,6 +238,11 @@ fn audio_main_thread(
         }
     }
 
+    // A Play whose asset isn't loaded goes back on the queue and isn't counted.
+    if let Some(mut stats) = stats {
+        stats.deferred_commands += (queued - audio_channels.command_queue.len()) as u32;
+    }
+
     let delta = time.delta();
     let mut completed_tweens = Vec::new();
     let mut sounds_to_stop = Vec::new();
@@ -270,7 +301,7 @@ fn audio_main_thread(
     }
 
     for (sound_id, handle) in pending_stops {
-        stop_and_free_audio_player(&mut godot, handle);
+        release_audio_player(&mut godot, &mut pool, handle);
         trace!("Stopped sound: {:?}", sound_id);
     }
 
@@ -293,11 +324,8 @@ fn audio_main_thread(
 
         let is_playing = player.is_playing();
         if !is_playing {
-            let mut node = player.into_node();
-            if let Some(mut parent) = node.get_parent() {
-                parent.remove_child(&node);
-            }
-            node.queue_free();
+            player.reset();
+            pool.release(&mut godot, player.into_node());
             finished_sounds.push(sound_id);
         }
     }
@@ -350,6 +378,7 @@ fn apply_pending_ops(player: &mut AudioPlayer, ops: &PendingSoundOps) {
 fn process_play_command(
     play_cmd: &PlayCommand,
     assets: &mut Assets<GodotResource>,
+    pool: &mut HelperNodePool,
     scene_tree: &mut SceneTreeRef,
     output: &mut AudioOutput,
     godot: &mut GodotAccess,
@@ -383,13 +412,25 @@ fn process_play_command(
 
     // Create appropriate player based on type
     let player_handle = match &play_cmd.player_type {
-        AudioPlayerType::NonPositional => create_audio_player(audio_stream, &initial_settings),
-        AudioPlayerType::Spatial2D { position } => {
-            create_audio_player_2d(audio_stream, &initial_settings, *position)
-        }
-        AudioPlayerType::Spatial3D { position } => {
-            create_audio_player_3d(audio_stream, &initial_settings, *position)
+        AudioPlayerType::NonPositional => {
+            create_audio_player(pool, godot, audio_stream, &initial_settings)
         }
+        AudioPlayerType::Spatial2D { position } => create_audio_player_2d(
+            pool,
+            godot,
+            audio_stream,
+            &initial_settings,
+            *position,
+            output.panning_strength,
+        ),
+        AudioPlayerType::Spatial3D { position } => create_audio_player_3d(
+            pool,
+            godot,
+            audio_stream,
+            &initial_settings,
+            *position,
+            output.panning_strength,
+        ),
     };
 
     if let Some(handle) = player_handle {
@@ -435,10 +476,12 @@ fn process_play_command(
 }
 
 fn create_audio_player(
+    pool: &mut HelperNodePool,
+    godot: &mut GodotAccess,
     audio_stream: godot::obj::Gd<AudioStream>,
     settings: &AudioSettings,
 ) -> Option<GodotNodeHandle> {
-    let mut player = AudioStreamPlayer::new_alloc();
+    let mut player = pool.lease::<AudioStreamPlayer>(godot);
     player.set_stream(&audio_stream);
     player.set_volume_db(volume_to_db(settings.volume));
     player.set_pitch_scale(settings.pitch);
@@ -457,15 +500,19 @@ fn create_audio_player(
 }
 
 fn create_audio_player_2d(
+    pool: &mut HelperNodePool,
+    godot: &mut GodotAccess,
     audio_stream: godot::obj::Gd<AudioStream>,
     settings: &AudioSettings,
     position: Vec2,
+    panning_strength: Option<f32>,
 ) -> Option<GodotNodeHandle> {
-    let mut player = AudioStreamPlayer2D::new_alloc();
+    let mut player = pool.lease::<AudioStreamPlayer2D>(godot);
     player.set_stream(&audio_stream);
     player.set_volume_db(volume_to_db(settings.volume));
     player.set_pitch_scale(settings.pitch);
     player.set_position(godot::prelude::Vector2::new(position.x, position.y));
+    player.set_panning_strength(panning_strength.unwrap_or(1.0));
 
     // Don't play yet - need to add to scene tree first
     Some(GodotNodeHandle::new(
@@ -474,17 +521,21 @@ fn create_audio_player_2d(
 }
 
 fn create_audio_player_3d(
+    pool: &mut HelperNodePool,
+    godot: &mut GodotAccess,
     audio_stream: godot::obj::Gd<AudioStream>,
     settings: &AudioSettings,
     position: Vec3,
+    panning_strength: Option<f32>,
 ) -> Option<GodotNodeHandle> {
-    let mut player = AudioStreamPlayer3D::new_alloc();
+    let mut player = pool.lease::<AudioStreamPlayer3D>(godot);
     player.set_stream(&audio_stream);
     player.set_volume_db(volume_to_db(settings.volume));
     player.set_pitch_scale(settings.pitch);
     player.set_position(godot::prelude::Vector3::new(
         position.x, position.y, position.z,
     ));
+    player.set_panning_strength(panning_strength.unwrap_or(1.0));
 
     // Don't play yet - need to add to scene tree first
     Some(GodotNodeHandle::new(
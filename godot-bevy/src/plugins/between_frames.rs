@@ -0,0 +1,249 @@
+//! Run part of the ECS off Godot's main thread.
+//!
+//! With [`BetweenFramesPlugin`], systems in the [`BetweenFrames`] schedule run on
+//! a background thread after each Bevy frame, while Godot renders and processes
+//! its other nodes. Their results are in the world by the next frame's `First`.
+//! `Update` and the other main schedules are unchanged and still run on the main
+//! thread; only work moved into [`BetweenFrames`] leaves it. `Update` can't move
+//! as a whole: many of its systems, godot-bevy's own included, take `GodotAccess`,
+//! `SceneTreeRef` or other non-send parameters, which only run on the main thread.
+//!
+//! ```ignore
+//! app.add_plugins(BetweenFramesPlugin::default())
+//!     .add_systems(BetweenFrames, (steer_crowd, plan_paths).chain())
+//!     .add_systems(Update, apply_crowd_to_nodes);
+//!
+//! fn plan_paths(mut agents: Query<(&mut Path, &Goal)>, godot: Res<MainThreadCommands>) {
+//!     // Pure ECS work here. A Godot call goes through the channel instead:
+//!     let queued = godot.queue(|world| {
+//!         world.resource_mut::<PathDebugDraw>().dirty = true;
+//!     });
+//!     if queued.is_err() {
+//!         // Full: keep the request and try again next frame.
+//!     }
+//! }
+//! ```
+//!
+//! Godot objects may only be touched from the main thread, so the schedule's
+//! systems may not use `GodotAccess`, `SceneTreeRef` or any other non-send
+//! parameter; Bevy panics if one tries. They queue Godot mutations on
+//! [`MainThreadCommands`] instead, which are applied on the main thread in the next
+//! frame's `First`, before the scene tree is read. The channel is bounded, and
+//! waiting for room would deadlock the frame that drains it, so a full queue
+//! refuses the command with [`MainThreadCommandError::Full`] and leaves retrying
+//! to the caller.
+//!
+//! The world is on the background thread between frames, so callers that reach
+//! into it from outside a Bevy frame (an input event reaching `InputReact`,
+//! `BevyApp::get_app_mut`) first wait for the schedule to finish, and
+//! `BevyApp::get_app`, which can't wait, returns `None`. `send_event` keeps
+//! working throughout. On the web the schedule runs in `Last` instead.
+
+use std::any::Any;
+
+use bevy_app::{App, First, Plugin};
+use bevy_ecs::prelude::Resource;
+use bevy_ecs::schedule::{IntoScheduleConfigs, Schedule, ScheduleLabel};
+use bevy_ecs::world::World;
+use crossbeam_channel::{Receiver, Sender, TrySendError};
+use thiserror::Error;
+
+use crate::plugins::core::GodotSyncSet;
+
+/// The schedule run off the main thread. See the [module docs](self).
+#[derive(ScheduleLabel, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BetweenFrames;
+
+/// Plugin running [`BetweenFrames`] on a background thread. See the
+/// [module docs](self).
+#[derive(Debug, Clone)]
+pub struct BetweenFramesPlugin {
+    /// How many [`MainThreadCommands`] may wait for the next frame.
+    pub command_capacity: usize,
+}
+
+impl Default for BetweenFramesPlugin {
+    fn default() -> Self {
+        Self {
+            command_capacity: 1024,
+        }
+    }
+}
+
+impl Plugin for BetweenFramesPlugin {
+    fn build(&self, app: &mut App) {
+        let (sender, receiver) = crossbeam_channel::bounded(self.command_capacity);
+        app.add_schedule(Schedule::new(BetweenFrames))
+            .insert_resource(MainThreadCommands(sender))
+            .insert_resource(MainThreadCommandQueue(receiver))
+            .add_systems(
+                First,
+                apply_main_thread_commands.in_set(GodotSyncSet::BeforeTreeUpdate),
+            );
+
+        #[cfg(not(target_arch = "wasm32"))]
+        app.insert_resource(BetweenFramesEnabled);
+        #[cfg(target_arch = "wasm32")]
+        app.add_systems(bevy_app::Last, |world: &mut World| {
+            world.run_schedule(BetweenFrames)
+        });
+    }
+}
+
+type MainThreadCommand = Box<dyn FnOnce(&mut World) + Send>;
+
+/// Queue work for the main thread from any system, including [`BetweenFrames`]
+/// ones. Commands run with the whole world in the next frame's `First`, where
+/// Godot objects may be used.
+#[derive(Resource, Clone)]
+pub struct MainThreadCommands(Sender<MainThreadCommand>);
+
+impl MainThreadCommands {
+    /// Queue `command` for the next frame's `First`. On error the command was
+    /// not queued and will not run.
+    pub fn queue(
+        &self,
+        command: impl FnOnce(&mut World) + Send + 'static,
+    ) -> Result<(), MainThreadCommandError> {
+        self.0.try_send(Box::new(command)).map_err(|err| match err {
+            TrySendError::Full(_) => MainThreadCommandError::Full,
+            TrySendError::Disconnected(_) => MainThreadCommandError::Closed,
+        })
+    }
+}
+
+/// Why [`MainThreadCommands::queue`] refused a command.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+pub enum MainThreadCommandError {
+    /// `command_capacity` commands already wait for the next frame. Retry then,
+    /// or raise [`BetweenFramesPlugin::command_capacity`].
+    #[error("main-thread command queue is full")]
+    Full,
+    /// The app that drained the queue is gone.
+    #[error("main-thread command queue is closed")]
+    Closed,
+}
+
+#[derive(Resource)]
+struct MainThreadCommandQueue(Receiver<MainThreadCommand>);
+
+/// Tells `BevyApp` to hand the world to its [`BetweenFramesWorker`] after each frame.
+#[derive(Resource)]
+pub(crate) struct BetweenFramesEnabled;
+
+fn apply_main_thread_commands(world: &mut World) {
+    let queue = world.resource::<MainThreadCommandQueue>().0.clone();
+    for command in queue.try_iter() {
+        command(world);
+    }
+}
+
+type WorkerResult = (World, Option<Box<dyn Any + Send>>);
+
+/// The background thread [`BetweenFrames`] runs on, owned by a `BevyApp`.
+pub(crate) struct BetweenFramesWorker {
+    to_worker: Sender<World>,
+    from_worker: Receiver<WorkerResult>,
+    running: bool,
+}
+
+impl BetweenFramesWorker {
+    pub(crate) fn spawn() -> Self {
+        let (to_worker, worlds) = crossbeam_channel::bounded::<World>(1);
+        let (results, from_worker) = crossbeam_channel::bounded::<WorkerResult>(1);
+        std::thread::Builder::new()
+            .name("bevy-between-frames".into())
+            .spawn(move || {
+                for mut world in worlds {
+                    let panic = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                        world.run_schedule(BetweenFrames)
+                    }))
+                    .err();
+                    if results.send((world, panic)).is_err() {
+                        break;
+                    }
+                }
+            })
+            .expect("failed to spawn the BetweenFrames thread");
+        Self {
+            to_worker,
+            from_worker,
+            running: false,
+        }
+    }
+
+    /// Run [`BetweenFrames`] on `world` in the background.
+    pub(crate) fn start(&mut self, world: World) {
+        self.running = self.to_worker.send(world).is_ok();
+    }
+
+    /// True from [`start`](Self::start) until [`join`](Self::join): the world is
+    /// on the background thread.
+    pub(crate) fn is_running(&self) -> bool {
+        self.running
+    }
+
+    /// Wait for the running schedule, if any, and take the world back with the
+    /// panic it raised.
+    pub(crate) fn join(&mut self) -> Option<WorkerResult> {
+        if !std::mem::take(&mut self.running) {
+            return None;
+        }
+        self.from_worker.recv().ok()
+    }
+}
+
+impl Drop for BetweenFramesWorker {
+    // Non-send resources must be dropped on the thread that made them.
+    fn drop(&mut self) {
+        drop(self.join());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_ecs::system::ResMut;
+    use std::thread::ThreadId;
+
+    #[derive(Resource, Default)]
+    struct RanOn(Option<ThreadId>);
+
+    #[test]
+    fn worker_runs_the_schedule_and_returns_the_world() {
+        let mut world = World::new();
+        world.init_resource::<RanOn>();
+        let mut schedule = Schedule::new(BetweenFrames);
+        schedule.add_systems(|mut ran: ResMut<RanOn>| {
+            ran.0 = Some(std::thread::current().id());
+        });
+        world.add_schedule(schedule);
+
+        let mut worker = BetweenFramesWorker::spawn();
+        worker.start(world);
+        assert!(worker.is_running());
+        let (world, panic) = worker.join().expect("the world comes back");
+        assert!(panic.is_none());
+        assert!(!worker.is_running());
+        assert!(worker.join().is_none());
+
+        let ran_on = world.resource::<RanOn>().0.expect("the system ran");
+        assert_ne!(ran_on, std::thread::current().id());
+    }
+
+    #[test]
+    fn full_queue_refuses_commands() {
+        let (sender, receiver) = crossbeam_channel::bounded(1);
+        let commands = MainThreadCommands(sender);
+        assert_eq!(commands.queue(|_| {}), Ok(()));
+        assert_eq!(commands.queue(|_| {}), Err(MainThreadCommandError::Full));
+
+        let mut world = World::new();
+        world.insert_resource(MainThreadCommandQueue(receiver));
+        apply_main_thread_commands(&mut world);
+        assert_eq!(commands.queue(|_| {}), Ok(()));
+
+        drop(world);
+        assert_eq!(commands.queue(|_| {}), Err(MainThreadCommandError::Closed));
+    }
+}
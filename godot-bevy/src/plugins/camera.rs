@@ -0,0 +1,367 @@
+//! Follow, shake, zoom and switch `Camera2D`/`Camera3D` nodes from components.
+//!
+//! With [`GodotCameraPlugin`], a camera entity can carry:
+//!
+//! - [`CameraFollow`], moving its `Transform` toward another entity's, with
+//!   optional smoothing. The move goes through transform sync like any other.
+//! - [`CameraShake`], a trauma value that decays over time and jitters the
+//!   camera's offset (`offset` in 2D, `h_offset`/`v_offset` in 3D), so the shake
+//!   never accumulates into its position.
+//! - [`CameraZoom`], easing `zoom` in 2D, and the field of view (or orthogonal
+//!   size) in 3D.
+//! - [`ActiveCamera`], making its camera the current one. Inserting it on
+//!   another camera moves the marker there.
+//!
+//! ```ignore
+//! fn setup(
+//!     mut commands: Commands,
+//!     player: Single<Entity, With<Player>>,
+//!     camera: Single<Entity, With<MainCamera>>,
+//! ) {
+//!     commands.entity(*camera).insert((
+//!         CameraFollow::new(*player).with_smoothing(0.1),
+//!         CameraShake::new(Vec2::splat(12.0)),
+//!         CameraZoom::new(1.0).with_smoothing(0.25),
+//!         ActiveCamera,
+//!     ));
+//! }
+//!
+//! fn on_explosion(mut shakes: Query<&mut CameraShake>) {
+//!     shakes.iter_mut().for_each(|mut shake| shake.add_trauma(0.6));
+//! }
+//! ```
+//!
+//! Following reads and writes local `Transform`s, so the camera and its target
+//! should share a parent (or both be top-level in the scene).
+
+use bevy_app::{App, FixedLast, Last, Plugin};
+use bevy_ecs::component::Component;
+use bevy_ecs::entity::Entity;
+use bevy_ecs::query::{Added, Or, With, Without};
+use bevy_ecs::schedule::IntoScheduleConfigs;
+use bevy_ecs::system::{Commands, Query, Res};
+use bevy_math::{Vec2, Vec3};
+use bevy_time::Time;
+use bevy_transform::components::Transform;
+use godot::builtin::Vector2;
+use godot::classes::{Camera2D, Camera3D, camera_3d};
+use godot::obj::Gd;
+use tracing::warn;
+
+use crate::interop::{GodotAccess, GodotNodeHandle};
+use crate::plugins::core::GodotSyncSet;
+
+/// Plugin driving the camera components. See the [module docs](self).
+#[derive(Default)]
+pub struct GodotCameraPlugin;
+
+impl Plugin for GodotCameraPlugin {
+    fn build(&self, app: &mut App) {
+        // Following runs at the rate transforms are written, right before the write.
+        app.add_systems(
+            FixedLast,
+            follow_targets.in_set(GodotSyncSet::BeforeTransformFlush),
+        )
+        .add_systems(
+            Last,
+            (switch_active_camera, apply_camera_shake, apply_camera_zoom),
+        );
+    }
+}
+
+#[cfg(debug_assertions)]
+crate::inventory::submit! {
+    crate::watchdog::PluginRequirement::new::<CameraFollow, GodotCameraPlugin>()
+}
+
+#[cfg(debug_assertions)]
+crate::inventory::submit! {
+    crate::watchdog::PluginRequirement::new::<CameraShake, GodotCameraPlugin>()
+}
+
+#[cfg(debug_assertions)]
+crate::inventory::submit! {
+    crate::watchdog::PluginRequirement::new::<CameraZoom, GodotCameraPlugin>()
+}
+
+#[cfg(debug_assertions)]
+crate::inventory::submit! {
+    crate::watchdog::PluginRequirement::new::<ActiveCamera, GodotCameraPlugin>()
+}
+
+/// Move this camera's `Transform` toward `target`'s, plus `offset`.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct CameraFollow {
+    pub target: Entity,
+    /// Seconds for the camera to close half the distance. `0` snaps to the target.
+    pub smoothing: f32,
+    pub offset: Vec3,
+}
+
+impl CameraFollow {
+    pub fn new(target: Entity) -> Self {
+        Self {
+            target,
+            smoothing: 0.0,
+            offset: Vec3::ZERO,
+        }
+    }
+
+    pub fn with_smoothing(mut self, half_life: f32) -> Self {
+        self.smoothing = half_life;
+        self
+    }
+
+    pub fn with_offset(mut self, offset: Vec3) -> Self {
+        self.offset = offset;
+        self
+    }
+}
+
+/// Trauma-based screen shake. The offset grows with the square of `trauma`, so
+/// small hits barely move the camera and big ones shake it hard.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct CameraShake {
+    /// From `0` (still) to `1` (full shake).
+    pub trauma: f32,
+    /// Trauma lost per second.
+    pub decay: f32,
+    /// Offset at full trauma: pixels in 2D, units in 3D.
+    pub max_offset: Vec2,
+    /// How fast the offset wanders, in cycles per second.
+    pub frequency: f32,
+    applied: bool,
+}
+
+impl CameraShake {
+    pub fn new(max_offset: Vec2) -> Self {
+        Self {
+            trauma: 0.0,
+            decay: 1.0,
+            max_offset,
+            frequency: 15.0,
+            applied: false,
+        }
+    }
+
+    pub fn with_decay(mut self, per_second: f32) -> Self {
+        self.decay = per_second;
+        self
+    }
+
+    pub fn with_frequency(mut self, frequency: f32) -> Self {
+        self.frequency = frequency;
+        self
+    }
+
+    /// Add to `trauma`, up to `1`.
+    pub fn add_trauma(&mut self, amount: f32) {
+        self.trauma = (self.trauma + amount).clamp(0.0, 1.0);
+    }
+
+    /// The offset at `elapsed` seconds for the current trauma.
+    pub fn offset(&self, elapsed: f32) -> Vec2 {
+        let strength = self.trauma * self.trauma;
+        let t = elapsed * self.frequency;
+        Vec2::new(wobble(t, 0.0), wobble(t, 17.0)) * self.max_offset * strength
+    }
+}
+
+/// Ease the camera to `zoom`, where `2.0` shows things twice as big.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct CameraZoom {
+    pub zoom: f32,
+    /// Seconds to close half the difference. `0` zooms at once.
+    pub smoothing: f32,
+    current: Option<f32>,
+    /// The 3D camera's field of view or size at zoom `1`.
+    base: Option<f32>,
+}
+
+impl CameraZoom {
+    pub fn new(zoom: f32) -> Self {
+        Self {
+            zoom,
+            smoothing: 0.0,
+            current: None,
+            base: None,
+        }
+    }
+
+    pub fn with_smoothing(mut self, half_life: f32) -> Self {
+        self.smoothing = half_life;
+        self
+    }
+
+    /// The zoom the camera shows right now.
+    pub fn current(&self) -> f32 {
+        self.current.unwrap_or(self.zoom)
+    }
+}
+
+/// Makes the entity's camera the current one. Only one entity keeps the marker:
+/// the camera it was inserted on last.
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ActiveCamera;
+
+/// A `Camera2D` or `Camera3D`.
+enum Camera {
+    TwoD(Gd<Camera2D>),
+    ThreeD(Gd<Camera3D>),
+}
+
+impl Camera {
+    fn get(godot: &mut GodotAccess, handle: GodotNodeHandle) -> Option<Self> {
+        godot
+            .try_get::<Camera2D>(handle)
+            .map(Self::TwoD)
+            .or_else(|| godot.try_get::<Camera3D>(handle).map(Self::ThreeD))
+    }
+}
+
+/// The fraction of the remaining distance to cover in `dt` seconds.
+fn smoothing_factor(half_life: f32, dt: f32) -> f32 {
+    if half_life <= 0.0 {
+        1.0
+    } else {
+        1.0 - 0.5f32.powf(dt / half_life)
+    }
+}
+
+/// Smooth noise in `-1..=1`, different for each `seed`.
+fn wobble(t: f32, seed: f32) -> f32 {
+    ((t + seed).sin() + 0.5 * (2.3 * t + 1.7 * seed).sin()) / 1.5
+}
+
+fn follow_targets(
+    mut cameras: Query<(&CameraFollow, &mut Transform)>,
+    targets: Query<&Transform, Without<CameraFollow>>,
+    time: Res<Time>,
+) {
+    for (follow, mut transform) in &mut cameras {
+        // Despawned targets leave the camera where it is.
+        let Ok(target) = targets.get(follow.target) else {
+            continue;
+        };
+        let goal = target.translation + follow.offset;
+        let factor = smoothing_factor(follow.smoothing, time.delta_secs());
+        let translation = transform.translation.lerp(goal, factor);
+        if transform.translation != translation {
+            transform.translation = translation;
+        }
+    }
+}
+
+type NewActiveCamera = (
+    With<ActiveCamera>,
+    Or<(Added<ActiveCamera>, Added<GodotNodeHandle>)>,
+);
+
+fn switch_active_camera(
+    added: Query<(Entity, &GodotNodeHandle), NewActiveCamera>,
+    active: Query<Entity, With<ActiveCamera>>,
+    mut commands: Commands,
+    mut godot: GodotAccess,
+) {
+    let Some((entity, handle)) = added.iter().last() else {
+        return;
+    };
+    for other in &active {
+        if other != entity {
+            commands.entity(other).remove::<ActiveCamera>();
+        }
+    }
+    match Camera::get(&mut godot, *handle) {
+        Some(Camera::TwoD(mut camera)) => camera.make_current(),
+        Some(Camera::ThreeD(mut camera)) => camera.make_current(),
+        None => warn!("ActiveCamera on {entity}, whose node isn't a Camera2D/3D"),
+    }
+}
+
+fn apply_camera_shake(
+    mut cameras: Query<(&GodotNodeHandle, &mut CameraShake)>,
+    time: Res<Time>,
+    mut godot: GodotAccess,
+) {
+    for (handle, mut shake) in &mut cameras {
+        // Still cameras are left alone after the offset is reset once.
+        if shake.trauma <= 0.0 && !shake.applied {
+            continue;
+        }
+        let offset = shake.offset(time.elapsed_secs());
+        match Camera::get(&mut godot, *handle) {
+            Some(Camera::TwoD(mut camera)) => camera.set_offset(Vector2::new(offset.x, offset.y)),
+            Some(Camera::ThreeD(mut camera)) => {
+                camera.set_h_offset(offset.x);
+                camera.set_v_offset(offset.y);
+            }
+            None => continue,
+        }
+        shake.applied = shake.trauma > 0.0;
+        shake.trauma = (shake.trauma - shake.decay * time.delta_secs()).max(0.0);
+    }
+}
+
+fn apply_camera_zoom(
+    mut cameras: Query<(&GodotNodeHandle, &mut CameraZoom)>,
+    time: Res<Time>,
+    mut godot: GodotAccess,
+) {
+    for (handle, mut zoom) in &mut cameras {
+        let previous = zoom.current;
+        let factor = smoothing_factor(zoom.smoothing, time.delta_secs());
+        let current = match previous {
+            Some(current) => current + (zoom.zoom - current) * factor,
+            None => zoom.zoom,
+        };
+        if previous == Some(current) || current <= 0.0 {
+            continue;
+        }
+        match Camera::get(&mut godot, *handle) {
+            Some(Camera::TwoD(mut camera)) => camera.set_zoom(Vector2::splat(current)),
+            Some(Camera::ThreeD(mut camera)) => {
+                let perspective = camera.get_projection() == camera_3d::ProjectionType::PERSPECTIVE;
+                let base = *zoom.base.get_or_insert_with(|| {
+                    if perspective {
+                        camera.get_fov()
+                    } else {
+                        camera.get_size()
+                    }
+                });
+                if perspective {
+                    camera.set_fov((base / current).clamp(1.0, 179.0));
+                } else {
+                    camera.set_size(base / current);
+                }
+            }
+            None => continue,
+        }
+        zoom.current = Some(current);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn smoothing_halves_the_gap_per_half_life() {
+        assert_eq!(smoothing_factor(0.0, 0.016), 1.0);
+        assert!((smoothing_factor(0.5, 0.5) - 0.5).abs() < 1e-6);
+        assert!((smoothing_factor(0.5, 1.0) - 0.75).abs() < 1e-6);
+    }
+
+    #[test]
+    fn shake_scales_with_trauma_squared() {
+        let mut shake = CameraShake::new(Vec2::new(10.0, 20.0));
+        assert_eq!(shake.offset(1.3), Vec2::ZERO);
+
+        shake.add_trauma(0.5);
+        let half = shake.offset(1.3);
+        shake.add_trauma(2.0);
+        assert_eq!(shake.trauma, 1.0);
+        let full = shake.offset(1.3);
+        assert!((full * 0.25 - half).length() < 1e-5);
+        assert!(full.x.abs() <= 10.0 && full.y.abs() <= 20.0);
+    }
+}
@@ -0,0 +1,298 @@
+//! Additive sub-scene streaming for open worlds.
+//!
+//! Each [`StreamingChunk`] entity describes a sub-scene and where it sits in the
+//! world. Every frame the [`ChunkStreamingPolicy`] decides which chunks should be
+//! resident given the [`StreamingFocus`] positions; chunks are spawned through
+//! [`GodotScene`] and every entity mirrored from their nodes is tagged with
+//! [`ChunkMember`], so unloading a chunk despawns exactly its entities and frees
+//! its nodes.
+
+use bevy_app::{App, Plugin, PreUpdate, Update};
+use bevy_ecs::{
+    component::Component,
+    entity::Entity,
+    event::Event,
+    prelude::Resource,
+    query::{Added, With, Without},
+    system::{Commands, Query, Res},
+};
+use bevy_math::Vec3;
+use bevy_transform::components::Transform;
+use std::sync::Arc;
+use tracing::debug;
+
+use crate::interop::GodotNodeHandle;
+use crate::plugins::packed_scene::{GodotPackedScenePlugin, GodotScene};
+use crate::plugins::scene_tree::GodotChildOf;
+
+/// Plugin that streams [`StreamingChunk`] sub-scenes in and out around
+/// [`StreamingFocus`] entities. Adds `GodotPackedScenePlugin` if it is missing.
+///
+/// ```ignore
+/// app.add_plugins(ChunkStreamingPlugin::default());
+///
+/// fn setup(mut commands: Commands) {
+///     commands.spawn(StreamingChunk::new("res://world/chunk_0_0.tscn", Vec3::ZERO));
+///     commands.spawn(StreamingChunk::new("res://world/chunk_1_0.tscn", Vec3::X * 64.0));
+/// }
+///
+/// // on the player entity:
+/// commands.entity(player).insert(StreamingFocus);
+/// ```
+pub struct ChunkStreamingPlugin {
+    policy: Arc<dyn ChunkStreamingPolicy>,
+}
+
+impl Default for ChunkStreamingPlugin {
+    fn default() -> Self {
+        Self::with_policy(DistancePolicy::default())
+    }
+}
+
+impl ChunkStreamingPlugin {
+    /// Use a custom policy instead of the default [`DistancePolicy`].
+    pub fn with_policy(policy: impl ChunkStreamingPolicy) -> Self {
+        Self {
+            policy: Arc::new(policy),
+        }
+    }
+}
+
+impl Plugin for ChunkStreamingPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<GodotPackedScenePlugin>() {
+            app.add_plugins(GodotPackedScenePlugin);
+        }
+        app.insert_resource(StreamingPolicy(self.policy.clone()))
+            .add_systems(PreUpdate, tag_chunk_members)
+            .add_systems(Update, stream_chunks);
+    }
+}
+
+/// Decides which chunks should be resident. Called once per chunk per frame.
+pub trait ChunkStreamingPolicy: Send + Sync + 'static {
+    /// Whether `chunk` should be loaded, given whether it currently is and the
+    /// world positions of every [`StreamingFocus`].
+    fn wants_loaded(&self, chunk: &StreamingChunk, loaded: bool, focus: &[Vec3]) -> bool;
+}
+
+/// Loads chunks whose center is within `load_radius` of any focus and unloads them
+/// once every focus is beyond `unload_radius`. Keep `unload_radius` larger than
+/// `load_radius` so a focus on the boundary doesn't thrash a chunk in and out.
+#[derive(Debug, Clone, Copy)]
+pub struct DistancePolicy {
+    pub load_radius: f32,
+    pub unload_radius: f32,
+}
+
+impl Default for DistancePolicy {
+    fn default() -> Self {
+        Self {
+            load_radius: 100.0,
+            unload_radius: 120.0,
+        }
+    }
+}
+
+impl ChunkStreamingPolicy for DistancePolicy {
+    fn wants_loaded(&self, chunk: &StreamingChunk, loaded: bool, focus: &[Vec3]) -> bool {
+        let radius = if loaded {
+            self.unload_radius
+        } else {
+            self.load_radius
+        };
+        focus
+            .iter()
+            .any(|position| position.distance_squared(chunk.center) <= radius * radius)
+    }
+}
+
+/// The active streaming policy. Replace it to change policy at runtime.
+#[derive(Resource, Clone)]
+pub struct StreamingPolicy(pub Arc<dyn ChunkStreamingPolicy>);
+
+/// A streamable sub-scene. Spawn one Bevy-only entity per chunk; the loaded scene
+/// instance gets its own entity, so this one persists across load/unload cycles.
+#[derive(Component, Debug, Clone)]
+pub struct StreamingChunk {
+    /// Scene file instanced when the chunk loads.
+    pub scene: String,
+    /// Point the policy measures focus distance against.
+    pub center: Vec3,
+    /// Node the chunk is instanced under; the scene root when `None`.
+    pub parent: Option<GodotNodeHandle>,
+    loaded: Option<Entity>,
+}
+
+impl StreamingChunk {
+    pub fn new(scene: impl Into<String>, center: Vec3) -> Self {
+        Self {
+            scene: scene.into(),
+            center,
+            parent: None,
+            loaded: None,
+        }
+    }
+
+    pub fn with_parent(mut self, parent: GodotNodeHandle) -> Self {
+        self.parent = Some(parent);
+        self
+    }
+
+    /// The entity of the loaded scene's root node, while the chunk is resident.
+    pub fn loaded_root(&self) -> Option<Entity> {
+        self.loaded
+    }
+}
+
+/// Marks an entity whose position drives streaming, e.g. the player or camera.
+#[derive(Component, Debug, Default, Clone, Copy)]
+pub struct StreamingFocus;
+
+/// Tags every entity that belongs to a loaded chunk with the chunk's entity.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChunkMember(pub Entity);
+
+/// Triggered when a chunk's scene has been requested for spawning.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct ChunkLoaded {
+    pub chunk: Entity,
+}
+
+/// Triggered when a chunk's entities have been despawned and its nodes freed.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct ChunkUnloaded {
+    pub chunk: Entity,
+}
+
+fn stream_chunks(
+    mut commands: Commands,
+    policy: Res<StreamingPolicy>,
+    focus: Query<&Transform, With<StreamingFocus>>,
+    mut chunks: Query<(Entity, &mut StreamingChunk)>,
+    members: Query<(Entity, &ChunkMember)>,
+) {
+    let focus: Vec<Vec3> = focus.iter().map(|t| t.translation).collect();
+
+    for (chunk_entity, mut chunk) in chunks.iter_mut() {
+        let loaded = chunk.loaded.is_some();
+        let wanted = policy.0.wants_loaded(&chunk, loaded, &focus);
+        if wanted == loaded {
+            continue;
+        }
+
+        if wanted {
+            let mut scene = GodotScene::from_path(&chunk.scene);
+            if let Some(parent) = chunk.parent {
+                scene = scene.with_parent(parent);
+            }
+            let root = commands.spawn((scene, ChunkMember(chunk_entity))).id();
+            chunk.loaded = Some(root);
+            debug!("Loading chunk {} ({chunk_entity:?})", chunk.scene);
+            commands.trigger(ChunkLoaded {
+                chunk: chunk_entity,
+            });
+        } else {
+            // Every member goes, not just the root, so the chunk unloads cleanly
+            // even with SceneTreeConfig::auto_despawn_children off. Despawning a
+            // GodotNodeHandle frees its node.
+            for (entity, member) in members.iter() {
+                if member.0 == chunk_entity {
+                    commands.entity(entity).try_despawn();
+                }
+            }
+            chunk.loaded = None;
+            debug!("Unloading chunk {} ({chunk_entity:?})", chunk.scene);
+            commands.trigger(ChunkUnloaded {
+                chunk: chunk_entity,
+            });
+        }
+    }
+}
+
+/// Propagate `ChunkMember` down `GodotChildOf` to entities the scene tree just
+/// mirrored from a chunk's nodes. Runs after `First`, where those entities spawn.
+fn tag_chunk_members(
+    mut commands: Commands,
+    new_children: Query<Entity, (Added<GodotChildOf>, Without<ChunkMember>)>,
+    parents: Query<&GodotChildOf>,
+    members: Query<&ChunkMember>,
+) {
+    for entity in new_children.iter() {
+        let mut current = entity;
+        while let Ok(child_of) = parents.get(current) {
+            current = child_of.get();
+            if let Ok(member) = members.get(current) {
+                commands.entity(entity).insert(*member);
+                break;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn app() -> App {
+        let mut app = App::new();
+        app.insert_resource(StreamingPolicy(Arc::new(DistancePolicy {
+            load_radius: 10.0,
+            unload_radius: 15.0,
+        })))
+        .add_systems(Update, stream_chunks);
+        app
+    }
+
+    #[test]
+    fn distance_policy_has_hysteresis() {
+        let policy = DistancePolicy {
+            load_radius: 10.0,
+            unload_radius: 15.0,
+        };
+        let chunk = StreamingChunk::new("res://c.tscn", Vec3::ZERO);
+        let focus = [Vec3::X * 12.0];
+        assert!(!policy.wants_loaded(&chunk, false, &focus));
+        assert!(policy.wants_loaded(&chunk, true, &focus));
+        assert!(!policy.wants_loaded(&chunk, true, &[]));
+    }
+
+    #[test]
+    fn chunk_loads_near_focus_and_unload_despawns_members() {
+        let mut app = app();
+        let chunk = app
+            .world_mut()
+            .spawn(StreamingChunk::new("res://c.tscn", Vec3::ZERO))
+            .id();
+        let focus = app
+            .world_mut()
+            .spawn((StreamingFocus, Transform::default()))
+            .id();
+
+        app.update();
+        let root = app
+            .world()
+            .get::<StreamingChunk>(chunk)
+            .and_then(StreamingChunk::loaded_root)
+            .expect("chunk loaded");
+        assert_eq!(
+            app.world().get::<ChunkMember>(root),
+            Some(&ChunkMember(chunk))
+        );
+        let member = app.world_mut().spawn(ChunkMember(chunk)).id();
+
+        app.world_mut()
+            .entity_mut(focus)
+            .insert(Transform::from_xyz(100.0, 0.0, 0.0));
+        app.update();
+        assert!(app.world().get_entity(root).is_err());
+        assert!(app.world().get_entity(member).is_err());
+        assert!(
+            app.world()
+                .get::<StreamingChunk>(chunk)
+                .unwrap()
+                .loaded_root()
+                .is_none()
+        );
+    }
+}
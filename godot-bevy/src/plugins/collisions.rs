@@ -52,6 +52,7 @@
 //! ```
 
 use crate::interop::GodotNodeHandle;
+use crate::plugins::core::collisions_active;
 use crate::plugins::scene_tree::NodeEntityIndex;
 use bevy_app::{App, FixedFirst, Plugin};
 use bevy_ecs::{
@@ -470,7 +471,8 @@ impl Plugin for GodotCollisionsPlugin {
                 (
                     process_godot_collisions,
                     trigger_collision_observers.after(process_godot_collisions),
-                ),
+                )
+                    .run_if(collisions_active),
             );
     }
 }
@@ -483,6 +485,7 @@ fn process_godot_collisions(
     mut ended_writer: MessageWriter<CollisionEnded>,
     node_index: Res<NodeEntityIndex>,
 ) {
+    crate::profile_scope!("process_godot_collisions");
     // Clear per-frame data
     collision_state.begin_frame();
 
@@ -546,6 +549,7 @@ fn trigger_collision_observers(
     mut started_reader: MessageReader<CollisionStarted>,
     mut ended_reader: MessageReader<CollisionEnded>,
 ) {
+    crate::profile_scope!("trigger_collision_observers");
     for &event in started_reader.read() {
         commands.trigger(event);
     }
@@ -708,4 +712,21 @@ mod tests {
         assert_eq!(state.len(), 1);
         assert_eq!(state.started_this_frame.len(), 1);
     }
+
+    #[test]
+    fn test_collisions_pause_with_toggle() {
+        use crate::plugins::core::PluginToggles;
+        use bevy_ecs::system::RunSystemOnce;
+        use bevy_ecs::world::World;
+
+        let mut world = World::new();
+        // Without the resource nothing is paused.
+        assert!(world.run_system_once(collisions_active).unwrap());
+
+        world.insert_resource(PluginToggles {
+            collisions: false,
+            ..Default::default()
+        });
+        assert!(!world.run_system_once(collisions_active).unwrap());
+    }
 }
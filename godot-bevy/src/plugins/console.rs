@@ -0,0 +1,596 @@
+//! Drop-down developer console built from Godot `Control`s, with commands
+//! implemented against the Bevy `World`.
+//!
+//! The console is light enough to leave in shipped builds, where the editor
+//! inspector isn't available. Press the toggle key (backtick by default) to open
+//! it; Up/Down walk the history and Tab completes command names.
+//!
+//! ```ignore
+//! app.add_plugins(GodotConsolePlugin::default())
+//!     .add_systems(Startup, |mut console: ResMut<GodotConsole>| {
+//!         console.register("spawn_mob", |args, world| {
+//!             let count: usize = args.first().map_or(Ok(1), |n| n.parse()).map_err(|_| "usage: spawn_mob [count]")?;
+//!             for _ in 0..count {
+//!                 world.spawn(Mob::default());
+//!             }
+//!             Ok(format!("spawned {count} mobs"))
+//!         });
+//!     });
+//! ```
+//!
+//! A command gets its arguments (split on whitespace, `"double quotes"` group)
+//! and exclusive `World` access, so it can also run a system with
+//! `world.run_system_cached_with`. `Ok` text is printed as-is, `Err` text as an
+//! error. `help` and `clear` are built in.
+
+use std::sync::Arc;
+
+use bevy_app::{App, Last, Plugin};
+use bevy_ecs::change_detection::Mut;
+use bevy_ecs::prelude::Resource;
+use bevy_ecs::schedule::IntoScheduleConfigs;
+use bevy_ecs::system::{Local, Res, ResMut};
+use bevy_ecs::world::World;
+use godot::builtin::{Variant, Vector2};
+use godot::classes::{
+    CanvasLayer, Input, InputEvent, InputEventKey, LineEdit, PanelContainer, RichTextLabel,
+    VBoxContainer, control, node,
+};
+use godot::global::Key;
+use godot::meta::ToGodot;
+use godot::obj::{Gd, InstanceId, NewAlloc};
+use godot::prelude::Callable;
+use parking_lot::Mutex;
+
+use crate::interop::GodotAccess;
+use crate::plugins::scene_tree::SceneTreeRef;
+use crate::watchers::scene_tree_watcher::exclude_from_mirror;
+
+/// Above the scene manager's fade overlay, so the console stays usable during
+/// transitions.
+const CONSOLE_LAYER: i32 = 200;
+/// Lines kept in the output before the oldest are dropped.
+const MAX_LINES: usize = 500;
+
+/// Plugin adding the [`GodotConsole`]. See the [module docs](self).
+pub struct GodotConsolePlugin {
+    /// Physical key that opens and closes the console.
+    pub toggle_key: Key,
+    /// Height of the console in pixels.
+    pub height: f32,
+}
+
+impl Default for GodotConsolePlugin {
+    fn default() -> Self {
+        Self {
+            toggle_key: Key::QUOTELEFT,
+            height: 320.0,
+        }
+    }
+}
+
+impl GodotConsolePlugin {
+    pub fn with_toggle_key(mut self, key: Key) -> Self {
+        self.toggle_key = key;
+        self
+    }
+}
+
+impl Plugin for GodotConsolePlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(ConsoleSettings {
+            toggle_key: self.toggle_key,
+            height: self.height,
+        })
+        .init_resource::<GodotConsole>()
+        .init_resource::<ConsoleInputQueue>()
+        .add_systems(Last, (run_console_input, sync_console_ui).chain());
+    }
+}
+
+/// A console command: arguments and the world in, text to print out.
+pub type ConsoleCommand =
+    Box<dyn FnMut(&[&str], &mut World) -> Result<String, String> + Send + Sync>;
+
+/// Registered commands, output and history of the console.
+#[derive(Resource, Default)]
+pub struct GodotConsole {
+    commands: Vec<(String, ConsoleCommand)>,
+    lines: Vec<String>,
+    lines_changed: bool,
+    history: ConsoleHistory,
+    open: bool,
+    /// Text to put in the input line on the next UI sync.
+    replace_input: Option<String>,
+}
+
+impl GodotConsole {
+    /// Add a command, replacing any command with the same name.
+    pub fn register(
+        &mut self,
+        name: impl Into<String>,
+        command: impl FnMut(&[&str], &mut World) -> Result<String, String> + Send + Sync + 'static,
+    ) -> &mut Self {
+        let name = name.into();
+        self.commands.retain(|(existing, _)| *existing != name);
+        self.commands.push((name, Box::new(command)));
+        self
+    }
+
+    /// Append a line to the output.
+    pub fn print(&mut self, line: impl Into<String>) {
+        self.lines.push(line.into());
+        if self.lines.len() > MAX_LINES {
+            let excess = self.lines.len() - MAX_LINES;
+            self.lines.drain(..excess);
+        }
+        self.lines_changed = true;
+    }
+
+    pub fn lines(&self) -> &[String] {
+        &self.lines
+    }
+
+    /// Whether the console is showing. Gameplay reading `ActionInput` may want
+    /// to ignore keys while the player types.
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+
+    fn names(&self) -> impl Iterator<Item = &str> {
+        ["clear", "help"]
+            .into_iter()
+            .chain(self.commands.iter().map(|(name, _)| name.as_str()))
+    }
+}
+
+/// Submitted lines, most recent last, with an Up/Down cursor.
+#[derive(Debug, Default)]
+struct ConsoleHistory {
+    entries: Vec<String>,
+    cursor: Option<usize>,
+}
+
+impl ConsoleHistory {
+    fn push(&mut self, line: &str) {
+        if self.entries.last().map(String::as_str) != Some(line) {
+            self.entries.push(line.to_string());
+        }
+        self.cursor = None;
+    }
+
+    /// One entry back; stays on the oldest.
+    fn previous(&mut self) -> Option<&str> {
+        let index = match self.cursor {
+            None => self.entries.len().checked_sub(1)?,
+            Some(index) => index.saturating_sub(1),
+        };
+        self.cursor = Some(index);
+        Some(&self.entries[index])
+    }
+
+    /// One entry forward; past the newest gives back an empty line.
+    fn next(&mut self) -> Option<&str> {
+        let index = self.cursor? + 1;
+        if index < self.entries.len() {
+            self.cursor = Some(index);
+            Some(&self.entries[index])
+        } else {
+            self.cursor = None;
+            Some("")
+        }
+    }
+}
+
+/// Split a command line on whitespace; double quotes group words.
+fn split_args(line: &str) -> Vec<String> {
+    let mut args = Vec::new();
+    let mut current = String::new();
+    let mut quoted = false;
+    let mut in_arg = false;
+    for c in line.chars() {
+        match c {
+            '"' => {
+                quoted = !quoted;
+                in_arg = true;
+            }
+            c if c.is_whitespace() && !quoted => {
+                if in_arg {
+                    args.push(std::mem::take(&mut current));
+                    in_arg = false;
+                }
+            }
+            c => {
+                current.push(c);
+                in_arg = true;
+            }
+        }
+    }
+    if in_arg {
+        args.push(current);
+    }
+    args
+}
+
+/// Complete the command name being typed. Returns the new input line and, when
+/// several commands match, the candidates to list.
+fn complete<'a>(
+    names: impl Iterator<Item = &'a str>,
+    input: &str,
+) -> Option<(String, Vec<&'a str>)> {
+    let prefix = input.trim_start();
+    if prefix.contains(char::is_whitespace) {
+        return None;
+    }
+    let mut matches: Vec<&str> = names.filter(|name| name.starts_with(prefix)).collect();
+    matches.sort_unstable();
+    matches.dedup();
+    match matches.as_slice() {
+        [] => None,
+        [only] => Some((format!("{only} "), Vec::new())),
+        [first, rest @ ..] => {
+            let common = rest.iter().fold(first.len(), |len, name| {
+                first
+                    .bytes()
+                    .zip(name.bytes())
+                    .take(len)
+                    .take_while(|(a, b)| a == b)
+                    .count()
+            });
+            Some((first[..common].to_string(), matches))
+        }
+    }
+}
+
+/// Filled by the input line's signal callbacks, drained in `Last`.
+#[derive(Resource, Default, Clone)]
+struct ConsoleInputQueue(Arc<Mutex<Vec<ConsoleInput>>>);
+
+#[derive(Debug, Clone, PartialEq)]
+enum ConsoleInput {
+    Submit(String),
+    HistoryPrevious,
+    HistoryNext,
+    Complete(String),
+}
+
+#[derive(Resource)]
+struct ConsoleSettings {
+    toggle_key: Key,
+    height: f32,
+}
+
+fn run_console_input(world: &mut World) {
+    let inputs = std::mem::take(&mut *world.resource::<ConsoleInputQueue>().0.lock());
+    if inputs.is_empty() {
+        return;
+    }
+    world.resource_scope(|world, mut console: Mut<GodotConsole>| {
+        for input in inputs {
+            match input {
+                ConsoleInput::Submit(line) => submit(&mut console, world, &line),
+                ConsoleInput::HistoryPrevious => {
+                    console.replace_input = console.history.previous().map(str::to_string);
+                }
+                ConsoleInput::HistoryNext => {
+                    console.replace_input = console.history.next().map(str::to_string);
+                }
+                ConsoleInput::Complete(text) => {
+                    let Some((completed, candidates)) = complete(console.names(), &text) else {
+                        continue;
+                    };
+                    let candidates = candidates.join("  ");
+                    if !candidates.is_empty() {
+                        console.print(candidates);
+                    }
+                    console.replace_input = Some(completed);
+                }
+            }
+        }
+    });
+}
+
+fn submit(console: &mut GodotConsole, world: &mut World, line: &str) {
+    let args = split_args(line);
+    let Some((name, args)) = args.split_first() else {
+        return;
+    };
+    console.history.push(line.trim());
+    console.print(format!("> {}", line.trim()));
+
+    match name.as_str() {
+        "clear" => {
+            console.lines.clear();
+            console.lines_changed = true;
+        }
+        "help" => {
+            let mut names: Vec<&str> = console.names().collect();
+            names.sort_unstable();
+            let names = names.join("  ");
+            console.print(names);
+        }
+        _ => {
+            let Some((_, command)) = console.commands.iter_mut().find(|(n, _)| n == name) else {
+                console.print(format!("unknown command `{name}`, try `help`"));
+                return;
+            };
+            let args: Vec<&str> = args.iter().map(String::as_str).collect();
+            match command(&args, world) {
+                Ok(output) if output.is_empty() => {}
+                Ok(output) => console.print(output),
+                Err(error) => console.print(format!("error: {error}")),
+            }
+        }
+    }
+}
+
+#[derive(Default)]
+struct ConsoleUi {
+    layer: Option<InstanceId>,
+    output: Option<InstanceId>,
+    input: Option<InstanceId>,
+    toggle_held: bool,
+}
+
+fn sync_console_ui(
+    mut ui: Local<ConsoleUi>,
+    mut console: ResMut<GodotConsole>,
+    settings: Res<ConsoleSettings>,
+    queue: Res<ConsoleInputQueue>,
+    mut scene_tree: SceneTreeRef,
+    mut godot: GodotAccess,
+) {
+    let held = godot
+        .singleton::<Input>()
+        .is_physical_key_pressed(settings.toggle_key);
+    let toggled = held && !ui.toggle_held;
+    ui.toggle_held = held;
+    if toggled {
+        console.open = !console.open;
+    }
+
+    if ui.layer.is_none() {
+        if !console.open {
+            return;
+        }
+        build_console_ui(&mut ui, &settings, &queue, &mut scene_tree);
+        console.lines_changed = true;
+    }
+    let (Some(mut layer), Some(mut output), Some(mut input)) = (
+        ui.layer
+            .and_then(|id| godot.try_get_instance_id::<CanvasLayer>(id)),
+        ui.output
+            .and_then(|id| godot.try_get_instance_id::<RichTextLabel>(id)),
+        ui.input
+            .and_then(|id| godot.try_get_instance_id::<LineEdit>(id)),
+    ) else {
+        // Freed from outside (e.g. a scene change freeing the root's children).
+        *ui = ConsoleUi {
+            toggle_held: held,
+            ..ConsoleUi::default()
+        };
+        return;
+    };
+
+    if toggled {
+        layer.set_visible(console.open);
+        if console.open {
+            input.grab_focus();
+        } else {
+            input.release_focus();
+        }
+    }
+    if console.lines_changed {
+        output.set_text(&console.lines.join("\n"));
+        console.lines_changed = false;
+    }
+    if let Some(text) = console.replace_input.take() {
+        input.set_text(&text);
+        input.set_caret_column(text.chars().count() as i32);
+    }
+}
+
+fn build_console_ui(
+    ui: &mut ConsoleUi,
+    settings: &ConsoleSettings,
+    queue: &ConsoleInputQueue,
+    scene_tree: &mut SceneTreeRef,
+) {
+    let Some(mut root) = scene_tree.get().get_root() else {
+        return;
+    };
+
+    let mut layer = CanvasLayer::new_alloc();
+    layer.set_name("GodotBevyConsole");
+    layer.set_layer(CONSOLE_LAYER);
+    // Keep working while the tree is paused -- that is often when it's needed.
+    layer.set_process_mode(node::ProcessMode::ALWAYS);
+    exclude_from_mirror(&mut layer);
+
+    let mut panel = PanelContainer::new_alloc();
+    panel.set_anchors_preset(control::LayoutPreset::TOP_WIDE);
+    panel.set_custom_minimum_size(Vector2::new(0.0, settings.height));
+
+    let mut column = VBoxContainer::new_alloc();
+
+    let mut output = RichTextLabel::new_alloc();
+    output.set_scroll_follow(true);
+    output.set_selection_enabled(true);
+    output.set_v_size_flags(control::SizeFlags::EXPAND_FILL);
+
+    let mut input = LineEdit::new_alloc();
+    input.set_placeholder("help, Tab to complete, Up/Down for history");
+    // Godot 4.4+ stops editing after Enter unless told otherwise.
+    if input.has_method("set_keep_editing_on_text_submit") {
+        input.call("set_keep_editing_on_text_submit", &[true.to_variant()]);
+    }
+
+    let submitted = queue.clone();
+    let input_id = input.instance_id();
+    input.connect(
+        "text_submitted",
+        &Callable::from_fn("console_text_submitted", move |args| {
+            let text = args.first().map(|arg| arg.to_string()).unwrap_or_default();
+            submitted.0.lock().push(ConsoleInput::Submit(text));
+            if let Ok(mut input) = Gd::<LineEdit>::try_from_instance_id(input_id) {
+                input.clear();
+            }
+            Variant::nil()
+        }),
+    );
+    let keys = queue.clone();
+    let toggle_key = settings.toggle_key;
+    input.connect(
+        "gui_input",
+        &Callable::from_fn("console_gui_input", move |args| {
+            let Some(event) = args
+                .first()
+                .and_then(|arg| arg.try_to::<Gd<InputEvent>>().ok())
+                .and_then(|event| event.try_cast::<InputEventKey>().ok())
+            else {
+                return Variant::nil();
+            };
+            if !event.is_pressed() {
+                return Variant::nil();
+            }
+            let Ok(mut input) = Gd::<LineEdit>::try_from_instance_id(input_id) else {
+                return Variant::nil();
+            };
+            // Don't type the toggle key into the line; the toggle itself is
+            // polled in `sync_console_ui`.
+            if event.get_physical_keycode() == toggle_key {
+                input.accept_event();
+                return Variant::nil();
+            }
+            let console_input = match event.get_keycode() {
+                Key::UP => ConsoleInput::HistoryPrevious,
+                Key::DOWN => ConsoleInput::HistoryNext,
+                Key::TAB => ConsoleInput::Complete(input.get_text().to_string()),
+                _ => return Variant::nil(),
+            };
+            keys.0.lock().push(console_input);
+            // Tab would otherwise move focus out of the line.
+            input.accept_event();
+            Variant::nil()
+        }),
+    );
+
+    column.add_child(&output);
+    column.add_child(&input);
+    panel.add_child(&column);
+    layer.add_child(&panel);
+    root.add_child(&layer);
+    input.grab_focus();
+
+    ui.layer = Some(layer.instance_id());
+    ui.output = Some(output.instance_id());
+    ui.input = Some(input_id);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn make_world() -> World {
+        let mut world = World::new();
+        world.init_resource::<ConsoleInputQueue>();
+        let mut console = GodotConsole::default();
+        console.register("add", |args, world| {
+            let sum: i64 = args
+                .iter()
+                .map(|arg| {
+                    arg.parse::<i64>()
+                        .map_err(|_| format!("not a number: {arg}"))
+                })
+                .sum::<Result<_, _>>()?;
+            world.insert_resource(Total(sum));
+            Ok(sum.to_string())
+        });
+        console.register("add_many", |_, _| Ok(String::new()));
+        world.insert_resource(console);
+        world
+    }
+
+    #[derive(Resource)]
+    struct Total(i64);
+
+    fn send(world: &mut World, input: ConsoleInput) {
+        world.resource::<ConsoleInputQueue>().0.lock().push(input);
+        run_console_input(world);
+    }
+
+    #[test]
+    fn args_split_on_whitespace_and_quotes() {
+        assert_eq!(
+            split_args(r#"say "hello there"  now """#),
+            vec!["say", "hello there", "now", ""]
+        );
+        assert!(split_args("   ").is_empty());
+    }
+
+    #[test]
+    fn submitted_commands_run_against_the_world() {
+        let mut world = make_world();
+        send(&mut world, ConsoleInput::Submit("add 2 40".into()));
+        assert_eq!(world.resource::<Total>().0, 42);
+        send(&mut world, ConsoleInput::Submit("add x".into()));
+        send(&mut world, ConsoleInput::Submit("nope".into()));
+        assert_eq!(
+            world.resource::<GodotConsole>().lines(),
+            [
+                "> add 2 40",
+                "42",
+                "> add x",
+                "error: not a number: x",
+                "> nope",
+                "unknown command `nope`, try `help`",
+            ]
+        );
+        send(&mut world, ConsoleInput::Submit("clear".into()));
+        assert!(world.resource::<GodotConsole>().lines().is_empty());
+    }
+
+    #[test]
+    fn history_walks_back_and_forward() {
+        let mut world = make_world();
+        send(&mut world, ConsoleInput::Submit("help".into()));
+        send(&mut world, ConsoleInput::Submit("add 1".into()));
+        send(&mut world, ConsoleInput::Submit("add 1".into()));
+
+        let mut walk = |input| {
+            send(&mut world, input);
+            world.resource_mut::<GodotConsole>().replace_input.take()
+        };
+        assert_eq!(
+            walk(ConsoleInput::HistoryPrevious).as_deref(),
+            Some("add 1")
+        );
+        assert_eq!(walk(ConsoleInput::HistoryPrevious).as_deref(), Some("help"));
+        assert_eq!(walk(ConsoleInput::HistoryPrevious).as_deref(), Some("help"));
+        assert_eq!(walk(ConsoleInput::HistoryNext).as_deref(), Some("add 1"));
+        assert_eq!(walk(ConsoleInput::HistoryNext).as_deref(), Some(""));
+        assert_eq!(walk(ConsoleInput::HistoryNext), None);
+    }
+
+    #[test]
+    fn tab_completes_command_names() {
+        let mut world = make_world();
+        send(&mut world, ConsoleInput::Complete("he".into()));
+        assert_eq!(
+            world
+                .resource_mut::<GodotConsole>()
+                .replace_input
+                .take()
+                .as_deref(),
+            Some("help ")
+        );
+
+        send(&mut world, ConsoleInput::Complete("a".into()));
+        let mut console = world.resource_mut::<GodotConsole>();
+        assert_eq!(console.replace_input.take().as_deref(), Some("add"));
+        assert_eq!(console.lines(), ["add  add_many"]);
+
+        assert_eq!(complete(["add"].into_iter(), "add 1"), None);
+        assert_eq!(complete(["add"].into_iter(), "x"), None);
+    }
+}
@@ -1,15 +1,23 @@
-use bevy_app::{App, First, Plugin};
+use bevy_app::{App, First, FixedLast, Plugin};
 use bevy_ecs::component::Component;
 use bevy_ecs::event::EntityEvent;
 use bevy_ecs::lifecycle::Remove;
 use bevy_ecs::observer::On;
 use bevy_ecs::prelude::{Name, Resource};
-use bevy_ecs::schedule::IntoScheduleConfigs;
-use bevy_ecs::system::{Query, ResMut};
+use bevy_ecs::schedule::{InternedScheduleLabel, IntoScheduleConfigs, SystemSet};
+use bevy_ecs::system::{Query, Res, ResMut};
 use bevy_time::{Time, Virtual};
 use std::any::TypeId;
+use std::time::Duration;
+#[cfg(feature = "trace_tracy")]
+use {
+    bevy_app::Last,
+    bevy_diagnostic::{Diagnostic, DiagnosticPath, Diagnostics, RegisterDiagnostic},
+    bevy_platform::collections::HashMap,
+};
 
 use crate::interop::{GodotAccess, GodotMainThread, GodotNode, GodotNodeHandle};
+use crate::plugins::node_pool::HelperNodePool;
 use bevy_ecs::system::EntityCommands;
 use godot::classes::Node;
 use godot::obj::Singleton;
@@ -127,6 +135,287 @@ impl AppSceneTreeExt for App {
     }
 }
 
+/// Per-frame counts of the work godot-bevy did crossing the Godot boundary.
+///
+/// Reset at the start of each Godot frame, before `First`, so read it in `Last` (or
+/// after the frame, in tests) for complete numbers. Every sync system updates it
+/// when present; `GodotBaseCorePlugin` adds it.
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct SyncStats {
+    /// Scene tree nodes mirrored into new entities.
+    pub nodes_added: u32,
+    /// Entities despawned (or stripped, if protected) because their node left the tree.
+    pub nodes_removed: u32,
+    /// Node transforms read from Godot into `Transform`.
+    pub transforms_read: u32,
+    /// `Transform`s written back to Godot nodes.
+    pub transforms_written: u32,
+    /// Godot signals delivered to Bevy observers.
+    pub signals_forwarded: u32,
+    /// Godot input events drained into Bevy messages.
+    pub inputs_drained: u32,
+    /// Queued Godot-side operations applied, such as signal connections and audio commands.
+    pub deferred_commands: u32,
+}
+
+impl SyncStats {
+    /// Zero every counter.
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
+
+#[cfg(feature = "trace_tracy")]
+impl SyncStats {
+    /// Diagnostics the counters are published under each frame when
+    /// `trace_tracy` is enabled, in field order.
+    pub const DIAGNOSTICS: [DiagnosticPath; 7] = [
+        DiagnosticPath::const_new("godot_bevy/nodes_added"),
+        DiagnosticPath::const_new("godot_bevy/nodes_removed"),
+        DiagnosticPath::const_new("godot_bevy/transforms_read"),
+        DiagnosticPath::const_new("godot_bevy/transforms_written"),
+        DiagnosticPath::const_new("godot_bevy/signals_forwarded"),
+        DiagnosticPath::const_new("godot_bevy/inputs_drained"),
+        DiagnosticPath::const_new("godot_bevy/deferred_commands"),
+    ];
+
+    fn counters(&self) -> [u32; 7] {
+        [
+            self.nodes_added,
+            self.nodes_removed,
+            self.transforms_read,
+            self.transforms_written,
+            self.signals_forwarded,
+            self.inputs_drained,
+            self.deferred_commands,
+        ]
+    }
+}
+
+/// Publish the frame's [`SyncStats`] as Bevy diagnostics and Tracy plots.
+#[cfg(feature = "trace_tracy")]
+fn publish_sync_diagnostics(stats: Res<SyncStats>, mut diagnostics: Diagnostics) {
+    for (path, count) in SyncStats::DIAGNOSTICS.iter().zip(stats.counters()) {
+        diagnostics.add_measurement(path, || f64::from(count));
+    }
+    crate::profiling::plot_sync_stats(&stats);
+}
+
+/// Wall-clock time spent in each schedule godot-bevy runs, for a whole frame.
+///
+/// Recorded only while the resource exists: `GodotDebuggerPlugin` adds it, or
+/// `init_resource` it yourself. Fixed steps are summed per frame, under
+/// `RunFixedMainLoop`.
+#[derive(Resource, Debug, Default, Clone)]
+pub struct ScheduleTimings {
+    current: Vec<(InternedScheduleLabel, Duration)>,
+    last_frame: Vec<(InternedScheduleLabel, Duration)>,
+}
+
+impl ScheduleTimings {
+    /// Timings of the last completed frame, in run order.
+    pub fn last_frame(&self) -> &[(InternedScheduleLabel, Duration)] {
+        &self.last_frame
+    }
+
+    pub(crate) fn record(&mut self, label: InternedScheduleLabel, elapsed: Duration) {
+        match self.current.iter_mut().find(|(l, _)| *l == label) {
+            Some((_, total)) => *total += elapsed,
+            None => self.current.push((label, elapsed)),
+        }
+    }
+
+    /// Close the frame: what was recorded becomes [`last_frame`](Self::last_frame).
+    pub(crate) fn finish_frame(&mut self) {
+        self.last_frame = std::mem::take(&mut self.current);
+    }
+}
+
+/// Godot node and singleton lookups made through [`GodotAccess`] by each system,
+/// for finding systems that fetch a node per entity in a hot loop.
+///
+/// Only with `trace_tracy`; `GodotBaseCorePlugin` adds it. Every frame the total
+/// is plotted in Tracy as `godot_bevy/godot_lookups`, along with the
+/// [`PLOTTED_SYSTEMS`](Self::PLOTTED_SYSTEMS) busiest systems under
+/// `godot_bevy/godot_lookups/<system>`, and the debugger's diagnostics tab lists
+/// them. Method calls on a node after looking it up are not counted. System
+/// names need Bevy's `debug` feature.
+#[cfg(feature = "trace_tracy")]
+#[derive(Resource, Debug, Default, Clone)]
+pub struct GodotLookupStats {
+    /// Counts so far this frame. Keys are kept across frames so a system only
+    /// allocates its entry once.
+    current: HashMap<Box<str>, u32>,
+    last_frame: Vec<(String, u32)>,
+}
+
+#[cfg(feature = "trace_tracy")]
+impl GodotLookupStats {
+    /// How many systems get their own Tracy plot each frame.
+    pub const PLOTTED_SYSTEMS: usize = 8;
+
+    /// Diagnostic the frame's total is published under.
+    pub const DIAGNOSTIC: DiagnosticPath = DiagnosticPath::const_new("godot_bevy/godot_lookups");
+
+    /// Counts of the last completed frame by system, most calls first.
+    pub fn last_frame(&self) -> &[(String, u32)] {
+        &self.last_frame
+    }
+
+    /// Lookups in the last completed frame, across all systems.
+    pub fn total(&self) -> u32 {
+        self.last_frame.iter().map(|(_, count)| count).sum()
+    }
+
+    /// Count one lookup made by `system`.
+    pub(crate) fn count(&mut self, system: &str) {
+        match self.current.get_mut(system) {
+            Some(count) => *count += 1,
+            None => {
+                self.current.insert(system.into(), 1);
+            }
+        }
+    }
+
+    /// Close the frame: the counts so far become [`last_frame`](Self::last_frame),
+    /// most lookups first.
+    pub(crate) fn finish_frame(&mut self) {
+        self.last_frame.clear();
+        self.last_frame.extend(
+            self.current
+                .iter_mut()
+                .filter(|(_, count)| **count > 0)
+                .map(|(system, count)| (system.to_string(), std::mem::take(count))),
+        );
+        self.last_frame
+            .sort_by(|(a_system, a), (b_system, b)| b.cmp(a).then_with(|| a_system.cmp(b_system)));
+    }
+}
+
+/// Publish the last frame's [`GodotLookupStats`] as a Bevy diagnostic and Tracy plots.
+#[cfg(feature = "trace_tracy")]
+fn publish_godot_lookups(stats: Res<GodotLookupStats>, mut diagnostics: Diagnostics) {
+    diagnostics.add_measurement(&GodotLookupStats::DIAGNOSTIC, || f64::from(stats.total()));
+    crate::profiling::plot_godot_lookups(stats.last_frame(), GodotLookupStats::PLOTTED_SYSTEMS);
+}
+
+/// Pause whole godot-bevy subsystems at runtime, for menus, cutscenes, or finding
+/// which one costs the frame. Everything is on by default; `GodotBaseCorePlugin`
+/// adds it.
+///
+/// ```ignore
+/// fn enter_cutscene(mut toggles: ResMut<PluginToggles>) {
+///     toggles.collisions = false;
+///     toggles.transform_sync = false;
+/// }
+/// ```
+///
+/// Pausing skips the subsystem's systems; nothing is dropped. The same run
+/// conditions are public ([`collisions_active`], ...) for systems of your own.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PluginToggles {
+    /// Collision messages and observers. Godot's collision signals queue up while
+    /// paused and are delivered on resume, so `Collisions` stays accurate.
+    pub collisions: bool,
+    /// Audio commands and tweens. Commands queue up while paused; sounds already
+    /// playing keep playing.
+    pub audio: bool,
+    /// Transform reads from and writes to Godot. `Transform`s changed while paused
+    /// are written on resume.
+    pub transform_sync: bool,
+}
+
+impl Default for PluginToggles {
+    fn default() -> Self {
+        Self {
+            collisions: true,
+            audio: true,
+            transform_sync: true,
+        }
+    }
+}
+
+/// Run condition: [`PluginToggles::collisions`] is on, or there are no toggles.
+pub fn collisions_active(toggles: Option<Res<PluginToggles>>) -> bool {
+    toggles.is_none_or(|t| t.collisions)
+}
+
+/// Run condition: [`PluginToggles::audio`] is on, or there are no toggles.
+pub fn audio_active(toggles: Option<Res<PluginToggles>>) -> bool {
+    toggles.is_none_or(|t| t.audio)
+}
+
+/// Run condition: [`PluginToggles::transform_sync`] is on, or there are no toggles.
+pub fn transform_sync_active(toggles: Option<Res<PluginToggles>>) -> bool {
+    toggles.is_none_or(|t| t.transform_sync)
+}
+
+/// Public sync points in the frame, for ordering user systems against
+/// godot-bevy's own work. Each point lives in one schedule (two for
+/// `TransformRead`); a system added to the set in any other schedule isn't
+/// ordered by it.
+///
+/// The sets run in a fixed order, configured by `GodotBaseCorePlugin`. In `First`:
+/// `BeforeTreeUpdate`, `SceneTreeRead`, `SignalPump`, `InputPump`,
+/// `AfterInputDrain`. In `FixedLast`: `BeforeTransformFlush`, `TransformWrite`,
+/// `AfterTransformFlush`. A system after `SceneTreeRead` sees every node added
+/// this frame as an entity, with its components.
+///
+/// ```ignore
+/// app.add_systems(First, tag_new_nodes.after(GodotSyncSet::SceneTreeRead))
+///     .add_systems(FixedLast, clamp_positions.in_set(GodotSyncSet::BeforeTransformFlush));
+/// ```
+#[derive(SystemSet, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GodotSyncSet {
+    /// `First`, before this frame's scene tree changes are mirrored into ECS.
+    BeforeTreeUpdate,
+    /// `First`: scene tree changes are mirrored into ECS -- entities spawned,
+    /// despawned and renamed, and their commands applied.
+    SceneTreeRead,
+    /// `First`: queued Godot signals and `GodotEventSender` events are triggered
+    /// as observer events, and pending signal connections are made.
+    SignalPump,
+    /// `First`: Godot input events are written as messages, and bridged to
+    /// `bevy_input` when `BevyInputBridgePlugin` is added.
+    InputPump,
+    /// `First`, after Godot input events have been written as messages (and
+    /// bridged to `bevy_input`, when `BevyInputBridgePlugin` is added).
+    AfterInputDrain,
+    /// `FixedFirst`, and `PreUpdate` on frames without a physics step: node
+    /// transforms are read into `Transform`s.
+    TransformRead,
+    /// `FixedLast`, before `Transform`s are written to Godot nodes.
+    BeforeTransformFlush,
+    /// `FixedLast`: `Transform`s are written to Godot nodes.
+    TransformWrite,
+    /// `FixedLast`, after `Transform`s are written to Godot nodes.
+    AfterTransformFlush,
+}
+
+/// Chain the [`GodotSyncSet`]s in their documented order.
+fn configure_sync_sets(app: &mut App) {
+    app.configure_sets(
+        First,
+        (
+            GodotSyncSet::BeforeTreeUpdate,
+            GodotSyncSet::SceneTreeRead,
+            GodotSyncSet::SignalPump,
+            GodotSyncSet::InputPump,
+            GodotSyncSet::AfterInputDrain,
+        )
+            .chain(),
+    )
+    .configure_sets(
+        FixedLast,
+        (
+            GodotSyncSet::BeforeTransformFlush,
+            GodotSyncSet::TransformWrite,
+            GodotSyncSet::AfterTransformFlush,
+        )
+            .chain(),
+    );
+}
+
 /// Minimal core plugin with only essential Godot-Bevy integration.
 /// This includes scene tree management, basic Bevy setup, and core resources.
 #[derive(Default)]
@@ -141,16 +430,31 @@ impl Plugin for GodotBaseCorePlugin {
             .add_plugins(bevy_diagnostic::DiagnosticsPlugin)
             .init_non_send::<GodotMainThread>()
             .init_resource::<SceneTreeComponentRegistry>()
+            .init_resource::<SyncStats>()
+            .init_resource::<PluginToggles>()
+            .init_resource::<HelperNodePool>()
             .add_observer(on_godot_node_handle_removed);
+        configure_sync_sets(app);
 
         // Keeps RunFixedMainLoop's Before/After anchor sets live for ecosystem plugins
         // (e.g. leafwing). TimePlugin stays so Time<Real>/Virtual still advance in _process.
         crate::plugins::fixed_schedule::host_fixed_main_loop(app);
 
+        #[cfg(feature = "trace_tracy")]
+        {
+            for path in SyncStats::DIAGNOSTICS {
+                app.register_diagnostic(Diagnostic::new(path));
+            }
+            app.register_diagnostic(Diagnostic::new(GodotLookupStats::DIAGNOSTIC))
+                .init_resource::<GodotLookupStats>()
+                .add_systems(Last, (publish_sync_diagnostics, publish_godot_lookups));
+        }
+
         // The event bridge's channel + First drain live in core so any app can
         // receive events; `add_godot_event` installs the GDScript decoder
         // registry on demand.
         crate::plugins::event_bridge::ensure_event_channel(app);
+        crate::plugins::deferred_calls::init_deferred_calls(app);
     }
 }
 
@@ -194,3 +498,35 @@ fn on_godot_node_handle_removed(
         node.queue_free();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Resource, Default)]
+    struct Order(Vec<&'static str>);
+
+    #[test]
+    fn sync_sets_run_in_documented_order() {
+        let mut app = App::new();
+        app.init_resource::<Order>();
+        configure_sync_sets(&mut app);
+        // Added in reverse, so only the set ordering can put them right.
+        app.add_systems(
+            First,
+            (
+                (|mut order: ResMut<Order>| order.0.push("input")).in_set(GodotSyncSet::InputPump),
+                (|mut order: ResMut<Order>| order.0.push("signals"))
+                    .in_set(GodotSyncSet::SignalPump),
+                (|mut order: ResMut<Order>| order.0.push("tree"))
+                    .in_set(GodotSyncSet::SceneTreeRead),
+            ),
+        );
+
+        app.world_mut().run_schedule(First);
+        assert_eq!(
+            app.world().resource::<Order>().0,
+            vec!["tree", "signals", "input"]
+        );
+    }
+}
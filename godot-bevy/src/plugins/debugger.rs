@@ -2,20 +2,52 @@
 //!
 //! This plugin integrates with Godot's EditorDebuggerPlugin system to provide
 //! real-time inspection of Bevy entities and components in the Godot editor.
-
-use bevy_app::{App, Plugin, Update};
+//!
+//! The editor panel can also change the world's structure: spawn and despawn
+//! entities, and add or remove reflected components and resources. Added values
+//! start from `Default`, so a type shows up in the panel's menus only if it is
+//! registered with both `#[reflect(Component)]` (or `Resource`) and
+//! `#[reflect(Default)]`.
+//!
+//! A diagnostics tab shows frame time and entity count, time spent per schedule
+//! from [`ScheduleTimings`], the boundary-crossing counters from [`SyncStats`],
+//! and any `bevy_diagnostic` measurements the app registered (for instance with
+//! `FrameTimeDiagnosticsPlugin`). With the `multiplayer` feature it also lists
+//! the bandwidth, round-trip times and replication counts from `NetStats`, and
+//! with `trace_tracy` the systems making the most Godot lookups, from
+//! `GodotLookupStats`.
+
+use std::sync::{Arc, Mutex};
+
+use bevy_app::{App, Last, Plugin, Update};
+use bevy_diagnostic::DiagnosticsStore;
+use bevy_ecs::entity::Entity;
 use bevy_ecs::prelude::{Name, Resource, World};
+use bevy_ecs::reflect::{ReflectComponent, ReflectResource};
+use bevy_ecs::resource::IsResource;
 use bevy_ecs::world::EntityRef;
-use bevy_reflect::{PartialReflect, ReflectFromPtr, ReflectRef};
-use bevy_time::Time;
+use bevy_reflect::prelude::ReflectDefault;
+use bevy_reflect::{
+    PartialReflect, Reflect, ReflectFromPtr, ReflectRef, TypeRegistration, TypeRegistry,
+};
+use bevy_time::{Real, Time};
 use godot::classes::EngineDebugger;
 use godot::meta::ToGodot;
 use godot::prelude::{VarDictionary as Dictionary, *};
+use tracing::warn;
 
 use crate::interop::GodotNodeHandle;
+use crate::plugins::core::{ScheduleTimings, SyncStats};
 use crate::plugins::scene_tree::GodotChildOf;
 use bevy_ecs::reflect::AppTypeRegistry;
 
+/// Prefix of the messages the editor panel sends to the game (`bevy:spawn_entity`, ...).
+const EDIT_CAPTURE: &str = "bevy";
+
+/// Systems listed in the diagnostics tab's Godot lookups section.
+#[cfg(feature = "trace_tracy")]
+const REPORTED_LOOKUP_SYSTEMS: usize = 10;
+
 /// Configuration for the debugger plugin
 #[derive(Resource)]
 pub struct DebuggerConfig {
@@ -38,6 +70,7 @@ impl Default for DebuggerConfig {
 #[derive(Resource, Default)]
 struct DebuggerTimer {
     elapsed: f32,
+    diagnostics_elapsed: f32,
 }
 
 /// Plugin that enables Bevy entity inspection in Godot's debugger
@@ -48,7 +81,11 @@ impl Plugin for GodotDebuggerPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<DebuggerConfig>()
             .init_resource::<DebuggerTimer>()
-            .add_systems(Update, debugger_exclusive_system);
+            .init_resource::<DebuggerSession>()
+            .init_resource::<ScheduleTimings>()
+            .add_systems(Update, debugger_exclusive_system)
+            // `Last`, so `SyncStats` covers the frame's sync work.
+            .add_systems(Last, debugger_diagnostics_system);
     }
 }
 
@@ -61,6 +98,12 @@ fn debugger_exclusive_system(world: &mut World) {
         return;
     }
 
+    if !EngineDebugger::singleton().is_active() {
+        return;
+    }
+
+    let edited = sync_editor_session(world);
+
     let delta = world
         .get_resource::<Time>()
         .map(|t| t.delta_secs())
@@ -70,7 +113,8 @@ fn debugger_exclusive_system(world: &mut World) {
         let mut timer = world.get_resource_mut::<DebuggerTimer>();
         if let Some(ref mut timer) = timer {
             timer.elapsed += delta;
-            if timer.elapsed < update_interval {
+            // Show the result of an edit right away instead of at the next tick.
+            if timer.elapsed < update_interval && !edited {
                 false
             } else {
                 timer.elapsed = 0.0;
@@ -85,13 +129,26 @@ fn debugger_exclusive_system(world: &mut World) {
         return;
     }
 
-    if !EngineDebugger::singleton().is_active() {
-        return;
-    }
-
     // Clone registry so we can release the borrow on world
     let type_registry = world.get_resource::<AppTypeRegistry>().cloned();
 
+    let entities = collect_entities(world, type_registry.as_ref());
+    let resources = reflected_resources(world, type_registry.as_ref());
+
+    let mut debugger = EngineDebugger::singleton();
+    debugger.send_message("bevy:entities", &entities);
+    debugger.send_message("bevy:resources", &resources);
+}
+
+/// `parent_bits` in `bevy:entities` for an entity without a parent.
+pub(crate) const NO_PARENT_BITS: i64 = -1;
+
+/// Every entity with its reflected components, as sent in `bevy:entities`:
+/// `[entity_bits, name, has_godot_node, parent_bits, components]` each.
+pub(crate) fn collect_entities(
+    world: &mut World,
+    type_registry: Option<&AppTypeRegistry>,
+) -> VarArray {
     let mut entities = VarArray::new();
     let mut query = world.query::<EntityRef>();
 
@@ -106,7 +163,7 @@ fn debugger_exclusive_system(world: &mut World) {
         let parent_bits: i64 = entity_ref
             .get::<GodotChildOf>()
             .map(|child_of| child_of.get().to_bits() as i64)
-            .unwrap_or(-1);
+            .unwrap_or(NO_PARENT_BITS);
 
         // Build component data with reflection
         let mut components = VarArray::new();
@@ -120,7 +177,7 @@ fn debugger_exclusive_system(world: &mut World) {
             let mut component_dict = Dictionary::new();
 
             // Try to get pretty type name from registry, fallback to extracting from full path
-            let (full_name, short_name) = if let Some(ref registry) = type_registry {
+            let (full_name, short_name) = if let Some(registry) = type_registry {
                 let registry = registry.read();
                 if let Some(type_id) = component_info.type_id() {
                     if let Some(registration) = registry.get(type_id) {
@@ -150,7 +207,7 @@ fn debugger_exclusive_system(world: &mut World) {
             component_dict.set("short_name", short_name.as_str());
 
             // Try to get reflected value
-            if let Some(ref registry) = type_registry {
+            if let Some(registry) = type_registry {
                 let registry = registry.read();
                 if let Some(type_id) = component_info.type_id()
                     && let Some(registration) = registry.get(type_id)
@@ -176,9 +233,429 @@ fn debugger_exclusive_system(world: &mut World) {
 
         entities.push(&entry.to_variant());
     }
+    entities
+}
 
-    let mut debugger = EngineDebugger::singleton();
-    debugger.send_message("bevy:entities", &entities);
+/// Editor-side state of the debugger connection: the capture receiving edits from
+/// the panel, and what has been sent to it once.
+#[derive(Resource, Default)]
+struct DebuggerSession {
+    /// Filled by the message capture, drained by [`sync_editor_session`].
+    pending: Arc<Mutex<Vec<EditRequest>>>,
+    capture_registered: bool,
+    types_sent: bool,
+}
+
+impl Drop for DebuggerSession {
+    fn drop(&mut self) {
+        if self.capture_registered {
+            let mut debugger = EngineDebugger::singleton();
+            if debugger.has_capture(EDIT_CAPTURE) {
+                debugger.unregister_message_capture(EDIT_CAPTURE);
+            }
+        }
+    }
+}
+
+/// A structural change requested from the editor panel.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum EditRequest {
+    SpawnEntity,
+    DespawnEntity(Entity),
+    InsertComponent { entity: Entity, type_path: String },
+    RemoveComponent { entity: Entity, type_path: String },
+    InsertResource(String),
+    RemoveResource(String),
+}
+
+impl EditRequest {
+    pub(crate) fn parse(message: &str, data: &VarArray) -> Option<Self> {
+        let entity = || {
+            let bits = data.get(0)?.try_to::<i64>().ok()?;
+            Entity::try_from_bits(bits as u64)
+        };
+        let type_path = |index| Some(data.get(index)?.try_to::<GString>().ok()?.to_string());
+
+        Some(match message {
+            "spawn_entity" => Self::SpawnEntity,
+            "despawn_entity" => Self::DespawnEntity(entity()?),
+            "insert_component" => Self::InsertComponent {
+                entity: entity()?,
+                type_path: type_path(1)?,
+            },
+            "remove_component" => Self::RemoveComponent {
+                entity: entity()?,
+                type_path: type_path(1)?,
+            },
+            "insert_resource" => Self::InsertResource(type_path(0)?),
+            "remove_resource" => Self::RemoveResource(type_path(0)?),
+            _ => return None,
+        })
+    }
+}
+
+/// Register the edit capture and send the editable types on first contact, then
+/// apply the edits received since the last frame. Returns whether any were applied.
+fn sync_editor_session(world: &mut World) -> bool {
+    let Some(mut session) = world.get_resource_mut::<DebuggerSession>() else {
+        return false;
+    };
+
+    if !session.capture_registered {
+        let pending = session.pending.clone();
+        let capture = Callable::from_fn("bevy_debugger_edit", move |args| {
+            let (Some(message), Some(data)) = (args.first(), args.get(1)) else {
+                return false;
+            };
+            let (Ok(message), Ok(data)) = (message.try_to::<GString>(), data.try_to::<VarArray>())
+            else {
+                return false;
+            };
+            let message = message.to_string();
+            match EditRequest::parse(&message, &data) {
+                Some(request) => {
+                    pending.lock().unwrap().push(request);
+                    true
+                }
+                None => {
+                    warn!("Bevy debugger: ignoring malformed '{message}' request");
+                    false
+                }
+            }
+        });
+        EngineDebugger::singleton().register_message_capture(EDIT_CAPTURE, &capture);
+        session.capture_registered = true;
+    }
+
+    let send_types = !std::mem::replace(&mut session.types_sent, true);
+    let requests = std::mem::take(&mut *session.pending.lock().unwrap());
+
+    let Some(registry) = world.get_resource::<AppTypeRegistry>().cloned() else {
+        return false;
+    };
+
+    if send_types {
+        EngineDebugger::singleton().send_message("bevy:types", &editable_types_data(&registry));
+    }
+    apply_edits(world, &registry, &requests)
+}
+
+/// The `bevy:types` data: the editable component and resource type paths.
+pub(crate) fn editable_types_data(registry: &AppTypeRegistry) -> VarArray {
+    let (components, resources) = editable_types(&registry.read());
+    let mut data = VarArray::new();
+    for paths in [components, resources] {
+        let paths: PackedStringArray = paths.into_iter().map(GString::from).collect();
+        data.push(&paths.to_variant());
+    }
+    data
+}
+
+/// Apply edits, warning about the ones that fail. Returns whether there were any.
+pub(crate) fn apply_edits(
+    world: &mut World,
+    registry: &AppTypeRegistry,
+    requests: &[EditRequest],
+) -> bool {
+    let registry = registry.read();
+    for request in requests {
+        if let Err(err) = apply_edit(world, &registry, request) {
+            warn!("Bevy debugger: {request:?} failed: {err}");
+        }
+    }
+    !requests.is_empty()
+}
+
+/// Type paths of the components and resources the panel can add, sorted.
+fn editable_types(registry: &TypeRegistry) -> (Vec<&'static str>, Vec<&'static str>) {
+    let mut components = Vec::new();
+    let mut resources = Vec::new();
+    for registration in registry.iter() {
+        if registration.data::<ReflectDefault>().is_none() {
+            continue;
+        }
+        let path = registration.type_info().type_path();
+        // Resources are components too; list them only once.
+        if registration.data::<ReflectResource>().is_some() {
+            resources.push(path);
+        } else if registration.data::<ReflectComponent>().is_some() {
+            components.push(path);
+        }
+    }
+    components.sort_unstable();
+    resources.sort_unstable();
+    (components, resources)
+}
+
+fn apply_edit(
+    world: &mut World,
+    registry: &TypeRegistry,
+    request: &EditRequest,
+) -> Result<(), String> {
+    match request {
+        EditRequest::SpawnEntity => {
+            world.spawn_empty();
+        }
+        EditRequest::DespawnEntity(entity) => {
+            let entity_ref = world
+                .get_entity(*entity)
+                .map_err(|_| format!("entity {entity} does not exist"))?;
+            if entity_ref.contains::<IsResource>() {
+                return Err(format!("entity {entity} holds a resource"));
+            }
+            // Mirrored entities take their Godot node with them.
+            world.despawn(*entity);
+        }
+        EditRequest::InsertComponent { entity, type_path } => {
+            let registration = registration(registry, type_path)?;
+            let component = type_data::<ReflectComponent>(registration, "Component")?;
+            let value = default_value(registration)?;
+            let mut entity_mut = world
+                .get_entity_mut(*entity)
+                .map_err(|_| format!("entity {entity} does not exist"))?;
+            component.insert(&mut entity_mut, value.as_partial_reflect(), registry);
+        }
+        EditRequest::RemoveComponent { entity, type_path } => {
+            let registration = registration(registry, type_path)?;
+            let component = type_data::<ReflectComponent>(registration, "Component")?;
+            let mut entity_mut = world
+                .get_entity_mut(*entity)
+                .map_err(|_| format!("entity {entity} does not exist"))?;
+            component.remove(&mut entity_mut);
+        }
+        EditRequest::InsertResource(type_path) => {
+            let registration = registration(registry, type_path)?;
+            type_data::<ReflectResource>(registration, "Resource")?;
+            let component = type_data::<ReflectComponent>(registration, "Component")?;
+            let value = default_value(registration)?;
+            // Resources live on their own entity; replace the value in place if present.
+            let existing = world
+                .components()
+                .get_id(registration.type_id())
+                .and_then(|id| world.resource_entities().get(id));
+            let mut entity_mut = match existing {
+                Some(entity) => world.entity_mut(entity),
+                None => world.spawn_empty(),
+            };
+            component.insert(&mut entity_mut, value.as_partial_reflect(), registry);
+        }
+        EditRequest::RemoveResource(type_path) => {
+            let registration = registration(registry, type_path)?;
+            type_data::<ReflectResource>(registration, "Resource")?;
+            let removed = world
+                .components()
+                .get_id(registration.type_id())
+                .is_some_and(|id| world.remove_resource_by_id(id));
+            if !removed {
+                return Err(format!("resource {type_path} does not exist"));
+            }
+        }
+    }
+    Ok(())
+}
+
+fn registration<'a>(
+    registry: &'a TypeRegistry,
+    type_path: &str,
+) -> Result<&'a TypeRegistration, String> {
+    registry
+        .get_with_type_path(type_path)
+        .ok_or_else(|| format!("{type_path} is not registered"))
+}
+
+fn type_data<'a, T: bevy_reflect::TypeData>(
+    registration: &'a TypeRegistration,
+    reflect_attr: &str,
+) -> Result<&'a T, String> {
+    registration.data::<T>().ok_or_else(|| {
+        format!(
+            "{} has no #[reflect({reflect_attr})]",
+            registration.type_info().type_path()
+        )
+    })
+}
+
+fn default_value(registration: &TypeRegistration) -> Result<Box<dyn Reflect>, String> {
+    Ok(type_data::<ReflectDefault>(registration, "Default")?.default())
+}
+
+/// Reflected resources currently in the world, in the same shape as components.
+pub(crate) fn reflected_resources(
+    world: &World,
+    type_registry: Option<&AppTypeRegistry>,
+) -> VarArray {
+    let mut resources = VarArray::new();
+    let Some(registry) = type_registry else {
+        return resources;
+    };
+    let registry = registry.read();
+
+    for (info, ptr) in world.iter_resources() {
+        let Some(registration) = info.type_id().and_then(|id| registry.get(id)) else {
+            continue;
+        };
+        if registration.data::<ReflectResource>().is_none() {
+            continue;
+        }
+        let table = registration.type_info().type_path_table();
+        let mut resource_dict = Dictionary::new();
+        resource_dict.set("name", table.path());
+        resource_dict.set("short_name", table.short_path());
+        if let Some(reflect_from_ptr) = registration.data::<ReflectFromPtr>() {
+            // SAFETY: ptr points to the resource this registration describes
+            let reflected = unsafe { reflect_from_ptr.as_reflect(ptr) };
+            resource_dict.set("value", &reflect_to_dict(reflected));
+        }
+        resources.push(&resource_dict.to_variant());
+    }
+    resources
+}
+
+fn debugger_diagnostics_system(world: &mut World) {
+    let Some(config) = world.get_resource::<DebuggerConfig>() else {
+        return;
+    };
+    if !config.enabled {
+        return;
+    }
+    let update_interval = config.update_interval;
+    let delta = world
+        .get_resource::<Time>()
+        .map(|t| t.delta_secs())
+        .unwrap_or(0.0);
+    {
+        let Some(mut timer) = world.get_resource_mut::<DebuggerTimer>() else {
+            return;
+        };
+        timer.diagnostics_elapsed += delta;
+        if timer.diagnostics_elapsed < update_interval {
+            return;
+        }
+        timer.diagnostics_elapsed = 0.0;
+    }
+
+    if !EngineDebugger::singleton().is_active() {
+        return;
+    }
+
+    let mut report = Dictionary::new();
+    let frame_ms = world
+        .get_resource::<Time<Real>>()
+        .map_or(0.0, |t| t.delta_secs_f64() * 1000.0);
+    report.set("frame_ms", frame_ms);
+    report.set("entity_count", world.entities().count_spawned() as i64);
+
+    // [path, value, suffix]
+    let mut diagnostics = VarArray::new();
+    if let Some(store) = world.get_resource::<DiagnosticsStore>() {
+        for diagnostic in store.iter().filter(|d| d.is_enabled) {
+            let Some(value) = diagnostic.smoothed() else {
+                continue;
+            };
+            let mut entry = VarArray::new();
+            entry.push(diagnostic.path().as_str());
+            entry.push(value);
+            entry.push(diagnostic.suffix.as_ref());
+            diagnostics.push(&entry.to_variant());
+        }
+    }
+    report.set("diagnostics", &diagnostics);
+
+    // [schedule, milliseconds]
+    let mut schedules = VarArray::new();
+    if let Some(timings) = world.get_resource::<ScheduleTimings>() {
+        for (label, elapsed) in timings.last_frame() {
+            let mut entry = VarArray::new();
+            entry.push(format!("{label:?}").as_str());
+            entry.push(elapsed.as_secs_f64() * 1000.0);
+            schedules.push(&entry.to_variant());
+        }
+    }
+    report.set("schedules", &schedules);
+
+    if let Some(stats) = world.get_resource::<SyncStats>() {
+        report.set("sync", &sync_stats_dict(stats));
+    }
+
+    // [system, Godot lookups], busiest first
+    #[cfg(feature = "trace_tracy")]
+    if let Some(lookups) = world.get_resource::<crate::plugins::core::GodotLookupStats>() {
+        let mut systems = VarArray::new();
+        for (system, count) in lookups.last_frame().iter().take(REPORTED_LOOKUP_SYSTEMS) {
+            let mut entry = VarArray::new();
+            entry.push(system.as_str());
+            entry.push(*count);
+            systems.push(&entry.to_variant());
+        }
+        report.set("godot_lookups", &systems);
+    }
+
+    #[cfg(feature = "multiplayer")]
+    if let Some(stats) = world.get_resource::<crate::plugins::multiplayer::NetStats>() {
+        report.set("network", &network_report(stats));
+    }
+
+    let mut data = VarArray::new();
+    data.push(&report.to_variant());
+    EngineDebugger::singleton().send_message("bevy:diagnostics", &data);
+}
+
+/// [`SyncStats`] counters by field name.
+pub(crate) fn sync_stats_dict(stats: &SyncStats) -> Dictionary {
+    let mut sync = Dictionary::new();
+    sync.set("nodes_added", stats.nodes_added);
+    sync.set("nodes_removed", stats.nodes_removed);
+    sync.set("transforms_read", stats.transforms_read);
+    sync.set("transforms_written", stats.transforms_written);
+    sync.set("signals_forwarded", stats.signals_forwarded);
+    sync.set("inputs_drained", stats.inputs_drained);
+    sync.set("deferred_commands", stats.deferred_commands);
+    sync
+}
+
+#[cfg(feature = "multiplayer")]
+fn network_report(stats: &crate::plugins::multiplayer::NetStats) -> Dictionary {
+    let mut network = Dictionary::new();
+    let transport = &stats.transport;
+    network.set("sent_bytes_per_sec", transport.sent_bytes_per_sec);
+    network.set("received_bytes_per_sec", transport.received_bytes_per_sec);
+    network.set("sent_packets_per_sec", transport.sent_packets_per_sec);
+    network.set(
+        "received_packets_per_sec",
+        transport.received_packets_per_sec,
+    );
+
+    // [type name, sent msg/s, sent B/s, received msg/s, received B/s]
+    let mut channels = VarArray::new();
+    for (kind, channel) in &stats.channels {
+        let mut entry = VarArray::new();
+        entry.push(*kind);
+        entry.push(channel.sent_messages_per_sec);
+        entry.push(channel.sent_bytes_per_sec);
+        entry.push(channel.received_messages_per_sec);
+        entry.push(channel.received_bytes_per_sec);
+        channels.push(&entry.to_variant());
+    }
+    network.set("channels", &channels);
+
+    // [peer, milliseconds]
+    let mut rtt = VarArray::new();
+    for (peer, elapsed) in &stats.rtt {
+        let mut entry = VarArray::new();
+        entry.push(*peer);
+        entry.push(elapsed.as_secs_f64() * 1000.0);
+        rtt.push(&entry.to_variant());
+    }
+    network.set("rtt", &rtt);
+
+    let replication = &stats.replication;
+    let mut counts = Dictionary::new();
+    counts.set("synchronized", replication.synchronized as i64);
+    counts.set("owned", replication.owned as i64);
+    counts.set("local_authority", replication.local_authority as i64);
+    counts.set("predicted", replication.predicted as i64);
+    network.set("replication", &counts);
+    network
 }
 
 /// Extract a short type name from a full path (e.g., "foo::bar::Baz" -> "Baz")
@@ -192,7 +669,7 @@ fn extract_short_name(full_name: String) -> (String, String) {
 }
 
 /// Convert a reflected value to a Godot Dictionary
-fn reflect_to_dict(value: &dyn PartialReflect) -> Dictionary {
+pub(crate) fn reflect_to_dict(value: &dyn PartialReflect) -> Dictionary {
     let mut dict = Dictionary::new();
 
     match value.reflect_ref() {
@@ -319,3 +796,125 @@ fn reflect_value_to_variant(value: &dyn PartialReflect) -> Variant {
     // For complex types, recurse
     reflect_to_dict(value).to_variant()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_ecs::component::Component;
+    use bevy_ecs::query::Without;
+    use bevy_reflect::TypePath;
+
+    #[derive(Component, Reflect, Default, Debug, PartialEq)]
+    #[reflect(Component, Default)]
+    struct Health(u32);
+
+    #[derive(Component, Reflect)]
+    #[reflect(Component)]
+    struct NoDefault;
+
+    #[derive(Resource, Reflect, Default, Debug, PartialEq)]
+    #[reflect(Resource, Default)]
+    struct Score(u32);
+
+    fn registry() -> TypeRegistry {
+        let mut registry = TypeRegistry::new();
+        registry.register::<Health>();
+        registry.register::<NoDefault>();
+        registry.register::<Score>();
+        registry
+    }
+
+    #[test]
+    fn editable_types_need_default() {
+        let registry = registry();
+        let (components, resources) = editable_types(&registry);
+        assert!(components.contains(&Health::type_path()));
+        assert!(!components.contains(&NoDefault::type_path()));
+        assert!(!components.contains(&Score::type_path()));
+        assert_eq!(resources, vec![Score::type_path()]);
+    }
+
+    #[test]
+    fn edits_add_and_remove_components() {
+        let registry = registry();
+        let mut world = World::new();
+
+        apply_edit(&mut world, &registry, &EditRequest::SpawnEntity).unwrap();
+        let entity = world
+            .query_filtered::<Entity, Without<IsResource>>()
+            .single(&world)
+            .unwrap();
+
+        let type_path = Health::type_path().to_string();
+        apply_edit(
+            &mut world,
+            &registry,
+            &EditRequest::InsertComponent {
+                entity,
+                type_path: type_path.clone(),
+            },
+        )
+        .unwrap();
+        assert_eq!(world.get::<Health>(entity), Some(&Health(0)));
+
+        apply_edit(
+            &mut world,
+            &registry,
+            &EditRequest::RemoveComponent { entity, type_path },
+        )
+        .unwrap();
+        assert!(world.get::<Health>(entity).is_none());
+
+        let no_default = EditRequest::InsertComponent {
+            entity,
+            type_path: NoDefault::type_path().to_string(),
+        };
+        assert!(apply_edit(&mut world, &registry, &no_default).is_err());
+
+        apply_edit(&mut world, &registry, &EditRequest::DespawnEntity(entity)).unwrap();
+        assert!(world.get_entity(entity).is_err());
+    }
+
+    #[test]
+    fn edits_add_and_remove_resources() {
+        let registry = registry();
+        let mut world = World::new();
+        let type_path = Score::type_path().to_string();
+
+        apply_edit(
+            &mut world,
+            &registry,
+            &EditRequest::InsertResource(type_path.clone()),
+        )
+        .unwrap();
+        assert_eq!(world.get_resource::<Score>(), Some(&Score(0)));
+
+        // Inserting again resets the existing resource rather than adding a second one.
+        world.resource_mut::<Score>().0 = 5;
+        apply_edit(
+            &mut world,
+            &registry,
+            &EditRequest::InsertResource(type_path.clone()),
+        )
+        .unwrap();
+        assert_eq!(world.get_resource::<Score>(), Some(&Score(0)));
+
+        let resource_entity = world
+            .resource_entities()
+            .get(world.component_id::<Score>().unwrap())
+            .unwrap();
+        assert!(
+            apply_edit(
+                &mut world,
+                &registry,
+                &EditRequest::DespawnEntity(resource_entity)
+            )
+            .is_err()
+        );
+
+        let remove = EditRequest::RemoveResource(type_path);
+        apply_edit(&mut world, &registry, &remove).unwrap();
+        assert!(!world.contains_resource::<Score>());
+        assert!(apply_edit(&mut world, &registry, &remove).is_err());
+    }
+}
@@ -0,0 +1,168 @@
+//! The queue behind the Godot calls the crate defers, and when it is flushed.
+//! See [`DeferredFlush`].
+
+use crate::plugins::core::GodotSyncSet;
+use bevy_app::{App, First, Last};
+use bevy_ecs::prelude::Resource;
+use bevy_ecs::schedule::{IntoScheduleConfigs, SystemSet};
+use bevy_ecs::system::{NonSendMut, Res, SystemParam};
+use bevy_ecs::world::World;
+use godot::builtin::{StringName, VarArray, Variant};
+use godot::classes::Object;
+use godot::obj::{Gd, InstanceId};
+
+/// When the Godot calls the crate defers are made: a node moved with
+/// `reparent_godot` or `move_to_index`, or a call queued on
+/// [`GodotDeferredCalls`]. The tree may be locked while a system runs, so these
+/// calls never happen inside one.
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DeferredFlush {
+    /// In `Last`, once this frame's systems have run, ahead of Godot's own
+    /// deferred queue. Visible in the frame that is about to be drawn.
+    #[default]
+    EndOfFrame,
+    /// Handed to Godot's `call_deferred`, to run in queue order with Godot's own
+    /// deferred calls once every node's `_process` is done. Still before the
+    /// frame is drawn, but after scripts' deferred calls made this frame.
+    GodotQueue,
+    /// At the start of the next Bevy frame, in `First` before the scene tree is
+    /// read, so the next frame's mirror already sees the result.
+    NextFrame,
+}
+
+/// The deferred calls run in this set: in `Last` with
+/// [`DeferredFlush::EndOfFrame`] (or handed to Godot with
+/// [`DeferredFlush::GodotQueue`]), and in `First`, inside
+/// [`GodotSyncSet::BeforeTreeUpdate`], with [`DeferredFlush::NextFrame`].
+/// Order a `Last` system that defers calls with `.before(DeferredCallSet::Flush)`.
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DeferredCallSet {
+    Flush,
+}
+
+struct DeferredCall {
+    object: InstanceId,
+    method: StringName,
+    args: Vec<Variant>,
+}
+
+/// The calls queued this frame, in order. Main-thread only: it holds `Variant`s.
+#[derive(Default)]
+pub(crate) struct DeferredCallQueue(Vec<DeferredCall>);
+
+impl DeferredCallQueue {
+    fn push(&mut self, object: InstanceId, method: StringName, args: Vec<Variant>) {
+        self.0.push(DeferredCall {
+            object,
+            method,
+            args,
+        });
+    }
+
+    /// Make every queued call now, skipping objects freed since.
+    fn run(&mut self) {
+        for call in self.0.drain(..) {
+            if let Ok(mut object) = Gd::<Object>::try_from_instance_id(call.object) {
+                object.callv(
+                    &call.method,
+                    &call.args.iter().cloned().collect::<VarArray>(),
+                );
+            }
+        }
+    }
+
+    /// Pass every queued call on to Godot's `call_deferred`.
+    fn hand_to_godot(&mut self) {
+        for call in self.0.drain(..) {
+            if let Ok(mut object) = Gd::<Object>::try_from_instance_id(call.object) {
+                object.call_deferred(&call.method, &call.args);
+            }
+        }
+    }
+}
+
+/// Defer a Godot call from a system, to run at the [`DeferredFlush`] point:
+///
+/// ```ignore
+/// fn open_menu(mut calls: GodotDeferredCalls, menu: Query<&GodotNodeHandle, With<Menu>>) {
+///     for handle in &menu {
+///         calls.call(handle.instance_id(), "grab_focus", &[]);
+///     }
+///     // Needed this frame, after a spawn made above: run the queue now.
+///     calls.flush_now();
+/// }
+/// ```
+#[derive(SystemParam)]
+pub struct GodotDeferredCalls<'w> {
+    queue: NonSendMut<'w, DeferredCallQueue>,
+}
+
+impl GodotDeferredCalls<'_> {
+    /// Queue `object.method(args)`. Skipped if the object is freed first.
+    pub fn call(&mut self, object: InstanceId, method: &str, args: &[Variant]) {
+        self.queue
+            .push(object, StringName::from(method), args.to_vec());
+    }
+
+    /// Make every queued call now, from this system, whatever [`DeferredFlush`]
+    /// says. Only safe where the tree isn't locked: not from a physics callback,
+    /// and not while the calls' nodes are being added or removed.
+    pub fn flush_now(&mut self) {
+        self.queue.run();
+    }
+
+    /// How many calls are waiting.
+    pub fn len(&self) -> usize {
+        self.queue.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.queue.0.is_empty()
+    }
+}
+
+/// Queue a call from a command, where the world is at hand. Falls back to
+/// Godot's `call_deferred` in an app without the queue.
+pub(crate) fn defer_call(
+    world: &mut World,
+    mut object: Gd<Object>,
+    method: &str,
+    args: &[Variant],
+) {
+    match world.get_non_send_mut::<DeferredCallQueue>() {
+        Some(mut queue) => queue.push(
+            object.instance_id(),
+            StringName::from(method),
+            args.to_vec(),
+        ),
+        None => {
+            object.call_deferred(method, args);
+        }
+    }
+}
+
+/// Installs the queue and its flush systems. Called by the core plugin.
+pub(crate) fn init_deferred_calls(app: &mut App) {
+    app.init_non_send::<DeferredCallQueue>()
+        .init_resource::<DeferredFlush>()
+        .configure_sets(
+            First,
+            DeferredCallSet::Flush.in_set(GodotSyncSet::BeforeTreeUpdate),
+        )
+        .add_systems(First, flush_next_frame.in_set(DeferredCallSet::Flush))
+        .add_systems(Last, flush_end_of_frame.in_set(DeferredCallSet::Flush));
+}
+
+fn flush_end_of_frame(mut queue: NonSendMut<DeferredCallQueue>, flush: Res<DeferredFlush>) {
+    match *flush {
+        DeferredFlush::EndOfFrame => queue.run(),
+        DeferredFlush::GodotQueue => queue.hand_to_godot(),
+        DeferredFlush::NextFrame => {}
+    }
+}
+
+fn flush_next_frame(mut queue: NonSendMut<DeferredCallQueue>, flush: Res<DeferredFlush>) {
+    if *flush == DeferredFlush::NextFrame {
+        queue.run();
+    }
+}
@@ -0,0 +1,742 @@
+//! Repeatable runs: a fixed-seed mode for the bridge, and an audit that hashes
+//! state every fixed tick to find where two runs drift apart.
+//!
+//! [`DeterminismPlugin`] makes a run repeatable for integration tests and
+//! replays. It provides a seeded [`DeterministicRng`], runs every schedule on the
+//! single-threaded executor so systems without an explicit order always run in
+//! the same order (and entities spawned from them, mirrored nodes included, get
+//! the same ids), connects deferred signals in entity order so observers of one
+//! signal fire in the same order, and can advance `Update` by exactly one physics
+//! step per frame instead of by wall-clock time:
+//!
+//! ```ignore
+//! app.add_plugins(DeterminismPlugin {
+//!     seed: 42,
+//!     lock_update_step: true,
+//! });
+//!
+//! fn spawn_loot(mut rng: ResMut<DeterministicRng>, mut commands: Commands) {
+//!     let gold = 10 + rng.below(20);
+//!     commands.spawn(Loot { gold });
+//! }
+//! ```
+//!
+//! Whether a run really repeats is what the audit checks.
+//!
+//! ## Audit
+//!
+//! A recording run stores, per fixed tick, one hash per audited component type
+//! plus every `ActionInput` it saw. Feeding that [`DeterminismLog`] back in
+//! replays the inputs at the same ticks and reports the first tick whose state
+//! differs, together with the systems that last wrote the diverging component
+//! in that tick:
+//!
+//! ```ignore
+//! // Run 1: record.
+//! app.add_plugins(DeterminismAuditPlugin)
+//!     .audit_component::<Health>()
+//!     .audit_component_with::<Transform>(hash_transform);
+//! // ... on quit:
+//! fn save(audit: Res<DeterminismAudit>) {
+//!     audit.log().save("/tmp/run.audit").unwrap();
+//! }
+//!
+//! // Run 2: replay and compare.
+//! let reference = DeterminismLog::load("/tmp/run.audit").unwrap();
+//! app.add_plugins(DeterminismAuditPlugin)
+//!     .insert_resource(DeterminismAudit::replaying(reference))
+//!     .audit_component::<Health>()
+//!     .audit_component_with::<Transform>(hash_transform)
+//!     .add_observer(|divergence: On<DeterminismDivergence>| {
+//!         panic!("{divergence:?}");
+//!     });
+//! ```
+//!
+//! Ticks are counted from the first fixed step, so both runs must start from the
+//! same state. Entity ids are part of the hash, so entities (and resources,
+//! which are entities too) must also be spawned in the same order. Only `ActionInput` is replayed; gameplay that polls
+//! `GodotActions` or reads raw device events is not covered.
+
+use std::fmt::Write as _;
+use std::hash::{Hash, Hasher};
+use std::io;
+use std::path::Path;
+
+use bevy_app::{
+    App, First, FixedFirst, FixedLast, FixedPostUpdate, FixedPreUpdate, FixedUpdate, Last, Plugin,
+    PostUpdate, PreUpdate, RunFixedMainLoop, RunFixedMainLoopSystems, Update,
+};
+use bevy_ecs::{
+    change_detection::{DetectChanges, Mut, Tick},
+    component::Component,
+    entity::Entity,
+    event::Event,
+    message::{MessageCursor, Messages},
+    prelude::Resource,
+    schedule::{IntoScheduleConfigs, ScheduleLabel, Schedules, SingleThreadedExecutor},
+    world::{Ref, World},
+};
+use bevy_time::{Time, TimeUpdateStrategy, Virtual};
+use bevy_transform::components::Transform;
+use tracing::error;
+
+use crate::plugins::input::ActionInput;
+
+/// Plugin that records a [`DeterminismLog`] every fixed tick, and compares it
+/// against a reference when [`DeterminismAudit::replaying`] is inserted.
+/// Not part of `GodotDefaultPlugins`; hashing every audited component each tick
+/// is meant for test runs, not shipped builds.
+pub struct DeterminismAuditPlugin;
+
+impl Plugin for DeterminismAuditPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_message::<ActionInput>()
+            .init_resource::<DeterminismAudit>()
+            .init_resource::<AuditedComponents>()
+            .add_systems(
+                RunFixedMainLoop,
+                (
+                    begin_audit_tick.in_set(RunFixedMainLoopSystems::BeforeFixedMainLoop),
+                    end_audit_tick.in_set(RunFixedMainLoopSystems::AfterFixedMainLoop),
+                ),
+            );
+    }
+}
+
+/// Plugin making a run repeatable: a seeded [`DeterministicRng`], a stable
+/// system and signal order, and optionally a fixed `Update` step. See the
+/// [module docs](self).
+///
+/// Not part of `GodotDefaultPlugins`: single-threaded schedules give up
+/// parallelism, which tests and replays can afford and shipped builds rarely
+/// want.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DeterminismPlugin {
+    /// Seed of the [`DeterministicRng`] resource.
+    pub seed: u64,
+    /// Advance `Update`'s clocks by one physics step per frame, ignoring how
+    /// long the frame really took. `Engine.time_scale` still scales the
+    /// virtual clock.
+    pub lock_update_step: bool,
+}
+
+impl Plugin for DeterminismPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(DeterministicRng::new(self.seed))
+            .init_resource::<StableOrder>();
+        let labels = [
+            First.intern(),
+            PreUpdate.intern(),
+            RunFixedMainLoop.intern(),
+            FixedFirst.intern(),
+            FixedPreUpdate.intern(),
+            FixedUpdate.intern(),
+            FixedPostUpdate.intern(),
+            FixedLast.intern(),
+            Update.intern(),
+            PostUpdate.intern(),
+            Last.intern(),
+        ];
+        for label in labels {
+            app.edit_schedule(label, |schedule| {
+                schedule.set_executor(SingleThreadedExecutor::new());
+            });
+        }
+        if self.lock_update_step {
+            app.insert_resource(TimeUpdateStrategy::FixedTimesteps(1));
+        }
+    }
+}
+
+/// Present while [`DeterminismPlugin`] is: systems that would otherwise handle
+/// entities in archetype order sort them first.
+#[derive(Resource, Default)]
+pub(crate) struct StableOrder;
+
+/// Choose which components take part in the per-tick hash.
+pub trait DeterminismAuditAppExt {
+    /// Audit a component through its `Hash` impl.
+    fn audit_component<T: Component + Hash>(&mut self) -> &mut Self;
+
+    /// Audit a component without a `Hash` impl, e.g. one holding floats.
+    /// See [`hash_transform`] for an example.
+    fn audit_component_with<T: Component>(&mut self, hash: fn(&T, &mut AuditHasher)) -> &mut Self;
+}
+
+impl DeterminismAuditAppExt for App {
+    fn audit_component<T: Component + Hash>(&mut self) -> &mut Self {
+        self.audit_component_with::<T>(|value, hasher| value.hash(hasher))
+    }
+
+    fn audit_component_with<T: Component>(&mut self, hash: fn(&T, &mut AuditHasher)) -> &mut Self {
+        self.init_resource::<AuditedComponents>();
+        self.world_mut()
+            .resource_mut::<AuditedComponents>()
+            .0
+            .push(AuditedComponent {
+                name: std::any::type_name::<T>(),
+                sample: Box::new(move |world, tick_start| sample::<T>(world, hash, tick_start)),
+            });
+        self
+    }
+}
+
+/// Hashes a `Transform` bit for bit, for [`audit_component_with`](DeterminismAuditAppExt::audit_component_with).
+pub fn hash_transform(transform: &Transform, hasher: &mut AuditHasher) {
+    for value in transform
+        .translation
+        .to_array()
+        .into_iter()
+        .chain(transform.rotation.to_array())
+        .chain(transform.scale.to_array())
+    {
+        hasher.write_u32(value.to_bits());
+    }
+}
+
+/// FNV-1a, so hashes are stable across runs, platforms and Rust versions.
+#[derive(Debug, Clone)]
+pub struct AuditHasher(u64);
+
+impl Default for AuditHasher {
+    fn default() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl Hasher for AuditHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= u64::from(*byte);
+            self.0 = self.0.wrapping_mul(0x0000_0100_0000_01b3);
+        }
+    }
+}
+
+/// Seeded random numbers, inserted by [`DeterminismPlugin`]: the same seed gives
+/// the same sequence on every run and platform.
+///
+/// SplitMix64 -- fast and well distributed, not for cryptography.
+#[derive(Resource, Debug, Clone, PartialEq, Eq)]
+pub struct DeterministicRng {
+    state: u64,
+}
+
+impl DeterministicRng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    pub fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+
+    /// Uniform in `[0, 1)`.
+    pub fn next_f32(&mut self) -> f32 {
+        (self.next_u64() >> 40) as f32 / (1u32 << 24) as f32
+    }
+
+    /// Uniform in `[0, 1)`.
+    pub fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// Uniform in `[0, bound)`. Panics if `bound` is 0.
+    pub fn below(&mut self, bound: u64) -> u64 {
+        assert!(bound > 0, "DeterministicRng::below(0)");
+        ((u128::from(self.next_u64()) * u128::from(bound)) >> 64) as u64
+    }
+
+    /// Uniform in `range`.
+    pub fn range(&mut self, range: std::ops::Range<f32>) -> f32 {
+        range.start + (range.end - range.start) * self.next_f32()
+    }
+
+    /// `true` with probability `p`.
+    pub fn chance(&mut self, p: f32) -> bool {
+        self.next_f32() < p
+    }
+
+    /// A generator with its own sequence, for work whose share of the numbers
+    /// shouldn't depend on what else drew from this one -- a world generator
+    /// on a task, say.
+    pub fn fork(&mut self) -> Self {
+        Self::new(self.next_u64())
+    }
+}
+
+/// State of the audit. Inserted by [`DeterminismAuditPlugin`] in recording mode.
+#[derive(Resource, Debug, Default)]
+pub struct DeterminismAudit {
+    log: DeterminismLog,
+    reference: Option<DeterminismLog>,
+    divergence: Option<DeterminismDivergence>,
+    tick: u64,
+    tick_start: Tick,
+    inputs: MessageCursor<ActionInput>,
+}
+
+impl DeterminismAudit {
+    /// Replay `reference`'s inputs and compare every tick against it.
+    pub fn replaying(reference: DeterminismLog) -> Self {
+        Self {
+            reference: Some(reference),
+            ..Self::default()
+        }
+    }
+
+    /// Everything recorded so far in this run.
+    pub fn log(&self) -> &DeterminismLog {
+        &self.log
+    }
+
+    /// The first divergence from the reference, if any.
+    pub fn divergence(&self) -> Option<&DeterminismDivergence> {
+        self.divergence.as_ref()
+    }
+
+    /// Number of fixed ticks audited so far.
+    pub fn tick(&self) -> u64 {
+        self.tick
+    }
+}
+
+/// Hashes and inputs of one run.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DeterminismLog {
+    /// One entry per fixed tick, starting at tick 1.
+    pub ticks: Vec<TickHashes>,
+    /// `ActionInput`s with the tick they were first visible in.
+    pub inputs: Vec<(u64, ActionInput)>,
+}
+
+/// Per-component hashes for one fixed tick.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TickHashes {
+    pub tick: u64,
+    /// `(type name, hash)` in registration order.
+    pub components: Vec<(String, u64)>,
+}
+
+impl DeterminismLog {
+    /// Write the log as tab-separated text.
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        std::fs::write(path, self.to_text())
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        let text = std::fs::read_to_string(path)?;
+        Self::parse(&text).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+    }
+
+    pub fn to_text(&self) -> String {
+        let mut text = String::new();
+        for (tick, input) in &self.inputs {
+            let _ = writeln!(
+                text,
+                "input\t{tick}\t{}\t{}\t{:08x}",
+                input.action,
+                u8::from(input.pressed),
+                input.strength.to_bits()
+            );
+        }
+        for tick in &self.ticks {
+            let _ = write!(text, "tick\t{}", tick.tick);
+            for (name, hash) in &tick.components {
+                let _ = write!(text, "\t{name}\t{hash:016x}");
+            }
+            text.push('\n');
+        }
+        text
+    }
+
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut log = Self::default();
+        for (number, line) in text.lines().enumerate() {
+            let bad = || format!("line {}: malformed audit entry", number + 1);
+            let fields: Vec<&str> = line.split('\t').collect();
+            match fields.as_slice() {
+                [] | [""] => {}
+                ["input", tick, action, pressed, strength] => {
+                    let strength = u32::from_str_radix(strength, 16).map_err(|_| bad())?;
+                    log.inputs.push((
+                        tick.parse().map_err(|_| bad())?,
+                        ActionInput {
+                            action: action.to_string(),
+                            pressed: *pressed == "1",
+                            strength: f32::from_bits(strength),
+                        },
+                    ));
+                }
+                ["tick", tick, rest @ ..] if rest.len() % 2 == 0 => {
+                    let components = rest
+                        .chunks(2)
+                        .map(|pair| {
+                            u64::from_str_radix(pair[1], 16)
+                                .map(|hash| (pair[0].to_string(), hash))
+                                .map_err(|_| bad())
+                        })
+                        .collect::<Result<_, _>>()?;
+                    log.ticks.push(TickHashes {
+                        tick: tick.parse().map_err(|_| bad())?,
+                        components,
+                    });
+                }
+                _ => return Err(bad()),
+            }
+        }
+        Ok(log)
+    }
+}
+
+/// Triggered once, at the first tick whose hashes differ from the reference.
+#[derive(Event, Debug, Clone, PartialEq)]
+pub struct DeterminismDivergence {
+    pub tick: u64,
+    /// Type name of the first audited component that differs.
+    pub component: String,
+    pub expected: u64,
+    pub actual: u64,
+    /// Systems in the fixed schedules that last wrote `component` during `tick`:
+    /// the final writer of each changed entity, not every system touching it.
+    /// Empty if the change came from commands or from outside the fixed loop.
+    pub systems: Vec<String>,
+}
+
+#[derive(Resource, Default)]
+struct AuditedComponents(Vec<AuditedComponent>);
+
+struct AuditedComponent {
+    name: &'static str,
+    sample: Box<dyn Fn(&mut World, Tick) -> ComponentSample + Send + Sync>,
+}
+
+struct ComponentSample {
+    hash: u64,
+    /// Change ticks of the components modified since the tick began.
+    changed_at: Vec<Tick>,
+}
+
+fn sample<T: Component>(
+    world: &mut World,
+    hash: fn(&T, &mut AuditHasher),
+    tick_start: Tick,
+) -> ComponentSample {
+    let this_run = world.read_change_tick();
+    let mut query = world.query::<(Entity, Ref<T>)>();
+    let mut total = 0u64;
+    let mut changed_at = Vec::new();
+    for (entity, value) in query.iter(world) {
+        let mut hasher = AuditHasher::default();
+        hasher.write_u64(entity.to_bits());
+        hash(&value, &mut hasher);
+        // Summed so the result doesn't depend on archetype iteration order.
+        total = total.wrapping_add(hasher.finish());
+        let changed = value.last_changed();
+        if changed.is_newer_than(tick_start, this_run) && !changed_at.contains(&changed) {
+            changed_at.push(changed);
+        }
+    }
+    ComponentSample {
+        hash: total,
+        changed_at,
+    }
+}
+
+fn begin_audit_tick(world: &mut World) {
+    // The fixed driver skips FixedMain while paused; don't count those steps.
+    if world.resource::<Time<Virtual>>().is_paused() {
+        return;
+    }
+    let tick_start = world.change_tick();
+    world.resource_scope(|world, mut audit: Mut<DeterminismAudit>| {
+        audit.tick += 1;
+        audit.tick_start = tick_start;
+        let tick = audit.tick;
+        let mut messages = world.resource_mut::<Messages<ActionInput>>();
+        let audit = &mut *audit;
+        match &audit.reference {
+            Some(reference) => {
+                audit.inputs.clear(&messages);
+                for (_, input) in reference.inputs.iter().filter(|(at, _)| *at == tick) {
+                    messages.write(input.clone());
+                    audit.log.inputs.push((tick, input.clone()));
+                }
+                audit.inputs.clear(&messages);
+            }
+            None => {
+                for input in audit.inputs.read(&messages) {
+                    audit.log.inputs.push((tick, input.clone()));
+                }
+            }
+        }
+    });
+}
+
+fn end_audit_tick(world: &mut World) {
+    if world.resource::<Time<Virtual>>().is_paused() {
+        return;
+    }
+    let (tick, tick_start) = {
+        let audit = world.resource::<DeterminismAudit>();
+        (audit.tick, audit.tick_start)
+    };
+    world.resource_scope(|world, audited: Mut<AuditedComponents>| {
+        let samples: Vec<(&'static str, ComponentSample)> = audited
+            .0
+            .iter()
+            .map(|component| (component.name, (component.sample)(world, tick_start)))
+            .collect();
+
+        let divergence = compare(world.resource::<DeterminismAudit>(), tick, &samples).map(
+            |(component, expected, sample)| DeterminismDivergence {
+                tick,
+                component: component.to_string(),
+                expected,
+                actual: sample.hash,
+                systems: writers(world, &sample.changed_at),
+            },
+        );
+
+        let mut audit = world.resource_mut::<DeterminismAudit>();
+        audit.log.ticks.push(TickHashes {
+            tick,
+            components: samples
+                .iter()
+                .map(|(name, sample)| (name.to_string(), sample.hash))
+                .collect(),
+        });
+        if let Some(divergence) = divergence {
+            error!(
+                "determinism audit: {} diverged at fixed tick {} (last written by {:?})",
+                divergence.component, divergence.tick, divergence.systems
+            );
+            audit.divergence = Some(divergence.clone());
+            world.trigger(divergence);
+        }
+    });
+}
+
+/// The first sampled component whose hash differs from the reference at `tick`,
+/// unless a divergence was already reported or the reference has ended.
+fn compare<'a>(
+    audit: &DeterminismAudit,
+    tick: u64,
+    samples: &'a [(&'static str, ComponentSample)],
+) -> Option<(&'static str, u64, &'a ComponentSample)> {
+    if audit.divergence.is_some() {
+        return None;
+    }
+    let expected = audit
+        .reference
+        .as_ref()?
+        .ticks
+        .iter()
+        .find(|entry| entry.tick == tick)?;
+    samples.iter().find_map(|(name, sample)| {
+        let expected = expected
+            .components
+            .iter()
+            .find(|(expected_name, _)| expected_name == name)
+            .map_or(0, |(_, hash)| *hash);
+        (expected != sample.hash).then_some((*name, expected, sample))
+    })
+}
+
+/// Names of the fixed-schedule systems whose last run matches one of `changed_at`.
+fn writers(world: &World, changed_at: &[Tick]) -> Vec<String> {
+    let Some(schedules) = world.get_resource::<Schedules>() else {
+        return Vec::new();
+    };
+    let labels = [
+        FixedFirst.intern(),
+        FixedPreUpdate.intern(),
+        FixedUpdate.intern(),
+        FixedPostUpdate.intern(),
+        FixedLast.intern(),
+    ];
+    let mut names = Vec::new();
+    for label in labels {
+        let Some(Ok(systems)) = schedules.get(label).map(|schedule| schedule.systems()) else {
+            continue;
+        };
+        for (_, system) in systems {
+            if changed_at.contains(&system.get_last_run()) {
+                names.push(system.name().to_string());
+            }
+        }
+    }
+    names
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::plugins::fixed_schedule::{host_fixed_main_loop, run_godot_fixed_main};
+    use bevy_ecs::observer::On;
+    use bevy_ecs::system::{Query, Res, ResMut};
+    use bevy_time::TimePlugin;
+    use std::time::Duration;
+
+    #[derive(Component, Hash)]
+    struct Counter(u32);
+
+    #[derive(Resource, Default)]
+    struct Divergences(Vec<DeterminismDivergence>);
+
+    /// Tick at which `glitch` corrupts the counters. Always inserted: resources
+    /// are entities too, so inserting it in one run only would shift entity ids.
+    #[derive(Resource)]
+    struct GlitchAt(Option<u64>);
+
+    fn count(mut counters: Query<&mut Counter>) {
+        for mut counter in &mut counters {
+            counter.0 += 1;
+        }
+    }
+
+    fn glitch(
+        glitch_at: Res<GlitchAt>,
+        audit: Res<DeterminismAudit>,
+        mut counters: Query<&mut Counter>,
+    ) {
+        if glitch_at.0 == Some(audit.tick()) {
+            for mut counter in &mut counters {
+                counter.0 += 100;
+            }
+        }
+    }
+
+    fn make_app(audit: DeterminismAudit, glitch_at: Option<u64>) -> App {
+        let mut app = App::new();
+        app.add_plugins(TimePlugin);
+        host_fixed_main_loop(&mut app);
+        app.add_plugins(DeterminismAuditPlugin)
+            .insert_resource(audit)
+            .audit_component::<Counter>()
+            .init_resource::<Divergences>()
+            .add_observer(
+                |divergence: On<DeterminismDivergence>, mut seen: ResMut<Divergences>| {
+                    seen.0.push(divergence.event().clone());
+                },
+            )
+            .add_systems(FixedUpdate, count)
+            .insert_resource(GlitchAt(glitch_at))
+            .add_systems(FixedPostUpdate, glitch);
+        app.world_mut().spawn(Counter(0));
+        app
+    }
+
+    fn step(app: &mut App, steps: usize) {
+        for _ in 0..steps {
+            run_godot_fixed_main(app.world_mut(), Duration::from_secs_f64(1.0 / 60.0));
+        }
+    }
+
+    fn send(app: &mut App, action: &str) {
+        app.world_mut()
+            .resource_mut::<Messages<ActionInput>>()
+            .write(ActionInput {
+                action: action.to_string(),
+                pressed: true,
+                strength: 1.0,
+            });
+    }
+
+    fn record() -> DeterminismLog {
+        let mut app = make_app(DeterminismAudit::default(), None);
+        step(&mut app, 2);
+        send(&mut app, "jump");
+        step(&mut app, 3);
+        app.world().resource::<DeterminismAudit>().log().clone()
+    }
+
+    #[test]
+    fn recording_hashes_every_tick_and_tags_inputs() {
+        let log = record();
+        assert_eq!(log.ticks.len(), 5);
+        assert_eq!(log.ticks[0].tick, 1);
+        assert_ne!(log.ticks[0].components[0].1, log.ticks[1].components[0].1);
+        assert_eq!(log.inputs.len(), 1);
+        assert_eq!(log.inputs[0].0, 3);
+        assert_eq!(log.inputs[0].1.action, "jump");
+    }
+
+    #[test]
+    fn log_round_trips_through_text() {
+        let log = record();
+        assert_eq!(DeterminismLog::parse(&log.to_text()), Ok(log));
+        assert!(DeterminismLog::parse("tick\tx").is_err());
+    }
+
+    #[test]
+    fn identical_replay_does_not_diverge_and_replays_inputs() {
+        let reference = record();
+        let mut app = make_app(DeterminismAudit::replaying(reference.clone()), None);
+        step(&mut app, 5);
+        let audit = app.world().resource::<DeterminismAudit>();
+        assert_eq!(audit.divergence(), None);
+        assert_eq!(audit.log(), &reference);
+        assert!(app.world().resource::<Divergences>().0.is_empty());
+    }
+
+    #[test]
+    fn replay_reports_first_divergent_tick_and_writer() {
+        let reference = record();
+        let mut app = make_app(DeterminismAudit::replaying(reference), Some(4));
+        step(&mut app, 5);
+
+        let seen = &app.world().resource::<Divergences>().0;
+        assert_eq!(seen.len(), 1, "only the first divergence is reported");
+        let divergence = &seen[0];
+        assert_eq!(divergence.tick, 4);
+        assert!(divergence.component.ends_with("Counter"));
+        assert_ne!(divergence.expected, divergence.actual);
+        assert_eq!(divergence.systems.len(), 1, "{:?}", divergence.systems);
+        assert!(divergence.systems[0].ends_with("glitch"));
+    }
+
+    #[test]
+    fn seeded_rng_repeats_and_stays_in_range() {
+        let mut a = DeterministicRng::new(7);
+        let mut b = DeterministicRng::new(7);
+        let first: Vec<u64> = (0..4).map(|_| a.next_u64()).collect();
+        assert_eq!(first, (0..4).map(|_| b.next_u64()).collect::<Vec<_>>());
+        assert_ne!(DeterministicRng::new(8).next_u64(), first[0]);
+
+        for _ in 0..1000 {
+            assert!((0.0..1.0).contains(&a.next_f32()));
+            assert!(a.below(3) < 3);
+            assert!((-2.0..2.0).contains(&a.range(-2.0..2.0)));
+        }
+    }
+
+    #[test]
+    fn locked_update_step_advances_by_the_fixed_timestep() {
+        let mut app = App::new();
+        app.add_plugins(TimePlugin).add_plugins(DeterminismPlugin {
+            seed: 1,
+            lock_update_step: true,
+        });
+        let step = app.world().resource::<Time<bevy_time::Fixed>>().timestep();
+        app.update();
+        app.update();
+        assert_eq!(app.world().resource::<Time<Virtual>>().delta(), step);
+        assert_eq!(
+            app.world().resource::<DeterministicRng>(),
+            &DeterministicRng::new(1)
+        );
+    }
+}
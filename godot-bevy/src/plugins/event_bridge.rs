@@ -1,4 +1,5 @@
 use crate::app::BevyApp;
+use crate::plugins::core::GodotSyncSet;
 use crate::plugins::signals::{SignalDispatch, SignalEnvelope};
 use bevy_app::{App, First};
 use bevy_ecs::event::Event;
@@ -10,8 +11,10 @@ use godot::prelude::{FromGodot, Variant};
 use parking_lot::Mutex;
 use std::collections::HashMap;
 
-/// The event drain runs in this set, in `First`. Order a system around delivery
-/// with `.after(EventBridgeSet::Drain)`; observers themselves fire inside it.
+/// The event drain runs in this set, in `First`, inside
+/// [`GodotSyncSet::SignalPump`]. Order a system around delivery with
+/// `.after(EventBridgeSet::Drain)`; observers themselves fire inside it.
+/// Signals forwarded by `GodotSignals` are delivered just before it.
 #[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
 pub enum EventBridgeSet {
     Drain,
@@ -57,7 +60,11 @@ pub(crate) fn ensure_event_channel(app: &mut App) {
     app.world_mut().insert_resource(GodotEventSender(tx));
     app.world_mut()
         .insert_resource(GodotEventReceiver(Mutex::new(rx)));
-    app.add_systems(
+    app.configure_sets(
+        First,
+        EventBridgeSet::Drain.in_set(GodotSyncSet::SignalPump),
+    )
+    .add_systems(
         First,
         drain_and_trigger_events.in_set(EventBridgeSet::Drain),
     );
@@ -66,6 +73,7 @@ pub(crate) fn ensure_event_channel(app: &mut App) {
 /// Mirror of `drain_and_trigger_signals`: collect via `try_iter` (consume-once)
 /// to avoid overlapping `world` borrows, then trigger each box.
 fn drain_and_trigger_events(world: &mut bevy_ecs::world::World) {
+    crate::profile_scope!("drain_and_trigger_events");
     let mut pending: Vec<Box<dyn SignalDispatch>> = Vec::new();
     if let Some(receiver) = world.get_resource::<GodotEventReceiver>() {
         pending.extend(receiver.0.lock().try_iter());
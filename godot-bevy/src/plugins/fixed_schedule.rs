@@ -8,10 +8,15 @@ use std::time::Duration;
 
 use bevy_app::{App, FixedMain, MainScheduleOrder, RunFixedMainLoop, RunFixedMainLoopSystems};
 use bevy_ecs::resource::Resource;
-use bevy_ecs::schedule::{IntoScheduleConfigs, ScheduleCleanupPolicy, ScheduleLabel};
+use bevy_ecs::schedule::{
+    InternedScheduleLabel, IntoScheduleConfigs, ScheduleCleanupPolicy, ScheduleLabel,
+};
 use bevy_ecs::world::World;
+use bevy_platform::time::Instant;
 use bevy_time::{Fixed, Time, Virtual};
 
+use crate::plugins::core::{ScheduleTimings, SyncStats};
+
 /// Marker that occupies the slot `RunFixedMainLoop` held in `MainScheduleOrder`.
 /// Not registered as a real schedule -- `app.update()` calls `try_run_schedule`
 /// on it, which no-ops silently. This is the active split point: schedules
@@ -123,7 +128,20 @@ fn godot_fixed_driver(world: &mut World) {
 /// at the end of `_process` in `app.rs` (after the suffix).
 pub(crate) fn run_godot_fixed_main(world: &mut World, delta: Duration) {
     world.resource_mut::<GodotFixedDelta>().0 = delta;
-    world.try_run_schedule(RunFixedMainLoop).ok();
+    run_timed(world, RunFixedMainLoop.intern());
+}
+
+/// `try_run_schedule`, adding the elapsed time to [`ScheduleTimings`] if present.
+fn run_timed(world: &mut World, label: InternedScheduleLabel) {
+    if !world.contains_resource::<ScheduleTimings>() {
+        let _ = world.try_run_schedule(label);
+        return;
+    }
+    let start = Instant::now();
+    let _ = world.try_run_schedule(label);
+    if let Some(mut timings) = world.get_resource_mut::<ScheduleTimings>() {
+        timings.record(label, start.elapsed());
+    }
 }
 
 // ── split-Main helpers ────────────────────────────────────────────────────────
@@ -156,10 +174,21 @@ pub(crate) fn run_startup(world: &mut World) {
 /// Run all schedules before the split marker (First, PreUpdate, StateTransition, …).
 /// Never calls `clear_trackers`.
 pub(crate) fn run_main_prefix(world: &mut World) {
+    // The prefix opens the frame, so the counters start fresh here.
+    if let Some(mut stats) = world.get_resource_mut::<SyncStats>() {
+        stats.reset();
+    }
+    if let Some(mut timings) = world.get_resource_mut::<ScheduleTimings>() {
+        timings.finish_frame();
+    }
+    #[cfg(feature = "trace_tracy")]
+    if let Some(mut lookups) = world.get_resource_mut::<crate::plugins::core::GodotLookupStats>() {
+        lookups.finish_frame();
+    }
     world.resource_scope(|world, order: bevy_ecs::world::Mut<MainScheduleOrder>| {
         let i = split_idx(&order);
         for &label in &order.labels[..i] {
-            let _ = world.try_run_schedule(label);
+            run_timed(world, label);
         }
     });
 }
@@ -170,7 +199,7 @@ pub(crate) fn run_main_suffix(world: &mut World) {
     world.resource_scope(|world, order: bevy_ecs::world::Mut<MainScheduleOrder>| {
         let i = split_idx(&order);
         for &label in &order.labels[i + 1..] {
-            let _ = world.try_run_schedule(label);
+            run_timed(world, label);
         }
     });
 }
@@ -210,7 +239,7 @@ pub(crate) fn run_physics_step(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use bevy_app::{App, FixedUpdate, PostStartup, PreStartup, PreUpdate, Startup, Update};
+    use bevy_app::{App, First, FixedUpdate, PostStartup, PreStartup, PreUpdate, Startup, Update};
     use bevy_ecs::prelude::*;
     use bevy_time::TimePlugin;
 
@@ -464,6 +493,60 @@ mod tests {
         assert_eq!(c.suffix, 1, "Update runs in suffix");
     }
 
+    #[test]
+    fn sync_stats_reset_when_prefix_opens_the_frame() {
+        let mut app = hosted_app();
+        app.init_resource::<SyncStats>();
+        app.add_systems(First, |mut stats: ResMut<SyncStats>| stats.nodes_added += 2);
+        app.add_systems(Update, |mut stats: ResMut<SyncStats>| {
+            stats.transforms_written += 1
+        });
+
+        for _ in 0..2 {
+            run_main_prefix(app.world_mut());
+            run_main_suffix(app.world_mut());
+        }
+
+        let stats = app.world().resource::<SyncStats>();
+        assert_eq!(stats.nodes_added, 2, "only this frame's additions");
+        assert_eq!(
+            stats.transforms_written, 1,
+            "suffix work survives to frame end"
+        );
+    }
+
+    #[test]
+    fn schedule_timings_cover_the_previous_frame() {
+        let mut app = hosted_app();
+        app.init_resource::<ScheduleTimings>();
+
+        run_main_prefix(app.world_mut());
+        run_godot_fixed_main(app.world_mut(), Duration::from_millis(16));
+        run_godot_fixed_main(app.world_mut(), Duration::from_millis(16));
+        run_main_suffix(app.world_mut());
+        assert!(
+            app.world()
+                .resource::<ScheduleTimings>()
+                .last_frame()
+                .is_empty(),
+            "nothing is published until the next frame opens"
+        );
+
+        run_main_prefix(app.world_mut());
+        let timings = app.world().resource::<ScheduleTimings>();
+        let labels: Vec<_> = timings.last_frame().iter().map(|(l, _)| *l).collect();
+        assert_eq!(labels.first(), Some(&First.intern()));
+        assert!(labels.contains(&Update.intern()));
+        assert_eq!(
+            labels
+                .iter()
+                .filter(|l| **l == RunFixedMainLoop.intern())
+                .count(),
+            1,
+            "fixed steps are summed under one entry"
+        );
+    }
+
     #[test]
     fn state_transition_runs_in_prefix() {
         // Concrete consumer: bevy_state's StatesPlugin inserts StateTransition after
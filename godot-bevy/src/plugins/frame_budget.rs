@@ -0,0 +1,307 @@
+//! Frame budget watchdog: warn when the Bevy side of a frame takes too long.
+//!
+//! [`FrameBudgetPlugin`] reads the per-schedule [`ScheduleTimings`] of every
+//! finished frame and triggers [`FrameBudgetExceeded`] when the total, or any
+//! schedule with its own budget, went over. Useful on low-end test devices
+//! to catch regressions before they turn into dropped frames.
+//!
+//! ```ignore
+//! app.add_plugins(FrameBudgetPlugin {
+//!     budget: Duration::from_millis(6),
+//!     overlay: true,
+//! })
+//! .add_observer(|exceeded: On<FrameBudgetExceeded>| {
+//!     telemetry::record("frame_budget", exceeded.total);
+//! });
+//! ```
+//!
+//! Only time spent in Bevy schedules is measured; Godot's own rendering and
+//! physics are not included.
+
+use std::time::Duration;
+
+use bevy_app::{App, First, Last, Plugin};
+use bevy_ecs::event::Event;
+use bevy_ecs::prelude::Resource;
+use bevy_ecs::schedule::{InternedScheduleLabel, ScheduleLabel};
+use bevy_ecs::system::{Commands, Local, Res, ResMut};
+use bevy_time::{Real, Time};
+use godot::builtin::Color;
+use godot::classes::{CanvasLayer, Label, control, node};
+use godot::obj::{InstanceId, NewAlloc};
+use tracing::warn;
+
+use crate::interop::GodotAccess;
+use crate::plugins::core::ScheduleTimings;
+use crate::plugins::scene_tree::SceneTreeRef;
+use crate::watchers::scene_tree_watcher::exclude_from_mirror;
+
+/// Above the console, so the numbers stay visible while it is open.
+const OVERLAY_LAYER: i32 = 210;
+
+/// Plugin adding the frame budget watchdog. See the [module docs](self).
+pub struct FrameBudgetPlugin {
+    /// Time all Bevy schedules together may take per frame.
+    pub budget: Duration,
+    /// Show the last frame's time in a corner of the screen, red when over budget.
+    pub overlay: bool,
+}
+
+impl Default for FrameBudgetPlugin {
+    fn default() -> Self {
+        Self {
+            // Half a 60 Hz frame, leaving the rest to Godot.
+            budget: Duration::from_micros(8_333),
+            overlay: false,
+        }
+    }
+}
+
+impl Plugin for FrameBudgetPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ScheduleTimings>()
+            .insert_resource(FrameBudget::new(self.budget))
+            .init_resource::<LastFrameBudget>()
+            .add_systems(First, check_frame_budget);
+        if self.overlay {
+            app.add_systems(Last, update_budget_overlay);
+        }
+    }
+}
+
+/// Budgets checked by [`FrameBudgetPlugin`]; change them at runtime as needed.
+#[derive(Resource, Debug, Clone)]
+pub struct FrameBudget {
+    /// Time all schedules together may take per frame.
+    pub total: Duration,
+    /// Budgets for single schedules, checked on top of the total.
+    pub schedules: Vec<(InternedScheduleLabel, Duration)>,
+    /// Minimum time between two logged warnings. [`FrameBudgetExceeded`] is
+    /// triggered for every frame regardless.
+    pub warn_interval: Duration,
+}
+
+impl FrameBudget {
+    pub fn new(total: Duration) -> Self {
+        Self {
+            total,
+            schedules: Vec::new(),
+            warn_interval: Duration::from_secs(1),
+        }
+    }
+
+    /// Also flag frames where `schedule` alone takes longer than `budget`.
+    pub fn with_schedule_budget(mut self, schedule: impl ScheduleLabel, budget: Duration) -> Self {
+        let schedule = schedule.intern();
+        self.schedules.retain(|(label, _)| *label != schedule);
+        self.schedules.push((schedule, budget));
+        self
+    }
+
+    /// Schedules of `timings` over their own budget.
+    fn over_budget(
+        &self,
+        timings: &[(InternedScheduleLabel, Duration)],
+    ) -> Vec<InternedScheduleLabel> {
+        timings
+            .iter()
+            .filter(|(label, elapsed)| {
+                self.schedules
+                    .iter()
+                    .any(|(budgeted, budget)| budgeted == label && elapsed > budget)
+            })
+            .map(|(label, _)| *label)
+            .collect()
+    }
+}
+
+/// Triggered in `First` when the previous frame went over its [`FrameBudget`].
+#[derive(Event, Debug, Clone)]
+pub struct FrameBudgetExceeded {
+    /// Time all schedules took.
+    pub total: Duration,
+    /// The total budget.
+    pub budget: Duration,
+    /// Time per schedule, in run order.
+    pub schedules: Vec<(InternedScheduleLabel, Duration)>,
+    /// Schedules over their own budget, if any were set.
+    pub over_budget: Vec<InternedScheduleLabel>,
+}
+
+impl FrameBudgetExceeded {
+    /// The schedules, slowest first, as `Update 4.10ms, First 0.52ms, ...`.
+    pub fn breakdown(&self) -> String {
+        let mut schedules = self.schedules.clone();
+        schedules.sort_by_key(|(_, elapsed)| std::cmp::Reverse(*elapsed));
+        schedules
+            .iter()
+            .map(|(label, elapsed)| format!("{label:?} {:.2}ms", elapsed.as_secs_f64() * 1000.0))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+/// The previous frame's total, for the overlay.
+#[derive(Resource, Debug, Default)]
+struct LastFrameBudget {
+    total: Duration,
+    exceeded: bool,
+}
+
+fn check_frame_budget(
+    timings: Res<ScheduleTimings>,
+    budget: Res<FrameBudget>,
+    time: Option<Res<Time<Real>>>,
+    mut last: ResMut<LastFrameBudget>,
+    mut last_warning: Local<Option<Duration>>,
+    mut commands: Commands,
+) {
+    let schedules = timings.last_frame();
+    if schedules.is_empty() {
+        return;
+    }
+    let total: Duration = schedules.iter().map(|(_, elapsed)| *elapsed).sum();
+    let over_budget = budget.over_budget(schedules);
+    let exceeded = total > budget.total || !over_budget.is_empty();
+    *last = LastFrameBudget { total, exceeded };
+    if !exceeded {
+        return;
+    }
+
+    let event = FrameBudgetExceeded {
+        total,
+        budget: budget.total,
+        schedules: schedules.to_vec(),
+        over_budget,
+    };
+    let now = time.map(|time| time.elapsed()).unwrap_or_default();
+    if last_warning.is_none_or(|at| now.saturating_sub(at) >= budget.warn_interval) {
+        *last_warning = Some(now);
+        warn!(
+            "frame budget exceeded: {:.2}ms of {:.2}ms ({})",
+            total.as_secs_f64() * 1000.0,
+            budget.total.as_secs_f64() * 1000.0,
+            event.breakdown()
+        );
+    }
+    commands.trigger(event);
+}
+
+fn update_budget_overlay(
+    mut label_id: Local<Option<InstanceId>>,
+    last: Res<LastFrameBudget>,
+    budget: Res<FrameBudget>,
+    mut scene_tree: SceneTreeRef,
+    mut godot: GodotAccess,
+) {
+    let mut label = match label_id.and_then(|id| godot.try_get_instance_id::<Label>(id)) {
+        Some(label) => label,
+        None => {
+            let Some(label) = build_overlay(&mut scene_tree) else {
+                return;
+            };
+            *label_id = Some(label.instance_id());
+            label
+        }
+    };
+    label.set_text(&format!(
+        "bevy {:.2} / {:.2} ms",
+        last.total.as_secs_f64() * 1000.0,
+        budget.total.as_secs_f64() * 1000.0
+    ));
+    let color = if last.exceeded {
+        Color::from_rgb(1.0, 0.45, 0.4)
+    } else {
+        Color::from_rgb(0.75, 0.95, 0.75)
+    };
+    label.add_theme_color_override("font_color", color);
+}
+
+fn build_overlay(scene_tree: &mut SceneTreeRef) -> Option<godot::obj::Gd<Label>> {
+    let mut root = scene_tree.get().get_root()?;
+
+    let mut layer = CanvasLayer::new_alloc();
+    layer.set_name("GodotBevyFrameBudget");
+    layer.set_layer(OVERLAY_LAYER);
+    layer.set_process_mode(node::ProcessMode::ALWAYS);
+    exclude_from_mirror(&mut layer);
+
+    let mut label = Label::new_alloc();
+    label.set_anchors_preset(control::LayoutPreset::TOP_RIGHT);
+    label.set_horizontal_alignment(godot::global::HorizontalAlignment::RIGHT);
+    label.set_mouse_filter(control::MouseFilter::IGNORE);
+
+    layer.add_child(&label);
+    root.add_child(&layer);
+    Some(label)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::plugins::fixed_schedule::{host_fixed_main_loop, run_main_prefix, run_main_suffix};
+    use bevy_app::Update;
+    use bevy_ecs::observer::On;
+    use bevy_time::TimePlugin;
+
+    #[derive(Resource, Default)]
+    struct Seen(Vec<FrameBudgetExceeded>);
+
+    fn make_app(budget: FrameBudget) -> App {
+        let mut app = App::new();
+        app.add_plugins(TimePlugin);
+        host_fixed_main_loop(&mut app);
+        app.add_plugins(FrameBudgetPlugin::default())
+            .insert_resource(budget)
+            .init_resource::<Seen>()
+            .add_observer(
+                |exceeded: On<FrameBudgetExceeded>, mut seen: ResMut<Seen>| {
+                    seen.0.push(exceeded.event().clone());
+                },
+            )
+            .add_systems(Update, || std::thread::sleep(Duration::from_millis(2)));
+        app
+    }
+
+    fn frame(app: &mut App) {
+        run_main_prefix(app.world_mut());
+        run_main_suffix(app.world_mut());
+    }
+
+    #[test]
+    fn slow_frames_trigger_with_a_breakdown() {
+        let mut app = make_app(FrameBudget::new(Duration::from_millis(1)));
+        frame(&mut app);
+        assert!(
+            app.world().resource::<Seen>().0.is_empty(),
+            "no finished frame yet"
+        );
+
+        frame(&mut app);
+        let seen = &app.world().resource::<Seen>().0;
+        assert_eq!(seen.len(), 1);
+        assert!(seen[0].total >= Duration::from_millis(2));
+        assert!(seen[0].over_budget.is_empty());
+        assert!(seen[0].breakdown().starts_with("Update "));
+    }
+
+    #[test]
+    fn schedule_budgets_flag_single_schedules() {
+        let budget = FrameBudget::new(Duration::from_secs(1))
+            .with_schedule_budget(Update, Duration::from_millis(1))
+            .with_schedule_budget(First, Duration::from_secs(1));
+        let mut app = make_app(budget);
+        frame(&mut app);
+        frame(&mut app);
+        let seen = &app.world().resource::<Seen>().0;
+        assert_eq!(seen.len(), 1);
+        assert_eq!(seen[0].over_budget, vec![Update.intern()]);
+
+        app.world_mut()
+            .resource_mut::<FrameBudget>()
+            .schedules
+            .clear();
+        frame(&mut app);
+        assert_eq!(app.world().resource::<Seen>().0.len(), 1);
+    }
+}
@@ -1,19 +1,32 @@
-use bevy_app::{App, Plugin};
+use bevy_app::{App, Plugin, Startup};
+use bevy_ecs::prelude::Resource;
+use bevy_ecs::system::{Res, ResMut};
 use bevy_log::{
     Level, tracing,
     tracing_subscriber::{self, EnvFilter},
 };
 use chrono::Local;
 use godot::global::{godot_error, godot_print, godot_print_rich, godot_warn};
+use parking_lot::{Mutex, RwLock};
 use std::{
+    collections::VecDeque,
     error::Error,
     path::{MAIN_SEPARATOR_STR, Path},
+    str::FromStr,
     string::ParseError,
+    sync::Arc,
 };
+use tracing::{Metadata, subscriber::Interest};
 use tracing_subscriber::{
-    Layer, field::Visit, filter::FromEnvError, layer::SubscriberExt, util::SubscriberInitExt,
+    Layer,
+    field::Visit,
+    filter::{FromEnvError, LevelFilter},
+    layer::{Context, SubscriberExt},
+    util::SubscriberInitExt,
 };
 
+use crate::plugins::console::GodotConsole;
+
 /// NOTE: This plugin is only available if the `godot_bevy_log` feature is enabled
 pub struct GodotBevyLogPlugin {
     /// Filters logs using the [`EnvFilter`] format
@@ -33,6 +46,9 @@ pub struct GodotBevyLogPlugin {
     /// You can disable the timestamp entirely by providing `None`.
     /// Example default format: `11:30:37.631`
     pub timestamp_format: Option<String>,
+
+    /// How many recent log records [`GodotLogBuffer`] keeps. Defaults to 256.
+    pub buffer_capacity: usize,
 }
 
 impl Default for GodotBevyLogPlugin {
@@ -43,12 +59,13 @@ impl Default for GodotBevyLogPlugin {
             color: true,
             // Timestamp formatting reference https://docs.rs/chrono/0.4.41/chrono/format/strftime/index.html
             timestamp_format: Some("%T%.3f".to_owned()),
+            buffer_capacity: 256,
         }
     }
 }
 
 impl Plugin for GodotBevyLogPlugin {
-    fn build(&self, _app: &mut App) {
+    fn build(&self, app: &mut App) {
         // Copied behavior from https://docs.rs/bevy_log/0.16.1/src/bevy_log/lib.rs.html#279
         let default_filter = { format!("{},{}", self.level, self.filter) };
         let filter_layer = EnvFilter::try_from_default_env()
@@ -67,15 +84,26 @@ impl Plugin for GodotBevyLogPlugin {
             })
             .unwrap();
 
+        let buffer = GodotLogBuffer::new(self.buffer_capacity);
+        let module_filters = GodotLogFilters::default();
         let godot_proxy_layer = GodotProxyLayer {
             color: self.color,
             timestamp_format: self.timestamp_format.clone(),
+            buffer: buffer.clone(),
         };
 
+        // Lets strict-threading panics name the system that touched Godot.
+        #[cfg(feature = "strict-threading")]
+        let system_names = Some(crate::interop::thread_check::systems::SystemSpanLayer);
+        #[cfg(not(feature = "strict-threading"))]
+        let system_names: Option<tracing_subscriber::layer::Identity> = None;
+
         #[cfg(feature = "trace_tracy")]
         tracing_subscriber::registry()
             .with(godot_proxy_layer)
             .with(filter_layer)
+            .with(ModuleFilterLayer(module_filters.clone()))
+            .with(system_names)
             .with(tracing_tracy::TracyLayer::default())
             .init();
 
@@ -83,7 +111,207 @@ impl Plugin for GodotBevyLogPlugin {
         tracing_subscriber::registry()
             .with(godot_proxy_layer)
             .with(filter_layer)
+            .with(ModuleFilterLayer(module_filters.clone()))
+            .with(system_names)
             .init();
+
+        app.insert_resource(buffer)
+            .insert_resource(module_filters)
+            .add_systems(Startup, register_console_commands);
+    }
+}
+
+/// Per-module levels that can be changed while the game runs, e.g. to turn on
+/// `debug!` output for one system from the console.
+///
+/// These only narrow what the plugin's `level` and `filter` let through: to see
+/// `debug!` from a module at runtime, the static filter has to allow it first.
+/// The most specific module path wins.
+///
+/// ```ignore
+/// fn quiet_ai(filters: Res<GodotLogFilters>) {
+///     filters.set("my_game::ai", LevelFilter::WARN);
+/// }
+/// ```
+#[derive(Resource, Clone, Default)]
+pub struct GodotLogFilters(Arc<RwLock<Vec<(String, LevelFilter)>>>);
+
+impl GodotLogFilters {
+    /// Only let `module` (and its submodules) log at `level` or above.
+    pub fn set(&self, module: impl Into<String>, level: LevelFilter) {
+        let module = module.into();
+        let mut filters = self.0.write();
+        match filters.iter_mut().find(|(existing, _)| *existing == module) {
+            Some((_, existing)) => *existing = level,
+            None => filters.push((module, level)),
+        }
+    }
+
+    pub fn remove(&self, module: &str) {
+        self.0.write().retain(|(existing, _)| existing != module);
+    }
+
+    pub fn clear(&self) {
+        self.0.write().clear();
+    }
+
+    /// The level set for `module` itself, if any.
+    pub fn get(&self, module: &str) -> Option<LevelFilter> {
+        self.0
+            .read()
+            .iter()
+            .find(|(existing, _)| existing == module)
+            .map(|(_, level)| *level)
+    }
+
+    fn allows(&self, target: &str, level: &Level) -> bool {
+        let filters = self.0.read();
+        filters
+            .iter()
+            .filter(|(module, _)| {
+                target
+                    .strip_prefix(module.as_str())
+                    .is_some_and(|rest| rest.is_empty() || rest.starts_with("::"))
+            })
+            .max_by_key(|(module, _)| module.len())
+            .is_none_or(|(_, filter)| filter >= level)
+    }
+}
+
+/// One log event kept by [`GodotLogBuffer`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct LogRecord {
+    pub level: Level,
+    /// Module path the event came from.
+    pub target: String,
+    pub message: String,
+}
+
+impl std::fmt::Display for LogRecord {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} {}: {}",
+            level_letter(&self.level),
+            self.target,
+            self.message
+        )
+    }
+}
+
+/// The most recent log records, oldest first, for in-game display. When the
+/// [`GodotConsole`](crate::plugins::console::GodotConsole) is present, its `log`
+/// command prints from here.
+#[derive(Resource, Clone)]
+pub struct GodotLogBuffer {
+    records: Arc<Mutex<VecDeque<LogRecord>>>,
+    capacity: usize,
+}
+
+impl GodotLogBuffer {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            records: Arc::new(Mutex::new(VecDeque::with_capacity(capacity))),
+            capacity,
+        }
+    }
+
+    /// Up to `count` of the newest records, oldest first.
+    pub fn recent(&self, count: usize) -> Vec<LogRecord> {
+        let records = self.records.lock();
+        records
+            .iter()
+            .skip(records.len().saturating_sub(count))
+            .cloned()
+            .collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.records.lock().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.records.lock().is_empty()
+    }
+
+    pub fn clear(&self) {
+        self.records.lock().clear();
+    }
+
+    fn push(&self, record: LogRecord) {
+        if self.capacity == 0 {
+            return;
+        }
+        let mut records = self.records.lock();
+        if records.len() == self.capacity {
+            records.pop_front();
+        }
+        records.push_back(record);
+    }
+}
+
+/// Adds `log [count]` and `log_level <module> <level|reset>` to the console, if
+/// the app has one.
+fn register_console_commands(
+    console: Option<ResMut<GodotConsole>>,
+    buffer: Res<GodotLogBuffer>,
+    filters: Res<GodotLogFilters>,
+) {
+    let Some(mut console) = console else {
+        return;
+    };
+    let buffer = buffer.clone();
+    console.register("log", move |args, _| {
+        let count = match args.first() {
+            Some(count) => count.parse().map_err(|_| "usage: log [count]")?,
+            None => 20,
+        };
+        let lines: Vec<String> = buffer
+            .recent(count)
+            .iter()
+            .map(LogRecord::to_string)
+            .collect();
+        Ok(lines.join("\n"))
+    });
+    let filters = filters.clone();
+    console.register("log_level", move |args, _| {
+        let [module, level] = args else {
+            return Err("usage: log_level <module> <off|error|warn|info|debug|trace|reset>".into());
+        };
+        if *level == "reset" {
+            filters.remove(module);
+            return Ok(format!("{module}: default level"));
+        }
+        let level = LevelFilter::from_str(level).map_err(|_| format!("unknown level `{level}`"))?;
+        filters.set(*module, level);
+        Ok(format!("{module}: {level}"))
+    });
+}
+
+/// Applies [`GodotLogFilters`] on top of the static `EnvFilter`.
+struct ModuleFilterLayer(GodotLogFilters);
+
+impl<S> Layer<S> for ModuleFilterLayer
+where
+    S: tracing::Subscriber,
+{
+    fn register_callsite(&self, _metadata: &'static Metadata<'static>) -> Interest {
+        // The filters change at runtime, so every callsite must be asked again.
+        Interest::sometimes()
+    }
+
+    fn enabled(&self, metadata: &Metadata<'_>, _context: Context<'_, S>) -> bool {
+        self.0.allows(metadata.target(), metadata.level())
+    }
+}
+
+fn level_letter(level: &Level) -> &'static str {
+    match *level {
+        Level::TRACE => "T",
+        Level::DEBUG => "D",
+        Level::INFO => "I",
+        Level::WARN => "W",
+        Level::ERROR => "E",
     }
 }
 
@@ -100,6 +328,7 @@ impl Visit for GodotProxyLayerVisitor {
 struct GodotProxyLayer {
     color: bool,
     timestamp_format: Option<String>,
+    buffer: GodotLogBuffer,
 }
 
 impl<S> Layer<S> for GodotProxyLayer
@@ -136,13 +365,7 @@ where
                 Level::ERROR => "[color=Salmon]E[/color]",
             },
 
-            false => match *metadata.level() {
-                Level::TRACE => "T",
-                Level::DEBUG => "D",
-                Level::INFO => "I",
-                Level::WARN => "W",
-                Level::ERROR => "E",
-            },
+            false => level_letter(metadata.level()),
         };
 
         let msg = msg_vistor.0.unwrap_or_default();
@@ -175,14 +398,64 @@ where
             false => godot_print!("{}{} {} @ {}", timestamp, level, msg, short_location),
         };
 
+        // The debugger panel points at this file for every entry, so name the
+        // real origin in the message.
         match *metadata.level() {
             Level::WARN => {
-                godot_warn!("{}", msg);
+                godot_warn!("{} @ {}", msg, short_location);
             }
             Level::ERROR => {
-                godot_error!("{}", msg);
+                godot_error!("{} @ {}", msg, short_location);
             }
             _ => {}
         };
+
+        self.buffer.push(LogRecord {
+            level: *metadata.level(),
+            target: metadata.target().to_string(),
+            message: msg,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn most_specific_module_filter_wins() {
+        let filters = GodotLogFilters::default();
+        assert!(filters.allows("game::ai", &Level::TRACE));
+
+        filters.set("game", LevelFilter::WARN);
+        filters.set("game::ai::path", LevelFilter::DEBUG);
+        assert!(!filters.allows("game::ai", &Level::INFO));
+        assert!(filters.allows("game::ai", &Level::ERROR));
+        assert!(filters.allows("game::ai::path::grid", &Level::DEBUG));
+        // Prefixes only match on module boundaries.
+        assert!(filters.allows("gameplay", &Level::INFO));
+
+        filters.remove("game");
+        assert!(filters.allows("game::ai", &Level::INFO));
+        assert_eq!(filters.get("game::ai::path"), Some(LevelFilter::DEBUG));
+    }
+
+    #[test]
+    fn buffer_keeps_newest_records() {
+        let buffer = GodotLogBuffer::new(2);
+        for message in ["a", "b", "c"] {
+            buffer.push(LogRecord {
+                level: Level::WARN,
+                target: "game".into(),
+                message: message.into(),
+            });
+        }
+        let messages: Vec<String> = buffer.recent(5).into_iter().map(|r| r.message).collect();
+        assert_eq!(messages, ["b", "c"]);
+        assert_eq!(buffer.recent(1)[0].to_string(), "W game: c");
+
+        let disabled = GodotLogBuffer::new(0);
+        disabled.push(buffer.recent(1).remove(0));
+        assert!(disabled.is_empty());
     }
 }
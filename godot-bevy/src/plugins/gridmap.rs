@@ -0,0 +1,258 @@
+//! Read and build `GridMap` cells from systems with [`GridMapAccess`].
+//!
+//! The 3D counterpart of `TileMapAccess`: with [`GodotGridMapPlugin`], any entity
+//! whose node is a `GridMap` can be passed to [`GridMapAccess`]. Reads go to the
+//! node straight away; writes are queued and applied together in `Last`, so
+//! generating or blowing up a level costs one node lookup per grid rather than
+//! per cell. Reads made before then already see the queued writes:
+//!
+//! ```ignore
+//! const STONE: GridCell = GridCell::new(0);
+//!
+//! fn generate(mut grid: GridMapAccess, level: Single<Entity, With<Level>>) {
+//!     grid.set_cells(*level, floor_cells().map(|cell| (cell, STONE)));
+//! }
+//!
+//! fn explode(mut grid: GridMapAccess, level: Single<Entity, With<Level>>, blasts: MessageReader<Blast>) {
+//!     for blast in blasts.read() {
+//!         let Some(center) = grid.world_to_map(*level, blast.position) else { continue };
+//!         for offset in blast_offsets(blast.radius) {
+//!             grid.erase_cell(*level, center + offset);
+//!         }
+//!     }
+//! }
+//! ```
+//!
+//! Each applied batch is reported as a [`GridCellsChanged`] message listing the
+//! cells it touched. Cells placed in the editor or from GDScript aren't reported.
+
+use bevy_app::{App, Last, Plugin};
+use bevy_ecs::entity::Entity;
+use bevy_ecs::message::{Message, MessageWriter};
+use bevy_ecs::prelude::Resource;
+use bevy_ecs::system::{Query, ResMut, SystemParam};
+use bevy_math::{IVec3, Vec3};
+use bevy_platform::collections::HashMap;
+use godot::builtin::{Vector3, Vector3i};
+use godot::classes::GridMap;
+use godot::obj::Gd;
+use tracing::warn;
+
+use crate::interop::{GodotAccess, GodotNodeHandle};
+
+/// `GridMap.INVALID_CELL_ITEM`, what an empty cell holds.
+const INVALID_CELL_ITEM: i32 = -1;
+
+/// Plugin applying the cell edits queued through [`GridMapAccess`]. See the
+/// [module docs](self).
+#[derive(Default)]
+pub struct GodotGridMapPlugin;
+
+impl Plugin for GodotGridMapPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<GridEdits>()
+            .add_message::<GridCellsChanged>()
+            .add_systems(Last, apply_grid_edits);
+    }
+}
+
+/// The item in a cell: its id in the `GridMap`'s `MeshLibrary`, and its
+/// orientation as an index from `GridMap.get_orthogonal_index_from_basis`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct GridCell {
+    pub item: i32,
+    pub orientation: i32,
+}
+
+impl GridCell {
+    pub const fn new(item: i32) -> Self {
+        Self {
+            item,
+            orientation: 0,
+        }
+    }
+
+    pub const fn with_orientation(mut self, orientation: i32) -> Self {
+        self.orientation = orientation;
+        self
+    }
+}
+
+/// Cells of a `GridMap` entity changed through [`GridMapAccess`].
+#[derive(Message, Debug, Clone, PartialEq, Eq)]
+pub struct GridCellsChanged {
+    pub entity: Entity,
+    pub cells: Vec<IVec3>,
+}
+
+/// Queued writes per grid; `None` erases the cell. Later writes to a cell
+/// replace earlier ones.
+#[derive(Resource, Debug, Default)]
+struct GridEdits(HashMap<Entity, HashMap<IVec3, Option<GridCell>>>);
+
+impl GridEdits {
+    fn queue(&mut self, grid: Entity, coords: IVec3, cell: Option<GridCell>) {
+        self.0.entry(grid).or_default().insert(coords, cell);
+    }
+
+    /// The queued write for a cell: `Some(None)` if it will be erased.
+    fn pending(&self, grid: Entity, coords: IVec3) -> Option<Option<GridCell>> {
+        self.0.get(&grid)?.get(&coords).copied()
+    }
+
+    /// The grid's used cells once its queued writes are applied.
+    fn merge_used(&self, grid: Entity, mut used: Vec<IVec3>) -> Vec<IVec3> {
+        let Some(edits) = self.0.get(&grid) else {
+            return used;
+        };
+        used.retain(|coords| !edits.contains_key(coords));
+        used.extend(
+            edits
+                .iter()
+                .filter(|(_, cell)| cell.is_some())
+                .map(|(coords, _)| *coords),
+        );
+        used
+    }
+}
+
+/// Main-thread access to `GridMap` cells. See the [module docs](self).
+#[derive(SystemParam)]
+pub struct GridMapAccess<'w, 's> {
+    handles: Query<'w, 's, &'static GodotNodeHandle>,
+    edits: ResMut<'w, GridEdits>,
+    godot: GodotAccess<'w>,
+}
+
+impl GridMapAccess<'_, '_> {
+    /// The item in a cell, or `None` if it is empty or `grid` isn't a `GridMap`.
+    pub fn cell(&mut self, grid: Entity, coords: IVec3) -> Option<GridCell> {
+        if let Some(pending) = self.edits.pending(grid, coords) {
+            return pending;
+        }
+        let node = self.grid(grid)?;
+        let coords = to_vector3i(coords);
+        let item = node.get_cell_item(coords);
+        (item != INVALID_CELL_ITEM).then(|| GridCell {
+            item,
+            orientation: node.get_cell_item_orientation(coords),
+        })
+    }
+
+    /// Queue placing an item in a cell.
+    pub fn set_cell(&mut self, grid: Entity, coords: IVec3, cell: GridCell) {
+        self.edits.queue(grid, coords, Some(cell));
+    }
+
+    /// Queue placing many items.
+    pub fn set_cells(&mut self, grid: Entity, cells: impl IntoIterator<Item = (IVec3, GridCell)>) {
+        for (coords, cell) in cells {
+            self.edits.queue(grid, coords, Some(cell));
+        }
+    }
+
+    /// Queue clearing a cell.
+    pub fn erase_cell(&mut self, grid: Entity, coords: IVec3) {
+        self.edits.queue(grid, coords, None);
+    }
+
+    /// Every non-empty cell, queued writes included, in no particular order.
+    pub fn used_cells(&mut self, grid: Entity) -> Vec<IVec3> {
+        let used = self
+            .grid(grid)
+            .map(|node| {
+                node.get_used_cells()
+                    .iter_shared()
+                    .map(from_vector3i)
+                    .collect()
+            })
+            .unwrap_or_default();
+        self.edits.merge_used(grid, used)
+    }
+
+    /// The cell containing a global position.
+    pub fn world_to_map(&mut self, grid: Entity, position: Vec3) -> Option<IVec3> {
+        let node = self.grid(grid)?;
+        let local = node.to_local(Vector3::new(position.x, position.y, position.z));
+        Some(from_vector3i(node.local_to_map(local)))
+    }
+
+    /// The global position of a cell's center.
+    pub fn map_to_world(&mut self, grid: Entity, coords: IVec3) -> Option<Vec3> {
+        let node = self.grid(grid)?;
+        let global = node.to_global(node.map_to_local(to_vector3i(coords)));
+        Some(Vec3::new(global.x, global.y, global.z))
+    }
+
+    fn grid(&mut self, entity: Entity) -> Option<Gd<GridMap>> {
+        let handle = self.handles.get(entity).ok()?;
+        self.godot.try_get::<GridMap>(*handle)
+    }
+}
+
+fn to_vector3i(coords: IVec3) -> Vector3i {
+    Vector3i::new(coords.x, coords.y, coords.z)
+}
+
+fn from_vector3i(coords: Vector3i) -> IVec3 {
+    IVec3::new(coords.x, coords.y, coords.z)
+}
+
+fn apply_grid_edits(
+    mut edits: ResMut<GridEdits>,
+    handles: Query<&GodotNodeHandle>,
+    mut changed: MessageWriter<GridCellsChanged>,
+    mut godot: GodotAccess,
+) {
+    if edits.0.is_empty() {
+        return;
+    }
+    for (entity, cells) in edits.0.drain() {
+        let Some(mut node) = handles
+            .get(entity)
+            .ok()
+            .and_then(|handle| godot.try_get::<GridMap>(*handle))
+        else {
+            warn!("GridMapAccess: dropped edits to {entity}, which isn't a GridMap");
+            continue;
+        };
+        for (coords, cell) in &cells {
+            let coords = to_vector3i(*coords);
+            match cell {
+                Some(cell) => node
+                    .set_cell_item_ex(coords, cell.item)
+                    .orientation(cell.orientation)
+                    .done(),
+                None => node.set_cell_item(coords, INVALID_CELL_ITEM),
+            }
+        }
+        changed.write(GridCellsChanged {
+            entity,
+            cells: cells.into_keys().collect(),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn queued_edits_shadow_the_grid() {
+        let grid = Entity::from_raw_u32(1).unwrap();
+        let stone = GridCell::new(3).with_orientation(10);
+        let mut edits = GridEdits::default();
+
+        edits.queue(grid, IVec3::ZERO, Some(stone));
+        edits.queue(grid, IVec3::Y, Some(stone));
+        edits.queue(grid, IVec3::Y, None);
+
+        assert_eq!(edits.pending(grid, IVec3::ZERO), Some(Some(stone)));
+        assert_eq!(edits.pending(grid, IVec3::Y), Some(None));
+        assert_eq!(edits.pending(grid, IVec3::X), None);
+
+        let mut used = edits.merge_used(grid, vec![IVec3::X, IVec3::Y]);
+        used.sort_by_key(|coords| (coords.x, coords.y, coords.z));
+        assert_eq!(used, vec![IVec3::ZERO, IVec3::X]);
+    }
+}
@@ -0,0 +1,290 @@
+//! Web requests from systems, backed by Godot's `HTTPRequest` node.
+//!
+//! [`GodotHttpClient`] queues a request from any system and returns its
+//! [`HttpRequestId`]. In `Last`, each queued request gets its own `HTTPRequest`
+//! node under the root, leased from the shared [`HelperNodePool`]; when it
+//! completes, an [`HttpResponse`] is triggered for observers on the next `First`
+//! and the node goes back to the pool on the next `Last`.
+//!
+//! ```ignore
+//! app.add_plugins(GodotHttpPlugin)
+//!     .add_observer(|response: On<HttpResponse>| match &response.event().result {
+//!         Ok(reply) if reply.is_success() => info!("leaderboard: {}", reply.text()),
+//!         Ok(reply) => warn!("leaderboard: HTTP {}", reply.status),
+//!         Err(err) => warn!("leaderboard: {err}"),
+//!     });
+//!
+//! fn submit_score(http: GodotHttpClient, score: Res<Score>) {
+//!     http.send(
+//!         HttpRequestSpec::post("https://example.com/scores", format!("{{\"score\":{}}}", score.0))
+//!             .header("Content-Type", "application/json"),
+//!     );
+//! }
+//! ```
+
+use std::borrow::Cow;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+use bevy_app::{App, Last, Plugin};
+use bevy_ecs::event::Event;
+use bevy_ecs::prelude::Resource;
+use bevy_ecs::system::{Res, ResMut, SystemParam};
+use godot::builtin::{GString, PackedByteArray, PackedStringArray, Variant};
+use godot::classes::http_client::Method;
+use godot::classes::object::ConnectFlags;
+use godot::classes::{HttpRequest, http_request};
+use godot::global::Error;
+use godot::obj::{EngineEnum, InstanceId};
+use godot::prelude::Callable;
+use parking_lot::Mutex;
+use thiserror::Error;
+
+use crate::interop::GodotAccess;
+use crate::plugins::core::SyncStats;
+use crate::plugins::event_bridge::GodotEventSender;
+use crate::plugins::node_pool::HelperNodePool;
+use crate::plugins::scene_tree::SceneTreeRef;
+
+/// Plugin adding [`GodotHttpClient`]. See the [module docs](self).
+#[derive(Default)]
+pub struct GodotHttpPlugin;
+
+impl Plugin for GodotHttpPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<HttpQueue>()
+            .add_systems(Last, start_http_requests);
+    }
+}
+
+/// Identifies a request; carried by its [`HttpResponse`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HttpRequestId(u64);
+
+/// A request to send with [`GodotHttpClient::send`].
+#[derive(Debug, Clone)]
+pub struct HttpRequestSpec {
+    pub url: String,
+    pub method: Method,
+    /// Full header lines, e.g. `"Content-Type: application/json"`.
+    pub headers: Vec<String>,
+    pub body: Vec<u8>,
+    /// Give up after this long; `None` waits indefinitely.
+    pub timeout: Option<Duration>,
+}
+
+impl HttpRequestSpec {
+    pub fn new(method: Method, url: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            method,
+            headers: Vec::new(),
+            body: Vec::new(),
+            timeout: None,
+        }
+    }
+
+    pub fn get(url: impl Into<String>) -> Self {
+        Self::new(Method::GET, url)
+    }
+
+    pub fn post(url: impl Into<String>, body: impl Into<Vec<u8>>) -> Self {
+        Self::new(Method::POST, url).with_body(body)
+    }
+
+    pub fn put(url: impl Into<String>, body: impl Into<Vec<u8>>) -> Self {
+        Self::new(Method::PUT, url).with_body(body)
+    }
+
+    pub fn delete(url: impl Into<String>) -> Self {
+        Self::new(Method::DELETE, url)
+    }
+
+    pub fn header(mut self, name: &str, value: &str) -> Self {
+        self.headers.push(format!("{name}: {value}"));
+        self
+    }
+
+    pub fn with_body(mut self, body: impl Into<Vec<u8>>) -> Self {
+        self.body = body.into();
+        self
+    }
+
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+}
+
+/// Triggered when a request completes, fails, or could not be started.
+#[derive(Event, Debug, Clone)]
+pub struct HttpResponse {
+    pub id: HttpRequestId,
+    pub result: Result<HttpReply, HttpError>,
+}
+
+/// What the server sent back. Any status counts as a reply, not only 2xx.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HttpReply {
+    pub status: u16,
+    /// Header lines as received, e.g. `"Content-Type: text/html"`.
+    pub headers: Vec<String>,
+    pub body: Vec<u8>,
+}
+
+impl HttpReply {
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+
+    /// The body as UTF-8, with invalid sequences replaced.
+    pub fn text(&self) -> Cow<'_, str> {
+        String::from_utf8_lossy(&self.body)
+    }
+
+    /// Value of the first header called `name` (case-insensitive).
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.iter().find_map(|line| {
+            let (key, value) = line.split_once(':')?;
+            key.trim()
+                .eq_ignore_ascii_case(name)
+                .then_some(value.trim())
+        })
+    }
+}
+
+#[derive(Debug, Clone, Error)]
+pub enum HttpError {
+    /// `HTTPRequest.request` refused the request, e.g. for a malformed URL.
+    #[error("failed to start request: {0:?}")]
+    Start(Error),
+    /// The request was sent but no reply arrived (DNS, TLS, timeout, ...).
+    #[error("request failed: {0:?}")]
+    Transport(http_request::Result),
+}
+
+/// Queue web requests from any system; results arrive as [`HttpResponse`] events.
+///
+/// Only needs shared access, so systems using it still run in parallel; the
+/// `HTTPRequest` nodes are created on the main thread in `Last`.
+#[derive(SystemParam)]
+pub struct GodotHttpClient<'w> {
+    queue: Res<'w, HttpQueue>,
+}
+
+impl GodotHttpClient<'_> {
+    pub fn get(&self, url: impl Into<String>) -> HttpRequestId {
+        self.send(HttpRequestSpec::get(url))
+    }
+
+    pub fn post(&self, url: impl Into<String>, body: impl Into<Vec<u8>>) -> HttpRequestId {
+        self.send(HttpRequestSpec::post(url, body))
+    }
+
+    pub fn send(&self, request: HttpRequestSpec) -> HttpRequestId {
+        let id = HttpRequestId(self.queue.next_id.fetch_add(1, Ordering::Relaxed));
+        self.queue.pending.lock().push((id, request));
+        id
+    }
+}
+
+#[derive(Resource, Default)]
+struct HttpQueue {
+    next_id: AtomicU64,
+    pending: Mutex<Vec<(HttpRequestId, HttpRequestSpec)>>,
+    /// Nodes whose request completed, to hand back to the pool.
+    finished: Arc<Mutex<Vec<InstanceId>>>,
+}
+
+fn start_http_requests(
+    queue: Res<HttpQueue>,
+    sender: Res<GodotEventSender>,
+    mut stats: Option<ResMut<SyncStats>>,
+    mut scene_tree: SceneTreeRef,
+    mut pool: ResMut<HelperNodePool>,
+    mut godot: GodotAccess,
+) {
+    for instance_id in std::mem::take(&mut *queue.finished.lock()) {
+        if let Some(node) = godot.try_get_instance_id::<HttpRequest>(instance_id) {
+            pool.release(&mut godot, node);
+        }
+    }
+
+    let pending = std::mem::take(&mut *queue.pending.lock());
+    if pending.is_empty() {
+        return;
+    }
+    let Some(mut root) = scene_tree.get().get_root() else {
+        return;
+    };
+
+    for (id, request) in pending {
+        if let Some(stats) = stats.as_mut() {
+            stats.deferred_commands += 1;
+        }
+
+        // Pooled nodes keep their last timeout, so always set it.
+        let mut node = pool.lease::<HttpRequest>(&mut godot);
+        node.set_timeout(request.timeout.map_or(0.0, |timeout| timeout.as_secs_f64()));
+        root.add_child(&node);
+
+        let instance_id = node.instance_id();
+        let tx = sender.clone();
+        let finished = queue.finished.clone();
+        let on_completed = Callable::from_fn("http_request_completed", move |args| {
+            tx.send(HttpResponse {
+                id,
+                result: completed_result(args),
+            });
+            finished.lock().push(instance_id);
+            Variant::nil()
+        });
+        node.connect_flags("request_completed", &on_completed, ConnectFlags::ONE_SHOT);
+
+        let headers: PackedStringArray = request.headers.iter().map(GString::from).collect();
+        let body = PackedByteArray::from(request.body.as_slice());
+        let error = node
+            .request_raw_ex(request.url.as_str())
+            .custom_headers(&headers)
+            .method(request.method)
+            .request_data_raw(&body)
+            .done();
+        if error != Error::OK {
+            sender.send(HttpResponse {
+                id,
+                result: Err(HttpError::Start(error)),
+            });
+            node.disconnect("request_completed", &on_completed);
+            pool.release(&mut godot, node);
+        }
+    }
+}
+
+/// `request_completed(result, response_code, headers, body)`.
+fn completed_result(args: &[&Variant]) -> Result<HttpReply, HttpError> {
+    let arg = |i: usize| args.get(i).copied().cloned().unwrap_or_default();
+    let result = arg(0)
+        .try_to::<i64>()
+        .ok()
+        .and_then(|ord| http_request::Result::try_from_ord(i32::try_from(ord).ok()?))
+        .unwrap_or(http_request::Result::REQUEST_FAILED);
+    if result != http_request::Result::SUCCESS {
+        return Err(HttpError::Transport(result));
+    }
+    Ok(HttpReply {
+        status: arg(1)
+            .try_to::<i64>()
+            .ok()
+            .and_then(|code| u16::try_from(code).ok())
+            .unwrap_or_default(),
+        headers: arg(2)
+            .try_to::<PackedStringArray>()
+            .map(|headers| headers.as_slice().iter().map(|h| h.to_string()).collect())
+            .unwrap_or_default(),
+        body: arg(3)
+            .try_to::<PackedByteArray>()
+            .map(|body| body.to_vec())
+            .unwrap_or_default(),
+    })
+}
@@ -0,0 +1,240 @@
+//! Analog axes built from Godot Input Map actions, exposed through Bevy's `Axis`.
+//!
+//! Godot actions carry a strength: how far a trigger is pulled, or how far a
+//! stick is pushed past the action's dead zone. Register an axis once and read
+//! it like any other Bevy axis:
+//!
+//! ```ignore
+//! app.add_plugins(BevyInputBridgePlugin)
+//!     .add_action_axis("move_x", "move_left", "move_right")
+//!     .add_action_axis("move_y", "move_down", "move_up")
+//!     .add_action_trigger("throttle", "accelerate");
+//!
+//! fn steer(axes: Res<Axis<GodotActionAxis>>) {
+//!     let x = axes.get(GodotActionAxis("move_x")).unwrap_or(0.0);
+//!     // ...
+//! }
+//! ```
+//!
+//! Dead zones and bindings stay in Godot's Input Map, so they can be tuned (or
+//! remapped by players) without touching Rust. Response curves and inversion on
+//! top of that are set per axis in [`InputTuning`](super::tuning::InputTuning).
+
+use bevy_app::App;
+use bevy_ecs::change_detection::DetectChanges;
+use bevy_ecs::message::MessageReader;
+use bevy_ecs::prelude::Resource;
+use bevy_ecs::system::{Res, ResMut};
+use bevy_input::Axis;
+use bevy_platform::collections::HashMap;
+
+use super::events::ActionInput;
+use super::tuning::InputTuning;
+
+/// Key of an axis registered with [`ActionAxisAppExt`], for `Res<Axis<GodotActionAxis>>`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct GodotActionAxis(pub &'static str);
+
+/// Register analog axes driven by Godot actions. See the [module docs](self).
+pub trait ActionAxisAppExt {
+    /// A -1.0..=1.0 axis: the strength of `positive` minus that of `negative`.
+    fn add_action_axis(
+        &mut self,
+        axis: &'static str,
+        negative: &'static str,
+        positive: &'static str,
+    ) -> &mut Self;
+
+    /// A 0.0..=1.0 axis following the strength of a single action, e.g. a trigger.
+    fn add_action_trigger(&mut self, axis: &'static str, action: &'static str) -> &mut Self;
+}
+
+impl ActionAxisAppExt for App {
+    fn add_action_axis(
+        &mut self,
+        axis: &'static str,
+        negative: &'static str,
+        positive: &'static str,
+    ) -> &mut Self {
+        register(self, axis, Some(negative), positive)
+    }
+
+    fn add_action_trigger(&mut self, axis: &'static str, action: &'static str) -> &mut Self {
+        register(self, axis, None, action)
+    }
+}
+
+fn register<'a>(
+    app: &'a mut App,
+    axis: &'static str,
+    negative: Option<&'static str>,
+    positive: &'static str,
+) -> &'a mut App {
+    app.init_resource::<ActionAxisBindings>()
+        .init_resource::<Axis<GodotActionAxis>>()
+        .init_resource::<InputTuning>();
+    let world = app.world_mut();
+    world
+        .resource_mut::<ActionAxisBindings>()
+        .axes
+        .push(ActionAxisBinding {
+            axis: GodotActionAxis(axis),
+            negative,
+            positive,
+        });
+    world
+        .resource_mut::<Axis<GodotActionAxis>>()
+        .set(GodotActionAxis(axis), 0.0);
+    app
+}
+
+#[derive(Resource, Default)]
+pub(crate) struct ActionAxisBindings {
+    axes: Vec<ActionAxisBinding>,
+    /// Last strength seen per bound action; released actions read 0.
+    strengths: HashMap<&'static str, f32>,
+}
+
+struct ActionAxisBinding {
+    axis: GodotActionAxis,
+    negative: Option<&'static str>,
+    positive: &'static str,
+}
+
+impl ActionAxisBindings {
+    fn bound_action(&self, name: &str) -> Option<&'static str> {
+        self.axes.iter().find_map(|binding| {
+            if binding.positive == name {
+                Some(binding.positive)
+            } else {
+                binding.negative.filter(|negative| *negative == name)
+            }
+        })
+    }
+
+    fn strength(&self, action: &str) -> f32 {
+        self.strengths.get(action).copied().unwrap_or(0.0)
+    }
+}
+
+pub(crate) fn bridge_action_axes(
+    mut actions: MessageReader<ActionInput>,
+    mut bindings: ResMut<ActionAxisBindings>,
+    tuning: Res<InputTuning>,
+    mut axes: ResMut<Axis<GodotActionAxis>>,
+) {
+    crate::profile_scope!("bridge_action_axes");
+    let mut changed = tuning.is_changed();
+    for input in actions.read() {
+        if let Some(action) = bindings.bound_action(&input.action) {
+            let strength = if input.pressed { input.strength } else { 0.0 };
+            bindings.strengths.insert(action, strength);
+            changed = true;
+        }
+    }
+    if !changed {
+        return;
+    }
+
+    for binding in &bindings.axes {
+        let negative = binding
+            .negative
+            .map_or(0.0, |action| bindings.strength(action));
+        let raw = bindings.strength(binding.positive) - negative;
+        let value = tuning.get(binding.axis).apply(raw);
+        if axes.get_unclamped(binding.axis) != Some(value) {
+            axes.set(binding.axis, value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_app::First;
+    use bevy_ecs::message::Messages;
+
+    use crate::plugins::input::tuning::AxisTuning;
+
+    fn make_app() -> App {
+        let mut app = App::new();
+        app.add_message::<ActionInput>()
+            .add_systems(First, bridge_action_axes)
+            .add_action_axis("move_x", "move_left", "move_right")
+            .add_action_trigger("throttle", "accelerate");
+        app
+    }
+
+    fn send(app: &mut App, action: &str, pressed: bool, strength: f32) {
+        app.world_mut()
+            .resource_mut::<Messages<ActionInput>>()
+            .write(ActionInput {
+                action: action.to_string(),
+                pressed,
+                strength,
+            });
+    }
+
+    fn axis(app: &App, name: &'static str) -> Option<f32> {
+        app.world()
+            .resource::<Axis<GodotActionAxis>>()
+            .get(GodotActionAxis(name))
+    }
+
+    #[test]
+    fn registered_axes_start_at_zero() {
+        let mut app = make_app();
+        app.update();
+        assert_eq!(axis(&app, "move_x"), Some(0.0));
+        assert_eq!(axis(&app, "throttle"), Some(0.0));
+    }
+
+    #[test]
+    fn opposing_actions_combine_into_one_axis() {
+        let mut app = make_app();
+        send(&mut app, "move_right", true, 0.75);
+        app.update();
+        assert_eq!(axis(&app, "move_x"), Some(0.75));
+
+        send(&mut app, "move_left", true, 0.25);
+        app.update();
+        assert_eq!(axis(&app, "move_x"), Some(0.5));
+
+        send(&mut app, "move_right", false, 0.0);
+        send(&mut app, "move_left", false, 0.0);
+        app.update();
+        assert_eq!(axis(&app, "move_x"), Some(0.0));
+    }
+
+    #[test]
+    fn trigger_follows_strength_and_ignores_unbound_actions() {
+        let mut app = make_app();
+        send(&mut app, "accelerate", true, 0.6);
+        send(&mut app, "jump", true, 1.0);
+        app.update();
+        assert_eq!(axis(&app, "throttle"), Some(0.6));
+        assert_eq!(axis(&app, "jump"), None);
+    }
+
+    #[test]
+    fn tuning_filters_axis_and_applies_when_changed() {
+        let mut app = make_app();
+        send(&mut app, "move_right", true, 0.5);
+        app.update();
+        assert_eq!(axis(&app, "move_x"), Some(0.5));
+
+        // No new input: the tuning change alone re-filters the held value.
+        app.world_mut().resource_mut::<InputTuning>().set(
+            "move_x",
+            AxisTuning::default().with_exponent(2.0).inverted(),
+        );
+        app.update();
+        assert_eq!(axis(&app, "move_x"), Some(-0.25));
+
+        app.world_mut().resource_mut::<InputTuning>().default =
+            AxisTuning::default().with_dead_zone(0.7);
+        send(&mut app, "accelerate", true, 0.6);
+        app.update();
+        assert_eq!(axis(&app, "throttle"), Some(0.0));
+    }
+}
@@ -0,0 +1,192 @@
+//! Declare a game's actions in one Rust enum and have them created in Godot's
+//! Input Map.
+//!
+//! `#[derive(GodotActionSet)]` on a fieldless enum names one Godot action per
+//! variant, with default bindings. [`ActionSetAppExt::add_action_set`] creates
+//! every action the project doesn't define yet at startup, so a fresh checkout
+//! works without touching Project Settings, while bindings a designer changed in
+//! the Input Map are left alone:
+//!
+//! ```ignore
+//! #[derive(GodotActionSet, Clone, Copy, PartialEq, Eq, Hash, Debug)]
+//! enum PlayerAction {
+//!     #[action(key = SPACE, joy_button = A)]
+//!     Jump,
+//!     #[action(key = A, key = LEFT, joy_axis(LEFT_X, -1.0), deadzone = 0.2)]
+//!     MoveLeft,
+//!     #[action(key = D, key = RIGHT, joy_axis(LEFT_X, 1.0), deadzone = 0.2)]
+//!     MoveRight,
+//!     #[action(name = "fire", mouse = LEFT)]
+//!     Shoot,
+//! }
+//!
+//! app.add_action_set::<PlayerAction>();
+//!
+//! fn jump(actions: Res<GodotActions>) {
+//!     if actions.just_pressed(PlayerAction::Jump) { /* ... */ }
+//! }
+//! ```
+//!
+//! Variants map to snake_case action names (`MoveLeft` is `"move_left"`) unless
+//! `name` is given. Binding idents are Godot's `Key`, `MouseButton`, `JoyButton`
+//! and `JoyAxis` constants.
+//!
+//! When the game runs from the editor, created actions are also saved to
+//! `project.godot`, so they show up under Project Settings > Input Map once the
+//! project is reloaded.
+
+use std::marker::PhantomData;
+
+use bevy_app::{App, Plugin, PreStartup};
+use bevy_ecs::system::ResMut;
+use godot::builtin::{Array, StringName, VarDictionary};
+use godot::classes::{
+    InputEvent, InputEventJoypadButton, InputEventJoypadMotion, InputEventKey,
+    InputEventMouseButton, InputMap, Os, ProjectSettings,
+};
+use godot::global::{JoyAxis, JoyButton, Key, MouseButton};
+use godot::meta::ToGodot;
+use godot::obj::{Gd, NewGd};
+
+use super::actions::{ActionRef, GodotActions};
+use crate::interop::GodotAccess;
+
+/// An enum of Godot actions, usually derived with `#[derive(GodotActionSet)]`.
+/// See the [module docs](self).
+pub trait GodotActionSet: Copy + Send + Sync + 'static {
+    /// Every action in the set.
+    fn variants() -> &'static [Self];
+
+    /// The Godot action name.
+    fn action_name(self) -> &'static str;
+
+    /// Bindings the action gets when the project doesn't define it.
+    fn default_bindings(self) -> &'static [ActionBinding];
+
+    /// Dead zone the action gets when the project doesn't define it.
+    fn deadzone(self) -> f32 {
+        0.5
+    }
+}
+
+/// A default binding of a [`GodotActionSet`] action.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ActionBinding {
+    /// A key by physical location, so WASD stays WASD on AZERTY layouts.
+    Key(Key),
+    MouseButton(MouseButton),
+    /// A button on any gamepad.
+    JoyButton(JoyButton),
+    /// One direction of a stick or trigger on any gamepad; the value is -1.0 or 1.0.
+    JoyAxis(JoyAxis, f32),
+}
+
+impl ActionBinding {
+    fn to_input_event(self) -> Gd<InputEvent> {
+        match self {
+            ActionBinding::Key(key) => {
+                let mut event = InputEventKey::new_gd();
+                event.set_physical_keycode(key);
+                event.upcast()
+            }
+            ActionBinding::MouseButton(button) => {
+                let mut event = InputEventMouseButton::new_gd();
+                event.set_button_index(button);
+                event.upcast()
+            }
+            ActionBinding::JoyButton(button) => {
+                let mut event = InputEventJoypadButton::new_gd();
+                event.set_device(-1);
+                event.set_button_index(button);
+                event.upcast()
+            }
+            ActionBinding::JoyAxis(axis, value) => {
+                let mut event = InputEventJoypadMotion::new_gd();
+                event.set_device(-1);
+                event.set_axis(axis);
+                event.set_axis_value(value.signum());
+                event.upcast()
+            }
+        }
+    }
+}
+
+impl<A: GodotActionSet> From<A> for ActionRef<'static> {
+    fn from(action: A) -> Self {
+        ActionRef::declared(action.action_name())
+    }
+}
+
+/// Register [`GodotActionSet`]s. See the [module docs](self).
+pub trait ActionSetAppExt {
+    /// Create the actions of `A` missing from the Input Map before `Startup`.
+    fn add_action_set<A: GodotActionSet>(&mut self) -> &mut Self;
+}
+
+impl ActionSetAppExt for App {
+    fn add_action_set<A: GodotActionSet>(&mut self) -> &mut Self {
+        self.add_plugins(ActionSetPlugin::<A>(PhantomData))
+    }
+}
+
+struct ActionSetPlugin<A>(PhantomData<fn() -> A>);
+
+impl<A: GodotActionSet> Plugin for ActionSetPlugin<A> {
+    fn build(&self, app: &mut App) {
+        app.add_systems(PreStartup, create_missing_actions::<A>);
+    }
+}
+
+fn create_missing_actions<A: GodotActionSet>(
+    actions: Option<ResMut<GodotActions>>,
+    mut godot: GodotAccess,
+) {
+    let mut input_map = godot.singleton::<InputMap>();
+    let mut created = Vec::new();
+    for &action in A::variants() {
+        let name = StringName::from(action.action_name());
+        if input_map.has_action(&name) {
+            continue;
+        }
+        input_map
+            .add_action_ex(&name)
+            .deadzone(action.deadzone())
+            .done();
+        for binding in action.default_bindings() {
+            input_map.action_add_event(&name, &binding.to_input_event());
+        }
+        created.push(action);
+    }
+    if created.is_empty() {
+        return;
+    }
+    tracing::debug!("Created {} Input Map actions", created.len());
+
+    // `GodotActions` caches the action list on its first poll.
+    if let Some(mut actions) = actions {
+        actions.reseed();
+    }
+    if godot.singleton::<Os>().has_feature("editor") {
+        save_to_project_settings(&mut godot, &created);
+    }
+}
+
+fn save_to_project_settings<A: GodotActionSet>(godot: &mut GodotAccess, created: &[A]) {
+    let mut settings = godot.singleton::<ProjectSettings>();
+    for &action in created {
+        let mut events = Array::<Gd<InputEvent>>::new();
+        for binding in action.default_bindings() {
+            events.push(&binding.to_input_event());
+        }
+        let mut setting = VarDictionary::new();
+        setting.set("deadzone", action.deadzone());
+        setting.set("events", &events);
+        settings.set_setting(
+            &format!("input/{}", action.action_name()),
+            &setting.to_variant(),
+        );
+    }
+    if settings.save() != godot::global::Error::OK {
+        tracing::warn!("Failed to save created Input Map actions to project.godot");
+    }
+}
@@ -106,6 +106,16 @@ impl<'a> From<&'a Action> for ActionRef<'a> {
     }
 }
 
+impl ActionRef<'static> {
+    /// An action declared in Rust (e.g. by a `GodotActionSet`); skips the warn path.
+    pub(crate) fn declared(key: &'static str) -> Self {
+        ActionRef {
+            key,
+            warn_if_unknown: false,
+        }
+    }
+}
+
 // ── accessors ────────────────────────────────────────────────────────────────
 
 impl GodotActions {
@@ -174,6 +184,13 @@ impl GodotActions {
         )
     }
 
+    /// Drop the cached action list so the next poll picks up actions added to
+    /// the `InputMap` since.
+    pub(crate) fn reseed(&mut self) {
+        self.action_set.clear();
+        self.action_keys.clear();
+    }
+
     pub(crate) fn set_active(&mut self, clock: Clock) {
         self.active = clock;
     }
@@ -246,6 +263,7 @@ pub(crate) fn restore_process_clock(world: &mut World) {
 }
 
 fn poll_process_actions(mut ga: ResMut<GodotActions>) {
+    crate::profile_scope!("poll_process_actions");
     ga.set_active(Clock::Process);
     ga.poll(Clock::Process);
 }
@@ -2,7 +2,7 @@ use bevy_app::{App, First, Plugin};
 use bevy_ecs::{
     message::{Message, MessageWriter, message_update_system},
     schedule::IntoScheduleConfigs,
-    system::NonSendMut,
+    system::{NonSendMut, ResMut},
 };
 use bevy_math::Vec2;
 use bevy_reflect::Reflect;
@@ -18,6 +18,8 @@ use godot::{
 };
 use tracing::trace;
 
+use crate::plugins::core::{GodotSyncSet, SyncStats};
+
 /// Plugin that handles Godot input events and converts them to Bevy messages.
 /// This is the base input plugin that provides raw input message types.
 ///
@@ -33,15 +35,20 @@ pub type GodotInputPlugin = GodotInputEventPlugin;
 
 impl Plugin for GodotInputEventPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(First, write_input_messages.before(message_update_system))
-            .add_message::<GodotKeyboardInput>()
-            .add_message::<GodotMouseButtonInput>()
-            .add_message::<GodotMouseMotion>()
-            .add_message::<TouchInput>()
-            .add_message::<ActionInput>()
-            .add_message::<GamepadButtonInput>()
-            .add_message::<GamepadAxisInput>()
-            .add_message::<PanGestureInput>();
+        app.add_systems(
+            First,
+            write_input_messages
+                .before(message_update_system)
+                .in_set(GodotSyncSet::InputPump),
+        )
+        .add_message::<GodotKeyboardInput>()
+        .add_message::<GodotMouseButtonInput>()
+        .add_message::<GodotMouseMotion>()
+        .add_message::<TouchInput>()
+        .add_message::<ActionInput>()
+        .add_message::<GamepadButtonInput>()
+        .add_message::<GamepadAxisInput>()
+        .add_message::<PanGestureInput>();
     }
 }
 
@@ -82,7 +89,7 @@ pub struct TouchInput {
 }
 
 /// Godot action input event (for input map actions)
-#[derive(Debug, Message, Clone)]
+#[derive(Debug, Message, Clone, PartialEq)]
 pub struct ActionInput {
     pub action: String,
     pub pressed: bool,
@@ -154,13 +161,18 @@ pub(crate) fn write_input_messages(
     mut gamepad_button_events: MessageWriter<GamepadButtonInput>,
     mut gamepad_axis_events: MessageWriter<GamepadAxisInput>,
     mut pan_gesture_events: MessageWriter<PanGestureInput>,
+    mut stats: Option<ResMut<SyncStats>>,
 ) {
+    crate::profile_scope!("write_input_messages");
     // Fetch once per frame, not per event: get_actions() is an allocating FFI
     // call and the action set is stable within a frame.
     let actions = InputMap::singleton().get_actions();
 
     for (event_type, input_event) in events.0.try_iter() {
         trace!("Processing {:?} input event", event_type);
+        if let Some(stats) = stats.as_mut() {
+            stats.inputs_drained += 1;
+        }
 
         match event_type {
             InputEventType::Normal => {
@@ -5,7 +5,7 @@ use bevy_ecs::{
     schedule::IntoScheduleConfigs,
 };
 use bevy_input::{
-    ButtonState, InputPlugin,
+    Axis, ButtonState, InputPlugin,
     gestures::PanGesture as BevyPanGesture,
     keyboard::{Key, KeyCode, KeyboardInput as BevyKeyboardInput, NativeKey, NativeKeyCode},
     mouse::{
@@ -15,13 +15,21 @@ use bevy_input::{
     touch::TouchPhase,
 };
 
+use crate::plugins::core::GodotSyncSet;
+use crate::plugins::input::action_axis::{ActionAxisBindings, GodotActionAxis, bridge_action_axes};
 use crate::plugins::input::events::{
     GodotKeyboardInput, GodotMouseButton, GodotMouseButtonInput, GodotMouseMotion,
     PanGestureInput as GodotPanGestureInput,
 };
+use crate::plugins::input::tuning::InputTuning;
+use godot::global::Key as GodotKey;
 
 /// Plugin that bridges godot-bevy's input messages to Bevy's standard input resources.
 /// Automatically includes `GodotInputEventPlugin`.
+///
+/// Godot actions with analog strength can also be read as `Axis<GodotActionAxis>`
+/// once registered with [`ActionAxisAppExt`](super::action_axis::ActionAxisAppExt),
+/// filtered through [`InputTuning`].
 #[derive(Default)]
 pub struct BevyInputBridgePlugin;
 
@@ -29,6 +37,9 @@ impl Plugin for BevyInputBridgePlugin {
     fn build(&self, app: &mut App) {
         app.add_plugins(super::events::GodotInputEventPlugin)
             .add_plugins(InputPlugin)
+            .init_resource::<ActionAxisBindings>()
+            .init_resource::<Axis<GodotActionAxis>>()
+            .init_resource::<InputTuning>()
             .add_systems(
                 First,
                 (
@@ -37,11 +48,13 @@ impl Plugin for BevyInputBridgePlugin {
                     bridge_mouse_motion,
                     bridge_mouse_scroll,
                     bridge_pan_gesture,
+                    bridge_action_axes,
                 )
                     // Godot input messages are written and buffer-swapped within
                     // First; without this ordering the bridge can run outside the
                     // one-frame window where they are readable and drop input.
-                    .after(super::events::write_input_messages),
+                    .after(super::events::write_input_messages)
+                    .in_set(GodotSyncSet::InputPump),
             );
     }
 }
@@ -50,6 +63,7 @@ fn bridge_keyboard_input(
     mut keyboard_messages: MessageReader<GodotKeyboardInput>,
     mut bevy_keyboard_events: MessageWriter<BevyKeyboardInput>,
 ) {
+    crate::profile_scope!("bridge_keyboard_input");
     for msg in keyboard_messages.read() {
         let key_code = godot_key_to_bevy_keycode(msg.keycode)
             .unwrap_or(KeyCode::Unidentified(NativeKeyCode::Unidentified));
@@ -86,6 +100,7 @@ fn bridge_mouse_button_input(
     mut mouse_messages: MessageReader<GodotMouseButtonInput>,
     mut bevy_mouse_button_messages: MessageWriter<BevyMouseButtonInput>,
 ) {
+    crate::profile_scope!("bridge_mouse_button_input");
     for message in mouse_messages.read() {
         // Skip wheel events - they're handled separately in bridge_mouse_scroll
         if matches!(
@@ -117,6 +132,7 @@ fn bridge_mouse_motion(
     mut mouse_motion_messages: MessageReader<GodotMouseMotion>,
     mut bevy_mouse_motion_messages: MessageWriter<BevyMouseMotion>,
 ) {
+    crate::profile_scope!("bridge_mouse_motion");
     for event in mouse_motion_messages.read() {
         bevy_mouse_motion_messages.write(BevyMouseMotion { delta: event.delta });
     }
@@ -126,6 +142,7 @@ fn bridge_mouse_scroll(
     mut mouse_button_messages: MessageReader<GodotMouseButtonInput>,
     mut bevy_mouse_scroll_messages: MessageWriter<BevyMouseWheel>,
 ) {
+    crate::profile_scope!("bridge_mouse_scroll");
     for message in mouse_button_messages.read() {
         match message.button {
             GodotMouseButton::WheelUp => {
@@ -177,6 +194,7 @@ fn bridge_pan_gesture(
     mut pan_messages: MessageReader<GodotPanGestureInput>,
     mut bevy_pan_messages: MessageWriter<BevyPanGesture>,
 ) {
+    crate::profile_scope!("bridge_pan_gesture");
     for event in pan_messages.read() {
         bevy_pan_messages.write(BevyPanGesture(event.delta));
     }
@@ -226,122 +244,135 @@ fn godot_key_to_bevy_key(godot_key: godot::global::Key) -> Key {
     }
 }
 
-fn godot_key_to_bevy_keycode(godot_key: godot::global::Key) -> Option<KeyCode> {
+/// Godot keys with a Bevy `KeyCode` equivalent; many keys have none.
+const KEY_CODES: &[(GodotKey, KeyCode)] = {
+    use GodotKey as GK;
     use KeyCode as BK;
-    use godot::global::Key as GK;
-
-    match godot_key {
-        GK::A => Some(BK::KeyA),
-        GK::B => Some(BK::KeyB),
-        GK::C => Some(BK::KeyC),
-        GK::D => Some(BK::KeyD),
-        GK::E => Some(BK::KeyE),
-        GK::F => Some(BK::KeyF),
-        GK::G => Some(BK::KeyG),
-        GK::H => Some(BK::KeyH),
-        GK::I => Some(BK::KeyI),
-        GK::J => Some(BK::KeyJ),
-        GK::K => Some(BK::KeyK),
-        GK::L => Some(BK::KeyL),
-        GK::M => Some(BK::KeyM),
-        GK::N => Some(BK::KeyN),
-        GK::O => Some(BK::KeyO),
-        GK::P => Some(BK::KeyP),
-        GK::Q => Some(BK::KeyQ),
-        GK::R => Some(BK::KeyR),
-        GK::S => Some(BK::KeyS),
-        GK::T => Some(BK::KeyT),
-        GK::U => Some(BK::KeyU),
-        GK::V => Some(BK::KeyV),
-        GK::W => Some(BK::KeyW),
-        GK::X => Some(BK::KeyX),
-        GK::Y => Some(BK::KeyY),
-        GK::Z => Some(BK::KeyZ),
-
-        GK::KEY_0 => Some(BK::Digit0),
-        GK::KEY_1 => Some(BK::Digit1),
-        GK::KEY_2 => Some(BK::Digit2),
-        GK::KEY_3 => Some(BK::Digit3),
-        GK::KEY_4 => Some(BK::Digit4),
-        GK::KEY_5 => Some(BK::Digit5),
-        GK::KEY_6 => Some(BK::Digit6),
-        GK::KEY_7 => Some(BK::Digit7),
-        GK::KEY_8 => Some(BK::Digit8),
-        GK::KEY_9 => Some(BK::Digit9),
-
-        GK::SPACE => Some(BK::Space),
-        GK::ENTER => Some(BK::Enter),
-        GK::ESCAPE => Some(BK::Escape),
-        GK::BACKSPACE => Some(BK::Backspace),
-        GK::TAB => Some(BK::Tab),
-        GK::SHIFT => Some(BK::ShiftLeft),
-        GK::CTRL => Some(BK::ControlLeft),
-        GK::ALT => Some(BK::AltLeft),
-
-        GK::LEFT => Some(BK::ArrowLeft),
-        GK::RIGHT => Some(BK::ArrowRight),
-        GK::UP => Some(BK::ArrowUp),
-        GK::DOWN => Some(BK::ArrowDown),
-
-        GK::F1 => Some(BK::F1),
-        GK::F2 => Some(BK::F2),
-        GK::F3 => Some(BK::F3),
-        GK::F4 => Some(BK::F4),
-        GK::F5 => Some(BK::F5),
-        GK::F6 => Some(BK::F6),
-        GK::F7 => Some(BK::F7),
-        GK::F8 => Some(BK::F8),
-        GK::F9 => Some(BK::F9),
-        GK::F10 => Some(BK::F10),
-        GK::F11 => Some(BK::F11),
-        GK::F12 => Some(BK::F12),
-
+    &[
+        (GK::A, BK::KeyA),
+        (GK::B, BK::KeyB),
+        (GK::C, BK::KeyC),
+        (GK::D, BK::KeyD),
+        (GK::E, BK::KeyE),
+        (GK::F, BK::KeyF),
+        (GK::G, BK::KeyG),
+        (GK::H, BK::KeyH),
+        (GK::I, BK::KeyI),
+        (GK::J, BK::KeyJ),
+        (GK::K, BK::KeyK),
+        (GK::L, BK::KeyL),
+        (GK::M, BK::KeyM),
+        (GK::N, BK::KeyN),
+        (GK::O, BK::KeyO),
+        (GK::P, BK::KeyP),
+        (GK::Q, BK::KeyQ),
+        (GK::R, BK::KeyR),
+        (GK::S, BK::KeyS),
+        (GK::T, BK::KeyT),
+        (GK::U, BK::KeyU),
+        (GK::V, BK::KeyV),
+        (GK::W, BK::KeyW),
+        (GK::X, BK::KeyX),
+        (GK::Y, BK::KeyY),
+        (GK::Z, BK::KeyZ),
+        (GK::KEY_0, BK::Digit0),
+        (GK::KEY_1, BK::Digit1),
+        (GK::KEY_2, BK::Digit2),
+        (GK::KEY_3, BK::Digit3),
+        (GK::KEY_4, BK::Digit4),
+        (GK::KEY_5, BK::Digit5),
+        (GK::KEY_6, BK::Digit6),
+        (GK::KEY_7, BK::Digit7),
+        (GK::KEY_8, BK::Digit8),
+        (GK::KEY_9, BK::Digit9),
+        (GK::SPACE, BK::Space),
+        (GK::ENTER, BK::Enter),
+        (GK::ESCAPE, BK::Escape),
+        (GK::BACKSPACE, BK::Backspace),
+        (GK::TAB, BK::Tab),
+        (GK::SHIFT, BK::ShiftLeft),
+        (GK::CTRL, BK::ControlLeft),
+        (GK::ALT, BK::AltLeft),
+        (GK::LEFT, BK::ArrowLeft),
+        (GK::RIGHT, BK::ArrowRight),
+        (GK::UP, BK::ArrowUp),
+        (GK::DOWN, BK::ArrowDown),
+        (GK::F1, BK::F1),
+        (GK::F2, BK::F2),
+        (GK::F3, BK::F3),
+        (GK::F4, BK::F4),
+        (GK::F5, BK::F5),
+        (GK::F6, BK::F6),
+        (GK::F7, BK::F7),
+        (GK::F8, BK::F8),
+        (GK::F9, BK::F9),
+        (GK::F10, BK::F10),
+        (GK::F11, BK::F11),
+        (GK::F12, BK::F12),
         // Numpad keys
-        GK::KP_0 => Some(BK::Numpad0),
-        GK::KP_1 => Some(BK::Numpad1),
-        GK::KP_2 => Some(BK::Numpad2),
-        GK::KP_3 => Some(BK::Numpad3),
-        GK::KP_4 => Some(BK::Numpad4),
-        GK::KP_5 => Some(BK::Numpad5),
-        GK::KP_6 => Some(BK::Numpad6),
-        GK::KP_7 => Some(BK::Numpad7),
-        GK::KP_8 => Some(BK::Numpad8),
-        GK::KP_9 => Some(BK::Numpad9),
-        GK::KP_ADD => Some(BK::NumpadAdd),
-        GK::KP_SUBTRACT => Some(BK::NumpadSubtract),
-        GK::KP_MULTIPLY => Some(BK::NumpadMultiply),
-        GK::KP_DIVIDE => Some(BK::NumpadDivide),
-        GK::KP_PERIOD => Some(BK::NumpadDecimal),
-        GK::KP_ENTER => Some(BK::NumpadEnter),
-
+        (GK::KP_0, BK::Numpad0),
+        (GK::KP_1, BK::Numpad1),
+        (GK::KP_2, BK::Numpad2),
+        (GK::KP_3, BK::Numpad3),
+        (GK::KP_4, BK::Numpad4),
+        (GK::KP_5, BK::Numpad5),
+        (GK::KP_6, BK::Numpad6),
+        (GK::KP_7, BK::Numpad7),
+        (GK::KP_8, BK::Numpad8),
+        (GK::KP_9, BK::Numpad9),
+        (GK::KP_ADD, BK::NumpadAdd),
+        (GK::KP_SUBTRACT, BK::NumpadSubtract),
+        (GK::KP_MULTIPLY, BK::NumpadMultiply),
+        (GK::KP_DIVIDE, BK::NumpadDivide),
+        (GK::KP_PERIOD, BK::NumpadDecimal),
+        (GK::KP_ENTER, BK::NumpadEnter),
         // Additional common keys
-        GK::DELETE => Some(BK::Delete),
-        GK::INSERT => Some(BK::Insert),
-        GK::HOME => Some(BK::Home),
-        GK::END => Some(BK::End),
-        GK::PAGEUP => Some(BK::PageUp),
-        GK::PAGEDOWN => Some(BK::PageDown),
-        GK::CAPSLOCK => Some(BK::CapsLock),
-        GK::NUMLOCK => Some(BK::NumLock),
-        GK::SCROLLLOCK => Some(BK::ScrollLock),
-        GK::PAUSE => Some(BK::Pause),
-        GK::PRINT => Some(BK::PrintScreen),
-
+        (GK::DELETE, BK::Delete),
+        (GK::INSERT, BK::Insert),
+        (GK::HOME, BK::Home),
+        (GK::END, BK::End),
+        (GK::PAGEUP, BK::PageUp),
+        (GK::PAGEDOWN, BK::PageDown),
+        (GK::CAPSLOCK, BK::CapsLock),
+        (GK::NUMLOCK, BK::NumLock),
+        (GK::SCROLLLOCK, BK::ScrollLock),
+        (GK::PAUSE, BK::Pause),
+        (GK::PRINT, BK::PrintScreen),
         // Punctuation and symbols
-        GK::COMMA => Some(BK::Comma),
-        GK::PERIOD => Some(BK::Period),
-        GK::SLASH => Some(BK::Slash),
-        GK::SEMICOLON => Some(BK::Semicolon),
-        GK::APOSTROPHE => Some(BK::Quote),
-        GK::BRACKETLEFT => Some(BK::BracketLeft),
-        GK::BRACKETRIGHT => Some(BK::BracketRight),
-        GK::BACKSLASH => Some(BK::Backslash),
-        GK::QUOTELEFT => Some(BK::Backquote),
-        GK::MINUS => Some(BK::Minus),
-        GK::EQUAL => Some(BK::Equal),
-
-        _ => None, // Many keys don't have direct equivalents
-    }
+        (GK::COMMA, BK::Comma),
+        (GK::PERIOD, BK::Period),
+        (GK::SLASH, BK::Slash),
+        (GK::SEMICOLON, BK::Semicolon),
+        (GK::APOSTROPHE, BK::Quote),
+        (GK::BRACKETLEFT, BK::BracketLeft),
+        (GK::BRACKETRIGHT, BK::BracketRight),
+        (GK::BACKSLASH, BK::Backslash),
+        (GK::QUOTELEFT, BK::Backquote),
+        (GK::MINUS, BK::Minus),
+        (GK::EQUAL, BK::Equal),
+    ]
+};
+
+fn godot_key_to_bevy_keycode(godot_key: GodotKey) -> Option<KeyCode> {
+    KEY_CODES
+        .iter()
+        .find(|(godot, _)| *godot == godot_key)
+        .map(|(_, bevy)| *bevy)
+}
+
+/// The Godot key at `key_code`'s position, for APIs taking a physical keycode.
+/// Left and right modifiers share one Godot key.
+pub(crate) fn bevy_keycode_to_godot_key(key_code: KeyCode) -> Option<GodotKey> {
+    let key_code = match key_code {
+        KeyCode::ShiftRight => KeyCode::ShiftLeft,
+        KeyCode::ControlRight => KeyCode::ControlLeft,
+        KeyCode::AltRight => KeyCode::AltLeft,
+        other => other,
+    };
+    KEY_CODES
+        .iter()
+        .find(|(_, bevy)| *bevy == key_code)
+        .map(|(godot, _)| *godot)
 }
 
 fn godot_mouse_to_bevy_mouse(godot_button: GodotMouseButton) -> BevyMouseButton {
@@ -502,4 +533,16 @@ mod tests {
             "bridge must only write events, never press ButtonInput<KeyCode> directly"
         );
     }
+
+    #[test]
+    fn key_table_maps_both_ways() {
+        for (godot, bevy) in KEY_CODES {
+            assert_eq!(godot_key_to_bevy_keycode(*godot), Some(*bevy));
+            assert_eq!(bevy_keycode_to_godot_key(*bevy), Some(*godot));
+        }
+        assert_eq!(
+            bevy_keycode_to_godot_key(KeyCode::ShiftRight),
+            Some(godot::global::Key::SHIFT)
+        );
+    }
 }
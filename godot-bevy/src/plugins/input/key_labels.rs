@@ -0,0 +1,217 @@
+//! Labels for binding UIs: keys named after the player's keyboard layout, and
+//! gamepad buttons named after the connected controller.
+//!
+//! Bevy's `KeyCode` is a physical position (`KeyCode::KeyQ` is the key left of
+//! `W` on a US keyboard), so showing `"Q"` to an AZERTY player is wrong. Godot
+//! knows the active layout:
+//!
+//! ```ignore
+//! fn show_binding(mut godot: GodotAccess, bindings: Res<Bindings>) {
+//!     // "Q" on QWERTY, "A" on AZERTY
+//!     let text = key_display_name(&mut godot, bindings.attack);
+//!     // ...
+//! }
+//! ```
+//!
+//! Gamepad labels come from the joypad name Godot reports:
+//!
+//! ```ignore
+//! let family = gamepad_family(&mut godot, event.device);
+//! let text = gamepad_button_label(family, event.button_index); // "Cross", "A", "B", ...
+//! let icon = format!("res://ui/glyphs/{}.png", gamepad_button_glyph_name(family, event.button_index));
+//! ```
+
+use bevy_input::keyboard::KeyCode;
+use godot::classes::{DisplayServer, Input, Os};
+use godot::global::Key as GodotKey;
+
+use super::input_bridge::bevy_keycode_to_godot_key;
+use crate::interop::GodotAccess;
+
+/// The label printed on `key` in the active keyboard layout, e.g. `"A"` for
+/// `KeyCode::KeyQ` on AZERTY. Keys Godot can't name fall back to the `KeyCode`
+/// variant name.
+pub fn key_display_name(godot: &mut GodotAccess, key: KeyCode) -> String {
+    let Some(physical) = bevy_keycode_to_godot_key(key) else {
+        return format!("{key:?}");
+    };
+    let label = godot
+        .singleton::<DisplayServer>()
+        .keyboard_get_label_from_physical(physical);
+    // Layout-independent keys (F1, Enter, arrows) have no label of their own.
+    let label = if label == GodotKey::NONE {
+        physical
+    } else {
+        label
+    };
+    let name = godot
+        .singleton::<Os>()
+        .get_keycode_string(label)
+        .to_string();
+    if name.is_empty() {
+        format!("{key:?}")
+    } else {
+        name
+    }
+}
+
+/// Controller family, for picking face button names and glyphs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum GamepadFamily {
+    /// Xbox and most generic XInput pads.
+    #[default]
+    Xbox,
+    PlayStation,
+    Nintendo,
+}
+
+impl GamepadFamily {
+    /// Guess the family from a joypad name such as `"PS5 Controller"`.
+    /// Unknown pads are treated as [`Xbox`](Self::Xbox), the usual layout.
+    pub fn from_joy_name(name: &str) -> Self {
+        let name = name.to_ascii_lowercase();
+        if ["playstation", "dualshock", "dualsense", "ps3", "ps4", "ps5"]
+            .iter()
+            .any(|hint| name.contains(hint))
+        {
+            Self::PlayStation
+        } else if ["nintendo", "switch", "joy-con", "joycon"]
+            .iter()
+            .any(|hint| name.contains(hint))
+        {
+            Self::Nintendo
+        } else {
+            Self::Xbox
+        }
+    }
+
+    fn glyph_prefix(self) -> &'static str {
+        match self {
+            Self::Xbox => "xbox",
+            Self::PlayStation => "playstation",
+            Self::Nintendo => "nintendo",
+        }
+    }
+}
+
+/// The family of the controller Godot reports for `device`, as in
+/// `GamepadButtonInput::device`.
+pub fn gamepad_family(godot: &mut GodotAccess, device: i32) -> GamepadFamily {
+    let name = godot.singleton::<Input>().get_joy_name(device).to_string();
+    GamepadFamily::from_joy_name(&name)
+}
+
+/// Button name as printed on a `family` controller, for a Godot `JoyButton`
+/// index such as `GamepadButtonInput::button_index`. Nintendo pads follow
+/// Godot's positional mapping, so the south button is labelled `"B"`.
+pub fn gamepad_button_label(family: GamepadFamily, button_index: i32) -> &'static str {
+    use GamepadFamily::*;
+    match (family, button_index) {
+        (Xbox, 0) => "A",
+        (Xbox, 1) => "B",
+        (Xbox, 2) => "X",
+        (Xbox, 3) => "Y",
+        (PlayStation, 0) => "Cross",
+        (PlayStation, 1) => "Circle",
+        (PlayStation, 2) => "Square",
+        (PlayStation, 3) => "Triangle",
+        (Nintendo, 0) => "B",
+        (Nintendo, 1) => "A",
+        (Nintendo, 2) => "Y",
+        (Nintendo, 3) => "X",
+
+        (Xbox, 4) => "View",
+        (PlayStation, 4) => "Share",
+        (Nintendo, 4) => "-",
+        (Xbox, 5) => "Xbox",
+        (PlayStation, 5) => "PS",
+        (Nintendo, 5) => "Home",
+        (Xbox, 6) => "Menu",
+        (PlayStation, 6) => "Options",
+        (Nintendo, 6) => "+",
+
+        (PlayStation, 7) => "L3",
+        (PlayStation, 8) => "R3",
+        (PlayStation, 9) => "L1",
+        (PlayStation, 10) => "R1",
+        (Xbox, 9) => "LB",
+        (Xbox, 10) => "RB",
+        (Nintendo, 9) => "L",
+        (Nintendo, 10) => "R",
+        (_, 7) => "Left Stick",
+        (_, 8) => "Right Stick",
+
+        (_, 11) => "D-pad Up",
+        (_, 12) => "D-pad Down",
+        (_, 13) => "D-pad Left",
+        (_, 14) => "D-pad Right",
+        (Nintendo, 15) => "Capture",
+        (PlayStation, 15) => "Mic",
+        (Xbox, 15) => "Share",
+        (_, 16) => "Paddle 1",
+        (_, 17) => "Paddle 2",
+        (_, 18) => "Paddle 3",
+        (_, 19) => "Paddle 4",
+        (_, 20) => "Touchpad",
+        _ => "Unknown",
+    }
+}
+
+/// A stable, lowercase name for a button glyph, e.g. `"playstation_cross"` or
+/// `"xbox_left_stick"`, to build icon paths from.
+pub fn gamepad_button_glyph_name(family: GamepadFamily, button_index: i32) -> String {
+    let label = match gamepad_button_label(family, button_index) {
+        "-" => "minus",
+        "+" => "plus",
+        label => label,
+    };
+    let label = label.to_ascii_lowercase().replace([' ', '-'], "_");
+    format!("{}_{label}", family.glyph_prefix())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn joy_names_map_to_families() {
+        assert_eq!(
+            GamepadFamily::from_joy_name("PS5 Controller"),
+            GamepadFamily::PlayStation
+        );
+        assert_eq!(
+            GamepadFamily::from_joy_name("Nintendo Switch Pro Controller"),
+            GamepadFamily::Nintendo
+        );
+        assert_eq!(
+            GamepadFamily::from_joy_name("Xbox Series Controller"),
+            GamepadFamily::Xbox
+        );
+        assert_eq!(GamepadFamily::from_joy_name(""), GamepadFamily::Xbox);
+    }
+
+    #[test]
+    fn labels_and_glyphs_follow_the_family() {
+        assert_eq!(gamepad_button_label(GamepadFamily::Xbox, 0), "A");
+        assert_eq!(gamepad_button_label(GamepadFamily::PlayStation, 0), "Cross");
+        assert_eq!(gamepad_button_label(GamepadFamily::Nintendo, 0), "B");
+        assert_eq!(gamepad_button_label(GamepadFamily::Xbox, 99), "Unknown");
+
+        assert_eq!(
+            gamepad_button_glyph_name(GamepadFamily::PlayStation, 0),
+            "playstation_cross"
+        );
+        assert_eq!(
+            gamepad_button_glyph_name(GamepadFamily::Xbox, 7),
+            "xbox_left_stick"
+        );
+        assert_eq!(
+            gamepad_button_glyph_name(GamepadFamily::Nintendo, 4),
+            "nintendo_minus"
+        );
+        assert_eq!(
+            gamepad_button_glyph_name(GamepadFamily::Xbox, 11),
+            "xbox_d_pad_up"
+        );
+    }
+}
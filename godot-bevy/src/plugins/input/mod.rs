@@ -1,12 +1,27 @@
+pub mod action_axis;
+pub mod action_set;
 pub mod actions;
 pub mod events;
 pub mod input_bridge;
+pub mod key_labels;
+pub mod react;
+pub mod tuning;
 
 // Re-export the main plugins
 pub use events::GodotInputEventPlugin;
 pub use input_bridge::BevyInputBridgePlugin;
+pub use react::{GodotInputReactPlugin, InputReact};
+pub use tuning::{AxisTuning, InputTuning};
+
+// Re-export binding UI labels
+pub use key_labels::{
+    GamepadFamily, gamepad_button_glyph_name, gamepad_button_label, gamepad_family,
+    key_display_name,
+};
 
 // Re-export actions API
+pub use action_axis::{ActionAxisAppExt, GodotActionAxis};
+pub use action_set::{ActionBinding, ActionSetAppExt, GodotActionSet};
 pub use actions::{Action, GodotActions, GodotActionsPlugin, GodotInputSet};
 
 // Re-export event types for convenience
@@ -0,0 +1,71 @@
+//! Optional low-latency input path: react to input inside Godot's input callbacks
+//! instead of waiting for the next frame's `First`.
+//!
+//! Normally input events are queued by the input watcher and turned into messages
+//! at the start of the next Bevy frame. With [`GodotInputReactPlugin`], `BevyApp`
+//! handles `_input` itself: it drains the queue right away and
+//! runs the [`InputReact`] schedule, where systems can read the usual input
+//! messages and act on them (fire a shot, start a jump animation) up to a frame
+//! earlier.
+//!
+//! ```ignore
+//! app.add_plugins(GodotInputReactPlugin)
+//!     .add_systems(InputReact, fire_on_click);
+//!
+//! fn fire_on_click(mut clicks: MessageReader<GodotMouseButtonInput>, mut gun: GodotAccess, ...) {
+//!     for click in clicks.read().filter(|c| c.pressed) {
+//!         // ...
+//!     }
+//! }
+//! ```
+//!
+//! `InputReact` runs once per input event, outside the regular frame, so keep
+//! it small: no `Time` delta, and `Update` systems still see the same messages in
+//! the next frame as usual. It runs from `_input`, before Godot knows whether the
+//! GUI will consume the event, so unhandled-input messages arrive with the next
+//! frame rather than in `InputReact`.
+
+use bevy_app::{App, Plugin};
+use bevy_ecs::prelude::Resource;
+use bevy_ecs::schedule::ScheduleLabel;
+use bevy_ecs::world::World;
+use tracing::warn;
+
+use super::GodotInputEventPlugin;
+use super::events::write_input_messages;
+
+/// Schedule run from `BevyApp`'s `_input` callback, right after the pending input
+/// events were written as messages. Added by [`GodotInputReactPlugin`].
+#[derive(ScheduleLabel, Debug, Hash, PartialEq, Eq, Clone)]
+pub struct InputReact;
+
+/// Enables the [`InputReact`] schedule. See the [module docs](self).
+#[derive(Default)]
+pub struct GodotInputReactPlugin;
+
+impl Plugin for GodotInputReactPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<GodotInputEventPlugin>() {
+            app.add_plugins(GodotInputEventPlugin);
+        }
+        app.init_schedule(InputReact)
+            .init_resource::<InputReactEnabled>();
+    }
+}
+
+/// Presence tells `BevyApp` to enable its input callbacks.
+#[derive(Resource, Default)]
+pub(crate) struct InputReactEnabled;
+
+pub(crate) fn input_react_enabled(world: &World) -> bool {
+    world.contains_resource::<InputReactEnabled>()
+}
+
+/// Drain queued input into messages, then run [`InputReact`].
+pub(crate) fn run_input_react(world: &mut World) {
+    if let Err(err) = world.run_system_cached(write_input_messages) {
+        warn!("InputReact: could not drain input events: {err}");
+        return;
+    }
+    let _ = world.try_run_schedule(InputReact);
+}
@@ -0,0 +1,125 @@
+//! Stick filtering for action axes: dead zone, response curve and inversion.
+//!
+//! [`InputTuning`] is applied by `BevyInputBridgePlugin` when it writes
+//! `Axis<GodotActionAxis>`, so every system reads the filtered value:
+//!
+//! ```ignore
+//! app.add_plugins(BevyInputBridgePlugin)
+//!     .add_action_axis("look_y", "look_down", "look_up")
+//!     .add_systems(Startup, |mut tuning: ResMut<InputTuning>| {
+//!         tuning.set(
+//!             "look_y",
+//!             AxisTuning::default().with_dead_zone(0.15).with_exponent(2.0).inverted(),
+//!         );
+//!     });
+//! ```
+//!
+//! Changing the resource at runtime (an options menu) re-filters the axes on the
+//! next frame, without waiting for the stick to move.
+
+use bevy_ecs::prelude::Resource;
+use bevy_platform::collections::HashMap;
+
+use super::action_axis::GodotActionAxis;
+
+/// How one axis responds to the raw action strength.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AxisTuning {
+    /// Magnitudes below this read as 0; the rest is rescaled to start at 0.
+    /// Applied on top of the action's own dead zone in the Input Map.
+    pub dead_zone: f32,
+    /// Response curve: 1.0 is linear, higher values give finer control near
+    /// the center.
+    pub exponent: f32,
+    /// Flip the sign, e.g. for inverted look.
+    pub invert: bool,
+}
+
+impl Default for AxisTuning {
+    /// Pass-through: no extra dead zone, linear, not inverted.
+    fn default() -> Self {
+        Self {
+            dead_zone: 0.0,
+            exponent: 1.0,
+            invert: false,
+        }
+    }
+}
+
+impl AxisTuning {
+    pub fn with_dead_zone(mut self, dead_zone: f32) -> Self {
+        self.dead_zone = dead_zone;
+        self
+    }
+
+    pub fn with_exponent(mut self, exponent: f32) -> Self {
+        self.exponent = exponent;
+        self
+    }
+
+    pub fn inverted(mut self) -> Self {
+        self.invert = true;
+        self
+    }
+
+    /// Filter a raw value in -1.0..=1.0.
+    pub fn apply(&self, value: f32) -> f32 {
+        let magnitude = value.abs();
+        if magnitude <= self.dead_zone {
+            return 0.0;
+        }
+        let live = ((magnitude - self.dead_zone) / (1.0 - self.dead_zone)).clamp(0.0, 1.0);
+        let shaped = live.powf(self.exponent) * value.signum();
+        if self.invert { -shaped } else { shaped }
+    }
+}
+
+/// Per-axis [`AxisTuning`] for `Axis<GodotActionAxis>`. Added by
+/// `BevyInputBridgePlugin`. See the [module docs](self).
+#[derive(Resource, Debug, Clone, Default)]
+pub struct InputTuning {
+    /// Used for axes without their own entry.
+    pub default: AxisTuning,
+    axes: HashMap<GodotActionAxis, AxisTuning>,
+}
+
+impl InputTuning {
+    pub fn set(&mut self, axis: &'static str, tuning: AxisTuning) -> &mut Self {
+        self.axes.insert(GodotActionAxis(axis), tuning);
+        self
+    }
+
+    /// Go back to [`default`](Self::default) for this axis.
+    pub fn reset(&mut self, axis: &'static str) -> &mut Self {
+        self.axes.remove(&GodotActionAxis(axis));
+        self
+    }
+
+    pub fn get(&self, axis: GodotActionAxis) -> AxisTuning {
+        self.axes.get(&axis).copied().unwrap_or(self.default)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_tuning_passes_values_through() {
+        let tuning = AxisTuning::default();
+        for value in [-1.0, -0.3, 0.0, 0.42, 1.0] {
+            assert_eq!(tuning.apply(value), value);
+        }
+    }
+
+    #[test]
+    fn dead_zone_rescales_and_curve_keeps_sign() {
+        let tuning = AxisTuning::default().with_dead_zone(0.2).with_exponent(2.0);
+        assert_eq!(tuning.apply(0.15), 0.0);
+        assert_eq!(tuning.apply(-0.2), 0.0);
+        assert!((tuning.apply(0.6) - 0.25).abs() < 1e-6);
+        assert!((tuning.apply(-0.6) + 0.25).abs() < 1e-6);
+        assert_eq!(tuning.apply(1.0), 1.0);
+        assert_eq!(tuning.inverted().apply(1.0), -1.0);
+    }
+}
@@ -0,0 +1,193 @@
+//! Named physics and render layers, read from the project settings.
+//!
+//! [`GodotLayerNamesPlugin`] loads the names set under *Project Settings > Layer
+//! Names* into the [`LayerNames`] resource, so masks can be built by name instead
+//! of by bit:
+//!
+//! ```ignore
+//! fn configure_bullet(layers: Res<LayerNames>, mut godot: GodotAccess, ...) {
+//!     let mut area = godot.get::<Area2D>(handle);
+//!     area.set_collision_layer(layers.physics_2d.bit("bullets").unwrap_or(0));
+//!     area.set_collision_mask(layers.physics_2d.mask(["enemies", "walls"])?);
+//! }
+//! ```
+//!
+//! Layer numbers are 1-based, as in the editor and `set_collision_layer_value`.
+
+use bevy_app::{App, Plugin, PreStartup};
+use bevy_ecs::prelude::Resource;
+use bevy_ecs::system::ResMut;
+use godot::builtin::GString;
+use godot::classes::ProjectSettings;
+use godot::obj::Singleton;
+use thiserror::Error;
+
+use crate::interop::GodotAccess;
+
+const PHYSICS_LAYERS: u32 = 32;
+const RENDER_LAYERS: u32 = 20;
+
+/// Plugin loading [`LayerNames`] before `Startup`. See the [module docs](self).
+#[derive(Default)]
+pub struct GodotLayerNamesPlugin;
+
+impl Plugin for GodotLayerNamesPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<LayerNames>()
+            .add_systems(PreStartup, load_layer_names);
+    }
+}
+
+/// The project's layer names, one table per kind of layer.
+#[derive(Resource, Debug, Clone, Default, PartialEq, Eq)]
+pub struct LayerNames {
+    pub physics_2d: LayerTable,
+    pub physics_3d: LayerTable,
+    pub render_2d: LayerTable,
+    pub render_3d: LayerTable,
+}
+
+impl LayerNames {
+    /// Read the names from `ProjectSettings`. Main thread only.
+    pub fn from_project_settings() -> Self {
+        let settings = ProjectSettings::singleton();
+        let table = |kind: &str, count: u32| {
+            LayerTable::new((1..=count).filter_map(|layer| {
+                let name = settings
+                    .get_setting(&format!("layer_names/{kind}/layer_{layer}"))
+                    .try_to::<GString>()
+                    .ok()?;
+                Some((layer, name.to_string()))
+            }))
+        };
+        Self {
+            physics_2d: table("2d_physics", PHYSICS_LAYERS),
+            physics_3d: table("3d_physics", PHYSICS_LAYERS),
+            render_2d: table("2d_render", RENDER_LAYERS),
+            render_3d: table("3d_render", RENDER_LAYERS),
+        }
+    }
+}
+
+/// Names of one kind of layer. Unnamed layers are skipped; if two layers share
+/// a name, lookups find the lower one.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LayerTable {
+    /// Indexed by layer number - 1.
+    names: Vec<Option<String>>,
+}
+
+impl LayerTable {
+    /// A table from `(layer, name)` pairs. Layers outside 1..=32 and empty names
+    /// are ignored.
+    pub fn new(names: impl IntoIterator<Item = (u32, impl Into<String>)>) -> Self {
+        let mut table = Self::default();
+        for (layer, name) in names {
+            let name = name.into();
+            if !(1..=PHYSICS_LAYERS).contains(&layer) || name.is_empty() {
+                continue;
+            }
+            let index = layer as usize - 1;
+            if table.names.len() <= index {
+                table.names.resize(index + 1, None);
+            }
+            table.names[index] = Some(name);
+        }
+        table
+    }
+
+    /// The number of the layer called `name`.
+    pub fn layer(&self, name: &str) -> Option<u32> {
+        self.iter()
+            .find(|(_, layer_name)| *layer_name == name)
+            .map(|(layer, _)| layer)
+    }
+
+    /// A mask with only the layer called `name` set.
+    pub fn bit(&self, name: &str) -> Option<u32> {
+        self.layer(name).map(|layer| 1 << (layer - 1))
+    }
+
+    /// The name of `layer`, if it has one.
+    pub fn name(&self, layer: u32) -> Option<&str> {
+        let index = usize::try_from(layer).ok()?.checked_sub(1)?;
+        self.names.get(index)?.as_deref()
+    }
+
+    /// A mask with every named layer set, failing on the first unknown name.
+    pub fn mask<'a>(&self, names: impl IntoIterator<Item = &'a str>) -> Result<u32, UnknownLayer> {
+        names.into_iter().try_fold(0, |mask, name| {
+            self.bit(name)
+                .map(|bit| mask | bit)
+                .ok_or_else(|| UnknownLayer(name.to_string()))
+        })
+    }
+
+    /// Names of the layers set in `mask`, lowest first. Unnamed layers are skipped.
+    pub fn names_in(&self, mask: u32) -> impl Iterator<Item = &str> {
+        self.iter()
+            .filter(move |(layer, _)| mask & (1 << (layer - 1)) != 0)
+            .map(|(_, name)| name)
+    }
+
+    /// `(layer, name)` for every named layer, lowest first.
+    pub fn iter(&self) -> impl Iterator<Item = (u32, &str)> {
+        self.names
+            .iter()
+            .enumerate()
+            .filter_map(|(index, name)| Some((index as u32 + 1, name.as_deref()?)))
+    }
+}
+
+/// A layer name that the project doesn't define.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("no layer named {0:?}")]
+pub struct UnknownLayer(pub String);
+
+fn load_layer_names(mut names: ResMut<LayerNames>, _godot: GodotAccess) {
+    *names = LayerNames::from_project_settings();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn table() -> LayerTable {
+        LayerTable::new([
+            (1, "world"),
+            (3, "enemies"),
+            (32, "triggers"),
+            (4, ""),
+            (33, "out of range"),
+            (5, "enemies"),
+        ])
+    }
+
+    #[test]
+    fn names_map_to_layers_and_bits() {
+        let layers = table();
+        assert_eq!(layers.layer("enemies"), Some(3), "lowest layer wins");
+        assert_eq!(layers.bit("world"), Some(0b1));
+        assert_eq!(layers.bit("triggers"), Some(1 << 31));
+        assert_eq!(layers.bit("missing"), None);
+        assert_eq!(layers.name(3), Some("enemies"));
+        assert_eq!(layers.name(4), None);
+        assert_eq!(layers.name(0), None);
+        assert_eq!(layers.name(33), None);
+    }
+
+    #[test]
+    fn masks_round_trip_through_names() {
+        let layers = table();
+        let mask = layers.mask(["world", "enemies"]).unwrap();
+        assert_eq!(mask, 0b101);
+        assert_eq!(
+            layers.names_in(mask | 0b1000).collect::<Vec<_>>(),
+            ["world", "enemies"]
+        );
+        assert_eq!(
+            layers.mask(["world", "walls"]),
+            Err(UnknownLayer("walls".into()))
+        );
+    }
+}
@@ -0,0 +1,405 @@
+//! Reflected components mirrored into their node's metadata.
+//!
+//! With [`GodotMetaSyncPlugin`], a component marked `#[reflect(SyncToGodotMeta)]`
+//! is written into its entity's node as a metadata `Dictionary` every frame, so
+//! GDScript tooling and the remote inspector can see Bevy state without a
+//! hand-written `#[export]` per field. Edits made to that metadata are read back:
+//!
+//! ```ignore
+//! #[derive(Component, Reflect, Default)]
+//! #[reflect(Component, SyncToGodotMeta)]
+//! struct Stamina {
+//!     current: f32,
+//!     regen: i32,
+//! }
+//!
+//! app.add_plugins(GodotMetaSyncPlugin)
+//!     .register_type::<Stamina>()
+//!     // For a component whose derive you don't control:
+//!     .sync_to_godot_meta::<Name>();
+//! ```
+//!
+//! ```gdscript
+//! var stamina = $Player.get_meta("bevy_Stamina")   # {"current": 80.0, "regen": 2}
+//! stamina.current = 100.0                         # applied before the next Update
+//! ```
+//!
+//! The key is `bevy_` and the type's short name. Struct fields are keyed by name
+//! and tuple struct fields by index (`"0"`, `"1"`, ...). Numbers, `bool`,
+//! `String`, `Vec2` and `Vec3` are written as themselves, nested structs as
+//! nested dictionaries and fieldless enum variants by name; fields of any other
+//! type are left out. Edits are read back as strictly as the event bridge
+//! decodes: a value of the wrong type leaves its field unchanged.
+//!
+//! Metadata is written in `Last` when the value differs from the last write, and
+//! edits are applied in `PreUpdate`. Removing the component removes the metadata.
+
+use std::any::TypeId;
+use std::collections::HashMap;
+
+use bevy_app::{App, Last, Plugin, PreUpdate};
+use bevy_ecs::component::Component;
+use bevy_ecs::entity::Entity;
+use bevy_ecs::reflect::{AppTypeRegistry, ReflectComponent};
+use bevy_ecs::world::World;
+use bevy_math::{Vec2, Vec3};
+use bevy_reflect::enums::DynamicEnum;
+use bevy_reflect::{
+    FromType, GetTypeRegistration, PartialReflect, Reflect, ReflectMut, ReflectRef, TypePath,
+};
+use godot::builtin::{StringName, VarDictionary as Dictionary, Variant, Vector2, Vector3};
+use godot::classes::Object;
+use godot::meta::ToGodot;
+use godot::obj::{Gd, InstanceId};
+
+use crate::interop::GodotNodeHandle;
+
+/// Plugin mirroring `#[reflect(SyncToGodotMeta)]` components into node
+/// metadata. See the [module docs](self).
+#[derive(Default, Debug, Clone, Copy)]
+pub struct GodotMetaSyncPlugin;
+
+impl Plugin for GodotMetaSyncPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_non_send::<MetaSyncState>()
+            .add_systems(PreUpdate, read_meta_edits)
+            .add_systems(Last, write_meta);
+    }
+}
+
+/// Type data marking a reflected component for [`GodotMetaSyncPlugin`], added
+/// with `#[reflect(SyncToGodotMeta)]`.
+#[derive(Clone)]
+pub struct ReflectSyncToGodotMeta;
+
+impl<C: Component> FromType<C> for ReflectSyncToGodotMeta {
+    fn from_type() -> Self {
+        Self
+    }
+}
+
+/// Mark components for [`GodotMetaSyncPlugin`] from the app.
+pub trait GodotMetaSyncAppExt {
+    /// Sync `C` as if it had `#[reflect(SyncToGodotMeta)]`. `C` still needs
+    /// `#[reflect(Component)]`.
+    fn sync_to_godot_meta<C>(&mut self) -> &mut Self
+    where
+        C: Component + Reflect + TypePath + GetTypeRegistration;
+}
+
+impl GodotMetaSyncAppExt for App {
+    fn sync_to_godot_meta<C>(&mut self) -> &mut Self
+    where
+        C: Component + Reflect + TypePath + GetTypeRegistration,
+    {
+        self.register_type::<C>()
+            .register_type_data::<C, ReflectSyncToGodotMeta>()
+    }
+}
+
+/// The metadata last written for each synced component, to tell edits apart
+/// from our own writes. Main-thread only: it holds `Dictionary`s.
+#[derive(Default)]
+struct MetaSyncState(HashMap<(Entity, TypeId), Written>);
+
+struct Written {
+    node: InstanceId,
+    key: StringName,
+    // A deep copy: the node's own dictionary may be edited in place.
+    value: Dictionary,
+}
+
+struct SyncedType {
+    type_id: TypeId,
+    reflect: ReflectComponent,
+    key: StringName,
+}
+
+fn synced_types(world: &World) -> Vec<SyncedType> {
+    let Some(registry) = world.get_resource::<AppTypeRegistry>() else {
+        return Vec::new();
+    };
+    let registry = registry.read();
+    registry
+        .iter_with_data::<ReflectSyncToGodotMeta>()
+        .filter_map(|(registration, _)| {
+            Some(SyncedType {
+                type_id: registration.type_id(),
+                reflect: registration.data::<ReflectComponent>()?.clone(),
+                key: StringName::from(
+                    meta_key(registration.type_info().type_path_table().short_path()).as_str(),
+                ),
+            })
+        })
+        .collect()
+}
+
+/// The metadata key for a type: metadata names must be identifiers.
+fn meta_key(short_path: &str) -> String {
+    let name: String = short_path
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    format!("bevy_{name}")
+}
+
+fn read_meta_edits(world: &mut World) {
+    let types = synced_types(world);
+    let mut written = std::mem::take(&mut world.non_send_mut::<MetaSyncState>().0);
+
+    for ((entity, type_id), last) in &mut written {
+        let Some(synced) = types.iter().find(|t| t.type_id == *type_id) else {
+            continue;
+        };
+        let Ok(node) = Gd::<Object>::try_from_instance_id(last.node) else {
+            continue;
+        };
+        if !node.has_meta(&last.key) {
+            continue;
+        }
+        let Ok(edited) = node.get_meta(&last.key).try_to::<Dictionary>() else {
+            continue;
+        };
+        if edited == last.value {
+            continue;
+        }
+        let Ok(mut entity_mut) = world.get_entity_mut(*entity) else {
+            continue;
+        };
+        if let Some(mut component) = synced.reflect.reflect_mut(&mut entity_mut) {
+            apply_dict(component.as_partial_reflect_mut(), &edited);
+        }
+        last.value = edited.duplicate_deep();
+    }
+
+    world.non_send_mut::<MetaSyncState>().0 = written;
+}
+
+fn write_meta(world: &mut World) {
+    let types = synced_types(world);
+    let mut written = std::mem::take(&mut world.non_send_mut::<MetaSyncState>().0);
+
+    let mut nodes = world.query::<(Entity, &GodotNodeHandle)>();
+    for (entity, handle) in nodes.iter(world) {
+        let node_id = handle.instance_id();
+        for synced in &types {
+            let Some(component) = synced.reflect.reflect(world.entity(entity)) else {
+                continue;
+            };
+            let Some(value) = fields_to_dict(component.as_partial_reflect()) else {
+                continue;
+            };
+            let key = (entity, synced.type_id);
+            if written
+                .get(&key)
+                .is_some_and(|last| last.node == node_id && last.value == value)
+            {
+                continue;
+            }
+            let Ok(mut node) = Gd::<Object>::try_from_instance_id(node_id) else {
+                continue;
+            };
+            node.set_meta(&synced.key, &value.to_variant());
+            written.insert(
+                key,
+                Written {
+                    node: node_id,
+                    key: synced.key.clone(),
+                    value: value.duplicate_deep(),
+                },
+            );
+        }
+    }
+
+    // Drop the metadata of components removed (or entities despawned) since.
+    written.retain(|(entity, type_id), last| {
+        let present = world.get_entity(*entity).is_ok_and(|entity_ref| {
+            types
+                .iter()
+                .find(|t| t.type_id == *type_id)
+                .is_some_and(|synced| synced.reflect.reflect(entity_ref).is_some())
+        });
+        if !present && let Ok(mut node) = Gd::<Object>::try_from_instance_id(last.node) {
+            node.remove_meta(&last.key);
+        }
+        present
+    });
+
+    world.non_send_mut::<MetaSyncState>().0 = written;
+}
+
+/// The fields of a struct or tuple struct. `None` for other kinds of value.
+pub(crate) fn fields_to_dict(value: &dyn PartialReflect) -> Option<Dictionary> {
+    let mut dict = Dictionary::new();
+    match value.reflect_ref() {
+        ReflectRef::Struct(s) => {
+            for i in 0..s.field_len() {
+                if let Some(name) = s.name_at(i)
+                    && let Some(field) = s.field_at(i).and_then(field_to_variant)
+                {
+                    dict.set(name, &field);
+                }
+            }
+        }
+        ReflectRef::TupleStruct(ts) => {
+            for i in 0..ts.field_len() {
+                if let Some(field) = ts.field(i).and_then(field_to_variant) {
+                    dict.set(i.to_string().as_str(), &field);
+                }
+            }
+        }
+        _ => return None,
+    }
+    Some(dict)
+}
+
+/// A field as metadata can hold it, or `None` to leave it out.
+fn field_to_variant(value: &dyn PartialReflect) -> Option<Variant> {
+    if let Some(v) = value.try_downcast_ref::<f32>() {
+        return Some(v.to_variant());
+    }
+    if let Some(v) = value.try_downcast_ref::<f64>() {
+        return Some(v.to_variant());
+    }
+    if let Some(v) = value.try_downcast_ref::<i32>() {
+        return Some(v.to_variant());
+    }
+    if let Some(v) = value.try_downcast_ref::<i64>() {
+        return Some(v.to_variant());
+    }
+    if let Some(v) = value.try_downcast_ref::<u8>() {
+        return Some(v.to_variant());
+    }
+    if let Some(v) = value.try_downcast_ref::<u16>() {
+        return Some(v.to_variant());
+    }
+    if let Some(v) = value.try_downcast_ref::<u32>() {
+        return Some(v.to_variant());
+    }
+    if let Some(v) = value.try_downcast_ref::<usize>() {
+        return i64::try_from(*v).ok().map(|v| v.to_variant());
+    }
+    if let Some(v) = value.try_downcast_ref::<bool>() {
+        return Some(v.to_variant());
+    }
+    if let Some(v) = value.try_downcast_ref::<String>() {
+        return Some(v.as_str().to_variant());
+    }
+    if let Some(v) = value.try_downcast_ref::<Vec2>() {
+        return Some(Vector2::new(v.x, v.y).to_variant());
+    }
+    if let Some(v) = value.try_downcast_ref::<Vec3>() {
+        return Some(Vector3::new(v.x, v.y, v.z).to_variant());
+    }
+
+    match value.reflect_ref() {
+        ReflectRef::Struct(_) | ReflectRef::TupleStruct(_) => {
+            fields_to_dict(value).map(|dict| dict.to_variant())
+        }
+        ReflectRef::Enum(e) if e.field_len() == 0 => Some(e.variant_name().to_variant()),
+        _ => None,
+    }
+}
+
+/// Set the fields of `target` named in `dict`, the inverse of [`fields_to_dict`].
+pub(crate) fn apply_dict(target: &mut dyn PartialReflect, dict: &Dictionary) {
+    match target.reflect_mut() {
+        ReflectMut::Struct(s) => {
+            for i in 0..s.field_len() {
+                let Some(name) = s.name_at(i).map(str::to_owned) else {
+                    continue;
+                };
+                if let Some(value) = dict.get(name.as_str())
+                    && let Some(field) = s.field_at_mut(i)
+                {
+                    apply_variant(field, &value);
+                }
+            }
+        }
+        ReflectMut::TupleStruct(ts) => {
+            for i in 0..ts.field_len() {
+                if let Some(value) = dict.get(i.to_string().as_str())
+                    && let Some(field) = ts.field_mut(i)
+                {
+                    apply_variant(field, &value);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Set `field` from `value` when it holds the field's type; otherwise leave it.
+fn apply_variant(field: &mut dyn PartialReflect, value: &Variant) {
+    if let Some(field) = field.try_downcast_mut::<f32>() {
+        if let Ok(v) = value.try_to::<f32>() {
+            *field = v;
+        }
+    } else if let Some(field) = field.try_downcast_mut::<f64>() {
+        if let Ok(v) = value.try_to::<f64>() {
+            *field = v;
+        }
+    } else if let Some(field) = field.try_downcast_mut::<i32>() {
+        if let Ok(v) = value.try_to::<i32>() {
+            *field = v;
+        }
+    } else if let Some(field) = field.try_downcast_mut::<i64>() {
+        if let Ok(v) = value.try_to::<i64>() {
+            *field = v;
+        }
+    } else if let Some(field) = field.try_downcast_mut::<u8>() {
+        if let Ok(v) = value.try_to::<u8>() {
+            *field = v;
+        }
+    } else if let Some(field) = field.try_downcast_mut::<u16>() {
+        if let Ok(v) = value.try_to::<u16>() {
+            *field = v;
+        }
+    } else if let Some(field) = field.try_downcast_mut::<u32>() {
+        if let Ok(v) = value.try_to::<u32>() {
+            *field = v;
+        }
+    } else if let Some(field) = field.try_downcast_mut::<usize>() {
+        if let Some(v) = value
+            .try_to::<i64>()
+            .ok()
+            .and_then(|v| usize::try_from(v).ok())
+        {
+            *field = v;
+        }
+    } else if let Some(field) = field.try_downcast_mut::<bool>() {
+        if let Ok(v) = value.try_to::<bool>() {
+            *field = v;
+        }
+    } else if let Some(field) = field.try_downcast_mut::<String>() {
+        if let Ok(v) = value.try_to::<String>() {
+            *field = v;
+        }
+    } else if let Some(field) = field.try_downcast_mut::<Vec2>() {
+        if let Ok(v) = value.try_to::<Vector2>() {
+            *field = Vec2::new(v.x, v.y);
+        }
+    } else if let Some(field) = field.try_downcast_mut::<Vec3>() {
+        if let Ok(v) = value.try_to::<Vector3>() {
+            *field = Vec3::new(v.x, v.y, v.z);
+        }
+    } else if let Ok(dict) = value.try_to::<Dictionary>() {
+        apply_dict(field, &dict);
+    } else if let Ok(variant_name) = value.try_to::<String>()
+        && matches!(field.reflect_ref(), ReflectRef::Enum(_))
+    {
+        // An unknown variant name is rejected by `try_apply`.
+        let _ = field.try_apply(&DynamicEnum::new(variant_name, ()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn meta_keys_are_identifiers() {
+        assert_eq!(meta_key("Stamina"), "bevy_Stamina");
+        assert_eq!(meta_key("Cooldown<Dash>"), "bevy_Cooldown_Dash_");
+    }
+}
@@ -0,0 +1,292 @@
+//! Bevy metrics for GDScript, through the `BevyMetrics` engine singleton.
+//!
+//! The crate registers `BevyMetrics` when the extension loads, so existing
+//! GDScript overlays and tools can show Bevy state without any Rust. It reports
+//! what [`GodotMetricsPlugin`] captured at the end of the last frame:
+//!
+//! ```gdscript
+//! func _process(_delta):
+//!     $Label.text = "%d entities, %.1f ms" % [BevyMetrics.entity_count(), BevyMetrics.frame_ms()]
+//!     var score = BevyMetrics.get_resource("Score")
+//!     if score:
+//!         $Score.text = str(score.fields.value)
+//! ```
+//!
+//! Resources are only readable once exposed, since copying them out costs a
+//! reflection clone each frame:
+//!
+//! ```ignore
+//! app.add_plugins(GodotMetricsPlugin)
+//!     .expose_resource::<Score>();
+//! ```
+//!
+//! `get_resource` returns the same dictionaries as the editor's Bevy inspector:
+//! `{type, fields}` for structs, `{type, variant, fields}` for enums, and so on.
+//! Without the plugin, every method returns zeros and empty collections.
+
+use std::sync::Mutex;
+
+use bevy_app::{App, Last, Plugin};
+use bevy_diagnostic::{DiagnosticsStore, FrameCount};
+use bevy_ecs::prelude::{Resource, World};
+use bevy_reflect::{Reflect, TypePath};
+use bevy_time::{Real, Time};
+use godot::classes::{Engine, IObject, Object};
+use godot::prelude::{VarDictionary as Dictionary, *};
+
+use crate::plugins::core::{ScheduleTimings, SyncStats};
+use crate::plugins::debugger::{reflect_to_dict, sync_stats_dict};
+
+/// Name GDScript reaches the singleton under.
+const SINGLETON_NAME: &str = "BevyMetrics";
+
+/// What the last frame left for GDScript to read.
+static SNAPSHOT: Mutex<Option<Snapshot>> = Mutex::new(None);
+
+/// Plugin capturing metrics and exposed resources for `BevyMetrics` in `Last`.
+/// See the [module docs](self).
+#[derive(Default)]
+pub struct GodotMetricsPlugin;
+
+impl Plugin for GodotMetricsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ExposedResources>()
+            .init_resource::<ScheduleTimings>()
+            .add_systems(Last, snapshot_metrics);
+    }
+}
+
+/// Make a reflected resource readable from GDScript with
+/// `BevyMetrics.get_resource("ShortName")`.
+pub trait ExposeResourceAppExt {
+    fn expose_resource<R: Resource + Reflect + TypePath>(&mut self) -> &mut Self;
+}
+
+impl ExposeResourceAppExt for App {
+    fn expose_resource<R: Resource + Reflect + TypePath>(&mut self) -> &mut Self {
+        self.world_mut()
+            .get_resource_or_init::<ExposedResources>()
+            .0
+            .push((R::short_type_path(), |world| {
+                world
+                    .get_resource::<R>()
+                    .and_then(|resource| resource.reflect_clone().ok())
+            }));
+        self
+    }
+}
+
+/// Resources [`ExposeResourceAppExt::expose_resource`] opted in, by short name.
+#[derive(Resource, Default)]
+struct ExposedResources(Vec<(&'static str, fn(&World) -> Option<Box<dyn Reflect>>)>);
+
+#[derive(Default)]
+struct Snapshot {
+    frame: u32,
+    frame_ms: f64,
+    entity_count: u32,
+    sync: SyncStats,
+    /// Milliseconds per schedule.
+    schedules: Vec<(String, f64)>,
+    /// Smoothed value per diagnostic path.
+    diagnostics: Vec<(String, f64)>,
+    resources: Vec<(&'static str, Box<dyn Reflect>)>,
+}
+
+fn snapshot_metrics(world: &World) {
+    let snapshot = Snapshot {
+        frame: world
+            .get_resource::<FrameCount>()
+            .map_or(0, |frame| frame.0),
+        frame_ms: world
+            .get_resource::<Time<Real>>()
+            .map_or(0.0, |time| time.delta_secs_f64() * 1000.0),
+        entity_count: world.entities().count_spawned(),
+        sync: world
+            .get_resource::<SyncStats>()
+            .copied()
+            .unwrap_or_default(),
+        schedules: world
+            .get_resource::<ScheduleTimings>()
+            .map(|timings| {
+                timings
+                    .last_frame()
+                    .iter()
+                    .map(|(label, elapsed)| (format!("{label:?}"), elapsed.as_secs_f64() * 1000.0))
+                    .collect()
+            })
+            .unwrap_or_default(),
+        diagnostics: world
+            .get_resource::<DiagnosticsStore>()
+            .map(|store| {
+                store
+                    .iter()
+                    .filter(|diagnostic| diagnostic.is_enabled)
+                    .filter_map(|diagnostic| {
+                        Some((diagnostic.path().to_string(), diagnostic.smoothed()?))
+                    })
+                    .collect()
+            })
+            .unwrap_or_default(),
+        resources: world
+            .get_resource::<ExposedResources>()
+            .map(|exposed| {
+                exposed
+                    .0
+                    .iter()
+                    .filter_map(|(name, read)| Some((*name, read(world)?)))
+                    .collect()
+            })
+            .unwrap_or_default(),
+    };
+    *SNAPSHOT.lock().unwrap_or_else(|e| e.into_inner()) = Some(snapshot);
+}
+
+fn with_snapshot<T: Default>(read: impl FnOnce(&Snapshot) -> T) -> T {
+    SNAPSHOT
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .as_ref()
+        .map(read)
+        .unwrap_or_default()
+}
+
+/// Register the `BevyMetrics` singleton. `#[bevy_app]` calls this during
+/// `InitStage::Scene`; with a hand-written `ExtensionLibrary`, call it from
+/// `on_stage_init` yourself, and [`unregister_singleton`] from `on_stage_deinit`.
+pub fn register_singleton() {
+    let mut engine = Engine::singleton();
+    if !engine.has_singleton(SINGLETON_NAME) {
+        engine.register_singleton(SINGLETON_NAME, &BevyMetrics::new_alloc());
+    }
+}
+
+/// Unregister and free the `BevyMetrics` singleton.
+pub fn unregister_singleton() {
+    let mut engine = Engine::singleton();
+    if let Some(metrics) = engine.get_singleton(SINGLETON_NAME) {
+        engine.unregister_singleton(SINGLETON_NAME);
+        metrics.free();
+    }
+}
+
+/// Read-only Bevy metrics for GDScript. See the [module docs](self).
+#[derive(GodotClass)]
+#[class(base=Object)]
+pub struct BevyMetrics {
+    base: Base<Object>,
+}
+
+#[godot_api]
+impl IObject for BevyMetrics {
+    fn init(base: Base<Object>) -> Self {
+        Self { base }
+    }
+}
+
+#[godot_api]
+impl BevyMetrics {
+    /// Bevy's frame count at the end of the last frame.
+    #[func]
+    fn frame(&self) -> i64 {
+        with_snapshot(|snapshot| snapshot.frame.into())
+    }
+
+    /// Real time the last frame took, in milliseconds.
+    #[func]
+    fn frame_ms(&self) -> f64 {
+        with_snapshot(|snapshot| snapshot.frame_ms)
+    }
+
+    #[func]
+    fn entity_count(&self) -> i64 {
+        with_snapshot(|snapshot| snapshot.entity_count.into())
+    }
+
+    /// The last frame's `SyncStats` counters, by field name.
+    #[func]
+    fn sync_stats(&self) -> Dictionary {
+        with_snapshot(|snapshot| sync_stats_dict(&snapshot.sync))
+    }
+
+    /// Milliseconds spent per schedule in the last frame, by schedule name.
+    #[func]
+    fn schedule_timings(&self) -> Dictionary {
+        with_snapshot(|snapshot| {
+            let mut timings = Dictionary::new();
+            for (label, ms) in &snapshot.schedules {
+                timings.set(label.as_str(), *ms);
+            }
+            timings
+        })
+    }
+
+    /// Smoothed `bevy_diagnostic` measurements, by path.
+    #[func]
+    fn diagnostics(&self) -> Dictionary {
+        with_snapshot(|snapshot| {
+            let mut diagnostics = Dictionary::new();
+            for (path, value) in &snapshot.diagnostics {
+                diagnostics.set(path.as_str(), *value);
+            }
+            diagnostics
+        })
+    }
+
+    /// Short names of the resources `get_resource` can read.
+    #[func]
+    fn exposed_resources(&self) -> PackedStringArray {
+        with_snapshot(|snapshot| {
+            snapshot
+                .resources
+                .iter()
+                .map(|(name, _)| GString::from(*name))
+                .collect()
+        })
+    }
+
+    /// An exposed resource as a dictionary, or `null` if it isn't exposed or
+    /// doesn't exist.
+    #[func]
+    fn get_resource(&self, name: GString) -> Variant {
+        let name = name.to_string();
+        with_snapshot(|snapshot| {
+            snapshot
+                .resources
+                .iter()
+                .find(|(exposed, _)| *exposed == name)
+                .map(|(_, value)| reflect_to_dict(value.as_partial_reflect()).to_variant())
+        })
+        .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_ecs::system::RunSystemOnce;
+
+    #[derive(Resource, Reflect, Default, Clone, Debug, PartialEq)]
+    struct Score(u32);
+
+    #[derive(Resource, Reflect, Default)]
+    struct Hidden(u32);
+
+    #[test]
+    fn snapshots_hold_exposed_resources_only() {
+        let mut app = App::new();
+        app.expose_resource::<Score>()
+            .insert_resource(Score(42))
+            .insert_resource(Hidden(7));
+        app.world_mut().run_system_once(snapshot_metrics).unwrap();
+
+        let resources = with_snapshot(|snapshot| {
+            snapshot
+                .resources
+                .iter()
+                .map(|(name, value)| (*name, value.downcast_ref::<Score>().cloned()))
+                .collect::<Vec<_>>()
+        });
+        assert_eq!(resources, vec![("Score", Some(Score(42)))]);
+    }
+}
@@ -2,34 +2,130 @@ use bevy_app::plugin_group;
 #[cfg(feature = "bevy_gamepad")]
 use bevy_gilrs::GilrsPlugin;
 
+pub mod app_signals;
+pub mod area_physics;
 pub mod assets;
 pub mod audio;
+pub mod between_frames;
+pub mod camera;
+pub mod chunk_streaming;
 pub mod collisions;
+pub mod console;
 pub mod core;
 pub mod debugger;
+pub mod deferred_calls;
+pub mod determinism;
 pub mod event_bridge;
 pub mod fixed_schedule;
+pub mod frame_budget;
 #[cfg(feature = "godot_bevy_log")]
 pub mod godot_bevy_logger;
+pub mod gridmap;
+pub mod http;
 pub mod input;
+pub mod layers;
+pub mod meta_sync;
+pub mod metrics;
+pub mod multimesh;
+#[cfg(feature = "multiplayer")]
+pub mod multiplayer;
+#[cfg(feature = "navigation")]
+pub mod navigation;
+#[cfg(feature = "editor_badges")]
+pub mod node_badges;
+pub mod node_pool;
 pub mod packed_scene;
+pub mod particles;
+pub mod physics_material;
+pub mod platformer;
+pub mod presence;
+#[cfg(not(feature = "api-4-2"))]
+pub mod ragdoll;
+#[cfg(feature = "persistence")]
+pub mod region_persistence;
+pub mod remote_inspector;
+pub mod resource_bridge;
+pub mod rich_text;
+pub mod scene_manager;
+pub mod scene_preview;
 pub mod scene_tree;
+pub mod script_calls;
 pub mod signals;
+pub mod soft_body;
+pub mod split_screen;
+pub mod theme;
+#[cfg(not(feature = "api-4-2"))]
+pub mod tilemap;
+pub mod timer;
 pub mod transforms;
+pub mod tween;
+pub mod ui_binding;
+pub mod ui_events;
+pub mod ui_layers;
+pub mod user_files;
+pub mod vehicle;
+pub mod window;
 
 // Re-export all plugins for convenience
+pub use app_signals::{AddStateSignalAppExt, ExposeEventAppExt};
+pub use area_physics::GodotAreaPhysicsPlugin;
 pub use assets::GodotAssetsPlugin;
 pub use audio::GodotAudioPlugin;
+pub use between_frames::BetweenFramesPlugin;
+pub use camera::GodotCameraPlugin;
+pub use chunk_streaming::ChunkStreamingPlugin;
 pub use collisions::GodotCollisionsPlugin;
+pub use console::GodotConsolePlugin;
 pub use core::GodotBaseCorePlugin;
 pub use debugger::{DebuggerConfig, GodotDebuggerPlugin};
+pub use deferred_calls::{DeferredCallSet, DeferredFlush, GodotDeferredCalls};
+pub use determinism::{DeterminismAuditPlugin, DeterminismPlugin};
 pub use event_bridge::{AddGodotEventAppExt, EventBridgeSet, GodotEventSender, send_event};
+pub use frame_budget::FrameBudgetPlugin;
 #[cfg(feature = "godot_bevy_log")]
-pub use godot_bevy_logger::GodotBevyLogPlugin;
-pub use input::{BevyInputBridgePlugin, GodotInputEventPlugin};
+pub use godot_bevy_logger::{GodotBevyLogPlugin, GodotLogBuffer, GodotLogFilters};
+pub use gridmap::GodotGridMapPlugin;
+pub use http::GodotHttpPlugin;
+pub use input::{BevyInputBridgePlugin, GodotInputEventPlugin, GodotInputReactPlugin};
+pub use layers::GodotLayerNamesPlugin;
+pub use meta_sync::GodotMetaSyncPlugin;
+pub use metrics::GodotMetricsPlugin;
+pub use multimesh::GodotMultiMeshPlugin;
+#[cfg(feature = "multiplayer")]
+pub use multiplayer::GodotMultiplayerPlugin;
+#[cfg(feature = "navigation")]
+pub use navigation::GodotNavigationPlugin;
+#[cfg(feature = "editor_badges")]
+pub use node_badges::GodotNodeBadgesPlugin;
 pub use packed_scene::GodotPackedScenePlugin;
-pub use scene_tree::GodotSceneTreePlugin;
+pub use particles::GodotParticlesPlugin;
+pub use physics_material::GodotPhysicsMaterialPlugin;
+pub use platformer::Platformer2DControllerPlugin;
+pub use presence::GodotPresencePlugin;
+#[cfg(not(feature = "api-4-2"))]
+pub use ragdoll::GodotRagdollPlugin;
+#[cfg(feature = "persistence")]
+pub use region_persistence::RegionPersistencePlugin;
+pub use remote_inspector::RemoteInspectorPlugin;
+pub use resource_bridge::GodotResourceBridgePlugin;
+pub use rich_text::GodotRichTextPlugin;
+pub use scene_manager::SceneManagerPlugin;
+pub use scene_preview::ScenePreviewPlugin;
+pub use scene_tree::{GodotSceneTreePlugin, GodotTreeOrderPlugin};
+pub use script_calls::GodotCall;
+pub use soft_body::GodotSoftBodyPlugin;
+pub use split_screen::SplitScreenPlugin;
+pub use theme::GodotThemePlugin;
+#[cfg(not(feature = "api-4-2"))]
+pub use tilemap::GodotTileMapPlugin;
+pub use timer::GodotTimerPlugin;
 pub use transforms::GodotTransformSyncPlugin;
+pub use tween::GodotTweenPlugin;
+pub use ui_events::GodotUiEventsPlugin;
+pub use ui_layers::GodotUiLayersPlugin;
+pub use user_files::UserFileWatcherPlugin;
+pub use vehicle::GodotVehiclePlugin;
+pub use window::GodotWindowPlugin;
 
 // Re-export for backwards compatibility
 #[deprecated(note = "Use GodotInputEventPlugin instead")]
@@ -49,6 +145,7 @@ plugin_group! {
     pub struct GodotDefaultPlugins {
         :GodotAssetsPlugin,
         :GodotCollisionsPlugin,
+        :GodotLayerNamesPlugin,
         :BevyInputBridgePlugin,
         :GodotAudioPlugin,
         :GodotPackedScenePlugin,
@@ -0,0 +1,454 @@
+//! Render large node-less populations through one `MultiMeshInstance2D`/`3D`.
+//!
+//! A node per boid or bullet costs a scene tree entry, a transform write and a draw
+//! call each. With [`GodotMultiMeshPlugin`], an entity with a [`MultiMeshBatch`]
+//! owns one `MultiMeshInstance` node, and every entity with [`InMultiMesh`]
+//! pointing at it becomes one instance of its mesh. Each frame the members'
+//! `Transform`s are packed into one buffer and uploaded with a single
+//! `MultiMesh.set_buffer` call.
+//!
+//! ```ignore
+//! fn setup(mut commands: Commands, assets: Res<AssetServer>) {
+//!     let flock = commands
+//!         .spawn(MultiMeshBatch::new_2d().with_mesh(assets.load("res://boid_mesh.tres")))
+//!         .id();
+//!     for _ in 0..10_000 {
+//!         commands.spawn((Boid::random(), Transform::default(), InMultiMesh(flock)));
+//!     }
+//! }
+//! ```
+//!
+//! Member transforms are relative to the batch node. Putting a [`MultiMeshBatch`]
+//! on the entity of a `MultiMeshInstance` placed in the editor uses that node, and
+//! its mesh and material, instead of creating one.
+
+use std::collections::HashMap;
+
+use bevy_app::{App, Last, Plugin};
+use bevy_asset::{Assets, Handle};
+use bevy_ecs::change_detection::DetectChanges;
+use bevy_ecs::component::Component;
+use bevy_ecs::entity::Entity;
+use bevy_ecs::lifecycle::{Discard, Insert};
+use bevy_ecs::observer::On;
+use bevy_ecs::query::{Added, Without};
+use bevy_ecs::schedule::IntoScheduleConfigs;
+use bevy_ecs::system::{Commands, Query, ResMut};
+use bevy_ecs::world::Ref;
+use bevy_transform::components::Transform;
+use godot::builtin::{Color, PackedFloat32Array};
+use godot::classes::{Mesh, MultiMesh, MultiMeshInstance2D, MultiMeshInstance3D, Node, multi_mesh};
+use godot::obj::{Gd, NewAlloc, NewGd};
+use tracing::warn;
+
+use crate::interop::{GodotAccess, GodotNodeHandle};
+use crate::node_tree_view::find_node;
+use crate::plugins::assets::GodotResource;
+use crate::plugins::scene_tree::SceneTreeRef;
+
+/// Plugin drawing [`MultiMeshBatch`]es. See the [module docs](self).
+#[derive(Default)]
+pub struct GodotMultiMeshPlugin;
+
+impl Plugin for GodotMultiMeshPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_observer(on_member_inserted)
+            .add_observer(on_member_discarded)
+            .add_systems(
+                Last,
+                (spawn_multimesh_nodes, upload_multimesh_buffers).chain(),
+            );
+    }
+}
+
+#[cfg(debug_assertions)]
+crate::inventory::submit! {
+    crate::watchdog::PluginRequirement::new::<MultiMeshBatch, GodotMultiMeshPlugin>()
+}
+
+#[cfg(debug_assertions)]
+crate::inventory::submit! {
+    crate::watchdog::PluginRequirement::new::<InMultiMesh, GodotMultiMeshPlugin>()
+}
+
+/// Whether a batch draws in 2D or 3D.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MultiMeshKind {
+    TwoD,
+    ThreeD,
+}
+
+impl MultiMeshKind {
+    fn transform_floats(self) -> usize {
+        match self {
+            MultiMeshKind::TwoD => 8,
+            MultiMeshKind::ThreeD => 12,
+        }
+    }
+}
+
+/// One `MultiMeshInstance` drawing every entity with an [`InMultiMesh`] pointing
+/// here. See the [module docs](self).
+#[derive(Component, Debug, Clone)]
+pub struct MultiMeshBatch {
+    pub kind: MultiMeshKind,
+    /// The mesh each member draws. `None` keeps the node's own mesh.
+    pub mesh: Option<Handle<GodotResource>>,
+    /// Path of the parent for a created node, wildcards allowed. `None` uses the
+    /// current scene.
+    pub parent: Option<String>,
+    /// Upload each member's [`MultiMeshColor`] (white if it has none).
+    pub use_colors: bool,
+    members: Vec<Entity>,
+    slots: HashMap<Entity, usize>,
+    dirty: bool,
+    mesh_applied: bool,
+}
+
+impl MultiMeshBatch {
+    pub fn new(kind: MultiMeshKind) -> Self {
+        Self {
+            kind,
+            mesh: None,
+            parent: None,
+            use_colors: false,
+            members: Vec::new(),
+            slots: HashMap::new(),
+            dirty: true,
+            mesh_applied: false,
+        }
+    }
+
+    pub fn new_2d() -> Self {
+        Self::new(MultiMeshKind::TwoD)
+    }
+
+    pub fn new_3d() -> Self {
+        Self::new(MultiMeshKind::ThreeD)
+    }
+
+    /// Waits until the asset has loaded before drawing.
+    pub fn with_mesh(mut self, mesh: Handle<GodotResource>) -> Self {
+        self.mesh = Some(mesh);
+        self
+    }
+
+    pub fn under(mut self, path: impl Into<String>) -> Self {
+        self.parent = Some(path.into());
+        self
+    }
+
+    pub fn with_colors(mut self) -> Self {
+        self.use_colors = true;
+        self
+    }
+
+    /// Members in instance order.
+    pub fn members(&self) -> &[Entity] {
+        &self.members
+    }
+
+    /// The instance index `entity` draws at. Removing a member moves the last
+    /// member into its slot.
+    pub fn slot(&self, entity: Entity) -> Option<usize> {
+        self.slots.get(&entity).copied()
+    }
+
+    pub fn len(&self) -> usize {
+        self.members.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.members.is_empty()
+    }
+
+    fn add(&mut self, entity: Entity) {
+        if !self.slots.contains_key(&entity) {
+            self.slots.insert(entity, self.members.len());
+            self.members.push(entity);
+            self.dirty = true;
+        }
+    }
+
+    fn remove(&mut self, entity: Entity) {
+        let Some(slot) = self.slots.remove(&entity) else {
+            return;
+        };
+        self.members.swap_remove(slot);
+        if let Some(&moved) = self.members.get(slot) {
+            self.slots.insert(moved, slot);
+        }
+        self.dirty = true;
+    }
+}
+
+/// Draw this entity as an instance of the [`MultiMeshBatch`] on the given entity.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InMultiMesh(pub Entity);
+
+/// Per-instance color for batches created [`with_colors`](MultiMeshBatch::with_colors).
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct MultiMeshColor(pub Color);
+
+fn on_member_inserted(
+    inserted: On<Insert, InMultiMesh>,
+    members: Query<&InMultiMesh>,
+    mut batches: Query<&mut MultiMeshBatch>,
+) {
+    let Ok(&InMultiMesh(batch)) = members.get(inserted.entity) else {
+        return;
+    };
+    match batches.get_mut(batch) {
+        Ok(mut batch) => batch.add(inserted.entity),
+        Err(_) => warn!(
+            "InMultiMesh({batch}) on {}: {batch} has no MultiMeshBatch",
+            inserted.entity
+        ),
+    }
+}
+
+/// Runs before a new value overwrites the old one, and on removal and despawn.
+fn on_member_discarded(
+    discarded: On<Discard, InMultiMesh>,
+    members: Query<&InMultiMesh>,
+    mut batches: Query<&mut MultiMeshBatch>,
+) {
+    if let Ok(&InMultiMesh(batch)) = members.get(discarded.entity)
+        && let Ok(mut batch) = batches.get_mut(batch)
+    {
+        batch.remove(discarded.entity);
+    }
+}
+
+fn spawn_multimesh_nodes(
+    added: Query<(Entity, &MultiMeshBatch), (Added<MultiMeshBatch>, Without<GodotNodeHandle>)>,
+    mut commands: Commands,
+    mut scene_tree: SceneTreeRef,
+    _godot: GodotAccess,
+) {
+    for (entity, batch) in &added {
+        let tree = scene_tree.get();
+        let parent = match &batch.parent {
+            Some(path) => tree
+                .get_root()
+                .and_then(|root| find_node(&root.upcast(), path)),
+            None => tree.get_current_scene(),
+        };
+        let Some(mut parent) = parent else {
+            warn!(
+                "MultiMeshBatch: no parent node for {entity} ({:?})",
+                batch.parent
+            );
+            continue;
+        };
+
+        let mut node: Gd<Node> = match batch.kind {
+            MultiMeshKind::TwoD => {
+                let mut node = MultiMeshInstance2D::new_alloc();
+                node.set_multimesh(&MultiMesh::new_gd());
+                node.upcast()
+            }
+            MultiMeshKind::ThreeD => {
+                let mut node = MultiMeshInstance3D::new_alloc();
+                node.set_multimesh(&MultiMesh::new_gd());
+                node.upcast()
+            }
+        };
+        node.set_name(&format!("MultiMeshBatch{entity}"));
+        parent.add_child(&node);
+
+        commands.entity(entity).insert(GodotNodeHandle::new(node));
+    }
+}
+
+fn upload_multimesh_buffers(
+    mut batches: Query<(Entity, &mut MultiMeshBatch, &GodotNodeHandle)>,
+    transforms: Query<Ref<Transform>>,
+    colors: Query<Ref<MultiMeshColor>>,
+    mut assets: Option<ResMut<Assets<GodotResource>>>,
+    mut godot: GodotAccess,
+) {
+    for (entity, mut batch, handle) in &mut batches {
+        let Some(mut multimesh) = batch_multimesh(&mut godot, *handle, batch.kind) else {
+            warn!("MultiMeshBatch on {entity}, whose node isn't a matching MultiMeshInstance");
+            continue;
+        };
+
+        if !batch.mesh_applied {
+            let mesh = match &batch.mesh {
+                Some(mesh) => assets
+                    .as_mut()
+                    .and_then(|assets| assets.get_mut(mesh))
+                    .and_then(|mut resource| resource.try_cast::<Mesh>()),
+                None => multimesh.get_mesh(),
+            };
+            // Nothing to draw until the mesh has loaded.
+            let Some(mesh) = mesh else {
+                continue;
+            };
+            multimesh.set_mesh(&mesh);
+            configure(&mut multimesh, batch.kind, batch.use_colors);
+            batch.mesh_applied = true;
+            batch.dirty = true;
+        }
+
+        let changed = batch.dirty
+            || batch.members.iter().any(|member| {
+                transforms.get(*member).is_ok_and(|t| t.is_changed())
+                    || colors.get(*member).is_ok_and(|c| c.is_changed())
+            });
+        if !changed {
+            continue;
+        }
+
+        let count = batch.members.len();
+        if (multimesh.get_instance_count() as usize) < count {
+            // Resizing drops the buffer, which is rewritten in full below anyway.
+            multimesh.set_instance_count(count.next_power_of_two() as i32);
+        }
+        let capacity = multimesh.get_instance_count() as usize;
+        let use_colors = multimesh.is_using_colors();
+        let use_custom_data = multimesh.is_using_custom_data();
+
+        let mut buffer =
+            Vec::with_capacity(capacity * stride(batch.kind, use_colors, use_custom_data));
+        for member in &batch.members {
+            let transform = transforms.get(*member).map(|t| *t).unwrap_or_default();
+            write_transform(&mut buffer, batch.kind, &transform);
+            if use_colors {
+                let MultiMeshColor(color) = colors
+                    .get(*member)
+                    .map(|c| *c)
+                    .unwrap_or(MultiMeshColor(Color::WHITE));
+                buffer.extend_from_slice(&[color.r, color.g, color.b, color.a]);
+            }
+            if use_custom_data {
+                buffer.extend_from_slice(&[0.0; 4]);
+            }
+        }
+        buffer.resize(
+            capacity * stride(batch.kind, use_colors, use_custom_data),
+            0.0,
+        );
+
+        multimesh.set_buffer(&PackedFloat32Array::from(buffer.as_slice()));
+        multimesh.set_visible_instance_count(count as i32);
+        batch.dirty = false;
+    }
+}
+
+fn batch_multimesh(
+    godot: &mut GodotAccess,
+    handle: GodotNodeHandle,
+    kind: MultiMeshKind,
+) -> Option<Gd<MultiMesh>> {
+    match kind {
+        MultiMeshKind::TwoD => {
+            let mut node = godot.try_get::<MultiMeshInstance2D>(handle)?;
+            Some(node.get_multimesh().unwrap_or_else(|| {
+                let multimesh = MultiMesh::new_gd();
+                node.set_multimesh(&multimesh);
+                multimesh
+            }))
+        }
+        MultiMeshKind::ThreeD => {
+            let mut node = godot.try_get::<MultiMeshInstance3D>(handle)?;
+            Some(node.get_multimesh().unwrap_or_else(|| {
+                let multimesh = MultiMesh::new_gd();
+                node.set_multimesh(&multimesh);
+                multimesh
+            }))
+        }
+    }
+}
+
+/// The transform format and color flag can only change with no instances.
+fn configure(multimesh: &mut Gd<MultiMesh>, kind: MultiMeshKind, use_colors: bool) {
+    let format = match kind {
+        MultiMeshKind::TwoD => multi_mesh::TransformFormat::TRANSFORM_2D,
+        MultiMeshKind::ThreeD => multi_mesh::TransformFormat::TRANSFORM_3D,
+    };
+    let use_colors = use_colors || multimesh.is_using_colors();
+    multimesh.set_instance_count(0);
+    multimesh.set_transform_format(format);
+    multimesh.set_use_colors(use_colors);
+}
+
+fn stride(kind: MultiMeshKind, use_colors: bool, use_custom_data: bool) -> usize {
+    kind.transform_floats() + 4 * use_colors as usize + 4 * use_custom_data as usize
+}
+
+/// Append `transform` in `MultiMesh.buffer` layout: the basis rows, each followed
+/// by that row's origin component. 2D keeps the x and y rows, with a zero in
+/// place of the basis z column.
+fn write_transform(buffer: &mut Vec<f32>, kind: MultiMeshKind, transform: &Transform) {
+    let affine = transform.compute_affine();
+    let (x, y, z, origin) = (
+        affine.matrix3.x_axis,
+        affine.matrix3.y_axis,
+        affine.matrix3.z_axis,
+        affine.translation,
+    );
+    match kind {
+        MultiMeshKind::TwoD => buffer.extend_from_slice(&[
+            x.x, y.x, 0.0, origin.x, //
+            x.y, y.y, 0.0, origin.y,
+        ]),
+        MultiMeshKind::ThreeD => buffer.extend_from_slice(&[
+            x.x, y.x, z.x, origin.x, //
+            x.y, y.y, z.y, origin.y, //
+            x.z, y.z, z.z, origin.z,
+        ]),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_ecs::world::World;
+    use bevy_math::{Quat, Vec3};
+
+    #[test]
+    fn members_keep_dense_slots() {
+        let mut world = World::new();
+        world.add_observer(on_member_inserted);
+        world.add_observer(on_member_discarded);
+        let batch = world.spawn(MultiMeshBatch::new_2d()).id();
+        let other = world.spawn(MultiMeshBatch::new_2d()).id();
+        let a = world.spawn(InMultiMesh(batch)).id();
+        let b = world.spawn(InMultiMesh(batch)).id();
+        let c = world.spawn(InMultiMesh(batch)).id();
+
+        world.despawn(a);
+        let slots = world.get::<MultiMeshBatch>(batch).unwrap();
+        assert_eq!(slots.members(), &[c, b]);
+        assert_eq!(slots.slot(c), Some(0));
+        assert_eq!(slots.slot(a), None);
+
+        world.entity_mut(b).insert(InMultiMesh(other));
+        assert_eq!(world.get::<MultiMeshBatch>(batch).unwrap().members(), &[c]);
+        assert_eq!(world.get::<MultiMeshBatch>(other).unwrap().members(), &[b]);
+    }
+
+    #[test]
+    fn transforms_use_the_buffer_layout() {
+        let transform = Transform::from_xyz(10.0, 20.0, 30.0).with_scale(Vec3::splat(2.0));
+        let mut buffer = Vec::new();
+        write_transform(&mut buffer, MultiMeshKind::ThreeD, &transform);
+        assert_eq!(
+            buffer,
+            [
+                2.0, 0.0, 0.0, 10.0, 0.0, 2.0, 0.0, 20.0, 0.0, 0.0, 2.0, 30.0
+            ]
+        );
+
+        let transform = Transform::from_xyz(5.0, 6.0, 0.0)
+            .with_rotation(Quat::from_rotation_z(std::f32::consts::FRAC_PI_2));
+        let mut buffer = Vec::new();
+        write_transform(&mut buffer, MultiMeshKind::TwoD, &transform);
+        let expected = [0.0, -1.0, 0.0, 5.0, 1.0, 0.0, 0.0, 6.0];
+        for (actual, expected) in buffer.iter().zip(expected) {
+            assert!((actual - expected).abs() < 1e-6, "{buffer:?}");
+        }
+    }
+}
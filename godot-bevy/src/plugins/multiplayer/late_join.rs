@@ -0,0 +1,498 @@
+//! Catching up peers that join a session already in progress.
+//!
+//! When a client connects, the server sends it a snapshot of every [`LateJoin`]
+//! entity: its node path, the scene its node was instantiated from, and the
+//! value of each component registered with
+//! [`AddLateJoinAppExt::add_late_join_component`]. The client instantiates the
+//! scenes it's missing at the same paths, parents before children, and inserts
+//! the components once the scene tree mirror has created the entities. Nodes it
+//! already has (placed in the level, or replicated by a `MultiplayerSpawner`)
+//! only get their components.
+//!
+//! ```ignore
+//! #[derive(Component, Clone, Serialize, Deserialize)]
+//! struct Health(u32);
+//!
+//! app.add_late_join_component::<Health>("health")
+//!     .add_observer(|progress: On<LateJoinProgress>| {
+//!         info!("loading world: {}/{}", progress.applied, progress.total);
+//!     })
+//!     .add_observer(|_: On<LateJoinCompleted>| info!("caught up"));
+//!
+//! fn spawn_enemy(mut commands: Commands) {
+//!     commands.spawn((GodotScene::from_path("res://enemy.tscn"), Health(3), LateJoin));
+//! }
+//! ```
+//!
+//! Entities are matched across peers by node path, as with Godot's own RPCs, so
+//! instantiated nodes need the same name on every peer. Every peer must register
+//! the same components under the same names; the name, not the Rust type name,
+//! labels a component in the snapshot, so peers from different builds agree. The snapshot is taken once, when the peer connects:
+//! changes after that travel however the game replicates them.
+
+use bevy_app::{App, First, PostUpdate};
+use bevy_ecs::component::Component;
+use bevy_ecs::entity::Entity;
+use bevy_ecs::event::Event;
+use bevy_ecs::message::MessageReader;
+use bevy_ecs::observer::On;
+use bevy_ecs::prelude::Resource;
+use bevy_ecs::query::With;
+use bevy_ecs::schedule::IntoScheduleConfigs;
+use bevy_ecs::system::{Res, ResMut};
+use bevy_ecs::world::World;
+use godot::classes::{Node, PackedScene, ResourceLoader};
+use godot::obj::Gd;
+use serde::{Deserialize, Serialize};
+use std::any::TypeId;
+use std::collections::HashMap;
+use tracing::{error, warn};
+
+use super::rpc::{AddRpcAppExt, RpcMessage, RpcReceived, SendRpc, receive_rpcs};
+use super::{NetworkState, PeerConnected, PeerId};
+use crate::interop::{GodotAccess, GodotNodeHandle};
+use crate::plugins::core::GodotSyncSet;
+use crate::plugins::scene_tree::{NodeEntityIndex, SceneTreeRef};
+
+/// Entities per snapshot RPC, so a large world doesn't go out as one packet.
+const SNAPSHOT_CHUNK: usize = 64;
+
+/// Sends this entity to peers that join later. The entity must have a node.
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LateJoin;
+
+/// Triggered on the server once a joining peer's snapshot is sent.
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LateJoinSnapshotSent {
+    pub peer: PeerId,
+    pub entities: u32,
+}
+
+/// Triggered on a joining client each frame its snapshot advances: `applied`
+/// of `total` entities have their node and components.
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LateJoinProgress {
+    pub applied: u32,
+    pub total: u32,
+}
+
+/// Triggered on a joining client once every entity of its snapshot is applied.
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LateJoinCompleted {
+    pub entities: u32,
+}
+
+pub trait AddLateJoinAppExt {
+    /// Include `C` in the snapshot sent to joining peers under `name`, and insert
+    /// it on the matching entity when one arrives. Also sets up the snapshot
+    /// exchange. Every peer must register `C` under the same name.
+    ///
+    /// # Panics
+    /// If `name` is already registered for a different component.
+    fn add_late_join_component<C: Component + RpcMessage>(
+        &mut self,
+        name: &'static str,
+    ) -> &mut Self;
+}
+
+impl AddLateJoinAppExt for App {
+    fn add_late_join_component<C: Component + RpcMessage>(
+        &mut self,
+        name: &'static str,
+    ) -> &mut Self {
+        ensure_late_join(self);
+        let mut registry = self.world_mut().resource_mut::<LateJoinRegistry>();
+        if let Some((type_id, _)) = registry.decoders.get(name) {
+            assert!(
+                *type_id == TypeId::of::<C>(),
+                "late-join component name {name:?} is already registered for another type"
+            );
+            return self;
+        }
+        registry.encoders.push((name, encode::<C>));
+        registry
+            .decoders
+            .insert(name, (TypeId::of::<C>(), decode::<C>));
+        self
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct SnapshotChunk {
+    /// Entities in the whole snapshot.
+    total: u32,
+    entities: Vec<SnapshotEntity>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+struct SnapshotEntity {
+    path: String,
+    /// `res://` path of the scene the node was instantiated from, if any.
+    scene: String,
+    /// Serialized components, by registered name.
+    components: Vec<(String, String)>,
+}
+
+type Encoder = fn(&World, Entity) -> Option<Result<String, serde_json::Error>>;
+type Decoder = fn(&mut World, Entity, &str) -> Result<(), serde_json::Error>;
+
+fn encode<C: Component + RpcMessage>(
+    world: &World,
+    entity: Entity,
+) -> Option<Result<String, serde_json::Error>> {
+    world.get::<C>(entity).map(serde_json::to_string)
+}
+
+fn decode<C: Component + RpcMessage>(
+    world: &mut World,
+    entity: Entity,
+    payload: &str,
+) -> Result<(), serde_json::Error> {
+    let value = serde_json::from_str::<C>(payload)?;
+    world.entity_mut(entity).insert(value);
+    Ok(())
+}
+
+/// Component codecs, in registration order.
+#[derive(Resource, Default)]
+struct LateJoinRegistry {
+    encoders: Vec<(&'static str, Encoder)>,
+    decoders: HashMap<&'static str, (TypeId, Decoder)>,
+}
+
+/// Server: peers waiting for their snapshot.
+#[derive(Resource, Default)]
+struct PendingSnapshots(Vec<PeerId>);
+
+/// Client: the snapshot being applied.
+#[derive(Resource, Default)]
+struct IncomingSnapshot {
+    total: Option<u32>,
+    /// Entities whose node exists, waiting for the mirror to create them.
+    waiting: Vec<SnapshotEntity>,
+    /// Entities the mirror created, waiting for their components.
+    ready: Vec<(Entity, SnapshotEntity)>,
+    applied: u32,
+}
+
+fn ensure_late_join(app: &mut App) {
+    if app.world().contains_resource::<LateJoinRegistry>() {
+        return;
+    }
+    app.init_resource::<LateJoinRegistry>()
+        .init_resource::<PendingSnapshots>()
+        .init_resource::<IncomingSnapshot>()
+        .add_rpc::<SnapshotChunk>("godot_bevy::late_join_snapshot")
+        .add_observer(queue_snapshot)
+        .add_systems(
+            First,
+            (
+                (resolve_snapshot_entities, apply_snapshot_components)
+                    .chain()
+                    .after(GodotSyncSet::SceneTreeRead),
+                receive_snapshot_chunks.after(receive_rpcs),
+            )
+                .chain(),
+        )
+        .add_systems(PostUpdate, send_snapshots);
+}
+
+fn queue_snapshot(
+    connected: On<PeerConnected>,
+    state: Res<NetworkState>,
+    mut pending: ResMut<PendingSnapshots>,
+) {
+    if state.is_server() {
+        pending.0.push(connected.peer);
+    }
+}
+
+/// Snapshot the [`LateJoin`] entities for every peer that connected this frame.
+fn send_snapshots(world: &mut World) {
+    let peers = std::mem::take(&mut world.resource_mut::<PendingSnapshots>().0);
+    if peers.is_empty() {
+        return;
+    }
+    let entities = take_snapshot(world);
+    let total = entities.len() as u32;
+    for peer in peers {
+        // An empty snapshot still goes out, so the client knows it's done.
+        let mut chunks = entities.chunks(SNAPSHOT_CHUNK).peekable();
+        if chunks.peek().is_none() {
+            world.write_message(SendRpc::to_peer(
+                peer,
+                SnapshotChunk {
+                    total,
+                    entities: Vec::new(),
+                },
+            ));
+        }
+        for chunk in chunks {
+            world.write_message(SendRpc::to_peer(
+                peer,
+                SnapshotChunk {
+                    total,
+                    entities: chunk.to_vec(),
+                },
+            ));
+        }
+        world.trigger(LateJoinSnapshotSent {
+            peer,
+            entities: total,
+        });
+    }
+}
+
+fn take_snapshot(world: &mut World) -> Vec<SnapshotEntity> {
+    let handles: Vec<(Entity, GodotNodeHandle)> = world
+        .query_filtered::<(Entity, &GodotNodeHandle), With<LateJoin>>()
+        .iter(world)
+        .map(|(entity, &handle)| (entity, handle))
+        .collect();
+    let registry = world.resource::<LateJoinRegistry>();
+    let mut entities = Vec::with_capacity(handles.len());
+    for (entity, handle) in handles {
+        let Ok(node) = Gd::<Node>::try_from_instance_id(handle.instance_id()) else {
+            continue;
+        };
+        let mut components = Vec::new();
+        for &(kind, encode) in &registry.encoders {
+            match encode(world, entity) {
+                Some(Ok(payload)) => components.push((kind.to_string(), payload)),
+                Some(Err(err)) => error!("LateJoin: {kind} on {entity} failed to serialize: {err}"),
+                None => {}
+            }
+        }
+        entities.push(SnapshotEntity {
+            path: node.get_path().to_string(),
+            scene: node.get_scene_file_path().to_string(),
+            components,
+        });
+    }
+    in_dependency_order(&mut entities);
+    entities
+}
+
+/// Parents before their children, so every node has its parent when it's
+/// instantiated.
+fn in_dependency_order(entities: &mut [SnapshotEntity]) {
+    entities.sort_by(|a, b| {
+        let depth = |entity: &SnapshotEntity| entity.path.matches('/').count();
+        depth(a).cmp(&depth(b)).then_with(|| a.path.cmp(&b.path))
+    });
+}
+
+/// Instantiate the scenes the client is missing, and queue every entity for
+/// its components.
+fn receive_snapshot_chunks(
+    mut chunks: MessageReader<RpcReceived<SnapshotChunk>>,
+    mut incoming: ResMut<IncomingSnapshot>,
+    mut scene_tree: SceneTreeRef,
+    mut godot: GodotAccess,
+) {
+    let mut scenes: HashMap<String, Gd<PackedScene>> = HashMap::new();
+    for chunk in chunks.read() {
+        // Only the server speaks for the world.
+        if chunk.from != 1 {
+            continue;
+        }
+        incoming.total = Some(chunk.message.total);
+        let Some(root) = scene_tree
+            .get()
+            .get_root()
+            .map(|root| root.upcast::<Node>())
+        else {
+            continue;
+        };
+        for entity in &chunk.message.entities {
+            if root.get_node_or_null(entity.path.as_str()).is_some() {
+                incoming.waiting.push(entity.clone());
+                continue;
+            }
+            if instantiate(&mut godot, &root, entity, &mut scenes) {
+                incoming.waiting.push(entity.clone());
+            } else {
+                incoming.applied += 1;
+            }
+        }
+    }
+}
+
+fn instantiate(
+    godot: &mut GodotAccess,
+    root: &Gd<Node>,
+    entity: &SnapshotEntity,
+    scenes: &mut HashMap<String, Gd<PackedScene>>,
+) -> bool {
+    let Some((parent_path, name)) = entity.path.rsplit_once('/') else {
+        return false;
+    };
+    let Some(mut parent) = root.get_node_or_null(parent_path) else {
+        warn!(
+            "LateJoin: no parent {parent_path} for {}; skipping it",
+            entity.path
+        );
+        return false;
+    };
+    if entity.scene.is_empty() {
+        warn!(
+            "LateJoin: {} isn't on this peer and wasn't instantiated from a scene; skipping it",
+            entity.path
+        );
+        return false;
+    }
+    let scene = match scenes.get(&entity.scene) {
+        Some(scene) => scene.clone(),
+        None => {
+            let Some(scene) = godot
+                .singleton::<ResourceLoader>()
+                .load(entity.scene.as_str())
+                .and_then(|resource| resource.try_cast::<PackedScene>().ok())
+            else {
+                error!(
+                    "LateJoin: {} for {} isn't a PackedScene",
+                    entity.scene, entity.path
+                );
+                return false;
+            };
+            scenes.insert(entity.scene.clone(), scene.clone());
+            scene
+        }
+    };
+    let Some(mut node) = scene.instantiate() else {
+        error!("LateJoin: failed to instantiate {}", entity.scene);
+        return false;
+    };
+    node.set_name(name);
+    parent.add_child(&node);
+    true
+}
+
+/// Move the snapshot entities the mirror has created to `ready`.
+fn resolve_snapshot_entities(
+    mut incoming: ResMut<IncomingSnapshot>,
+    index: Res<NodeEntityIndex>,
+    mut scene_tree: SceneTreeRef,
+) {
+    if incoming.waiting.is_empty() {
+        return;
+    }
+    let Some(root) = scene_tree.get().get_root() else {
+        return;
+    };
+    let incoming = &mut *incoming;
+    for entity in std::mem::take(&mut incoming.waiting) {
+        let created = root
+            .get_node_or_null(entity.path.as_str())
+            .and_then(|node| index.get(node.instance_id()));
+        match created {
+            Some(target) => incoming.ready.push((target, entity)),
+            None => incoming.waiting.push(entity),
+        }
+    }
+}
+
+/// Insert the components of the `ready` entities, and report progress.
+fn apply_snapshot_components(world: &mut World) {
+    let (total, before, ready) = {
+        let mut incoming = world.resource_mut::<IncomingSnapshot>();
+        let Some(total) = incoming.total else {
+            return;
+        };
+        (total, incoming.applied, std::mem::take(&mut incoming.ready))
+    };
+
+    for (target, entity) in &ready {
+        // Despawned since it was resolved.
+        if world.get_entity(*target).is_err() {
+            continue;
+        }
+        for (kind, payload) in &entity.components {
+            let Some(decode) = world
+                .resource::<LateJoinRegistry>()
+                .decoders
+                .get(kind.as_str())
+                .map(|(_, decode)| *decode)
+            else {
+                warn!(
+                    "LateJoin: component {kind:?} isn't registered here; register it with add_late_join_component on every peer"
+                );
+                continue;
+            };
+            if let Err(err) = decode(world, *target, payload) {
+                error!(
+                    "LateJoin: {kind} for {} failed to decode: {err}",
+                    entity.path
+                );
+            }
+        }
+    }
+
+    let mut incoming = world.resource_mut::<IncomingSnapshot>();
+    incoming.applied += ready.len() as u32;
+    let applied = incoming.applied;
+    let done = applied >= total && incoming.waiting.is_empty();
+    if done {
+        *incoming = IncomingSnapshot::default();
+    }
+    if applied != before {
+        world.trigger(LateJoinProgress { applied, total });
+    }
+    if done {
+        world.trigger(LateJoinCompleted { entities: total });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Component, Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Health(u32);
+
+    fn entity(path: &str) -> SnapshotEntity {
+        SnapshotEntity {
+            path: path.to_string(),
+            scene: String::new(),
+            components: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn parents_come_before_children() {
+        let mut entities = vec![
+            entity("/root/Level/Enemies/Boss/Shield"),
+            entity("/root/Level/Players"),
+            entity("/root/Level/Enemies/Boss"),
+            entity("/root/Level/Enemies/Archer"),
+        ];
+        in_dependency_order(&mut entities);
+        let paths: Vec<_> = entities.iter().map(|e| e.path.as_str()).collect();
+        assert_eq!(
+            paths,
+            [
+                "/root/Level/Players",
+                "/root/Level/Enemies/Archer",
+                "/root/Level/Enemies/Boss",
+                "/root/Level/Enemies/Boss/Shield",
+            ]
+        );
+    }
+
+    #[test]
+    fn registered_components_round_trip() {
+        let mut app = App::new();
+        app.add_late_join_component::<Health>("health");
+        let world = app.world_mut();
+        let source = world.spawn(Health(7)).id();
+        let target = world.spawn_empty().id();
+
+        let registry = world.resource::<LateJoinRegistry>();
+        let (kind, encode) = registry.encoders[0];
+        let payload = encode(world, source).unwrap().unwrap();
+        assert!(encode(world, target).is_none());
+        let (_, decode) = registry.decoders[kind];
+        decode(world, target, &payload).unwrap();
+        assert_eq!(world.get::<Health>(target), Some(&Health(7)));
+    }
+}
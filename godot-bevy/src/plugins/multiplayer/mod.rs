@@ -0,0 +1,191 @@
+//! Godot's high-level multiplayer API, bridged into Bevy.
+//!
+//! [`GodotMultiplayerPlugin`] surfaces the scene tree's `MultiplayerAPI` to ECS:
+//!
+//! - Peer connection signals become events ([`PeerConnected`], [`PeerDisconnected`],
+//!   [`ConnectedToServer`], [`ConnectionFailed`], [`ServerDisconnected`]) for observers.
+//! - Sessions are hosted and joined by writing [`NetworkCommand`] messages (ENet or
+//!   WebSocket); [`NetworkState`] tracks the local peer's connection.
+//! - Typed RPCs: register a serde type with [`AddRpcAppExt::add_rpc`], write
+//!   [`SendRpc<T>`] messages to send it and read [`RpcReceived<T>`] messages on the
+//!   other end. Payloads travel as JSON strings through a relay node every peer owns.
+//! - `MultiplayerSpawner` and `MultiplayerSynchronizer` nodes are bridged: spawner
+//!   activity triggers [`MultiplayerSpawned`] / [`MultiplayerDespawned`], and the root
+//!   of every synchronizer carries a [`MultiplayerAuthority`] component.
+//! - [`NetworkOwner`] follows a node's multiplayer authority both ways, and
+//!   [`HasAuthority`] marks the entities this peer owns.
+//! - Peers joining mid-game get a snapshot of the [`LateJoin`] entities, with their
+//!   scenes and the components registered with
+//!   [`AddLateJoinAppExt::add_late_join_component`].
+//! - [`NetStats`] reports bandwidth per RPC name, round-trip times and replication
+//!   counts, refreshed every second.
+//!
+//! A peer set on the `MultiplayerAPI` by other means (e.g. from GDScript) works too.
+//!
+//! ```ignore
+//! #[derive(Serialize, Deserialize)]
+//! struct Chat(String);
+//!
+//! app.add_plugins(GodotMultiplayerPlugin).add_rpc::<Chat>("chat");
+//!
+//! fn send_chat(mut rpcs: MessageWriter<SendRpc<Chat>>) {
+//!     rpcs.write(SendRpc::to_all(Chat("hello".into())));
+//! }
+//!
+//! fn show_chat(mut chat: MessageReader<RpcReceived<Chat>>) {
+//!     for rpc in chat.read() {
+//!         info!("peer {}: {}", rpc.from, rpc.message.0);
+//!     }
+//! }
+//! ```
+
+mod late_join;
+mod peer;
+mod prediction;
+mod replication;
+mod rpc;
+mod stats;
+
+pub use late_join::{
+    AddLateJoinAppExt, LateJoin, LateJoinCompleted, LateJoinProgress, LateJoinSnapshotSent,
+};
+pub use peer::{
+    NetworkCommand, NetworkState, enet_client, enet_server, websocket_client, websocket_server,
+};
+pub use prediction::{
+    AddPredictionAppExt, Predicted, PredictedInput, Rollback, ServerAuthoritative, resimulating,
+};
+pub use replication::{
+    HasAuthority, MultiplayerAuthority, MultiplayerDespawned, MultiplayerSpawned, NetworkOwner,
+    has_authority,
+};
+pub use rpc::{AddRpcAppExt, RpcMessage, RpcReceived, RpcTarget, RpcTransfer, SendRpc};
+pub use stats::{ChannelStats, NetStats, ReplicationCounts, TransportStats};
+
+use bevy_app::{App, First, Plugin, PreUpdate, Startup};
+use bevy_ecs::event::Event;
+use bevy_ecs::schedule::IntoScheduleConfigs;
+use bevy_ecs::system::Res;
+use godot::builtin::{Callable, Variant};
+use godot::classes::{MultiplayerApi, Object};
+use godot::obj::{Gd, Inherits};
+use tracing::warn;
+
+use crate::interop::GodotAccess;
+use crate::plugins::event_bridge::{EventBridgeSet, GodotEventSender};
+use crate::plugins::scene_tree::SceneTreeRef;
+
+/// Plugin bridging Godot's high-level multiplayer API. See the [module docs](self).
+#[derive(Default)]
+pub struct GodotMultiplayerPlugin;
+
+impl Plugin for GodotMultiplayerPlugin {
+    fn build(&self, app: &mut App) {
+        rpc::ensure_rpc_channel(app);
+        app.init_resource::<NetworkState>()
+            .init_resource::<NetStats>()
+            .add_message::<NetworkCommand>()
+            .add_systems(Startup, (connect_multiplayer_signals, rpc::spawn_rpc_relay))
+            .add_systems(First, rpc::receive_rpcs.after(EventBridgeSet::Drain))
+            .add_systems(
+                PreUpdate,
+                (
+                    (peer::apply_network_commands, peer::update_network_state).chain(),
+                    replication::connect_spawner_signals,
+                    (
+                        replication::update_multiplayer_authority,
+                        replication::sync_network_owners,
+                        replication::update_has_authority,
+                    )
+                        .chain()
+                        .after(peer::update_network_state),
+                    stats::sample_net_stats.after(replication::update_has_authority),
+                ),
+            );
+    }
+}
+
+/// A peer's unique id in the session. The server is 1.
+pub type PeerId = i32;
+
+/// Triggered on every peer when another peer connects.
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PeerConnected {
+    pub peer: PeerId,
+}
+
+/// Triggered on every peer when another peer disconnects.
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PeerDisconnected {
+    pub peer: PeerId,
+}
+
+/// Triggered on a client once its connection to the server succeeds.
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConnectedToServer;
+
+/// Triggered on a client when it could not connect to the server, and when a
+/// [`NetworkCommand`] could not create its peer.
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConnectionFailed;
+
+/// Triggered on a client when the server goes away.
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ServerDisconnected;
+
+/// The scene tree's `MultiplayerAPI`, the one every node uses unless overridden.
+pub(crate) fn multiplayer_api(scene_tree: &mut SceneTreeRef) -> Option<Gd<MultiplayerApi>> {
+    scene_tree.get().get_multiplayer()
+}
+
+/// Route the `MultiplayerAPI` connection signals through the event bridge, so
+/// they reach observers on the next `First`.
+fn connect_multiplayer_signals(
+    mut scene_tree: SceneTreeRef,
+    sender: Res<GodotEventSender>,
+    _godot: GodotAccess,
+) {
+    let Some(mut api) = multiplayer_api(&mut scene_tree) else {
+        warn!("GodotMultiplayerPlugin: scene tree has no MultiplayerAPI; peer events disabled");
+        return;
+    };
+
+    let peer_id = |args: &[&Variant]| args.first().and_then(|v| v.try_to::<PeerId>().ok());
+
+    let tx = sender.clone();
+    connect(&mut api, "peer_connected", move |args| {
+        if let Some(peer) = peer_id(args) {
+            tx.send(PeerConnected { peer });
+        }
+    });
+    let tx = sender.clone();
+    connect(&mut api, "peer_disconnected", move |args| {
+        if let Some(peer) = peer_id(args) {
+            tx.send(PeerDisconnected { peer });
+        }
+    });
+    let tx = sender.clone();
+    connect(&mut api, "connected_to_server", move |_| {
+        tx.send(ConnectedToServer)
+    });
+    let tx = sender.clone();
+    connect(&mut api, "connection_failed", move |_| {
+        tx.send(ConnectionFailed)
+    });
+    let tx = sender.clone();
+    connect(&mut api, "server_disconnected", move |_| {
+        tx.send(ServerDisconnected)
+    });
+}
+
+pub(crate) fn connect<T: Inherits<Object>>(
+    object: &mut Gd<T>,
+    signal: &str,
+    mut handler: impl FnMut(&[&Variant]) + 'static,
+) {
+    let callable = Callable::from_fn(format!("multiplayer_{signal}"), move |args| {
+        handler(args);
+        Variant::nil()
+    });
+    object.upcast_mut::<Object>().connect(signal, &callable);
+}
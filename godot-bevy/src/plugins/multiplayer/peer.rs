@@ -0,0 +1,208 @@
+//! Hosting and joining sessions over ENet or WebSocket, and the resulting
+//! [`NetworkState`].
+
+use bevy_ecs::change_detection::DetectChangesMut;
+use bevy_ecs::message::{Message, MessageReader};
+use bevy_ecs::prelude::Resource;
+use bevy_ecs::system::{Commands, ResMut};
+use godot::classes::multiplayer_peer::ConnectionStatus;
+use godot::classes::{
+    ENetMultiplayerPeer, MultiplayerPeer, OfflineMultiplayerPeer, WebSocketMultiplayerPeer,
+};
+use godot::global::Error;
+use godot::obj::{Gd, NewGd};
+use tracing::{info, warn};
+
+use super::{ConnectionFailed, PeerId, multiplayer_api};
+use crate::plugins::scene_tree::SceneTreeRef;
+
+/// Godot's default for `ENetMultiplayerPeer.create_server`.
+const DEFAULT_MAX_CLIENTS: u32 = 32;
+
+/// Connection state of the local peer, refreshed in `PreUpdate`.
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum NetworkState {
+    /// No peer, or the peer's connection is closed.
+    #[default]
+    Disconnected,
+    /// A client waiting for the server to accept it.
+    Connecting,
+    /// Hosting (`peer_id` 1) or connected to a server.
+    Connected { peer_id: PeerId },
+}
+
+impl NetworkState {
+    pub fn is_connected(&self) -> bool {
+        matches!(self, Self::Connected { .. })
+    }
+
+    pub fn is_server(&self) -> bool {
+        self.peer_id() == Some(1)
+    }
+
+    /// The local peer id while connected.
+    pub fn peer_id(&self) -> Option<PeerId> {
+        match self {
+            Self::Connected { peer_id } => Some(*peer_id),
+            _ => None,
+        }
+    }
+}
+
+/// Host or join a session, or leave the current one. The new peer replaces
+/// whatever peer the scene tree's `MultiplayerAPI` had.
+///
+/// ```ignore
+/// fn host(mut network: MessageWriter<NetworkCommand>) {
+///     network.write(NetworkCommand::host_enet(7000));
+/// }
+/// ```
+///
+/// A peer that can't be created (port in use, bad address) triggers [`ConnectionFailed`].
+#[derive(Message, Debug, Clone, PartialEq, Eq)]
+pub enum NetworkCommand {
+    HostEnet {
+        port: u16,
+        max_clients: u32,
+    },
+    JoinEnet {
+        address: String,
+        port: u16,
+    },
+    HostWebSocket {
+        port: u16,
+    },
+    JoinWebSocket {
+        url: String,
+    },
+    /// Close the current peer and go back offline.
+    Disconnect,
+}
+
+impl NetworkCommand {
+    pub fn host_enet(port: u16) -> Self {
+        Self::HostEnet {
+            port,
+            max_clients: DEFAULT_MAX_CLIENTS,
+        }
+    }
+
+    pub fn join_enet(address: impl Into<String>, port: u16) -> Self {
+        Self::JoinEnet {
+            address: address.into(),
+            port,
+        }
+    }
+
+    pub fn host_websocket(port: u16) -> Self {
+        Self::HostWebSocket { port }
+    }
+
+    /// `url` is a `ws://` or `wss://` address.
+    pub fn join_websocket(url: impl Into<String>) -> Self {
+        Self::JoinWebSocket { url: url.into() }
+    }
+}
+
+/// Create an ENet server peer listening on `port`.
+pub fn enet_server(port: u16, max_clients: u32) -> Result<Gd<MultiplayerPeer>, Error> {
+    let mut peer = ENetMultiplayerPeer::new_gd();
+    let max_clients = i32::try_from(max_clients).unwrap_or(i32::MAX);
+    check(
+        peer.create_server_ex(port.into())
+            .max_clients(max_clients)
+            .done(),
+    )?;
+    Ok(peer.upcast())
+}
+
+/// Create an ENet client peer connecting to `address:port`.
+pub fn enet_client(address: &str, port: u16) -> Result<Gd<MultiplayerPeer>, Error> {
+    let mut peer = ENetMultiplayerPeer::new_gd();
+    check(peer.create_client(address, port.into()))?;
+    Ok(peer.upcast())
+}
+
+/// Create a WebSocket server peer listening on `port`.
+pub fn websocket_server(port: u16) -> Result<Gd<MultiplayerPeer>, Error> {
+    let mut peer = WebSocketMultiplayerPeer::new_gd();
+    check(peer.create_server(port.into()))?;
+    Ok(peer.upcast())
+}
+
+/// Create a WebSocket client peer connecting to `url`.
+pub fn websocket_client(url: &str) -> Result<Gd<MultiplayerPeer>, Error> {
+    let mut peer = WebSocketMultiplayerPeer::new_gd();
+    check(peer.create_client(url))?;
+    Ok(peer.upcast())
+}
+
+fn check(error: Error) -> Result<(), Error> {
+    match error {
+        Error::OK => Ok(()),
+        error => Err(error),
+    }
+}
+
+pub(super) fn apply_network_commands(
+    mut commands: Commands,
+    mut requests: MessageReader<NetworkCommand>,
+    mut scene_tree: SceneTreeRef,
+) {
+    if requests.is_empty() {
+        return;
+    }
+    let Some(mut api) = multiplayer_api(&mut scene_tree) else {
+        warn!("GodotMultiplayerPlugin: scene tree has no MultiplayerAPI; ignoring NetworkCommand");
+        requests.clear();
+        return;
+    };
+
+    for request in requests.read() {
+        let peer = match request {
+            NetworkCommand::HostEnet { port, max_clients } => enet_server(*port, *max_clients),
+            NetworkCommand::JoinEnet { address, port } => enet_client(address, *port),
+            NetworkCommand::HostWebSocket { port } => websocket_server(*port),
+            NetworkCommand::JoinWebSocket { url } => websocket_client(url),
+            NetworkCommand::Disconnect => {
+                if let Some(mut current) = api.get_multiplayer_peer() {
+                    current.close();
+                }
+                Ok(OfflineMultiplayerPeer::new_gd().upcast())
+            }
+        };
+
+        match peer {
+            Ok(peer) => {
+                info!("GodotMultiplayerPlugin: {request:?}");
+                api.set_multiplayer_peer(&peer);
+            }
+            Err(error) => {
+                warn!("GodotMultiplayerPlugin: {request:?} failed: {error:?}");
+                commands.trigger(ConnectionFailed);
+            }
+        }
+    }
+}
+
+/// Polled rather than driven by the connection signals: the status is a couple of
+/// FFI calls, and polling also catches peers set on the `MultiplayerAPI` directly.
+pub(super) fn update_network_state(mut state: ResMut<NetworkState>, mut scene_tree: SceneTreeRef) {
+    let api = multiplayer_api(&mut scene_tree);
+    let peer = api
+        .as_ref()
+        .and_then(|api| api.get_multiplayer_peer())
+        .filter(|peer| !peer.is_class("OfflineMultiplayerPeer"));
+
+    let current = match (api, peer) {
+        (Some(api), Some(peer)) => match peer.get_connection_status() {
+            ConnectionStatus::CONNECTED => NetworkState::Connected {
+                peer_id: api.get_unique_id(),
+            },
+            ConnectionStatus::CONNECTING => NetworkState::Connecting,
+            _ => NetworkState::Disconnected,
+        },
+        _ => NetworkState::Disconnected,
+    };
+    state.set_if_neq(current);
+}
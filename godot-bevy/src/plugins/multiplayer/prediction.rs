@@ -0,0 +1,752 @@
+//! Client-side prediction with server reconciliation.
+//!
+//! A client simulates the entities it controls ([`Predicted`]) right away from
+//! its own input, instead of waiting a round trip for the server. Every fixed
+//! tick it records the input it used ([`PredictedInput`]) and a snapshot of each
+//! rollback component, and sends the input to the server.
+//!
+//! The server applies those inputs in order and sends back its state for the
+//! component, tagged with the client tick of the last input it applied
+//! ([`ServerAuthoritative`]). If that state differs from what the client had
+//! predicted for the tick, the client restores it and runs `FixedMain` again for
+//! every tick since, replaying the recorded inputs. This all happens in
+//! `RunFixedMainLoop` before the next tick, so gameplay only sees the
+//! corrected result.
+//!
+//! ```ignore
+//! #[derive(Component, Clone, PartialEq, Serialize, Deserialize)]
+//! struct Velocity(Vec2);
+//!
+//! #[derive(Clone, Default, Serialize, Deserialize)]
+//! struct MoveInput(Vec2);
+//!
+//! app.add_rollback_component::<Velocity>("velocity")
+//!     .add_predicted_input::<MoveInput>("move_input")
+//!     // Read the device only for new ticks, not while replaying old ones.
+//!     .add_systems(FixedPreUpdate, read_move_input.run_if(not(resimulating)))
+//!     .add_systems(FixedUpdate, apply_move_input);
+//!
+//! fn apply_move_input(mut players: Query<(&PredictedInput<MoveInput>, &mut Velocity)>) {
+//!     for (input, mut velocity) in &mut players {
+//!         velocity.0 = input.current.0 * 200.0;
+//!     }
+//! }
+//! ```
+//!
+//! On every peer, the entity must carry `PredictedInput<MoveInput>` and
+//! `ServerAuthoritative<Velocity>`. On the client that controls it, it also
+//! carries `Predicted`. Entities are matched across peers by node path, as with
+//! Godot's own RPCs. The server only accepts a node's inputs from that node's
+//! multiplayer authority.
+//!
+//! Systems with side effects that must not repeat (sounds, spawning) should
+//! skip resimulated ticks with `run_if(not(resimulating))`. `Time<Fixed>` does
+//! not advance while resimulating.
+
+use std::collections::VecDeque;
+
+use bevy_app::{
+    App, First, FixedFirst, FixedLast, FixedMain, RunFixedMainLoop, RunFixedMainLoopSystems,
+};
+use bevy_ecs::change_detection::DetectChanges;
+use bevy_ecs::component::{Component, Mutable};
+use bevy_ecs::entity::Entity;
+use bevy_ecs::message::{MessageReader, MessageWriter};
+use bevy_ecs::prelude::Resource;
+use bevy_ecs::query::{Has, With};
+use bevy_ecs::schedule::common_conditions::not;
+use bevy_ecs::schedule::{IntoScheduleConfigs, SystemSet};
+use bevy_ecs::system::{Commands, Query, Res, ResMut};
+use bevy_ecs::world::{Ref, World};
+use bevy_time::{Fixed, Time, Virtual};
+use godot::classes::Node;
+use serde::{Deserialize, Serialize};
+use tracing::debug;
+
+use super::NetworkState;
+use super::rpc::{AddRpcAppExt, RpcMessage, RpcReceived, RpcTransfer, SendRpc, receive_rpcs};
+use crate::interop::{GodotAccess, GodotNodeHandle};
+use crate::plugins::scene_tree::{NodeEntityIndex, SceneTreeRef};
+
+/// Ticks of input and state kept for rollback, about two seconds at 60 Hz.
+/// Corrections older than this snap the component without resimulating.
+const HISTORY_TICKS: usize = 128;
+
+/// Inputs repeated in every input packet, so one dropped packet loses nothing.
+const REDUNDANT_INPUTS: usize = 4;
+
+/// Fixed tick counter and rollback state.
+#[derive(Resource, Debug, Default)]
+pub struct Rollback {
+    tick: u32,
+    resimulating: bool,
+    /// Earliest tick whose recorded state was corrected since the last tick.
+    rollback_to: Option<u32>,
+}
+
+impl Rollback {
+    /// The fixed tick being simulated, or last simulated outside `FixedMain`.
+    /// The first tick is 1.
+    pub fn tick(&self) -> u32 {
+        self.tick
+    }
+
+    /// Whether `FixedMain` is replaying an old tick after a correction.
+    pub fn is_resimulating(&self) -> bool {
+        self.resimulating
+    }
+
+    /// Restore the state recorded at the end of `tick` and resimulate every
+    /// tick after it, before the next tick runs. Done automatically when a
+    /// [`ServerAuthoritative`] value disagrees with the prediction.
+    pub fn request_rollback(&mut self, tick: u32) {
+        self.rollback_to = Some(self.rollback_to.map_or(tick, |earlier| earlier.min(tick)));
+    }
+}
+
+/// Run condition: `FixedMain` is replaying an old tick.
+pub fn resimulating(rollback: Option<Res<Rollback>>) -> bool {
+    rollback.is_some_and(|rollback| rollback.resimulating)
+}
+
+/// Marks an entity this peer simulates ahead of the server: its rollback
+/// components are recorded every tick and rewound on correction, and its
+/// [`PredictedInput`]s are sent to the server.
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Predicted;
+
+/// Input driving an entity, readable by gameplay as `current` on every peer.
+///
+/// On the controlling client, write `current` before `FixedUpdate`; it is
+/// recorded each tick and put back when the tick is resimulated. On the
+/// server, `current` is set from the inputs the client sent, one per tick. When
+/// none is waiting, the last one is kept.
+#[derive(Component, Debug, Clone, Default)]
+pub struct PredictedInput<I: Send + Sync + 'static> {
+    pub current: I,
+    /// Controlling client: inputs by tick. Server: received, not yet applied.
+    history: VecDeque<(u32, I)>,
+    /// The input written for the upcoming tick, set aside while old ticks are
+    /// replayed.
+    live: Option<I>,
+}
+
+impl<I: Send + Sync + 'static> PredictedInput<I> {
+    pub fn new(current: I) -> Self {
+        Self {
+            current,
+            history: VecDeque::new(),
+            live: None,
+        }
+    }
+
+    fn input_at(&self, tick: u32) -> Option<&I> {
+        self.history
+            .iter()
+            .find(|(recorded, _)| *recorded == tick)
+            .map(|(_, input)| input)
+    }
+
+    fn push(&mut self, tick: u32, input: I) {
+        if self.history.len() == HISTORY_TICKS {
+            self.history.pop_front();
+        }
+        self.history.push_back((tick, input));
+    }
+}
+
+/// The server's value of `T` for an entity.
+///
+/// The server refreshes it from `T` every tick and sends it to the clients.
+/// A client holding it applies `value` to `T` directly, unless the entity is
+/// [`Predicted`], in which case `value` is compared against the prediction for
+/// `tick` and `T` is rolled back if they differ.
+#[derive(Component, Debug, Clone, Default, PartialEq)]
+pub struct ServerAuthoritative<T: Send + Sync + 'static> {
+    /// For a predicted entity, the controlling client's tick of the last input
+    /// the server applied. Otherwise the server's tick.
+    pub tick: u32,
+    pub value: T,
+}
+
+impl<T: Send + Sync + 'static> ServerAuthoritative<T> {
+    pub fn new(value: T) -> Self {
+        Self { tick: 0, value }
+    }
+}
+
+/// Client tick of the input the server last applied to an entity.
+#[derive(Component, Debug, Clone, Copy)]
+struct AppliedInputTick(u32);
+
+/// State of one rollback component at the end of each recorded tick.
+#[derive(Resource)]
+struct RollbackHistory<C: Component> {
+    ticks: VecDeque<(u32, Vec<(Entity, C)>)>,
+}
+
+impl<C: Component> Default for RollbackHistory<C> {
+    fn default() -> Self {
+        Self {
+            ticks: VecDeque::new(),
+        }
+    }
+}
+
+impl<C: Component> RollbackHistory<C> {
+    fn at_mut(&mut self, tick: u32) -> Option<&mut Vec<(Entity, C)>> {
+        self.ticks
+            .iter_mut()
+            .find(|(recorded, _)| *recorded == tick)
+            .map(|(_, state)| state)
+    }
+}
+
+/// Restores every registered component to its recorded state at a tick.
+#[derive(Resource, Default)]
+struct RollbackComponents(Vec<fn(&mut World, u32) -> bool>);
+
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+enum PredictionSystems {
+    Reconcile,
+    Rollback,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(bound = "I: RpcMessage")]
+struct InputFrame<I> {
+    node: String,
+    /// The newest few inputs, oldest first.
+    inputs: Vec<(u32, I)>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(bound = "C: RpcMessage")]
+struct StateFrame<C> {
+    node: String,
+    tick: u32,
+    value: C,
+}
+
+pub trait AddPredictionAppExt {
+    /// Record `C` every tick on [`Predicted`] entities so it can be rolled
+    /// back, and replicate the server's value through [`ServerAuthoritative<C>`].
+    /// The server's states travel as RPCs named `name`, which every peer must use
+    /// for `C` (see [`AddRpcAppExt::add_rpc`]).
+    fn add_rollback_component<C>(&mut self, name: &'static str) -> &mut Self
+    where
+        C: Component<Mutability = Mutable> + Clone + PartialEq + RpcMessage;
+
+    /// Record [`PredictedInput<I>`] on [`Predicted`] entities and send it to the
+    /// server, which applies it to its copy of the entity. Inputs travel as RPCs
+    /// named `name`, which every peer must use for `I`.
+    fn add_predicted_input<I>(&mut self, name: &'static str) -> &mut Self
+    where
+        I: Clone + RpcMessage;
+}
+
+impl AddPredictionAppExt for App {
+    fn add_rollback_component<C>(&mut self, name: &'static str) -> &mut Self
+    where
+        C: Component<Mutability = Mutable> + Clone + PartialEq + RpcMessage,
+    {
+        ensure_prediction(self);
+        if self.world().contains_resource::<RollbackHistory<C>>() {
+            return self;
+        }
+        self.init_resource::<RollbackHistory<C>>();
+        self.world_mut()
+            .resource_mut::<RollbackComponents>()
+            .0
+            .push(restore::<C>);
+        self.add_rpc::<StateFrame<C>>(name)
+            .add_systems(
+                RunFixedMainLoop,
+                reconcile::<C>
+                    .run_if(not_server)
+                    .in_set(PredictionSystems::Reconcile),
+            )
+            .add_systems(
+                FixedLast,
+                (
+                    record_state::<C>,
+                    send_authoritative_state::<C>.run_if(is_server),
+                ),
+            )
+            .add_systems(
+                First,
+                receive_authoritative_state::<C>
+                    .run_if(is_client)
+                    .after(receive_rpcs),
+            )
+    }
+
+    fn add_predicted_input<I>(&mut self, name: &'static str) -> &mut Self
+    where
+        I: Clone + RpcMessage,
+    {
+        ensure_prediction(self);
+        self.add_rpc::<InputFrame<I>>(name)
+            .add_systems(FixedFirst, apply_input::<I>.after(begin_tick))
+            .add_systems(
+                FixedLast,
+                (
+                    record_input::<I>,
+                    send_predicted_input::<I>.run_if(is_client),
+                )
+                    .chain()
+                    .run_if(not(resimulating)),
+            )
+            .add_systems(
+                First,
+                receive_predicted_input::<I>
+                    .run_if(is_server)
+                    .after(receive_rpcs),
+            )
+    }
+}
+
+fn ensure_prediction(app: &mut App) {
+    if app.world().contains_resource::<Rollback>() {
+        return;
+    }
+    app.init_resource::<Rollback>()
+        .init_resource::<RollbackComponents>()
+        .configure_sets(
+            RunFixedMainLoop,
+            (PredictionSystems::Reconcile, PredictionSystems::Rollback)
+                .chain()
+                .in_set(RunFixedMainLoopSystems::BeforeFixedMainLoop),
+        )
+        .add_systems(
+            RunFixedMainLoop,
+            rollback_and_resimulate.in_set(PredictionSystems::Rollback),
+        )
+        .add_systems(FixedFirst, begin_tick);
+}
+
+fn is_server(state: Option<Res<NetworkState>>) -> bool {
+    state.is_some_and(|state| state.is_server())
+}
+
+fn not_server(state: Option<Res<NetworkState>>) -> bool {
+    !is_server(state)
+}
+
+fn is_client(state: Option<Res<NetworkState>>) -> bool {
+    state.is_some_and(|state| state.is_connected() && !state.is_server())
+}
+
+fn begin_tick(mut rollback: ResMut<Rollback>) {
+    rollback.tick += 1;
+}
+
+/// Replay recorded input while resimulating; on the server, take the next
+/// input the client sent.
+fn apply_input<I: Clone + Send + Sync + 'static>(
+    rollback: Res<Rollback>,
+    mut entities: Query<(Entity, &mut PredictedInput<I>, Has<Predicted>)>,
+    mut commands: Commands,
+) {
+    for (entity, mut input, predicted) in entities.iter_mut() {
+        if predicted {
+            if !rollback.resimulating {
+                if let Some(live) = input.live.take() {
+                    input.current = live;
+                }
+            } else if let Some(recorded) = input.input_at(rollback.tick).cloned() {
+                if input.live.is_none() {
+                    input.live = Some(input.current.clone());
+                }
+                input.current = recorded;
+            }
+        } else if let Some((tick, next)) = input.history.pop_front() {
+            input.current = next;
+            commands.entity(entity).try_insert(AppliedInputTick(tick));
+        }
+    }
+}
+
+fn record_input<I: Clone + Send + Sync + 'static>(
+    rollback: Res<Rollback>,
+    mut entities: Query<&mut PredictedInput<I>, With<Predicted>>,
+) {
+    for mut input in entities.iter_mut() {
+        let current = input.current.clone();
+        input.push(rollback.tick, current);
+    }
+}
+
+fn record_state<C: Component + Clone>(
+    rollback: Res<Rollback>,
+    mut history: ResMut<RollbackHistory<C>>,
+    entities: Query<(Entity, &C), With<Predicted>>,
+) {
+    let state: Vec<(Entity, C)> = entities
+        .iter()
+        .map(|(entity, value)| (entity, value.clone()))
+        .collect();
+    // Resimulation rewrites ticks already in the history.
+    if let Some(recorded) = history.at_mut(rollback.tick) {
+        *recorded = state;
+        return;
+    }
+    if history.ticks.len() == HISTORY_TICKS {
+        history.ticks.pop_front();
+    }
+    history.ticks.push_back((rollback.tick, state));
+}
+
+/// Compare fresh server values against the prediction, fixing the recorded
+/// state and requesting a rollback on a mismatch.
+fn reconcile<C>(
+    mut rollback: ResMut<Rollback>,
+    mut history: ResMut<RollbackHistory<C>>,
+    mut entities: Query<(Entity, Ref<ServerAuthoritative<C>>, &mut C, Has<Predicted>)>,
+) where
+    C: Component<Mutability = Mutable> + Clone + PartialEq,
+{
+    for (entity, authoritative, mut value, predicted) in entities.iter_mut() {
+        if !authoritative.is_changed() {
+            continue;
+        }
+        if !predicted {
+            if *value != authoritative.value {
+                *value = authoritative.value.clone();
+            }
+            continue;
+        }
+        let Some(state) = history.at_mut(authoritative.tick) else {
+            debug!(
+                "{}: server state for tick {} is outside the rollback history, snapping",
+                std::any::type_name::<C>(),
+                authoritative.tick
+            );
+            *value = authoritative.value.clone();
+            continue;
+        };
+        match state.iter_mut().find(|(recorded, _)| *recorded == entity) {
+            Some((_, recorded)) if *recorded == authoritative.value => {}
+            Some((_, recorded)) => {
+                *recorded = authoritative.value.clone();
+                rollback.request_rollback(authoritative.tick);
+            }
+            None => {
+                state.push((entity, authoritative.value.clone()));
+                rollback.request_rollback(authoritative.tick);
+            }
+        }
+    }
+}
+
+fn restore<C>(world: &mut World, tick: u32) -> bool
+where
+    C: Component<Mutability = Mutable> + Clone,
+{
+    let Some(state) = world
+        .resource_mut::<RollbackHistory<C>>()
+        .at_mut(tick)
+        .map(|state| state.clone())
+    else {
+        return false;
+    };
+    for (entity, recorded) in state {
+        if let Some(mut value) = world.get_mut::<C>(entity) {
+            *value = recorded;
+        }
+    }
+    true
+}
+
+fn rollback_and_resimulate(world: &mut World) {
+    // The fixed driver skips FixedMain while paused; resimulating would run it.
+    if world.resource::<Time<Virtual>>().is_paused() {
+        return;
+    }
+    let (target, from) = {
+        let mut rollback = world.resource_mut::<Rollback>();
+        let Some(from) = rollback.rollback_to.take() else {
+            return;
+        };
+        (rollback.tick, from)
+    };
+    if from > target {
+        return;
+    }
+
+    let restores = world.resource::<RollbackComponents>().0.clone();
+    let mut restored = true;
+    for restore in restores {
+        restored &= restore(world, from);
+    }
+    if !restored {
+        debug!("rollback to tick {from}: state is no longer recorded for every component");
+    }
+
+    {
+        let mut rollback = world.resource_mut::<Rollback>();
+        rollback.tick = from;
+        rollback.resimulating = true;
+    }
+    *world.resource_mut::<Time>() = world.resource::<Time<Fixed>>().as_generic();
+    for _ in from..target {
+        FixedMain::run_fixed_main(world);
+    }
+    *world.resource_mut::<Time>() = world.resource::<Time<Virtual>>().as_generic();
+    world.resource_mut::<Rollback>().resimulating = false;
+}
+
+fn node_path(godot: &mut GodotAccess, handle: GodotNodeHandle) -> Option<String> {
+    godot
+        .try_get::<Node>(handle)
+        .map(|node| node.get_path().to_string())
+}
+
+fn entity_at(scene_tree: &mut SceneTreeRef, index: &NodeEntityIndex, path: &str) -> Option<Entity> {
+    let node = scene_tree.get().get_root()?.get_node_or_null(path)?;
+    index.get(node.instance_id())
+}
+
+fn send_predicted_input<I: Clone + RpcMessage>(
+    entities: Query<(&GodotNodeHandle, &PredictedInput<I>), With<Predicted>>,
+    mut outgoing: MessageWriter<SendRpc<InputFrame<I>>>,
+    mut godot: GodotAccess,
+) {
+    for (&handle, input) in entities.iter() {
+        let Some(node) = node_path(&mut godot, handle) else {
+            continue;
+        };
+        let skip = input.history.len().saturating_sub(REDUNDANT_INPUTS);
+        let inputs = input.history.iter().skip(skip).cloned().collect();
+        outgoing.write(
+            SendRpc::to_server(InputFrame { node, inputs })
+                .with_transfer(RpcTransfer::UnreliableOrdered),
+        );
+    }
+}
+
+fn receive_predicted_input<I: Clone + RpcMessage>(
+    mut incoming: MessageReader<RpcReceived<InputFrame<I>>>,
+    mut entities: Query<(
+        &GodotNodeHandle,
+        &mut PredictedInput<I>,
+        Option<&AppliedInputTick>,
+    )>,
+    index: Res<NodeEntityIndex>,
+    mut scene_tree: SceneTreeRef,
+    mut godot: GodotAccess,
+) {
+    for rpc in incoming.read() {
+        let Some(entity) = entity_at(&mut scene_tree, &index, &rpc.message.node) else {
+            continue;
+        };
+        let Ok((&handle, mut input, applied)) = entities.get_mut(entity) else {
+            continue;
+        };
+        let Some(node) = godot.try_get::<Node>(handle) else {
+            continue;
+        };
+        // Only the node's authority may drive it.
+        if node.get_multiplayer_authority() != rpc.from {
+            continue;
+        }
+        let newest = input
+            .history
+            .back()
+            .map(|(tick, _)| *tick)
+            .or(applied.map(|applied| applied.0))
+            .unwrap_or(0);
+        for (tick, frame) in &rpc.message.inputs {
+            if *tick > newest {
+                input.push(*tick, frame.clone());
+            }
+        }
+    }
+}
+
+fn send_authoritative_state<C: Component + Clone + RpcMessage>(
+    rollback: Res<Rollback>,
+    mut entities: Query<(
+        &GodotNodeHandle,
+        &C,
+        &mut ServerAuthoritative<C>,
+        Option<&AppliedInputTick>,
+    )>,
+    mut outgoing: MessageWriter<SendRpc<StateFrame<C>>>,
+    mut godot: GodotAccess,
+) {
+    for (&handle, value, mut authoritative, applied) in entities.iter_mut() {
+        let tick = applied.map_or(rollback.tick, |applied| applied.0);
+        *authoritative = ServerAuthoritative {
+            tick,
+            value: value.clone(),
+        };
+        let Some(node) = node_path(&mut godot, handle) else {
+            continue;
+        };
+        outgoing.write(
+            SendRpc::to_all(StateFrame {
+                node,
+                tick,
+                value: value.clone(),
+            })
+            .with_transfer(RpcTransfer::UnreliableOrdered),
+        );
+    }
+}
+
+fn receive_authoritative_state<C: Component + Clone + RpcMessage>(
+    mut incoming: MessageReader<RpcReceived<StateFrame<C>>>,
+    mut entities: Query<&mut ServerAuthoritative<C>>,
+    index: Res<NodeEntityIndex>,
+    mut scene_tree: SceneTreeRef,
+) {
+    for rpc in incoming.read() {
+        // Only the server speaks for the state.
+        if rpc.from != 1 {
+            continue;
+        }
+        let Some(entity) = entity_at(&mut scene_tree, &index, &rpc.message.node) else {
+            continue;
+        };
+        if let Ok(mut authoritative) = entities.get_mut(entity) {
+            authoritative.tick = rpc.message.tick;
+            authoritative.value = rpc.message.value.clone();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::plugins::fixed_schedule::{host_fixed_main_loop, run_godot_fixed_main};
+    use bevy_app::FixedUpdate;
+    use bevy_time::TimePlugin;
+    use std::time::Duration;
+
+    #[derive(Component, Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Position(i32);
+
+    #[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+    struct Move(i32);
+
+    #[derive(Resource, Default)]
+    struct Simulated(u32);
+
+    fn movement(
+        mut simulated: ResMut<Simulated>,
+        mut entities: Query<(&PredictedInput<Move>, &mut Position)>,
+    ) {
+        simulated.0 += 1;
+        for (input, mut position) in entities.iter_mut() {
+            position.0 += input.current.0;
+        }
+    }
+
+    fn make_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(TimePlugin);
+        host_fixed_main_loop(&mut app);
+        app.add_rollback_component::<Position>("position")
+            .add_predicted_input::<Move>("move")
+            .init_resource::<Simulated>()
+            .add_systems(FixedUpdate, movement);
+        app
+    }
+
+    fn step(app: &mut App, entity: Entity, input: i32) {
+        app.world_mut()
+            .get_mut::<PredictedInput<Move>>(entity)
+            .unwrap()
+            .current = Move(input);
+        run_godot_fixed_main(app.world_mut(), Duration::from_secs_f64(1.0 / 60.0));
+    }
+
+    fn correct(app: &mut App, entity: Entity, tick: u32, value: i32) {
+        app.world_mut()
+            .entity_mut(entity)
+            .insert(ServerAuthoritative {
+                tick,
+                value: Position(value),
+            });
+    }
+
+    #[test]
+    fn misprediction_rolls_back_and_replays_inputs() {
+        let mut app = make_app();
+        let player = app
+            .world_mut()
+            .spawn((Predicted, Position(0), PredictedInput::new(Move(0))))
+            .id();
+        for input in [1, 2, 3, 4, 5] {
+            step(&mut app, player, input);
+        }
+        assert_eq!(app.world().get::<Position>(player).unwrap().0, 15);
+
+        // The server was blocked at tick 2 and only moved to 1.
+        correct(&mut app, player, 2, 1);
+        step(&mut app, player, 6);
+
+        // 1, then ticks 3..=5 replayed with their own inputs, then tick 6.
+        assert_eq!(
+            app.world().get::<Position>(player).unwrap().0,
+            1 + 3 + 4 + 5 + 6
+        );
+        assert_eq!(app.world().resource::<Simulated>().0, 5 + 3 + 1);
+        let rollback = app.world().resource::<Rollback>();
+        assert_eq!(rollback.tick(), 6);
+        assert!(!rollback.is_resimulating());
+        assert_eq!(
+            app.world()
+                .get::<PredictedInput<Move>>(player)
+                .unwrap()
+                .current,
+            Move(6)
+        );
+    }
+
+    #[test]
+    fn matching_server_state_does_not_resimulate() {
+        let mut app = make_app();
+        let player = app
+            .world_mut()
+            .spawn((Predicted, Position(0), PredictedInput::new(Move(0))))
+            .id();
+        for _ in 0..3 {
+            step(&mut app, player, 2);
+        }
+        correct(&mut app, player, 2, 4);
+        step(&mut app, player, 2);
+
+        assert_eq!(app.world().get::<Position>(player).unwrap().0, 8);
+        assert_eq!(app.world().resource::<Simulated>().0, 4);
+    }
+
+    #[test]
+    fn unpredicted_entities_take_server_values_and_queued_inputs() {
+        let mut app = make_app();
+        let remote = app
+            .world_mut()
+            .spawn((Position(0), PredictedInput::new(Move(0))))
+            .id();
+        {
+            let mut input = app
+                .world_mut()
+                .get_mut::<PredictedInput<Move>>(remote)
+                .unwrap();
+            input.push(7, Move(10));
+            input.push(8, Move(20));
+        }
+        run_godot_fixed_main(app.world_mut(), Duration::from_secs_f64(1.0 / 60.0));
+        assert_eq!(app.world().get::<Position>(remote).unwrap().0, 10);
+        assert_eq!(app.world().get::<AppliedInputTick>(remote).unwrap().0, 7);
+
+        correct(&mut app, remote, 1, 100);
+        run_godot_fixed_main(app.world_mut(), Duration::from_secs_f64(1.0 / 60.0));
+        assert_eq!(app.world().get::<Position>(remote).unwrap().0, 120);
+        // With the queue empty the last input repeats.
+        run_godot_fixed_main(app.world_mut(), Duration::from_secs_f64(1.0 / 60.0));
+        assert_eq!(app.world().get::<Position>(remote).unwrap().0, 140);
+        assert_eq!(app.world().resource::<Simulated>().0, 3);
+    }
+}
@@ -0,0 +1,263 @@
+//! `MultiplayerSpawner` / `MultiplayerSynchronizer` state and node ownership,
+//! mirrored into ECS.
+
+use bevy_ecs::change_detection::DetectChanges;
+use bevy_ecs::component::Component;
+use bevy_ecs::entity::Entity;
+use bevy_ecs::event::Event;
+use bevy_ecs::query::{Added, Has, With, Without};
+use bevy_ecs::system::{Commands, Query, Res};
+use bevy_ecs::world::Ref;
+use godot::classes::{MultiplayerSynchronizer, Node};
+use godot::obj::Gd;
+
+use super::{NetworkState, PeerId, connect};
+use crate::interop::node_markers::{MultiplayerSpawnerMarker, MultiplayerSynchronizerMarker};
+use crate::interop::{GodotAccess, GodotNodeHandle};
+use crate::plugins::event_bridge::GodotEventSender;
+use crate::plugins::scene_tree::NodeEntityIndex;
+
+/// Triggered when a `MultiplayerSpawner` spawns a node, locally or by replication.
+///
+/// The node's entity is created by the scene tree mirror as usual; look it up with
+/// `NodeEntityIndex::get_handle(node)`.
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MultiplayerSpawned {
+    pub spawner: GodotNodeHandle,
+    pub node: GodotNodeHandle,
+}
+
+/// Triggered when a `MultiplayerSpawner` despawns a node it replicated.
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MultiplayerDespawned {
+    pub spawner: GodotNodeHandle,
+    pub node: GodotNodeHandle,
+}
+
+/// The peer that owns an entity: the one whose word counts for its state.
+///
+/// For an entity with a Godot node, this is the node's multiplayer authority,
+/// kept in sync both ways every `PreUpdate`: Godot-side changes
+/// (`set_multiplayer_authority` from GDScript) update the component, and
+/// changing the component hands the node (and its children) over in Godot.
+/// Roots of `MultiplayerSynchronizer`s get one automatically; add it to any
+/// other entity you want tracked. Pure ECS entities can carry one too.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NetworkOwner(pub PeerId);
+
+/// Present while this peer owns the entity, i.e. its [`NetworkOwner`] is the
+/// local peer id. Refreshed in `PreUpdate`. While offline the local peer is 1,
+/// so a single-player game owns everything owned by the server.
+///
+/// ```ignore
+/// fn move_own_player(mut players: Query<&mut Transform, (With<Player>, With<HasAuthority>)>) {
+///     // ...
+/// }
+/// ```
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct HasAuthority;
+
+/// Run condition: this peer has authority over at least one entity with `M`.
+///
+/// ```ignore
+/// app.add_systems(Update, read_player_input.run_if(has_authority::<Player>()));
+/// ```
+pub fn has_authority<M: Component>() -> impl FnMut(Query<(), (With<M>, With<HasAuthority>)>) -> bool
+{
+    |owned: Query<(), (With<M>, With<HasAuthority>)>| !owned.is_empty()
+}
+
+/// Authority last agreed between the component and the node, to tell which
+/// side changed.
+#[derive(Component, Debug, Clone, Copy)]
+pub(super) struct SyncedNetworkOwner(PeerId);
+
+/// Multiplayer authority of the root node of a `MultiplayerSynchronizer`: the peer
+/// whose property values are replicated to everyone else. Refreshed in `PreUpdate`.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MultiplayerAuthority {
+    /// Peer id of the authority (1 is the server).
+    pub peer: PeerId,
+    /// Whether this peer is the authority, i.e. should drive the node's state.
+    pub is_local: bool,
+}
+
+pub(super) fn connect_spawner_signals(
+    spawners: Query<&GodotNodeHandle, Added<MultiplayerSpawnerMarker>>,
+    sender: Res<GodotEventSender>,
+    mut godot: GodotAccess,
+) {
+    for &spawner in spawners.iter() {
+        let Some(mut node) = godot.try_get::<Node>(spawner) else {
+            continue;
+        };
+        let spawned_node = |args: &[&godot::builtin::Variant]| {
+            args.first()
+                .and_then(|v| v.try_to::<Gd<Node>>().ok())
+                .map(|node| GodotNodeHandle::from(node.instance_id()))
+        };
+
+        let tx = sender.clone();
+        connect(&mut node, "spawned", move |args| {
+            if let Some(node) = spawned_node(args) {
+                tx.send(MultiplayerSpawned { spawner, node });
+            }
+        });
+        let tx = sender.clone();
+        connect(&mut node, "despawned", move |args| {
+            if let Some(node) = spawned_node(args) {
+                tx.send(MultiplayerDespawned { spawner, node });
+            }
+        });
+    }
+}
+
+/// Authority can be handed over at any time (`set_multiplayer_authority`), so it's
+/// re-read every frame; synchronizers are few, so this is a handful of FFI calls.
+pub(super) fn update_multiplayer_authority(
+    mut commands: Commands,
+    synchronizers: Query<&GodotNodeHandle, With<MultiplayerSynchronizerMarker>>,
+    current: Query<&MultiplayerAuthority>,
+    owners: Query<(), With<NetworkOwner>>,
+    node_index: Res<NodeEntityIndex>,
+    mut godot: GodotAccess,
+) {
+    for &handle in synchronizers.iter() {
+        let Some(synchronizer) = godot.try_get::<MultiplayerSynchronizer>(handle) else {
+            continue;
+        };
+        let Some(root) = synchronizer.get_node_or_null(&synchronizer.get_root_path()) else {
+            continue;
+        };
+        let Some(entity) = node_index.get(root.instance_id()) else {
+            continue;
+        };
+        let authority = MultiplayerAuthority {
+            peer: root.get_multiplayer_authority(),
+            is_local: root.is_multiplayer_authority(),
+        };
+        if current.get(entity).ok() != Some(&authority) {
+            commands.entity(entity).insert(authority);
+        }
+        if !owners.contains(entity) {
+            commands.entity(entity).insert((
+                NetworkOwner(authority.peer),
+                SyncedNetworkOwner(authority.peer),
+            ));
+        }
+    }
+}
+
+/// Reconcile [`NetworkOwner`] with the multiplayer authority of the entity's node.
+/// A changed component wins over a changed node, like the transform sync.
+pub(super) fn sync_network_owners(
+    mut owners: Query<(
+        Entity,
+        &GodotNodeHandle,
+        &mut NetworkOwner,
+        Option<&mut SyncedNetworkOwner>,
+    )>,
+    mut commands: Commands,
+    mut godot: GodotAccess,
+) {
+    for (entity, &handle, mut owner, synced) in owners.iter_mut() {
+        let Some(mut node) = godot.try_get::<Node>(handle) else {
+            continue;
+        };
+        let Some(mut synced) = synced else {
+            // Newly added by the user: the component is the intent.
+            if node.get_multiplayer_authority() != owner.0 {
+                node.set_multiplayer_authority(owner.0);
+            }
+            commands.entity(entity).insert(SyncedNetworkOwner(owner.0));
+            continue;
+        };
+        if owner.0 != synced.0 {
+            node.set_multiplayer_authority(owner.0);
+            synced.0 = owner.0;
+            continue;
+        }
+        let authority = node.get_multiplayer_authority();
+        if authority != synced.0 {
+            owner.0 = authority;
+            synced.0 = authority;
+        }
+    }
+}
+
+/// Add or remove [`HasAuthority`] where the owner or the local peer changed.
+pub(super) fn update_has_authority(
+    state: Res<NetworkState>,
+    owners: Query<(Entity, Ref<NetworkOwner>, Has<HasAuthority>)>,
+    orphaned: Query<Entity, (With<HasAuthority>, Without<NetworkOwner>)>,
+    mut commands: Commands,
+) {
+    let local = state.peer_id().unwrap_or(1);
+    for (entity, owner, has_authority) in owners.iter() {
+        if !state.is_changed() && !owner.is_changed() {
+            continue;
+        }
+        match (owner.0 == local, has_authority) {
+            (true, false) => {
+                commands.entity(entity).insert(HasAuthority);
+            }
+            (false, true) => {
+                commands.entity(entity).remove::<HasAuthority>();
+            }
+            _ => {}
+        }
+    }
+    for entity in orphaned.iter() {
+        commands.entity(entity).remove::<HasAuthority>();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_app::{App, Update};
+    use bevy_ecs::schedule::IntoScheduleConfigs;
+
+    #[derive(Component)]
+    struct Player;
+
+    #[derive(bevy_ecs::prelude::Resource, Default)]
+    struct Ran(bool);
+
+    #[test]
+    fn has_authority_follows_owner_and_local_peer() {
+        let mut app = App::new();
+        app.insert_resource(NetworkState::Connected { peer_id: 2 })
+            .init_resource::<Ran>()
+            .add_systems(
+                Update,
+                (
+                    update_has_authority,
+                    (|mut ran: bevy_ecs::system::ResMut<Ran>| ran.0 = true)
+                        .run_if(has_authority::<Player>()),
+                )
+                    .chain(),
+            );
+        let mine = app.world_mut().spawn((Player, NetworkOwner(2))).id();
+        let server = app.world_mut().spawn(NetworkOwner(1)).id();
+        app.update();
+        assert!(app.world().entity(mine).contains::<HasAuthority>());
+        assert!(!app.world().entity(server).contains::<HasAuthority>());
+        assert!(app.world().resource::<Ran>().0);
+
+        app.world_mut().get_mut::<NetworkOwner>(mine).unwrap().0 = 3;
+        app.world_mut().entity_mut(server).remove::<NetworkOwner>();
+        app.world_mut().entity_mut(server).insert(HasAuthority);
+        app.world_mut().resource_mut::<Ran>().0 = false;
+        app.update();
+        assert!(!app.world().entity(mine).contains::<HasAuthority>());
+        assert!(!app.world().entity(server).contains::<HasAuthority>());
+        assert!(!app.world().resource::<Ran>().0);
+
+        // Going offline makes this peer 1, the owner of server entities.
+        *app.world_mut().resource_mut::<NetworkState>() = NetworkState::Disconnected;
+        app.world_mut().entity_mut(server).insert(NetworkOwner(1));
+        app.update();
+        assert!(app.world().entity(server).contains::<HasAuthority>());
+    }
+}
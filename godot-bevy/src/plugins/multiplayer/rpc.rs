@@ -0,0 +1,448 @@
+//! Typed RPCs over Godot's `MultiplayerAPI`.
+//!
+//! Every peer adds a `BevyRpcRelay` node at `/root/BevyRpcRelay`. Sending a
+//! [`SendRpc<T>`] serializes `T` to JSON and calls an RPC method on the relay; the
+//! receiving relay hands `(sender, name, payload)` to Bevy, where the decoder
+//! registered by [`AddRpcAppExt::add_rpc`] turns it back into an [`RpcReceived<T>`].
+//!
+//! The name each type travels under is given at registration rather than taken
+//! from `std::any::type_name`, whose output changes between compiler versions and
+//! crate layouts: peers running different builds would silently drop each
+//! other's RPCs.
+
+use bevy_app::{App, Last};
+use bevy_ecs::message::{Message, MessageReader};
+use bevy_ecs::prelude::Resource;
+use bevy_ecs::system::{Res, ResMut};
+use bevy_ecs::world::World;
+use crossbeam_channel::{Receiver, Sender};
+use godot::classes::{INode, Node};
+use godot::meta::ToGodot;
+use godot::obj::{Base, InstanceId, NewAlloc, WithBaseField};
+use godot::prelude::{GString, GodotClass, godot_api};
+use parking_lot::Mutex;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use std::any::TypeId;
+use std::collections::HashMap;
+use std::marker::PhantomData;
+use tracing::{error, warn};
+
+use super::stats::NetCounters;
+use super::{PeerId, multiplayer_api};
+use crate::interop::GodotAccess;
+use crate::plugins::scene_tree::SceneTreeRef;
+use crate::watchers::scene_tree_watcher::exclude_from_mirror;
+
+const RELAY_NAME: &str = "BevyRpcRelay";
+
+/// A type that can be sent as an RPC. Implemented for every serde type.
+pub trait RpcMessage: Serialize + DeserializeOwned + Send + Sync + 'static {}
+
+impl<T> RpcMessage for T where T: Serialize + DeserializeOwned + Send + Sync + 'static {}
+
+/// Which peers an RPC goes to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RpcTarget {
+    /// Every connected peer except this one.
+    #[default]
+    All,
+    /// One peer. Targeting this peer's own id delivers locally.
+    Peer(PeerId),
+    /// The server (peer 1). Delivers locally when this peer is the server.
+    Server,
+    /// Every connected peer except the given one and this one.
+    AllExcept(PeerId),
+}
+
+impl RpcTarget {
+    fn peer_id(self) -> i64 {
+        match self {
+            RpcTarget::All => 0,
+            RpcTarget::Peer(peer) => peer as i64,
+            RpcTarget::Server => 1,
+            RpcTarget::AllExcept(peer) => -(peer as i64),
+        }
+    }
+}
+
+/// How an RPC is delivered, mirroring Godot's `TransferMode`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RpcTransfer {
+    /// Arrives exactly once, in order.
+    #[default]
+    Reliable,
+    /// May be dropped; late packets are discarded.
+    UnreliableOrdered,
+    /// May be dropped or arrive out of order.
+    Unreliable,
+}
+
+impl RpcTransfer {
+    fn method(self) -> &'static str {
+        match self {
+            RpcTransfer::Reliable => "bevy_rpc_reliable",
+            RpcTransfer::UnreliableOrdered => "bevy_rpc_unreliable_ordered",
+            RpcTransfer::Unreliable => "bevy_rpc_unreliable",
+        }
+    }
+}
+
+/// Write this message to send `message` to other peers. Sent in `Last`.
+#[derive(Debug, Clone)]
+pub struct SendRpc<T> {
+    pub target: RpcTarget,
+    pub transfer: RpcTransfer,
+    pub message: T,
+}
+
+impl<T: RpcMessage> Message for SendRpc<T> {}
+
+impl<T> SendRpc<T> {
+    pub fn to_all(message: T) -> Self {
+        Self::new(RpcTarget::All, message)
+    }
+
+    pub fn to_peer(peer: PeerId, message: T) -> Self {
+        Self::new(RpcTarget::Peer(peer), message)
+    }
+
+    pub fn to_server(message: T) -> Self {
+        Self::new(RpcTarget::Server, message)
+    }
+
+    pub fn new(target: RpcTarget, message: T) -> Self {
+        Self {
+            target,
+            transfer: RpcTransfer::Reliable,
+            message,
+        }
+    }
+
+    pub fn with_transfer(mut self, transfer: RpcTransfer) -> Self {
+        self.transfer = transfer;
+        self
+    }
+}
+
+/// An RPC that arrived from peer `from`. Written in `First`.
+#[derive(Debug, Clone)]
+pub struct RpcReceived<T> {
+    pub from: PeerId,
+    pub message: T,
+}
+
+impl<T: RpcMessage> Message for RpcReceived<T> {}
+
+pub trait AddRpcAppExt {
+    /// Register `T` as an RPC payload sent under `name`: adds the [`SendRpc<T>`]
+    /// and [`RpcReceived<T>`] messages and the system that sends them. Every peer
+    /// must register the same types under the same names, e.g. `"chat"`.
+    ///
+    /// # Panics
+    /// If `name` is already registered for a different type.
+    fn add_rpc<T: RpcMessage>(&mut self, name: &'static str) -> &mut Self;
+}
+
+impl AddRpcAppExt for App {
+    fn add_rpc<T: RpcMessage>(&mut self, name: &'static str) -> &mut Self {
+        ensure_rpc_channel(self);
+        let mut registry = self.world_mut().resource_mut::<RpcRegistry>();
+        if let Some((type_id, _)) = registry.decoders.get(name) {
+            assert!(
+                *type_id == TypeId::of::<T>(),
+                "RPC name {name:?} is already registered for another type"
+            );
+            return self;
+        }
+        registry
+            .decoders
+            .insert(name, (TypeId::of::<T>(), decode::<T>));
+        self.insert_resource(RpcName::<T>::new(name))
+            .add_message::<SendRpc<T>>()
+            .add_message::<RpcReceived<T>>()
+            .add_systems(Last, send_rpcs::<T>)
+    }
+}
+
+type Decoder = fn(&mut World, PeerId, &str) -> Result<(), serde_json::Error>;
+
+fn decode<T: RpcMessage>(
+    world: &mut World,
+    from: PeerId,
+    payload: &str,
+) -> Result<(), serde_json::Error> {
+    let message = serde_json::from_str::<T>(payload)?;
+    world.write_message(RpcReceived { from, message });
+    Ok(())
+}
+
+/// Payload decoders keyed by wire name, with the type each name belongs to.
+#[derive(Resource, Default)]
+struct RpcRegistry {
+    decoders: HashMap<&'static str, (TypeId, Decoder)>,
+}
+
+/// The wire name `T` was registered under.
+#[derive(Resource)]
+struct RpcName<T> {
+    name: &'static str,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T> RpcName<T> {
+    fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            _marker: PhantomData,
+        }
+    }
+}
+
+struct IncomingRpc {
+    from: PeerId,
+    kind: String,
+    payload: String,
+    /// Sent to ourselves, never touched the network.
+    local: bool,
+}
+
+#[derive(Resource, Clone)]
+pub(super) struct RpcInbox(Sender<IncomingRpc>);
+
+#[derive(Resource)]
+struct RpcInboxReceiver(Mutex<Receiver<IncomingRpc>>);
+
+/// The relay node, once spawned.
+#[derive(Resource, Default)]
+pub(super) struct RpcRelay(Option<InstanceId>);
+
+pub(super) fn ensure_rpc_channel(app: &mut App) {
+    if app.world().contains_resource::<RpcInbox>() {
+        return;
+    }
+    let (tx, rx) = crossbeam_channel::unbounded();
+    app.insert_resource(RpcInbox(tx))
+        .insert_resource(RpcInboxReceiver(Mutex::new(rx)))
+        .init_resource::<RpcRegistry>()
+        .init_resource::<RpcRelay>()
+        .init_resource::<NetCounters>();
+}
+
+/// Add the relay under the root, at the same path on every peer.
+pub(super) fn spawn_rpc_relay(
+    mut scene_tree: SceneTreeRef,
+    inbox: Res<RpcInbox>,
+    mut relay: ResMut<RpcRelay>,
+    _godot: GodotAccess,
+) {
+    let Some(mut root) = scene_tree.get().get_root() else {
+        return;
+    };
+    // A relay left by a previous app instance would push the new one off the path.
+    if let Some(mut stale) = root.get_node_or_null(RELAY_NAME) {
+        root.remove_child(&stale);
+        stale.queue_free();
+    }
+    let mut node = BevyRpcRelay::new_alloc();
+    node.bind_mut().inbox = Some(inbox.0.clone());
+    node.set_name(RELAY_NAME);
+    exclude_from_mirror(&mut node);
+    root.add_child(&node);
+    relay.0 = Some(node.instance_id());
+}
+
+pub(super) fn receive_rpcs(world: &mut World) {
+    let incoming: Vec<IncomingRpc> = match world.get_resource::<RpcInboxReceiver>() {
+        Some(receiver) => receiver.0.lock().try_iter().collect(),
+        None => return,
+    };
+    for rpc in incoming {
+        let decoder = world
+            .resource::<RpcRegistry>()
+            .decoders
+            .get_key_value(rpc.kind.as_str())
+            .map(|(kind, (_, decoder))| (*kind, *decoder));
+        let Some((kind, decoder)) = decoder else {
+            warn!(
+                "RPC {:?} from peer {} isn't registered here; register it with add_rpc on every peer",
+                rpc.kind, rpc.from
+            );
+            continue;
+        };
+        if !rpc.local {
+            world
+                .resource_mut::<NetCounters>()
+                .received(kind, kind.len() + rpc.payload.len());
+        }
+        if let Err(err) = decoder(world, rpc.from, &rpc.payload) {
+            error!(
+                "RPC {} from peer {} failed to decode: {err}",
+                rpc.kind, rpc.from
+            );
+        }
+    }
+}
+
+fn send_rpcs<T: RpcMessage>(
+    mut outgoing: MessageReader<SendRpc<T>>,
+    name: Res<RpcName<T>>,
+    relay: Res<RpcRelay>,
+    inbox: Res<RpcInbox>,
+    mut counters: ResMut<NetCounters>,
+    mut scene_tree: SceneTreeRef,
+    mut godot: GodotAccess,
+) {
+    if outgoing.is_empty() {
+        return;
+    }
+    let Some(mut node) = relay.0.and_then(|id| godot.try_get_instance_id::<Node>(id)) else {
+        warn!("SendRpc: the RPC relay isn't in the tree yet; dropping RPCs");
+        outgoing.clear();
+        return;
+    };
+    let Some(api) = multiplayer_api(&mut scene_tree) else {
+        outgoing.clear();
+        return;
+    };
+    let local = api.get_unique_id();
+    let kind = name.name;
+
+    for rpc in outgoing.read() {
+        let payload = match serde_json::to_string(&rpc.message) {
+            Ok(payload) => payload,
+            Err(err) => {
+                error!("SendRpc<{kind}>: failed to serialize: {err}");
+                continue;
+            }
+        };
+        let to_self = match rpc.target {
+            RpcTarget::Peer(peer) => peer == local,
+            RpcTarget::Server => api.is_server(),
+            RpcTarget::All | RpcTarget::AllExcept(_) => false,
+        };
+        if to_self {
+            let _ = inbox.0.send(IncomingRpc {
+                from: local,
+                kind: kind.to_string(),
+                payload,
+                local: true,
+            });
+            continue;
+        }
+        counters.sent(kind, kind.len() + payload.len());
+        node.rpc_id(
+            rpc.target.peer_id(),
+            rpc.transfer.method(),
+            &[kind.to_variant(), payload.to_variant()],
+        );
+    }
+}
+
+/// Receives RPCs for every registered type and forwards them to Bevy.
+#[derive(GodotClass)]
+#[class(base=Node)]
+pub(crate) struct BevyRpcRelay {
+    base: Base<Node>,
+    inbox: Option<Sender<IncomingRpc>>,
+}
+
+#[godot_api]
+impl INode for BevyRpcRelay {
+    fn init(base: Base<Node>) -> Self {
+        Self { base, inbox: None }
+    }
+}
+
+#[godot_api]
+impl BevyRpcRelay {
+    #[rpc(any_peer, reliable)]
+    fn bevy_rpc_reliable(&mut self, kind: GString, payload: GString) {
+        self.forward(kind, payload);
+    }
+
+    #[rpc(any_peer, unreliable_ordered)]
+    fn bevy_rpc_unreliable_ordered(&mut self, kind: GString, payload: GString) {
+        self.forward(kind, payload);
+    }
+
+    #[rpc(any_peer, unreliable)]
+    fn bevy_rpc_unreliable(&mut self, kind: GString, payload: GString) {
+        self.forward(kind, payload);
+    }
+
+    fn forward(&mut self, kind: GString, payload: GString) {
+        let from = self
+            .base()
+            .get_multiplayer()
+            .map(|api| api.get_remote_sender_id())
+            .unwrap_or(0);
+        if let Some(inbox) = &self.inbox {
+            let _ = inbox.send(IncomingRpc {
+                from,
+                kind: kind.to_string(),
+                payload: payload.to_string(),
+                local: false,
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_ecs::message::Messages;
+
+    type Chat = (String, u32);
+
+    #[test]
+    fn incoming_rpcs_decode_into_typed_messages() {
+        let mut app = App::new();
+        app.add_rpc::<Chat>("chat");
+        let inbox = app.world().resource::<RpcInbox>().clone();
+
+        let send = |kind: &str, payload: &str| {
+            inbox
+                .0
+                .send(IncomingRpc {
+                    from: 7,
+                    kind: kind.to_string(),
+                    payload: payload.to_string(),
+                    local: false,
+                })
+                .unwrap();
+        };
+        send("chat", r#"["hi",3]"#);
+        send("chat", "not json");
+        send("unregistered", "{}");
+        receive_rpcs(app.world_mut());
+
+        let received: Vec<_> = app
+            .world_mut()
+            .resource_mut::<Messages<RpcReceived<Chat>>>()
+            .drain()
+            .collect();
+        assert_eq!(
+            received.len(),
+            1,
+            "bad payloads and unknown types are dropped"
+        );
+        assert_eq!(received[0].from, 7);
+        assert_eq!(received[0].message, ("hi".to_string(), 3));
+    }
+
+    #[test]
+    #[should_panic(expected = "already registered for another type")]
+    fn rpc_names_are_unique_per_type() {
+        let mut app = App::new();
+        app.add_rpc::<Chat>("chat").add_rpc::<String>("chat");
+    }
+
+    #[test]
+    fn targets_map_to_godot_peer_ids() {
+        assert_eq!(RpcTarget::All.peer_id(), 0);
+        assert_eq!(RpcTarget::Server.peer_id(), 1);
+        assert_eq!(RpcTarget::Peer(42).peer_id(), 42);
+        assert_eq!(RpcTarget::AllExcept(42).peer_id(), -42);
+    }
+}
@@ -0,0 +1,248 @@
+//! Bandwidth and replication counters, published as [`NetStats`].
+
+use std::collections::{BTreeMap, HashMap};
+use std::time::Duration;
+
+use bevy_ecs::prelude::Resource;
+use bevy_ecs::query::With;
+use bevy_ecs::system::{Local, Query, Res, ResMut};
+use bevy_time::{Real, Time};
+use godot::classes::enet_connection::HostStatistic;
+use godot::classes::enet_packet_peer::PeerStatistic;
+use godot::classes::{ENetConnection, ENetMultiplayerPeer, MultiplayerApi};
+use godot::obj::Gd;
+
+use super::{HasAuthority, MultiplayerAuthority, NetworkOwner, PeerId, Predicted, multiplayer_api};
+use crate::plugins::scene_tree::SceneTreeRef;
+
+/// Network traffic and replication counts, averaged over the last
+/// [`NetStats::SAMPLE_INTERVAL`]. Shown in the inspector's diagnostics tab.
+///
+/// ```ignore
+/// fn report_traffic(stats: Res<NetStats>) {
+///     for (kind, channel) in &stats.channels {
+///         debug!("{kind}: {:.0} B/s out", channel.sent_bytes_per_sec);
+///     }
+/// }
+/// ```
+#[derive(Resource, Debug, Clone, Default, PartialEq)]
+pub struct NetStats {
+    /// Typed RPC traffic per payload type (see [`AddRpcAppExt`](super::AddRpcAppExt)),
+    /// keyed by type name. Bytes are the serialized payload handed to Godot, once
+    /// per send even when it goes to several peers, without transport overhead.
+    pub channels: BTreeMap<&'static str, ChannelStats>,
+    /// Everything the transport sent and received, headers and Godot's own
+    /// replication included. ENet peers only; zero for other peers.
+    pub transport: TransportStats,
+    /// Round-trip time to each connected peer. ENet peers only.
+    pub rtt: BTreeMap<PeerId, Duration>,
+    pub replication: ReplicationCounts,
+}
+
+impl NetStats {
+    /// How often the rates are refreshed.
+    pub const SAMPLE_INTERVAL: Duration = Duration::from_secs(1);
+
+    /// All RPC bytes sent per second, over every channel.
+    pub fn rpc_bytes_sent_per_sec(&self) -> f64 {
+        self.channels.values().map(|c| c.sent_bytes_per_sec).sum()
+    }
+
+    /// All RPC bytes received per second, over every channel.
+    pub fn rpc_bytes_received_per_sec(&self) -> f64 {
+        self.channels
+            .values()
+            .map(|c| c.received_bytes_per_sec)
+            .sum()
+    }
+}
+
+/// Per-second traffic of one RPC payload type.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ChannelStats {
+    pub sent_messages_per_sec: f64,
+    pub sent_bytes_per_sec: f64,
+    pub received_messages_per_sec: f64,
+    pub received_bytes_per_sec: f64,
+}
+
+/// Per-second traffic of the whole connection.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct TransportStats {
+    pub sent_bytes_per_sec: f64,
+    pub sent_packets_per_sec: f64,
+    pub received_bytes_per_sec: f64,
+    pub received_packets_per_sec: f64,
+}
+
+/// How many entities take part in replication.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ReplicationCounts {
+    /// Roots of a `MultiplayerSynchronizer` ([`MultiplayerAuthority`]).
+    pub synchronized: usize,
+    /// Entities with a [`NetworkOwner`].
+    pub owned: usize,
+    /// Entities this peer has authority over ([`HasAuthority`]).
+    pub local_authority: usize,
+    /// Entities this peer predicts ([`Predicted`]).
+    pub predicted: usize,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct ChannelCounts {
+    sent_messages: u64,
+    sent_bytes: u64,
+    received_messages: u64,
+    received_bytes: u64,
+}
+
+/// Raw RPC counts since the last sample.
+#[derive(Resource, Debug, Default)]
+pub(super) struct NetCounters {
+    channels: HashMap<&'static str, ChannelCounts>,
+}
+
+impl NetCounters {
+    pub(super) fn sent(&mut self, kind: &'static str, bytes: usize) {
+        let counts = self.channels.entry(kind).or_default();
+        counts.sent_messages += 1;
+        counts.sent_bytes += bytes as u64;
+    }
+
+    pub(super) fn received(&mut self, kind: &'static str, bytes: usize) {
+        let counts = self.channels.entry(kind).or_default();
+        counts.received_messages += 1;
+        counts.received_bytes += bytes as u64;
+    }
+
+    /// Turn the counts into rates over `elapsed` and start over. Channels seen
+    /// before stay listed at zero once they go quiet.
+    fn drain_into(
+        &mut self,
+        channels: &mut BTreeMap<&'static str, ChannelStats>,
+        elapsed: Duration,
+    ) {
+        let secs = elapsed.as_secs_f64().max(f64::EPSILON);
+        for stats in channels.values_mut() {
+            *stats = ChannelStats::default();
+        }
+        for (kind, counts) in self.channels.drain() {
+            channels.insert(
+                kind,
+                ChannelStats {
+                    sent_messages_per_sec: counts.sent_messages as f64 / secs,
+                    sent_bytes_per_sec: counts.sent_bytes as f64 / secs,
+                    received_messages_per_sec: counts.received_messages as f64 / secs,
+                    received_bytes_per_sec: counts.received_bytes as f64 / secs,
+                },
+            );
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(super) fn sample_net_stats(
+    mut stats: ResMut<NetStats>,
+    mut counters: ResMut<NetCounters>,
+    time: Option<Res<Time<Real>>>,
+    mut last_sample: Local<Option<Duration>>,
+    synchronized: Query<(), With<MultiplayerAuthority>>,
+    owned: Query<(), With<NetworkOwner>>,
+    local_authority: Query<(), With<HasAuthority>>,
+    predicted: Query<(), With<Predicted>>,
+    mut scene_tree: SceneTreeRef,
+) {
+    let now = time.map(|time| time.elapsed()).unwrap_or_default();
+    let last = *last_sample.get_or_insert(now);
+    let elapsed = now.saturating_sub(last);
+    if elapsed < NetStats::SAMPLE_INTERVAL {
+        return;
+    }
+    *last_sample = Some(now);
+    crate::profile_scope!("sample_net_stats");
+
+    let stats = &mut *stats;
+    counters.drain_into(&mut stats.channels, elapsed);
+    stats.replication = ReplicationCounts {
+        synchronized: synchronized.iter().count(),
+        owned: owned.iter().count(),
+        local_authority: local_authority.iter().count(),
+        predicted: predicted.iter().count(),
+    };
+
+    stats.transport = TransportStats::default();
+    stats.rtt.clear();
+    let Some(api) = multiplayer_api(&mut scene_tree) else {
+        return;
+    };
+    let Some(enet) = enet_peer(&api) else {
+        return;
+    };
+    if let Some(mut host) = enet.get_host() {
+        stats.transport = pop_transport_stats(&mut host, elapsed);
+    }
+    for peer in api.get_peers().as_slice() {
+        if let Some(packet_peer) = enet.get_peer(*peer) {
+            let rtt_ms = packet_peer.get_statistic(PeerStatistic::ROUND_TRIP_TIME);
+            stats
+                .rtt
+                .insert(*peer, Duration::from_secs_f64(rtt_ms.max(0.0) / 1000.0));
+        }
+    }
+}
+
+fn enet_peer(api: &Gd<MultiplayerApi>) -> Option<Gd<ENetMultiplayerPeer>> {
+    api.get_multiplayer_peer()?
+        .try_cast::<ENetMultiplayerPeer>()
+        .ok()
+}
+
+/// ENet resets each host statistic when it is popped, so these are the totals
+/// since the previous sample.
+fn pop_transport_stats(host: &mut Gd<ENetConnection>, elapsed: Duration) -> TransportStats {
+    let secs = elapsed.as_secs_f64().max(f64::EPSILON);
+    let mut rate = |statistic| host.pop_statistic(statistic) / secs;
+    TransportStats {
+        sent_bytes_per_sec: rate(HostStatistic::TOTAL_SENT_DATA),
+        sent_packets_per_sec: rate(HostStatistic::TOTAL_SENT_PACKETS),
+        received_bytes_per_sec: rate(HostStatistic::TOTAL_RECEIVED_DATA),
+        received_packets_per_sec: rate(HostStatistic::TOTAL_RECEIVED_PACKETS),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counters_become_per_second_rates() {
+        let mut counters = NetCounters::default();
+        counters.sent("game::Chat", 100);
+        counters.sent("game::Chat", 50);
+        counters.received("game::Chat", 20);
+        counters.received("game::Input", 8);
+
+        let mut channels = BTreeMap::new();
+        counters.drain_into(&mut channels, Duration::from_millis(500));
+        assert_eq!(
+            channels["game::Chat"],
+            ChannelStats {
+                sent_messages_per_sec: 4.0,
+                sent_bytes_per_sec: 300.0,
+                received_messages_per_sec: 2.0,
+                received_bytes_per_sec: 40.0,
+            }
+        );
+        assert_eq!(channels["game::Input"].received_bytes_per_sec, 16.0);
+
+        counters.sent("game::Input", 4);
+        counters.drain_into(&mut channels, Duration::from_secs(1));
+        assert_eq!(
+            channels["game::Chat"],
+            ChannelStats::default(),
+            "quiet channels drop to zero"
+        );
+        assert_eq!(channels["game::Input"].sent_bytes_per_sec, 4.0);
+        assert_eq!(channels["game::Input"].received_bytes_per_sec, 0.0);
+    }
+}
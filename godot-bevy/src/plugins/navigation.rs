@@ -0,0 +1,733 @@
+//! Pathfinding for ECS-driven AI through `NavigationAgent2D`/`3D` nodes.
+//!
+//! With [`GodotNavigationPlugin`], an entity with a [`NavAgent`] gets a navigation
+//! agent: its own node if that is a `NavigationAgent2D`/`3D`, else the first such
+//! child, else one created as a child. Inserting a [`NavTarget`] asks the agent for
+//! a path, and every physics tick (in `FixedPreUpdate`) the entity's
+//! [`NextPathPosition`] is updated, ready for movement systems in `FixedUpdate`:
+//!
+//! ```ignore
+//! fn chase(mut commands: Commands, enemies: Query<Entity, With<Enemy>>, player: Single<&Transform, With<Player>>) {
+//!     for enemy in &enemies {
+//!         commands.entity(enemy).insert(NavTarget(player.translation));
+//!     }
+//! }
+//!
+//! fn walk(mut enemies: Query<(&mut Transform, &NextPathPosition), Without<NavigationFinished>>, time: Res<Time>) {
+//!     for (mut transform, next) in &mut enemies {
+//!         let step = (next.0 - transform.translation).clamp_length_max(SPEED * time.delta_secs());
+//!         transform.translation += step;
+//!     }
+//! }
+//! ```
+//!
+//! Positions are global, as in Godot's navigation API; in 2D only `x` and `y` are
+//! used. [`PathUpdated`] is triggered when the agent computes a new path, and
+//! [`NavTargetReached`] when it gets there; the entity then carries
+//! [`NavigationFinished`] until the next target.
+//!
+//! # Avoidance
+//!
+//! With avoidance enabled, write the velocity the entity wants as [`NavVelocity`];
+//! the avoidance-adjusted one comes back as [`SafeVelocity`] on the next frame.
+//!
+//! # Obstacles
+//!
+//! A [`DynamicObstacle`] gives a moving entity a `NavigationObstacle2D`/`3D` that
+//! avoiding agents steer around, found or created like the agent. Its radius
+//! fits the entity's `CollisionShape2D`/`3D` children unless one is given, and
+//! its velocity follows the entity's movement every physics tick:
+//!
+//! ```ignore
+//! commands.spawn((GodotScene::from_path("res://boulder.tscn"), DynamicObstacle::new()));
+//! ```
+//!
+//! Circles, rectangles, capsules, spheres, boxes and cylinders are fitted; set
+//! the radius for other shapes.
+//!
+//! Requires the `navigation` feature: gdext only generates Godot's navigation
+//! classes with its experimental API enabled.
+
+use bevy_app::{App, FixedPostUpdate, FixedPreUpdate, Plugin};
+use bevy_ecs::change_detection::{DetectChanges, DetectChangesMut};
+use bevy_ecs::component::Component;
+use bevy_ecs::entity::Entity;
+use bevy_ecs::event::EntityEvent;
+use bevy_ecs::observer::On;
+use bevy_ecs::query::{Changed, Has, With};
+use bevy_ecs::schedule::IntoScheduleConfigs;
+use bevy_ecs::system::{Commands, Query, Res};
+use bevy_math::{Vec2, Vec3};
+use bevy_time::Time;
+use godot::builtin::{Variant, Vector2, Vector3};
+use godot::classes::{
+    BoxShape3D, CapsuleShape2D, CapsuleShape3D, CircleShape2D, CollisionShape2D, CollisionShape3D,
+    CylinderShape3D, NavigationAgent2D, NavigationAgent3D, NavigationObstacle2D,
+    NavigationObstacle3D, Node, Node3D, RectangleShape2D, SphereShape3D,
+};
+use godot::obj::{Gd, InstanceId, NewAlloc};
+use godot::prelude::Callable;
+use tracing::warn;
+
+use crate::interop::{GodotAccess, GodotNodeHandle};
+use crate::plugins::event_bridge::GodotEventSender;
+use crate::watchers::scene_tree_watcher::exclude_from_mirror;
+
+/// Plugin connecting [`NavAgent`]s to navigation agent nodes. See the
+/// [module docs](self).
+#[derive(Default)]
+pub struct GodotNavigationPlugin;
+
+impl Plugin for GodotNavigationPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_observer(on_path_changed)
+            .add_observer(on_target_reached)
+            .add_observer(on_velocity_computed)
+            .add_systems(
+                FixedPreUpdate,
+                (
+                    attach_nav_agents,
+                    apply_nav_targets,
+                    update_next_path_positions,
+                )
+                    .chain(),
+            )
+            .add_systems(
+                FixedPreUpdate,
+                (attach_obstacles, update_obstacle_velocities).chain(),
+            )
+            .add_systems(FixedPostUpdate, apply_nav_velocities);
+    }
+}
+
+#[cfg(debug_assertions)]
+crate::inventory::submit! {
+    crate::watchdog::PluginRequirement::new::<NavAgent, GodotNavigationPlugin>()
+}
+
+/// Navigate this entity. `None` settings keep the agent node's own, so an agent
+/// set up in the editor is left as authored.
+#[derive(Component, Debug, Default, Clone, PartialEq)]
+pub struct NavAgent {
+    pub max_speed: Option<f32>,
+    /// Avoidance radius.
+    pub radius: Option<f32>,
+    /// Distance at which the target counts as reached.
+    pub target_desired_distance: Option<f32>,
+    /// Distance at which a path point counts as passed.
+    pub path_desired_distance: Option<f32>,
+    pub avoidance: Option<bool>,
+    agent: Option<InstanceId>,
+    path_changed: bool,
+}
+
+impl NavAgent {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_max_speed(mut self, max_speed: f32) -> Self {
+        self.max_speed = Some(max_speed);
+        self
+    }
+
+    pub fn with_radius(mut self, radius: f32) -> Self {
+        self.radius = Some(radius);
+        self
+    }
+
+    pub fn with_target_distance(mut self, distance: f32) -> Self {
+        self.target_desired_distance = Some(distance);
+        self
+    }
+
+    pub fn with_path_distance(mut self, distance: f32) -> Self {
+        self.path_desired_distance = Some(distance);
+        self
+    }
+
+    pub fn with_avoidance(mut self) -> Self {
+        self.avoidance = Some(true);
+        self
+    }
+
+    /// The agent node's instance id, once it has been found or created.
+    pub fn agent(&self) -> Option<InstanceId> {
+        self.agent
+    }
+}
+
+/// Where a [`NavAgent`] should go, in global coordinates.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct NavTarget(pub Vec3);
+
+/// The next point on the path to the [`NavTarget`], updated every physics tick.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct NextPathPosition(pub Vec3);
+
+/// The agent has reached its target or can get no closer.
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct NavigationFinished;
+
+/// The velocity an avoidance-enabled agent wants to move at.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct NavVelocity(pub Vec3);
+
+/// The agent's velocity after avoidance, in answer to [`NavVelocity`].
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct SafeVelocity(pub Vec3);
+
+/// Make this entity a moving obstacle for avoiding agents. See the
+/// [module docs](self#obstacles).
+#[derive(Component, Debug, Default, Clone, PartialEq)]
+pub struct DynamicObstacle {
+    /// Avoidance radius; `None` fits the collision shapes.
+    pub radius: Option<f32>,
+    obstacle: Option<InstanceId>,
+    last_position: Option<Vec3>,
+}
+
+impl DynamicObstacle {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_radius(mut self, radius: f32) -> Self {
+        self.radius = Some(radius);
+        self
+    }
+
+    /// The obstacle node's instance id, once it has been found or created.
+    pub fn obstacle(&self) -> Option<InstanceId> {
+        self.obstacle
+    }
+}
+
+/// The agent computed a new path, in global coordinates.
+#[derive(EntityEvent, Debug, Clone, PartialEq)]
+pub struct PathUpdated {
+    pub entity: Entity,
+    pub path: Vec<Vec3>,
+}
+
+/// The agent reached its [`NavTarget`].
+#[derive(EntityEvent, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NavTargetReached {
+    pub entity: Entity,
+}
+
+/// `path_changed` from the agent node; the path is read on the next tick.
+#[derive(EntityEvent, Debug, Clone, Copy)]
+struct PathChanged {
+    entity: Entity,
+}
+
+/// `velocity_computed` from the agent node.
+#[derive(EntityEvent, Debug, Clone, Copy)]
+struct VelocityComputed {
+    entity: Entity,
+    velocity: Vec3,
+}
+
+/// A `NavigationAgent2D` or `NavigationAgent3D`, which share their API but no
+/// base class.
+enum Agent {
+    TwoD(Gd<NavigationAgent2D>),
+    ThreeD(Gd<NavigationAgent3D>),
+}
+
+impl Agent {
+    fn get(godot: &mut GodotAccess, id: InstanceId) -> Option<Self> {
+        godot
+            .try_get_instance_id::<NavigationAgent2D>(id)
+            .map(Self::TwoD)
+            .or_else(|| {
+                godot
+                    .try_get_instance_id::<NavigationAgent3D>(id)
+                    .map(Self::ThreeD)
+            })
+    }
+
+    fn from_node(node: Gd<Node>) -> Option<Self> {
+        match node.try_cast::<NavigationAgent2D>() {
+            Ok(agent) => Some(Self::TwoD(agent)),
+            Err(node) => node.try_cast::<NavigationAgent3D>().ok().map(Self::ThreeD),
+        }
+    }
+
+    fn node(&self) -> Gd<Node> {
+        match self {
+            Self::TwoD(agent) => agent.clone().upcast(),
+            Self::ThreeD(agent) => agent.clone().upcast(),
+        }
+    }
+
+    fn configure(&mut self, settings: &NavAgent) {
+        macro_rules! configure {
+            ($agent:expr) => {{
+                if let Some(max_speed) = settings.max_speed {
+                    $agent.set_max_speed(max_speed);
+                }
+                if let Some(radius) = settings.radius {
+                    $agent.set_radius(radius);
+                }
+                if let Some(distance) = settings.target_desired_distance {
+                    $agent.set_target_desired_distance(distance);
+                }
+                if let Some(distance) = settings.path_desired_distance {
+                    $agent.set_path_desired_distance(distance);
+                }
+                if let Some(avoidance) = settings.avoidance {
+                    $agent.set_avoidance_enabled(avoidance);
+                }
+            }};
+        }
+        match self {
+            Self::TwoD(agent) => configure!(agent),
+            Self::ThreeD(agent) => configure!(agent),
+        }
+    }
+
+    fn set_target(&mut self, target: Vec3) {
+        match self {
+            Self::TwoD(agent) => agent.set_target_position(Vector2::new(target.x, target.y)),
+            Self::ThreeD(agent) => {
+                agent.set_target_position(Vector3::new(target.x, target.y, target.z))
+            }
+        }
+    }
+
+    fn set_velocity(&mut self, velocity: Vec3) {
+        match self {
+            Self::TwoD(agent) => agent.set_velocity(Vector2::new(velocity.x, velocity.y)),
+            Self::ThreeD(agent) => {
+                agent.set_velocity(Vector3::new(velocity.x, velocity.y, velocity.z))
+            }
+        }
+    }
+
+    /// Advances the agent along its path, so call once per tick.
+    fn next_path_position(&mut self) -> Vec3 {
+        match self {
+            Self::TwoD(agent) => {
+                let next = agent.get_next_path_position();
+                Vec3::new(next.x, next.y, 0.0)
+            }
+            Self::ThreeD(agent) => {
+                let next = agent.get_next_path_position();
+                Vec3::new(next.x, next.y, next.z)
+            }
+        }
+    }
+
+    fn is_finished(&mut self) -> bool {
+        match self {
+            Self::TwoD(agent) => agent.is_navigation_finished(),
+            Self::ThreeD(agent) => agent.is_navigation_finished(),
+        }
+    }
+
+    fn path(&self) -> Vec<Vec3> {
+        match self {
+            Self::TwoD(agent) => agent
+                .get_current_navigation_path()
+                .as_slice()
+                .iter()
+                .map(|point| Vec3::new(point.x, point.y, 0.0))
+                .collect(),
+            Self::ThreeD(agent) => agent
+                .get_current_navigation_path()
+                .as_slice()
+                .iter()
+                .map(|point| Vec3::new(point.x, point.y, point.z))
+                .collect(),
+        }
+    }
+}
+
+/// A `NavigationObstacle2D` or `NavigationObstacle3D`.
+enum Obstacle {
+    TwoD(Gd<NavigationObstacle2D>),
+    ThreeD(Gd<NavigationObstacle3D>),
+}
+
+impl Obstacle {
+    fn get(godot: &mut GodotAccess, id: InstanceId) -> Option<Self> {
+        godot
+            .try_get_instance_id::<NavigationObstacle2D>(id)
+            .map(Self::TwoD)
+            .or_else(|| {
+                godot
+                    .try_get_instance_id::<NavigationObstacle3D>(id)
+                    .map(Self::ThreeD)
+            })
+    }
+
+    fn from_node(node: Gd<Node>) -> Option<Self> {
+        match node.try_cast::<NavigationObstacle2D>() {
+            Ok(obstacle) => Some(Self::TwoD(obstacle)),
+            Err(node) => node
+                .try_cast::<NavigationObstacle3D>()
+                .ok()
+                .map(Self::ThreeD),
+        }
+    }
+
+    fn node(&self) -> Gd<Node> {
+        match self {
+            Self::TwoD(obstacle) => obstacle.clone().upcast(),
+            Self::ThreeD(obstacle) => obstacle.clone().upcast(),
+        }
+    }
+
+    fn set_radius(&mut self, radius: f32) {
+        match self {
+            Self::TwoD(obstacle) => obstacle.set_radius(radius),
+            Self::ThreeD(obstacle) => obstacle.set_radius(radius),
+        }
+    }
+
+    fn set_velocity(&mut self, velocity: Vec3) {
+        match self {
+            Self::TwoD(obstacle) => obstacle.set_velocity(Vector2::new(velocity.x, velocity.y)),
+            Self::ThreeD(obstacle) => {
+                obstacle.set_velocity(Vector3::new(velocity.x, velocity.y, velocity.z))
+            }
+        }
+    }
+
+    fn global_position(&self) -> Vec3 {
+        match self {
+            Self::TwoD(obstacle) => {
+                let position = obstacle.get_global_position();
+                Vec3::new(position.x, position.y, 0.0)
+            }
+            Self::ThreeD(obstacle) => {
+                let position = obstacle.get_global_position();
+                Vec3::new(position.x, position.y, position.z)
+            }
+        }
+    }
+}
+
+fn attach_nav_agents(
+    mut agents: Query<(Entity, &GodotNodeHandle, &mut NavAgent)>,
+    sender: Res<GodotEventSender>,
+    mut godot: GodotAccess,
+) {
+    for (entity, handle, mut nav) in &mut agents {
+        if nav.agent.is_some_and(|id| id.lookup_validity()) {
+            continue;
+        }
+        let Some(node) = godot.try_get::<Node>(*handle) else {
+            continue;
+        };
+        let mut agent = find_or_create_agent(node);
+        agent.configure(&nav);
+        connect_agent(&mut agent.node(), entity, &sender);
+        nav.agent = Some(agent.node().instance_id());
+        nav.path_changed = false;
+    }
+}
+
+fn find_or_create_agent(mut node: Gd<Node>) -> Agent {
+    if let Some(agent) = Agent::from_node(node.clone()) {
+        return agent;
+    }
+    if let Some(agent) = node.get_children().iter_shared().find_map(Agent::from_node) {
+        return agent;
+    }
+    let agent = if node.clone().try_cast::<Node3D>().is_ok() {
+        Agent::ThreeD(NavigationAgent3D::new_alloc())
+    } else {
+        Agent::TwoD(NavigationAgent2D::new_alloc())
+    };
+    let mut child = agent.node();
+    child.set_name("NavAgent");
+    exclude_from_mirror(&mut child);
+    node.add_child(&child);
+    agent
+}
+
+fn connect_agent(agent: &mut Gd<Node>, entity: Entity, sender: &GodotEventSender) {
+    let tx = sender.clone();
+    agent.connect(
+        "path_changed",
+        &Callable::from_fn("nav_path_changed", move |_| {
+            tx.send(PathChanged { entity });
+            Variant::nil()
+        }),
+    );
+    let tx = sender.clone();
+    agent.connect(
+        "target_reached",
+        &Callable::from_fn("nav_target_reached", move |_| {
+            tx.send(NavTargetReached { entity });
+            Variant::nil()
+        }),
+    );
+    let tx = sender.clone();
+    agent.connect(
+        "velocity_computed",
+        &Callable::from_fn("nav_velocity_computed", move |args| {
+            let velocity = args.first().and_then(|arg| {
+                arg.try_to::<Vector3>()
+                    .map(|v| Vec3::new(v.x, v.y, v.z))
+                    .or_else(|_| arg.try_to::<Vector2>().map(|v| Vec3::new(v.x, v.y, 0.0)))
+                    .ok()
+            });
+            if let Some(velocity) = velocity {
+                tx.send(VelocityComputed { entity, velocity });
+            }
+            Variant::nil()
+        }),
+    );
+}
+
+fn apply_nav_targets(
+    targets: Query<(Entity, &NavAgent, &NavTarget), Changed<NavTarget>>,
+    mut commands: Commands,
+    mut godot: GodotAccess,
+) {
+    for (entity, nav, target) in &targets {
+        let Some(mut agent) = nav.agent.and_then(|id| Agent::get(&mut godot, id)) else {
+            warn!("NavTarget on {entity}, which has no navigation agent yet");
+            continue;
+        };
+        agent.set_target(target.0);
+        commands.entity(entity).remove::<NavigationFinished>();
+    }
+}
+
+fn update_next_path_positions(
+    mut agents: Query<(Entity, &mut NavAgent, Has<NavigationFinished>), With<NavTarget>>,
+    mut commands: Commands,
+    mut godot: GodotAccess,
+) {
+    for (entity, mut nav, was_finished) in &mut agents {
+        let Some(mut agent) = nav.agent.and_then(|id| Agent::get(&mut godot, id)) else {
+            continue;
+        };
+        let next = agent.next_path_position();
+        let finished = agent.is_finished();
+        let mut entity_commands = commands.entity(entity);
+        entity_commands.insert(NextPathPosition(next));
+        if finished && !was_finished {
+            entity_commands.insert(NavigationFinished);
+        }
+        if std::mem::take(&mut nav.path_changed) {
+            commands.trigger(PathUpdated {
+                entity,
+                path: agent.path(),
+            });
+        }
+    }
+}
+
+fn apply_nav_velocities(
+    velocities: Query<(&NavAgent, &NavVelocity), Changed<NavVelocity>>,
+    mut godot: GodotAccess,
+) {
+    for (nav, velocity) in &velocities {
+        if let Some(mut agent) = nav.agent.and_then(|id| Agent::get(&mut godot, id)) {
+            agent.set_velocity(velocity.0);
+        }
+    }
+}
+
+fn attach_obstacles(
+    mut obstacles: Query<(&GodotNodeHandle, &mut DynamicObstacle)>,
+    mut godot: GodotAccess,
+) {
+    for (handle, mut settings) in &mut obstacles {
+        let attached = settings.obstacle.filter(|id| id.lookup_validity());
+        if attached.is_some() && !settings.is_changed() {
+            continue;
+        }
+        let mut obstacle = match attached.and_then(|id| Obstacle::get(&mut godot, id)) {
+            Some(obstacle) => obstacle,
+            None => {
+                let Some(node) = godot.try_get::<Node>(*handle) else {
+                    continue;
+                };
+                find_or_create_obstacle(node)
+            }
+        };
+        let fitted = obstacle
+            .node()
+            .get_parent()
+            .and_then(|parent| fit_radius(&parent));
+        if let Some(radius) = settings.radius.or(fitted) {
+            obstacle.set_radius(radius);
+        }
+        let settings = settings.bypass_change_detection();
+        settings.obstacle = Some(obstacle.node().instance_id());
+        settings.last_position = None;
+    }
+}
+
+fn find_or_create_obstacle(mut node: Gd<Node>) -> Obstacle {
+    if let Some(obstacle) = Obstacle::from_node(node.clone()) {
+        return obstacle;
+    }
+    if let Some(obstacle) = node
+        .get_children()
+        .iter_shared()
+        .find_map(Obstacle::from_node)
+    {
+        return obstacle;
+    }
+    let obstacle = if node.clone().try_cast::<Node3D>().is_ok() {
+        Obstacle::ThreeD(NavigationObstacle3D::new_alloc())
+    } else {
+        Obstacle::TwoD(NavigationObstacle2D::new_alloc())
+    };
+    let mut child = obstacle.node();
+    child.set_name("NavObstacle");
+    exclude_from_mirror(&mut child);
+    node.add_child(&child);
+    obstacle
+}
+
+/// Radius of the circle around `node` enclosing its collision shapes, in the
+/// avoidance plane (`x`/`z` in 3D).
+fn fit_radius(node: &Gd<Node>) -> Option<f32> {
+    node.get_children()
+        .iter_shared()
+        .filter_map(|child| match child.try_cast::<CollisionShape2D>() {
+            Ok(shape) => shape_radius_2d(&shape),
+            Err(child) => child
+                .try_cast::<CollisionShape3D>()
+                .ok()
+                .and_then(|shape| shape_radius_3d(&shape)),
+        })
+        .reduce(f32::max)
+}
+
+fn shape_radius_2d(node: &Gd<CollisionShape2D>) -> Option<f32> {
+    let shape = node.get_shape()?;
+    let radius = if let Ok(circle) = shape.clone().try_cast::<CircleShape2D>() {
+        circle.get_radius()
+    } else if let Ok(rectangle) = shape.clone().try_cast::<RectangleShape2D>() {
+        let size = rectangle.get_size();
+        Vec2::new(size.x, size.y).length() / 2.0
+    } else if let Ok(capsule) = shape.try_cast::<CapsuleShape2D>() {
+        capsule.get_radius().max(capsule.get_height() / 2.0)
+    } else {
+        return None;
+    };
+    let scale = node.get_scale();
+    let offset = node.get_position();
+    Some(enclosing_radius(
+        Vec2::new(offset.x, offset.y),
+        radius,
+        scale.x.abs().max(scale.y.abs()),
+    ))
+}
+
+fn shape_radius_3d(node: &Gd<CollisionShape3D>) -> Option<f32> {
+    let shape = node.get_shape()?;
+    let radius = if let Ok(sphere) = shape.clone().try_cast::<SphereShape3D>() {
+        sphere.get_radius()
+    } else if let Ok(cube) = shape.clone().try_cast::<BoxShape3D>() {
+        let size = cube.get_size();
+        Vec2::new(size.x, size.z).length() / 2.0
+    } else if let Ok(capsule) = shape.clone().try_cast::<CapsuleShape3D>() {
+        capsule.get_radius()
+    } else if let Ok(cylinder) = shape.try_cast::<CylinderShape3D>() {
+        cylinder.get_radius()
+    } else {
+        return None;
+    };
+    let scale = node.get_scale();
+    let offset = node.get_position();
+    Some(enclosing_radius(
+        Vec2::new(offset.x, offset.z),
+        radius,
+        scale.x.abs().max(scale.z.abs()),
+    ))
+}
+
+/// Radius around the origin enclosing a circle of `radius`, scaled by `scale`,
+/// centred at `offset`.
+fn enclosing_radius(offset: Vec2, radius: f32, scale: f32) -> f32 {
+    offset.length() + radius * scale
+}
+
+fn update_obstacle_velocities(
+    time: Res<Time>,
+    mut obstacles: Query<&mut DynamicObstacle>,
+    mut godot: GodotAccess,
+) {
+    let delta = time.delta_secs();
+    for mut settings in &mut obstacles {
+        let Some(mut obstacle) = settings
+            .obstacle
+            .and_then(|id| Obstacle::get(&mut godot, id))
+        else {
+            continue;
+        };
+        let position = obstacle.global_position();
+        let settings = settings.bypass_change_detection();
+        if let Some(last) = settings.last_position
+            && delta > 0.0
+        {
+            obstacle.set_velocity((position - last) / delta);
+        }
+        settings.last_position = Some(position);
+    }
+}
+
+fn on_path_changed(changed: On<PathChanged>, mut agents: Query<&mut NavAgent>) {
+    if let Ok(mut nav) = agents.get_mut(changed.entity) {
+        nav.path_changed = true;
+    }
+}
+
+fn on_target_reached(reached: On<NavTargetReached>, mut commands: Commands) {
+    if let Ok(mut entity) = commands.get_entity(reached.entity) {
+        entity.insert(NavigationFinished);
+    }
+}
+
+fn on_velocity_computed(computed: On<VelocityComputed>, mut commands: Commands) {
+    if let Ok(mut entity) = commands.get_entity(computed.entity) {
+        entity.insert(SafeVelocity(computed.velocity));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_ecs::world::World;
+
+    #[test]
+    fn agent_signals_update_components() {
+        let mut world = World::new();
+        world.add_observer(on_path_changed);
+        world.add_observer(on_target_reached);
+        world.add_observer(on_velocity_computed);
+        let entity = world.spawn(NavAgent::new().with_avoidance()).id();
+
+        world.trigger(PathChanged { entity });
+        world.trigger(NavTargetReached { entity });
+        world.trigger(VelocityComputed {
+            entity,
+            velocity: Vec3::X,
+        });
+        world.flush();
+
+        assert!(world.get::<NavAgent>(entity).unwrap().path_changed);
+        assert!(world.entity(entity).contains::<NavigationFinished>());
+        assert_eq!(
+            world.get::<SafeVelocity>(entity),
+            Some(&SafeVelocity(Vec3::X))
+        );
+    }
+
+    #[test]
+    fn enclosing_radius_covers_offset_and_scaled_shape() {
+        assert_eq!(enclosing_radius(Vec2::ZERO, 2.0, 1.0), 2.0);
+        assert_eq!(enclosing_radius(Vec2::new(3.0, 4.0), 1.0, 2.0), 7.0);
+    }
+}
@@ -0,0 +1,165 @@
+//! Badges in the editor's Scene dock showing which nodes of the running game
+//! have entities, and what components those entities have.
+//!
+//! Requires the `editor_badges` feature. While the game runs from the editor,
+//! [`GodotNodeBadgesPlugin`] sends the mirrored nodes to the godot-bevy editor
+//! plugin twice a second, over the same debugger connection as the entity
+//! inspector. The editor then shows each node of the edited scene with an
+//! entity as `Player  [12v1]`, with the entity's components in the tooltip:
+//!
+//! ```ignore
+//! #[bevy_app]
+//! fn build_app(app: &mut App) {
+//!     app.add_plugins(GodotDefaultPlugins);
+//!     #[cfg(debug_assertions)]
+//!     app.add_plugins(GodotNodeBadgesPlugin);
+//! }
+//! ```
+//!
+//! Nodes are matched by their path from the current scene, so the badges show
+//! on the scene that is open in the editor when it is also the one running.
+//! Nothing is sent when the game isn't run from the editor.
+//!
+//! # Protocol
+//!
+//! One `bevy:node_badges` message, sent only when a badge changed. Its data is
+//! an array with one `[path, entity_bits, components]` entry per mirrored node:
+//! `path` is relative to the current scene (`"."` for its root) or absolute for
+//! nodes outside it, `entity_bits` is [`Entity::to_bits`], and `components` is
+//! a `PackedStringArray` of short type names, sorted.
+
+use bevy_app::{App, Last, Plugin};
+use bevy_ecs::entity::Entity;
+use bevy_ecs::system::Local;
+use bevy_ecs::world::World;
+use bevy_time::{Real, Time};
+use godot::classes::{EngineDebugger, Node};
+use godot::prelude::*;
+
+use crate::interop::GodotNodeHandle;
+
+/// Seconds between two checks for changed badges.
+const SEND_INTERVAL: f32 = 0.5;
+
+/// Components every mirrored entity has, or that the Scene dock already shows.
+const HIDDEN_COMPONENTS: [&str; 3] = ["GodotNodeHandle", "GodotChildOf", "GodotChildren"];
+
+/// Plugin sending node badges to the editor. See the [module docs](self).
+#[derive(Default)]
+pub struct GodotNodeBadgesPlugin;
+
+impl Plugin for GodotNodeBadgesPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Last, send_node_badges);
+    }
+}
+
+/// One mirrored node, as sent to the editor.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+struct NodeBadge {
+    path: String,
+    entity: Entity,
+    components: Vec<String>,
+}
+
+impl NodeBadge {
+    fn to_variant_entry(&self) -> VarArray {
+        let components: PackedStringArray = self.components.iter().map(GString::from).collect();
+        varray![
+            self.path.as_str(),
+            self.entity.to_bits() as i64,
+            &components
+        ]
+    }
+}
+
+fn send_node_badges(world: &mut World, mut elapsed: Local<f32>, mut sent: Local<Vec<NodeBadge>>) {
+    if !EngineDebugger::singleton().is_active() {
+        return;
+    }
+    *elapsed += world
+        .get_resource::<Time<Real>>()
+        .map(|time| time.delta_secs())
+        .unwrap_or(0.0);
+    if *elapsed < SEND_INTERVAL {
+        return;
+    }
+    *elapsed = 0.0;
+
+    let badges = collect_badges(world);
+    if badges == *sent {
+        return;
+    }
+    let mut data = VarArray::new();
+    for badge in &badges {
+        data.push(&badge.to_variant_entry().to_variant());
+    }
+    EngineDebugger::singleton().send_message("bevy:node_badges", &data);
+    *sent = badges;
+}
+
+fn collect_badges(world: &mut World) -> Vec<NodeBadge> {
+    let mut nodes = world.query::<(Entity, &GodotNodeHandle)>();
+    let world = &*world;
+    let mut scene_path = None;
+    let mut badges: Vec<NodeBadge> = nodes
+        .iter(world)
+        .filter_map(|(entity, handle)| {
+            let node = Gd::<Node>::try_from_instance_id(handle.instance_id()).ok()?;
+            if !node.is_inside_tree() {
+                return None;
+            }
+            let scene = scene_path.get_or_insert_with(|| {
+                node.get_tree()
+                    .get_current_scene()
+                    .map(|scene| scene.get_path().to_string())
+                    .unwrap_or_default()
+            });
+            let path = scene_relative(&node.get_path().to_string(), scene);
+            let mut components: Vec<String> = world
+                .inspect_entity(entity)
+                .ok()?
+                .map(|info| info.name().shortname().to_string())
+                .filter(|name| !HIDDEN_COMPONENTS.contains(&name.as_str()))
+                .collect();
+            components.sort();
+            Some(NodeBadge {
+                path,
+                entity,
+                components,
+            })
+        })
+        .collect();
+    badges.sort();
+    badges
+}
+
+/// `path` relative to the scene at `scene`, or unchanged if it is outside it.
+fn scene_relative(path: &str, scene: &str) -> String {
+    if scene.is_empty() {
+        return path.to_owned();
+    }
+    match path.strip_prefix(scene) {
+        Some("") => ".".to_owned(),
+        Some(rest) => rest.strip_prefix('/').unwrap_or(path).to_owned(),
+        None => path.to_owned(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn paths_are_relative_to_the_current_scene() {
+        let scene = "/root/Main";
+        assert_eq!(scene_relative("/root/Main", scene), ".");
+        assert_eq!(scene_relative("/root/Main/Player/Gun", scene), "Player/Gun");
+        assert_eq!(scene_relative("/root/MainMenu", scene), "/root/MainMenu");
+        assert_eq!(
+            scene_relative("/root/BevyAppSingleton", scene),
+            "/root/BevyAppSingleton"
+        );
+        assert_eq!(scene_relative("/root/Main/Player", ""), "/root/Main/Player");
+    }
+}
@@ -0,0 +1,185 @@
+//! Reuse short-lived helper nodes instead of allocating and freeing one per use.
+//!
+//! Plugins that create a node for every sound or request lease it from the shared
+//! [`HelperNodePool`] and release it when done; the next lease of the same class
+//! gets the detached node back. User plugins can do the same:
+//!
+//! ```ignore
+//! fn play_click(mut pool: ResMut<HelperNodePool>, mut godot: GodotAccess, ...) {
+//!     let mut timer = pool.lease::<Timer>(&mut godot);
+//!     timer.set_wait_time(0.2);
+//!     parent.add_child(&timer);
+//!     // ... later, once it has fired:
+//!     pool.release(&mut godot, timer);
+//! }
+//! ```
+//!
+//! Leased nodes are plumbing: they carry the `_bevy_exclude` meta, so they never
+//! get an entity, and nothing about their previous use is reset beyond removing
+//! them from the tree. Set every property you rely on after leasing.
+
+use bevy_ecs::prelude::Resource;
+use bevy_platform::collections::{HashMap, HashSet};
+use godot::classes::Node;
+use godot::obj::{Gd, Inherits, InstanceId, NewAlloc};
+use tracing::{trace, warn};
+
+use crate::interop::GodotAccess;
+use crate::watchers::scene_tree_watcher::exclude_from_mirror;
+
+/// Idle nodes kept per class by default; releases beyond this free the node.
+pub const DEFAULT_MAX_IDLE_PER_CLASS: usize = 32;
+
+/// Shared pool of detached helper nodes, keyed by Godot class. Added by
+/// `GodotBaseCorePlugin`. See the [module docs](self).
+#[derive(Resource, Debug)]
+pub struct HelperNodePool {
+    idle: HashMap<String, Vec<InstanceId>>,
+    /// Nodes handed out by [`lease`](Self::lease) and not yet released. Other
+    /// plumbing nodes carry the exclude meta too, so the meta can't tell them apart.
+    leased: HashSet<InstanceId>,
+    max_idle_per_class: usize,
+    metrics: HelperPoolMetrics,
+}
+
+/// Counters since startup, from [`HelperNodePool::metrics`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct HelperPoolMetrics {
+    /// Leases that had to allocate a new node.
+    pub allocated: u64,
+    /// Leases served from the pool.
+    pub reused: u64,
+    /// Nodes handed back with [`HelperNodePool::release`].
+    pub released: u64,
+    /// Released nodes freed because their class was already at capacity.
+    pub freed: u64,
+    /// Nodes currently waiting in the pool.
+    pub idle: usize,
+}
+
+impl HelperPoolMetrics {
+    /// Share of leases served without allocating, from 0.0 to 1.0.
+    pub fn reuse_ratio(&self) -> f64 {
+        let leases = self.allocated + self.reused;
+        if leases == 0 {
+            0.0
+        } else {
+            self.reused as f64 / leases as f64
+        }
+    }
+}
+
+impl Default for HelperNodePool {
+    fn default() -> Self {
+        Self {
+            idle: HashMap::default(),
+            leased: HashSet::default(),
+            max_idle_per_class: DEFAULT_MAX_IDLE_PER_CLASS,
+            metrics: HelperPoolMetrics::default(),
+        }
+    }
+}
+
+impl HelperNodePool {
+    /// A detached node of class `T`: a pooled one if available, else a new one.
+    pub fn lease<T: NewAlloc + Inherits<Node>>(&mut self, godot: &mut GodotAccess) -> Gd<T> {
+        let class = T::class_id().to_string();
+        while let Some(id) = self.idle.get_mut(&class).and_then(Vec::pop) {
+            self.metrics.idle -= 1;
+            // Something else may have freed it while it sat in the pool.
+            if let Some(node) = godot.try_get_instance_id::<T>(id) {
+                self.metrics.reused += 1;
+                self.leased.insert(id);
+                return node;
+            }
+        }
+
+        let mut node = T::new_alloc();
+        exclude_from_mirror(&mut node);
+        self.leased.insert(node.instance_id());
+        self.metrics.allocated += 1;
+        trace!("HelperNodePool: allocated {class}");
+        node
+    }
+
+    /// Detach `node` from its parent and keep it for the next lease of its class.
+    ///
+    /// Only nodes currently leased with [`lease`](Self::lease) are pooled. Anything
+    /// else is freed, since removing a mirrored node from the tree despawns its
+    /// entity anyway.
+    pub fn release<T: Inherits<Node>>(&mut self, _godot: &mut GodotAccess, node: Gd<T>) {
+        let mut node = node.upcast::<Node>();
+        if let Some(mut parent) = node.get_parent() {
+            parent.remove_child(&node);
+        }
+        if !self.leased.remove(&node.instance_id()) {
+            warn!(
+                "HelperNodePool: {} {} was not leased from the pool; freeing it",
+                node.get_class(),
+                node.instance_id()
+            );
+            node.queue_free();
+            return;
+        }
+        self.metrics.released += 1;
+
+        let idle = self.idle.entry(node.get_class().to_string()).or_default();
+        if idle.len() >= self.max_idle_per_class {
+            node.queue_free();
+            self.metrics.freed += 1;
+        } else {
+            idle.push(node.instance_id());
+            self.metrics.idle += 1;
+        }
+    }
+
+    pub fn metrics(&self) -> HelperPoolMetrics {
+        self.metrics
+    }
+
+    pub fn max_idle_per_class(&self) -> usize {
+        self.max_idle_per_class
+    }
+
+    /// Change the per-class capacity. Nodes already idle are kept until leased.
+    pub fn set_max_idle_per_class(&mut self, max: usize) {
+        self.max_idle_per_class = max;
+    }
+
+    /// Free every idle node, e.g. after a level with many sounds ends.
+    pub fn clear(&mut self, godot: &mut GodotAccess) {
+        for id in self.idle.drain().flat_map(|(_, ids)| ids) {
+            if let Some(node) = godot.try_get_instance_id::<Node>(id) {
+                node.free();
+            }
+        }
+        self.metrics.idle = 0;
+    }
+}
+
+impl Drop for HelperNodePool {
+    /// Idle nodes are outside the tree, so nothing else would free them. The pool
+    /// is dropped with the app, on the main thread.
+    fn drop(&mut self) {
+        for id in self.idle.drain().flat_map(|(_, ids)| ids) {
+            if let Ok(node) = Gd::<Node>::try_from_instance_id(id) {
+                node.free();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reuse_ratio_counts_pooled_leases() {
+        let mut metrics = HelperPoolMetrics::default();
+        assert_eq!(metrics.reuse_ratio(), 0.0);
+
+        metrics.allocated = 1;
+        metrics.reused = 3;
+        assert_eq!(metrics.reuse_ratio(), 0.75);
+    }
+}
@@ -1,19 +1,25 @@
-use super::scene_tree::SceneTreeRef;
+use super::scene_tree::node_type_checking::add_node_type_markers_from_string;
+use super::scene_tree::plugin::{SceneTreeDecorated, get_inheritance_hierarchy};
+use super::scene_tree::{GodotChildOf, Groups, SceneTreeRef};
 use crate::interop::{GodotAccess, GodotNodeHandle};
 use crate::plugins::assets::GodotResource;
+use crate::plugins::core::SceneTreeComponentRegistry;
+use crate::plugins::scene_tree::autosync::try_add_bundles_for_node;
 use crate::plugins::signals::{
     DeferredSignalConnectionTrait, DeferredSignalConnections, SignalConnectionSpec, SignalSender,
 };
 use crate::plugins::transforms::IntoGodotTransform;
 use crate::plugins::transforms::IntoGodotTransform2D;
+use crate::watchers::scene_tree_watcher::EXCLUDE_META;
 use bevy_app::{App, Plugin, PostUpdate};
 use bevy_asset::{Assets, Handle};
 use bevy_ecs::event::Event;
-use bevy_ecs::prelude::Res;
+use bevy_ecs::prelude::{Name, Res, Resource};
 use bevy_ecs::{
     component::Component,
     entity::Entity,
     query::Without,
+    schedule::IntoScheduleConfigs,
     system::{Commands, Query, ResMut},
 };
 use bevy_transform::components::Transform;
@@ -23,18 +29,24 @@ use godot::{
     builtin::GString,
     classes::{Node, Node2D, Node3D, PackedScene, ResourceLoader},
 };
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::str::FromStr;
-use tracing::error;
+use tracing::{debug, error};
 
 #[derive(Default)]
 pub struct GodotPackedScenePlugin;
 impl Plugin for GodotPackedScenePlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(PostUpdate, spawn_scene);
+        app.init_resource::<GodotScenePrewarm>()
+            .add_systems(PostUpdate, (prewarm_scenes, spawn_scene).chain());
     }
 }
 
+#[cfg(debug_assertions)]
+crate::inventory::submit! {
+    crate::watchdog::PluginRequirement::new::<GodotScene, GodotPackedScenePlugin>()
+}
+
 // silence warning about the following docs referring to private `spawn_scene`
 #[allow(rustdoc::private_intra_doc_links)]
 /// A to-be-instanced-and-spawned Godot scene.
@@ -140,6 +152,205 @@ impl GodotScene {
     }
 }
 
+/// PackedScenes queued to have their ECS archetypes created ahead of the first spawn.
+///
+/// The first time an entity lands in a new component combination, Bevy allocates
+/// the archetype, its table, and every archetype edge along the insert path. For a
+/// complex scene spawned mid-gameplay that is a visible hitch. Prewarming instances
+/// the scene off-tree once, decorates a throwaway entity per node exactly the way
+/// the scene-tree plugin would (markers, registered components, autosync bundles,
+/// `GodotChildOf`), then despawns them and frees the instance. Archetypes are never
+/// deallocated, so later spawns of the scene reuse them.
+///
+/// The throwaway entities are real: `On<Add>`/`On<Insert>` observers and component
+/// hooks run for them, and removal observers run when they are despawned in the
+/// same command flush. Observers with side effects should skip entities for which
+/// [`is_prewarm_entity`](Self::is_prewarm_entity) is true. They are not tagged with
+/// a marker component, since that would change the archetypes being prewarmed.
+///
+/// Queue scenes during plugin build with [`PrewarmGodotSceneAppExt`], or at runtime
+/// (e.g. from a loading screen) through this resource. Each scene is prewarmed at
+/// most once; handle-based requests wait until the asset has loaded.
+#[derive(Resource, Default)]
+pub struct GodotScenePrewarm {
+    pending: Vec<GodotSceneResource>,
+    prewarmed: HashSet<String>,
+    /// Throwaway entities from the last prewarm pass.
+    in_flight: HashSet<Entity>,
+}
+
+impl GodotScenePrewarm {
+    /// Queue the scene at `path` (e.g. `"res://enemy.tscn"`) for prewarming.
+    pub fn prewarm_path(&mut self, path: &str) {
+        self.pending
+            .push(GodotSceneResource::Path(path.to_string()));
+    }
+
+    /// Queue a scene loaded through Bevy's asset system for prewarming.
+    pub fn prewarm_handle(&mut self, handle: Handle<GodotResource>) {
+        self.pending.push(GodotSceneResource::Handle(handle));
+    }
+
+    /// True while queued scenes are still waiting to be prewarmed.
+    pub fn is_pending(&self) -> bool {
+        !self.pending.is_empty()
+    }
+
+    /// True once the scene at `path` has been prewarmed.
+    pub fn is_prewarmed(&self, path: &str) -> bool {
+        self.prewarmed.contains(path)
+    }
+
+    /// True if `entity` is a throwaway entity spawned to prewarm a scene. Valid while
+    /// its commands are applied, i.e. from observers and component hooks.
+    pub fn is_prewarm_entity(&self, entity: Entity) -> bool {
+        self.in_flight.contains(&entity)
+    }
+}
+
+/// Queues PackedScenes for archetype prewarming at app build.
+pub trait PrewarmGodotSceneAppExt {
+    /// Prewarm the archetypes the scene at `path` will produce. See [`GodotScenePrewarm`].
+    fn prewarm_godot_scene(&mut self, path: &str) -> &mut Self;
+}
+
+impl PrewarmGodotSceneAppExt for App {
+    fn prewarm_godot_scene(&mut self, path: &str) -> &mut Self {
+        self.init_resource::<GodotScenePrewarm>();
+        self.world_mut()
+            .resource_mut::<GodotScenePrewarm>()
+            .prewarm_path(path);
+        self
+    }
+}
+
+fn prewarm_scenes(
+    mut commands: Commands,
+    mut prewarm: ResMut<GodotScenePrewarm>,
+    mut assets: Option<ResMut<Assets<GodotResource>>>,
+    component_registry: Res<SceneTreeComponentRegistry>,
+    mut godot: GodotAccess,
+) {
+    // Last pass's entities were despawned when its commands were applied.
+    if !prewarm.in_flight.is_empty() {
+        prewarm.in_flight.clear();
+    }
+    if prewarm.pending.is_empty() {
+        return;
+    }
+    let pending = std::mem::take(&mut prewarm.pending);
+
+    for resource in pending {
+        let key = match &resource {
+            GodotSceneResource::Path(path) => path.clone(),
+            GodotSceneResource::Handle(handle) => match handle.path() {
+                Some(path) => path.to_string(),
+                None => format!("{:?}", handle.id()),
+            },
+        };
+        if prewarm.prewarmed.contains(&key) {
+            continue;
+        }
+
+        let packed_scene = match &resource {
+            GodotSceneResource::Path(path) => {
+                let Some(resource) = godot.singleton::<ResourceLoader>().load(path.as_str()) else {
+                    error!("Failed to load scene for prewarming: {path}");
+                    continue;
+                };
+                resource.try_cast::<PackedScene>().ok()
+            }
+            GodotSceneResource::Handle(handle) => {
+                let Some(mut asset) = assets.as_mut().and_then(|assets| assets.get_mut(handle))
+                else {
+                    // Not loaded yet; retry next frame.
+                    prewarm.pending.push(resource);
+                    continue;
+                };
+                asset.try_cast::<PackedScene>()
+            }
+        };
+        let Some(packed_scene) = packed_scene else {
+            error!("Resource queued for prewarming is not a PackedScene: {key}");
+            continue;
+        };
+        let Some(mut instance) = packed_scene.instantiate() else {
+            error!("Failed to instantiate PackedScene for prewarming: {key}");
+            continue;
+        };
+
+        let mut spawned = Vec::new();
+        prewarm_node(
+            &mut commands,
+            instance.clone(),
+            None,
+            &component_registry,
+            &mut godot,
+            &mut spawned,
+        );
+        prewarm.in_flight.extend(spawned.iter().copied());
+        // Leaves first, so an auto-despawning parent never takes a child out from
+        // under its own despawn. The GodotNodeHandle removal observer queues each
+        // node for deletion; freeing the root covers excluded subtrees too.
+        for entity in spawned.into_iter().rev() {
+            commands.entity(entity).try_despawn();
+        }
+        instance.queue_free();
+
+        debug!("Prewarmed archetypes for scene {key}");
+        prewarm.prewarmed.insert(key);
+    }
+}
+
+/// Decorate a throwaway entity for `node` and its mirrored descendants, following
+/// the scene-tree plugin's insert order so the same archetype edges are created.
+fn prewarm_node(
+    commands: &mut Commands,
+    node: Gd<Node>,
+    parent: Option<Entity>,
+    component_registry: &SceneTreeComponentRegistry,
+    godot: &mut GodotAccess,
+    spawned: &mut Vec<Entity>,
+) {
+    if node.has_meta(EXCLUDE_META) {
+        return;
+    }
+
+    let handle = GodotNodeHandle::new(node.clone());
+    let mut entity_commands = commands.spawn_empty();
+    let class_hierarchy = get_inheritance_hierarchy(node.get_class().to_string().as_str());
+    for class_name in class_hierarchy.iter() {
+        if add_node_type_markers_from_string(&mut entity_commands, class_name.as_str()) {
+            break;
+        }
+    }
+    entity_commands.insert((
+        handle,
+        Name::from(node.get_name().to_string()),
+        Groups::from(&node),
+        SceneTreeDecorated,
+    ));
+    component_registry.add_to_entity(&mut entity_commands, &mut godot.node(handle));
+    let entity = entity_commands.id();
+    spawned.push(entity);
+
+    try_add_bundles_for_node(commands, entity, godot, handle, class_hierarchy.as_slice());
+    if let Some(parent) = parent {
+        commands.entity(entity).insert(GodotChildOf(parent));
+    }
+
+    for child in node.get_children().iter_shared() {
+        prewarm_node(
+            commands,
+            child,
+            Some(entity),
+            component_registry,
+            godot,
+            spawned,
+        );
+    }
+}
+
 fn spawn_scene(
     mut commands: Commands,
     mut new_scenes: Query<(&mut GodotScene, Entity, Option<&Transform>), Without<GodotNodeHandle>>,
@@ -148,6 +359,7 @@ fn spawn_scene(
     signal_sender: Option<Res<SignalSender>>,
     mut godot: GodotAccess,
 ) {
+    crate::profile_scope!("spawn_scene");
     // Build a per-frame cache for path-based scene loading.
     // This avoids repeated ResourceLoader.load() calls when spawning multiple
     // instances of the same scene in a single frame (~22x faster).
@@ -164,7 +376,7 @@ fn spawn_scene(
                 match resource.try_cast::<PackedScene>() {
                     Ok(ps) => ps,
                     Err(resource) => {
-                        error!("Resource is not a PackedScene: {:?}", resource);
+                        error!("Resource is not a PackedScene: {resource:?} (entity {ent})");
                         continue;
                     }
                 }
@@ -187,7 +399,7 @@ fn spawn_scene(
                             ps
                         }
                         Err(resource) => {
-                            error!("Resource is not a PackedScene: {:?}", resource);
+                            error!("Resource is not a PackedScene: {resource:?} (entity {ent})");
                             continue;
                         }
                     }
@@ -198,7 +410,7 @@ fn spawn_scene(
         let instance = match packed_scene.instantiate() {
             Some(instance) => instance,
             None => {
-                error!("Failed to instantiate PackedScene");
+                error!("Failed to instantiate PackedScene (entity {ent})");
                 continue;
             }
         };
@@ -232,7 +444,15 @@ fn spawn_scene(
 
         match scene.parent {
             Some(parent_id) => {
-                let mut parent = godot.get::<Node>(parent_id);
+                let Some(mut parent) = godot.try_get::<Node>(parent_id) else {
+                    error!(
+                        "Failed to spawn scene: parent node {} was freed (entity {ent})",
+                        parent_id.instance_id()
+                    );
+                    instance.free();
+                    commands.entity(ent).remove::<GodotScene>();
+                    continue;
+                };
                 parent.add_child(&instance);
             }
             None => {
@@ -0,0 +1,390 @@
+//! Control `GPUParticles2D`/`3D` nodes from systems, without node handle plumbing.
+//!
+//! With [`GodotParticlesPlugin`], every mirrored `GPUParticles` node's entity gets
+//! a [`ParticleEmitter`] holding its `emitting`, `amount` and `one_shot`. Editing
+//! the component writes the node; a one-shot burst that finishes on its own
+//! flips `emitting` back to `false` on the component. [`ParticleCommand`]
+//! restarts a burst or sets a process material parameter:
+//!
+//! ```ignore
+//! fn on_hit(
+//!     mut hits: MessageReader<Hit>,
+//!     sparks: Query<Entity, With<Sparks>>,
+//!     mut particles: MessageWriter<ParticleCommand>,
+//! ) {
+//!     for hit in hits.read() {
+//!         let Ok(sparks) = sparks.get(hit.target) else { continue };
+//!         particles.write(ParticleCommand::set_param(sparks, "color", hit.color));
+//!         particles.write(ParticleCommand::Restart(sparks));
+//!     }
+//! }
+//! ```
+//!
+//! Parameters go through `set_shader_parameter` on a `ShaderMaterial` and are set
+//! as properties on a `ParticleProcessMaterial` (`"initial_velocity_min"`,
+//! `"color"`, ...). The material is a resource shared by every instance of the
+//! scene it came from; mark it "Local to Scene" in the editor to tune one emitter
+//! at a time.
+
+use bevy_app::{App, Last, Plugin};
+use bevy_ecs::change_detection::{DetectChanges, DetectChangesMut};
+use bevy_ecs::component::Component;
+use bevy_ecs::entity::Entity;
+use bevy_ecs::message::{Message, MessageReader};
+use bevy_ecs::query::{Added, Without};
+use bevy_ecs::schedule::IntoScheduleConfigs;
+use bevy_ecs::system::{Commands, Query};
+use bevy_ecs::world::Mut;
+use bevy_math::{Vec2, Vec3};
+use godot::builtin::{Color, Variant, Vector2, Vector3};
+use godot::classes::{GpuParticles2D, GpuParticles3D, Material, ShaderMaterial};
+use godot::meta::ToGodot;
+use godot::obj::Gd;
+use tracing::warn;
+
+use crate::interop::{GodotAccess, GodotNodeHandle};
+
+/// Plugin syncing [`ParticleEmitter`]s and applying [`ParticleCommand`]s. See the
+/// [module docs](self).
+#[derive(Default)]
+pub struct GodotParticlesPlugin;
+
+impl Plugin for GodotParticlesPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_message::<ParticleCommand>().add_systems(
+            Last,
+            (
+                insert_particle_emitters,
+                sync_particle_emitters,
+                apply_particle_commands,
+            )
+                .chain(),
+        );
+    }
+}
+
+#[cfg(debug_assertions)]
+crate::inventory::submit! {
+    crate::watchdog::PluginRequirement::new::<ParticleEmitter, GodotParticlesPlugin>()
+}
+
+/// The emission state of the entity's `GPUParticles2D`/`3D` node.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParticleEmitter {
+    pub emitting: bool,
+    /// Changing it restarts the node's particles.
+    pub amount: i32,
+    pub one_shot: bool,
+}
+
+/// Restart or tune an entity's `GPUParticles2D`/`3D`. Applied in `Last`.
+#[derive(Message, Debug, Clone, PartialEq)]
+pub enum ParticleCommand {
+    /// Clear the live particles and start emitting from the beginning, as for a
+    /// one-shot burst.
+    Restart(Entity),
+    /// Set a parameter of the node's process material.
+    SetParam {
+        entity: Entity,
+        name: String,
+        value: ParticleParam,
+    },
+}
+
+impl ParticleCommand {
+    pub fn set_param(
+        entity: Entity,
+        name: impl Into<String>,
+        value: impl Into<ParticleParam>,
+    ) -> Self {
+        Self::SetParam {
+            entity,
+            name: name.into(),
+            value: value.into(),
+        }
+    }
+
+    pub fn entity(&self) -> Entity {
+        match self {
+            Self::Restart(entity) | Self::SetParam { entity, .. } => *entity,
+        }
+    }
+}
+
+/// A value for [`ParticleCommand::SetParam`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ParticleParam {
+    Bool(bool),
+    Int(i64),
+    Float(f32),
+    Vec2(Vec2),
+    Vec3(Vec3),
+    Color(Color),
+}
+
+impl ParticleParam {
+    fn to_variant(self) -> Variant {
+        match self {
+            Self::Bool(value) => value.to_variant(),
+            Self::Int(value) => value.to_variant(),
+            Self::Float(value) => value.to_variant(),
+            Self::Vec2(value) => Vector2::new(value.x, value.y).to_variant(),
+            Self::Vec3(value) => Vector3::new(value.x, value.y, value.z).to_variant(),
+            Self::Color(value) => value.to_variant(),
+        }
+    }
+}
+
+impl From<bool> for ParticleParam {
+    fn from(value: bool) -> Self {
+        Self::Bool(value)
+    }
+}
+
+impl From<i64> for ParticleParam {
+    fn from(value: i64) -> Self {
+        Self::Int(value)
+    }
+}
+
+impl From<f32> for ParticleParam {
+    fn from(value: f32) -> Self {
+        Self::Float(value)
+    }
+}
+
+impl From<Vec2> for ParticleParam {
+    fn from(value: Vec2) -> Self {
+        Self::Vec2(value)
+    }
+}
+
+impl From<Vec3> for ParticleParam {
+    fn from(value: Vec3) -> Self {
+        Self::Vec3(value)
+    }
+}
+
+impl From<Color> for ParticleParam {
+    fn from(value: Color) -> Self {
+        Self::Color(value)
+    }
+}
+
+/// A `GPUParticles2D` or `GPUParticles3D`, which share their API but no base class.
+enum Particles {
+    TwoD(Gd<GpuParticles2D>),
+    ThreeD(Gd<GpuParticles3D>),
+}
+
+impl Particles {
+    fn get(godot: &mut GodotAccess, handle: GodotNodeHandle) -> Option<Self> {
+        godot
+            .try_get::<GpuParticles2D>(handle)
+            .map(Self::TwoD)
+            .or_else(|| godot.try_get::<GpuParticles3D>(handle).map(Self::ThreeD))
+    }
+
+    fn emitter(&self) -> ParticleEmitter {
+        match self {
+            Self::TwoD(node) => ParticleEmitter {
+                emitting: node.is_emitting(),
+                amount: node.get_amount(),
+                one_shot: node.get_one_shot(),
+            },
+            Self::ThreeD(node) => ParticleEmitter {
+                emitting: node.is_emitting(),
+                amount: node.get_amount(),
+                one_shot: node.get_one_shot(),
+            },
+        }
+    }
+
+    /// Write only the fields that differ, since setting `amount` or `one_shot`
+    /// restarts the particles.
+    fn apply(&mut self, emitter: &ParticleEmitter) {
+        let current = self.emitter();
+        match self {
+            Self::TwoD(node) => {
+                if current.amount != emitter.amount {
+                    node.set_amount(emitter.amount);
+                }
+                if current.one_shot != emitter.one_shot {
+                    node.set_one_shot(emitter.one_shot);
+                }
+                if current.emitting != emitter.emitting {
+                    node.set_emitting(emitter.emitting);
+                }
+            }
+            Self::ThreeD(node) => {
+                if current.amount != emitter.amount {
+                    node.set_amount(emitter.amount);
+                }
+                if current.one_shot != emitter.one_shot {
+                    node.set_one_shot(emitter.one_shot);
+                }
+                if current.emitting != emitter.emitting {
+                    node.set_emitting(emitter.emitting);
+                }
+            }
+        }
+    }
+
+    fn restart(&mut self) {
+        match self {
+            Self::TwoD(node) => node.restart(),
+            Self::ThreeD(node) => node.restart(),
+        }
+    }
+
+    fn process_material(&self) -> Option<Gd<Material>> {
+        match self {
+            Self::TwoD(node) => node.get_process_material(),
+            Self::ThreeD(node) => node.get_process_material(),
+        }
+    }
+}
+
+/// Which way a [`ParticleEmitter`] and its node should be brought back in line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Reconcile {
+    None,
+    /// A system edited the component.
+    Push,
+    /// The node stopped on its own, at the end of a one-shot burst.
+    Pull,
+}
+
+fn reconcile(emitter: &ParticleEmitter, node: &ParticleEmitter, edited: bool) -> Reconcile {
+    if emitter == node {
+        Reconcile::None
+    } else if edited {
+        Reconcile::Push
+    } else if emitter.emitting != node.emitting {
+        Reconcile::Pull
+    } else {
+        Reconcile::None
+    }
+}
+
+fn insert_particle_emitters(
+    added: Query<(Entity, &GodotNodeHandle), (Added<GodotNodeHandle>, Without<ParticleEmitter>)>,
+    mut commands: Commands,
+    mut godot: GodotAccess,
+) {
+    for (entity, handle) in &added {
+        if let Some(particles) = Particles::get(&mut godot, *handle) {
+            commands.entity(entity).insert(particles.emitter());
+        }
+    }
+}
+
+fn sync_particle_emitters(
+    mut emitters: Query<(Entity, &GodotNodeHandle, Mut<ParticleEmitter>)>,
+    mut godot: GodotAccess,
+) {
+    for (entity, handle, mut emitter) in &mut emitters {
+        let Some(mut particles) = Particles::get(&mut godot, *handle) else {
+            if emitter.is_added() {
+                warn!("ParticleEmitter on {entity}, whose node isn't a GPUParticles2D/3D");
+            }
+            continue;
+        };
+        let node = particles.emitter();
+        match reconcile(&emitter, &node, emitter.is_changed()) {
+            Reconcile::None => {}
+            Reconcile::Push => particles.apply(&emitter),
+            Reconcile::Pull => emitter.emitting = node.emitting,
+        }
+    }
+}
+
+fn apply_particle_commands(
+    mut requests: MessageReader<ParticleCommand>,
+    handles: Query<&GodotNodeHandle>,
+    mut emitters: Query<&mut ParticleEmitter>,
+    mut godot: GodotAccess,
+) {
+    for request in requests.read() {
+        let entity = request.entity();
+        let Some(mut particles) = handles
+            .get(entity)
+            .ok()
+            .and_then(|handle| Particles::get(&mut godot, *handle))
+        else {
+            warn!(
+                "GodotParticlesPlugin: {request:?} targets an entity without a GPUParticles node"
+            );
+            continue;
+        };
+        match request {
+            ParticleCommand::Restart(_) => {
+                particles.restart();
+                if let Ok(mut emitter) = emitters.get_mut(entity) {
+                    // Without marking the change, which would push nothing anyway.
+                    emitter.bypass_change_detection().emitting = true;
+                }
+            }
+            ParticleCommand::SetParam { name, value, .. } => {
+                let Some(mut material) = particles.process_material() else {
+                    warn!("GodotParticlesPlugin: {entity}'s particles have no process material");
+                    continue;
+                };
+                let value = value.to_variant();
+                match material.clone().try_cast::<ShaderMaterial>() {
+                    Ok(mut shader) => shader.set_shader_parameter(name.as_str(), &value),
+                    Err(_) => material.set(name.as_str(), &value),
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn emitter(emitting: bool, amount: i32) -> ParticleEmitter {
+        ParticleEmitter {
+            emitting,
+            amount,
+            one_shot: true,
+        }
+    }
+
+    #[test]
+    fn edits_push_and_finished_bursts_pull() {
+        assert_eq!(
+            reconcile(&emitter(true, 8), &emitter(true, 8), true),
+            Reconcile::None
+        );
+        assert_eq!(
+            reconcile(&emitter(true, 16), &emitter(true, 8), true),
+            Reconcile::Push
+        );
+        assert_eq!(
+            reconcile(&emitter(true, 8), &emitter(false, 8), false),
+            Reconcile::Pull
+        );
+        // Only `emitting` changes on the node by itself.
+        assert_eq!(
+            reconcile(&emitter(true, 16), &emitter(true, 8), false),
+            Reconcile::None
+        );
+    }
+
+    #[test]
+    fn commands_name_their_entity() {
+        let entity = Entity::from_raw_u32(7).unwrap();
+        let command = ParticleCommand::set_param(entity, "scale_min", 0.5f32);
+        assert_eq!(command.entity(), entity);
+        assert_eq!(
+            command,
+            ParticleCommand::SetParam {
+                entity,
+                name: "scale_min".into(),
+                value: ParticleParam::Float(0.5),
+            }
+        );
+    }
+}
@@ -0,0 +1,204 @@
+//! Friction and bounce of physics bodies as components, for ice floors and
+//! trampolines switched on and off from systems.
+//!
+//! With [`GodotPhysicsMaterialPlugin`], every mirrored `StaticBody2D`/`3D`
+//! (including animatable bodies) and `RigidBody2D`/`3D` gets a
+//! [`PhysicsMaterialOverride`] read from its node's material, or Godot's
+//! defaults when it has none. Editing the component writes the node in `Last`:
+//!
+//! ```ignore
+//! fn freeze_floor(mut floors: Query<&mut PhysicsMaterialOverride, With<Frozen>>) {
+//!     for mut floor in &mut floors {
+//!         floor.friction = 0.05;
+//!     }
+//! }
+//! ```
+//!
+//! Bodies often share one `PhysicsMaterial` resource. The first edit gives the
+//! body a material of its own, so freezing one floor doesn't freeze every floor
+//! built from the same scene; later edits change that material in place. Changes
+//! made to the material from GDScript aren't read back.
+
+use bevy_app::{App, Last, Plugin};
+use bevy_ecs::component::Component;
+use bevy_ecs::entity::Entity;
+use bevy_ecs::query::{Added, Changed, Without};
+use bevy_ecs::schedule::IntoScheduleConfigs;
+use bevy_ecs::system::{Commands, Query};
+use godot::classes::{PhysicsMaterial, RigidBody2D, RigidBody3D, StaticBody2D, StaticBody3D};
+use godot::obj::{Gd, InstanceId, NewGd};
+
+use crate::interop::{GodotAccess, GodotNodeHandle};
+
+/// Plugin mirroring body physics materials. See the [module docs](self).
+#[derive(Default)]
+pub struct GodotPhysicsMaterialPlugin;
+
+impl Plugin for GodotPhysicsMaterialPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Last,
+            (insert_physics_materials, apply_physics_materials).chain(),
+        );
+    }
+}
+
+/// The surface of a body: Godot's `PhysicsMaterial` properties.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct PhysicsMaterialOverride {
+    /// From 0 (no friction) to 1 (maximum friction).
+    pub friction: f32,
+    /// From 0 (no bounce) to 1 (full bounce).
+    pub bounce: f32,
+    /// Use this body's friction instead of the lower of the two bodies'.
+    pub rough: bool,
+    /// Subtract the other body's bounce instead of using the higher of the two.
+    pub absorbent: bool,
+}
+
+impl Default for PhysicsMaterialOverride {
+    /// Godot's values for a body without a material.
+    fn default() -> Self {
+        Self {
+            friction: 1.0,
+            bounce: 0.0,
+            rough: false,
+            absorbent: false,
+        }
+    }
+}
+
+impl PhysicsMaterialOverride {
+    fn read(material: &Gd<PhysicsMaterial>) -> Self {
+        Self {
+            friction: material.get_friction(),
+            bounce: material.get_bounce(),
+            rough: material.is_rough(),
+            absorbent: material.is_absorbent(),
+        }
+    }
+
+    fn write(&self, material: &mut Gd<PhysicsMaterial>) {
+        material.set_friction(self.friction);
+        material.set_bounce(self.bounce);
+        material.set_rough(self.rough);
+        material.set_absorbent(self.absorbent);
+    }
+}
+
+/// The material this plugin made for a body on its first edit.
+#[derive(Component)]
+struct OwnedPhysicsMaterial(InstanceId);
+
+/// A body with a `physics_material_override`; the four classes share the
+/// property but no base class declaring it.
+enum Body {
+    Static2D(Gd<StaticBody2D>),
+    Rigid2D(Gd<RigidBody2D>),
+    Static3D(Gd<StaticBody3D>),
+    Rigid3D(Gd<RigidBody3D>),
+}
+
+macro_rules! each_body {
+    ($body:expr, $node:ident => $expr:expr) => {
+        match $body {
+            Body::Static2D($node) => $expr,
+            Body::Rigid2D($node) => $expr,
+            Body::Static3D($node) => $expr,
+            Body::Rigid3D($node) => $expr,
+        }
+    };
+}
+
+impl Body {
+    fn get(godot: &mut GodotAccess, handle: GodotNodeHandle) -> Option<Self> {
+        godot
+            .try_get::<StaticBody2D>(handle)
+            .map(Self::Static2D)
+            .or_else(|| godot.try_get::<RigidBody2D>(handle).map(Self::Rigid2D))
+            .or_else(|| godot.try_get::<StaticBody3D>(handle).map(Self::Static3D))
+            .or_else(|| godot.try_get::<RigidBody3D>(handle).map(Self::Rigid3D))
+    }
+
+    fn material(&self) -> Option<Gd<PhysicsMaterial>> {
+        each_body!(self, body => body.get_physics_material_override())
+    }
+
+    fn set_material(&mut self, material: &Gd<PhysicsMaterial>) {
+        each_body!(self, body => body.set_physics_material_override(material))
+    }
+}
+
+fn insert_physics_materials(
+    added: Query<
+        (Entity, &GodotNodeHandle),
+        (Added<GodotNodeHandle>, Without<PhysicsMaterialOverride>),
+    >,
+    mut commands: Commands,
+    mut godot: GodotAccess,
+) {
+    for (entity, handle) in &added {
+        if let Some(body) = Body::get(&mut godot, *handle) {
+            let surface = body
+                .material()
+                .map(|material| PhysicsMaterialOverride::read(&material))
+                .unwrap_or_default();
+            commands.entity(entity).insert(surface);
+        }
+    }
+}
+
+fn apply_physics_materials(
+    bodies: Query<
+        (
+            Entity,
+            &GodotNodeHandle,
+            &PhysicsMaterialOverride,
+            Option<&OwnedPhysicsMaterial>,
+        ),
+        Changed<PhysicsMaterialOverride>,
+    >,
+    mut commands: Commands,
+    mut godot: GodotAccess,
+) {
+    for (entity, handle, surface, owned) in &bodies {
+        let Some(mut body) = Body::get(&mut godot, *handle) else {
+            continue;
+        };
+        let current = body.material();
+        let current_surface = current
+            .as_ref()
+            .map(PhysicsMaterialOverride::read)
+            .unwrap_or_default();
+        if current_surface == *surface {
+            continue;
+        }
+
+        match current {
+            Some(mut material) if owned.is_some_and(|owned| owned.0 == material.instance_id()) => {
+                surface.write(&mut material);
+            }
+            _ => {
+                let mut material = PhysicsMaterial::new_gd();
+                surface.write(&mut material);
+                body.set_material(&material);
+                commands
+                    .entity(entity)
+                    .insert(OwnedPhysicsMaterial(material.instance_id()));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_matches_a_body_without_material() {
+        let surface = PhysicsMaterialOverride::default();
+        assert_eq!(surface.friction, 1.0);
+        assert_eq!(surface.bounce, 0.0);
+        assert!(!surface.rough && !surface.absorbent);
+    }
+}
@@ -0,0 +1,335 @@
+//! Side-on platformer movement for `CharacterBody2D` entities.
+//!
+//! With [`Platformer2DControllerPlugin`], an entity whose node is a
+//! `CharacterBody2D` and which has a [`PlatformerController2D`] runs, jumps and
+//! falls each physics step: gravity with a terminal velocity, acceleration and
+//! deceleration, jump buffering, coyote time, and slopes through the body's own
+//! floor snapping. The plugin moves the body with `move_and_slide`, so Godot's
+//! collision response is what keeps it on the ground:
+//!
+//! ```ignore
+//! fn make_controllable(mut commands: Commands, player: Single<Entity, Added<Player>>) {
+//!     commands.entity(*player).insert((
+//!         PlatformerController2D { speed: 250.0, ..default() },
+//!         PlatformerActions::default(),
+//!     ));
+//! }
+//!
+//! fn jump_sound(jumped: On<PlatformerJumped>, mut sfx: MessageWriter<PlaySfx>) {
+//!     sfx.write(PlaySfx::Jump);
+//! }
+//! ```
+//!
+//! Input comes from [`PlatformerInput`]: fill it from your own systems (an AI,
+//! a replay), or add [`PlatformerActions`] to read it from Godot input actions.
+//! [`PlatformerState`] reports the outcome for animation. The components are
+//! reflected, so the tuning can be changed live from the inspector.
+
+use bevy_app::{App, FixedUpdate, Plugin};
+use bevy_ecs::change_detection::DetectChanges;
+use bevy_ecs::component::Component;
+use bevy_ecs::entity::Entity;
+use bevy_ecs::event::EntityEvent;
+use bevy_ecs::prelude::ReflectComponent;
+use bevy_ecs::schedule::{IntoScheduleConfigs, SystemSet};
+use bevy_ecs::system::{Commands, Query, Res};
+use bevy_ecs::world::Ref;
+use bevy_math::Vec2;
+use bevy_reflect::Reflect;
+use bevy_time::Time;
+use godot::builtin::Vector2;
+use godot::classes::CharacterBody2D;
+
+use crate::interop::{GodotAccess, GodotNodeHandle};
+use crate::plugins::input::GodotActions;
+
+/// Plugin moving [`PlatformerController2D`] entities. See the
+/// [module docs](self).
+#[derive(Default)]
+pub struct Platformer2DControllerPlugin;
+
+impl Plugin for Platformer2DControllerPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<PlatformerController2D>()
+            .register_type::<PlatformerActions>()
+            .register_type::<PlatformerInput>()
+            .register_type::<PlatformerState>()
+            .add_systems(
+                FixedUpdate,
+                (read_platformer_actions, move_platformers)
+                    .chain()
+                    .in_set(PlatformerSet),
+            );
+    }
+}
+
+#[cfg(debug_assertions)]
+crate::inventory::submit! {
+    crate::watchdog::PluginRequirement::new::<PlatformerController2D, Platformer2DControllerPlugin>()
+}
+
+/// The `FixedUpdate` systems reading input and moving bodies. Order your own
+/// input systems `.before` it and animation `.after` it.
+#[derive(SystemSet, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PlatformerSet;
+
+/// Movement tuning, in pixels and seconds. Godot's y axis points down, so a jump
+/// is a negative velocity.
+#[derive(Component, Reflect, Debug, Clone, Copy, PartialEq)]
+#[reflect(Component)]
+#[require(PlatformerInput, PlatformerState)]
+pub struct PlatformerController2D {
+    /// Top running speed.
+    pub speed: f32,
+    /// How fast running speed is gained.
+    pub acceleration: f32,
+    /// How fast running speed is lost once input is released.
+    pub deceleration: f32,
+    /// Vertical velocity a jump starts with.
+    pub jump_velocity: f32,
+    pub gravity: f32,
+    /// Falling speed is capped here.
+    pub max_fall_speed: f32,
+    /// A jump pressed this long before landing still happens on landing.
+    pub jump_buffer: f32,
+    /// A jump pressed this long after walking off a ledge still happens.
+    pub coyote_time: f32,
+    /// Steepest slope, in radians, that counts as floor.
+    pub floor_max_angle: f32,
+    /// How far down the body snaps to the floor, keeping it on downward slopes.
+    pub floor_snap_length: f32,
+}
+
+impl Default for PlatformerController2D {
+    fn default() -> Self {
+        Self {
+            speed: 250.0,
+            acceleration: 2500.0,
+            deceleration: 2500.0,
+            jump_velocity: -400.0,
+            gravity: 980.0,
+            max_fall_speed: 1000.0,
+            jump_buffer: 0.1,
+            coyote_time: 0.1,
+            floor_max_angle: 45f32.to_radians(),
+            floor_snap_length: 8.0,
+        }
+    }
+}
+
+/// This step's input: a run direction from `-1.0` (left) to `1.0` (right), and
+/// whether jump was just pressed.
+#[derive(Component, Reflect, Debug, Default, Clone, Copy, PartialEq)]
+#[reflect(Component)]
+pub struct PlatformerInput {
+    pub direction: f32,
+    pub jump_pressed: bool,
+}
+
+/// Fill [`PlatformerInput`] from these Godot input actions.
+#[derive(Component, Reflect, Debug, Clone, PartialEq, Eq)]
+#[reflect(Component)]
+pub struct PlatformerActions {
+    pub left: String,
+    pub right: String,
+    pub jump: String,
+}
+
+impl Default for PlatformerActions {
+    fn default() -> Self {
+        Self {
+            left: "move_left".into(),
+            right: "move_right".into(),
+            jump: "jump".into(),
+        }
+    }
+}
+
+/// Where the controller left the body after this step.
+#[derive(Component, Reflect, Debug, Default, Clone, Copy, PartialEq)]
+#[reflect(Component)]
+pub struct PlatformerState {
+    pub on_floor: bool,
+    /// Running, as opposed to sliding to a stop.
+    pub moving: bool,
+    /// The last direction input pointed.
+    pub facing_left: bool,
+    pub velocity: Vec2,
+    /// Seconds a buffered jump stays pending.
+    jump_buffered: f32,
+    /// Seconds left to jump after leaving the floor.
+    coyote: f32,
+}
+
+/// A [`PlatformerController2D`] entity jumped this step.
+#[derive(EntityEvent, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PlatformerJumped {
+    pub entity: Entity,
+}
+
+impl PlatformerState {
+    /// Advance by `delta` seconds: the new velocity, and whether a jump started.
+    fn step(
+        &mut self,
+        controller: &PlatformerController2D,
+        input: &PlatformerInput,
+        on_floor: bool,
+        mut velocity: Vec2,
+        delta: f32,
+    ) -> (Vec2, bool) {
+        self.coyote = if on_floor {
+            controller.coyote_time
+        } else {
+            (self.coyote - delta).max(0.0)
+        };
+        self.jump_buffered = if input.jump_pressed {
+            controller.jump_buffer
+        } else {
+            (self.jump_buffered - delta).max(0.0)
+        };
+
+        if !on_floor {
+            velocity.y = (velocity.y + controller.gravity * delta).min(controller.max_fall_speed);
+        }
+
+        // A zero buffer or coyote time still allows a jump pressed on the floor.
+        let can_jump = on_floor || self.coyote > 0.0;
+        let wants_jump = input.jump_pressed || self.jump_buffered > 0.0;
+        let jumped = can_jump && wants_jump;
+        if jumped {
+            velocity.y = controller.jump_velocity;
+            self.jump_buffered = 0.0;
+            self.coyote = 0.0;
+        }
+
+        let direction = input.direction.clamp(-1.0, 1.0);
+        let (target, rate) = if direction != 0.0 {
+            (direction * controller.speed, controller.acceleration)
+        } else {
+            (0.0, controller.deceleration)
+        };
+        velocity.x = move_toward(velocity.x, target, rate * delta);
+
+        self.moving = direction != 0.0;
+        if direction != 0.0 {
+            self.facing_left = direction < 0.0;
+        }
+        (velocity, jumped)
+    }
+}
+
+fn move_toward(from: f32, to: f32, max_delta: f32) -> f32 {
+    if (to - from).abs() <= max_delta {
+        to
+    } else {
+        from + (to - from).signum() * max_delta
+    }
+}
+
+fn read_platformer_actions(
+    mut inputs: Query<(&PlatformerActions, &mut PlatformerInput)>,
+    actions: Option<Res<GodotActions>>,
+) {
+    let Some(actions) = actions else {
+        return;
+    };
+    for (names, mut input) in &mut inputs {
+        input.direction = actions.axis(&names.left, &names.right);
+        input.jump_pressed = actions.just_pressed(&names.jump);
+    }
+}
+
+fn move_platformers(
+    mut platformers: Query<(
+        Entity,
+        &GodotNodeHandle,
+        Ref<PlatformerController2D>,
+        &PlatformerInput,
+        &mut PlatformerState,
+    )>,
+    time: Res<Time>,
+    mut commands: Commands,
+    mut godot: GodotAccess,
+) {
+    let delta = time.delta_secs();
+    for (entity, handle, controller, input, mut state) in &mut platformers {
+        let Some(mut body) = godot.try_get::<CharacterBody2D>(*handle) else {
+            continue;
+        };
+        if controller.is_changed() {
+            body.set_floor_max_angle(controller.floor_max_angle);
+            body.set_floor_snap_length(controller.floor_snap_length);
+            body.set_floor_stop_on_slope_enabled(true);
+            body.set_floor_constant_speed_enabled(true);
+        }
+
+        let velocity = body.get_velocity();
+        let (velocity, jumped) = state.step(
+            &controller,
+            input,
+            body.is_on_floor(),
+            Vec2::new(velocity.x, velocity.y),
+            delta,
+        );
+        body.set_velocity(Vector2::new(velocity.x, velocity.y));
+        body.move_and_slide();
+
+        let velocity = body.get_velocity();
+        state.velocity = Vec2::new(velocity.x, velocity.y);
+        state.on_floor = body.is_on_floor();
+        if jumped {
+            commands.trigger(PlatformerJumped { entity });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const STEP: f32 = 1.0 / 60.0;
+
+    #[test]
+    fn buffered_and_coyote_jumps() {
+        let controller = PlatformerController2D::default();
+        let mut state = PlatformerState::default();
+        let press = PlatformerInput {
+            jump_pressed: true,
+            ..Default::default()
+        };
+        let idle = PlatformerInput::default();
+
+        // Pressed in the air, just before landing: jumps on the landing step.
+        let (_, jumped) = state.step(&controller, &press, false, Vec2::ZERO, STEP);
+        assert!(!jumped);
+        let (velocity, jumped) = state.step(&controller, &idle, true, Vec2::ZERO, STEP);
+        assert!(jumped);
+        assert_eq!(velocity.y, controller.jump_velocity);
+
+        // Walked off a ledge a step ago: still allowed to jump.
+        let mut state = PlatformerState::default();
+        state.step(&controller, &idle, true, Vec2::ZERO, STEP);
+        let (_, jumped) = state.step(&controller, &press, false, Vec2::ZERO, STEP);
+        assert!(jumped);
+
+        // Long after leaving the floor: no jump, and gravity applies.
+        let (velocity, jumped) = state.step(&controller, &press, false, Vec2::ZERO, 1.0);
+        assert!(!jumped);
+        assert!(velocity.y > 0.0);
+    }
+
+    #[test]
+    fn running_accelerates_to_speed() {
+        let controller = PlatformerController2D::default();
+        let mut state = PlatformerState::default();
+        let right = PlatformerInput {
+            direction: 1.0,
+            ..Default::default()
+        };
+        let (velocity, _) = state.step(&controller, &right, true, Vec2::ZERO, STEP);
+        assert!(velocity.x > 0.0 && velocity.x < controller.speed);
+        let (velocity, _) = state.step(&controller, &right, true, velocity, 1.0);
+        assert_eq!(velocity.x, controller.speed);
+        assert!(state.moving && !state.facing_left);
+    }
+}
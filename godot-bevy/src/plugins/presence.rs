@@ -0,0 +1,289 @@
+//! Placeholder nodes for pure-ECS entities that need to show up on the Godot side.
+//!
+//! Large populations (bullets, crowds, particles-as-entities) are cheapest without
+//! nodes, but then Godot can't see them: they aren't in groups, don't y-sort, and
+//! get no visibility culling. [`GodotPresence`] gives such an entity a bare
+//! `Node2D`/`Node3D` (or plain `Node`) of its own, in the given groups, which then
+//! mirrors like any other node -- transform sync moves it, and
+//! `get_tree().get_nodes_in_group("enemies")` in GDScript finds it.
+//!
+//! ```ignore
+//! commands.spawn((
+//!     Transform::from_xyz(120.0, 40.0, 0.0),
+//!     GodotPresence::node_2d()
+//!         .in_group("enemies")
+//!         .under("/root/Main/YSorted")
+//!         .with_visibility_rect(Rect::from_center_size(Vec2::ZERO, Vec2::splat(32.0))),
+//! ));
+//!
+//! fn think(enemies: Query<&mut Brain, With<OnScreen>>) {
+//!     // only the enemies the camera can see
+//! }
+//! ```
+//!
+//! With a visibility rect (2D) or AABB (3D), a `VisibleOnScreenNotifier` child keeps
+//! the [`OnScreen`] marker current. Removing the component or despawning the entity
+//! frees the node.
+
+use bevy_app::{App, Last, Plugin};
+use bevy_ecs::component::Component;
+use bevy_ecs::entity::Entity;
+use bevy_ecs::event::EntityEvent;
+use bevy_ecs::lifecycle::Remove;
+use bevy_ecs::observer::On;
+use bevy_ecs::query::{Added, Without};
+use bevy_ecs::system::{Commands, Query, Res};
+use bevy_math::bounding::Aabb3d;
+use bevy_math::{Rect, Vec3};
+use godot::builtin::{Aabb, Rect2, Variant, Vector2, Vector3};
+use godot::classes::{Node, Node2D, Node3D, VisibleOnScreenNotifier2D, VisibleOnScreenNotifier3D};
+use godot::obj::{Gd, NewAlloc};
+use godot::prelude::Callable;
+use tracing::warn;
+
+use crate::interop::{GodotAccess, GodotNodeHandle};
+use crate::node_tree_view::find_node;
+use crate::plugins::event_bridge::GodotEventSender;
+use crate::plugins::scene_tree::SceneTreeRef;
+use crate::watchers::scene_tree_watcher::exclude_from_mirror;
+
+/// Plugin creating nodes for [`GodotPresence`]. See the [module docs](self).
+#[derive(Default)]
+pub struct GodotPresencePlugin;
+
+impl Plugin for GodotPresencePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_observer(on_screen_entered)
+            .add_observer(on_screen_exited)
+            .add_observer(on_presence_removed)
+            .add_systems(Last, spawn_presence_nodes);
+    }
+}
+
+#[cfg(debug_assertions)]
+crate::inventory::submit! {
+    crate::watchdog::PluginRequirement::new::<GodotPresence, GodotPresencePlugin>()
+}
+
+/// Give a node-less entity a placeholder node. See the [module docs](self).
+#[derive(Component, Debug, Clone)]
+pub struct GodotPresence {
+    pub kind: PresenceKind,
+    /// Groups the node joins.
+    pub groups: Vec<String>,
+    /// Path of the parent node, wildcards allowed. `None` uses the current scene.
+    pub parent: Option<String>,
+    pub visibility: Option<PresenceVisibility>,
+}
+
+/// The class of a presence node.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PresenceKind {
+    Node,
+    Node2D,
+    Node3D,
+}
+
+/// Area a `VisibleOnScreenNotifier` watches, relative to the node.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PresenceVisibility {
+    Rect(Rect),
+    Aabb(Aabb3d),
+}
+
+impl GodotPresence {
+    pub fn new(kind: PresenceKind) -> Self {
+        Self {
+            kind,
+            groups: Vec::new(),
+            parent: None,
+            visibility: None,
+        }
+    }
+
+    pub fn node() -> Self {
+        Self::new(PresenceKind::Node)
+    }
+
+    pub fn node_2d() -> Self {
+        Self::new(PresenceKind::Node2D)
+    }
+
+    pub fn node_3d() -> Self {
+        Self::new(PresenceKind::Node3D)
+    }
+
+    pub fn in_group(mut self, group: impl Into<String>) -> Self {
+        self.groups.push(group.into());
+        self
+    }
+
+    /// Add the node under the node at `path` instead of the current scene, e.g. a
+    /// `y_sort_enabled` container.
+    pub fn under(mut self, path: impl Into<String>) -> Self {
+        self.parent = Some(path.into());
+        self
+    }
+
+    /// Track [`OnScreen`] for this rect. Only for [`PresenceKind::Node2D`].
+    pub fn with_visibility_rect(mut self, rect: Rect) -> Self {
+        self.visibility = Some(PresenceVisibility::Rect(rect));
+        self
+    }
+
+    /// Track [`OnScreen`] for this box. Only for [`PresenceKind::Node3D`].
+    pub fn with_visibility_aabb(mut self, aabb: Aabb3d) -> Self {
+        self.visibility = Some(PresenceVisibility::Aabb(aabb));
+        self
+    }
+}
+
+/// Marks a [`GodotPresence`] entity whose visibility area is on screen.
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct OnScreen;
+
+/// A presence node's visibility area entered the screen.
+#[derive(EntityEvent, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PresenceScreenEntered {
+    pub entity: Entity,
+}
+
+/// A presence node's visibility area left the screen.
+#[derive(EntityEvent, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PresenceScreenExited {
+    pub entity: Entity,
+}
+
+fn on_screen_entered(entered: On<PresenceScreenEntered>, mut commands: Commands) {
+    if let Ok(mut entity) = commands.get_entity(entered.entity) {
+        entity.insert(OnScreen);
+    }
+}
+
+fn on_screen_exited(exited: On<PresenceScreenExited>, mut commands: Commands) {
+    if let Ok(mut entity) = commands.get_entity(exited.entity) {
+        entity.remove::<OnScreen>();
+    }
+}
+
+/// Dropping the handle frees the node (see the core plugin's removal observer).
+fn on_presence_removed(removed: On<Remove, GodotPresence>, mut commands: Commands) {
+    if let Ok(mut entity) = commands.get_entity(removed.entity) {
+        entity.remove::<(GodotNodeHandle, OnScreen)>();
+    }
+}
+
+fn spawn_presence_nodes(
+    added: Query<(Entity, &GodotPresence), (Added<GodotPresence>, Without<GodotNodeHandle>)>,
+    sender: Res<GodotEventSender>,
+    mut commands: Commands,
+    mut scene_tree: SceneTreeRef,
+    _godot: GodotAccess,
+) {
+    for (entity, presence) in &added {
+        let tree = scene_tree.get();
+        let parent = match &presence.parent {
+            Some(path) => tree
+                .get_root()
+                .and_then(|root| find_node(&root.upcast(), path)),
+            None => tree.get_current_scene(),
+        };
+        let Some(mut parent) = parent else {
+            warn!(
+                "GodotPresence: no parent node for {entity} ({:?})",
+                presence.parent
+            );
+            continue;
+        };
+
+        let mut node: Gd<Node> = match presence.kind {
+            PresenceKind::Node => Node::new_alloc(),
+            PresenceKind::Node2D => Node2D::new_alloc().upcast(),
+            PresenceKind::Node3D => Node3D::new_alloc().upcast(),
+        };
+        node.set_name(&format!("Presence{entity}"));
+        for group in &presence.groups {
+            node.add_to_group(group.as_str());
+        }
+        if let Some(visibility) = presence.visibility {
+            add_visibility_notifier(&mut node, presence.kind, visibility, entity, &sender);
+        }
+        parent.add_child(&node);
+
+        commands.entity(entity).insert(GodotNodeHandle::new(node));
+    }
+}
+
+fn add_visibility_notifier(
+    node: &mut Gd<Node>,
+    kind: PresenceKind,
+    visibility: PresenceVisibility,
+    entity: Entity,
+    sender: &GodotEventSender,
+) {
+    let mut notifier: Gd<Node> = match (kind, visibility) {
+        (PresenceKind::Node2D, PresenceVisibility::Rect(rect)) => {
+            let mut notifier = VisibleOnScreenNotifier2D::new_alloc();
+            notifier.set_rect(Rect2::new(
+                Vector2::new(rect.min.x, rect.min.y),
+                Vector2::new(rect.width(), rect.height()),
+            ));
+            notifier.upcast()
+        }
+        (PresenceKind::Node3D, PresenceVisibility::Aabb(aabb)) => {
+            let min = Vec3::from(aabb.min);
+            let size = Vec3::from(aabb.max) - min;
+            let mut notifier = VisibleOnScreenNotifier3D::new_alloc();
+            notifier.set_aabb(Aabb::new(
+                Vector3::new(min.x, min.y, min.z),
+                Vector3::new(size.x, size.y, size.z),
+            ));
+            notifier.upcast()
+        }
+        _ => {
+            warn!("GodotPresence: {visibility:?} doesn't fit a {kind:?} node ({entity})");
+            return;
+        }
+    };
+    exclude_from_mirror(&mut notifier);
+
+    let tx = sender.clone();
+    notifier.connect(
+        "screen_entered",
+        &Callable::from_fn("presence_screen_entered", move |_| {
+            tx.send(PresenceScreenEntered { entity });
+            Variant::nil()
+        }),
+    );
+    let tx = sender.clone();
+    notifier.connect(
+        "screen_exited",
+        &Callable::from_fn("presence_screen_exited", move |_| {
+            tx.send(PresenceScreenExited { entity });
+            Variant::nil()
+        }),
+    );
+    node.add_child(&notifier);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_ecs::world::World;
+
+    #[test]
+    fn screen_events_maintain_on_screen_marker() {
+        let mut world = World::new();
+        world.add_observer(on_screen_entered);
+        world.add_observer(on_screen_exited);
+        let entity = world.spawn_empty().id();
+
+        world.trigger(PresenceScreenEntered { entity });
+        world.flush();
+        assert!(world.entity(entity).contains::<OnScreen>());
+
+        world.trigger(PresenceScreenExited { entity });
+        world.flush();
+        assert!(!world.entity(entity).contains::<OnScreen>());
+    }
+}
@@ -0,0 +1,257 @@
+//! Ragdolls on `PhysicalBoneSimulator3D`, switched on and off with one command.
+//!
+//! Going limp takes a few steps in Godot: activate the skeleton's
+//! `PhysicalBoneSimulator3D`, give it full influence and start simulating its
+//! bones. Getting back up means fading the influence out so the animation takes
+//! over smoothly, then stopping the simulation. [`ActivateRagdoll`] and
+//! [`DeactivateRagdoll`] are entity commands doing each in one go, on the
+//! simulator's entity or on any ancestor of it, like the character's root:
+//!
+//! ```ignore
+//! fn knock_out(hit: On<Hit>, mut commands: Commands) {
+//!     commands.entity(hit.entity).queue(ActivateRagdoll::all());
+//! }
+//!
+//! fn get_up(mut commands: Commands, recovered: Query<Entity, (With<Ragdolling>, With<Recovered>)>) {
+//!     for entity in &recovered {
+//!         commands
+//!             .entity(entity)
+//!             .queue(DeactivateRagdoll::blend(Duration::from_millis(600)));
+//!     }
+//! }
+//!
+//! app.add_observer(|up: On<RagdollDeactivated>| info!("{} is back up", up.entity));
+//! ```
+//!
+//! [`RagdollActivated`] is triggered once the bones simulate, and
+//! [`RagdollDeactivated`] once the blend is over and the animation is fully back
+//! in control; [`Ragdolling`] marks the entity in between. Activating again
+//! during a blend cancels it. [`ActivateRagdoll::bones`] simulates only some
+//! bones, for an arm going limp.
+//!
+//! Needs Godot 4.3 or later, and [`GodotRagdollPlugin`] for blends.
+
+use std::time::Duration;
+
+use bevy_app::{App, Plugin, Update};
+use bevy_ecs::component::Component;
+use bevy_ecs::entity::Entity;
+use bevy_ecs::event::EntityEvent;
+use bevy_ecs::system::{Commands, EntityCommand, Query, Res};
+use bevy_ecs::world::EntityWorldMut;
+use bevy_time::Time;
+use godot::builtin::{Array, StringName};
+use godot::classes::{Node, PhysicalBoneSimulator3D};
+use godot::obj::Gd;
+use tracing::warn;
+
+use crate::interop::{GodotAccess, GodotNodeHandle};
+
+/// Plugin running [`DeactivateRagdoll`] blends. See the [module docs](self).
+#[derive(Default)]
+pub struct GodotRagdollPlugin;
+
+impl Plugin for GodotRagdollPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, blend_out_ragdolls);
+    }
+}
+
+/// Entity command turning the entity's ragdoll on. See the [module docs](self).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ActivateRagdoll {
+    /// Bones to simulate, by name; empty for all of them.
+    pub bones: Vec<String>,
+}
+
+impl ActivateRagdoll {
+    pub fn all() -> Self {
+        Self::default()
+    }
+
+    pub fn bones(bones: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        Self {
+            bones: bones.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+/// Entity command handing the entity's skeleton back to its animation. See the
+/// [module docs](self).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DeactivateRagdoll {
+    /// How long the ragdoll fades into the animation; zero snaps back at once.
+    pub blend: Duration,
+}
+
+impl DeactivateRagdoll {
+    pub fn immediate() -> Self {
+        Self::default()
+    }
+
+    pub fn blend(duration: Duration) -> Self {
+        Self { blend: duration }
+    }
+}
+
+/// On entities whose ragdoll is active, from [`ActivateRagdoll`] until
+/// [`RagdollDeactivated`].
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Ragdolling;
+
+/// The entity's bones started simulating.
+#[derive(EntityEvent, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RagdollActivated {
+    pub entity: Entity,
+}
+
+/// The entity's ragdoll finished blending out and stopped simulating.
+#[derive(EntityEvent, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RagdollDeactivated {
+    pub entity: Entity,
+}
+
+/// A [`DeactivateRagdoll`] fading out.
+#[derive(Component, Debug)]
+struct RagdollBlend {
+    simulator: GodotNodeHandle,
+    elapsed: Duration,
+    duration: Duration,
+}
+
+impl RagdollBlend {
+    /// The simulator's influence after `elapsed`, from 1 down to 0.
+    fn influence(&self) -> f32 {
+        if self.duration.is_zero() {
+            0.0
+        } else {
+            1.0 - (self.elapsed.as_secs_f32() / self.duration.as_secs_f32()).min(1.0)
+        }
+    }
+}
+
+impl EntityCommand for ActivateRagdoll {
+    type Out = ();
+
+    fn apply(self, mut entity: EntityWorldMut) {
+        let Some(mut simulator) = find_simulator(&entity) else {
+            warn!(
+                "ActivateRagdoll: {} has no PhysicalBoneSimulator3D",
+                entity.id()
+            );
+            return;
+        };
+        let bones: Array<StringName> = self
+            .bones
+            .iter()
+            .map(|bone| StringName::from(bone.as_str()))
+            .collect();
+        simulator.set_active(true);
+        simulator.set_influence(1.0);
+        simulator
+            .physical_bones_start_simulation_ex()
+            .bones(&bones)
+            .done();
+
+        entity.remove::<RagdollBlend>().insert(Ragdolling);
+        entity.trigger(|entity| RagdollActivated { entity });
+    }
+}
+
+impl EntityCommand for DeactivateRagdoll {
+    type Out = ();
+
+    fn apply(self, mut entity: EntityWorldMut) {
+        let Some(simulator) = find_simulator(&entity) else {
+            warn!(
+                "DeactivateRagdoll: {} has no PhysicalBoneSimulator3D",
+                entity.id()
+            );
+            return;
+        };
+        if self.blend.is_zero() || !simulator.is_simulating_physics() {
+            finish_blend(simulator);
+            entity.remove::<(Ragdolling, RagdollBlend)>();
+            entity.trigger(|entity| RagdollDeactivated { entity });
+            return;
+        }
+        entity.insert(RagdollBlend {
+            simulator: GodotNodeHandle::new(simulator.clone()),
+            elapsed: Duration::ZERO,
+            duration: self.blend,
+        });
+    }
+}
+
+/// The entity's simulator: its own node, or the first one below it.
+fn find_simulator(entity: &EntityWorldMut) -> Option<Gd<PhysicalBoneSimulator3D>> {
+    let handle = entity.get::<GodotNodeHandle>()?;
+    let node = Gd::<Node>::try_from_instance_id(handle.instance_id()).ok()?;
+    match node.try_cast::<PhysicalBoneSimulator3D>() {
+        Ok(simulator) => Some(simulator),
+        Err(node) => node
+            .find_children_ex("*")
+            .type_("PhysicalBoneSimulator3D")
+            .owned(false)
+            .done()
+            .iter_shared()
+            .find_map(|child| child.try_cast::<PhysicalBoneSimulator3D>().ok()),
+    }
+}
+
+/// Stop simulating and leave the simulator ready for the next activation.
+fn finish_blend(mut simulator: Gd<PhysicalBoneSimulator3D>) {
+    simulator.physical_bones_stop_simulation();
+    simulator.set_active(false);
+    simulator.set_influence(1.0);
+}
+
+fn blend_out_ragdolls(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut blends: Query<(Entity, &mut RagdollBlend)>,
+    mut godot: GodotAccess,
+) {
+    for (entity, mut blend) in &mut blends {
+        let Some(mut simulator) = godot.try_get::<PhysicalBoneSimulator3D>(blend.simulator) else {
+            commands
+                .entity(entity)
+                .remove::<(Ragdolling, RagdollBlend)>();
+            continue;
+        };
+        blend.elapsed = (blend.elapsed + time.delta()).min(blend.duration);
+        let influence = blend.influence();
+        simulator.set_influence(influence);
+
+        if influence <= 0.0 {
+            finish_blend(simulator);
+            commands
+                .entity(entity)
+                .remove::<(Ragdolling, RagdollBlend)>();
+            commands.trigger(RagdollDeactivated { entity });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use godot::obj::InstanceId;
+
+    use super::*;
+
+    #[test]
+    fn influence_fades_from_one_to_zero() {
+        let mut blend = RagdollBlend {
+            simulator: GodotNodeHandle::from_instance_id(InstanceId::from_i64(1)),
+            elapsed: Duration::ZERO,
+            duration: Duration::from_secs(2),
+        };
+        assert_eq!(blend.influence(), 1.0);
+        blend.elapsed = Duration::from_secs(1);
+        assert_eq!(blend.influence(), 0.5);
+        blend.elapsed = Duration::from_secs(3);
+        assert_eq!(blend.influence(), 0.0);
+        blend.duration = Duration::ZERO;
+        assert_eq!(blend.influence(), 0.0);
+    }
+}
@@ -0,0 +1,689 @@
+//! Saving a large world one region at a time.
+//!
+//! Space is cut into a grid of regions of `region_size`. Every [`Persistent`]
+//! entity belongs to the region its `Transform` is in. Regions around the
+//! [`StreamingFocus`] entities are loaded from their own file under
+//! `directory`; once every focus has moved away, a region's entities are saved
+//! back to that file and despawned. Nothing outside the regions near the player
+//! is kept in memory, and no save file grows with the size of the world.
+//!
+//! ```ignore
+//! #[derive(Component, Clone, Serialize, Deserialize)]
+//! struct Chest { gold: u32 }
+//!
+//! app.add_plugins(RegionPersistencePlugin {
+//!     directory: "user://world".into(),
+//!     region_size: Vec3::new(64.0, f32::INFINITY, 64.0),
+//!     ..default()
+//! })
+//! .add_region_component::<Chest>()
+//! .add_observer(|loaded: On<RegionLoaded>| info!("region {} is back", loaded.region));
+//!
+//! fn place_chest(mut commands: Commands) {
+//!     commands.spawn((Persistent::new("chest-17"), Chest { gold: 50 }, Transform::from_xyz(10.0, 0.0, 4.0)));
+//! }
+//!
+//! // before quitting, write out the regions still loaded:
+//! commands.trigger(SaveRegions);
+//! ```
+//!
+//! An entity is saved as its [`Persistent`] id, its `Transform` and each
+//! component registered with [`AddRegionComponentAppExt::add_region_component`].
+//! Loading spawns a Bevy entity with those; anything else, such as the node that
+//! shows it, is up to the game, e.g. an observer on one of the registered
+//! components. If an entity with the same id already exists, the saved
+//! components are inserted on it instead. Use an infinite size on an axis to
+//! ignore it, e.g. the height of a 2D or mostly flat world.
+//!
+//! The regions pair with [`ChunkStreamingPlugin`](super::ChunkStreamingPlugin):
+//! chunks stream the level's scenery, regions persist what changed in it.
+
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+use bevy_app::{App, Plugin, Update};
+use bevy_ecs::component::Component;
+use bevy_ecs::entity::Entity;
+use bevy_ecs::event::Event;
+use bevy_ecs::observer::On;
+use bevy_ecs::prelude::Resource;
+use bevy_ecs::query::{Changed, With, Without};
+use bevy_ecs::schedule::IntoScheduleConfigs;
+use bevy_ecs::system::{Commands, Query, Res, ResMut};
+use bevy_ecs::world::World;
+use bevy_math::{IVec3, Quat, Vec3};
+use bevy_transform::components::Transform;
+use godot::classes::ProjectSettings;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use tracing::{debug, warn};
+
+use crate::interop::GodotAccess;
+use crate::plugins::chunk_streaming::StreamingFocus;
+
+/// Plugin saving and loading [`Persistent`] entities by region. See the
+/// [module docs](self).
+#[derive(Debug, Clone)]
+pub struct RegionPersistencePlugin {
+    /// Where region files go: a Godot path (`user://...`) or an absolute one.
+    pub directory: String,
+    /// Size of one region along each axis; `f32::INFINITY` ignores that axis.
+    pub region_size: Vec3,
+    /// Regions within this many regions of a focus' region are loaded.
+    pub load_radius: i32,
+    /// Loaded regions are saved and unloaded once every focus is further away
+    /// than this. Keep it above `load_radius` so a focus on a border doesn't
+    /// save and reload the same region every few frames.
+    pub unload_radius: i32,
+}
+
+impl Default for RegionPersistencePlugin {
+    fn default() -> Self {
+        Self {
+            directory: "user://regions".into(),
+            region_size: Vec3::splat(64.0),
+            load_radius: 1,
+            unload_radius: 2,
+        }
+    }
+}
+
+impl Plugin for RegionPersistencePlugin {
+    fn build(&self, app: &mut App) {
+        let root = (!is_godot_path(&self.directory)).then(|| PathBuf::from(&self.directory));
+        app.init_resource::<RegionRegistry>()
+            .insert_resource(RegionPersistence::new(self.clone(), root))
+            .add_observer(on_save_regions)
+            .add_systems(
+                Update,
+                (
+                    resolve_region_directory.run_if(directory_unresolved),
+                    assign_regions,
+                    stream_regions,
+                )
+                    .chain(),
+            );
+    }
+}
+
+/// Stable identity of an entity saved by region. Ids must be unique within
+/// the world; they're how a loaded entity finds an existing copy of itself.
+#[derive(Component, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Persistent(pub String);
+
+impl Persistent {
+    pub fn new(id: impl Into<String>) -> Self {
+        Self(id.into())
+    }
+}
+
+/// The region a [`Persistent`] entity is in, kept up to date from its
+/// `Transform`.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Region(pub IVec3);
+
+/// Triggered once a region's file is read and its entities spawned. A region
+/// without a file yet loads with no entities.
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RegionLoaded {
+    pub region: IVec3,
+    pub entities: u32,
+}
+
+/// Triggered once a region's entities are written to its file.
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RegionSaved {
+    pub region: IVec3,
+    pub entities: u32,
+    /// Whether the entities were despawned, i.e. the focus moved away.
+    pub unloaded: bool,
+}
+
+/// Trigger to save every loaded region now, keeping them loaded. Use it on
+/// quit or at checkpoints.
+#[derive(Event, Debug, Clone, Copy, Default)]
+pub struct SaveRegions;
+
+/// Loaded regions and the resolved save directory.
+#[derive(Resource, Debug)]
+pub struct RegionPersistence {
+    settings: RegionPersistencePlugin,
+    /// Absolute save directory, once `ProjectSettings` has resolved it.
+    root: Option<PathBuf>,
+    loaded: HashSet<IVec3>,
+}
+
+impl RegionPersistence {
+    fn new(settings: RegionPersistencePlugin, root: Option<PathBuf>) -> Self {
+        Self {
+            settings,
+            root,
+            loaded: HashSet::new(),
+        }
+    }
+
+    /// The region containing `translation`.
+    pub fn region_of(&self, translation: Vec3) -> IVec3 {
+        let axis = |position: f32, size: f32| {
+            if size.is_finite() {
+                (position / size).floor() as i32
+            } else {
+                0
+            }
+        };
+        let size = self.settings.region_size;
+        IVec3::new(
+            axis(translation.x, size.x),
+            axis(translation.y, size.y),
+            axis(translation.z, size.z),
+        )
+    }
+
+    pub fn is_loaded(&self, region: IVec3) -> bool {
+        self.loaded.contains(&region)
+    }
+
+    pub fn loaded_regions(&self) -> impl Iterator<Item = IVec3> + '_ {
+        self.loaded.iter().copied()
+    }
+
+    /// The file `region` is saved to, once the directory is resolved.
+    pub fn region_file(&self, region: IVec3) -> Option<PathBuf> {
+        let name = format!("region_{}_{}_{}.json", region.x, region.y, region.z);
+        self.root.as_ref().map(|root| root.join(name))
+    }
+
+    /// Whether `region` is within `radius` regions of `focus`, ignoring
+    /// infinite axes.
+    fn within(&self, region: IVec3, focus: IVec3, radius: i32) -> bool {
+        let offset = (region - focus).abs();
+        offset.x <= radius && offset.y <= radius && offset.z <= radius
+    }
+
+    /// Every region within `load_radius` of `focus`.
+    fn around(&self, focus: IVec3) -> impl Iterator<Item = IVec3> {
+        let size = self.settings.region_size;
+        let radius = self.settings.load_radius.max(0);
+        let span = |size: f32| if size.is_finite() { radius } else { 0 };
+        let (sx, sy, sz) = (span(size.x), span(size.y), span(size.z));
+        (-sx..=sx).flat_map(move |x| {
+            (-sy..=sy).flat_map(move |y| (-sz..=sz).map(move |z| focus + IVec3::new(x, y, z)))
+        })
+    }
+}
+
+pub trait AddRegionComponentAppExt {
+    /// Save `C` with the [`Persistent`] entities that have it, and insert it
+    /// again when their region loads.
+    fn add_region_component<C>(&mut self) -> &mut Self
+    where
+        C: Component + Serialize + DeserializeOwned;
+}
+
+impl AddRegionComponentAppExt for App {
+    fn add_region_component<C>(&mut self) -> &mut Self
+    where
+        C: Component + Serialize + DeserializeOwned,
+    {
+        let kind = std::any::type_name::<C>();
+        let mut registry = self.world_mut().get_resource_or_init::<RegionRegistry>();
+        if registry.decoders.contains_key(kind) {
+            return self;
+        }
+        registry.encoders.push((kind, encode::<C>));
+        registry.decoders.insert(kind, decode::<C>);
+        self
+    }
+}
+
+type Encoder = fn(&World, Entity) -> Option<Result<String, serde_json::Error>>;
+type Decoder = fn(&mut World, Entity, &str) -> Result<(), serde_json::Error>;
+
+fn encode<C: Component + Serialize>(
+    world: &World,
+    entity: Entity,
+) -> Option<Result<String, serde_json::Error>> {
+    world.get::<C>(entity).map(serde_json::to_string)
+}
+
+fn decode<C: Component + DeserializeOwned>(
+    world: &mut World,
+    entity: Entity,
+    payload: &str,
+) -> Result<(), serde_json::Error> {
+    let value = serde_json::from_str::<C>(payload)?;
+    world.entity_mut(entity).insert(value);
+    Ok(())
+}
+
+/// Component codecs, in registration order.
+#[derive(Resource, Default)]
+struct RegionRegistry {
+    encoders: Vec<(&'static str, Encoder)>,
+    decoders: HashMap<&'static str, Decoder>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, PartialEq)]
+struct RegionFile {
+    region: [i32; 3],
+    entities: Vec<SavedEntity>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+struct SavedEntity {
+    id: String,
+    translation: [f32; 3],
+    rotation: [f32; 4],
+    scale: [f32; 3],
+    /// Serialized components, by type name.
+    components: Vec<(String, String)>,
+}
+
+fn is_godot_path(path: &str) -> bool {
+    path.starts_with("user://") || path.starts_with("res://")
+}
+
+fn directory_unresolved(store: Res<RegionPersistence>) -> bool {
+    store.root.is_none()
+}
+
+fn resolve_region_directory(mut store: ResMut<RegionPersistence>, mut godot: GodotAccess) {
+    let settings = godot.singleton::<ProjectSettings>();
+    let root = PathBuf::from(
+        settings
+            .globalize_path(store.settings.directory.as_str())
+            .to_string(),
+    );
+    debug!(
+        "RegionPersistencePlugin: saving {} to {}",
+        store.settings.directory,
+        root.display()
+    );
+    store.root = Some(root);
+}
+
+fn assign_regions(
+    mut commands: Commands,
+    store: Res<RegionPersistence>,
+    entities: Query<(Entity, &Transform, Option<&Region>), (With<Persistent>, Changed<Transform>)>,
+) {
+    for (entity, transform, region) in entities.iter() {
+        let current = Region(store.region_of(transform.translation));
+        if region != Some(&current) {
+            commands.entity(entity).insert(current);
+        }
+    }
+}
+
+/// Load the regions around each focus and any region a persistent entity has
+/// wandered into, then save and unload the regions every focus has left.
+fn stream_regions(world: &mut World) {
+    if world
+        .get_resource::<RegionPersistence>()
+        .is_none_or(|store| store.root.is_none())
+    {
+        return;
+    }
+
+    let focus: Vec<Vec3> = world
+        .query_filtered::<&Transform, With<StreamingFocus>>()
+        .iter(world)
+        .map(|transform| transform.translation)
+        .collect();
+    // Entities that wandered into an unloaded region load it first, so saving
+    // them doesn't overwrite what the region's file already holds.
+    let occupied: HashSet<IVec3> = world
+        .query_filtered::<&Region, With<Persistent>>()
+        .iter(world)
+        .map(|region| region.0)
+        .collect();
+
+    let store = world.resource::<RegionPersistence>();
+    let focus: Vec<IVec3> = focus
+        .into_iter()
+        .map(|position| store.region_of(position))
+        .collect();
+    let mut to_load: Vec<IVec3> = focus
+        .iter()
+        .flat_map(|&focus| store.around(focus))
+        .chain(occupied)
+        .filter(|region| !store.is_loaded(*region))
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .collect();
+    to_load.sort_by_key(|region| region.to_array());
+    for region in to_load {
+        load_region(world, region);
+    }
+
+    let store = world.resource::<RegionPersistence>();
+    let radius = store.settings.unload_radius;
+    let mut to_unload: Vec<IVec3> = store
+        .loaded_regions()
+        .filter(|&region| !focus.iter().any(|&f| store.within(region, f, radius)))
+        .collect();
+    to_unload.sort_by_key(|region| region.to_array());
+    for region in to_unload {
+        save_region(world, region, true);
+    }
+}
+
+fn load_region(world: &mut World, region: IVec3) {
+    let Some(path) = world.resource::<RegionPersistence>().region_file(region) else {
+        return;
+    };
+    let file = match std::fs::read_to_string(&path) {
+        Ok(text) => match serde_json::from_str::<RegionFile>(&text) {
+            Ok(file) => file,
+            Err(err) => {
+                warn!(
+                    "RegionPersistencePlugin: {} is not a region file: {err}",
+                    path.display()
+                );
+                // Stay unloaded: saving over it would lose whatever it holds.
+                return;
+            }
+        },
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => RegionFile::default(),
+        Err(err) => {
+            warn!(
+                "RegionPersistencePlugin: failed to read {}: {err}",
+                path.display()
+            );
+            return;
+        }
+    };
+
+    let existing: HashMap<String, Entity> = world
+        .query::<(Entity, &Persistent)>()
+        .iter(world)
+        .map(|(entity, persistent)| (persistent.0.clone(), entity))
+        .collect();
+    let decoders = world.resource::<RegionRegistry>().decoders.clone();
+
+    let entities = file.entities.len() as u32;
+    for saved in file.entities {
+        let transform = Transform {
+            translation: Vec3::from_array(saved.translation),
+            rotation: Quat::from_array(saved.rotation),
+            scale: Vec3::from_array(saved.scale),
+        };
+        let entity = match existing.get(&saved.id) {
+            Some(&entity) => {
+                world.entity_mut(entity).insert((transform, Region(region)));
+                entity
+            }
+            None => world
+                .spawn((Persistent(saved.id.clone()), transform, Region(region)))
+                .id(),
+        };
+        for (kind, payload) in &saved.components {
+            match decoders.get(kind.as_str()) {
+                Some(decode) => {
+                    if let Err(err) = decode(world, entity, payload) {
+                        warn!(
+                            "RegionPersistencePlugin: failed to load {kind} of {}: {err}",
+                            saved.id
+                        );
+                    }
+                }
+                None => warn!("RegionPersistencePlugin: {kind} is not a region component"),
+            }
+        }
+    }
+
+    world
+        .resource_mut::<RegionPersistence>()
+        .loaded
+        .insert(region);
+    debug!("RegionPersistencePlugin: loaded region {region} ({entities} entities)");
+    world.trigger(RegionLoaded { region, entities });
+}
+
+/// Write `region`'s entities to its file; on success, despawn them and forget
+/// the region if `unload`. A failed write keeps everything loaded.
+fn save_region(world: &mut World, region: IVec3, unload: bool) {
+    let Some(path) = world.resource::<RegionPersistence>().region_file(region) else {
+        return;
+    };
+
+    let mut members: Vec<(Entity, String, Transform)> = world
+        .query_filtered::<(Entity, &Persistent, &Region, &Transform), Without<StreamingFocus>>()
+        .iter(world)
+        .filter(|(_, _, entity_region, _)| entity_region.0 == region)
+        .map(|(entity, persistent, _, transform)| (entity, persistent.0.clone(), *transform))
+        .collect();
+    members.sort_by(|a, b| a.1.cmp(&b.1));
+
+    let registry = world.resource::<RegionRegistry>();
+    let entities = members
+        .iter()
+        .map(|(entity, id, transform)| SavedEntity {
+            id: id.clone(),
+            translation: transform.translation.to_array(),
+            rotation: transform.rotation.to_array(),
+            scale: transform.scale.to_array(),
+            components: registry
+                .encoders
+                .iter()
+                .filter_map(|(kind, encode)| match encode(world, *entity)? {
+                    Ok(payload) => Some((kind.to_string(), payload)),
+                    Err(err) => {
+                        warn!("RegionPersistencePlugin: failed to save {kind} of {id}: {err}");
+                        None
+                    }
+                })
+                .collect(),
+        })
+        .collect();
+    let file = RegionFile {
+        region: region.to_array(),
+        entities,
+    };
+
+    if let Err(err) = write_region_file(&path, &file) {
+        warn!(
+            "RegionPersistencePlugin: failed to write {}: {err}",
+            path.display()
+        );
+        return;
+    }
+
+    let count = members.len() as u32;
+    if unload {
+        for (entity, ..) in members {
+            world.despawn(entity);
+        }
+        world
+            .resource_mut::<RegionPersistence>()
+            .loaded
+            .remove(&region);
+    }
+    debug!("RegionPersistencePlugin: saved region {region} ({count} entities)");
+    world.trigger(RegionSaved {
+        region,
+        entities: count,
+        unloaded: unload,
+    });
+}
+
+/// Write through a temporary file, so a crash mid-write leaves the previous
+/// save intact.
+fn write_region_file(path: &Path, file: &RegionFile) -> std::io::Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let text = serde_json::to_string(file).map_err(std::io::Error::other)?;
+    let temporary = path.with_extension("json.tmp");
+    std::fs::write(&temporary, text)?;
+    std::fs::rename(&temporary, path)
+}
+
+fn on_save_regions(_: On<SaveRegions>, mut commands: Commands) {
+    commands.queue(|world: &mut World| {
+        let mut regions: Vec<IVec3> = world
+            .resource::<RegionPersistence>()
+            .loaded_regions()
+            .collect();
+        regions.sort_by_key(|region| region.to_array());
+        for region in regions {
+            save_region(world, region, false);
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Component, Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Gold(u32);
+
+    fn app(directory: &Path) -> App {
+        let settings = RegionPersistencePlugin {
+            directory: directory.display().to_string(),
+            region_size: Vec3::new(10.0, f32::INFINITY, 10.0),
+            load_radius: 0,
+            unload_radius: 1,
+        };
+        let mut app = App::new();
+        app.insert_resource(RegionPersistence::new(
+            settings,
+            Some(directory.to_path_buf()),
+        ))
+        .add_region_component::<Gold>()
+        .add_observer(on_save_regions)
+        .add_systems(Update, (assign_regions, stream_regions).chain());
+        app
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("godot-bevy-regions-{name}-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        dir
+    }
+
+    fn find(app: &mut App, id: &str) -> Option<Entity> {
+        let world = app.world_mut();
+        world
+            .query::<(Entity, &Persistent)>()
+            .iter(world)
+            .find(|(_, persistent)| persistent.0 == id)
+            .map(|(entity, _)| entity)
+    }
+
+    #[test]
+    fn region_of_floors_and_ignores_infinite_axes() {
+        let store = RegionPersistence::new(
+            RegionPersistencePlugin {
+                region_size: Vec3::new(10.0, f32::INFINITY, 10.0),
+                ..Default::default()
+            },
+            None,
+        );
+        assert_eq!(
+            store.region_of(Vec3::new(15.0, 1e6, -0.5)),
+            IVec3::new(1, 0, -1)
+        );
+        assert_eq!(store.around(IVec3::ZERO).count(), 9);
+    }
+
+    #[test]
+    fn regions_left_behind_are_saved_and_reloaded() {
+        let dir = temp_dir("roundtrip");
+        let mut app = app(&dir);
+        let player = app
+            .world_mut()
+            .spawn((StreamingFocus, Transform::default()))
+            .id();
+        app.world_mut().spawn((
+            Persistent::new("chest"),
+            Gold(50),
+            Transform::from_xyz(5.0, 0.0, 5.0),
+        ));
+        app.update();
+        assert!(
+            app.world()
+                .resource::<RegionPersistence>()
+                .is_loaded(IVec3::ZERO)
+        );
+
+        // Two regions away: region 0 is saved and its chest despawned.
+        app.world_mut()
+            .entity_mut(player)
+            .insert(Transform::from_xyz(25.0, 0.0, 5.0));
+        app.update();
+        assert!(find(&mut app, "chest").is_none());
+        assert!(dir.join("region_0_0_0.json").exists());
+        assert!(
+            !app.world()
+                .resource::<RegionPersistence>()
+                .is_loaded(IVec3::ZERO)
+        );
+
+        app.world_mut()
+            .entity_mut(player)
+            .insert(Transform::from_xyz(1.0, 0.0, 1.0));
+        app.update();
+        let chest = find(&mut app, "chest").expect("chest reloaded");
+        assert_eq!(app.world().get::<Gold>(chest), Some(&Gold(50)));
+        assert_eq!(
+            app.world().get::<Transform>(chest).unwrap().translation,
+            Vec3::new(5.0, 0.0, 5.0)
+        );
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn entity_moving_into_a_saved_region_keeps_its_contents() {
+        let dir = temp_dir("wander");
+        let mut app = app(&dir);
+        app.world_mut()
+            .spawn((StreamingFocus, Transform::default()));
+        app.world_mut().spawn((
+            Persistent::new("far"),
+            Gold(1),
+            Transform::from_xyz(55.0, 0.0, 0.0),
+        ));
+        // Region 5 loads for its entity, then saves since no focus is near.
+        app.update();
+        assert!(find(&mut app, "far").is_none());
+
+        let wanderer = app
+            .world_mut()
+            .spawn((Persistent::new("wanderer"), Gold(2), Transform::default()))
+            .id();
+        app.update();
+        app.world_mut()
+            .entity_mut(wanderer)
+            .insert(Transform::from_xyz(52.0, 0.0, 0.0));
+        app.update();
+
+        let text = std::fs::read_to_string(dir.join("region_5_0_0.json")).unwrap();
+        let file: RegionFile = serde_json::from_str(&text).unwrap();
+        let ids: Vec<&str> = file.entities.iter().map(|e| e.id.as_str()).collect();
+        assert_eq!(ids, ["far", "wanderer"]);
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn save_regions_keeps_them_loaded() {
+        let dir = temp_dir("save-all");
+        let mut app = app(&dir);
+        app.world_mut()
+            .spawn((StreamingFocus, Transform::default()));
+        app.world_mut()
+            .spawn((Persistent::new("coin"), Gold(3), Transform::default()));
+        app.update();
+
+        app.world_mut().trigger(SaveRegions);
+        app.world_mut().flush();
+        assert!(dir.join("region_0_0_0.json").exists());
+        assert!(find(&mut app, "coin").is_some());
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
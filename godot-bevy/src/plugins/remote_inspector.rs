@@ -0,0 +1,376 @@
+//! The entity inspector's data over TCP, for headless and exported builds.
+//!
+//! [`GodotDebuggerPlugin`](super::GodotDebuggerPlugin) only talks to the editor
+//! that launched the game. [`RemoteInspectorPlugin`] serves the same snapshots
+//! and accepts the same edits on a local TCP port, so a tool can attach to a
+//! dedicated server or a shipped build:
+//!
+//! ```ignore
+//! #[bevy_app]
+//! fn build_app(app: &mut App) {
+//!     app.add_plugins(GodotDefaultPlugins)
+//!         .add_plugins(RemoteInspectorPlugin::default());
+//! }
+//! ```
+//!
+//! # Protocol
+//!
+//! One JSON object per line, both ways. On connecting, a client receives
+//! `{"message": "bevy:types", "data": [...]}`, then `bevy:entities` and
+//! `bevy:resources` every `update_interval` seconds, with the same data as the
+//! editor's messages: entities are `[entity_bits, name, has_godot_node,
+//! parent_bits, components]`, and components and resources are reflected into
+//! `{"name", "short_name", "value"}` objects. Entity bits are decimal strings,
+//! since JSON numbers lose precision above 2^53; `parent_bits` is `null` for
+//! entities without a parent.
+//!
+//! A client sends `{"command": "...", "data": [...]}`, where the command is one of
+//! the editor panel's edits (`spawn_entity`, `despawn_entity`,
+//! `insert_component`, `remove_component`, `insert_resource`,
+//! `remove_resource`) with the same arguments, entities again as decimal
+//! strings, or `snapshot` to get the entities and resources right away. An edit
+//! is answered with a fresh snapshot; a malformed line with `bevy:error`. A
+//! client sending more than 64 KiB without a newline is disconnected, and so is
+//! one that leaves more than 16 MiB of messages unread. Try it with
+//! `nc localhost 6010`.
+//!
+//! There is no authentication: anyone who can reach the port can edit the
+//! world. The default address only accepts connections from the same machine;
+//! keep the plugin out of builds that players get.
+
+use bevy_app::{App, Last, Plugin};
+use bevy_ecs::prelude::Resource;
+use bevy_ecs::reflect::AppTypeRegistry;
+use bevy_ecs::world::World;
+use bevy_time::{Real, Time};
+use godot::builtin::{GString, PackedByteArray, VarArray, VarDictionary, Variant};
+use godot::classes::stream_peer_socket::Status;
+use godot::classes::{Json, StreamPeerTcp, TcpServer};
+use godot::global::Error;
+use godot::meta::ToGodot;
+use godot::obj::{Gd, NewGd};
+use godot::prelude::varray;
+use tracing::{info, warn};
+
+use crate::plugins::debugger::{
+    EditRequest, NO_PARENT_BITS, apply_edits, collect_entities, editable_types_data,
+    reflected_resources,
+};
+
+/// Longest line a client may send; one going past it is disconnected.
+const MAX_LINE_BYTES: usize = 64 * 1024;
+
+/// Most bytes a client may leave unread; one falling further behind is
+/// disconnected.
+const MAX_PENDING_BYTES: usize = 16 * 1024 * 1024;
+
+/// Plugin serving the inspector over TCP. See the [module docs](self).
+#[derive(Debug, Clone)]
+pub struct RemoteInspectorPlugin {
+    /// Address to listen on; `"*"` for every interface.
+    pub bind_address: String,
+    pub port: u16,
+    /// Seconds between snapshots.
+    pub update_interval: f32,
+}
+
+impl Default for RemoteInspectorPlugin {
+    fn default() -> Self {
+        Self {
+            bind_address: "127.0.0.1".to_owned(),
+            port: 6010,
+            update_interval: 0.5,
+        }
+    }
+}
+
+impl Plugin for RemoteInspectorPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(RemoteInspectorSettings(self.clone()))
+            .init_non_send::<RemoteInspectorServer>()
+            .add_systems(Last, serve_remote_inspector);
+    }
+}
+
+#[derive(Resource)]
+struct RemoteInspectorSettings(RemoteInspectorPlugin);
+
+/// The listening socket and its clients.
+#[derive(Default)]
+struct RemoteInspectorServer {
+    listener: Option<Gd<TcpServer>>,
+    /// Listening failed; not retried, so the warning shows once.
+    failed: bool,
+    clients: Vec<RemoteClient>,
+    elapsed: f32,
+}
+
+struct RemoteClient {
+    peer: Gd<StreamPeerTcp>,
+    /// Received bytes not yet ending in a newline.
+    buffer: Vec<u8>,
+    /// Bytes queued by [`send`](Self::send) that the socket hasn't taken yet.
+    pending: Vec<u8>,
+}
+
+fn serve_remote_inspector(world: &mut World) {
+    let Some(registry) = world.get_resource::<AppTypeRegistry>().cloned() else {
+        return;
+    };
+    let settings = world.resource::<RemoteInspectorSettings>().0.clone();
+    let mut server = std::mem::take(&mut *world.non_send_mut::<RemoteInspectorServer>());
+
+    if server.listener.is_none() && !server.failed {
+        let mut listener = TcpServer::new_gd();
+        let result = listener
+            .listen_ex(settings.port)
+            .bind_address(settings.bind_address.as_str())
+            .done();
+        if result == Error::OK {
+            info!(
+                "Remote inspector listening on {}:{}",
+                settings.bind_address, settings.port
+            );
+            server.listener = Some(listener);
+        } else {
+            warn!(
+                "Remote inspector could not listen on {}:{}: {result:?}",
+                settings.bind_address, settings.port
+            );
+            server.failed = true;
+        }
+    }
+
+    if let Some(listener) = server.listener.as_mut() {
+        while listener.is_connection_available() {
+            let Some(peer) = listener.take_connection() else {
+                break;
+            };
+            let mut client = RemoteClient {
+                peer,
+                buffer: Vec::new(),
+                pending: Vec::new(),
+            };
+            client.send("bevy:types", &editable_types_data(&registry));
+            server.clients.push(client);
+        }
+    }
+
+    let mut edits = Vec::new();
+    let mut snapshot_requested = false;
+    server.clients.retain_mut(|client| {
+        client.peer.poll();
+        if client.peer.get_status() != Status::CONNECTED {
+            return false;
+        }
+        let Some(lines) = client.receive() else {
+            warn!("Remote inspector: dropping a client that sent an overlong line");
+            return false;
+        };
+        for line in lines {
+            match parse_command(&line) {
+                Some(Command::Snapshot) => snapshot_requested = true,
+                Some(Command::Edit(request)) => edits.push(request),
+                None => {
+                    let error = format!("malformed command: {line}");
+                    client.send("bevy:error", &varray![error]);
+                }
+            }
+        }
+        true
+    });
+    let edited = apply_edits(world, &registry, &edits);
+
+    server.elapsed += world
+        .get_resource::<Time<Real>>()
+        .map(|time| time.delta_secs())
+        .unwrap_or(0.0);
+    let due = server.elapsed >= settings.update_interval;
+    if !server.clients.is_empty() && (due || edited || snapshot_requested) {
+        server.elapsed = 0.0;
+        let entities = entity_bits_as_strings(&collect_entities(world, Some(&registry)));
+        let resources = reflected_resources(world, Some(&registry));
+        for client in &mut server.clients {
+            client.send("bevy:entities", &entities);
+            client.send("bevy:resources", &resources);
+        }
+    }
+
+    server.clients.retain_mut(|client| {
+        if !client.flush() {
+            return false;
+        }
+        if client.pending.len() > MAX_PENDING_BYTES {
+            warn!("Remote inspector: dropping a client that isn't reading its messages");
+            return false;
+        }
+        true
+    });
+
+    *world.non_send_mut::<RemoteInspectorServer>() = server;
+}
+
+impl RemoteClient {
+    /// Queue a message; [`flush`](Self::flush) writes it out.
+    fn send(&mut self, message: &str, data: &VarArray) {
+        let mut envelope = VarDictionary::new();
+        envelope.set("message", message);
+        envelope.set("data", data);
+        let line = format!("{}\n", Json::stringify(&envelope.to_variant()));
+        self.pending.extend_from_slice(line.as_bytes());
+    }
+
+    /// Write as much of the queued output as the socket takes without blocking.
+    /// `false` when the connection failed.
+    fn flush(&mut self) -> bool {
+        if self.pending.is_empty() {
+            return true;
+        }
+        let result = self
+            .peer
+            .put_partial_data(&PackedByteArray::from(self.pending.as_slice()));
+        let error = result
+            .get(0)
+            .and_then(|error| error.try_to::<Error>().ok())
+            .unwrap_or(Error::FAILED);
+        if error != Error::OK {
+            return false;
+        }
+        let sent = result
+            .get(1)
+            .and_then(|sent| sent.try_to::<i64>().ok())
+            .map_or(0, |sent| (sent.max(0) as usize).min(self.pending.len()));
+        self.pending.drain(..sent);
+        true
+    }
+
+    /// Complete lines received since the last call, or `None` once the client
+    /// has sent more than [`MAX_LINE_BYTES`] without ending a line.
+    fn receive(&mut self) -> Option<Vec<String>> {
+        let available = self.peer.get_available_bytes();
+        if available > 0
+            && let Some(bytes) = self
+                .peer
+                .get_data(available)
+                .get(1)
+                .and_then(|bytes| bytes.try_to::<PackedByteArray>().ok())
+        {
+            self.buffer.extend_from_slice(bytes.as_slice());
+        }
+        let lines = drain_lines(&mut self.buffer);
+        (self.buffer.len() <= MAX_LINE_BYTES).then_some(lines)
+    }
+}
+
+enum Command {
+    Snapshot,
+    Edit(EditRequest),
+}
+
+fn parse_command(line: &str) -> Option<Command> {
+    let command = Json::parse_string(line).try_to::<VarDictionary>().ok()?;
+    let name = command
+        .get("command")?
+        .try_to::<GString>()
+        .ok()?
+        .to_string();
+    if name == "snapshot" {
+        return Some(Command::Snapshot);
+    }
+    let data = command
+        .get("data")
+        .and_then(|data| data.try_to::<VarArray>().ok())
+        .unwrap_or_default();
+    EditRequest::parse(&name, &entity_bits_from_json(&data)).map(Command::Edit)
+}
+
+/// Entities in `bevy:entities` with their bits and parent's bits as strings,
+/// and `null` for no parent.
+fn entity_bits_as_strings(entities: &VarArray) -> VarArray {
+    entities
+        .iter_shared()
+        .filter_map(|entry| entry.try_to::<VarArray>().ok())
+        .map(|mut entry| {
+            let bits = entry.at(0).try_to::<i64>().unwrap_or_default();
+            entry.set(0, &entity_bits_text(bits).to_variant());
+            let parent = match entry.at(3).try_to::<i64>() {
+                Ok(parent) if parent != NO_PARENT_BITS => entity_bits_text(parent).to_variant(),
+                _ => Variant::nil(),
+            };
+            entry.set(3, &parent);
+            entry.to_variant()
+        })
+        .collect()
+}
+
+/// Entity bits, carried as `i64` in Godot variants, as their unsigned decimal.
+fn entity_bits_text(bits: i64) -> String {
+    (bits as u64).to_string()
+}
+
+/// The entity argument of an edit, first when there is one, back from its
+/// decimal string to the integer bits [`EditRequest::parse`] expects.
+fn entity_bits_from_json(data: &VarArray) -> VarArray {
+    let mut data = data.duplicate_shallow();
+    if let Some(bits) = data
+        .get(0)
+        .and_then(|first| first.try_to::<GString>().ok())
+        .and_then(|text| parse_entity_bits(&text.to_string()))
+    {
+        data.set(0, &(bits as i64).to_variant());
+    }
+    data
+}
+
+/// Entity bits sent as a decimal string, as in `bevy:entities`.
+fn parse_entity_bits(text: &str) -> Option<u64> {
+    text.trim().parse().ok()
+}
+
+/// Take the complete lines out of `buffer`, leaving any partial one. Splitting
+/// bytes rather than text keeps a character cut between reads intact.
+fn drain_lines(buffer: &mut Vec<u8>) -> Vec<String> {
+    let Some(end) = buffer.iter().rposition(|byte| *byte == b'\n') else {
+        return Vec::new();
+    };
+    let complete: Vec<u8> = buffer.drain(..=end).collect();
+    String::from_utf8_lossy(&complete)
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(str::to_owned)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn entity_bits_survive_beyond_f64_precision() {
+        let bits = (1u64 << 53) + 1;
+        assert_eq!(parse_entity_bits(&bits.to_string()), Some(bits));
+        assert_eq!(parse_entity_bits(" 42 "), Some(42));
+        assert_eq!(parse_entity_bits("my_game::Health"), None);
+    }
+
+    #[test]
+    fn entity_bits_above_i64_max_stay_unsigned() {
+        let bits = u64::MAX - 1;
+        let text = entity_bits_text(bits as i64);
+        assert_eq!(text, bits.to_string());
+        assert_eq!(parse_entity_bits(&text), Some(bits));
+    }
+
+    #[test]
+    fn lines_are_drained_up_to_the_last_newline() {
+        let mut buffer = b"{\"command\": \"snapshot\"}\r\n\n{\"comm".to_vec();
+        assert_eq!(drain_lines(&mut buffer), ["{\"command\": \"snapshot\"}"]);
+        assert_eq!(buffer, b"{\"comm");
+
+        assert!(drain_lines(&mut buffer).is_empty());
+        buffer.extend_from_slice(b"and\": \"snapshot\"}\n");
+        assert_eq!(drain_lines(&mut buffer), ["{\"command\": \"snapshot\"}"]);
+        assert!(buffer.is_empty());
+    }
+}
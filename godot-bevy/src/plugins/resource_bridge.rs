@@ -0,0 +1,277 @@
+//! Bevy resources for GDScript, read and written through the `GodotBridge`
+//! engine singleton.
+//!
+//! [`GodotResourceBridgePlugin`] gives scripts one sanctioned doorway into the
+//! resources the app chooses to bridge, instead of each team inventing its own
+//! node metadata or autoload:
+//!
+//! ```ignore
+//! #[derive(Resource, Reflect, Default)]
+//! struct Score {
+//!     value: i64,
+//!     combo: i32,
+//! }
+//!
+//! app.add_plugins(GodotResourceBridgePlugin)
+//!     .init_resource::<Score>()
+//!     .bridge_resource::<Score>()
+//!     .add_observer(|set: On<BridgeResourceSet>| {
+//!         info!("a script changed {}", set.resource);
+//!     });
+//! ```
+//!
+//! ```gdscript
+//! var score = GodotBridge.get_resource("Score")   # {"value": 1200, "combo": 3}
+//! GodotBridge.set_resource("Score", {"combo": 0})  # queued for the next frame
+//! ```
+//!
+//! `get_resource` returns a copy of the resource as it was at the end of the last
+//! frame, with the field conversions of the meta sync plugin (see
+//! [`meta_sync`](crate::plugins::meta_sync)). `set_resource` doesn't touch the
+//! world: the fields given are queued, applied in the next frame's `First`
+//! alongside the event bridge, and followed by a [`BridgeResourceSet`] event.
+//! Fields left out keep their value, and a value of the wrong type is ignored.
+//!
+//! `#[bevy_app]` registers the singleton; with a hand-written `ExtensionLibrary`,
+//! call [`register_singleton`] and [`unregister_singleton`] yourself.
+
+use std::cell::RefCell;
+use std::sync::Mutex;
+
+use bevy_app::{App, First, Last, Plugin};
+use bevy_ecs::component::Mutable;
+use bevy_ecs::event::Event;
+use bevy_ecs::prelude::{Resource, World};
+use bevy_ecs::schedule::IntoScheduleConfigs;
+use bevy_reflect::{Reflect, TypePath};
+use godot::classes::{Engine, IObject, Object};
+use godot::prelude::{VarDictionary as Dictionary, *};
+
+use crate::plugins::core::GodotSyncSet;
+use crate::plugins::meta_sync::{apply_dict, fields_to_dict};
+
+/// Name GDScript reaches the singleton under.
+const SINGLETON_NAME: &str = "GodotBridge";
+
+/// The bridged resources at the end of the last frame.
+static SNAPSHOT: Mutex<Vec<(&'static str, Box<dyn Reflect>)>> = Mutex::new(Vec::new());
+
+thread_local! {
+    /// `set_resource` calls waiting for the next `First`. Main-thread only, as
+    /// both the calls and the system applying them are.
+    static PENDING: RefCell<Vec<(String, Dictionary)>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Plugin serving bridged resources to the `GodotBridge` singleton. See the
+/// [module docs](self).
+#[derive(Default, Debug, Clone, Copy)]
+pub struct GodotResourceBridgePlugin;
+
+impl Plugin for GodotResourceBridgePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<BridgedResources>()
+            .add_systems(First, apply_bridge_writes.in_set(GodotSyncSet::SignalPump))
+            .add_systems(Last, snapshot_bridged_resources);
+    }
+}
+
+/// Make a reflected resource readable and writable from GDScript with
+/// `GodotBridge.get_resource("ShortName")` and `set_resource`.
+pub trait BridgeResourceAppExt {
+    fn bridge_resource<R: Resource<Mutability = Mutable> + Reflect + TypePath>(
+        &mut self,
+    ) -> &mut Self;
+}
+
+impl BridgeResourceAppExt for App {
+    fn bridge_resource<R: Resource<Mutability = Mutable> + Reflect + TypePath>(
+        &mut self,
+    ) -> &mut Self {
+        self.world_mut()
+            .get_resource_or_init::<BridgedResources>()
+            .0
+            .push(BridgedResource {
+                name: R::short_type_path(),
+                read: |world| {
+                    world
+                        .get_resource::<R>()
+                        .and_then(|resource| resource.reflect_clone().ok())
+                },
+                write: |world, fields| {
+                    let Some(mut resource) = world.get_resource_mut::<R>() else {
+                        return false;
+                    };
+                    apply_dict(resource.as_partial_reflect_mut(), fields);
+                    true
+                },
+            });
+        self
+    }
+}
+
+/// A script changed a bridged resource with `GodotBridge.set_resource`.
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BridgeResourceSet {
+    /// The resource's short type name.
+    pub resource: &'static str,
+}
+
+struct BridgedResource {
+    name: &'static str,
+    read: fn(&World) -> Option<Box<dyn Reflect>>,
+    write: fn(&mut World, &Dictionary) -> bool,
+}
+
+/// Resources [`BridgeResourceAppExt::bridge_resource`] opted in.
+#[derive(Resource, Default)]
+struct BridgedResources(Vec<BridgedResource>);
+
+fn snapshot_bridged_resources(world: &World) {
+    let snapshot = world
+        .get_resource::<BridgedResources>()
+        .map(|bridged| {
+            bridged
+                .0
+                .iter()
+                .filter_map(|resource| Some((resource.name, (resource.read)(world)?)))
+                .collect()
+        })
+        .unwrap_or_default();
+    *SNAPSHOT.lock().unwrap_or_else(|e| e.into_inner()) = snapshot;
+}
+
+fn apply_bridge_writes(world: &mut World) {
+    let pending = PENDING.with(|pending| std::mem::take(&mut *pending.borrow_mut()));
+    if pending.is_empty() {
+        return;
+    }
+    let writers: Vec<_> = world
+        .resource::<BridgedResources>()
+        .0
+        .iter()
+        .map(|resource| (resource.name, resource.write))
+        .collect();
+
+    for (name, fields) in pending {
+        let Some((name, write)) = writers.iter().find(|(bridged, _)| *bridged == name) else {
+            continue;
+        };
+        if write(world, &fields) {
+            world.trigger(BridgeResourceSet { resource: name });
+        }
+    }
+}
+
+/// Register the `GodotBridge` singleton. `#[bevy_app]` calls this during
+/// `InitStage::Scene`; with a hand-written `ExtensionLibrary`, call it from
+/// `on_stage_init` yourself, and [`unregister_singleton`] from `on_stage_deinit`.
+pub fn register_singleton() {
+    let mut engine = Engine::singleton();
+    if !engine.has_singleton(SINGLETON_NAME) {
+        engine.register_singleton(SINGLETON_NAME, &GodotBridge::new_alloc());
+    }
+}
+
+/// Unregister and free the `GodotBridge` singleton.
+pub fn unregister_singleton() {
+    let mut engine = Engine::singleton();
+    if let Some(bridge) = engine.get_singleton(SINGLETON_NAME) {
+        engine.unregister_singleton(SINGLETON_NAME);
+        bridge.free();
+    }
+}
+
+/// Bridged Bevy resources for GDScript. See the [module docs](self).
+#[derive(GodotClass)]
+#[class(base=Object)]
+pub struct GodotBridge {
+    base: Base<Object>,
+}
+
+#[godot_api]
+impl IObject for GodotBridge {
+    fn init(base: Base<Object>) -> Self {
+        Self { base }
+    }
+}
+
+#[godot_api]
+impl GodotBridge {
+    /// Short names of the resources `get_resource` and `set_resource` accept.
+    #[func]
+    fn resource_names(&self) -> PackedStringArray {
+        let snapshot = SNAPSHOT.lock().unwrap_or_else(|e| e.into_inner());
+        snapshot
+            .iter()
+            .map(|(name, _)| GString::from(*name))
+            .collect()
+    }
+
+    /// A bridged resource's fields as of the end of the last frame, or `null`
+    /// if it isn't bridged or doesn't exist.
+    #[func]
+    fn get_resource(&self, name: GString) -> Variant {
+        let name = name.to_string();
+        let snapshot = SNAPSHOT.lock().unwrap_or_else(|e| e.into_inner());
+        snapshot
+            .iter()
+            .find(|(bridged, _)| *bridged == name)
+            .and_then(|(_, value)| fields_to_dict(value.as_partial_reflect()))
+            .map(|fields| fields.to_variant())
+            .unwrap_or_default()
+    }
+
+    /// Queue `fields` to be applied to a bridged resource in the next frame.
+    /// Returns `false`, with a warning, if the resource isn't bridged.
+    #[func]
+    fn set_resource(&self, name: GString, fields: Dictionary) -> bool {
+        let name = name.to_string();
+        let bridged = {
+            let snapshot = SNAPSHOT.lock().unwrap_or_else(|e| e.into_inner());
+            if snapshot.iter().any(|(bridged, _)| *bridged == name) {
+                Ok(())
+            } else {
+                Err(snapshot.iter().map(|(name, _)| *name).collect::<Vec<_>>())
+            }
+        };
+        if let Err(known) = bridged {
+            godot_warn!("GodotBridge: no bridged resource {name:?}; bridged: {known:?}");
+            return false;
+        }
+        PENDING.with(|pending| pending.borrow_mut().push((name, fields)));
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_ecs::system::RunSystemOnce;
+
+    #[derive(Resource, Reflect, Default, Clone, Debug, PartialEq)]
+    struct Score {
+        value: i64,
+    }
+
+    #[derive(Resource, Reflect, Default)]
+    struct Hidden(u32);
+
+    #[test]
+    fn snapshots_hold_bridged_resources_only() {
+        let mut app = App::new();
+        app.bridge_resource::<Score>()
+            .insert_resource(Score { value: 42 })
+            .insert_resource(Hidden(7));
+        app.world_mut()
+            .run_system_once(snapshot_bridged_resources)
+            .unwrap();
+
+        let resources = SNAPSHOT
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(name, value)| (*name, value.downcast_ref::<Score>().cloned()))
+            .collect::<Vec<_>>();
+        assert_eq!(resources, vec![("Score", Some(Score { value: 42 }))]);
+    }
+}
@@ -0,0 +1,266 @@
+//! Typewriter reveal and BBCode building for `RichTextLabel`s, for dialogue boxes.
+//!
+//! With [`GodotRichTextPlugin`], giving a `RichTextLabel`'s entity a
+//! [`TypewriterText`] sets the label's text and reveals it a few characters per
+//! second. When the last character shows, [`TypewriterFinished`] is triggered on
+//! the entity:
+//!
+//! ```ignore
+//! let line = BbCode::new()
+//!     .text("You found the ")
+//!     .color(Color::GOLD, "Sunstone")
+//!     .text("!");
+//! commands.entity(dialogue_label).insert(TypewriterText::new(line, 30.0));
+//!
+//! app.add_observer(|done: On<TypewriterFinished>, mut prompt: ResMut<ShowContinuePrompt>| {
+//!     prompt.0 = true;
+//! });
+//!
+//! fn skip_line(mut lines: Query<&mut TypewriterText>, input: Res<ButtonInput<KeyCode>>) {
+//!     if input.just_pressed(KeyCode::Space) {
+//!         lines.iter_mut().for_each(|mut line| line.skip());
+//!     }
+//! }
+//! ```
+//!
+//! Tags don't count as characters, so `[b]` never shows up half-typed.
+
+use std::fmt;
+
+use bevy_app::{App, Plugin, PostUpdate};
+use bevy_ecs::component::Component;
+use bevy_ecs::entity::Entity;
+use bevy_ecs::event::EntityEvent;
+use bevy_ecs::system::{Commands, Query, Res};
+use bevy_time::Time;
+use godot::builtin::Color;
+use godot::classes::RichTextLabel;
+
+use crate::interop::{GodotAccess, GodotNodeHandle};
+
+/// Plugin driving [`TypewriterText`]. See the [module docs](self).
+#[derive(Default)]
+pub struct GodotRichTextPlugin;
+
+impl Plugin for GodotRichTextPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(PostUpdate, advance_typewriters);
+    }
+}
+
+#[cfg(debug_assertions)]
+crate::inventory::submit! {
+    crate::watchdog::PluginRequirement::new::<TypewriterText, GodotRichTextPlugin>()
+}
+
+/// Reveal BBCode `text` on the entity's `RichTextLabel` at `chars_per_sec`.
+#[derive(Component, Debug, Clone)]
+pub struct TypewriterText {
+    text: String,
+    pub chars_per_sec: f32,
+    revealed: f32,
+    applied: bool,
+    finished: bool,
+}
+
+impl TypewriterText {
+    pub fn new(text: impl Into<String>, chars_per_sec: f32) -> Self {
+        Self {
+            text: text.into(),
+            chars_per_sec,
+            revealed: 0.0,
+            applied: false,
+            finished: false,
+        }
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Replace the text and start revealing it from the beginning.
+    pub fn set_text(&mut self, text: impl Into<String>) {
+        *self = Self::new(text, self.chars_per_sec);
+    }
+
+    /// Show the rest of the text on the next update.
+    pub fn skip(&mut self) {
+        self.revealed = f32::INFINITY;
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+}
+
+/// Triggered on the entity when its [`TypewriterText`] has fully revealed.
+#[derive(EntityEvent, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TypewriterFinished {
+    pub entity: Entity,
+}
+
+fn advance_typewriters(
+    mut typewriters: Query<(Entity, &GodotNodeHandle, &mut TypewriterText)>,
+    time: Res<Time>,
+    mut commands: Commands,
+    mut godot: GodotAccess,
+) {
+    for (entity, handle, mut typewriter) in &mut typewriters {
+        if typewriter.finished {
+            continue;
+        }
+        let Some(mut label) = godot.try_get::<RichTextLabel>(*handle) else {
+            continue;
+        };
+        if !typewriter.applied {
+            label.set_use_bbcode(true);
+            label.set_text(&typewriter.text);
+            typewriter.applied = true;
+        }
+        // With threaded processing, the character count isn't known until done.
+        if !label.is_finished() {
+            label.set_visible_characters(0);
+            continue;
+        }
+
+        typewriter.revealed += typewriter.chars_per_sec * time.delta_secs();
+        let total = label.get_total_character_count();
+        if typewriter.revealed >= total as f32 {
+            label.set_visible_characters(-1);
+            typewriter.finished = true;
+            commands.trigger(TypewriterFinished { entity });
+        } else {
+            label.set_visible_characters(typewriter.revealed as i32);
+        }
+    }
+}
+
+/// Builds BBCode for `RichTextLabel`s, escaping plain text so a stray `[` in a
+/// player name can't open a tag.
+///
+/// ```ignore
+/// let line = BbCode::new().bold(&speaker).text(": ").wave(&greeting).build();
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BbCode(String);
+
+impl BbCode {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append plain text.
+    pub fn text(mut self, text: &str) -> Self {
+        self.0.push_str(&escape_bbcode(text));
+        self
+    }
+
+    /// Append BBCode as is.
+    pub fn raw(mut self, bbcode: &str) -> Self {
+        self.0.push_str(bbcode);
+        self
+    }
+
+    /// Append plain `text` wrapped in `[tag]...[/tag]`. `tag` may carry
+    /// options, e.g. `"font_size=24"`; the closing tag uses its name only.
+    pub fn tagged(self, tag: &str, text: &str) -> Self {
+        let name = tag.split(['=', ' ']).next().unwrap_or(tag);
+        let close = format!("[/{name}]");
+        self.raw(&format!("[{tag}]")).text(text).raw(&close)
+    }
+
+    pub fn bold(self, text: &str) -> Self {
+        self.tagged("b", text)
+    }
+
+    pub fn italic(self, text: &str) -> Self {
+        self.tagged("i", text)
+    }
+
+    pub fn underline(self, text: &str) -> Self {
+        self.tagged("u", text)
+    }
+
+    pub fn color(self, color: Color, text: &str) -> Self {
+        self.tagged(&format!("color={}", html_color(color)), text)
+    }
+
+    pub fn url(self, url: &str, text: &str) -> Self {
+        self.tagged(&format!("url={url}"), text)
+    }
+
+    pub fn wave(self, text: &str) -> Self {
+        self.tagged("wave", text)
+    }
+
+    pub fn shake(self, text: &str) -> Self {
+        self.tagged("shake", text)
+    }
+
+    pub fn newline(self) -> Self {
+        self.raw("\n")
+    }
+
+    pub fn build(self) -> String {
+        self.0
+    }
+}
+
+impl fmt::Display for BbCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<BbCode> for String {
+    fn from(bbcode: BbCode) -> Self {
+        bbcode.0
+    }
+}
+
+/// Escape `[` so `text` shows literally in a BBCode-enabled `RichTextLabel`.
+pub fn escape_bbcode(text: &str) -> String {
+    text.replace('[', "[lb]")
+}
+
+fn html_color(color: Color) -> String {
+    let channel = |value: f32| (value.clamp(0.0, 1.0) * 255.0).round() as u8;
+    format!(
+        "#{:02x}{:02x}{:02x}{:02x}",
+        channel(color.r),
+        channel(color.g),
+        channel(color.b),
+        channel(color.a)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builder_escapes_text_and_closes_tags() {
+        let line = BbCode::new()
+            .bold("[Guard]")
+            .text(": halt, ")
+            .color(Color::from_rgba(1.0, 0.0, 0.5, 1.0), "stranger")
+            .tagged("font_size=24", "!")
+            .build();
+        assert_eq!(
+            line,
+            "[b][lb]Guard][/b]: halt, [color=#ff0080ff]stranger[/color][font_size=24]![/font_size]"
+        );
+    }
+
+    #[test]
+    fn set_text_restarts_the_reveal() {
+        let mut typewriter = TypewriterText::new("hello", 10.0);
+        typewriter.skip();
+        typewriter.finished = true;
+        typewriter.set_text("again");
+        assert_eq!(typewriter.text(), "again");
+        assert_eq!(typewriter.chars_per_sec, 10.0);
+        assert!(!typewriter.is_finished());
+        assert_eq!(typewriter.revealed, 0.0);
+    }
+}
@@ -0,0 +1,615 @@
+//! Scene transitions with fade and loading-screen hooks.
+//!
+//! [`SceneManager`] queues a change of Godot's current scene and drives it through
+//! fade-out, threaded load, switch, and fade-in, triggering [`SceneChangeStarted`]
+//! and [`SceneChangeFinished`] (or [`SceneChangeFailed`]) around it. Entities left behind by the old scene
+//! (stale node handles, and [`SceneScoped`] entities that existed when the
+//! fade-out ended) are despawned on the switch.
+//!
+//! The overlay is drawn with a [`SceneTransitionEffect`]: a fade, circle wipe or
+//! pixelation, or a shader registered with
+//! [`SceneTransitionAppExt::add_scene_transition`]. The config picks the default;
+//! a [`ChangeSceneRequest`] can pick another for one change.
+
+use bevy_app::{App, Plugin, Update};
+use bevy_asset::{AssetServer, Assets, Handle, LoadState};
+use bevy_ecs::{
+    component::Component,
+    entity::Entity,
+    event::Event,
+    prelude::Resource,
+    query::{Or, With, Without},
+    system::{Commands, Query, Res, ResMut},
+};
+use bevy_time::{Real, Time};
+use godot::builtin::{Color, VarArray};
+use godot::classes::{
+    CanvasLayer, ColorRect, Node, PackedScene, ResourceLoader, Shader, ShaderMaterial, control,
+    resource_loader,
+};
+use godot::meta::ToGodot;
+use godot::obj::{Gd, InstanceId, NewAlloc, NewGd};
+use std::collections::{HashMap, HashSet};
+use std::time::Duration;
+use tracing::{debug, error, warn};
+
+use crate::interop::{GodotAccess, GodotNodeHandle};
+use crate::plugins::assets::GodotResource;
+use crate::plugins::scene_tree::{ProtectedNodeEntity, SceneTreeRef};
+use crate::watchers::scene_tree_watcher::exclude_from_mirror;
+
+/// Layer the fade overlay is drawn on -- Godot's maximum, above any game UI.
+const FADE_LAYER: i32 = 128;
+
+/// Plugin that adds [`SceneManager`] and runs its transitions in `Update`.
+///
+/// ```ignore
+/// app.add_plugins(SceneManagerPlugin::default().with_fade(0.3, Color::BLACK));
+///
+/// fn next_level(mut scenes: ResMut<SceneManager>) {
+///     scenes.change_to("res://levels/level2.tscn");
+/// }
+/// ```
+#[derive(Clone, Default)]
+pub struct SceneManagerPlugin {
+    pub config: SceneTransitionConfig,
+}
+
+impl SceneManagerPlugin {
+    /// Fade to `color` over `seconds` before the switch, and back over `seconds` after.
+    pub fn with_fade(mut self, seconds: f32, color: Color) -> Self {
+        self.config.fade_out = Duration::from_secs_f32(seconds);
+        self.config.fade_in = Duration::from_secs_f32(seconds);
+        self.config.fade_color = Some(color);
+        self
+    }
+
+    /// Draw the overlay with `effect` unless a request picks another.
+    pub fn with_effect(mut self, effect: SceneTransitionEffect) -> Self {
+        self.config.effect = effect;
+        self
+    }
+
+    /// Show the scene at `path` while the next scene loads.
+    pub fn with_loading_screen(mut self, path: &str) -> Self {
+        self.config.loading_screen = Some(path.to_string());
+        self
+    }
+}
+
+impl Plugin for SceneManagerPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(self.config.clone())
+            .init_resource::<SceneManager>()
+            .init_resource::<SceneTransitionShaders>()
+            .add_systems(Update, drive_scene_transition);
+    }
+}
+
+/// How a scene transition looks. Read each frame, so it can be changed at runtime.
+#[derive(Resource, Clone, Debug, Default)]
+pub struct SceneTransitionConfig {
+    /// Duration of the fade before the old scene is replaced.
+    pub fade_out: Duration,
+    /// Duration of the fade after the new scene is in.
+    pub fade_in: Duration,
+    /// Color of the built-in fade overlay. `None` skips the overlay; the fade
+    /// phases still run, so [`SceneManager::fade_alpha`] can drive your own.
+    pub fade_color: Option<Color>,
+    /// How the overlay covers the screen.
+    pub effect: SceneTransitionEffect,
+    /// Scene instanced under the root while the next scene loads, freed on switch.
+    pub loading_screen: Option<String>,
+}
+
+/// The scene a transition is heading to.
+#[derive(Debug, Clone)]
+pub enum SceneTarget {
+    /// A scene file, loaded on a Godot loader thread.
+    Path(String),
+    /// A scene loaded through Bevy's asset system.
+    Handle(Handle<GodotResource>),
+    /// The current scene, reloaded from its file.
+    Reload,
+}
+
+/// How the overlay covers and uncovers the screen.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum SceneTransitionEffect {
+    /// Blend to the fade color.
+    #[default]
+    Fade,
+    /// A circle of the fade color closing in on the center.
+    CircleWipe,
+    /// Pixelate the screen into ever larger blocks, then cut to the fade color.
+    Pixelate,
+    /// A shader registered with [`SceneTransitionAppExt::add_scene_transition`].
+    Custom(String),
+}
+
+/// A scene change with its own transition effect. Queue it with
+/// [`SceneManager::change`].
+#[derive(Debug, Clone)]
+pub struct ChangeSceneRequest {
+    pub target: SceneTarget,
+    /// Overrides [`SceneTransitionConfig::effect`] for this change.
+    pub transition: Option<SceneTransitionEffect>,
+}
+
+impl ChangeSceneRequest {
+    pub fn new(target: SceneTarget) -> Self {
+        Self {
+            target,
+            transition: None,
+        }
+    }
+
+    pub fn with_transition(mut self, effect: SceneTransitionEffect) -> Self {
+        self.transition = Some(effect);
+        self
+    }
+}
+
+impl From<SceneTarget> for ChangeSceneRequest {
+    fn from(target: SceneTarget) -> Self {
+        Self::new(target)
+    }
+}
+
+/// Register custom transition shaders.
+pub trait SceneTransitionAppExt {
+    /// Make `code`, a `canvas_item` shader, available as
+    /// `SceneTransitionEffect::Custom(name)`. The overlay sets two uniforms:
+    /// `progress` (0.0 uncovered to 1.0 fully covered) and `color` (the fade color).
+    /// At `progress` 1.0 the shader must cover the whole screen, since the scene
+    /// switch happens behind it.
+    fn add_scene_transition(
+        &mut self,
+        name: impl Into<String>,
+        code: impl Into<String>,
+    ) -> &mut Self;
+}
+
+impl SceneTransitionAppExt for App {
+    fn add_scene_transition(
+        &mut self,
+        name: impl Into<String>,
+        code: impl Into<String>,
+    ) -> &mut Self {
+        self.init_resource::<SceneTransitionShaders>();
+        self.world_mut()
+            .resource_mut::<SceneTransitionShaders>()
+            .custom
+            .insert(name.into(), code.into());
+        self
+    }
+}
+
+/// Custom transition shaders by name.
+#[derive(Resource, Default)]
+struct SceneTransitionShaders {
+    custom: HashMap<String, String>,
+}
+
+impl SceneTransitionShaders {
+    fn code(&self, effect: &SceneTransitionEffect) -> &str {
+        match effect {
+            SceneTransitionEffect::Fade => FADE_SHADER,
+            SceneTransitionEffect::CircleWipe => CIRCLE_WIPE_SHADER,
+            SceneTransitionEffect::Pixelate => PIXELATE_SHADER,
+            SceneTransitionEffect::Custom(name) => match self.custom.get(name) {
+                Some(code) => code,
+                None => {
+                    warn!("SceneManager: no transition registered as {name:?}; fading instead");
+                    FADE_SHADER
+                }
+            },
+        }
+    }
+}
+
+/// Where a transition is. Phases with zero duration are passed through in one frame.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum SceneTransitionState {
+    #[default]
+    Idle,
+    FadingOut,
+    Loading,
+    Switching,
+    FadingIn,
+}
+
+/// Triggered when a queued transition begins fading out.
+#[derive(Event, Debug, Clone)]
+pub struct SceneChangeStarted {
+    pub target: SceneTarget,
+}
+
+/// Triggered when a transition has switched scenes and finished fading in.
+#[derive(Event, Debug, Clone)]
+pub struct SceneChangeFinished {
+    pub target: SceneTarget,
+}
+
+/// Triggered instead of [`SceneChangeFinished`] when the incoming scene failed to
+/// load or switch. The old scene stays, and this fires once the fade-in is done.
+#[derive(Event, Debug, Clone)]
+pub struct SceneChangeFailed {
+    pub target: SceneTarget,
+    pub reason: String,
+}
+
+/// Despawned when the current scene changes through [`SceneManager`]. Attach it to
+/// node-less entities that belong to a level (spawners, timers, level state).
+///
+/// Only entities scoped by the end of the fade-out belong to the outgoing scene;
+/// ones spawned while the next scene loads survive the switch and belong to it.
+#[derive(Component, Debug, Default, Clone, Copy)]
+pub struct SceneScoped;
+
+/// Queues scene changes and reports transition progress.
+///
+/// Requests made while a transition is running are queued and run in order.
+#[derive(Resource, Default)]
+pub struct SceneManager {
+    queue: Vec<ChangeSceneRequest>,
+    current: Option<SceneTarget>,
+    /// Why the current change failed, reported when its fade-in ends.
+    failure: Option<String>,
+    state: SceneTransitionState,
+    elapsed: Duration,
+    load_progress: f32,
+    old_scene: Option<InstanceId>,
+    /// [`SceneScoped`] entities of the outgoing scene, recorded when the fade-out ends.
+    scoped: HashSet<Entity>,
+    overlay: Option<InstanceId>,
+    loading_screen: Option<InstanceId>,
+}
+
+impl SceneManager {
+    /// Change to the scene file at `path` (e.g. `"res://levels/level2.tscn"`).
+    pub fn change_to(&mut self, path: impl Into<String>) {
+        self.change(SceneTarget::Path(path.into()));
+    }
+
+    /// Change to a scene loaded through Bevy's asset system.
+    pub fn change_to_handle(&mut self, handle: Handle<GodotResource>) {
+        self.change(SceneTarget::Handle(handle));
+    }
+
+    /// Reload the current scene.
+    pub fn reload(&mut self) {
+        self.change(SceneTarget::Reload);
+    }
+
+    /// Queue a change, optionally with its own transition effect:
+    ///
+    /// ```ignore
+    /// scenes.change(
+    ///     ChangeSceneRequest::new(SceneTarget::Path("res://boss.tscn".into()))
+    ///         .with_transition(SceneTransitionEffect::CircleWipe),
+    /// );
+    /// ```
+    pub fn change(&mut self, request: impl Into<ChangeSceneRequest>) {
+        self.queue.push(request.into());
+    }
+
+    pub fn state(&self) -> SceneTransitionState {
+        self.state
+    }
+
+    /// True from the start of a fade-out to the end of the fade-in.
+    pub fn is_transitioning(&self) -> bool {
+        self.state != SceneTransitionState::Idle
+    }
+
+    /// Threaded-load progress of the incoming scene, `0.0..=1.0`.
+    pub fn load_progress(&self) -> f32 {
+        self.load_progress
+    }
+
+    /// Opacity of the fade: rises to 1.0 over the fade-out, holds through the
+    /// load and switch, and falls back to 0.0 over the fade-in.
+    pub fn fade_alpha(&self, config: &SceneTransitionConfig) -> f32 {
+        let ratio = |duration: Duration| {
+            if duration.is_zero() {
+                1.0
+            } else {
+                (self.elapsed.as_secs_f32() / duration.as_secs_f32()).min(1.0)
+            }
+        };
+        match self.state {
+            SceneTransitionState::Idle => 0.0,
+            SceneTransitionState::FadingOut => ratio(config.fade_out),
+            SceneTransitionState::Loading | SceneTransitionState::Switching => 1.0,
+            SceneTransitionState::FadingIn => 1.0 - ratio(config.fade_in),
+        }
+    }
+}
+
+/// Result of polling the incoming scene.
+enum LoadPoll {
+    Pending,
+    Ready(Gd<PackedScene>),
+    Reload,
+    Failed(String),
+}
+
+type OrphanQuery<'w, 's> = Query<
+    'w,
+    's,
+    (Entity, Option<&'static GodotNodeHandle>),
+    (
+        Or<(With<GodotNodeHandle>, With<SceneScoped>)>,
+        Without<ProtectedNodeEntity>,
+    ),
+>;
+
+#[allow(clippy::too_many_arguments)]
+fn drive_scene_transition(
+    mut commands: Commands,
+    mut manager: ResMut<SceneManager>,
+    config: Res<SceneTransitionConfig>,
+    shaders: Res<SceneTransitionShaders>,
+    time: Res<Time<Real>>,
+    assets: Option<ResMut<Assets<GodotResource>>>,
+    asset_server: Option<Res<AssetServer>>,
+    mut scene_tree: SceneTreeRef,
+    orphans: OrphanQuery,
+    mut godot: GodotAccess,
+) {
+    let manager = &mut *manager;
+    if manager.state == SceneTransitionState::Idle {
+        if manager.queue.is_empty() {
+            return;
+        }
+        let ChangeSceneRequest { target, transition } = manager.queue.remove(0);
+        commands.trigger(SceneChangeStarted {
+            target: target.clone(),
+        });
+        manager.current = Some(target);
+        manager.state = SceneTransitionState::FadingOut;
+        manager.elapsed = Duration::ZERO;
+        manager.load_progress = 0.0;
+        if let Some(color) = config.fade_color {
+            let effect = transition.as_ref().unwrap_or(&config.effect);
+            manager.overlay = Some(spawn_fade_overlay(
+                &mut scene_tree,
+                color,
+                shaders.code(effect),
+            ));
+        }
+    } else {
+        manager.elapsed += time.delta();
+    }
+
+    if manager.state == SceneTransitionState::FadingOut && manager.elapsed >= config.fade_out {
+        manager.state = SceneTransitionState::Loading;
+        manager.elapsed = Duration::ZERO;
+        manager.scoped = orphans
+            .iter()
+            .filter(|(_, handle)| handle.is_none())
+            .map(|(entity, _)| entity)
+            .collect();
+        if let Some(SceneTarget::Path(path)) = &manager.current {
+            godot
+                .singleton::<ResourceLoader>()
+                .load_threaded_request(path.as_str());
+        }
+        if let Some(path) = &config.loading_screen {
+            manager.loading_screen = spawn_loading_screen(&mut godot, &mut scene_tree, path);
+        }
+    }
+
+    if manager.state == SceneTransitionState::Loading {
+        let poll = match manager.current.clone() {
+            Some(SceneTarget::Path(path)) => poll_threaded_load(&mut godot, &path, manager),
+            Some(SceneTarget::Handle(handle)) => {
+                match assets
+                    .and_then(|mut assets| assets.get_mut(&handle).map(|mut a| a.try_cast()))
+                {
+                    Some(Some(packed)) => LoadPoll::Ready(packed),
+                    Some(None) => LoadPoll::Failed("resource is not a PackedScene".into()),
+                    None => match asset_server
+                        .as_ref()
+                        .and_then(|server| server.get_load_state(handle.id()))
+                    {
+                        Some(LoadState::Failed(err)) => LoadPoll::Failed(err.to_string()),
+                        _ => LoadPoll::Pending,
+                    },
+                }
+            }
+            Some(SceneTarget::Reload) | None => LoadPoll::Reload,
+        };
+        let mut tree = scene_tree.get();
+        manager.old_scene = tree.get_current_scene().map(|scene| scene.instance_id());
+        let switched = match poll {
+            LoadPoll::Pending => return,
+            LoadPoll::Ready(packed) => switch_result(tree.change_scene_to_packed(&packed)),
+            LoadPoll::Reload => switch_result(tree.reload_current_scene()),
+            LoadPoll::Failed(reason) => Err(format!("failed to load: {reason}")),
+        };
+        match switched {
+            Ok(()) => manager.state = SceneTransitionState::Switching,
+            Err(reason) => {
+                error!("SceneManager: {:?} {reason}", manager.current);
+                free_node(&mut godot, manager.loading_screen.take());
+                manager.scoped.clear();
+                manager.failure = Some(reason);
+                manager.state = SceneTransitionState::FadingIn;
+            }
+        }
+        manager.elapsed = Duration::ZERO;
+        manager.load_progress = 1.0;
+    }
+
+    if manager.state == SceneTransitionState::Switching {
+        // Godot swaps scenes at the end of the frame the change was requested in,
+        // so the new scene becomes current on a later frame.
+        let current = scene_tree
+            .get()
+            .get_current_scene()
+            .map(|scene| scene.instance_id());
+        if current.is_none() || current == manager.old_scene {
+            return;
+        }
+        let scoped = std::mem::take(&mut manager.scoped);
+        despawn_orphans(&mut commands, &orphans, &scoped);
+        free_node(&mut godot, manager.loading_screen.take());
+        manager.state = SceneTransitionState::FadingIn;
+        manager.elapsed = Duration::ZERO;
+    }
+
+    if manager.state == SceneTransitionState::FadingIn && manager.elapsed >= config.fade_in {
+        free_node(&mut godot, manager.overlay.take());
+        if let Some(target) = manager.current.take() {
+            match manager.failure.take() {
+                Some(reason) => commands.trigger(SceneChangeFailed { target, reason }),
+                None => commands.trigger(SceneChangeFinished { target }),
+            }
+        }
+        manager.state = SceneTransitionState::Idle;
+        manager.elapsed = Duration::ZERO;
+        return;
+    }
+
+    let alpha = manager.fade_alpha(&config);
+    if let Some(mut overlay) = manager
+        .overlay
+        .and_then(|id| godot.try_get_instance_id::<CanvasLayer>(id))
+        .and_then(|layer| layer.get_child(0))
+        .and_then(|rect| rect.try_cast::<ColorRect>().ok())
+        .and_then(|rect| rect.get_material())
+        .and_then(|material| material.try_cast::<ShaderMaterial>().ok())
+    {
+        overlay.set_shader_parameter("progress", &alpha.to_variant());
+    }
+}
+
+fn poll_threaded_load(godot: &mut GodotAccess, path: &str, manager: &mut SceneManager) -> LoadPoll {
+    let mut loader = godot.singleton::<ResourceLoader>();
+    let progress = VarArray::new();
+    let status = loader
+        .load_threaded_get_status_ex(path)
+        .progress(&progress)
+        .done();
+    if let Some(ratio) = progress.get(0).and_then(|v| v.try_to::<f32>().ok()) {
+        manager.load_progress = ratio;
+    }
+    match status {
+        resource_loader::ThreadLoadStatus::IN_PROGRESS => LoadPoll::Pending,
+        resource_loader::ThreadLoadStatus::LOADED => match loader
+            .load_threaded_get(path)
+            .and_then(|resource| resource.try_cast::<PackedScene>().ok())
+        {
+            Some(packed) => LoadPoll::Ready(packed),
+            None => LoadPoll::Failed("resource is not a PackedScene".into()),
+        },
+        status => LoadPoll::Failed(format!("threaded load status {status:?}")),
+    }
+}
+
+/// The scene tree's answer to a scene change, as the reason it failed.
+fn switch_result(result: godot::global::Error) -> Result<(), String> {
+    match result {
+        godot::global::Error::OK => Ok(()),
+        err => Err(format!("failed to switch: {err:?}")),
+    }
+}
+
+/// Despawn what the old scene left behind: entities whose node was freed with it,
+/// and its `scoped` entities. Protected entities are kept.
+fn despawn_orphans(commands: &mut Commands, orphans: &OrphanQuery, scoped: &HashSet<Entity>) {
+    let mut count = 0;
+    for (entity, handle) in orphans.iter() {
+        let stale = handle.is_some_and(|h| !h.instance_id().lookup_validity());
+        if stale || (handle.is_none() && scoped.contains(&entity)) {
+            commands.entity(entity).try_despawn();
+            count += 1;
+        }
+    }
+    debug!("SceneManager: despawned {count} entities left by the previous scene");
+}
+
+fn spawn_fade_overlay(
+    scene_tree: &mut SceneTreeRef,
+    color: Color,
+    shader_code: &str,
+) -> InstanceId {
+    let mut layer = CanvasLayer::new_alloc();
+    layer.set_name("SceneTransitionOverlay");
+    layer.set_layer(FADE_LAYER);
+    exclude_from_mirror(&mut layer);
+
+    let mut rect = ColorRect::new_alloc();
+    rect.set_anchors_preset(control::LayoutPreset::FULL_RECT);
+    rect.set_mouse_filter(control::MouseFilter::IGNORE);
+    let mut shader = Shader::new_gd();
+    shader.set_code(shader_code);
+    let mut material = ShaderMaterial::new_gd();
+    material.set_shader(&shader);
+    material.set_shader_parameter("color", &color.to_variant());
+    material.set_shader_parameter("progress", &0.0f32.to_variant());
+    rect.set_material(&material);
+    layer.add_child(&rect);
+
+    if let Some(mut root) = scene_tree.get().get_root() {
+        root.add_child(&layer);
+    }
+    layer.instance_id()
+}
+
+fn spawn_loading_screen(
+    godot: &mut GodotAccess,
+    scene_tree: &mut SceneTreeRef,
+    path: &str,
+) -> Option<InstanceId> {
+    let Some(packed) = godot
+        .singleton::<ResourceLoader>()
+        .load(path)
+        .and_then(|resource| resource.try_cast::<PackedScene>().ok())
+    else {
+        warn!("SceneManager: loading screen {path} is not a PackedScene");
+        return None;
+    };
+    let instance = packed.instantiate()?;
+    scene_tree.get().get_root()?.add_child(&instance);
+    Some(instance.instance_id())
+}
+
+fn free_node(godot: &mut GodotAccess, id: Option<InstanceId>) {
+    if let Some(mut node) = id.and_then(|id| godot.try_get_instance_id::<Node>(id)) {
+        node.queue_free();
+    }
+}
+
+const FADE_SHADER: &str = "shader_type canvas_item;
+uniform float progress = 0.0;
+uniform vec4 color : source_color = vec4(0.0, 0.0, 0.0, 1.0);
+void fragment() {
+    COLOR = vec4(color.rgb, color.a * progress);
+}
+";
+
+const CIRCLE_WIPE_SHADER: &str = "shader_type canvas_item;
+uniform float progress = 0.0;
+uniform vec4 color : source_color = vec4(0.0, 0.0, 0.0, 1.0);
+void fragment() {
+    float aspect = SCREEN_PIXEL_SIZE.y / SCREEN_PIXEL_SIZE.x;
+    vec2 offset = (UV - 0.5) * vec2(aspect, 1.0);
+    float radius = length(vec2(aspect, 1.0) * 0.5) * (1.0 - progress);
+    COLOR = vec4(color.rgb, length(offset) >= radius ? color.a : 0.0);
+}
+";
+
+const PIXELATE_SHADER: &str = "shader_type canvas_item;
+uniform float progress = 0.0;
+uniform vec4 color : source_color = vec4(0.0, 0.0, 0.0, 1.0);
+uniform sampler2D screen_texture : hint_screen_texture, filter_nearest;
+void fragment() {
+    vec2 cell = SCREEN_PIXEL_SIZE * mix(1.0, 64.0, progress);
+    vec2 uv = (floor(SCREEN_UV / cell) + 0.5) * cell;
+    vec3 pixelated = texture(screen_texture, uv).rgb;
+    COLOR = vec4(mix(pixelated, color.rgb, smoothstep(0.75, 1.0, progress)), progress > 0.0 ? 1.0 : 0.0);
+}
+";
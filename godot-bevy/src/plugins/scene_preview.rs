@@ -0,0 +1,254 @@
+//! One-shot thumbnails of `PackedScene`s, for level-select screens and inventories.
+//!
+//! [`ScenePreviews::capture`] queues a scene and returns its [`ScenePreviewId`]. In
+//! `Last`, the scene is instanced into its own off-screen `SubViewport`, which
+//! renders exactly once; after that frame is drawn the viewport's image is copied
+//! into an `ImageTexture`, the viewport is freed, and a [`ScenePreviewCaptured`] is
+//! triggered for observers on the next `First`.
+//!
+//! ```ignore
+//! app.add_plugins(ScenePreviewPlugin)
+//!     .add_observer(|captured: On<ScenePreviewCaptured>, mut thumbnails: ResMut<Thumbnails>| {
+//!         match &captured.event().result {
+//!             Ok(texture) => thumbnails.insert(captured.event().id, texture.clone()),
+//!             Err(err) => warn!("thumbnail: {err}"),
+//!         }
+//!     });
+//!
+//! fn request_thumbnails(previews: ScenePreviews, levels: Res<Levels>) {
+//!     for level in &levels.0 {
+//!         previews.capture(ScenePreviewRequest::from_path(&level.scene, UVec2::new(256, 144)));
+//!     }
+//! }
+//! ```
+//!
+//! 3D scenes need their own `Camera3D`: the viewport has its own world and sees
+//! nothing else. Headless runs render nothing, so every capture fails there.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use bevy_app::{App, Last, Plugin};
+use bevy_asset::{Assets, Handle};
+use bevy_ecs::event::Event;
+use bevy_ecs::prelude::Resource;
+use bevy_ecs::system::{Res, ResMut, SystemParam};
+use bevy_math::UVec2;
+use godot::builtin::{Variant, Vector2i};
+use godot::classes::object::ConnectFlags;
+use godot::classes::{
+    ImageTexture, Node, PackedScene, RenderingServer, ResourceLoader, SubViewport, sub_viewport,
+};
+use godot::obj::{Gd, InstanceId, NewAlloc};
+use godot::prelude::Callable;
+use parking_lot::Mutex;
+use thiserror::Error;
+
+use crate::interop::{GodotAccess, GodotResourceHandle};
+use crate::plugins::assets::GodotResource;
+use crate::plugins::event_bridge::GodotEventSender;
+use crate::plugins::scene_tree::SceneTreeRef;
+use crate::watchers::scene_tree_watcher::exclude_from_mirror;
+
+/// Plugin adding [`ScenePreviews`]. See the [module docs](self).
+#[derive(Default)]
+pub struct ScenePreviewPlugin;
+
+impl Plugin for ScenePreviewPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PreviewQueue>()
+            .add_systems(Last, start_scene_previews);
+    }
+}
+
+/// Identifies a capture; carried by its [`ScenePreviewCaptured`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ScenePreviewId(u64);
+
+/// A scene to capture with [`ScenePreviews::capture`].
+#[derive(Debug, Clone)]
+pub struct ScenePreviewRequest {
+    pub scene: PreviewScene,
+    /// Size of the captured image in pixels.
+    pub size: UVec2,
+    /// Leave the background transparent instead of the project's clear color.
+    pub transparent: bool,
+}
+
+impl ScenePreviewRequest {
+    pub fn new(scene: PreviewScene, size: UVec2) -> Self {
+        Self {
+            scene,
+            size,
+            transparent: true,
+        }
+    }
+
+    /// Note that this calls `ResourceLoader.load()`, which blocks. Load through
+    /// Bevy's `AssetServer` and use [`from_handle`](Self::from_handle) to avoid that.
+    pub fn from_path(path: impl Into<String>, size: UVec2) -> Self {
+        Self::new(PreviewScene::Path(path.into()), size)
+    }
+
+    /// Waits until the asset has loaded before capturing.
+    pub fn from_handle(handle: Handle<GodotResource>, size: UVec2) -> Self {
+        Self::new(PreviewScene::Handle(handle), size)
+    }
+
+    pub fn with_transparent(mut self, transparent: bool) -> Self {
+        self.transparent = transparent;
+        self
+    }
+}
+
+/// Where the scene of a [`ScenePreviewRequest`] comes from.
+#[derive(Debug, Clone)]
+pub enum PreviewScene {
+    Path(String),
+    Handle(Handle<GodotResource>),
+}
+
+/// Triggered when a capture finishes or fails.
+#[derive(Event, Debug, Clone)]
+pub struct ScenePreviewCaptured {
+    pub id: ScenePreviewId,
+    /// The captured `ImageTexture`.
+    pub result: Result<GodotResourceHandle, ScenePreviewError>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum ScenePreviewError {
+    #[error("failed to load {0}")]
+    Load(String),
+    #[error("resource is not a PackedScene")]
+    NotAPackedScene,
+    #[error("failed to instantiate scene")]
+    Instantiate,
+    /// The viewport produced no image, e.g. when running headless.
+    #[error("viewport produced no image")]
+    Capture,
+}
+
+/// Queue scene captures from any system; results arrive as [`ScenePreviewCaptured`]
+/// events.
+///
+/// Only needs shared access; the viewports are created on the main thread in `Last`.
+#[derive(SystemParam)]
+pub struct ScenePreviews<'w> {
+    queue: Res<'w, PreviewQueue>,
+}
+
+impl ScenePreviews<'_> {
+    pub fn capture(&self, request: ScenePreviewRequest) -> ScenePreviewId {
+        let id = ScenePreviewId(self.queue.next_id.fetch_add(1, Ordering::Relaxed));
+        self.queue.pending.lock().push((id, request));
+        id
+    }
+}
+
+#[derive(Resource, Default)]
+struct PreviewQueue {
+    next_id: AtomicU64,
+    pending: Mutex<Vec<(ScenePreviewId, ScenePreviewRequest)>>,
+}
+
+fn start_scene_previews(
+    queue: Res<PreviewQueue>,
+    sender: Res<GodotEventSender>,
+    mut assets: Option<ResMut<Assets<GodotResource>>>,
+    mut scene_tree: SceneTreeRef,
+    mut godot: GodotAccess,
+) {
+    let pending = std::mem::take(&mut *queue.pending.lock());
+    if pending.is_empty() {
+        return;
+    }
+    let Some(mut root) = scene_tree.get().get_root() else {
+        queue.pending.lock().extend(pending);
+        return;
+    };
+
+    let mut waiting = Vec::new();
+    for (id, request) in pending {
+        let packed = match &request.scene {
+            PreviewScene::Path(path) => {
+                Some(match godot.singleton::<ResourceLoader>().load(path) {
+                    Some(resource) => resource
+                        .try_cast::<PackedScene>()
+                        .map_err(|_| ScenePreviewError::NotAPackedScene),
+                    None => Err(ScenePreviewError::Load(path.clone())),
+                })
+            }
+            PreviewScene::Handle(handle) => assets
+                .as_mut()
+                .and_then(|assets| assets.get_mut(handle))
+                .map(|mut resource| {
+                    resource
+                        .try_cast::<PackedScene>()
+                        .ok_or(ScenePreviewError::NotAPackedScene)
+                }),
+        };
+        let Some(packed) = packed else {
+            waiting.push((id, request));
+            continue;
+        };
+        let instance =
+            packed.and_then(|packed| packed.instantiate().ok_or(ScenePreviewError::Instantiate));
+        let instance = match instance {
+            Ok(instance) => instance,
+            Err(err) => {
+                sender.send(ScenePreviewCaptured {
+                    id,
+                    result: Err(err),
+                });
+                continue;
+            }
+        };
+
+        let mut viewport = SubViewport::new_alloc();
+        exclude_from_mirror(&mut viewport);
+        viewport.set_size(Vector2i::new(
+            request.size.x.max(1) as i32,
+            request.size.y.max(1) as i32,
+        ));
+        viewport.set_transparent_background(request.transparent);
+        viewport.set_use_own_world_3d(true);
+        viewport.set_update_mode(sub_viewport::UpdateMode::ONCE);
+        viewport.add_child(&instance);
+        root.add_child(&viewport);
+
+        let viewport_id = viewport.instance_id();
+        let tx = sender.clone();
+        let on_drawn = Callable::from_fn("scene_preview_drawn", move |_| {
+            tx.send(ScenePreviewCaptured {
+                id,
+                result: capture_viewport(viewport_id),
+            });
+            free_node(viewport_id);
+            Variant::nil()
+        });
+        godot.singleton::<RenderingServer>().connect_flags(
+            "frame_post_draw",
+            &on_drawn,
+            ConnectFlags::ONE_SHOT,
+        );
+    }
+    queue.pending.lock().extend(waiting);
+}
+
+fn capture_viewport(viewport_id: InstanceId) -> Result<GodotResourceHandle, ScenePreviewError> {
+    let viewport = Gd::<SubViewport>::try_from_instance_id(viewport_id)
+        .map_err(|_| ScenePreviewError::Capture)?;
+    let image = viewport
+        .get_texture()
+        .and_then(|texture| texture.get_image())
+        .filter(|image| !image.is_empty())
+        .ok_or(ScenePreviewError::Capture)?;
+    let texture = ImageTexture::create_from_image(&image).ok_or(ScenePreviewError::Capture)?;
+    Ok(GodotResourceHandle::new(texture.upcast()))
+}
+
+fn free_node(instance_id: InstanceId) {
+    if let Ok(mut node) = Gd::<Node>::try_from_instance_id(instance_id) {
+        node.queue_free();
+    }
+}
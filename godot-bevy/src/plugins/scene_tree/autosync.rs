@@ -63,6 +63,93 @@ pub fn register_all_required_components(app: &mut App) {
     debug!("Ran {count} GodotRequiredComponents registrations");
 }
 
+/// Function type for registering a reflected type generated by `#[derive(AutoRegister)]`.
+pub type TypeRegistrarFn = fn(&mut App);
+
+/// Registry entry for a reflected type, constructed only by the `AutoRegister`
+/// derive via `inventory::submit!`.
+#[doc(hidden)]
+pub struct AutoRegisteredType {
+    pub type_name: &'static str,
+    pub register_fn: TypeRegistrarFn,
+}
+
+crate::inventory::collect!(AutoRegisteredType);
+
+/// Register every `#[derive(AutoRegister)]` type in this app's type registry.
+/// Per-`App`, like [`register_all_required_components`].
+pub fn register_all_auto_types(app: &mut App) {
+    let mut count = 0;
+    for entry in crate::inventory::iter::<AutoRegisteredType> {
+        (entry.register_fn)(app);
+        trace!("Registered reflected type {}", entry.type_name);
+        count += 1;
+    }
+    debug!("Ran {count} AutoRegister type registrations");
+}
+
+/// Registry entry for a `#[gdbevy(icon = "...")]` editor icon, constructed only by
+/// the `GodotNode` derive via `inventory::submit!`.
+#[doc(hidden)]
+pub struct GodotNodeIcon {
+    pub godot_class_id_fn: fn() -> ClassId,
+    pub icon_path: &'static str,
+}
+
+crate::inventory::collect!(GodotNodeIcon);
+
+/// The `[icons]` section of a `.gdextension` file, one line per generated class with
+/// an `icon`. Empty when no class declares one.
+pub fn gdextension_icons_section() -> String {
+    icons_section(
+        crate::inventory::iter::<GodotNodeIcon>
+            .into_iter()
+            .map(|entry| ((entry.godot_class_id_fn)().to_string(), entry.icon_path))
+            .collect(),
+    )
+}
+
+/// `gdextension` (the contents of a `.gdextension` file) with its `[icons]` section
+/// replaced by [`gdextension_icons_section`]. Godot reads the section when it loads
+/// the extension, so changes show after an editor restart.
+pub fn gdextension_with_icons(gdextension: &str) -> String {
+    replace_icons_section(gdextension, &gdextension_icons_section())
+}
+
+fn icons_section(mut icons: Vec<(String, &str)>) -> String {
+    if icons.is_empty() {
+        return String::new();
+    }
+    icons.sort();
+    let mut section = String::from("[icons]\n\n");
+    for (class, path) in icons {
+        section.push_str(&format!("{class} = \"{path}\"\n"));
+    }
+    section
+}
+
+fn replace_icons_section(gdextension: &str, section: &str) -> String {
+    let mut out = String::new();
+    let mut in_icons = false;
+    for line in gdextension.lines() {
+        let trimmed = line.trim();
+        if trimmed.starts_with('[') {
+            in_icons = trimmed == "[icons]";
+        }
+        if !in_icons {
+            out.push_str(line);
+            out.push('\n');
+        }
+    }
+    let mut out = out.trim_end().to_string();
+    out.push('\n');
+    if !section.is_empty() {
+        out.push('\n');
+        out.push_str(section);
+    }
+    out
+}
+
 // Registered creators keyed by Godot class name, built once at plugin build.
 static BUNDLE_REGISTRY: OnceLock<HashMap<String, Vec<&'static AutoSyncBundleRegistry>>> =
     OnceLock::new();
@@ -135,6 +222,30 @@ mod tests {
         GodotRequiredComponents { component_name: "Primary", registrar_fn: register_primary }
     }
 
+    #[derive(bevy_reflect::Reflect)]
+    struct Registered(u8);
+
+    fn register_registered(app: &mut App) {
+        app.register_type::<Registered>();
+    }
+    crate::inventory::submit! {
+        AutoRegisteredType { type_name: "Registered", register_fn: register_registered }
+    }
+
+    #[test]
+    fn icons_section_replaces_the_old_one() {
+        let gdextension = "[configuration]\nentry_symbol = \"gdext_rust_init\"\n\n[icons]\n\nOld = \"res://old.svg\"\n\n[libraries]\nlinux.debug.x86_64 = \"res://rust/target/debug/libgame.so\"\n";
+        let section = icons_section(vec![
+            ("Orbiter3D".into(), "res://icons/orbiter.svg"),
+            ("Door2D".into(), "res://icons/door.svg"),
+        ]);
+        assert_eq!(
+            replace_icons_section(gdextension, &section),
+            "[configuration]\nentry_symbol = \"gdext_rust_init\"\n\n[libraries]\nlinux.debug.x86_64 = \"res://rust/target/debug/libgame.so\"\n\n[icons]\n\nDoor2D = \"res://icons/door.svg\"\nOrbiter3D = \"res://icons/orbiter.svg\"\n"
+        );
+        assert!(!replace_icons_section(gdextension, "").contains("[icons]"));
+    }
+
     #[test]
     fn registrar_entries_apply_to_new_world() {
         let mut app = App::new();
@@ -142,4 +253,15 @@ mod tests {
         let e = app.world_mut().spawn(Primary).id();
         assert_eq!(app.world().get::<Companion>(e), Some(&Companion(7)));
     }
+
+    #[test]
+    fn auto_registered_types_reach_the_type_registry() {
+        let mut app = App::new();
+        register_all_auto_types(&mut app);
+        let registry = app
+            .world()
+            .resource::<bevy_ecs::reflect::AppTypeRegistry>()
+            .read();
+        assert!(registry.contains(std::any::TypeId::of::<Registered>()));
+    }
 }
@@ -0,0 +1,143 @@
+//! Keeping entities and their nodes alive and dead together.
+//!
+//! Despawning an entity frees its node (see the core plugin's removal observer),
+//! and a node leaving the tree despawns its entity. Two gaps remain, closed here:
+//! [`GodotDespawnExt::despawn_with_node`] takes a node's mirrored descendants down
+//! with it whatever the despawn settings, and the reaper despawns entities whose
+//! node was freed without leaving the tree first -- an orphan node freed from
+//! GDScript, say -- so no entity is left holding a dead handle.
+
+use bevy_ecs::entity::Entity;
+use bevy_ecs::message::{Message, MessageWriter};
+use bevy_ecs::prelude::Resource;
+use bevy_ecs::query::Has;
+use bevy_ecs::system::{Commands, EntityCommands, Local, Query, Res};
+use bevy_ecs::world::EntityWorldMut;
+use godot::classes::Object;
+use godot::obj::{Gd, InstanceId};
+use tracing::debug;
+
+use super::plugin::{NodeRemoved, ProtectedNodeEntity, strip_godot_components};
+use super::relationship::GodotChildren;
+use crate::interop::{GodotAccess, GodotNodeHandle};
+
+/// Despawn an entity together with its node's subtree.
+pub trait GodotDespawnExt {
+    /// Despawn this entity and the entities mirroring its node's descendants,
+    /// and free the node.
+    ///
+    /// A plain `despawn` frees the node too, and its descendants follow as their
+    /// nodes leave the tree -- unless `auto_despawn_children` is off or they are
+    /// [`ProtectedNodeEntity`]s, which then outlive nodes that are already gone.
+    /// This despawns all of them right away.
+    fn despawn_with_node(&mut self);
+}
+
+impl GodotDespawnExt for EntityCommands<'_> {
+    fn despawn_with_node(&mut self) {
+        self.queue(|entity: EntityWorldMut| {
+            let root = entity.id();
+            let world = entity.into_world_mut();
+            let mut subtree = vec![root];
+            let mut index = 0;
+            while let Some(&parent) = subtree.get(index) {
+                if let Some(children) = world.get::<GodotChildren>(parent) {
+                    subtree.extend(children.iter().copied());
+                }
+                index += 1;
+            }
+            // Children first, so the root's despawn hook has nothing left to do.
+            for entity in subtree.into_iter().rev() {
+                let _ = world.try_despawn(entity);
+            }
+        });
+    }
+}
+
+/// How many node handles the reaper checks per frame. The checks are spread
+/// over frames, so a dangling entity is found within
+/// `entities / checks_per_frame` frames.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NodeReaperConfig {
+    /// `0` turns the reaper off.
+    pub checks_per_frame: usize,
+}
+
+impl Default for NodeReaperConfig {
+    fn default() -> Self {
+        Self {
+            checks_per_frame: 256,
+        }
+    }
+}
+
+/// The reaper found an entity whose node had been freed, and despawned it (or,
+/// for a [`ProtectedNodeEntity`], removed its Godot components).
+///
+/// Nodes freed while in the tree are handled by the scene tree's `NodeRemoved`
+/// path and never show up here, so each of these points at a node freed
+/// somewhere the mirror doesn't see.
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DanglingEntityReaped {
+    pub entity: Entity,
+    pub instance_id: InstanceId,
+    pub protected: bool,
+}
+
+pub(super) fn reap_freed_nodes(
+    handles: Query<(Entity, &GodotNodeHandle, Has<ProtectedNodeEntity>)>,
+    config: Res<NodeReaperConfig>,
+    mut cursor: Local<usize>,
+    mut reaped: MessageWriter<DanglingEntityReaped>,
+    mut commands: Commands,
+    _godot: GodotAccess,
+) {
+    if config.checks_per_frame == 0 {
+        return;
+    }
+    let mut checked = 0;
+    for (entity, handle, protected) in handles.iter().skip(*cursor).take(config.checks_per_frame) {
+        checked += 1;
+        let instance_id = handle.instance_id();
+        if Gd::<Object>::try_from_instance_id(instance_id).is_ok() {
+            continue;
+        }
+        debug!("Reaping {entity}: its node {instance_id} was freed");
+        commands.trigger(NodeRemoved { entity });
+        if protected {
+            strip_godot_components(&mut commands, entity);
+        } else {
+            commands.entity(entity).despawn();
+        }
+        reaped.write(DanglingEntityReaped {
+            entity,
+            instance_id,
+            protected,
+        });
+    }
+    *cursor = next_cursor(*cursor, checked, config.checks_per_frame);
+}
+
+/// Where the next frame's checks start: past this frame's, or back at the start
+/// once the end was reached.
+fn next_cursor(cursor: usize, checked: usize, checks_per_frame: usize) -> usize {
+    if checked < checks_per_frame {
+        0
+    } else {
+        cursor + checked
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reaper_sweeps_then_wraps() {
+        assert_eq!(next_cursor(0, 256, 256), 256);
+        assert_eq!(next_cursor(256, 256, 256), 512);
+        // Fewer left than the budget: the sweep is done.
+        assert_eq!(next_cursor(512, 10, 256), 0);
+        assert_eq!(next_cursor(0, 0, 256), 0);
+    }
+}
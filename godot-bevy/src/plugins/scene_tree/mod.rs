@@ -1,15 +1,30 @@
 pub mod autosync;
+pub mod lifetime;
 pub mod node_type_checking;
+pub mod order;
+pub mod path_lookup;
 pub mod plugin;
+pub mod rebind;
 pub mod relationship;
+pub mod tags;
 
 // Re-export main components
 pub use autosync::{
-    AutoSyncBundleRegistry, BundleCreatorFn, GodotRequiredComponents,
-    RequiredComponentsRegistrarFn, register_all_autosync_bundles, register_all_required_components,
+    AutoRegisteredType, AutoSyncBundleRegistry, BundleCreatorFn, GodotNodeIcon,
+    GodotRequiredComponents, RequiredComponentsRegistrarFn, TypeRegistrarFn,
+    gdextension_icons_section, gdextension_with_icons, register_all_auto_types,
+    register_all_autosync_bundles, register_all_required_components,
 };
+pub use lifetime::{DanglingEntityReaped, GodotDespawnExt, NodeReaperConfig};
+pub use order::{
+    GodotMoveToIndexExt, GodotTreeOrderPlugin, SceneTreeOrder, SiblingIndex, TreeDepth,
+};
+pub use path_lookup::{EntityByPath, NodePathCache};
 pub use plugin::{
-    GodotSceneTreePlugin, Groups, NodeEntityIndex, ProtectedNodeEntity, SceneTreeConfig,
-    SceneTreeMessage, SceneTreeMessageReader, SceneTreeMessageType, SceneTreeRef,
+    GodotSceneTreePlugin, Groups, NodeAdded, NodeEntityIndex, NodeRemoved, NodeRenamed,
+    ProtectedNodeEntity, SceneTreeConfig, SceneTreeMessage, SceneTreeMessageReader,
+    SceneTreeMessageType, SceneTreeRef,
 };
-pub use relationship::{GodotChildOf, GodotChildren};
+pub use rebind::{HandleRebound, RebindByPath};
+pub use relationship::{GodotChildOf, GodotChildren, GodotReparentExt};
+pub use tags::{TAGS_META, TagIndex, Tags};
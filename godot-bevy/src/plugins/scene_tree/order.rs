@@ -0,0 +1,377 @@
+use bevy_app::{App, Plugin, PreUpdate};
+use bevy_ecs::change_detection::DetectChangesMut;
+use bevy_ecs::component::Component;
+use bevy_ecs::entity::Entity;
+use bevy_ecs::event::EntityEvent;
+use bevy_ecs::lifecycle::RemovedComponents;
+use bevy_ecs::observer::On;
+use bevy_ecs::prelude::{ReflectComponent, Resource};
+use bevy_ecs::query::{Added, Changed, QueryData, QueryFilter, QueryManyIter, Without};
+use bevy_ecs::schedule::IntoScheduleConfigs;
+use bevy_ecs::system::{Commands, EntityCommands, Query, Res, ResMut, SystemParam};
+use bevy_ecs::world::EntityWorldMut;
+use bevy_platform::collections::HashSet;
+use bevy_reflect::Reflect;
+use godot::builtin::Variant;
+use godot::classes::Node;
+use godot::meta::ToGodot;
+use godot::obj::Gd;
+use godot::prelude::Callable;
+use tracing::warn;
+
+use super::relationship::{GodotChildOf, GodotChildren};
+use crate::interop::{GodotAccess, GodotNodeHandle};
+use crate::plugins::deferred_calls::defer_call;
+use crate::plugins::event_bridge::GodotEventSender;
+
+/// Iterate mirrored entities in scene tree order -- parents before children,
+/// siblings by their index under the parent -- instead of archetype order, which
+/// changes as components are added and removed.
+///
+/// ```ignore
+/// fn step_simulation(mut order: SceneTreeOrder, mut bodies: Query<(&mut Body, &Transform)>) {
+///     for (mut body, transform) in order.iter_mut(&mut bodies) {
+///         // Same order every run, as long as the scene is the same.
+///     }
+/// }
+///
+/// fn layout(mut order: SceneTreeOrder, menu: Single<Entity, With<Menu>>) {
+///     for (row, item) in order.children(*menu).into_iter().enumerate() {
+///         // Rows in the order the nodes appear in the editor.
+///     }
+/// }
+/// ```
+///
+/// Sibling indices are read from Godot on every call, since moving a node among
+/// its siblings sends no scene tree message; call [`entities`](Self::entities)
+/// once and pass the result to `Query::iter_many` when iterating several queries
+/// in one system. Entities without a node are skipped.
+#[derive(SystemParam)]
+pub struct SceneTreeOrder<'w, 's> {
+    roots: Query<'w, 's, (Entity, &'static GodotNodeHandle), Without<GodotChildOf>>,
+    child_lists: Query<'w, 's, &'static GodotChildren>,
+    handles: Query<'w, 's, &'static GodotNodeHandle>,
+    godot: GodotAccess<'w>,
+}
+
+impl SceneTreeOrder<'_, '_> {
+    /// Every mirrored entity, depth first in scene tree order.
+    pub fn entities(&mut self) -> Vec<Entity> {
+        let mut roots: Vec<(Entity, Option<Gd<Node>>)> = self
+            .roots
+            .iter()
+            .map(|(entity, handle)| (entity, self.godot.try_get::<Node>(*handle)))
+            .collect();
+        // Top-level entities are rarely more than the root window, but nodes whose
+        // parent isn't mirrored land here too.
+        roots.sort_by(|(a_entity, a), (b_entity, b)| match (a, b) {
+            (Some(a), Some(b)) if a.is_greater_than(b) => std::cmp::Ordering::Greater,
+            (Some(a), Some(b)) if b.is_greater_than(a) => std::cmp::Ordering::Less,
+            _ => a_entity.cmp(b_entity),
+        });
+        let roots = roots
+            .into_iter()
+            .filter(|(_, node)| node.is_some())
+            .map(|(entity, _)| entity)
+            .collect();
+        depth_first(roots, |parent| self.children(parent))
+    }
+
+    /// `parent`'s mirrored children in sibling order.
+    pub fn children(&mut self, parent: Entity) -> Vec<Entity> {
+        let Ok(children) = self.child_lists.get(parent) else {
+            return Vec::new();
+        };
+        let mut indexed: Vec<(Entity, i32)> = children
+            .iter()
+            .filter_map(|&child| {
+                let handle = self.handles.get(child).ok()?;
+                let node = self.godot.try_get::<Node>(*handle)?;
+                Some((child, node.get_index()))
+            })
+            .collect();
+        sort_siblings(&mut indexed);
+        indexed.into_iter().map(|(entity, _)| entity).collect()
+    }
+
+    /// `query`'s items in scene tree order.
+    pub fn iter<'q, 's, D: QueryData, F: QueryFilter>(
+        &mut self,
+        query: &'q Query<'_, 's, D, F>,
+    ) -> QueryManyIter<'q, 's, D::ReadOnly, F, std::vec::IntoIter<Entity>> {
+        query.iter_many(self.entities())
+    }
+
+    /// `query`'s items in scene tree order, mutably.
+    pub fn iter_mut<'q, 's, D: QueryData, F: QueryFilter>(
+        &mut self,
+        query: &'q mut Query<'_, 's, D, F>,
+    ) -> QueryManyIter<'q, 's, D, F, std::vec::IntoIter<Entity>> {
+        query.iter_many_mut(self.entities())
+    }
+}
+
+/// Order siblings by their index under the parent.
+fn sort_siblings(siblings: &mut [(Entity, i32)]) {
+    siblings.sort_by_key(|&(entity, index)| (index, entity));
+}
+
+fn depth_first(roots: Vec<Entity>, mut children: impl FnMut(Entity) -> Vec<Entity>) -> Vec<Entity> {
+    let mut order = Vec::new();
+    let mut stack: Vec<Entity> = roots.into_iter().rev().collect();
+    while let Some(entity) = stack.pop() {
+        order.push(entity);
+        stack.extend(children(entity).into_iter().rev());
+    }
+    order
+}
+
+/// Plugin keeping [`SiblingIndex`] and [`TreeDepth`] on every mirrored entity.
+///
+/// Opt-in, since it connects `child_order_changed` on every mirrored node:
+///
+/// ```ignore
+/// app.add_plugins(GodotTreeOrderPlugin);
+///
+/// fn restack_cards(mut cards: Query<(&SiblingIndex, &mut Card), Changed<SiblingIndex>>) {
+///     for (index, mut card) in &mut cards {
+///         card.slot = index.0;
+///     }
+/// }
+/// ```
+///
+/// Both components are updated in `PreUpdate`, by the frame after Godot adds, moves,
+/// removes or reparents a node, and only change when their value does.
+#[derive(Default)]
+pub struct GodotTreeOrderPlugin;
+
+impl Plugin for GodotTreeOrderPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<TreeOrderDirty>()
+            .add_observer(on_child_order_changed)
+            .add_systems(
+                PreUpdate,
+                (track_tree_order_changes, update_tree_order).chain(),
+            );
+    }
+}
+
+/// The node's index under its Godot parent, as from `Node.get_index`. Siblings
+/// that aren't mirrored count too. Kept by [`GodotTreeOrderPlugin`].
+#[derive(Component, Reflect, Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[reflect(Component)]
+pub struct SiblingIndex(pub usize);
+
+/// How many mirrored ancestors the entity has; `0` for the root window. Kept by
+/// [`GodotTreeOrderPlugin`].
+#[derive(Component, Reflect, Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[reflect(Component)]
+pub struct TreeDepth(pub usize);
+
+/// Move an entity's node among its siblings from a system.
+pub trait GodotMoveToIndexExt {
+    /// Move this entity's node to `index` under its parent, shifting the siblings
+    /// after it. The Godot call is deferred to the [`DeferredFlush`] point (the
+    /// end of the frame by default), since the tree may be locked mid-frame;
+    /// [`SiblingIndex`] follows on the next frame.
+    ///
+    /// [`DeferredFlush`]: crate::plugins::deferred_calls::DeferredFlush
+    fn move_to_index(&mut self, index: usize) -> &mut Self;
+}
+
+impl GodotMoveToIndexExt for EntityCommands<'_> {
+    fn move_to_index(&mut self, index: usize) -> &mut Self {
+        self.queue(move |mut entity: EntityWorldMut| {
+            let Some(node) = entity
+                .get::<GodotNodeHandle>()
+                .and_then(|handle| Gd::<Node>::try_from_instance_id(handle.instance_id()).ok())
+            else {
+                warn!("move_to_index: {} has no Godot node", entity.id());
+                return;
+            };
+            let Some(parent) = node.get_parent() else {
+                warn!("move_to_index: {}'s node has no parent", entity.id());
+                return;
+            };
+            entity.world_scope(|world| {
+                defer_call(
+                    world,
+                    parent.upcast(),
+                    "move_child",
+                    &[node.to_variant(), (index as i64).to_variant()],
+                );
+            });
+        })
+    }
+}
+
+/// A mirrored node's `child_order_changed`.
+#[derive(EntityEvent, Debug, Clone, Copy)]
+struct ChildOrderChanged {
+    entity: Entity,
+}
+
+/// Entities whose components need rereading this frame.
+#[derive(Resource, Debug, Default)]
+struct TreeOrderDirty {
+    /// Reread the [`SiblingIndex`] of each of these entities' children.
+    parents: HashSet<Entity>,
+    /// Reread these entities' [`SiblingIndex`].
+    nodes: HashSet<Entity>,
+    /// Recompute these entities' [`TreeDepth`], and their descendants'.
+    depths: HashSet<Entity>,
+}
+
+fn on_child_order_changed(changed: On<ChildOrderChanged>, mut dirty: ResMut<TreeOrderDirty>) {
+    dirty.parents.insert(changed.entity);
+}
+
+fn track_tree_order_changes(
+    added: Query<(Entity, &GodotNodeHandle), Added<GodotNodeHandle>>,
+    moved: Query<Entity, Changed<GodotChildOf>>,
+    mut unparented: RemovedComponents<GodotChildOf>,
+    sender: Res<GodotEventSender>,
+    mut dirty: ResMut<TreeOrderDirty>,
+    mut godot: GodotAccess,
+) {
+    for (entity, handle) in &added {
+        let Some(mut node) = godot.try_get::<Node>(*handle) else {
+            continue;
+        };
+        let tx = sender.clone();
+        node.connect(
+            "child_order_changed",
+            &Callable::from_fn("tree_child_order_changed", move |_| {
+                tx.send(ChildOrderChanged { entity });
+                Variant::nil()
+            }),
+        );
+        dirty.nodes.insert(entity);
+        dirty.depths.insert(entity);
+    }
+    for entity in moved.iter().chain(unparented.read()) {
+        dirty.nodes.insert(entity);
+        dirty.depths.insert(entity);
+    }
+}
+
+fn update_tree_order(
+    mut dirty: ResMut<TreeOrderDirty>,
+    child_lists: Query<&GodotChildren>,
+    parents: Query<&GodotChildOf>,
+    mut nodes: Query<(
+        &GodotNodeHandle,
+        Option<&mut SiblingIndex>,
+        Option<&mut TreeDepth>,
+    )>,
+    mut commands: Commands,
+    mut godot: GodotAccess,
+) {
+    let TreeOrderDirty {
+        parents: dirty_parents,
+        nodes: dirty_nodes,
+        depths: dirty_depths,
+    } = &mut *dirty;
+
+    for parent in dirty_parents.drain() {
+        if let Ok(children) = child_lists.get(parent) {
+            dirty_nodes.extend(children.iter().copied());
+        }
+    }
+    for entity in dirty_nodes.drain() {
+        let Ok((handle, index, _)) = nodes.get_mut(entity) else {
+            continue;
+        };
+        let Some(node) = godot.try_get::<Node>(*handle) else {
+            continue;
+        };
+        // -1 for the root window, which has no parent.
+        let value = SiblingIndex(node.get_index().max(0) as usize);
+        match index {
+            Some(mut index) => {
+                index.set_if_neq(value);
+            }
+            None => {
+                commands.entity(entity).insert(value);
+            }
+        }
+    }
+
+    for entity in dirty_depths.drain() {
+        let depth = depth_of(entity, |child| {
+            parents.get(child).ok().map(GodotChildOf::get)
+        });
+        // A reparent moves the whole subtree.
+        let mut stack = vec![(entity, depth)];
+        while let Some((entity, depth)) = stack.pop() {
+            match nodes.get_mut(entity) {
+                Ok((_, _, Some(mut current))) => {
+                    current.set_if_neq(TreeDepth(depth));
+                }
+                Ok((_, _, None)) => {
+                    commands.entity(entity).insert(TreeDepth(depth));
+                }
+                Err(_) => {}
+            }
+            if let Ok(children) = child_lists.get(entity) {
+                stack.extend(children.iter().map(|&child| (child, depth + 1)));
+            }
+        }
+    }
+}
+
+/// The number of ancestors above `entity`.
+fn depth_of(entity: Entity, parent_of: impl Fn(Entity) -> Option<Entity>) -> usize {
+    let mut depth = 0;
+    let mut current = entity;
+    while let Some(parent) = parent_of(current) {
+        depth += 1;
+        current = parent;
+    }
+    depth
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entity(index: u32) -> Entity {
+        Entity::from_raw_u32(index).unwrap()
+    }
+
+    #[test]
+    fn parents_come_before_children_in_sibling_order() {
+        let mut siblings = vec![(entity(5), 2), (entity(3), 0), (entity(9), 1)];
+        sort_siblings(&mut siblings);
+        let sorted: Vec<Entity> = siblings.iter().map(|(entity, _)| *entity).collect();
+        assert_eq!(sorted, vec![entity(3), entity(9), entity(5)]);
+
+        // 1 -> [2 -> [4], 3]
+        let order = depth_first(vec![entity(1)], |parent| {
+            if parent == entity(1) {
+                vec![entity(2), entity(3)]
+            } else if parent == entity(2) {
+                vec![entity(4)]
+            } else {
+                Vec::new()
+            }
+        });
+        assert_eq!(order, vec![entity(1), entity(2), entity(4), entity(3)]);
+    }
+
+    #[test]
+    fn depth_counts_ancestors() {
+        // 1 -> 2 -> 3
+        let parent_of = |child: Entity| {
+            if child == entity(3) {
+                Some(entity(2))
+            } else if child == entity(2) {
+                Some(entity(1))
+            } else {
+                None
+            }
+        };
+        assert_eq!(depth_of(entity(1), parent_of), 0);
+        assert_eq!(depth_of(entity(3), parent_of), 2);
+    }
+}
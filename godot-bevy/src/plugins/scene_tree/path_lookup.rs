@@ -0,0 +1,134 @@
+use std::collections::HashMap;
+
+use bevy_ecs::entity::Entity;
+use bevy_ecs::message::MessageReader;
+use bevy_ecs::prelude::Resource;
+use bevy_ecs::system::{Res, ResMut, SystemParam};
+use godot::obj::InstanceId;
+
+use super::plugin::{NodeEntityIndex, SceneTreeMessage, SceneTreeRef};
+use crate::interop::GodotNodeHandle;
+
+/// Look up mirrored nodes by path, for the one-off lookups [`NodeTreeView`] is too
+/// heavy for:
+///
+/// ```ignore
+/// fn follow_player(mut nodes: EntityByPath, mut cameras: Query<&mut Transform, With<MainCamera>>) {
+///     let Some(player) = nodes.get("/root/Main/Player") else { return };
+///     // ...
+/// }
+/// ```
+///
+/// Absolute paths start at `/root`; relative paths are resolved from the root
+/// window. Only nodes with an entity are found. Results, including misses, are
+/// cached until the next scene tree change, so repeated lookups cost a hash
+/// lookup instead of a `get_node` call.
+///
+/// [`NodeTreeView`]: crate::node_tree_view::NodeTreeView
+#[derive(SystemParam)]
+pub struct EntityByPath<'w, 's> {
+    index: Res<'w, NodeEntityIndex>,
+    cache: ResMut<'w, NodePathCache>,
+    scene_tree: SceneTreeRef<'w, 's>,
+}
+
+impl EntityByPath<'_, '_> {
+    /// The entity of the node at `path`.
+    pub fn get(&mut self, path: &str) -> Option<Entity> {
+        self.get_with_handle(path).map(|(entity, _)| entity)
+    }
+
+    /// The handle of the node at `path`, if the node has an entity.
+    pub fn get_handle(&mut self, path: &str) -> Option<GodotNodeHandle> {
+        self.get_with_handle(path).map(|(_, handle)| handle)
+    }
+
+    pub fn get_with_handle(&mut self, path: &str) -> Option<(Entity, GodotNodeHandle)> {
+        let instance_id = match self.cache.paths.get(path) {
+            Some(cached) => *cached,
+            None => {
+                let instance_id = self.resolve(path);
+                self.cache.paths.insert(path.to_string(), instance_id);
+                instance_id
+            }
+        }?;
+        let entity = self.index.get(instance_id)?;
+        Some((entity, GodotNodeHandle::from_instance_id(instance_id)))
+    }
+
+    fn resolve(&mut self, path: &str) -> Option<InstanceId> {
+        let root = self.scene_tree.get().get_root()?;
+        let node = root.get_node_or_null(path)?;
+        let instance_id = node.instance_id();
+        self.index.contains(instance_id).then_some(instance_id)
+    }
+}
+
+/// Path to node cache behind [`EntityByPath`], cleared on any scene tree change.
+#[derive(Resource, Default, Debug)]
+pub struct NodePathCache {
+    paths: HashMap<String, Option<InstanceId>>,
+}
+
+impl NodePathCache {
+    pub fn clear(&mut self) {
+        self.paths.clear();
+    }
+
+    pub fn len(&self) -> usize {
+        self.paths.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.paths.is_empty()
+    }
+}
+
+/// Adds, removals, renames and reparents can all move a path, so any message
+/// drops the whole cache.
+pub(crate) fn invalidate_node_path_cache(
+    mut messages: MessageReader<SceneTreeMessage>,
+    mut cache: ResMut<NodePathCache>,
+) {
+    if !messages.is_empty() {
+        messages.clear();
+        cache.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::plugins::scene_tree::SceneTreeMessageType;
+    use bevy_ecs::message::Messages;
+    use bevy_ecs::system::RunSystemOnce;
+    use bevy_ecs::world::World;
+
+    #[test]
+    fn scene_tree_messages_clear_the_cache() {
+        let mut world = World::new();
+        world.init_resource::<Messages<SceneTreeMessage>>();
+        world.init_resource::<NodePathCache>();
+        let node = InstanceId::from_i64(1);
+        world
+            .resource_mut::<NodePathCache>()
+            .paths
+            .insert("/root/Main".into(), Some(node));
+
+        world.run_system_once(invalidate_node_path_cache).unwrap();
+        assert_eq!(world.resource::<NodePathCache>().len(), 1);
+
+        world.write_message(SceneTreeMessage {
+            node_id: GodotNodeHandle::from_instance_id(node),
+            message_type: SceneTreeMessageType::NodeRenamed,
+            node_type: None,
+            node_name: Some("Level".into()),
+            parent_id: None,
+            collision_mask: None,
+            groups: None,
+            transform: None,
+        });
+        world.run_system_once(invalidate_node_path_cache).unwrap();
+        assert!(world.resource::<NodePathCache>().is_empty());
+    }
+}
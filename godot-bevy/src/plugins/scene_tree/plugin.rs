@@ -1,9 +1,14 @@
+use super::lifetime::{DanglingEntityReaped, NodeReaperConfig, reap_freed_nodes};
 use super::node_type_checking::{
     add_node_type_markers_from_string, remove_comprehensive_node_type_markers,
 };
-use crate::plugins::core::SceneTreeComponentRegistry;
+use super::path_lookup::{NodePathCache, invalidate_node_path_cache};
+use super::rebind::{HandleRebound, RebindByPath, RebindIndex, record_rebind_paths};
+use super::tags::{TagIndex, Tags, register_tag_hooks};
+use crate::plugins::core::{GodotSyncSet, SceneTreeComponentRegistry, SyncStats};
+use crate::plugins::transforms::conversions::bevy_transform_from_variant;
 use crate::prelude::GodotScene;
-use crate::watchers::scene_tree_watcher::is_excluded_from_mirror;
+use crate::watchers::scene_tree_watcher::{EXCLUDE_META, is_excluded_from_mirror};
 use crate::{
     interop::{GodotAccess, GodotNodeHandle},
     plugins::collisions::{
@@ -14,6 +19,7 @@ use bevy_app::{App, First, Plugin, PreStartup};
 use bevy_ecs::{
     component::Component,
     entity::Entity,
+    event::EntityEvent,
     lifecycle::HookContext,
     message::{Message, MessageReader, MessageWriter, message_update_system},
     prelude::{Name, ReflectComponent, ReflectResource, Resource},
@@ -24,6 +30,7 @@ use bevy_ecs::{
 };
 use bevy_reflect::Reflect;
 use bevy_time::{Time, TimeSystems, Virtual};
+use bevy_transform::components::Transform;
 use godot::classes::ClassDb;
 use godot::tools::try_get_autoload_by_name;
 use godot::{
@@ -168,14 +175,20 @@ impl Plugin for GodotSceneTreePlugin {
         // Auto-register all discovered AutoSyncBundle plugins
         super::autosync::register_all_autosync_bundles(app);
         super::autosync::register_all_required_components(app);
+        super::autosync::register_all_auto_types(app);
 
         app.init_non_send::<SceneTreeRefImpl>()
             .init_resource::<NodeEntityIndex>()
             .init_resource::<PauseBridge>()
+            .init_resource::<NodePathCache>()
+            .init_resource::<NodeReaperConfig>()
+            .init_resource::<TagIndex>()
+            .init_resource::<RebindIndex>()
             .insert_resource(SceneTreeConfig {
                 auto_despawn_children: self.auto_despawn_children,
             })
             .add_message::<SceneTreeMessage>()
+            .add_message::<DanglingEntityReaped>()
             .add_systems(
                 PreStartup,
                 (connect_scene_tree, initialize_scene_tree).chain(),
@@ -183,8 +196,14 @@ impl Plugin for GodotSceneTreePlugin {
             .add_systems(
                 First,
                 (
-                    write_scene_tree_messages.before(message_update_system),
-                    read_scene_tree_messages.before(message_update_system),
+                    (
+                        (write_scene_tree_messages, read_scene_tree_messages),
+                        invalidate_node_path_cache.after(write_scene_tree_messages),
+                        reap_freed_nodes.after(read_scene_tree_messages),
+                        record_rebind_paths.after(read_scene_tree_messages),
+                    )
+                        .before(message_update_system)
+                        .in_set(GodotSyncSet::SceneTreeRead),
                     mirror_tree_pause_to_virtual.before(TimeSystems),
                 ),
             );
@@ -197,6 +216,7 @@ impl Plugin for GodotSceneTreePlugin {
             // 0.19 renamed the replace hook to `on_discard` (fires when the component
             // is about to be dropped via replace/remove); same semantics as before.
             .on_discard(on_godot_node_handle_replace);
+        register_tag_hooks(app.world_mut());
     }
 }
 
@@ -208,15 +228,37 @@ pub struct SceneTreeRef<'w, 's> {
 
 impl<'w, 's> SceneTreeRef<'w, 's> {
     pub fn get(&mut self) -> Gd<SceneTree> {
-        self.gd.0.clone()
+        self.gd.tree.clone()
+    }
+
+    /// A helper child (watcher) of the `BevyApp` node running this app.
+    pub(crate) fn app_child(&mut self, child_name: &str) -> Option<Gd<Node>> {
+        match self.gd.app_node {
+            Some(id) => Gd::<Node>::try_from_instance_id(id)
+                .ok()?
+                .try_get_node_as::<Node>(child_name),
+            None => get_bevy_app_child(child_name),
+        }
     }
 }
 
 #[doc(hidden)]
 #[derive(Debug)]
-pub(crate) struct SceneTreeRefImpl(Gd<SceneTree>);
+pub(crate) struct SceneTreeRefImpl {
+    tree: Gd<SceneTree>,
+    /// The `BevyApp` node whose watchers feed this app; `None` looks them up
+    /// under the autoload.
+    app_node: Option<InstanceId>,
+}
 
 impl SceneTreeRefImpl {
+    pub(crate) fn for_app(app_node: InstanceId) -> Self {
+        Self {
+            tree: Self::get_ref(),
+            app_node: Some(app_node),
+        }
+    }
+
     fn get_ref() -> Gd<SceneTree> {
         Engine::singleton()
             .get_main_loop()
@@ -227,7 +269,10 @@ impl SceneTreeRefImpl {
 
 impl Default for SceneTreeRefImpl {
     fn default() -> Self {
-        Self(Self::get_ref())
+        Self {
+            tree: Self::get_ref(),
+            app_node: None,
+        }
     }
 }
 
@@ -268,6 +313,7 @@ fn mirror_tree_pause_to_virtual(
     bridge.last_tree_paused = tree_paused;
 }
 
+#[allow(clippy::too_many_arguments)]
 fn initialize_scene_tree(
     mut commands: Commands,
     mut scene_tree: SceneTreeRef,
@@ -279,13 +325,16 @@ fn initialize_scene_tree(
     )>,
     component_registry: Res<SceneTreeComponentRegistry>,
     mut node_index: ResMut<NodeEntityIndex>,
+    rebind_paths: Query<&RebindByPath>,
+    mut rebind_index: ResMut<RebindIndex>,
     message_reader: Res<SceneTreeMessageReader>,
+    mut stats: Option<ResMut<SyncStats>>,
     mut godot: GodotAccess,
 ) {
     let root = scene_tree.get().get_root().unwrap();
 
     // Check if we have the optimized GDScript watcher for type pre-analysis
-    let optimized_watcher = get_bevy_app_child("OptimizedSceneTreeWatcher");
+    let optimized_watcher = scene_tree.app_child("OptimizedSceneTreeWatcher");
 
     let messages = if let Some(mut watcher) = optimized_watcher {
         // Use optimized GDScript watcher to analyze the initial tree with type information
@@ -314,6 +363,10 @@ fn initialize_scene_tree(
         let groups_array = result_dict
             .get("groups")
             .map(|value| value.to::<godot::builtin::VarArray>());
+        // Transforms are optional too - only present in v3+ of the addon
+        let transforms = result_dict
+            .get("transforms")
+            .map(|value| value.to::<godot::builtin::VarArray>());
 
         let mut messages = Vec::new();
         let len = instance_ids.len().min(node_types.len());
@@ -350,6 +403,11 @@ fn initialize_scene_tree(
                             .collect::<Vec<_>>()
                     })
                 });
+                // Parse transforms if available (v3+ addon)
+                let transform = transforms
+                    .as_ref()
+                    .and_then(|arr| arr.get(i))
+                    .and_then(|variant| bevy_transform_from_variant(&variant));
 
                 messages.push(SceneTreeMessage {
                     node_id: GodotNodeHandle::from(godot::prelude::InstanceId::from_i64(id)),
@@ -359,6 +417,7 @@ fn initialize_scene_tree(
                     parent_id,
                     collision_mask,
                     groups,
+                    transform,
                 });
             }
         }
@@ -377,6 +436,9 @@ fn initialize_scene_tree(
         &mut entities,
         &component_registry,
         &mut node_index,
+        &rebind_paths,
+        &mut rebind_index,
+        stats.as_deref_mut(),
         &mut godot,
     );
 
@@ -391,7 +453,7 @@ fn initialize_scene_tree(
 fn traverse_fallback(node: Gd<Node>) -> Vec<SceneTreeMessage> {
     fn traverse_recursive(node: Gd<Node>, messages: &mut Vec<SceneTreeMessage>) {
         // Excluded subtree: skip this node and (recursion is below) all descendants.
-        if node.has_meta("_bevy_exclude") {
+        if node.has_meta(EXCLUDE_META) {
             return;
         }
         messages.push(SceneTreeMessage {
@@ -402,6 +464,7 @@ fn traverse_fallback(node: Gd<Node>) -> Vec<SceneTreeMessage> {
             parent_id: None,
             collision_mask: None,
             groups: None, // No groups optimization available
+            transform: None,
         });
 
         for child in node.get_children().iter_shared() {
@@ -423,6 +486,42 @@ pub struct SceneTreeMessage {
     pub parent_id: Option<InstanceId>,
     pub collision_mask: Option<u8>,
     pub groups: Option<Vec<String>>, // Pre-analyzed groups from GDScript watcher (v2+)
+    /// The local transform of a `Node2D`/`Node3D`, captured by the GDScript watcher (v3+).
+    pub transform: Option<Transform>,
+}
+
+/// A node entered the mirrored tree and its entity is fully set up: markers,
+/// registered components and `GodotChildOf` are in place.
+///
+/// Triggered alongside [`SceneTreeMessage`], so setup can live in an observer on
+/// the entity itself:
+///
+/// ```ignore
+/// commands
+///     .spawn(GodotScene::from_path("res://enemy.tscn"))
+///     .observe(|added: On<NodeAdded>, mut enemies: ResMut<EnemyCount>| enemies.0 += 1)
+///     .observe(|removed: On<NodeRemoved>, mut enemies: ResMut<EnemyCount>| enemies.0 -= 1);
+/// ```
+///
+/// A reparented node keeps its entity and triggers neither event.
+#[derive(EntityEvent, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NodeAdded {
+    pub entity: Entity,
+}
+
+/// A node left the tree. Triggered just before its entity is despawned (or, for a
+/// [`ProtectedNodeEntity`], stripped of its Godot components), so observers can
+/// still read it.
+#[derive(EntityEvent, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NodeRemoved {
+    pub entity: Entity,
+}
+
+/// A node was renamed; its `Name` already holds `name`.
+#[derive(EntityEvent, Debug, Clone, PartialEq, Eq)]
+pub struct NodeRenamed {
+    pub entity: Entity,
+    pub name: String,
 }
 
 #[derive(Copy, Clone, Debug, GodotConvert)]
@@ -499,13 +598,14 @@ fn get_bevy_app_child(child_name: &str) -> Option<Gd<Node>> {
 fn connect_scene_tree(mut scene_tree: SceneTreeRef) {
     let mut scene_tree_gd = scene_tree.get();
 
-    let watcher = get_bevy_app_child("SceneTreeWatcher")
+    let watcher = scene_tree
+        .app_child("SceneTreeWatcher")
         .unwrap_or_else(|| {
             panic!("SceneTreeWatcher not found as child of BevyAppSingleton autoload or anywhere in the scene tree.");
         });
 
     // Check if we have the optimized GDScript watcher
-    let optimized_watcher = get_bevy_app_child("OptimizedSceneTreeWatcher");
+    let optimized_watcher = scene_tree.app_child("OptimizedSceneTreeWatcher");
 
     if optimized_watcher.is_some() {
         // The optimized GDScript watcher handles scene tree connections and forwards
@@ -592,6 +692,8 @@ fn on_godot_node_handle_insert(mut world: DeferredWorld, ctx: HookContext) {
     world
         .resource_mut::<NodeEntityIndex>()
         .insert(handle.instance_id(), ctx.entity);
+    #[cfg(feature = "strict-threading")]
+    crate::interop::thread_check::entities::insert(handle.instance_id(), ctx.entity);
 }
 
 /// Fires before a `GodotNodeHandle` is overwritten or removed, while the old
@@ -603,12 +705,15 @@ fn on_godot_node_handle_replace(mut world: DeferredWorld, ctx: HookContext) {
     world
         .resource_mut::<NodeEntityIndex>()
         .remove(handle.instance_id());
+    #[cfg(feature = "strict-threading")]
+    crate::interop::thread_check::entities::remove(handle.instance_id());
 }
 
 fn write_scene_tree_messages(
     message_reader: Res<SceneTreeMessageReader>,
     mut message_writer: MessageWriter<SceneTreeMessage>,
 ) {
+    crate::profile_scope!("write_scene_tree_messages");
     let receiver = message_reader.0.lock();
     let messages: Vec<_> = receiver.try_iter().collect();
     message_writer.write_batch(messages);
@@ -626,8 +731,9 @@ pub struct ProtectedNodeEntity;
 /// Name/GodotChildOf but must not re-run the registry, autosync, markers, Groups, or
 /// collision connects -- that would reset authored ECS state.
 #[derive(Component)]
-struct SceneTreeDecorated;
+pub(crate) struct SceneTreeDecorated;
 
+#[allow(clippy::too_many_arguments)]
 fn create_scene_tree_entity(
     commands: &mut Commands,
     messages: impl IntoIterator<Item = SceneTreeMessage>,
@@ -640,14 +746,20 @@ fn create_scene_tree_entity(
     )>,
     component_registry: &SceneTreeComponentRegistry,
     node_index: &mut NodeEntityIndex,
+    rebind_paths: &Query<&RebindByPath>,
+    rebind_index: &mut RebindIndex,
+    stats: Option<&mut SyncStats>,
     godot: &mut GodotAccess,
 ) {
+    let mut added = 0;
+    let mut removed = 0;
+
     // Resolve entities via the complete NodeEntityIndex (in-loop inserts below
     // plus the GodotNodeHandle hooks), avoiding an O(world) scan per batch.
     let scene_root = scene_tree.get().get_root().unwrap();
 
     // CollisionWatcher is optional - only required if GodotCollisionsPlugin is added
-    let collision_watcher = get_bevy_app_child("CollisionWatcher");
+    let collision_watcher = scene_tree.app_child("CollisionWatcher");
 
     // Collect collision bodies for batched signal connection.
     let mut pending_collision_bodies: Vec<(Gd<Node>, u8, ColliderKind)> = Vec::new();
@@ -663,6 +775,7 @@ fn create_scene_tree_entity(
             parent_id: parent_id_from_gdscript,
             collision_mask,
             groups,
+            transform,
         } = message;
         let instance_id = node_id.instance_id();
         let node_handle = node_id;
@@ -682,13 +795,25 @@ fn create_scene_tree_entity(
                     .map(|(_, _, _, decorated)| decorated)
                     .unwrap_or(false);
 
-                let mut new_entity_commands = if let Some(ent) = existing_entity {
-                    commands.entity(ent)
-                } else {
-                    commands.spawn_empty()
+                // A node added where a RebindByPath entity's node was takes over
+                // that entity instead of spawning a new one.
+                let rebound = match existing_entity {
+                    None if !rebind_index.is_empty() => godot
+                        .try_get::<Node>(node_handle)
+                        .filter(|node| node.is_inside_tree())
+                        .and_then(|node| rebind_index.take(&node.get_path().to_string()))
+                        .filter(|(ent, _)| rebind_paths.contains(*ent)),
+                    _ => None,
                 };
 
-                let mut node_accessor = godot.node(node_handle);
+                let mut new_entity_commands =
+                    if let Some(ent) = existing_entity.or(rebound.map(|(ent, _)| ent)) {
+                        commands.entity(ent)
+                    } else {
+                        commands.spawn_empty()
+                    };
+
+                let mut node_accessor = godot.node(node_handle).with_captured_transform(transform);
                 let mut node = node_accessor.get::<Node>();
 
                 let node_name = node_name.unwrap_or_else(|| node.get_name().to_string());
@@ -760,12 +885,16 @@ fn create_scene_tree_entity(
                         groups,
                         SceneTreeDecorated,
                     ));
+                    if let Some(tags) = Tags::from_node(&node) {
+                        new_entity_commands.insert(tags);
+                    }
 
                     // Add all components registered by plugins
                     component_registry.add_to_entity(&mut new_entity_commands, &mut node_accessor);
 
                     let new_entity = new_entity_commands.id();
                     node_index.insert(instance_id, new_entity);
+                    added += 1;
 
                     // Try to add any registered bundles for this node type
                     super::autosync::try_add_bundles_for_node(
@@ -801,6 +930,17 @@ fn create_scene_tree_entity(
                         }
                     }
                 }
+
+                if !already_decorated {
+                    commands.trigger(NodeAdded { entity: new_entity });
+                }
+                if let Some((_, old)) = rebound {
+                    commands.trigger(HandleRebound {
+                        entity: new_entity,
+                        old,
+                        new: node_handle,
+                    });
+                }
             }
             SceneTreeMessageType::NodeRemoved => {
                 if let Some(ent) = existing_entity {
@@ -823,8 +963,10 @@ fn create_scene_tree_entity(
                             .map(|n| is_excluded_from_mirror(&n))
                             .unwrap_or(false);
                         if into_excluded {
+                            commands.trigger(NodeRemoved { entity: ent });
                             commands.entity(ent).despawn();
                             node_index.remove(instance_id);
+                            removed += 1;
                         } else {
                             trace!(target: "godot_scene_tree_events",
                                 "Node is being reparented, preserving entity");
@@ -836,12 +978,19 @@ fn create_scene_tree_entity(
                             .get(ent)
                             .map(|(_, _, prot, _)| prot.is_some())
                             .unwrap_or(false);
+                        commands.trigger(NodeRemoved { entity: ent });
                         if !protected {
                             commands.entity(ent).despawn();
                         } else {
-                            _strip_godot_components(commands, ent);
+                            if let Some(path) =
+                                rebind_paths.get(ent).ok().and_then(RebindByPath::path)
+                            {
+                                rebind_index.wait(path, ent, node_handle);
+                            }
+                            strip_godot_components(commands, ent);
                         }
                         node_index.remove(instance_id);
+                        removed += 1;
                     }
                 } else {
                     // Entity was already despawned (common when using queue_free)
@@ -852,7 +1001,8 @@ fn create_scene_tree_entity(
                 if let Some(ent) = existing_entity {
                     let name = node_name
                         .unwrap_or_else(|| godot.get::<Node>(node_handle).get_name().to_string());
-                    commands.entity(ent).insert(Name::from(name));
+                    commands.entity(ent).insert(Name::from(name.clone()));
+                    commands.trigger(NodeRenamed { entity: ent, name });
                 } else {
                     trace!(target: "godot_scene_tree_messages", "Entity for renamed node was already despawned");
                 }
@@ -864,7 +1014,13 @@ fn create_scene_tree_entity(
     if !pending_collision_bodies.is_empty()
         && let Some(ref collision_watcher) = collision_watcher
     {
-        batch_connect_collision_signals(collision_watcher, &pending_collision_bodies);
+        let bulk_ops = scene_tree.app_child("OptimizedBulkOperations");
+        batch_connect_collision_signals(collision_watcher, bulk_ops, &pending_collision_bodies);
+    }
+
+    if let Some(stats) = stats {
+        stats.nodes_added += added;
+        stats.nodes_removed += removed;
     }
 }
 
@@ -872,7 +1028,7 @@ fn create_scene_tree_entity(
 /// class name: the chain is static and scenes have few distinct classes, so we
 /// walk `ClassDb` once per class, not per node; `Rc` avoids reallocating on hits.
 /// Main-thread only (holds `GodotAccess`), so the thread-local cache is lock-free.
-fn get_inheritance_hierarchy(class_name: &str) -> Rc<Vec<String>> {
+pub(crate) fn get_inheritance_hierarchy(class_name: &str) -> Rc<Vec<String>> {
     thread_local! {
         static CACHE: RefCell<HashMap<String, Rc<Vec<String>>>> = RefCell::new(HashMap::new());
     }
@@ -911,12 +1067,12 @@ enum ColliderKind {
 /// Falls back to individual connections if bulk operations node is not available.
 fn batch_connect_collision_signals(
     collision_watcher: &Gd<Node>,
+    bulk_ops: Option<Gd<Node>>,
     pending_bodies: &[(Gd<Node>, u8, ColliderKind)],
 ) {
     use godot::builtin::PackedInt64Array;
 
-    let bulk_ops = get_bevy_app_child("OptimizedBulkOperations")
-        .filter(|node| node.has_method("bulk_connect_collision_signals"));
+    let bulk_ops = bulk_ops.filter(|node| node.has_method("bulk_connect_collision_signals"));
 
     if let Some(mut bulk_ops) = bulk_ops {
         // Use batched GDScript call
@@ -1101,13 +1257,14 @@ fn warn_dead_contact_monitor(node: &Gd<Node>) {
          max_contacts_reported > 0 on this node to receive collision events.");
 }
 
-fn _strip_godot_components(commands: &mut Commands, ent: Entity) {
+pub(super) fn strip_godot_components(commands: &mut Commands, ent: Entity) {
     let mut entity_commands = commands.entity(ent);
 
     entity_commands.remove::<GodotNodeHandle>();
     entity_commands.remove::<GodotScene>();
     entity_commands.remove::<Name>();
     entity_commands.remove::<Groups>();
+    entity_commands.remove::<Tags>();
     entity_commands.remove::<SceneTreeDecorated>();
 
     remove_comprehensive_node_type_markers(&mut entity_commands);
@@ -1156,8 +1313,12 @@ fn read_scene_tree_messages(
     )>,
     component_registry: Res<SceneTreeComponentRegistry>,
     mut node_index: ResMut<NodeEntityIndex>,
+    rebind_paths: Query<&RebindByPath>,
+    mut rebind_index: ResMut<RebindIndex>,
+    mut stats: Option<ResMut<SyncStats>>,
     mut godot: GodotAccess,
 ) {
+    crate::profile_scope!("read_scene_tree_messages");
     let messages: Vec<_> = message_reader.read().cloned().collect();
     if messages.is_empty() {
         return;
@@ -1179,6 +1340,9 @@ fn read_scene_tree_messages(
         &mut entities,
         &component_registry,
         &mut node_index,
+        &rebind_paths,
+        &mut rebind_index,
+        stats.as_deref_mut(),
         &mut godot,
     );
 }
@@ -0,0 +1,136 @@
+//! Entities that outlive a reload of their node by taking over its replacement.
+//!
+//! A scene reload frees every node and adds new ones at the same paths, so an
+//! entity that other state points at (a HUD label, a spawn point) would be
+//! despawned and its replacement spawned fresh. With [`RebindByPath`], the entity
+//! is kept when its node goes, and the next node added at the node's last path
+//! is mirrored into it instead of into a new entity:
+//!
+//! ```ignore
+//! fn mark_hud(mut commands: Commands, labels: Query<Entity, Added<HudLabel>>) {
+//!     for entity in &labels {
+//!         commands.entity(entity).insert(RebindByPath::default());
+//!     }
+//! }
+//!
+//! app.add_observer(|rebound: On<HandleRebound>| {
+//!     info!("{} now mirrors {:?}", rebound.entity, rebound.new);
+//! });
+//! ```
+//!
+//! The rebound entity is decorated like a new one -- `Name`, markers, `Transform`
+//! and the other registered components come from the new node -- and keeps the
+//! components you added. It triggers [`NodeAdded`](super::NodeAdded), then
+//! [`HandleRebound`]. Until a node appears, the entity has no `GodotNodeHandle`.
+
+use std::collections::HashMap;
+
+use bevy_ecs::component::Component;
+use bevy_ecs::entity::Entity;
+use bevy_ecs::event::EntityEvent;
+use bevy_ecs::lifecycle::HookContext;
+use bevy_ecs::prelude::{Name, Resource};
+use bevy_ecs::query::{Added, Changed, Or};
+use bevy_ecs::system::Query;
+use bevy_ecs::world::DeferredWorld;
+use godot::classes::Node;
+
+use super::plugin::ProtectedNodeEntity;
+use super::relationship::GodotChildOf;
+use crate::interop::{GodotAccess, GodotNodeHandle};
+
+/// Keep this entity when its node is freed, and bind it to the next node added
+/// at the same path. Implies [`ProtectedNodeEntity`]. See the
+/// [module docs](self).
+#[derive(Component, Debug, Clone, Default, PartialEq, Eq)]
+#[component(on_add = protect_rebind_entity)]
+pub struct RebindByPath {
+    path: Option<String>,
+}
+
+impl RebindByPath {
+    /// The path of the node last bound, once known.
+    pub fn path(&self) -> Option<&str> {
+        self.path.as_deref()
+    }
+}
+
+/// Insert [`ProtectedNodeEntity`] along with [`RebindByPath`], so the entity
+/// survives its node being freed.
+fn protect_rebind_entity(mut world: DeferredWorld, context: HookContext) {
+    world
+        .commands()
+        .entity(context.entity)
+        .insert(ProtectedNodeEntity);
+}
+
+/// A [`RebindByPath`] entity was bound to a new node at its old node's path.
+#[derive(EntityEvent, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HandleRebound {
+    pub entity: Entity,
+    /// The freed node.
+    pub old: GodotNodeHandle,
+    /// The node now mirrored into the entity.
+    pub new: GodotNodeHandle,
+}
+
+/// [`RebindByPath`] entities whose node is gone, by the path they wait on.
+#[derive(Resource, Default)]
+pub(crate) struct RebindIndex {
+    waiting: HashMap<String, (Entity, GodotNodeHandle)>,
+}
+
+impl RebindIndex {
+    pub(super) fn wait(&mut self, path: &str, entity: Entity, old: GodotNodeHandle) {
+        self.waiting.insert(path.to_owned(), (entity, old));
+    }
+
+    pub(super) fn take(&mut self, path: &str) -> Option<(Entity, GodotNodeHandle)> {
+        self.waiting.remove(path)
+    }
+
+    pub(super) fn is_empty(&self) -> bool {
+        self.waiting.is_empty()
+    }
+}
+
+/// Note the path of each [`RebindByPath`] entity's node while it is alive: by
+/// the time its removal is processed, the node may be out of the tree or freed.
+pub(super) fn record_rebind_paths(
+    mut bound: Query<
+        (&mut RebindByPath, &GodotNodeHandle),
+        Or<(
+            Added<RebindByPath>,
+            Changed<GodotNodeHandle>,
+            Changed<Name>,
+            Changed<GodotChildOf>,
+        )>,
+    >,
+    mut godot: GodotAccess,
+) {
+    for (mut rebind, handle) in &mut bound {
+        if let Some(node) = godot.try_get::<Node>(*handle)
+            && node.is_inside_tree()
+        {
+            rebind.path = Some(node.get_path().to_string());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use godot::obj::InstanceId;
+
+    #[test]
+    fn waiting_entities_are_taken_once() {
+        let mut index = RebindIndex::default();
+        let entity = Entity::from_raw_u32(3).unwrap();
+        let old = GodotNodeHandle::from_instance_id(InstanceId::from_i64(7));
+        index.wait("/root/Level/HUD/Score", entity, old);
+
+        assert!(index.take("/root/Level/HUD/Lives").is_none());
+        assert_eq!(index.take("/root/Level/HUD/Score"), Some((entity, old)));
+        assert!(index.is_empty());
+    }
+}
@@ -8,8 +8,16 @@ use bevy_ecs::component::Component;
 use bevy_ecs::entity::Entity;
 use bevy_ecs::lifecycle::HookContext;
 use bevy_ecs::prelude::ReflectComponent;
-use bevy_ecs::world::DeferredWorld;
+use bevy_ecs::system::EntityCommands;
+use bevy_ecs::world::{DeferredWorld, EntityWorldMut};
 use bevy_reflect::Reflect;
+use godot::classes::Node;
+use godot::meta::ToGodot;
+use godot::obj::Gd;
+use tracing::warn;
+
+use crate::interop::GodotNodeHandle;
+use crate::plugins::deferred_calls::defer_call;
 
 /// Marks an entity as a child of a Godot node parent in the scene tree.
 ///
@@ -129,3 +137,49 @@ fn godot_children_on_despawn(mut world: DeferredWorld, context: HookContext) {
         commands.entity(entity).try_despawn();
     }
 }
+
+/// Move an entity's node under another entity's node from a system.
+///
+/// Reparents made anywhere else (GDScript, the editor, `Node::reparent` from Rust)
+/// are picked up from the scene tree watcher on the next `First`; this is for
+/// when the ECS side must agree right away.
+pub trait GodotReparentExt {
+    /// Reparent this entity's node under `new_parent`'s node, keeping its global
+    /// transform. The Godot call is deferred to the [`DeferredFlush`] point (the
+    /// end of the frame by default), since the tree may be locked mid-frame;
+    /// [`GodotChildOf`] is updated immediately.
+    ///
+    /// [`DeferredFlush`]: crate::plugins::deferred_calls::DeferredFlush
+    fn reparent_godot(&mut self, new_parent: Entity) -> &mut Self;
+}
+
+impl GodotReparentExt for EntityCommands<'_> {
+    fn reparent_godot(&mut self, new_parent: Entity) -> &mut Self {
+        self.queue(move |mut entity: EntityWorldMut| {
+            let child = entity.get::<GodotNodeHandle>().copied();
+            let parent = entity.world().get::<GodotNodeHandle>(new_parent).copied();
+            let (Some(child), Some(parent)) = (child, parent) else {
+                warn!(
+                    "reparent_godot: {} or its new parent {new_parent} has no Godot node",
+                    entity.id()
+                );
+                return;
+            };
+            let (Ok(node), Ok(parent_node)) = (
+                Gd::<Node>::try_from_instance_id(child.instance_id()),
+                Gd::<Node>::try_from_instance_id(parent.instance_id()),
+            ) else {
+                return;
+            };
+            entity.world_scope(|world| {
+                defer_call(
+                    world,
+                    node.upcast(),
+                    "reparent",
+                    &[parent_node.to_variant()],
+                );
+            });
+            entity.insert(GodotChildOf(new_parent));
+        })
+    }
+}
@@ -0,0 +1,209 @@
+//! Tags set on nodes in the editor, as a component.
+//!
+//! A node whose metadata has a [`TAGS_META`] entry (`bevy_tags`) is mirrored
+//! with a [`Tags`] component, so designers can tag nodes and systems can select
+//! them without a marker type per tag. The entry can be an `Array` or
+//! `PackedStringArray` of strings, or a single comma-separated `String`.
+//! [`TagIndex`] lists the entities per tag:
+//!
+//! ```ignore
+//! fn alert_bosses(tags: Res<TagIndex>, mut enemies: Query<&mut Enemy>) {
+//!     for &entity in tags.entities("boss") {
+//!         if let Ok(mut enemy) = enemies.get_mut(entity) {
+//!             enemy.alerted = true;
+//!         }
+//!     }
+//! }
+//!
+//! fn hazards(query: Query<(Entity, &Tags)>) {
+//!     for (entity, tags) in &query {
+//!         if tags.has("hazard") { /* ... */ }
+//!     }
+//! }
+//! ```
+//!
+//! Tags are read once, when the node is mirrored. `Tags` is immutable: insert a
+//! new one (e.g. `tags.clone().with("stunned")`) to change an entity's tags,
+//! which keeps the index current. The node's metadata isn't written back.
+
+use bevy_ecs::component::Component;
+use bevy_ecs::entity::Entity;
+use bevy_ecs::lifecycle::HookContext;
+use bevy_ecs::prelude::{ReflectComponent, Resource};
+use bevy_ecs::world::{DeferredWorld, World};
+use bevy_platform::collections::HashMap;
+use bevy_reflect::Reflect;
+use godot::builtin::{PackedStringArray, VarArray, Variant, VariantType};
+use godot::classes::Node;
+use godot::obj::Gd;
+use tracing::warn;
+
+/// The node metadata key tags are read from. Without a leading underscore, so
+/// the entry shows in the editor's inspector.
+pub const TAGS_META: &str = "bevy_tags";
+
+/// Tags of an entity, read from its node's [`TAGS_META`] metadata.
+/// See the [module docs](self).
+#[derive(Component, Debug, Clone, Default, PartialEq, Eq, Reflect)]
+#[component(immutable)]
+#[reflect(Component)]
+pub struct Tags(Vec<String>);
+
+impl Tags {
+    /// Tags from a list, dropping duplicates and empty strings.
+    pub fn new<T: Into<String>>(tags: impl IntoIterator<Item = T>) -> Self {
+        tags.into_iter()
+            .fold(Self::default(), |tags, tag| tags.with(tag))
+    }
+
+    pub fn has(&self, tag: &str) -> bool {
+        self.0.iter().any(|own| own == tag)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &str> {
+        self.0.iter().map(String::as_str)
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// These tags plus `tag`.
+    pub fn with(mut self, tag: impl Into<String>) -> Self {
+        let tag = tag.into();
+        if !tag.is_empty() && !self.has(&tag) {
+            self.0.push(tag);
+        }
+        self
+    }
+
+    /// These tags without `tag`.
+    pub fn without(mut self, tag: &str) -> Self {
+        self.0.retain(|own| own != tag);
+        self
+    }
+
+    /// The node's tags, if its metadata has a [`TAGS_META`] entry.
+    pub(crate) fn from_node(node: &Gd<Node>) -> Option<Self> {
+        node.has_meta(TAGS_META)
+            .then(|| Self::from_meta(&node.get_meta(TAGS_META), node))
+    }
+
+    fn from_meta(value: &Variant, node: &Gd<Node>) -> Self {
+        match value.get_type() {
+            VariantType::STRING | VariantType::STRING_NAME => Self::parse(&value.to_string()),
+            VariantType::PACKED_STRING_ARRAY => Self::new(
+                value
+                    .to::<PackedStringArray>()
+                    .as_slice()
+                    .iter()
+                    .map(|tag| tag.to_string()),
+            ),
+            VariantType::ARRAY => Self::new(
+                value
+                    .to::<VarArray>()
+                    .iter_shared()
+                    .map(|tag| tag.to_string()),
+            ),
+            other => {
+                warn!(
+                    "{TAGS_META} on {} is a {other:?}; expected an array of strings or a string",
+                    node.get_path()
+                );
+                Self::default()
+            }
+        }
+    }
+
+    /// Tags from a comma-separated string.
+    fn parse(text: &str) -> Self {
+        Self::new(text.split(',').map(str::trim))
+    }
+}
+
+/// The entities carrying each tag, in the order they were tagged. Kept current
+/// by [`Tags`]' hooks.
+#[derive(Resource, Debug, Default)]
+pub struct TagIndex {
+    entities: HashMap<String, Vec<Entity>>,
+}
+
+impl TagIndex {
+    /// The entities tagged `tag`.
+    pub fn entities(&self, tag: &str) -> &[Entity] {
+        self.entities.get(tag).map_or(&[], Vec::as_slice)
+    }
+
+    /// Every tag some entity carries.
+    pub fn tags(&self) -> impl Iterator<Item = &str> {
+        self.entities.keys().map(String::as_str)
+    }
+}
+
+pub(super) fn register_tag_hooks(world: &mut World) {
+    world
+        .register_component_hooks::<Tags>()
+        .on_insert(on_tags_insert)
+        .on_discard(on_tags_discard);
+}
+
+fn on_tags_insert(mut world: DeferredWorld, ctx: HookContext) {
+    let Some(tags) = world.get::<Tags>(ctx.entity).cloned() else {
+        return;
+    };
+    let mut index = world.resource_mut::<TagIndex>();
+    for tag in tags.0 {
+        index.entities.entry(tag).or_default().push(ctx.entity);
+    }
+}
+
+fn on_tags_discard(mut world: DeferredWorld, ctx: HookContext) {
+    let Some(tags) = world.get::<Tags>(ctx.entity).cloned() else {
+        return;
+    };
+    let mut index = world.resource_mut::<TagIndex>();
+    for tag in tags.iter() {
+        if let Some(entities) = index.entities.get_mut(tag) {
+            entities.retain(|entity| *entity != ctx.entity);
+            if entities.is_empty() {
+                index.entities.remove(tag);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn comma_separated_tags_are_trimmed_and_deduplicated() {
+        let tags = Tags::parse(" enemy, boss,,enemy ");
+        assert_eq!(tags.iter().collect::<Vec<_>>(), ["enemy", "boss"]);
+        assert!(tags.has("boss") && !tags.has(""));
+    }
+
+    #[test]
+    fn index_follows_inserts_replacements_and_despawns() {
+        let mut world = World::new();
+        world.init_resource::<TagIndex>();
+        register_tag_hooks(&mut world);
+
+        let boss = world.spawn(Tags::new(["enemy", "boss"])).id();
+        let grunt = world.spawn(Tags::new(["enemy"])).id();
+        let index = world.resource::<TagIndex>();
+        assert_eq!(index.entities("enemy"), [boss, grunt]);
+        assert_eq!(index.entities("boss"), [boss]);
+
+        let calmed = world.get::<Tags>(boss).unwrap().clone().without("boss");
+        world.entity_mut(boss).insert(calmed);
+        assert!(world.resource::<TagIndex>().entities("boss").is_empty());
+
+        world.despawn(grunt);
+        assert_eq!(world.resource::<TagIndex>().entities("enemy"), [boss]);
+    }
+}
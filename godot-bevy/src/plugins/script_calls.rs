@@ -0,0 +1,180 @@
+//! Call script methods on nodes from systems and get Rust values back.
+//!
+//! [`GodotCall`] does the `Variant` juggling: arguments go in as variants, the
+//! result comes out converted to the type asked for, and a missing method, a
+//! freed node or a result of the wrong type is an error instead of a panic or a
+//! silent `null`:
+//!
+//! ```ignore
+//! fn read_score(mut calls: GodotCall, hud: Query<&GodotNodeHandle, With<Hud>>) {
+//!     for handle in &hud {
+//!         match calls.call_typed::<i64>(*handle, "get_score", &[]) {
+//!             Ok(score) => info!("score: {score}"),
+//!             Err(err) => warn!("{err}"),
+//!         }
+//!     }
+//! }
+//! ```
+//!
+//! A method that `await`s returns before its work is done. [`GodotCall::call_async`]
+//! waits for it to finish and triggers a [`ScriptCallCompleted`] with its return
+//! value on the next `First`:
+//!
+//! ```ignore
+//! fn open_chest(mut calls: GodotCall, chest: Single<&GodotNodeHandle, With<Chest>>) {
+//!     // func play_open() -> int: await $Anim.animation_finished; return 3
+//!     calls.call_async::<i64>(*chest, "play_open", &[]);
+//! }
+//!
+//! app.add_observer(|done: On<ScriptCallCompleted<i64>>| {
+//!     info!("{:?}: {:?}", done.id, done.result);
+//! });
+//! ```
+//!
+//! If the node is freed while the method is suspended, Godot drops the coroutine
+//! and no event is triggered.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use bevy_ecs::event::Event;
+use bevy_ecs::system::{Res, SystemParam};
+use godot::builtin::{Variant, VariantType};
+use godot::classes::object::ConnectFlags;
+use godot::classes::{Node, Object};
+use godot::meta::FromGodot;
+use godot::obj::Gd;
+use godot::prelude::Callable;
+use thiserror::Error;
+
+use crate::interop::{GodotAccess, GodotNodeHandle};
+use crate::plugins::event_bridge::GodotEventSender;
+
+static NEXT_CALL_ID: AtomicU64 = AtomicU64::new(0);
+
+/// Identifies an async call; carried by its [`ScriptCallCompleted`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ScriptCallId(u64);
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum ScriptCallError {
+    #[error("node {0:?} has been freed")]
+    NodeFreed(GodotNodeHandle),
+    #[error("node has no method `{0}`")]
+    MissingMethod(String),
+    /// The method returned something that doesn't convert to the type asked for.
+    #[error("`{method}` returned {got:?}, expected {expected}")]
+    Conversion {
+        method: String,
+        expected: &'static str,
+        got: VariantType,
+    },
+}
+
+/// Triggered when a method started with [`GodotCall::call_async`] returns.
+#[derive(Event, Debug, Clone)]
+pub struct ScriptCallCompleted<R: Clone + Send + Sync + 'static> {
+    pub id: ScriptCallId,
+    pub result: Result<R, ScriptCallError>,
+}
+
+/// Call script methods on nodes with typed results. See the [module docs](self).
+#[derive(SystemParam)]
+pub struct GodotCall<'w> {
+    godot: GodotAccess<'w>,
+    sender: Res<'w, GodotEventSender>,
+}
+
+impl GodotCall<'_> {
+    /// Call `method` on the node and convert what it returns to `R`. A method
+    /// that `await`s returns its coroutine state here, which only converts to an
+    /// object type; use [`call_async`](Self::call_async) for those.
+    pub fn call_typed<R: FromGodot>(
+        &mut self,
+        handle: GodotNodeHandle,
+        method: &str,
+        args: &[Variant],
+    ) -> Result<R, ScriptCallError> {
+        let value = self.call_variant(handle, method, args)?;
+        convert(method, &value)
+    }
+
+    /// Call `method` on the node now, and trigger a [`ScriptCallCompleted<R>`]
+    /// on the next `First` once it has returned, waiting out any `await` in it.
+    /// Errors, including a failed call, arrive the same way.
+    pub fn call_async<R>(
+        &mut self,
+        handle: GodotNodeHandle,
+        method: &str,
+        args: &[Variant],
+    ) -> ScriptCallId
+    where
+        R: FromGodot + Clone + Send + Sync + 'static,
+    {
+        let id = ScriptCallId(NEXT_CALL_ID.fetch_add(1, Ordering::Relaxed));
+        let value = match self.call_variant(handle, method, args) {
+            Ok(value) => value,
+            Err(err) => {
+                self.sender.send(ScriptCallCompleted::<R> {
+                    id,
+                    result: Err(err),
+                });
+                return id;
+            }
+        };
+
+        let Some(mut state) = coroutine_state(&value) else {
+            self.sender.send(ScriptCallCompleted {
+                id,
+                result: convert::<R>(method, &value),
+            });
+            return id;
+        };
+        let tx = self.sender.clone();
+        let method = method.to_owned();
+        let on_completed = Callable::from_fn("script_call_completed", move |args| {
+            let value = args.first().copied().cloned().unwrap_or_default();
+            tx.send(ScriptCallCompleted {
+                id,
+                result: convert::<R>(&method, &value),
+            });
+            Variant::nil()
+        });
+        state.connect_flags("completed", &on_completed, ConnectFlags::ONE_SHOT);
+        id
+    }
+
+    fn call_variant(
+        &mut self,
+        handle: GodotNodeHandle,
+        method: &str,
+        args: &[Variant],
+    ) -> Result<Variant, ScriptCallError> {
+        let mut node = self
+            .godot
+            .try_get::<Node>(handle)
+            .ok_or(ScriptCallError::NodeFreed(handle))?;
+        if !node.has_method(method) {
+            return Err(ScriptCallError::MissingMethod(method.to_owned()));
+        }
+        Ok(node.call(method, args))
+    }
+}
+
+fn convert<R: FromGodot>(method: &str, value: &Variant) -> Result<R, ScriptCallError> {
+    value
+        .try_to::<R>()
+        .map_err(|_| ScriptCallError::Conversion {
+            method: method.to_owned(),
+            expected: std::any::type_name::<R>(),
+            got: value.get_type(),
+        })
+}
+
+/// The `GDScriptFunctionState` a suspended GDScript method returns, whose
+/// `completed` signal carries the method's eventual return value.
+fn coroutine_state(value: &Variant) -> Option<Gd<Object>> {
+    value
+        .try_to::<Gd<Object>>()
+        .ok()
+        .filter(|object| object.is_class("GDScriptFunctionState"))
+}
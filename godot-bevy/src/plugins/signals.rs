@@ -1,20 +1,28 @@
 use crate::interop::{GodotAccess, GodotNodeHandle};
+use crate::plugins::core::{GodotSyncSet, SyncStats};
+use crate::plugins::determinism::StableOrder;
+use crate::plugins::event_bridge::EventBridgeSet;
 use bevy_app::{App, First, Last, Plugin};
 use bevy_ecs::{
-    component::Component,
+    component::{Component, Components},
     entity::Entity,
     event::Event,
     prelude::Resource,
-    system::{Commands, Query, Res, SystemParam},
+    schedule::IntoScheduleConfigs,
+    system::{Commands, NonSendMut, Query, Res, ResMut, SystemName, SystemParam},
+    world::EntityRef,
 };
+use bevy_utils::prelude::DebugName;
 use crossbeam_channel::Sender;
 use godot::{
-    classes::Node,
-    obj::Gd,
+    classes::{Node, Object, object::ConnectFlags},
+    global::Error,
+    obj::{Gd, Inherits, InstanceId},
     prelude::{Callable, Variant},
 };
 use parking_lot::Mutex;
-use std::fmt::Debug;
+use std::any::TypeId;
+use std::fmt::{self, Debug, Display};
 use std::sync::Arc;
 use tracing::error;
 
@@ -59,7 +67,7 @@ struct PendingSignalConnections {
 }
 
 trait PendingSignalConnection: Send {
-    fn connect(self: Box<Self>, godot: &mut GodotAccess);
+    fn connect(self: Box<Self>, godot: &mut GodotAccess, scoped: &mut ScopedSignalConnections);
 }
 
 impl PendingSignalConnections {
@@ -75,26 +83,222 @@ impl PendingSignalConnections {
 fn ensure_signal_connection_queue(app: &mut App) {
     if !app.world().contains_resource::<PendingSignalConnections>() {
         app.init_resource::<PendingSignalConnections>()
+            .init_non_send::<ScopedSignalConnections>()
             // Process pending connections at end of frame so connections made
             // during Update are applied same-frame (ready for next frame's signals)
-            .add_systems(Last, process_pending_signal_connections);
+            .add_systems(
+                Last,
+                (
+                    disconnect_out_of_scope_signals,
+                    process_pending_signal_connections,
+                )
+                    .chain(),
+            );
     }
 }
 
 fn process_pending_signal_connections(
     pending: Res<PendingSignalConnections>,
+    mut scoped: NonSendMut<ScopedSignalConnections>,
+    mut stats: Option<ResMut<SyncStats>>,
     mut godot: GodotAccess,
 ) {
+    crate::profile_scope!("process_pending_signal_connections");
     for connection in pending.drain() {
-        connection.connect(&mut godot);
+        connection.connect(&mut godot, &mut scoped);
+        if let Some(stats) = stats.as_mut() {
+            stats.deferred_commands += 1;
+        }
+    }
+}
+
+/// How long a connection made with [`GodotSignals::connect_with`] or
+/// [`GodotSignals::connect_object_with`] lasts. The default is as long as the
+/// object emitting the signal.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SignalConnectOptions {
+    one_shot: bool,
+    scope: Option<ConnectionScope>,
+}
+
+impl SignalConnectOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Disconnect after the signal's first emission.
+    pub fn one_shot(mut self) -> Self {
+        self.one_shot = true;
+        self
+    }
+
+    /// Disconnect when `entity` is despawned.
+    pub fn scoped_to(mut self, entity: Entity) -> Self {
+        self.scope = Some(ConnectionScope {
+            owner: entity,
+            marker: None,
+        });
+        self
+    }
+
+    /// Disconnect when `entity` is despawned or no longer has a `C`.
+    pub fn scoped_while<C: Component>(mut self, entity: Entity) -> Self {
+        self.scope = Some(ConnectionScope {
+            owner: entity,
+            marker: Some(TypeId::of::<C>()),
+        });
+        self
+    }
+
+    fn flags(&self) -> ConnectFlags {
+        if self.one_shot {
+            ConnectFlags::ONE_SHOT
+        } else {
+            ConnectFlags::default()
+        }
+    }
+}
+
+/// The entity, and optionally its component, that a connection lives as long as.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct ConnectionScope {
+    owner: Entity,
+    marker: Option<TypeId>,
+}
+
+impl ConnectionScope {
+    /// Whether `owner`, the scope's entity if it still exists, keeps the
+    /// connection alive.
+    fn holds(&self, owner: Option<EntityRef>, components: &Components) -> bool {
+        let Some(owner) = owner else {
+            return false;
+        };
+        self.marker.is_none_or(|marker| {
+            components
+                .get_id(marker)
+                .is_some_and(|id| owner.contains_id(id))
+        })
+    }
+}
+
+/// Connections made with a [`ConnectionScope`], to disconnect when it ends.
+#[derive(Default)]
+struct ScopedSignalConnections(Vec<ScopedConnection>);
+
+struct ScopedConnection {
+    scope: ConnectionScope,
+    object: InstanceId,
+    signal_name: String,
+    callable: Callable,
+}
+
+impl ScopedSignalConnections {
+    fn track(
+        &mut self,
+        options: SignalConnectOptions,
+        object: InstanceId,
+        signal_name: &str,
+        callable: Callable,
+    ) {
+        if let Some(scope) = options.scope {
+            self.0.push(ScopedConnection {
+                scope,
+                object,
+                signal_name: signal_name.to_owned(),
+                callable,
+            });
+        }
+    }
+}
+
+/// Disconnect scoped connections whose entity was despawned or lost its marker,
+/// and forget those whose object was freed.
+fn disconnect_out_of_scope_signals(
+    mut scoped: NonSendMut<ScopedSignalConnections>,
+    owners: Query<EntityRef>,
+    components: &Components,
+) {
+    if scoped.0.is_empty() {
+        return;
+    }
+    scoped.0.retain(|connection| {
+        let Ok(mut object) = Gd::<Object>::try_from_instance_id(connection.object) else {
+            return false;
+        };
+        let owner = owners.get(connection.scope.owner).ok();
+        if connection.scope.holds(owner, components) {
+            return true;
+        }
+        let signal_name = connection.signal_name.as_str();
+        // A one-shot connection may already be gone.
+        if object.is_connected(signal_name, &connection.callable) {
+            object.disconnect(signal_name, &connection.callable);
+        }
+        false
+    });
+}
+
+/// Where a queued connection came from. Connections are applied in `Last`, after
+/// the requesting system has returned, so a failure is logged with this instead.
+#[derive(Clone, Debug)]
+struct ConnectionOrigin {
+    system: Option<DebugName>,
+    entity: Option<Entity>,
+}
+
+impl Display for ConnectionOrigin {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.system {
+            Some(system) => write!(f, "requested by system `{system}`")?,
+            None => write!(f, "requested by a GodotScene")?,
+        }
+        if let Some(entity) = self.entity {
+            write!(f, " for entity {entity}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Connect `callable`, logging a missing signal or a failed `connect` together
+/// with the origin of the request rather than leaving it to Godot's error output.
+/// Returns whether the connection was made.
+fn connect_callable<O: Inherits<Object>>(
+    object: &mut Gd<O>,
+    signal_name: &str,
+    callable: &Callable,
+    flags: ConnectFlags,
+    origin: &ConnectionOrigin,
+) -> bool {
+    let instance_id = object.instance_id();
+    let object = object.upcast_mut::<Object>();
+    if !object.has_signal(signal_name) {
+        error!(
+            "Failed to connect signal '{signal_name}': {} {} has no such signal ({origin})",
+            object.get_class(),
+            instance_id,
+        );
+        return false;
     }
+    let result = object.connect_flags(signal_name, callable, flags);
+    if result != Error::OK {
+        error!(
+            "Failed to connect signal '{signal_name}' on {} {}: {result:?} ({origin})",
+            object.get_class(),
+            instance_id,
+        );
+        return false;
+    }
+    true
 }
 
+#[allow(clippy::too_many_arguments)]
 fn connect_signal<T>(
     godot: &mut GodotAccess,
+    scoped: &mut ScopedSignalConnections,
     node: GodotNodeHandle,
     signal_name: &str,
-    source_entity: Option<Entity>,
+    options: SignalConnectOptions,
+    origin: ConnectionOrigin,
     mapper: Box<
         dyn FnMut(&[Variant], GodotNodeHandle, Option<Entity>) -> Option<T> + Send + 'static,
     >,
@@ -103,7 +307,15 @@ fn connect_signal<T>(
     T: Event + Clone + Send + 'static,
     for<'a> T::Trigger<'a>: Default,
 {
-    let mut node_ref = godot.get::<Node>(node);
+    let Some(mut node_ref) = godot.try_get::<Node>(node) else {
+        error!(
+            "Failed to connect signal '{signal_name}': node {} was freed before the \
+             connection was applied ({origin})",
+            node.instance_id()
+        );
+        return;
+    };
+    let source_entity = origin.entity;
     let signal_name_copy = signal_name.to_string();
     let source_node_handle = node;
     let mut mapper = mapper;
@@ -119,7 +331,15 @@ fn connect_signal<T>(
     };
 
     let callable = Callable::from_fn(format!("signal_handler_{signal_name_copy}"), closure);
-    node_ref.connect(signal_name, &callable);
+    if connect_callable(
+        &mut node_ref,
+        signal_name,
+        &callable,
+        options.flags(),
+        &origin,
+    ) {
+        scoped.track(options, node.instance_id(), signal_name, callable);
+    }
 }
 
 /// Plugin to enable Godot signal to Bevy observer routing for event type `T`.
@@ -190,23 +410,35 @@ where
             app.world_mut()
                 .insert_resource(SignalReceiver::new(receiver));
 
-            // Drain signals and trigger observers
-            app.add_systems(First, drain_and_trigger_signals);
+            // Drain signals and trigger observers, always ahead of bridged events
+            app.add_systems(
+                First,
+                drain_and_trigger_signals
+                    .in_set(GodotSyncSet::SignalPump)
+                    .before(EventBridgeSet::Drain),
+            );
         }
 
         // Per-T deferred connection processor
-        app.add_systems(First, process_deferred_signal_connections::<T>);
+        app.add_systems(
+            First,
+            process_deferred_signal_connections::<T>.in_set(GodotSyncSet::SignalPump),
+        );
     }
 }
 
 /// Exclusive system to drain signal queue and trigger observers
 fn drain_and_trigger_signals(world: &mut bevy_ecs::world::World) {
+    crate::profile_scope!("drain_and_trigger_signals");
     // Collect first to avoid overlapping mutable borrows of `world`
     let mut pending: Vec<Box<dyn SignalDispatch>> = Vec::new();
     if let Some(receiver) = world.get_resource::<SignalReceiver>() {
         let guard = receiver.0.lock();
         pending.extend(guard.try_iter());
     }
+    if let Some(mut stats) = world.get_resource_mut::<SyncStats>() {
+        stats.signals_forwarded += pending.len() as u32;
+    }
     for dispatch in pending.drain(..) {
         dispatch.trigger_in_world(world);
     }
@@ -241,6 +473,7 @@ where
 {
     sender: Res<'w, SignalSender>,
     pending: Res<'w, PendingSignalConnections>,
+    system: SystemName,
     _marker: std::marker::PhantomData<T>,
 }
 
@@ -268,11 +501,50 @@ where
         mapper: F,
     ) where
         F: FnMut(&[Variant], GodotNodeHandle, Option<Entity>) -> Option<T> + Send + 'static,
+    {
+        self.connect_with(
+            node,
+            signal_name,
+            source_entity,
+            SignalConnectOptions::default(),
+            mapper,
+        );
+    }
+
+    /// Like [`connect`](Self::connect), but the connection can be one-shot, or
+    /// torn down with the entity interested in it:
+    ///
+    /// ```ignore
+    /// // Stop hearing about the door once the quest marker is gone.
+    /// signals.connect_with(
+    ///     door,
+    ///     "opened",
+    ///     Some(quest),
+    ///     SignalConnectOptions::new().scoped_while::<ActiveQuest>(quest),
+    ///     |_, _, quest| Some(DoorOpened { quest: quest? }),
+    /// );
+    /// ```
+    ///
+    /// Scoped connections are checked in `Last`, so a signal emitted in the frame
+    /// the scope ends may still arrive.
+    pub fn connect_with<F>(
+        &self,
+        node: GodotNodeHandle,
+        signal_name: &str,
+        source_entity: Option<Entity>,
+        options: SignalConnectOptions,
+        mapper: F,
+    ) where
+        F: FnMut(&[Variant], GodotNodeHandle, Option<Entity>) -> Option<T> + Send + 'static,
     {
         self.pending.push(Box::new(PendingSignalConnectionImpl {
             node,
             signal_name: signal_name.to_string(),
-            source_entity,
+            options,
+            origin: ConnectionOrigin {
+                system: Some(self.system.name()),
+                entity: source_entity,
+            },
             mapper: Box::new(mapper),
             sender: self.sender.0.clone(),
             _marker: std::marker::PhantomData,
@@ -317,10 +589,32 @@ where
     where
         O: godot::obj::Inherits<godot::classes::Object> + godot::obj::GodotClass,
         F: FnMut(&[Variant]) -> Option<T> + Send + 'static,
+    {
+        self.connect_object_with(object, signal_name, SignalConnectOptions::default(), mapper);
+    }
+
+    /// Like [`connect_object`](Self::connect_object), with the options of
+    /// [`connect_with`](Self::connect_with). Scoping matters most here: the
+    /// `SceneTree` and other long-lived objects outlive the entities listening
+    /// to them.
+    pub fn connect_object_with<O, F>(
+        &self,
+        object: Gd<O>,
+        signal_name: &str,
+        options: SignalConnectOptions,
+        mapper: F,
+    ) where
+        O: godot::obj::Inherits<godot::classes::Object> + godot::obj::GodotClass,
+        F: FnMut(&[Variant]) -> Option<T> + Send + 'static,
     {
         self.pending.push(Box::new(PendingDirectNodeConnection {
             instance_id: object.instance_id(),
             signal_name: signal_name.to_string(),
+            options,
+            origin: ConnectionOrigin {
+                system: Some(self.system.name()),
+                entity: None,
+            },
             mapper: Box::new(mapper),
             sender: self.sender.0.clone(),
             _marker: std::marker::PhantomData,
@@ -339,7 +633,8 @@ where
 {
     node: GodotNodeHandle,
     signal_name: String,
-    source_entity: Option<Entity>,
+    options: SignalConnectOptions,
+    origin: ConnectionOrigin,
     mapper:
         Box<dyn FnMut(&[Variant], GodotNodeHandle, Option<Entity>) -> Option<T> + Send + 'static>,
     sender: Sender<Box<dyn SignalDispatch>>,
@@ -351,16 +646,26 @@ where
     T: Event + Clone + Send + 'static,
     for<'a> T::Trigger<'a>: Default,
 {
-    fn connect(self: Box<Self>, godot: &mut GodotAccess) {
+    fn connect(self: Box<Self>, godot: &mut GodotAccess, scoped: &mut ScopedSignalConnections) {
         let PendingSignalConnectionImpl {
             node,
             signal_name,
-            source_entity,
+            options,
+            origin,
             mapper,
             sender,
             _marker: _,
         } = *self;
-        connect_signal(godot, node, &signal_name, source_entity, mapper, sender);
+        connect_signal(
+            godot,
+            scoped,
+            node,
+            &signal_name,
+            options,
+            origin,
+            mapper,
+            sender,
+        );
     }
 }
 
@@ -372,6 +677,8 @@ where
 {
     instance_id: godot::obj::InstanceId,
     signal_name: String,
+    options: SignalConnectOptions,
+    origin: ConnectionOrigin,
     mapper: Box<dyn FnMut(&[Variant]) -> Option<T> + Send + 'static>,
     sender: Sender<Box<dyn SignalDispatch>>,
     _marker: std::marker::PhantomData<T>,
@@ -382,21 +689,23 @@ where
     T: Event + Clone + Send + 'static,
     for<'a> T::Trigger<'a>: Default,
 {
-    fn connect(self: Box<Self>, _godot: &mut GodotAccess) {
+    fn connect(self: Box<Self>, _godot: &mut GodotAccess, scoped: &mut ScopedSignalConnections) {
         // GodotAccess is unused here: direct object connections resolve the target
         // via InstanceId rather than through GodotAccess node lookups.
         let PendingDirectNodeConnection {
             instance_id,
             signal_name,
+            options,
+            origin,
             mut mapper,
             sender,
             _marker: _,
         } = *self;
 
-        let Ok(mut node) = Gd::<godot::classes::Object>::try_from_instance_id(instance_id) else {
+        let Ok(mut node) = Gd::<Object>::try_from_instance_id(instance_id) else {
             error!(
-                "Failed to get object with instance_id {:?} for signal connection",
-                instance_id
+                "Failed to connect signal '{signal_name}': object {instance_id} was freed \
+                 before the connection was applied ({origin})"
             );
             return;
         };
@@ -412,7 +721,9 @@ where
         };
 
         let callable = Callable::from_fn(format!("signal_handler_{signal_name_copy}"), closure);
-        node.connect(&signal_name, &callable);
+        if connect_callable(&mut node, &signal_name, &callable, options.flags(), &origin) {
+            scoped.track(options, instance_id, &signal_name, callable);
+        }
     }
 }
 
@@ -420,12 +731,20 @@ where
 fn process_deferred_signal_connections<T>(
     mut commands: Commands,
     mut query: Query<(Entity, &GodotNodeHandle, &mut DeferredSignalConnections<T>)>,
+    stable_order: Option<Res<StableOrder>>,
     signals: GodotSignals<T>,
 ) where
     T: Event + Clone + Send + 'static,
     for<'a> T::Trigger<'a>: Default,
 {
-    for (entity, handle, mut deferred) in query.iter_mut() {
+    crate::profile_scope!("process_deferred_signal_connections");
+    let mut pending: Vec<_> = query.iter_mut().collect();
+    // Godot calls a signal's callables in connection order, so connecting in
+    // entity order rather than archetype order keeps observer order stable.
+    if stable_order.is_some() {
+        pending.sort_by_key(|(entity, ..)| *entity);
+    }
+    for (entity, handle, mut deferred) in pending {
         for conn in deferred.connections.drain(..) {
             let signal = conn.signal_name;
             let mapper = conn.mapper;
@@ -551,9 +870,13 @@ where
     for<'a> T::Trigger<'a>: Default,
 {
     fn connect(&self, root_node: &Gd<Node>, source_entity: Entity, sender: &SignalSender) {
+        let origin = ConnectionOrigin {
+            system: None,
+            entity: Some(source_entity),
+        };
         let Some(mut target_node) = root_node.get_node_or_null(self.node_path.as_str()) else {
             error!(
-                "Failed to find node at path '{}' for signal connection",
+                "Failed to find node at path '{}' for signal connection ({origin})",
                 self.node_path
             );
             return;
@@ -573,10 +896,46 @@ where
                 Variant::nil()
             };
 
-            target_node.connect(
+            let callable = Callable::from_fn(format!("signal_handler_{signal_name}"), closure);
+            connect_callable(
+                &mut target_node,
                 &signal_name,
-                &Callable::from_fn(format!("signal_handler_{signal_name}"), closure),
+                &callable,
+                ConnectFlags::default(),
+                &origin,
             );
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_ecs::world::World;
+
+    #[derive(Component)]
+    struct Listening;
+
+    #[test]
+    fn scope_ends_with_the_entity_or_its_marker() {
+        let mut world = World::new();
+        let entity = world.spawn(Listening).id();
+        let scoped_to = SignalConnectOptions::new().scoped_to(entity).scope.unwrap();
+        let scoped_while = SignalConnectOptions::new()
+            .scoped_while::<Listening>(entity)
+            .scope
+            .unwrap();
+        let holds = |world: &World, scope: ConnectionScope| {
+            scope.holds(world.get_entity(entity).ok(), world.components())
+        };
+        assert!(holds(&world, scoped_to));
+        assert!(holds(&world, scoped_while));
+
+        world.entity_mut(entity).remove::<Listening>();
+        assert!(holds(&world, scoped_to));
+        assert!(!holds(&world, scoped_while));
+
+        world.despawn(entity);
+        assert!(!holds(&world, scoped_to));
+    }
+}
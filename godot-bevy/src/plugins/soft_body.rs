@@ -0,0 +1,315 @@
+//! `SoftBody3D` cloth and jelly from systems: pin points, tune the simulation,
+//! and check where the vertices went.
+//!
+//! With [`GodotSoftBodyPlugin`], every mirrored `SoftBody3D` gets a
+//! [`SoftBodySimulation`] read from the node; editing it writes the node in
+//! `Last`. Insert [`SoftBodyPins`] to take over which points are pinned, and
+//! [`SampleSoftBodyVertices`] to have the vertices' global positions copied into
+//! [`SoftBodyVertices`] every few physics ticks:
+//!
+//! ```ignore
+//! fn hang_banner(
+//!     mut commands: Commands,
+//!     banner: Single<Entity, Added<Banner>>,
+//!     pole: Single<Entity, With<Pole>>,
+//! ) {
+//!     commands.entity(*banner).insert((
+//!         SoftBodyPins(vec![
+//!             SoftBodyPin::attached(0, *pole),
+//!             SoftBodyPin::attached(10, *pole),
+//!         ]),
+//!         SampleSoftBodyVertices::every(4),
+//!     ));
+//! }
+//!
+//! fn cloth_touches_player(
+//!     cloth: Query<&SoftBodyVertices>,
+//!     player: Single<&Transform, With<Player>>,
+//! ) {
+//!     if cloth.iter().any(|vertices| vertices.any_within(player.translation, 0.5)) {
+//!         // ...
+//!     }
+//! }
+//! ```
+//!
+//! [`SoftBodyPins`] is the whole list: points pinned in the editor but missing
+//! from it are released. A pin attached to an entity follows that entity's node;
+//! one without an attachment stays where it is in the world. Point indices are
+//! the mesh's vertex indices, as in the editor.
+
+use bevy_app::{App, FixedPreUpdate, Last, Plugin};
+use bevy_ecs::change_detection::DetectChangesMut;
+use bevy_ecs::component::Component;
+use bevy_ecs::entity::Entity;
+use bevy_ecs::query::{Added, Changed, Without};
+use bevy_ecs::schedule::IntoScheduleConfigs;
+use bevy_ecs::system::{Commands, Query};
+use bevy_math::Vec3;
+use godot::builtin::{NodePath, PackedVector3Array};
+use godot::classes::mesh::ArrayType;
+use godot::classes::{Node, SoftBody3D};
+use godot::obj::{EngineEnum, Gd};
+
+use crate::interop::{GodotAccess, GodotNodeHandle};
+
+/// Plugin bridging soft bodies. See the [module docs](self).
+#[derive(Default)]
+pub struct GodotSoftBodyPlugin;
+
+impl Plugin for GodotSoftBodyPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(FixedPreUpdate, sample_soft_body_vertices)
+            .add_systems(
+                Last,
+                (
+                    insert_soft_body_simulation,
+                    apply_soft_body_simulation,
+                    apply_soft_body_pins,
+                )
+                    .chain(),
+            );
+    }
+}
+
+/// How a `SoftBody3D` is simulated.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct SoftBodySimulation {
+    /// Solver iterations per step; more is stiffer and slower.
+    pub precision: i32,
+    pub total_mass: f32,
+    /// From 0 (floppy) to 1 (stiff).
+    pub linear_stiffness: f32,
+    /// Inflation, for balloons and jelly.
+    pub pressure: f32,
+    pub damping: f32,
+    pub drag: f32,
+}
+
+/// One pinned point of a soft body.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SoftBodyPin {
+    /// Vertex index in the body's mesh.
+    pub point: i32,
+    /// Entity whose node the point moves with; `None` holds it in place.
+    pub attachment: Option<Entity>,
+}
+
+impl SoftBodyPin {
+    pub fn fixed(point: i32) -> Self {
+        Self {
+            point,
+            attachment: None,
+        }
+    }
+
+    pub fn attached(point: i32, to: Entity) -> Self {
+        Self {
+            point,
+            attachment: Some(to),
+        }
+    }
+}
+
+/// The points of a soft body that are pinned. See the [module docs](self).
+#[derive(Component, Debug, Default, Clone, PartialEq, Eq)]
+pub struct SoftBodyPins(pub Vec<SoftBodyPin>);
+
+/// Copy a soft body's vertex positions into [`SoftBodyVertices`] every
+/// `every_ticks` physics ticks.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+#[require(SoftBodyVertices)]
+pub struct SampleSoftBodyVertices {
+    pub every_ticks: u32,
+    ticks: u32,
+}
+
+impl SampleSoftBodyVertices {
+    pub fn every(ticks: u32) -> Self {
+        Self {
+            every_ticks: ticks.max(1),
+            ticks: 0,
+        }
+    }
+
+    /// Whether this tick is a sampling one. The first tick always is.
+    fn tick(&mut self) -> bool {
+        let due = self.ticks == 0;
+        self.ticks = (self.ticks + 1) % self.every_ticks.max(1);
+        due
+    }
+}
+
+/// Global positions of a soft body's vertices, by point index, as of the last
+/// sample.
+#[derive(Component, Debug, Default, Clone, PartialEq)]
+pub struct SoftBodyVertices(pub Vec<Vec3>);
+
+impl SoftBodyVertices {
+    /// Whether any vertex is within `radius` of `point`.
+    pub fn any_within(&self, point: Vec3, radius: f32) -> bool {
+        let radius_squared = radius * radius;
+        self.0
+            .iter()
+            .any(|vertex| vertex.distance_squared(point) <= radius_squared)
+    }
+}
+
+/// The points [`apply_soft_body_pins`] last pinned.
+#[derive(Component)]
+struct AppliedPins(Vec<i32>);
+
+/// Vertices in the body's mesh, which is how many points it has.
+fn point_count(body: &Gd<SoftBody3D>) -> i32 {
+    body.get_mesh()
+        .filter(|mesh| mesh.get_surface_count() > 0)
+        .and_then(|mesh| {
+            mesh.surface_get_arrays(0)
+                .get(ArrayType::VERTEX.ord() as usize)?
+                .try_to::<PackedVector3Array>()
+                .ok()
+        })
+        .map_or(0, |vertices| vertices.len() as i32)
+}
+
+fn insert_soft_body_simulation(
+    added: Query<(Entity, &GodotNodeHandle), (Added<GodotNodeHandle>, Without<SoftBodySimulation>)>,
+    mut commands: Commands,
+    mut godot: GodotAccess,
+) {
+    for (entity, handle) in &added {
+        if let Some(body) = godot.try_get::<SoftBody3D>(*handle) {
+            commands.entity(entity).insert(SoftBodySimulation {
+                precision: body.get_simulation_precision(),
+                total_mass: body.get_total_mass(),
+                linear_stiffness: body.get_linear_stiffness(),
+                pressure: body.get_pressure_coefficient(),
+                damping: body.get_damping_coefficient(),
+                drag: body.get_drag_coefficient(),
+            });
+        }
+    }
+}
+
+fn apply_soft_body_simulation(
+    bodies: Query<(&GodotNodeHandle, &SoftBodySimulation), Changed<SoftBodySimulation>>,
+    mut godot: GodotAccess,
+) {
+    for (handle, simulation) in &bodies {
+        let Some(mut body) = godot.try_get::<SoftBody3D>(*handle) else {
+            continue;
+        };
+        if body.get_simulation_precision() != simulation.precision {
+            body.set_simulation_precision(simulation.precision);
+        }
+        if body.get_total_mass() != simulation.total_mass {
+            body.set_total_mass(simulation.total_mass);
+        }
+        if body.get_linear_stiffness() != simulation.linear_stiffness {
+            body.set_linear_stiffness(simulation.linear_stiffness);
+        }
+        if body.get_pressure_coefficient() != simulation.pressure {
+            body.set_pressure_coefficient(simulation.pressure);
+        }
+        if body.get_damping_coefficient() != simulation.damping {
+            body.set_damping_coefficient(simulation.damping);
+        }
+        if body.get_drag_coefficient() != simulation.drag {
+            body.set_drag_coefficient(simulation.drag);
+        }
+    }
+}
+
+fn apply_soft_body_pins(
+    bodies: Query<
+        (
+            Entity,
+            &GodotNodeHandle,
+            &SoftBodyPins,
+            Option<&AppliedPins>,
+        ),
+        Changed<SoftBodyPins>,
+    >,
+    handles: Query<&GodotNodeHandle>,
+    mut commands: Commands,
+    mut godot: GodotAccess,
+) {
+    for (entity, handle, pins, applied) in &bodies {
+        let Some(mut body) = godot.try_get::<SoftBody3D>(*handle) else {
+            continue;
+        };
+        let pinned = |point: &i32| pins.0.iter().any(|pin| pin.point == *point);
+
+        // The first time, release what the editor pinned; then only our own.
+        let previous = match applied {
+            Some(applied) => applied.0.clone(),
+            None => (0..point_count(&body))
+                .filter(|point| body.is_point_pinned(*point))
+                .collect(),
+        };
+        for point in previous.iter().filter(|point| !pinned(point)) {
+            body.set_point_pinned(*point, false);
+        }
+
+        for pin in &pins.0 {
+            let path = pin
+                .attachment
+                .and_then(|attachment| handles.get(attachment).ok())
+                .and_then(|attachment| godot.try_get::<Node>(*attachment))
+                .map(|attachment| body.get_path_to(&attachment))
+                .unwrap_or_else(NodePath::default);
+            body.set_point_pinned_ex(pin.point, true)
+                .attachment_path(&path)
+                .done();
+        }
+        commands
+            .entity(entity)
+            .insert(AppliedPins(pins.0.iter().map(|pin| pin.point).collect()));
+    }
+}
+
+fn sample_soft_body_vertices(
+    mut bodies: Query<(
+        &GodotNodeHandle,
+        &mut SampleSoftBodyVertices,
+        &mut SoftBodyVertices,
+    )>,
+    mut godot: GodotAccess,
+) {
+    for (handle, mut sampling, mut vertices) in &mut bodies {
+        if !sampling.tick() {
+            continue;
+        }
+        let Some(body) = godot.try_get::<SoftBody3D>(*handle) else {
+            continue;
+        };
+        let sampled = (0..point_count(&body))
+            .map(|point| {
+                let position = body.get_point_transform(point);
+                Vec3::new(position.x, position.y, position.z)
+            })
+            .collect();
+        vertices.set_if_neq(SoftBodyVertices(sampled));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sampling_runs_on_the_first_of_every_n_ticks() {
+        let mut sampling = SampleSoftBodyVertices::every(3);
+        let due: Vec<bool> = (0..7).map(|_| sampling.tick()).collect();
+        assert_eq!(due, [true, false, false, true, false, false, true]);
+
+        let mut every_tick = SampleSoftBodyVertices::every(0);
+        assert!(every_tick.tick() && every_tick.tick());
+    }
+
+    #[test]
+    fn any_within_checks_every_vertex() {
+        let vertices = SoftBodyVertices(vec![Vec3::ZERO, Vec3::new(4.0, 0.0, 0.0)]);
+        assert!(vertices.any_within(Vec3::new(3.5, 0.0, 0.0), 0.6));
+        assert!(!vertices.any_within(Vec3::new(2.0, 0.0, 0.0), 1.0));
+    }
+}
@@ -0,0 +1,432 @@
+//! Local split screen: one `SubViewport` and camera per player, laid out over the
+//! window.
+//!
+//! With [`SplitScreenPlugin`], spawning a [`SplitScreenPlayer`] for a player entity
+//! creates a `SubViewportContainer` with a `SubViewport` showing the main world,
+//! and a `Camera2D`/`Camera3D` inside it that follows the player's node. The views
+//! are laid out by [`SplitScreenSettings`] and re-laid out when the window is
+//! resized or a view is added or removed:
+//!
+//! ```ignore
+//! fn join(mut commands: Commands, players: Query<(Entity, &Player)>) {
+//!     for (entity, player) in &players {
+//!         let view = commands
+//!             .spawn(
+//!                 SplitScreenPlayer::new(entity, player.index)
+//!                     .with_offset(Vec3::new(0.0, 6.0, 8.0))
+//!                     .with_gamepad(player.index as i32),
+//!             )
+//!             .id();
+//!         // Hear the world from the player's position, in their own view.
+//!         commands.entity(entity).insert(AudioListener::in_viewport(view));
+//!     }
+//! }
+//!
+//! fn jump(jumped: On<PlayerGamepadButton>, mut players: Query<&mut Velocity>) {
+//!     // `jumped.entity` is the player whose gamepad was pressed.
+//! }
+//! ```
+//!
+//! The view entity holds the `SubViewport`'s [`GodotNodeHandle`]; despawning it
+//! or removing the component frees the view. A [`SplitScreenPlayer`] with a
+//! gamepad `device` gets that gamepad's button and axis input as
+//! [`PlayerGamepadButton`]/[`PlayerGamepadAxis`] events on its target entity; this
+//! needs `GodotInputEventPlugin`.
+//!
+//! The views are drawn in a `CanvasLayer` at layer 0, over the main viewport.
+//! Keep the HUD in a `CanvasLayer` above it.
+
+use bevy_app::{App, Last, Plugin, PreUpdate};
+use bevy_ecs::change_detection::DetectChanges;
+use bevy_ecs::component::Component;
+use bevy_ecs::entity::Entity;
+use bevy_ecs::event::EntityEvent;
+use bevy_ecs::lifecycle::Remove;
+use bevy_ecs::message::{MessageReader, Messages};
+use bevy_ecs::observer::On;
+use bevy_ecs::prelude::Resource;
+use bevy_ecs::query::{Changed, Without};
+use bevy_ecs::schedule::common_conditions::resource_exists;
+use bevy_ecs::schedule::{IntoScheduleConfigs, SystemCondition};
+use bevy_ecs::system::{Commands, Local, Query, Res};
+use bevy_math::{Rect, Vec2, Vec3};
+use godot::builtin::{Vector2, Vector3};
+use godot::classes::{
+    Camera2D, Camera3D, CanvasLayer, Node, Node2D, Node3D, SubViewport, SubViewportContainer,
+};
+use godot::obj::{Gd, NewAlloc};
+
+use crate::interop::{GodotAccess, GodotNodeHandle};
+use crate::plugins::input::{GamepadAxisInput, GamepadButtonInput};
+use crate::plugins::scene_tree::SceneTreeRef;
+use crate::watchers::scene_tree_watcher::exclude_from_mirror;
+
+const LAYER_NAME: &str = "SplitScreen";
+
+/// Plugin creating and laying out [`SplitScreenPlayer`] views. See the
+/// [module docs](self).
+#[derive(Default)]
+pub struct SplitScreenPlugin;
+
+impl Plugin for SplitScreenPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SplitScreenSettings>()
+            .add_observer(on_split_screen_player_removed)
+            .add_systems(
+                PreUpdate,
+                route_gamepad_input.run_if(
+                    resource_exists::<Messages<GamepadButtonInput>>
+                        .and_then(resource_exists::<Messages<GamepadAxisInput>>),
+                ),
+            )
+            .add_systems(
+                Last,
+                (spawn_split_views, layout_split_views, follow_split_targets).chain(),
+            );
+    }
+}
+
+/// How the views share the window.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SplitScreenLayout {
+    /// Side by side for two players, a grid for more.
+    #[default]
+    Auto,
+    /// One column per player.
+    Horizontal,
+    /// One row per player.
+    Vertical,
+}
+
+impl SplitScreenLayout {
+    /// The rect of each of `views` views in a window of `size`, in slot order,
+    /// with `gap` pixels between neighbours.
+    pub fn rects(self, views: usize, size: Vec2, gap: f32) -> Vec<Rect> {
+        if views == 0 {
+            return Vec::new();
+        }
+        let (columns, rows) = match self {
+            Self::Horizontal => (views, 1),
+            Self::Vertical => (1, views),
+            Self::Auto if views <= 2 => (views, 1),
+            Self::Auto => {
+                let columns = (views as f32).sqrt().ceil() as usize;
+                (columns, views.div_ceil(columns))
+            }
+        };
+        let cell = Vec2::new(
+            (size.x - gap * (columns - 1) as f32) / columns as f32,
+            (size.y - gap * (rows - 1) as f32) / rows as f32,
+        );
+        (0..views)
+            .map(|i| {
+                let min = Vec2::new((i % columns) as f32, (i / columns) as f32) * (cell + gap);
+                Rect::from_corners(min, min + cell)
+            })
+            .collect()
+    }
+}
+
+/// Layout of the split-screen views.
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct SplitScreenSettings {
+    pub layout: SplitScreenLayout,
+    /// Pixels between neighbouring views.
+    pub gap: f32,
+}
+
+impl Default for SplitScreenSettings {
+    fn default() -> Self {
+        Self {
+            layout: SplitScreenLayout::Auto,
+            gap: 4.0,
+        }
+    }
+}
+
+/// A split-screen view following `target`, a player entity with a 2D or 3D node.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct SplitScreenPlayer {
+    pub target: Entity,
+    /// Position among the views: lower slots come first, left to right, then top
+    /// to bottom.
+    pub slot: u32,
+    /// Camera position relative to the target. In 3D a non-zero offset looks at
+    /// the target; zero takes the target's own transform (first person). 2D uses
+    /// `x` and `y`.
+    pub offset: Vec3,
+    /// Gamepad whose input is routed to `target`.
+    pub device: Option<i32>,
+}
+
+impl SplitScreenPlayer {
+    pub fn new(target: Entity, slot: u32) -> Self {
+        Self {
+            target,
+            slot,
+            offset: Vec3::ZERO,
+            device: None,
+        }
+    }
+
+    pub fn with_offset(mut self, offset: Vec3) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    pub fn with_gamepad(mut self, device: i32) -> Self {
+        self.device = Some(device);
+        self
+    }
+}
+
+/// A button on a player's gamepad, triggered on the [`SplitScreenPlayer`]'s target.
+#[derive(EntityEvent, Debug, Clone, Copy, PartialEq)]
+pub struct PlayerGamepadButton {
+    pub entity: Entity,
+    pub button_index: i32,
+    pub pressed: bool,
+    pub pressure: f32,
+}
+
+/// An axis on a player's gamepad, triggered on the [`SplitScreenPlayer`]'s target.
+#[derive(EntityEvent, Debug, Clone, Copy, PartialEq)]
+pub struct PlayerGamepadAxis {
+    pub entity: Entity,
+    pub axis: i32,
+    pub value: f32,
+}
+
+/// The container and camera made for a [`SplitScreenPlayer`].
+#[derive(Component, Debug, Clone, Copy)]
+struct SplitScreenView {
+    container: GodotNodeHandle,
+    camera: GodotNodeHandle,
+}
+
+fn spawn_split_views(
+    added: Query<(Entity, &SplitScreenPlayer), Without<SplitScreenView>>,
+    targets: Query<&GodotNodeHandle>,
+    mut commands: Commands,
+    mut scene_tree: SceneTreeRef,
+    mut godot: GodotAccess,
+) {
+    if added.is_empty() {
+        return;
+    }
+    let Some(mut root) = scene_tree.get().get_root() else {
+        return;
+    };
+    let mut layer = match root.try_get_node_as::<CanvasLayer>(LAYER_NAME) {
+        Some(layer) => layer,
+        None => {
+            let mut layer = CanvasLayer::new_alloc();
+            layer.set_name(LAYER_NAME);
+            exclude_from_mirror(&mut layer);
+            root.add_child(&layer);
+            layer
+        }
+    };
+
+    for (entity, player) in &added {
+        // The target's node may not be mirrored yet; try again next frame.
+        let Some(target) = targets
+            .get(player.target)
+            .ok()
+            .and_then(|handle| godot.try_get::<Node>(*handle))
+        else {
+            continue;
+        };
+
+        // SubViewports share the parent's World3D already; 2D needs it set.
+        let mut viewport = SubViewport::new_alloc();
+        if let Some(world) = root.get_world_2d() {
+            viewport.set_world_2d(&world);
+        }
+        let mut container = SubViewportContainer::new_alloc();
+        container.set_name(&format!("SplitScreenView{entity}"));
+        container.set_stretch(true);
+        container.add_child(&viewport);
+        layer.add_child(&container);
+
+        let camera: Gd<Node> = if target.clone().try_cast::<Node3D>().is_ok() {
+            let mut camera = Camera3D::new_alloc();
+            viewport.add_child(&camera);
+            camera.make_current();
+            camera.upcast()
+        } else {
+            let mut camera = Camera2D::new_alloc();
+            viewport.add_child(&camera);
+            camera.make_current();
+            camera.upcast()
+        };
+
+        commands.entity(entity).insert((
+            GodotNodeHandle::new(viewport),
+            SplitScreenView {
+                container: GodotNodeHandle::new(container),
+                camera: GodotNodeHandle::new(camera),
+            },
+        ));
+    }
+}
+
+fn layout_split_views(
+    views: Query<(&SplitScreenPlayer, &SplitScreenView)>,
+    changed: Query<(), Changed<SplitScreenPlayer>>,
+    settings: Res<SplitScreenSettings>,
+    mut laid_out: Local<Option<(Vec2, usize)>>,
+    mut scene_tree: SceneTreeRef,
+    mut godot: GodotAccess,
+) {
+    let Some(root) = scene_tree.get().get_root() else {
+        return;
+    };
+    let size = root.get_visible_rect().size;
+    let size = Vec2::new(size.x, size.y);
+    let count = views.iter().count();
+    if *laid_out == Some((size, count)) && changed.is_empty() && !settings.is_changed() {
+        return;
+    }
+    *laid_out = Some((size, count));
+
+    let mut ordered: Vec<_> = views.iter().collect();
+    ordered.sort_by_key(|(player, _)| player.slot);
+    let rects = settings.layout.rects(ordered.len(), size, settings.gap);
+    for ((_, view), rect) in ordered.into_iter().zip(rects) {
+        if let Some(mut container) = godot.try_get::<SubViewportContainer>(view.container) {
+            container.set_position(Vector2::new(rect.min.x, rect.min.y));
+            container.set_size(Vector2::new(rect.width(), rect.height()));
+        }
+    }
+}
+
+fn follow_split_targets(
+    views: Query<(&SplitScreenPlayer, &SplitScreenView)>,
+    targets: Query<&GodotNodeHandle>,
+    mut godot: GodotAccess,
+) {
+    for (player, view) in &views {
+        let Ok(target) = targets.get(player.target).copied() else {
+            continue;
+        };
+        let offset = player.offset;
+        if let Some(target) = godot.try_get::<Node3D>(target) {
+            let Some(mut camera) = godot.try_get::<Camera3D>(view.camera) else {
+                continue;
+            };
+            let transform = target.get_global_transform();
+            if offset == Vec3::ZERO {
+                camera.set_global_transform(transform);
+            } else {
+                camera.set_global_position(
+                    transform.origin + Vector3::new(offset.x, offset.y, offset.z),
+                );
+                camera.look_at(transform.origin);
+            }
+        } else if let Some(target) = godot.try_get::<Node2D>(target)
+            && let Some(mut camera) = godot.try_get::<Camera2D>(view.camera)
+        {
+            camera.set_global_position(
+                target.get_global_position() + Vector2::new(offset.x, offset.y),
+            );
+        }
+    }
+}
+
+fn route_gamepad_input(
+    mut buttons: MessageReader<GamepadButtonInput>,
+    mut axes: MessageReader<GamepadAxisInput>,
+    players: Query<&SplitScreenPlayer>,
+    mut commands: Commands,
+) {
+    let player_for = |device: i32| {
+        players
+            .iter()
+            .find(|player| player.device == Some(device))
+            .map(|player| player.target)
+    };
+    for input in buttons.read() {
+        if let Some(entity) = player_for(input.device) {
+            commands.trigger(PlayerGamepadButton {
+                entity,
+                button_index: input.button_index,
+                pressed: input.pressed,
+                pressure: input.pressure,
+            });
+        }
+    }
+    for input in axes.read() {
+        if let Some(entity) = player_for(input.device) {
+            commands.trigger(PlayerGamepadAxis {
+                entity,
+                axis: input.axis,
+                value: input.value,
+            });
+        }
+    }
+}
+
+/// Freeing the container frees the viewport and camera inside it.
+fn on_split_screen_player_removed(
+    removed: On<Remove, SplitScreenPlayer>,
+    views: Query<&SplitScreenView>,
+    mut commands: Commands,
+    mut godot: GodotAccess,
+) {
+    let Ok(view) = views.get(removed.entity) else {
+        return;
+    };
+    if let Some(mut container) = godot.try_get::<Node>(view.container) {
+        container.queue_free();
+    }
+    if let Ok(mut entity) = commands.get_entity(removed.entity) {
+        entity.remove::<(GodotNodeHandle, SplitScreenView)>();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rect(x: f32, y: f32, w: f32, h: f32) -> Rect {
+        Rect::from_corners(Vec2::new(x, y), Vec2::new(x + w, y + h))
+    }
+
+    #[test]
+    fn two_views_split_side_by_side() {
+        let size = Vec2::new(1284.0, 720.0);
+        let rects = SplitScreenLayout::Auto.rects(2, size, 4.0);
+        assert_eq!(
+            rects,
+            [rect(0.0, 0.0, 640.0, 720.0), rect(644.0, 0.0, 640.0, 720.0)]
+        );
+        assert_eq!(
+            SplitScreenLayout::Vertical.rects(2, Vec2::new(1280.0, 724.0), 4.0),
+            [
+                rect(0.0, 0.0, 1280.0, 360.0),
+                rect(0.0, 364.0, 1280.0, 360.0)
+            ]
+        );
+    }
+
+    #[test]
+    fn more_views_fill_a_grid() {
+        let rects = SplitScreenLayout::Auto.rects(3, Vec2::new(1280.0, 720.0), 0.0);
+        assert_eq!(
+            rects,
+            [
+                rect(0.0, 0.0, 640.0, 360.0),
+                rect(640.0, 0.0, 640.0, 360.0),
+                rect(0.0, 360.0, 640.0, 360.0),
+            ]
+        );
+        assert_eq!(
+            SplitScreenLayout::Horizontal
+                .rects(1, Vec2::new(800.0, 600.0), 4.0)
+                .len(),
+            1
+        );
+        assert!(SplitScreenLayout::Auto.rects(0, Vec2::ONE, 0.0).is_empty());
+    }
+}
@@ -0,0 +1,303 @@
+//! The project's `Theme` as a typed resource, for UI built from Rust.
+//!
+//! With [`GodotThemePlugin`], the theme's colors, constants, font sizes and fonts
+//! are read into the [`UiStyle`] resource, so procedurally created controls can
+//! match the rest of the UI instead of hardcoding values:
+//!
+//! ```ignore
+//! app.add_plugins(GodotThemePlugin::new("res://ui/theme.tres"));
+//!
+//! fn style_label(style: Res<UiStyle>, mut label: Gd<Label>) {
+//!     if let Some(color) = style.color("Label", "font_color") {
+//!         label.add_theme_color_override("font_color", color);
+//!     }
+//!     let margin = style.constant("MarginContainer", "margin_left").unwrap_or(8);
+//! }
+//!
+//! app.add_observer(|_: On<UiStyleChanged>, style: Res<UiStyle>| {
+//!     // restyle what was built from the old values
+//! });
+//! ```
+//!
+//! Lookups follow type variations: an item missing from `"HeaderLabel"` is looked
+//! up in its base type, `"Label"`. Without a path the plugin reads the project's
+//! custom theme (`gui/theme/custom`), or Godot's default theme when there is none.
+//!
+//! A theme loaded from a path comes through the asset server, so with Bevy's file
+//! watching on, saving the theme rebuilds [`UiStyle`] and triggers
+//! [`UiStyleChanged`]. Needs `GodotAssetsPlugin`.
+
+use std::collections::HashMap;
+
+use bevy_app::{App, Plugin, Startup, Update};
+use bevy_asset::{AssetEvent, AssetServer, Assets, Handle};
+use bevy_ecs::event::Event;
+use bevy_ecs::message::MessageReader;
+use bevy_ecs::prelude::Resource;
+use bevy_ecs::system::{Commands, Res, ResMut};
+use godot::builtin::{Color, StringName};
+use godot::classes::{ProjectSettings, Theme, ThemeDb};
+use godot::obj::Gd;
+use tracing::warn;
+
+use crate::interop::{GodotAccess, GodotResourceHandle};
+use crate::plugins::assets::GodotResource;
+
+/// Plugin reading a `Theme` into [`UiStyle`]. See the [module docs](self).
+#[derive(Default, Debug, Clone)]
+pub struct GodotThemePlugin {
+    /// The theme to read; `None` reads the project's theme.
+    pub path: Option<String>,
+}
+
+impl GodotThemePlugin {
+    pub fn new(path: impl Into<String>) -> Self {
+        Self {
+            path: Some(path.into()),
+        }
+    }
+}
+
+impl Plugin for GodotThemePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<UiStyle>()
+            .insert_resource(ThemeSource {
+                path: self.path.clone(),
+                handle: None,
+            })
+            .add_systems(Startup, load_theme)
+            .add_systems(Update, reload_theme);
+    }
+}
+
+/// The items of one theme type (`"Button"`, `"Label"`, or a type variation).
+#[derive(Debug, Clone, Default)]
+pub struct ThemeTypeStyle {
+    /// The type this one is a variation of, looked up for missing items.
+    pub variation_base: Option<String>,
+    pub colors: HashMap<String, Color>,
+    pub constants: HashMap<String, i32>,
+    pub font_sizes: HashMap<String, i32>,
+    pub fonts: HashMap<String, GodotResourceHandle>,
+}
+
+/// The values of a Godot `Theme`, by theme type and item name. See the
+/// [module docs](self).
+#[derive(Resource, Debug, Clone, Default)]
+pub struct UiStyle {
+    pub default_font: Option<GodotResourceHandle>,
+    pub default_font_size: Option<i32>,
+    pub default_base_scale: Option<f32>,
+    pub types: HashMap<String, ThemeTypeStyle>,
+}
+
+/// [`UiStyle`] was rebuilt from a changed theme.
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UiStyleChanged;
+
+impl UiStyle {
+    /// Read every type and item of `theme`.
+    pub fn from_theme(theme: &Gd<Theme>) -> Self {
+        let mut types = HashMap::new();
+        for theme_type in theme.get_type_list().as_slice() {
+            let ty = StringName::from(theme_type);
+            let base = theme.get_type_variation_base(&ty);
+            let mut style = ThemeTypeStyle {
+                variation_base: (!base.is_empty()).then(|| base.to_string()),
+                ..ThemeTypeStyle::default()
+            };
+            for name in theme.get_color_list(theme_type).as_slice() {
+                let item = StringName::from(name);
+                style
+                    .colors
+                    .insert(name.to_string(), theme.get_color(&item, &ty));
+            }
+            for name in theme.get_constant_list(theme_type).as_slice() {
+                let item = StringName::from(name);
+                style
+                    .constants
+                    .insert(name.to_string(), theme.get_constant(&item, &ty));
+            }
+            for name in theme.get_font_size_list(theme_type).as_slice() {
+                let item = StringName::from(name);
+                style
+                    .font_sizes
+                    .insert(name.to_string(), theme.get_font_size(&item, &ty));
+            }
+            for name in theme.get_font_list(theme_type).as_slice() {
+                let item = StringName::from(name);
+                if let Some(font) = theme.get_font(&item, &ty) {
+                    style
+                        .fonts
+                        .insert(name.to_string(), GodotResourceHandle::new(font.upcast()));
+                }
+            }
+            types.insert(theme_type.to_string(), style);
+        }
+
+        Self {
+            default_font: theme
+                .get_default_font()
+                .map(|font| GodotResourceHandle::new(font.upcast())),
+            default_font_size: theme
+                .has_default_font_size()
+                .then(|| theme.get_default_font_size()),
+            default_base_scale: theme
+                .has_default_base_scale()
+                .then(|| theme.get_default_base_scale()),
+            types,
+        }
+    }
+
+    pub fn theme_type(&self, theme_type: &str) -> Option<&ThemeTypeStyle> {
+        self.types.get(theme_type)
+    }
+
+    pub fn color(&self, theme_type: &str, name: &str) -> Option<Color> {
+        self.lookup(theme_type, |style| style.colors.get(name).copied())
+    }
+
+    pub fn constant(&self, theme_type: &str, name: &str) -> Option<i32> {
+        self.lookup(theme_type, |style| style.constants.get(name).copied())
+    }
+
+    pub fn font_size(&self, theme_type: &str, name: &str) -> Option<i32> {
+        self.lookup(theme_type, |style| style.font_sizes.get(name).copied())
+            .or(self.default_font_size)
+    }
+
+    pub fn font(&self, theme_type: &str, name: &str) -> Option<GodotResourceHandle> {
+        self.lookup(theme_type, |style| style.fonts.get(name).cloned())
+            .or_else(|| self.default_font.clone())
+    }
+
+    /// `get` on `theme_type`, then on its variation bases.
+    fn lookup<T>(&self, theme_type: &str, get: impl Fn(&ThemeTypeStyle) -> Option<T>) -> Option<T> {
+        let mut current = self.types.get(theme_type);
+        // Bounded, in case a theme's variations form a cycle.
+        for _ in 0..=self.types.len() {
+            let style = current?;
+            if let Some(value) = get(style) {
+                return Some(value);
+            }
+            current = self.types.get(style.variation_base.as_deref()?);
+        }
+        None
+    }
+}
+
+#[derive(Resource, Debug)]
+struct ThemeSource {
+    path: Option<String>,
+    handle: Option<Handle<GodotResource>>,
+}
+
+fn load_theme(
+    mut source: ResMut<ThemeSource>,
+    asset_server: Option<Res<AssetServer>>,
+    mut commands: Commands,
+    mut godot: GodotAccess,
+) {
+    let path = source.path.clone().unwrap_or_else(|| {
+        godot
+            .singleton::<ProjectSettings>()
+            .get_setting("gui/theme/custom")
+            .try_to::<String>()
+            .unwrap_or_default()
+    });
+
+    if path.is_empty() {
+        let theme_db = godot.singleton::<ThemeDb>();
+        let theme = theme_db
+            .get_project_theme()
+            .or_else(|| theme_db.get_default_theme());
+        if let Some(theme) = theme {
+            commands.insert_resource(UiStyle::from_theme(&theme));
+        }
+        return;
+    }
+
+    let Some(asset_server) = asset_server else {
+        warn!("GodotThemePlugin: loading {path} needs GodotAssetsPlugin");
+        return;
+    };
+    source.handle = Some(asset_server.load(path));
+}
+
+fn reload_theme(
+    mut events: MessageReader<AssetEvent<GodotResource>>,
+    source: Res<ThemeSource>,
+    assets: Option<ResMut<Assets<GodotResource>>>,
+    mut commands: Commands,
+) {
+    let (Some(handle), Some(mut assets)) = (&source.handle, assets) else {
+        return;
+    };
+    let mut changed = false;
+    for event in events.read() {
+        match event {
+            AssetEvent::LoadedWithDependencies { id } | AssetEvent::Modified { id }
+                if *id == handle.id() =>
+            {
+                changed = true;
+            }
+            _ => {}
+        }
+    }
+    if !changed {
+        return;
+    }
+
+    match assets
+        .get_mut(handle)
+        .and_then(|mut r| r.try_cast::<Theme>())
+    {
+        Some(theme) => {
+            commands.insert_resource(UiStyle::from_theme(&theme));
+            commands.trigger(UiStyleChanged);
+        }
+        None => warn!("GodotThemePlugin: {:?} is not a Theme", source.path),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lookups_follow_type_variations() {
+        let mut style = UiStyle::default();
+        let mut label = ThemeTypeStyle::default();
+        label.colors.insert("font_color".into(), Color::WHITE);
+        label.constants.insert("outline_size".into(), 2);
+        let mut header = ThemeTypeStyle {
+            variation_base: Some("Label".into()),
+            ..ThemeTypeStyle::default()
+        };
+        header.colors.insert("font_color".into(), Color::RED);
+        style.types.insert("Label".into(), label);
+        style.types.insert("HeaderLabel".into(), header);
+        style.default_font_size = Some(16);
+
+        assert_eq!(style.color("HeaderLabel", "font_color"), Some(Color::RED));
+        assert_eq!(style.constant("HeaderLabel", "outline_size"), Some(2));
+        assert_eq!(style.constant("HeaderLabel", "line_spacing"), None);
+        assert_eq!(style.font_size("HeaderLabel", "font_size"), Some(16));
+        assert_eq!(style.color("Button", "font_color"), None);
+    }
+
+    #[test]
+    fn cyclic_variations_end() {
+        let mut style = UiStyle::default();
+        for (ty, base) in [("A", "B"), ("B", "A")] {
+            style.types.insert(
+                ty.into(),
+                ThemeTypeStyle {
+                    variation_base: Some(base.into()),
+                    ..ThemeTypeStyle::default()
+                },
+            );
+        }
+        assert_eq!(style.constant("A", "margin"), None);
+    }
+}
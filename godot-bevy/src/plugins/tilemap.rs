@@ -0,0 +1,263 @@
+//! Read and paint `TileMapLayer` cells from systems with [`TileMapAccess`].
+//!
+//! With [`GodotTileMapPlugin`], any entity whose node is a `TileMapLayer` can be
+//! passed to [`TileMapAccess`]. Reads go to the node straight away; writes are
+//! queued and applied together in `Last`, one node lookup per layer, so a level
+//! generator can set thousands of cells in a loop. Reads made before then
+//! already see the queued writes:
+//!
+//! ```ignore
+//! const WALL: TileCell = TileCell::new(0, IVec2::new(1, 0));
+//!
+//! fn generate(mut tiles: TileMapAccess, layer: Single<Entity, With<Walls>>) {
+//!     for x in 0..64 {
+//!         tiles.set_cell(*layer, IVec2::new(x, 0), WALL);
+//!     }
+//! }
+//!
+//! fn dig(mut tiles: TileMapAccess, layer: Single<Entity, With<Walls>>, click: Res<Click>) {
+//!     if let Some(cell) = tiles.world_to_map(*layer, click.position) {
+//!         tiles.erase_cell(*layer, cell);
+//!     }
+//! }
+//! ```
+//!
+//! Each applied batch is reported as a [`TileCellsChanged`] message listing the
+//! cells it touched. Cells painted in the editor or from GDScript aren't reported.
+//!
+//! Needs Godot 4.3 or later, where `TileMapLayer` was introduced.
+
+use bevy_app::{App, Last, Plugin};
+use bevy_ecs::entity::Entity;
+use bevy_ecs::message::{Message, MessageWriter};
+use bevy_ecs::prelude::Resource;
+use bevy_ecs::system::{Query, ResMut, SystemParam};
+use bevy_math::{IVec2, Vec2};
+use bevy_platform::collections::HashMap;
+use godot::builtin::{Vector2, Vector2i};
+use godot::classes::TileMapLayer;
+use godot::obj::Gd;
+use tracing::warn;
+
+use crate::interop::{GodotAccess, GodotNodeHandle};
+
+/// Plugin applying the cell edits queued through [`TileMapAccess`]. See the
+/// [module docs](self).
+#[derive(Default)]
+pub struct GodotTileMapPlugin;
+
+impl Plugin for GodotTileMapPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<TileEdits>()
+            .add_message::<TileCellsChanged>()
+            .add_systems(Last, apply_tile_edits);
+    }
+}
+
+/// The tile in a cell: an atlas source, the tile's coordinates in the atlas, and
+/// an alternative tile (`0` for the base tile).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TileCell {
+    pub source_id: i32,
+    pub atlas_coords: IVec2,
+    pub alternative: i32,
+}
+
+impl TileCell {
+    pub const fn new(source_id: i32, atlas_coords: IVec2) -> Self {
+        Self {
+            source_id,
+            atlas_coords,
+            alternative: 0,
+        }
+    }
+
+    pub const fn with_alternative(mut self, alternative: i32) -> Self {
+        self.alternative = alternative;
+        self
+    }
+}
+
+/// Cells of a `TileMapLayer` entity changed through [`TileMapAccess`].
+#[derive(Message, Debug, Clone, PartialEq, Eq)]
+pub struct TileCellsChanged {
+    pub entity: Entity,
+    pub cells: Vec<IVec2>,
+}
+
+/// Queued writes per layer; `None` erases the cell. Later writes to a cell
+/// replace earlier ones.
+#[derive(Resource, Debug, Default)]
+struct TileEdits(HashMap<Entity, HashMap<IVec2, Option<TileCell>>>);
+
+impl TileEdits {
+    fn queue(&mut self, layer: Entity, coords: IVec2, cell: Option<TileCell>) {
+        self.0.entry(layer).or_default().insert(coords, cell);
+    }
+
+    /// The queued write for a cell: `Some(None)` if it will be erased.
+    fn pending(&self, layer: Entity, coords: IVec2) -> Option<Option<TileCell>> {
+        self.0.get(&layer)?.get(&coords).copied()
+    }
+
+    /// The layer's used cells once its queued writes are applied.
+    fn merge_used(&self, layer: Entity, mut used: Vec<IVec2>) -> Vec<IVec2> {
+        let Some(edits) = self.0.get(&layer) else {
+            return used;
+        };
+        used.retain(|coords| !edits.contains_key(coords));
+        used.extend(
+            edits
+                .iter()
+                .filter(|(_, cell)| cell.is_some())
+                .map(|(coords, _)| *coords),
+        );
+        used
+    }
+}
+
+/// Main-thread access to `TileMapLayer` cells. See the [module docs](self).
+#[derive(SystemParam)]
+pub struct TileMapAccess<'w, 's> {
+    handles: Query<'w, 's, &'static GodotNodeHandle>,
+    edits: ResMut<'w, TileEdits>,
+    godot: GodotAccess<'w>,
+}
+
+impl TileMapAccess<'_, '_> {
+    /// The tile in a cell, or `None` if it is empty or `layer` isn't a
+    /// `TileMapLayer`.
+    pub fn cell(&mut self, layer: Entity, coords: IVec2) -> Option<TileCell> {
+        if let Some(pending) = self.edits.pending(layer, coords) {
+            return pending;
+        }
+        let node = self.layer(layer)?;
+        let coords = to_vector2i(coords);
+        let source_id = node.get_cell_source_id(coords);
+        (source_id != -1).then(|| TileCell {
+            source_id,
+            atlas_coords: from_vector2i(node.get_cell_atlas_coords(coords)),
+            alternative: node.get_cell_alternative_tile(coords),
+        })
+    }
+
+    /// Queue painting a cell.
+    pub fn set_cell(&mut self, layer: Entity, coords: IVec2, cell: TileCell) {
+        self.edits.queue(layer, coords, Some(cell));
+    }
+
+    /// Queue painting many cells.
+    pub fn set_cells(&mut self, layer: Entity, cells: impl IntoIterator<Item = (IVec2, TileCell)>) {
+        for (coords, cell) in cells {
+            self.edits.queue(layer, coords, Some(cell));
+        }
+    }
+
+    /// Queue clearing a cell.
+    pub fn erase_cell(&mut self, layer: Entity, coords: IVec2) {
+        self.edits.queue(layer, coords, None);
+    }
+
+    /// Every non-empty cell, queued writes included, in no particular order.
+    pub fn used_cells(&mut self, layer: Entity) -> Vec<IVec2> {
+        let used = self
+            .layer(layer)
+            .map(|node| {
+                node.get_used_cells()
+                    .iter_shared()
+                    .map(from_vector2i)
+                    .collect()
+            })
+            .unwrap_or_default();
+        self.edits.merge_used(layer, used)
+    }
+
+    /// The cell under a global position.
+    pub fn world_to_map(&mut self, layer: Entity, position: Vec2) -> Option<IVec2> {
+        let node = self.layer(layer)?;
+        let local = node.to_local(Vector2::new(position.x, position.y));
+        Some(from_vector2i(node.local_to_map(local)))
+    }
+
+    /// The global position of a cell's center.
+    pub fn map_to_world(&mut self, layer: Entity, coords: IVec2) -> Option<Vec2> {
+        let node = self.layer(layer)?;
+        let global = node.to_global(node.map_to_local(to_vector2i(coords)));
+        Some(Vec2::new(global.x, global.y))
+    }
+
+    fn layer(&mut self, entity: Entity) -> Option<Gd<TileMapLayer>> {
+        let handle = self.handles.get(entity).ok()?;
+        self.godot.try_get::<TileMapLayer>(*handle)
+    }
+}
+
+fn to_vector2i(coords: IVec2) -> Vector2i {
+    Vector2i::new(coords.x, coords.y)
+}
+
+fn from_vector2i(coords: Vector2i) -> IVec2 {
+    IVec2::new(coords.x, coords.y)
+}
+
+fn apply_tile_edits(
+    mut edits: ResMut<TileEdits>,
+    handles: Query<&GodotNodeHandle>,
+    mut changed: MessageWriter<TileCellsChanged>,
+    mut godot: GodotAccess,
+) {
+    if edits.0.is_empty() {
+        return;
+    }
+    for (entity, cells) in edits.0.drain() {
+        let Some(mut node) = handles
+            .get(entity)
+            .ok()
+            .and_then(|handle| godot.try_get::<TileMapLayer>(*handle))
+        else {
+            warn!("TileMapAccess: dropped edits to {entity}, which isn't a TileMapLayer");
+            continue;
+        };
+        for (coords, cell) in &cells {
+            match cell {
+                Some(cell) => node
+                    .set_cell_ex(to_vector2i(*coords))
+                    .source_id(cell.source_id)
+                    .atlas_coords(to_vector2i(cell.atlas_coords))
+                    .alternative_tile(cell.alternative)
+                    .done(),
+                None => node.erase_cell(to_vector2i(*coords)),
+            }
+        }
+        changed.write(TileCellsChanged {
+            entity,
+            cells: cells.into_keys().collect(),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn queued_edits_shadow_the_layer() {
+        let layer = Entity::from_raw_u32(1).unwrap();
+        let other = Entity::from_raw_u32(2).unwrap();
+        let wall = TileCell::new(0, IVec2::new(1, 0));
+        let mut edits = TileEdits::default();
+
+        edits.queue(layer, IVec2::ZERO, Some(wall));
+        edits.queue(layer, IVec2::X, Some(wall));
+        edits.queue(layer, IVec2::X, None);
+
+        assert_eq!(edits.pending(layer, IVec2::ZERO), Some(Some(wall)));
+        assert_eq!(edits.pending(layer, IVec2::X), Some(None));
+        assert_eq!(edits.pending(layer, IVec2::Y), None);
+        assert_eq!(edits.pending(other, IVec2::ZERO), None);
+
+        let mut used = edits.merge_used(layer, vec![IVec2::X, IVec2::Y]);
+        used.sort_by_key(|coords| (coords.x, coords.y));
+        assert_eq!(used, vec![IVec2::ZERO, IVec2::Y]);
+    }
+}
@@ -0,0 +1,355 @@
+//! `Timer` nodes as components, and engine-backed timers created from systems.
+//!
+//! With [`GodotTimerPlugin`], every mirrored `Timer` node's entity gets a
+//! [`GodotTimer`] holding its `wait_time`, `one_shot` and `paused`, plus the
+//! `time_left` read back each frame. Editing the component writes the node, and
+//! each `timeout` triggers [`TimerFinished`] on the entity. [`TimerCommand`]
+//! starts and stops it:
+//!
+//! ```ignore
+//! fn on_cooldown_done(finished: On<TimerFinished>, mut abilities: Query<&mut Ability>) {
+//!     if let Ok(mut ability) = abilities.get_mut(finished.entity) {
+//!         ability.ready = true;
+//!     }
+//! }
+//! ```
+//!
+//! When GDScript listens for a timer's `timeout`, create a real `Timer` node from
+//! ECS with [`SpawnGodotTimerExt::spawn_godot_timer`]:
+//!
+//! ```ignore
+//! fn start_wave(mut commands: Commands) {
+//!     commands.spawn_godot_timer(
+//!         TimerSpawn::new(30.0).one_shot().named("WaveTimer").in_group("wave_timers"),
+//!     );
+//! }
+//! ```
+//!
+//! The node is added in `Last`, under the current scene unless
+//! [`TimerSpawn::under`] names another parent. Despawning the entity frees it.
+
+use bevy_app::{App, Last, Plugin};
+use bevy_ecs::change_detection::{DetectChanges, DetectChangesMut};
+use bevy_ecs::component::Component;
+use bevy_ecs::entity::Entity;
+use bevy_ecs::event::EntityEvent;
+use bevy_ecs::message::{Message, MessageReader};
+use bevy_ecs::query::{Added, Without};
+use bevy_ecs::schedule::IntoScheduleConfigs;
+use bevy_ecs::system::{Commands, EntityCommands, Query, Res};
+use bevy_ecs::world::Mut;
+use godot::builtin::Variant;
+use godot::classes::{Node, Timer};
+use godot::obj::{Gd, NewAlloc};
+use godot::prelude::Callable;
+use tracing::warn;
+
+use crate::interop::{GodotAccess, GodotNodeHandle};
+use crate::node_tree_view::find_node;
+use crate::plugins::event_bridge::GodotEventSender;
+use crate::plugins::scene_tree::SceneTreeRef;
+
+/// Plugin syncing [`GodotTimer`]s and creating spawned timers. See the
+/// [module docs](self).
+#[derive(Default)]
+pub struct GodotTimerPlugin;
+
+impl Plugin for GodotTimerPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_message::<TimerCommand>().add_systems(
+            Last,
+            (
+                create_spawned_timers,
+                insert_godot_timers,
+                apply_timer_commands,
+                sync_godot_timers,
+            )
+                .chain(),
+        );
+    }
+}
+
+#[cfg(debug_assertions)]
+crate::inventory::submit! {
+    crate::watchdog::PluginRequirement::new::<GodotTimer, GodotTimerPlugin>()
+}
+
+/// The state of the entity's `Timer` node.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct GodotTimer {
+    /// Seconds from start to `timeout`.
+    pub wait_time: f64,
+    pub one_shot: bool,
+    pub paused: bool,
+    time_left: f64,
+    stopped: bool,
+}
+
+impl GodotTimer {
+    /// Seconds until the next `timeout`, as of the end of the last frame.
+    pub fn time_left(&self) -> f64 {
+        self.time_left
+    }
+
+    pub fn is_stopped(&self) -> bool {
+        self.stopped
+    }
+
+    fn read(timer: &Gd<Timer>) -> Self {
+        Self {
+            wait_time: timer.get_wait_time(),
+            one_shot: timer.is_one_shot(),
+            paused: timer.is_paused(),
+            time_left: timer.get_time_left(),
+            stopped: timer.is_stopped(),
+        }
+    }
+
+    /// Write the fields a system can edit, where they differ from the node.
+    fn apply(&self, timer: &mut Gd<Timer>) {
+        if timer.get_wait_time() != self.wait_time {
+            timer.set_wait_time(self.wait_time);
+        }
+        if timer.is_one_shot() != self.one_shot {
+            timer.set_one_shot(self.one_shot);
+        }
+        if timer.is_paused() != self.paused {
+            timer.set_paused(self.paused);
+        }
+    }
+}
+
+/// The entity's `Timer` emitted `timeout`.
+#[derive(EntityEvent, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimerFinished {
+    pub entity: Entity,
+}
+
+/// Start or stop an entity's `Timer`. Applied in `Last`.
+#[derive(Message, Debug, Clone, Copy, PartialEq)]
+pub enum TimerCommand {
+    /// Start from the full `wait_time`, restarting a running timer.
+    Start(Entity),
+    Stop(Entity),
+}
+
+impl TimerCommand {
+    pub fn entity(&self) -> Entity {
+        match self {
+            Self::Start(entity) | Self::Stop(entity) => *entity,
+        }
+    }
+}
+
+/// How to create a timer with [`SpawnGodotTimerExt::spawn_godot_timer`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimerSpawn {
+    pub wait_time: f64,
+    pub one_shot: bool,
+    /// Start as soon as the node is in the tree.
+    pub autostart: bool,
+    pub name: Option<String>,
+    /// Path of the parent node, wildcards allowed. `None` uses the current scene.
+    pub parent: Option<String>,
+    pub groups: Vec<String>,
+}
+
+impl TimerSpawn {
+    /// A repeating timer that starts right away.
+    pub fn new(wait_time: f64) -> Self {
+        Self {
+            wait_time,
+            one_shot: false,
+            autostart: true,
+            name: None,
+            parent: None,
+            groups: Vec::new(),
+        }
+    }
+
+    pub fn one_shot(mut self) -> Self {
+        self.one_shot = true;
+        self
+    }
+
+    /// Wait for a [`TimerCommand::Start`] instead of starting right away.
+    pub fn stopped(mut self) -> Self {
+        self.autostart = false;
+        self
+    }
+
+    /// Node name, for GDScript to find it by path.
+    pub fn named(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    pub fn under(mut self, path: impl Into<String>) -> Self {
+        self.parent = Some(path.into());
+        self
+    }
+
+    pub fn in_group(mut self, group: impl Into<String>) -> Self {
+        self.groups.push(group.into());
+        self
+    }
+}
+
+/// A timer waiting for its node, created in `Last`.
+#[derive(Component, Debug, Clone)]
+struct PendingGodotTimer(TimerSpawn);
+
+/// Create engine-backed timers from systems. See the [module docs](self).
+pub trait SpawnGodotTimerExt {
+    /// Spawn an entity whose [`GodotTimer`] is backed by a new `Timer` node.
+    fn spawn_godot_timer(&mut self, spawn: TimerSpawn) -> EntityCommands<'_>;
+}
+
+impl SpawnGodotTimerExt for Commands<'_, '_> {
+    fn spawn_godot_timer(&mut self, spawn: TimerSpawn) -> EntityCommands<'_> {
+        // Stopped until the node is added and read back.
+        let timer = GodotTimer {
+            wait_time: spawn.wait_time,
+            one_shot: spawn.one_shot,
+            paused: false,
+            time_left: 0.0,
+            stopped: true,
+        };
+        self.spawn((timer, PendingGodotTimer(spawn)))
+    }
+}
+
+fn connect_timeout(timer: &mut Gd<Timer>, entity: Entity, sender: &GodotEventSender) {
+    let tx = sender.clone();
+    timer.connect(
+        "timeout",
+        &Callable::from_fn("timer_finished", move |_| {
+            tx.send(TimerFinished { entity });
+            Variant::nil()
+        }),
+    );
+}
+
+fn create_spawned_timers(
+    pending: Query<(Entity, &PendingGodotTimer), Without<GodotNodeHandle>>,
+    sender: Res<GodotEventSender>,
+    mut commands: Commands,
+    mut scene_tree: SceneTreeRef,
+    _godot: GodotAccess,
+) {
+    for (entity, PendingGodotTimer(spawn)) in &pending {
+        let tree = scene_tree.get();
+        let parent = match &spawn.parent {
+            Some(path) => tree
+                .get_root()
+                .and_then(|root| find_node(&root.upcast(), path)),
+            None => tree.get_current_scene(),
+        };
+        let Some(mut parent) = parent else {
+            warn!(
+                "TimerSpawn: no parent node for {entity} ({:?})",
+                spawn.parent
+            );
+            continue;
+        };
+
+        let mut timer = Timer::new_alloc();
+        match &spawn.name {
+            Some(name) => timer.set_name(name.as_str()),
+            None => timer.set_name(&format!("Timer{entity}")),
+        }
+        timer.set_wait_time(spawn.wait_time);
+        timer.set_one_shot(spawn.one_shot);
+        timer.set_autostart(spawn.autostart);
+        for group in &spawn.groups {
+            timer.add_to_group(group.as_str());
+        }
+        connect_timeout(&mut timer, entity, &sender);
+        parent.add_child(&timer);
+
+        let node: Gd<Node> = timer.upcast();
+        commands
+            .entity(entity)
+            .remove::<PendingGodotTimer>()
+            .insert(GodotNodeHandle::new(node));
+    }
+}
+
+fn insert_godot_timers(
+    added: Query<(Entity, &GodotNodeHandle), (Added<GodotNodeHandle>, Without<GodotTimer>)>,
+    sender: Res<GodotEventSender>,
+    mut commands: Commands,
+    mut godot: GodotAccess,
+) {
+    for (entity, handle) in &added {
+        if let Some(mut timer) = godot.try_get::<Timer>(*handle) {
+            connect_timeout(&mut timer, entity, &sender);
+            commands.entity(entity).insert(GodotTimer::read(&timer));
+        }
+    }
+}
+
+fn apply_timer_commands(
+    mut requests: MessageReader<TimerCommand>,
+    handles: Query<&GodotNodeHandle>,
+    mut godot: GodotAccess,
+) {
+    for request in requests.read() {
+        let Some(mut timer) = handles
+            .get(request.entity())
+            .ok()
+            .and_then(|handle| godot.try_get::<Timer>(*handle))
+        else {
+            warn!("GodotTimerPlugin: {request:?} targets an entity without a Timer node");
+            continue;
+        };
+        match request {
+            TimerCommand::Start(_) => timer.start(),
+            TimerCommand::Stop(_) => timer.stop(),
+        }
+    }
+}
+
+fn sync_godot_timers(
+    mut timers: Query<(Entity, &GodotNodeHandle, Mut<GodotTimer>)>,
+    mut godot: GodotAccess,
+) {
+    for (entity, handle, mut timer) in &mut timers {
+        let Some(mut node) = godot.try_get::<Timer>(*handle) else {
+            if timer.is_added() {
+                warn!("GodotTimer on {entity}, whose node isn't a Timer");
+            }
+            continue;
+        };
+        if timer.is_changed() {
+            timer.apply(&mut node);
+        }
+        // Read back without marking a change, so only system edits are pushed.
+        let timer = timer.bypass_change_detection();
+        timer.time_left = node.get_time_left();
+        timer.stopped = node.is_stopped();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_ecs::world::World;
+
+    #[test]
+    fn spawned_timers_wait_for_their_node() {
+        let mut world = World::new();
+        let entity = world
+            .commands()
+            .spawn_godot_timer(TimerSpawn::new(2.5).one_shot().stopped().named("Wave"))
+            .id();
+        world.flush();
+
+        let timer = world.get::<GodotTimer>(entity).unwrap();
+        assert_eq!(timer.wait_time, 2.5);
+        assert!(timer.one_shot && timer.is_stopped());
+        let PendingGodotTimer(spawn) = world.get::<PendingGodotTimer>(entity).unwrap();
+        assert_eq!(spawn.name.as_deref(), Some("Wave"));
+        assert!(!spawn.autostart);
+    }
+}
@@ -1,18 +1,22 @@
 use bevy_ecs::component::Component;
 use bevy_ecs::prelude::ReflectComponent;
 use bevy_reflect::Reflect;
-use bevy_transform::components::Transform as BevyTransform;
+use bevy_transform::components::{GlobalTransform, Transform as BevyTransform};
 
 /// Per-node transform sync state. The `shadow` is the last value exchanged with
 /// Godot (seeded from the node at registration, then updated by both the read and
 /// the write) -- it's the echo guard, comparing values rather than ticks so it
-/// works read-before-write. `written_once` is set only by the write and gates the
-/// first-write physics-interpolation reset.
+/// works read-before-write. `global_shadow` does the same for `GlobalTransform`
+/// when [`GlobalTransformSync`](super::GlobalTransformSync) is on: the last global
+/// transform read from or written to Godot. `written_once` is set only by the
+/// write and gates the first-write physics-interpolation reset.
 #[derive(Component, Default, Reflect)]
 #[reflect(Component)]
 pub struct TransformSyncMetadata {
     #[reflect(ignore)]
     pub shadow: BevyTransform,
+    #[reflect(ignore)]
+    pub global_shadow: GlobalTransform,
     pub written_once: bool,
 }
 
@@ -24,6 +28,71 @@ pub struct TransformSyncMetadata {
 #[reflect(Component)]
 pub struct DisableGodotTransformRead;
 
+/// Per-entity override of the global [`TransformSyncMode`](super::TransformSyncMode),
+/// so one scene can mix physics-driven nodes (`TwoWay`, reading what Godot
+/// simulated) with ECS-driven ones (`OneWay`) and static decoration (`Disabled`).
+/// Entities without it follow the global mode. Like the global mode, it only
+/// affects the automatic sync; [`DisableGodotTransformRead`] still applies on top.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+#[reflect(Component)]
+pub enum SyncTransform {
+    /// Neither read from nor written to Godot.
+    Disabled,
+    /// Written to Godot when Bevy changes it, never read back.
+    OneWay,
+    /// Read from Godot every physics step and written back on Bevy changes.
+    TwoWay,
+}
+
+impl SyncTransform {
+    /// Whether Godot-side changes are pulled into Bevy.
+    pub fn reads(self) -> bool {
+        self == Self::TwoWay
+    }
+
+    /// Whether Bevy-side changes are pushed to Godot.
+    pub fn writes(self) -> bool {
+        self != Self::Disabled
+    }
+}
+
 /// Godot group whose members are decorated with [`DisableGodotTransformRead`] at spawn
 /// while `GodotTransformSyncPlugin` is active.
 pub const NO_TRANSFORM_READ_GROUP: &str = "godot_bevy_no_transform_read";
+
+/// How far a `Transform` must move from the last value written to Godot before
+/// the Bevy->Godot write fires again. Smaller deltas are held back and
+/// accumulate, so nothing drifts: the node catches up as soon as the total
+/// crosses the threshold, and a change that never does stays Bevy-side only.
+///
+/// The default is exact (any change is written). Set the global threshold on
+/// [`GodotTransformConfig`](super::GodotTransformConfig), or insert this as a
+/// component to override it for one entity -- e.g. zero for the player, a coarse
+/// one for distant props.
+#[derive(Component, Default, Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct SyncThreshold {
+    /// Distance in world units.
+    pub translation: f32,
+    /// Angle in radians.
+    pub rotation: f32,
+    /// Largest change of any scale axis.
+    pub scale: f32,
+}
+
+impl SyncThreshold {
+    pub fn with_translation(mut self, distance: f32) -> Self {
+        self.translation = distance;
+        self
+    }
+
+    pub fn with_rotation(mut self, radians: f32) -> Self {
+        self.rotation = radians;
+        self
+    }
+
+    pub fn with_scale(mut self, delta: f32) -> Self {
+        self.scale = delta;
+        self
+    }
+}
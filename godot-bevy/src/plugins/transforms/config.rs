@@ -1,6 +1,10 @@
 use bevy_ecs::prelude::{ReflectResource, Resource};
 use bevy_reflect::Reflect;
 
+use super::change_filter::SyncThreshold;
+use super::global::GlobalTransformSync;
+use super::quantize::TransformQuantization;
+
 /// Transform synchronization modes
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Reflect)]
 pub enum TransformSyncMode {
@@ -21,6 +25,13 @@ pub enum TransformSyncMode {
 #[reflect(Resource)]
 pub struct GodotTransformConfig {
     pub sync_mode: TransformSyncMode,
+    /// Snap synced transforms to a grid; `None` syncs them as-is.
+    pub quantization: Option<TransformQuantization>,
+    /// Minimum change before a transform is written to Godot, for entities
+    /// without their own [`SyncThreshold`] component.
+    pub threshold: SyncThreshold,
+    /// Sync Bevy's `GlobalTransform` with the node's global transform as well.
+    pub global_transform: GlobalTransformSync,
 }
 
 impl GodotTransformConfig {
@@ -28,6 +39,7 @@ impl GodotTransformConfig {
     pub fn disabled() -> Self {
         Self {
             sync_mode: TransformSyncMode::Disabled,
+            ..Default::default()
         }
     }
 
@@ -35,6 +47,7 @@ impl GodotTransformConfig {
     pub fn one_way() -> Self {
         Self {
             sync_mode: TransformSyncMode::OneWay,
+            ..Default::default()
         }
     }
 
@@ -42,6 +55,25 @@ impl GodotTransformConfig {
     pub fn two_way() -> Self {
         Self {
             sync_mode: TransformSyncMode::TwoWay,
+            ..Default::default()
         }
     }
+
+    /// Snap synced transforms to `quantization`'s grid in both directions.
+    pub fn with_quantization(mut self, quantization: TransformQuantization) -> Self {
+        self.quantization = Some(quantization);
+        self
+    }
+
+    /// Skip writes smaller than `threshold`. See [`SyncThreshold`].
+    pub fn with_threshold(mut self, threshold: SyncThreshold) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
+    /// Also sync `GlobalTransform`. See [`GlobalTransformSync`].
+    pub fn with_global_transform(mut self, global_transform: GlobalTransformSync) -> Self {
+        self.global_transform = global_transform;
+        self
+    }
 }
@@ -64,6 +64,21 @@ impl IntoBevyTransform for GodotTransform2D {
     }
 }
 
+/// The `Transform` in a `Variant` holding a `Transform3D` or `Transform2D`, as
+/// the GDScript watcher captures a node's transform.
+pub(crate) fn bevy_transform_from_variant(
+    variant: &godot::builtin::Variant,
+) -> Option<BevyTransform> {
+    if let Ok(transform) = variant.try_to::<GodotTransform3D>() {
+        Some(transform.to_bevy_transform())
+    } else {
+        variant
+            .try_to::<GodotTransform2D>()
+            .ok()
+            .map(IntoBevyTransform::to_bevy_transform)
+    }
+}
+
 pub trait IntoGodotTransform {
     fn to_godot_transform(self) -> GodotTransform3D;
 }
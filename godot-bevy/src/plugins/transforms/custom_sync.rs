@@ -6,7 +6,11 @@
 /// change-detection guard and physics-interpolation reset behave identically to
 /// auto sync.
 ///
+/// To change the direction for a few entities while keeping auto sync, insert a
+/// [`SyncTransform`] on them instead.
+///
 /// [`GodotTransformSyncPlugin`]: crate::plugins::transforms::GodotTransformSyncPlugin
+/// [`SyncTransform`]: crate::plugins::transforms::SyncTransform
 ///
 /// # Usage
 ///
@@ -80,10 +84,15 @@ macro_rules! add_transform_sync_systems {
     // Bevy → Godot write, restricted to the filter. Runs in `FixedLast` (physics
     // rate) to match auto sync and Godot's physics-interpolation cadence.
     (@generate_post_system $app:expr, $bevy_to_godot_query:ty) => {
-        $app.add_systems(
-            $crate::bevy_app::FixedLast,
-            $crate::plugins::transforms::sync_systems::post_update_godot_transforms::<$bevy_to_godot_query>,
-        );
+        {
+            use $crate::prelude::bevy_ecs_prelude::IntoScheduleConfigs as _;
+            use $crate::plugins::core::GodotSyncSet;
+            $app.add_systems(
+                $crate::bevy_app::FixedLast,
+                $crate::plugins::transforms::sync_systems::post_update_godot_transforms::<$bevy_to_godot_query>
+                    .in_set(GodotSyncSet::TransformWrite),
+            );
+        }
     };
 
     // Godot → Bevy read, restricted to the filter. Runs every
@@ -117,6 +126,18 @@ pub trait GodotTransformSyncPluginExt {
 
     /// Configure the sync mode while keeping auto sync enabled
     fn with_sync_mode(self, mode: crate::plugins::transforms::TransformSyncMode) -> Self;
+
+    fn with_quantization(
+        self,
+        quantization: crate::plugins::transforms::TransformQuantization,
+    ) -> Self;
+
+    fn with_threshold(self, threshold: crate::plugins::transforms::SyncThreshold) -> Self;
+
+    fn with_global_transform(
+        self,
+        global_transform: crate::plugins::transforms::GlobalTransformSync,
+    ) -> Self;
 }
 
 impl GodotTransformSyncPluginExt for crate::plugins::transforms::GodotTransformSyncPlugin {
@@ -129,6 +150,27 @@ impl GodotTransformSyncPluginExt for crate::plugins::transforms::GodotTransformS
         self.sync_mode = mode;
         self
     }
+
+    fn with_quantization(
+        mut self,
+        quantization: crate::plugins::transforms::TransformQuantization,
+    ) -> Self {
+        self.quantization = Some(quantization);
+        self
+    }
+
+    fn with_threshold(mut self, threshold: crate::plugins::transforms::SyncThreshold) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
+    fn with_global_transform(
+        mut self,
+        global_transform: crate::plugins::transforms::GlobalTransformSync,
+    ) -> Self {
+        self.global_transform = global_transform;
+        self
+    }
 }
 
 // Re-export the macro at the crate level
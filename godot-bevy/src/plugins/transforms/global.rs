@@ -0,0 +1,165 @@
+use bevy_ecs::change_detection::{DetectChangesMut, Ref};
+use bevy_ecs::query::{AnyOf, Changed};
+use bevy_ecs::system::{Query, Res, ResMut};
+use bevy_reflect::Reflect;
+use bevy_transform::components::{GlobalTransform, Transform as BevyTransform};
+use godot::classes::{Node2D, Node3D};
+
+use crate::interop::node_markers::{Node2DMarker, Node3DMarker};
+use crate::interop::{GodotAccess, GodotNodeHandle};
+use crate::plugins::core::SyncStats;
+
+use super::change_filter::{SyncTransform, TransformSyncMetadata};
+use super::config::GodotTransformConfig;
+use super::conversions::{IntoBevyTransform, IntoGodotTransform, IntoGodotTransform2D};
+
+/// Whether Bevy's [`GlobalTransform`] mirrors the node's `global_transform`.
+///
+/// Only local transforms are synced by default, and godot-bevy mirrors the scene
+/// tree with `GodotChildOf` rather than `ChildOf`, so Bevy's own propagation
+/// never fills `GlobalTransform` in. Turn this on when gameplay needs
+/// world-space positions across the hierarchy.
+///
+/// Precedence:
+/// - The read runs alongside the local read (every physics step, plus the
+///   0-step `PreUpdate` fallback), so `GlobalTransform` is the world-space value
+///   as of the start of the step.
+/// - With [`ReadWrite`](Self::ReadWrite), a `GlobalTransform` changed by your
+///   systems is written after the local `Transform` write in `FixedLast`, so it
+///   wins if both were changed. The node's new local transform is then copied
+///   back into `Transform`.
+/// - Entities with [`SyncTransform::Disabled`] are skipped. Other `SyncTransform`
+///   modes and [`DisableGodotTransformRead`](super::DisableGodotTransformRead)
+///   only concern the local transform.
+/// - Don't add Bevy's `TransformPlugin` as well; its propagation would
+///   overwrite the synced value with the local one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Reflect)]
+pub enum GlobalTransformSync {
+    /// `GlobalTransform` is left alone.
+    #[default]
+    Disabled,
+    /// Godot's global transform is read into `GlobalTransform`.
+    Read,
+    /// Also write `GlobalTransform` changes made in Bevy back to Godot.
+    ReadWrite,
+}
+
+impl GlobalTransformSync {
+    pub fn reads(self) -> bool {
+        self != Self::Disabled
+    }
+
+    pub fn writes(self) -> bool {
+        self == Self::ReadWrite
+    }
+}
+
+/// Godot -> Bevy: copy each node's global transform into `GlobalTransform`, and
+/// into the metadata's `global_shadow` for the write's echo check.
+pub fn read_godot_global_transforms(
+    mut entities: Query<(
+        &mut GlobalTransform,
+        &mut TransformSyncMetadata,
+        &GodotNodeHandle,
+        Option<&SyncTransform>,
+        AnyOf<(&Node2DMarker, &Node3DMarker)>,
+    )>,
+    mut stats: Option<ResMut<SyncStats>>,
+    mut godot: GodotAccess,
+) {
+    crate::profile_scope!("read_godot_global_transforms");
+    let mut read = 0;
+    for (mut global, mut metadata, reference, sync, (node2d, _)) in entities.iter_mut() {
+        if sync == Some(&SyncTransform::Disabled) {
+            continue;
+        }
+        let Some(transform) = godot_global_transform(&mut godot, *reference, node2d.is_some())
+        else {
+            continue;
+        };
+        read += 1;
+        let transform = GlobalTransform::from(transform);
+        metadata.global_shadow = transform;
+        global.set_if_neq(transform);
+    }
+    if let Some(stats) = stats.as_mut() {
+        stats.transforms_read += read;
+    }
+}
+
+/// Bevy -> Godot: push `GlobalTransform` values Bevy changed, then pull the
+/// resulting local transform back so the two components agree.
+pub fn write_godot_global_transforms(
+    mut entities: Query<
+        (
+            Ref<GlobalTransform>,
+            &mut BevyTransform,
+            &mut TransformSyncMetadata,
+            &GodotNodeHandle,
+            Option<&SyncTransform>,
+            AnyOf<(&Node2DMarker, &Node3DMarker)>,
+        ),
+        Changed<GlobalTransform>,
+    >,
+    mut stats: Option<ResMut<SyncStats>>,
+    mut godot: GodotAccess,
+) {
+    crate::profile_scope!("write_godot_global_transforms");
+    let mut written = 0;
+    for (global, mut transform, mut metadata, reference, sync, (node2d, _)) in entities.iter_mut() {
+        if sync == Some(&SyncTransform::Disabled) {
+            continue;
+        }
+        // The read trips `Changed` too; only a value other than the one last
+        // exchanged with Godot is a Bevy-side edit.
+        if *global == metadata.global_shadow {
+            continue;
+        }
+        let is_2d = node2d.is_some();
+        let wanted = global.compute_transform();
+
+        let local = if is_2d {
+            let Some(mut node) = godot.try_get::<Node2D>(*reference) else {
+                continue;
+            };
+            node.set_global_transform(wanted.to_godot_transform_2d());
+            node.get_transform().to_bevy_transform()
+        } else {
+            let Some(mut node) = godot.try_get::<Node3D>(*reference) else {
+                continue;
+            };
+            node.set_global_transform(wanted.to_godot_transform());
+            node.get_transform().to_bevy_transform()
+        };
+        written += 1;
+        // Shadows first, so neither write sees anything new to send.
+        metadata.global_shadow = *global;
+        metadata.shadow = local;
+        transform.set_if_neq(local);
+    }
+    if let Some(stats) = stats.as_mut() {
+        stats.transforms_written += written;
+    }
+}
+
+fn godot_global_transform(
+    godot: &mut GodotAccess,
+    reference: GodotNodeHandle,
+    is_2d: bool,
+) -> Option<BevyTransform> {
+    if is_2d {
+        let node = godot.try_get::<Node2D>(reference)?;
+        Some(node.get_global_transform().to_bevy_transform())
+    } else {
+        let node = godot.try_get::<Node3D>(reference)?;
+        Some(node.get_global_transform().to_bevy_transform())
+    }
+}
+
+pub(super) fn global_transform_read_enabled(config: Res<GodotTransformConfig>) -> bool {
+    config.global_transform.reads()
+}
+
+pub(super) fn global_transform_write_enabled(config: Res<GodotTransformConfig>) -> bool {
+    config.global_transform.writes()
+}
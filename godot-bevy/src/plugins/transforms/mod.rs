@@ -2,18 +2,23 @@ pub mod change_filter;
 pub mod config;
 pub mod conversions;
 pub mod custom_sync;
+pub mod global;
 pub mod math;
 pub mod plugin;
+pub mod quantize;
 pub mod sync_systems;
 
 // Re-export main components and types
 pub use change_filter::{
-    DisableGodotTransformRead, NO_TRANSFORM_READ_GROUP, TransformSyncMetadata,
+    DisableGodotTransformRead, NO_TRANSFORM_READ_GROUP, SyncThreshold, SyncTransform,
+    TransformSyncMetadata,
 };
 pub use config::{GodotTransformConfig, TransformSyncMode};
 pub use conversions::{IntoBevyTransform, IntoGodotTransform, IntoGodotTransform2D};
 pub use custom_sync::{GodotTransformSyncPluginExt, add_transform_sync_systems};
+pub use global::GlobalTransformSync;
 pub use plugin::GodotTransformSyncPlugin;
+pub use quantize::{QuantizedTransform, TransformQuantization};
 
 // Re-export math utilities for advanced users
 pub use math::*;
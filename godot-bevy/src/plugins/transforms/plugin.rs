@@ -1,17 +1,28 @@
 use bevy_app::{App, FixedFirst, FixedLast, Plugin, PreUpdate};
-use bevy_ecs::{query::Without, schedule::IntoScheduleConfigs, system::Res};
+use bevy_ecs::{
+    query::{With, Without},
+    schedule::{IntoScheduleConfigs, SystemCondition},
+    system::{Query, Res},
+};
 use bevy_time::{Time, Virtual};
 use bevy_transform::components::Transform;
 use godot::classes::{Node, Node2D, Node3D};
 
-use crate::plugins::core::AppSceneTreeExt;
+use crate::interop::GodotNode;
+use crate::plugins::core::{AppSceneTreeExt, GodotSyncSet, transform_sync_active};
 use crate::plugins::fixed_schedule::prefix_ran_in_process_fallback;
 use crate::plugins::transforms::IntoBevyTransform;
 use crate::plugins::transforms::{GodotTransformConfig, TransformSyncMode};
 
 use super::change_filter::{
-    DisableGodotTransformRead, NO_TRANSFORM_READ_GROUP, TransformSyncMetadata,
+    DisableGodotTransformRead, NO_TRANSFORM_READ_GROUP, SyncThreshold, SyncTransform,
+    TransformSyncMetadata,
+};
+use super::global::{
+    GlobalTransformSync, global_transform_read_enabled, global_transform_write_enabled,
+    read_godot_global_transforms, write_godot_global_transforms,
 };
+use super::quantize::{QuantizedTransform, TransformQuantization, quantize_transforms};
 use super::sync_systems::{post_update_godot_transforms, pre_update_godot_transforms};
 
 pub struct GodotTransformSyncPlugin {
@@ -23,6 +34,15 @@ pub struct GodotTransformSyncPlugin {
     /// When false, still registers Transform and TransformSyncMetadata components
     /// but allows defining custom sync systems using the add_transform_sync_systems_*! macros.
     pub auto_sync: bool,
+    /// Snap synced transforms to a grid, for deterministic or networked games.
+    /// See [`TransformQuantization`].
+    pub quantization: Option<TransformQuantization>,
+    /// Skip writes to Godot smaller than this. Entities can override it with
+    /// their own [`SyncThreshold`] component.
+    pub threshold: SyncThreshold,
+    /// Also sync Bevy's `GlobalTransform` with the node's global transform.
+    /// See [`GlobalTransformSync`] for how it interacts with the local sync.
+    pub global_transform: GlobalTransformSync,
 }
 
 impl Default for GodotTransformSyncPlugin {
@@ -30,6 +50,9 @@ impl Default for GodotTransformSyncPlugin {
         Self {
             sync_mode: TransformSyncMode::default(),
             auto_sync: true,
+            quantization: None,
+            threshold: SyncThreshold::default(),
+            global_transform: GlobalTransformSync::default(),
         }
     }
 }
@@ -37,11 +60,11 @@ impl Default for GodotTransformSyncPlugin {
 impl Plugin for GodotTransformSyncPlugin {
     fn build(&self, app: &mut App) {
         // Register Transform component with custom initialization that reads from Godot
+        // A new entity gets its Transform in the batch that spawns it, so nothing
+        // sits at the origin for a frame.
         app.register_scene_tree_component_with_init::<Transform, _>(|entity, node| {
-            if let Some(node3d) = node.try_get::<Node3D>() {
-                entity.insert(node3d.get_transform().to_bevy_transform());
-            } else if let Some(node2d) = node.try_get::<Node2D>() {
-                entity.insert(node2d.get_transform().to_bevy_transform());
+            if let Some(transform) = spawn_transform(node) {
+                entity.insert(transform);
             }
         })
         // Seed the shadow from the node at registration so shadow == Transform ==
@@ -49,23 +72,23 @@ impl Plugin for GodotTransformSyncPlugin {
         // a user authoring in Startup/First before the first read, and avoids a
         // spurious frame-1 Changed.
         .register_scene_tree_component_with_init::<TransformSyncMetadata, _>(|entity, node| {
-            let shadow = if let Some(node3d) = node.try_get::<Node3D>() {
-                node3d.get_transform().to_bevy_transform()
-            } else if let Some(node2d) = node.try_get::<Node2D>() {
-                node2d.get_transform().to_bevy_transform()
-            } else {
-                Transform::default()
-            };
+            let shadow = spawn_transform(node).unwrap_or_default();
             entity.insert(TransformSyncMetadata {
                 shadow,
-                written_once: false,
+                ..Default::default()
             });
         });
 
         // Register the transform configuration resource with the plugin's config
         app.insert_resource(GodotTransformConfig {
             sync_mode: self.sync_mode,
-        });
+            quantization: self.quantization,
+            threshold: self.threshold,
+            global_transform: self.global_transform,
+        })
+        .register_type::<QuantizedTransform>()
+        .register_type::<SyncThreshold>()
+        .register_type::<SyncTransform>();
 
         // Only add automatic sync systems if auto_sync is enabled
         if self.auto_sync {
@@ -90,17 +113,31 @@ impl Plugin for GodotTransformSyncPlugin {
             // with zero physics steps (where the prefix is the `_process`
             // fallback), keeping idle frames covered. The value-shadow guard makes
             // any duplicate read idempotent.
+            //
+            // Entities with a `SyncTransform` get their own instances of the
+            // read and write, independent of the global mode; the systems skip
+            // the directions the component turns off.
             app.add_systems(
                 PreUpdate,
-                pre_update_godot_transforms::<Without<DisableGodotTransformRead>>
-                    .run_if(transform_sync_twoway_enabled)
+                (
+                    pre_update_godot_transforms::<GlobalRead>.run_if(transform_sync_twoway_enabled),
+                    pre_update_godot_transforms::<OverriddenRead>.run_if(transform_sync_overridden),
+                    read_godot_global_transforms.run_if(global_transform_read_enabled),
+                )
                     .run_if(prefix_ran_in_process_fallback)
-                    .run_if(transform_read_not_paused),
+                    .run_if(transform_read_not_paused)
+                    .run_if(transform_sync_active)
+                    .in_set(GodotSyncSet::TransformRead),
             );
             app.add_systems(
                 FixedFirst,
-                pre_update_godot_transforms::<Without<DisableGodotTransformRead>>
-                    .run_if(transform_sync_twoway_enabled),
+                (
+                    pre_update_godot_transforms::<GlobalRead>.run_if(transform_sync_twoway_enabled),
+                    pre_update_godot_transforms::<OverriddenRead>.run_if(transform_sync_overridden),
+                    read_godot_global_transforms.run_if(global_transform_read_enabled),
+                )
+                    .run_if(transform_sync_active)
+                    .in_set(GodotSyncSet::TransformRead),
             );
 
             // Bevy -> Godot write at physics rate (once per fixed tick). This is
@@ -109,12 +146,39 @@ impl Plugin for GodotTransformSyncPlugin {
             // physics/common/physics_interpolation.
             app.add_systems(
                 FixedLast,
-                post_update_godot_transforms::<()>.run_if(transform_sync_enabled),
+                (
+                    quantize_transforms
+                        .run_if(transform_sync_enabled.or_else(transform_sync_overridden)),
+                    post_update_godot_transforms::<Without<SyncTransform>>
+                        .run_if(transform_sync_enabled),
+                    post_update_godot_transforms::<With<SyncTransform>>
+                        .run_if(transform_sync_overridden),
+                    write_godot_global_transforms.run_if(global_transform_write_enabled),
+                )
+                    .chain()
+                    .run_if(transform_sync_active)
+                    .in_set(GodotSyncSet::TransformWrite),
             );
         }
     }
 }
 
+type GlobalRead = (Without<DisableGodotTransformRead>, Without<SyncTransform>);
+type OverriddenRead = (Without<DisableGodotTransformRead>, With<SyncTransform>);
+
+/// A new node's local transform, read from a `Node3D`/`Node2D`. The node may have
+/// moved between `node_added` and this frame's mirroring, so the transform captured
+/// with it is only a fallback for when the node can't be read. `None` for other nodes.
+fn spawn_transform(node: &mut GodotNode) -> Option<Transform> {
+    if let Some(node3d) = node.try_get::<Node3D>() {
+        Some(node3d.get_transform().to_bevy_transform())
+    } else if let Some(node2d) = node.try_get::<Node2D>() {
+        Some(node2d.get_transform().to_bevy_transform())
+    } else {
+        node.captured_transform()
+    }
+}
+
 fn transform_sync_enabled(config: Res<GodotTransformConfig>) -> bool {
     // aka one way or two way
     config.sync_mode != TransformSyncMode::Disabled
@@ -129,3 +193,7 @@ fn transform_read_not_paused(time: Option<Res<Time<Virtual>>>) -> bool {
 fn transform_sync_twoway_enabled(config: Res<GodotTransformConfig>) -> bool {
     config.sync_mode == TransformSyncMode::TwoWay
 }
+
+fn transform_sync_overridden(overrides: Query<(), With<SyncTransform>>) -> bool {
+    !overrides.is_empty()
+}
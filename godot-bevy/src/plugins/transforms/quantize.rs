@@ -0,0 +1,270 @@
+use bevy_ecs::component::Component;
+use bevy_ecs::entity::Entity;
+use bevy_ecs::prelude::ReflectComponent;
+use bevy_ecs::query::{Changed, With};
+use bevy_ecs::system::{Commands, Query, Res};
+use bevy_math::{IVec3, IVec4, Quat, Vec3};
+use bevy_reflect::Reflect;
+use bevy_transform::components::Transform as BevyTransform;
+
+use super::change_filter::{SyncTransform, TransformSyncMetadata};
+use super::config::{GodotTransformConfig, TransformSyncMode};
+
+/// Grid that synced transforms are snapped to, for games that need every peer
+/// to agree on positions bit for bit (lockstep, client prediction against an
+/// authoritative server).
+///
+/// With quantization on, the Godot->Bevy read snaps what it pulls from the
+/// node, and the Bevy->Godot write snaps the `Transform` itself before writing
+/// it, so the ECS only ever holds values on the grid. Each synced entity also
+/// carries a [`QuantizedTransform`] with the integer form, ready to replicate.
+///
+/// The defaults are powers of two, which f32 represents exactly.
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+pub struct TransformQuantization {
+    /// Translation step in world units.
+    pub translation_step: f32,
+    /// Step for each quaternion component. Keep it small (the default is
+    /// 1/32768): the snapped quaternion is not renormalized, so that snapping
+    /// stays idempotent.
+    pub rotation_step: f32,
+    /// Scale step.
+    pub scale_step: f32,
+}
+
+impl Default for TransformQuantization {
+    fn default() -> Self {
+        Self {
+            translation_step: 1.0 / 1024.0,
+            rotation_step: 1.0 / 32768.0,
+            scale_step: 1.0 / 1024.0,
+        }
+    }
+}
+
+impl TransformQuantization {
+    pub fn with_translation_step(mut self, step: f32) -> Self {
+        self.translation_step = step;
+        self
+    }
+
+    /// Integer grid coordinates of `transform`. The rotation is taken with a
+    /// non-negative `w`, so `q` and `-q` quantize the same.
+    pub fn quantize(&self, transform: &BevyTransform) -> QuantizedTransform {
+        let rotation = if transform.rotation.w < 0.0 {
+            -transform.rotation
+        } else {
+            transform.rotation
+        };
+        QuantizedTransform {
+            translation: (transform.translation / self.translation_step)
+                .round()
+                .as_ivec3(),
+            rotation: (bevy_math::Vec4::from(rotation) / self.rotation_step)
+                .round()
+                .as_ivec4(),
+            scale: (transform.scale / self.scale_step).round().as_ivec3(),
+        }
+    }
+
+    pub fn dequantize(&self, quantized: &QuantizedTransform) -> BevyTransform {
+        BevyTransform {
+            translation: quantized.translation.as_vec3() * self.translation_step,
+            rotation: Quat::from_vec4(quantized.rotation.as_vec4() * self.rotation_step),
+            scale: quantized.scale.as_vec3() * self.scale_step,
+        }
+    }
+
+    /// `transform` moved onto the grid. Snapping a snapped value is a no-op.
+    pub fn snap(&self, transform: &BevyTransform) -> BevyTransform {
+        self.dequantize(&self.quantize(transform))
+    }
+}
+
+/// Integer form of an entity's `Transform` under the active
+/// [`TransformQuantization`], updated whenever the sync snaps the transform.
+/// Send this instead of the float transform, then rebuild it on the other side
+/// with [`TransformQuantization::dequantize`] so both ends hold the same bits.
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Reflect)]
+#[cfg_attr(feature = "multiplayer", derive(serde::Serialize, serde::Deserialize))]
+#[reflect(Component)]
+pub struct QuantizedTransform {
+    pub translation: IVec3,
+    /// Quaternion `(x, y, z, w)`.
+    pub rotation: IVec4,
+    pub scale: IVec3,
+}
+
+/// Snap changed synced transforms onto the grid and refresh their
+/// [`QuantizedTransform`]. The automatic sync runs it in `FixedLast` just before
+/// the write to Godot; with `add_transform_sync_systems!`, order it before your
+/// `bevy_to_godot` systems yourself.
+pub fn quantize_transforms(
+    config: Res<GodotTransformConfig>,
+    mut entities: Query<
+        (
+            Entity,
+            &mut BevyTransform,
+            Option<&mut QuantizedTransform>,
+            Option<&SyncTransform>,
+        ),
+        (Changed<BevyTransform>, With<TransformSyncMetadata>),
+    >,
+    mut commands: Commands,
+) {
+    crate::profile_scope!("quantize_transforms");
+    let Some(quantization) = config.quantization else {
+        return;
+    };
+    let synced_by_default = config.sync_mode != TransformSyncMode::Disabled;
+    for (entity, mut transform, quantized_transform, sync) in entities.iter_mut() {
+        if !sync.map_or(synced_by_default, |sync| sync.writes()) {
+            continue;
+        }
+        let quantized = quantization.quantize(&transform);
+        let snapped = quantization.dequantize(&quantized);
+        if !same_bits(&snapped, &transform) {
+            *transform = snapped;
+        }
+        match quantized_transform {
+            Some(mut current) => {
+                if *current != quantized {
+                    *current = quantized;
+                }
+            }
+            None => {
+                commands.entity(entity).try_insert(quantized);
+            }
+        }
+    }
+}
+
+fn same_bits(a: &BevyTransform, b: &BevyTransform) -> bool {
+    let bits = |v: Vec3| v.to_array().map(f32::to_bits);
+    bits(a.translation) == bits(b.translation)
+        && bits(a.scale) == bits(b.scale)
+        && a.rotation.to_array().map(f32::to_bits) == b.rotation.to_array().map(f32::to_bits)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> BevyTransform {
+        BevyTransform {
+            translation: Vec3::new(1.2345, -678.9, 0.0001),
+            rotation: Quat::from_euler(bevy_math::EulerRot::XYZ, 0.3, -1.1, 2.0),
+            scale: Vec3::new(1.0, 0.333, 2.5),
+        }
+    }
+
+    #[test]
+    fn snapping_is_idempotent_and_close() {
+        let quantization = TransformQuantization::default();
+        let snapped = quantization.snap(&sample());
+        assert!(same_bits(&quantization.snap(&snapped), &snapped));
+        assert_eq!(
+            quantization.quantize(&snapped),
+            quantization.quantize(&sample())
+        );
+
+        assert!(
+            (snapped.translation - sample().translation)
+                .abs()
+                .max_element()
+                <= quantization.translation_step / 2.0
+        );
+        assert!(snapped.rotation.angle_between(sample().rotation) < 1e-3);
+        assert!((snapped.rotation.length() - 1.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn opposite_quaternions_quantize_the_same() {
+        let quantization = TransformQuantization::default();
+        let mut flipped = sample();
+        flipped.rotation = -flipped.rotation;
+        assert_eq!(
+            quantization.quantize(&flipped),
+            quantization.quantize(&sample())
+        );
+    }
+
+    #[test]
+    fn coarse_translation_step_snaps_to_grid() {
+        let quantization = TransformQuantization::default().with_translation_step(0.5);
+        let snapped = quantization.snap(&BevyTransform::from_xyz(1.3, -0.2, 7.76));
+        assert_eq!(snapped.translation, Vec3::new(1.5, 0.0, 8.0));
+    }
+
+    #[test]
+    fn system_snaps_synced_transforms_and_stores_integers() {
+        use bevy_app::{App, Update};
+
+        let quantization = TransformQuantization::default().with_translation_step(0.5);
+        let mut app = App::new();
+        app.insert_resource(GodotTransformConfig::one_way().with_quantization(quantization))
+            .add_systems(Update, quantize_transforms);
+        let synced = app
+            .world_mut()
+            .spawn((
+                BevyTransform::from_xyz(1.3, 0.0, 0.0),
+                TransformSyncMetadata::default(),
+            ))
+            .id();
+        let unsynced = app
+            .world_mut()
+            .spawn(BevyTransform::from_xyz(1.3, 0.0, 0.0))
+            .id();
+        app.update();
+
+        let world = app.world();
+        assert_eq!(
+            world.get::<BevyTransform>(synced).unwrap().translation.x,
+            1.5
+        );
+        assert_eq!(
+            world.get::<QuantizedTransform>(synced).unwrap().translation,
+            IVec3::new(3, 0, 0)
+        );
+        assert_eq!(
+            world.get::<BevyTransform>(unsynced).unwrap().translation.x,
+            1.3
+        );
+        assert!(world.get::<QuantizedTransform>(unsynced).is_none());
+    }
+
+    #[test]
+    fn per_entity_sync_mode_overrides_the_global_one() {
+        use bevy_app::{App, Update};
+
+        let quantization = TransformQuantization::default().with_translation_step(0.5);
+        let mut app = App::new();
+        app.insert_resource(GodotTransformConfig::disabled().with_quantization(quantization))
+            .add_systems(Update, quantize_transforms);
+        let spawn = |app: &mut App, sync: Option<SyncTransform>| {
+            let mut entity = app.world_mut().spawn((
+                BevyTransform::from_xyz(1.3, 0.0, 0.0),
+                TransformSyncMetadata::default(),
+            ));
+            if let Some(sync) = sync {
+                entity.insert(sync);
+            }
+            entity.id()
+        };
+        let global = spawn(&mut app, None);
+        let one_way = spawn(&mut app, Some(SyncTransform::OneWay));
+        let disabled = spawn(&mut app, Some(SyncTransform::Disabled));
+        app.update();
+
+        let x = |entity| {
+            app.world()
+                .get::<BevyTransform>(entity)
+                .unwrap()
+                .translation
+                .x
+        };
+        assert_eq!(x(global), 1.3, "globally disabled");
+        assert_eq!(x(one_way), 1.5);
+        assert_eq!(x(disabled), 1.3);
+    }
+}
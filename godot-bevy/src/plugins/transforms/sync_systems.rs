@@ -1,16 +1,18 @@
 use crate::interop::node_markers::{Node2DMarker, Node3DMarker};
 use crate::interop::{GodotAccess, GodotNodeHandle};
+use crate::plugins::core::SyncStats;
 use crate::plugins::transforms::{IntoBevyTransform, IntoGodotTransform, IntoGodotTransform2D};
 use bevy_ecs::change_detection::{Mut, Ref};
 use bevy_ecs::entity::Entity;
 use bevy_ecs::query::{AnyOf, Changed, QueryFilter};
-use bevy_ecs::system::Query;
+use bevy_ecs::system::{Query, Res, ResMut};
 use bevy_math::Quat;
 use bevy_transform::components::Transform as BevyTransform;
 use godot::classes::{Engine, Node, Node2D, Node3D, SceneTree};
 use godot::obj::Singleton;
 
-use super::change_filter::TransformSyncMetadata;
+use super::change_filter::{SyncThreshold, SyncTransform, TransformSyncMetadata};
+use super::config::GodotTransformConfig;
 use super::conversions::quats_differ;
 
 // Match the Godot<->Bevy conversion round-trip tolerance (conversions.rs): scale
@@ -65,11 +67,26 @@ pub(crate) fn merge_godot_into_bevy(
 
 // value gate: did Bevy author anything the shadow hasn't seen? same epsilons as
 // the read so a value just pulled from Godot reads back clean -- no echo, no FTI
-// reset.
-pub(crate) fn write_needed(bevy: &BevyTransform, shadow: &BevyTransform) -> bool {
-    bevy.translation != shadow.translation
-        || (bevy.scale - shadow.scale).abs().max_element() > SCALE_EPSILON
-        || rotation_differs(bevy.rotation, shadow.rotation)
+// reset. a nonzero threshold widens the gate; the shadow only advances on an
+// actual write, so held-back deltas add up instead of getting lost.
+pub(crate) fn write_needed(
+    bevy: &BevyTransform,
+    shadow: &BevyTransform,
+    threshold: &SyncThreshold,
+) -> bool {
+    let translated = if threshold.translation > 0.0 {
+        bevy.translation.distance(shadow.translation) > threshold.translation
+    } else {
+        bevy.translation != shadow.translation
+    };
+    let rotated = if threshold.rotation > 0.0 {
+        bevy.rotation.angle_between(shadow.rotation) > threshold.rotation
+    } else {
+        rotation_differs(bevy.rotation, shadow.rotation)
+    };
+    translated
+        || rotated
+        || (bevy.scale - shadow.scale).abs().max_element() > threshold.scale.max(SCALE_EPSILON)
 }
 
 #[tracing::instrument]
@@ -80,13 +97,24 @@ pub fn pre_update_godot_transforms<F: QueryFilter>(
             &mut BevyTransform,
             &GodotNodeHandle,
             &mut TransformSyncMetadata,
+            Option<&SyncTransform>,
             AnyOf<(&Node2DMarker, &Node3DMarker)>,
         ),
         F,
     >,
+    config: Option<Res<GodotTransformConfig>>,
+    mut stats: Option<ResMut<SyncStats>>,
     mut godot: GodotAccess,
 ) {
-    for (_, mut bevy_transform, reference, mut metadata, (node2d, node3d)) in entities.iter_mut() {
+    crate::profile_scope!("pre_update_godot_transforms");
+    let quantization = config.and_then(|config| config.quantization);
+    let mut read = 0;
+    for (_, mut bevy_transform, reference, mut metadata, sync, (node2d, node3d)) in
+        entities.iter_mut()
+    {
+        if sync.is_some_and(|sync| !sync.reads()) {
+            continue;
+        }
         let godot_transform = if node2d.is_some() {
             let Some(node) = godot.try_get::<Node2D>(*reference) else {
                 continue;
@@ -100,9 +128,19 @@ pub fn pre_update_godot_transforms<F: QueryFilter>(
         } else {
             panic!("Expected AnyOf to match either a Node2D or a Node3D, is there a bug in bevy?");
         };
+        // Snap before the shadow sees it, so a node resting between grid points
+        // reads as one stable value.
+        let godot_transform = match quantization {
+            Some(quantization) => quantization.snap(&godot_transform),
+            None => godot_transform,
+        };
 
+        read += 1;
         merge_godot_into_bevy(&mut bevy_transform, &godot_transform, &mut metadata.shadow);
     }
+    if let Some(stats) = stats.as_mut() {
+        stats.transforms_read += read;
+    }
 }
 
 #[tracing::instrument]
@@ -112,18 +150,31 @@ pub fn post_update_godot_transforms<F: QueryFilter>(
             Ref<BevyTransform>,
             &GodotNodeHandle,
             &mut TransformSyncMetadata,
+            Option<&SyncThreshold>,
+            Option<&SyncTransform>,
             AnyOf<(&Node2DMarker, &Node3DMarker)>,
         ),
         (Changed<BevyTransform>, F),
     >,
+    config: Option<Res<GodotTransformConfig>>,
+    mut stats: Option<ResMut<SyncStats>>,
     mut godot: GodotAccess,
 ) {
+    crate::profile_scope!("post_update_godot_transforms");
     // Read once per system run to avoid per-entity FFI.
     let fti_enabled = physics_interpolation_enabled();
+    let mut written = 0;
+    let default_threshold = config.map(|config| config.threshold).unwrap_or_default();
 
-    for (transform_ref, reference, mut metadata, (node2d, node3d)) in entities.iter_mut() {
+    for (transform_ref, reference, mut metadata, threshold, sync, (node2d, node3d)) in
+        entities.iter_mut()
+    {
+        if sync.is_some_and(|sync| !sync.writes()) {
+            continue;
+        }
         // value-skip first: a pure-Godot value never trips an FTI reset
-        if !write_needed(&transform_ref, &metadata.shadow) {
+        let threshold = threshold.unwrap_or(&default_threshold);
+        if !write_needed(&transform_ref, &metadata.shadow, threshold) {
             continue;
         }
 
@@ -143,6 +194,7 @@ pub fn post_update_godot_transforms<F: QueryFilter>(
             obj.set_transform(transform_ref.to_godot_transform());
         }
 
+        written += 1;
         metadata.shadow = *transform_ref;
         if is_first_write {
             metadata.written_once = true;
@@ -151,6 +203,9 @@ pub fn post_update_godot_transforms<F: QueryFilter>(
             }
         }
     }
+    if let Some(stats) = stats.as_mut() {
+        stats.transforms_written += written;
+    }
 }
 
 /// Whether Godot's project-wide physics interpolation is enabled.
@@ -161,3 +216,49 @@ fn physics_interpolation_enabled() -> bool {
         .map(|tree| tree.is_physics_interpolation_enabled())
         .unwrap_or(false)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_math::Vec3;
+
+    #[test]
+    fn default_threshold_writes_any_change() {
+        let shadow = BevyTransform::from_xyz(1.0, 2.0, 3.0);
+        let exact = SyncThreshold::default();
+        assert!(!write_needed(&shadow, &shadow, &exact));
+        assert!(write_needed(
+            &BevyTransform::from_xyz(1.0, 2.0, 3.0001),
+            &shadow,
+            &exact
+        ));
+    }
+
+    #[test]
+    fn small_deltas_are_held_back_until_they_add_up() {
+        let threshold = SyncThreshold::default()
+            .with_translation(0.1)
+            .with_rotation(0.01)
+            .with_scale(0.05);
+        let shadow = BevyTransform::default();
+
+        let nudged = BevyTransform {
+            translation: Vec3::new(0.05, 0.05, 0.0),
+            rotation: Quat::from_rotation_z(0.005),
+            scale: Vec3::splat(1.04),
+        };
+        assert!(!write_needed(&nudged, &shadow, &threshold));
+
+        let mut moved = nudged;
+        moved.translation.x = 0.1;
+        assert!(write_needed(&moved, &shadow, &threshold));
+
+        let mut turned = nudged;
+        turned.rotation = Quat::from_rotation_z(0.02);
+        assert!(write_needed(&turned, &shadow, &threshold));
+
+        let mut grown = nudged;
+        grown.scale.y = 1.06;
+        assert!(write_needed(&grown, &shadow, &threshold));
+    }
+}
@@ -0,0 +1,307 @@
+//! Tween node properties from systems, with easing drawn in the editor.
+//!
+//! Spawn a [`TweenProperty`] entity naming the target entity, a property path
+//! of its node and the values to go between. Each frame [`GodotTweenPlugin`]
+//! writes the eased value with `set_indexed`, so subproperties like
+//! `"modulate:a"` or `"position:y"` work. When the tween completes,
+//! [`TweenFinished`] is triggered on the target and the tween entity is
+//! despawned:
+//!
+//! ```ignore
+//! fn flash(hit: On<Hit>, assets: Res<AssetServer>, mut commands: Commands) {
+//!     commands.spawn(
+//!         TweenProperty::new(hit.entity, "modulate:a", 0.0, 1.0, Duration::from_millis(300))
+//!             .with_easing(TweenEasing::Curve(assets.load("res://curves/flash.tres"))),
+//!     );
+//! }
+//!
+//! app.add_observer(|finished: On<TweenFinished>| info!("{} is done", finished.entity));
+//! ```
+//!
+//! [`TweenEasing::Curve`] samples a Godot `Curve` resource over its 0–1 range,
+//! so designers shape the feel in the curve editor while systems decide when
+//! to play it. Values above 1 or below 0 overshoot the endpoints, for bounces
+//! and anticipation. A tween waits at its start until its curve has loaded.
+
+use std::time::Duration;
+
+use bevy_app::{App, Plugin, Update};
+use bevy_asset::{Assets, Handle};
+use bevy_ecs::component::Component;
+use bevy_ecs::entity::Entity;
+use bevy_ecs::event::EntityEvent;
+use bevy_ecs::system::{Commands, Query, Res, ResMut};
+use bevy_math::{Vec2, Vec3};
+use bevy_time::Time;
+use godot::builtin::{Color, Variant, Vector2, Vector3};
+use godot::classes::{Curve, Node};
+use godot::meta::ToGodot;
+use tracing::warn;
+
+use crate::interop::{GodotAccess, GodotNodeHandle};
+use crate::plugins::assets::GodotResource;
+
+/// Plugin running [`TweenProperty`] entities. See the [module docs](self).
+#[derive(Default)]
+pub struct GodotTweenPlugin;
+
+impl Plugin for GodotTweenPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, advance_tweens);
+    }
+}
+
+/// Animates one property of `target`'s node from `from` to `to`. Spawn it on
+/// its own entity; several can run on the same target at once.
+#[derive(Component, Debug, Clone)]
+pub struct TweenProperty {
+    pub target: Entity,
+    /// Property path on the node, as for `Node.set_indexed`.
+    pub property: String,
+    pub from: TweenValue,
+    pub to: TweenValue,
+    pub duration: Duration,
+    pub easing: TweenEasing,
+    elapsed: Duration,
+}
+
+impl TweenProperty {
+    pub fn new(
+        target: Entity,
+        property: impl Into<String>,
+        from: impl Into<TweenValue>,
+        to: impl Into<TweenValue>,
+        duration: Duration,
+    ) -> Self {
+        Self {
+            target,
+            property: property.into(),
+            from: from.into(),
+            to: to.into(),
+            duration,
+            easing: TweenEasing::Linear,
+            elapsed: Duration::ZERO,
+        }
+    }
+
+    pub fn with_easing(mut self, easing: TweenEasing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Time run so far.
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    /// Fraction of `duration` run after `elapsed`, from 0 to 1.
+    fn progress(&self, elapsed: Duration) -> f32 {
+        if self.duration.is_zero() {
+            1.0
+        } else {
+            (elapsed.as_secs_f32() / self.duration.as_secs_f32()).min(1.0)
+        }
+    }
+}
+
+/// How a tween's progress maps to the distance between its values.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum TweenEasing {
+    #[default]
+    Linear,
+    EaseIn,
+    EaseOut,
+    EaseInOut,
+    /// A Godot `Curve` resource, sampled at the progress from 0 to 1.
+    Curve(Handle<GodotResource>),
+}
+
+impl TweenEasing {
+    /// Ease `progress` with a built-in curve; `None` for [`TweenEasing::Curve`],
+    /// which needs the resource.
+    fn builtin(&self, progress: f32) -> Option<f32> {
+        Some(match self {
+            Self::Linear => progress,
+            Self::EaseIn => progress * progress,
+            Self::EaseOut => 1.0 - (1.0 - progress) * (1.0 - progress),
+            Self::EaseInOut => {
+                if progress < 0.5 {
+                    2.0 * progress * progress
+                } else {
+                    1.0 - (-2.0 * progress + 2.0).powi(2) / 2.0
+                }
+            }
+            Self::Curve(_) => return None,
+        })
+    }
+}
+
+/// A value a [`TweenProperty`] goes between. `from` and `to` must be the same
+/// kind; a mismatch snaps to `to` at the end.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TweenValue {
+    Float(f32),
+    Vec2(Vec2),
+    Vec3(Vec3),
+    Color(Color),
+}
+
+impl TweenValue {
+    /// The value `t` of the way from `self` to `to`; `t` may leave 0–1.
+    pub fn lerp(self, to: Self, t: f32) -> Self {
+        match (self, to) {
+            (Self::Float(a), Self::Float(b)) => Self::Float(a + (b - a) * t),
+            (Self::Vec2(a), Self::Vec2(b)) => Self::Vec2(a.lerp(b, t)),
+            (Self::Vec3(a), Self::Vec3(b)) => Self::Vec3(a.lerp(b, t)),
+            (Self::Color(a), Self::Color(b)) => Self::Color(Color::from_rgba(
+                a.r + (b.r - a.r) * t,
+                a.g + (b.g - a.g) * t,
+                a.b + (b.b - a.b) * t,
+                a.a + (b.a - a.a) * t,
+            )),
+            _ if t >= 1.0 => to,
+            _ => self,
+        }
+    }
+
+    fn to_variant(self) -> Variant {
+        match self {
+            Self::Float(value) => value.to_variant(),
+            Self::Vec2(value) => Vector2::new(value.x, value.y).to_variant(),
+            Self::Vec3(value) => Vector3::new(value.x, value.y, value.z).to_variant(),
+            Self::Color(value) => value.to_variant(),
+        }
+    }
+}
+
+impl From<f32> for TweenValue {
+    fn from(value: f32) -> Self {
+        Self::Float(value)
+    }
+}
+
+impl From<Vec2> for TweenValue {
+    fn from(value: Vec2) -> Self {
+        Self::Vec2(value)
+    }
+}
+
+impl From<Vec3> for TweenValue {
+    fn from(value: Vec3) -> Self {
+        Self::Vec3(value)
+    }
+}
+
+impl From<Color> for TweenValue {
+    fn from(value: Color) -> Self {
+        Self::Color(value)
+    }
+}
+
+/// A tween on this entity's node reached its end value. Triggered before the
+/// tween entity is despawned.
+#[derive(EntityEvent, Debug, Clone, PartialEq)]
+pub struct TweenFinished {
+    /// The tween's target.
+    pub entity: Entity,
+    /// The tween entity, despawned once observers have run.
+    pub tween: Entity,
+    pub property: String,
+}
+
+fn advance_tweens(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut tweens: Query<(Entity, &mut TweenProperty)>,
+    handles: Query<&GodotNodeHandle>,
+    mut assets: Option<ResMut<Assets<GodotResource>>>,
+    mut godot: GodotAccess,
+) {
+    for (entity, mut tween) in &mut tweens {
+        let Some(mut node) = handles
+            .get(tween.target)
+            .ok()
+            .and_then(|handle| godot.try_get::<Node>(*handle))
+        else {
+            warn!(
+                "TweenProperty {entity} targets {}, which has no node",
+                tween.target
+            );
+            commands.entity(entity).despawn();
+            continue;
+        };
+
+        let elapsed = (tween.elapsed + time.delta()).min(tween.duration);
+        let progress = tween.progress(elapsed);
+        let eased = match &tween.easing {
+            TweenEasing::Curve(curve) => assets
+                .as_mut()
+                .and_then(|assets| assets.get_mut(curve))
+                .and_then(|mut resource| resource.try_cast::<Curve>())
+                .map(|curve| curve.sample_baked(progress)),
+            easing => easing.builtin(progress),
+        };
+        // Hold at the start until the curve has loaded.
+        let Some(eased) = eased else {
+            continue;
+        };
+
+        tween.elapsed = elapsed;
+        let value = tween.from.lerp(tween.to, eased);
+        node.set_indexed(tween.property.as_str(), &value.to_variant());
+
+        if progress >= 1.0 {
+            commands.trigger(TweenFinished {
+                entity: tween.target,
+                tween: entity,
+                property: tween.property.clone(),
+            });
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builtin_easings_start_at_zero_and_end_at_one() {
+        for easing in [
+            TweenEasing::Linear,
+            TweenEasing::EaseIn,
+            TweenEasing::EaseOut,
+            TweenEasing::EaseInOut,
+        ] {
+            assert_eq!(easing.builtin(0.0), Some(0.0), "{easing:?}");
+            assert_eq!(easing.builtin(1.0), Some(1.0), "{easing:?}");
+        }
+        assert_eq!(TweenEasing::EaseInOut.builtin(0.5), Some(0.5));
+        assert!(TweenEasing::EaseIn.builtin(0.5).unwrap() < 0.5);
+        assert_eq!(TweenEasing::Curve(Handle::default()).builtin(0.5), None);
+    }
+
+    #[test]
+    fn values_lerp_and_overshoot() {
+        let from = TweenValue::Vec2(Vec2::ZERO);
+        let to = TweenValue::Vec2(Vec2::new(10.0, 20.0));
+        assert_eq!(from.lerp(to, 0.5), TweenValue::Vec2(Vec2::new(5.0, 10.0)));
+        assert_eq!(
+            TweenValue::Float(0.0).lerp(TweenValue::Float(10.0), 1.2),
+            TweenValue::Float(12.0)
+        );
+        // Mismatched kinds snap at the end.
+        assert_eq!(TweenValue::Float(0.0).lerp(to, 0.5), TweenValue::Float(0.0));
+        assert_eq!(TweenValue::Float(0.0).lerp(to, 1.0), to);
+    }
+
+    #[test]
+    fn progress_is_clamped_and_zero_duration_finishes_at_once() {
+        let target = Entity::from_raw_u32(1).unwrap();
+        let tween = TweenProperty::new(target, "modulate:a", 0.0, 1.0, Duration::from_secs(2));
+        assert_eq!(tween.progress(Duration::from_secs(1)), 0.5);
+        assert_eq!(tween.progress(Duration::from_secs(3)), 1.0);
+        let instant = TweenProperty::new(target, "modulate:a", 0.0, 1.0, Duration::ZERO);
+        assert_eq!(instant.progress(Duration::ZERO), 1.0);
+    }
+}
@@ -0,0 +1,363 @@
+//! Declarative bindings from Bevy state to Godot `Control` nodes.
+//!
+//! Instead of a hand-written system per label, bind a resource to a node path
+//! once and let change detection push updates:
+//!
+//! ```ignore
+//! app.bind_label::<Score>("/root/Main/HUD/ScoreLabel", |score| score.0.to_string())
+//!     .bind_progress::<Health>("/root/*/HUD/HealthBar", |health| health.0 as f64);
+//! ```
+//!
+//! Each binding is a `PostUpdate` system on the main thread. It writes the node
+//! when the resource changes, and also whenever the node at the path is
+//! (re)created, so a HUD in a reloaded scene starts out with the current value.
+//! Paths accept the wildcards of [`find_node_by_pattern`].
+//!
+//! Components bind to their entity's own node instead of a path; see
+//! [`UiBindingAppExt::bind_component_label`].
+//!
+//! Numbers can go through a [`NumberFormat`] instead of a closure, with digit
+//! grouping that follows the `TranslationServer` locale:
+//!
+//! ```ignore
+//! app.bind_label_number::<Gold>(
+//!     "/root/Main/HUD/GoldLabel",
+//!     |gold| gold.0 as f64,
+//!     NumberFormat::new().grouped().suffix(" g"),
+//! );
+//! // "1,234,567 g" in English, "1.234.567 g" in German
+//! ```
+
+use bevy_app::{App, PostUpdate};
+use bevy_ecs::change_detection::DetectChanges;
+use bevy_ecs::component::Component;
+use bevy_ecs::prelude::Resource;
+use bevy_ecs::query::{Added, Changed, Or};
+use bevy_ecs::system::{Local, Query, Res};
+use godot::classes::{Label, Node, Range, TranslationServer};
+use godot::obj::{Gd, Inherits, InstanceId, Singleton};
+use tracing::warn;
+
+use crate::interop::{GodotAccess, GodotNodeHandle};
+use crate::node_tree_view::find_node_by_pattern;
+use crate::plugins::scene_tree::SceneTreeRef;
+
+/// Bind resources and components to Godot nodes. See the [module docs](self).
+pub trait UiBindingAppExt {
+    /// Keep the `Label` at `path` showing `text(&R)`.
+    fn bind_label<R: Resource>(
+        &mut self,
+        path: impl Into<String>,
+        text: impl Fn(&R) -> String + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.bind_node::<R, Label>(path, move |resource, label| {
+            label.set_text(&text(resource));
+        })
+    }
+
+    /// Keep the `Range` at `path` (a `ProgressBar`, `Slider`, ...) at `value(&R)`.
+    fn bind_progress<R: Resource>(
+        &mut self,
+        path: impl Into<String>,
+        value: impl Fn(&R) -> f64 + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.bind_node::<R, Range>(path, move |resource, range| {
+            range.set_value(value(resource));
+        })
+    }
+
+    /// Keep the `Label` at `path` showing `value(&R)` formatted with `format`.
+    fn bind_label_number<R: Resource>(
+        &mut self,
+        path: impl Into<String>,
+        value: impl Fn(&R) -> f64 + Send + Sync + 'static,
+        format: NumberFormat,
+    ) -> &mut Self {
+        self.bind_label::<R>(path, move |resource| format.format(value(resource)))
+    }
+
+    /// Run `apply` on the node at `path`, cast to `N`, whenever `R` changes or
+    /// the node is (re)created. `bind_label` and `bind_progress` are built on this.
+    fn bind_node<R: Resource, N: Inherits<Node>>(
+        &mut self,
+        path: impl Into<String>,
+        apply: impl Fn(&R, &mut Gd<N>) + Send + Sync + 'static,
+    ) -> &mut Self;
+
+    /// Keep the `Label` node of every entity with `C` showing `text(&C)`.
+    fn bind_component_label<C: Component>(
+        &mut self,
+        text: impl Fn(&C) -> String + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.bind_component_node::<C, Label>(move |component, label| {
+            label.set_text(&text(component));
+        })
+    }
+
+    /// Keep the `Label` node of every entity with `C` showing `value(&C)`
+    /// formatted with `format`.
+    fn bind_component_label_number<C: Component>(
+        &mut self,
+        value: impl Fn(&C) -> f64 + Send + Sync + 'static,
+        format: NumberFormat,
+    ) -> &mut Self {
+        self.bind_component_label::<C>(move |component| format.format(value(component)))
+    }
+
+    /// Keep the `Range` node of every entity with `C` at `value(&C)`.
+    fn bind_component_progress<C: Component>(
+        &mut self,
+        value: impl Fn(&C) -> f64 + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.bind_component_node::<C, Range>(move |component, range| {
+            range.set_value(value(component));
+        })
+    }
+
+    /// Run `apply` on the node of every entity whose `C` changed, cast to `N`.
+    fn bind_component_node<C: Component, N: Inherits<Node>>(
+        &mut self,
+        apply: impl Fn(&C, &mut Gd<N>) + Send + Sync + 'static,
+    ) -> &mut Self;
+}
+
+impl UiBindingAppExt for App {
+    fn bind_node<R: Resource, N: Inherits<Node>>(
+        &mut self,
+        path: impl Into<String>,
+        apply: impl Fn(&R, &mut Gd<N>) + Send + Sync + 'static,
+    ) -> &mut Self {
+        let path = path.into();
+        self.add_systems(
+            PostUpdate,
+            move |resource: Res<R>,
+                  mut bound: Local<BoundNode>,
+                  mut scene_tree: SceneTreeRef,
+                  _godot: GodotAccess| {
+                let cached = bound
+                    .instance_id
+                    .and_then(|id| Gd::<N>::try_from_instance_id(id).ok());
+                let (mut node, fresh) = match cached {
+                    Some(node) => (node, false),
+                    // Not in the tree (yet, or any more): look it up again.
+                    None => {
+                        let Some(node) = resolve::<N>(&mut scene_tree, &path, &mut bound) else {
+                            return;
+                        };
+                        bound.instance_id = Some(node.instance_id());
+                        (node, true)
+                    }
+                };
+                if fresh || resource.is_changed() {
+                    apply(&resource, &mut node);
+                }
+            },
+        )
+    }
+
+    fn bind_component_node<C: Component, N: Inherits<Node>>(
+        &mut self,
+        apply: impl Fn(&C, &mut Gd<N>) + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.add_systems(
+            PostUpdate,
+            move |changed: Query<
+                (&GodotNodeHandle, &C),
+                Or<(Changed<C>, Added<GodotNodeHandle>)>,
+            >,
+                  mut godot: GodotAccess| {
+                for (handle, component) in changed.iter() {
+                    if let Some(mut node) = godot.try_get::<N>(*handle) {
+                        apply(component, &mut node);
+                    }
+                }
+            },
+        )
+    }
+}
+
+/// How [`UiBindingAppExt::bind_label_number`] turns a number into text.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct NumberFormat {
+    decimals: usize,
+    grouped: bool,
+    separators: Option<NumberSeparators>,
+    prefix: String,
+    suffix: String,
+}
+
+impl NumberFormat {
+    /// Whole numbers, no grouping.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Digits after the decimal separator; the value is rounded to fit.
+    pub fn decimals(mut self, decimals: usize) -> Self {
+        self.decimals = decimals;
+        self
+    }
+
+    /// Group thousands, e.g. `1,234,567`.
+    pub fn grouped(mut self) -> Self {
+        self.grouped = true;
+        self
+    }
+
+    /// Use these separators instead of the current locale's.
+    pub fn separators(mut self, separators: NumberSeparators) -> Self {
+        self.separators = Some(separators);
+        self
+    }
+
+    pub fn prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.prefix = prefix.into();
+        self
+    }
+
+    pub fn suffix(mut self, suffix: impl Into<String>) -> Self {
+        self.suffix = suffix.into();
+        self
+    }
+
+    /// Format `value`. Without fixed [`separators`](Self::separators), reads the
+    /// locale from `TranslationServer`, so call it on the main thread.
+    pub fn format(&self, value: f64) -> String {
+        let separators = self
+            .separators
+            .clone()
+            .unwrap_or_else(NumberSeparators::current);
+        self.format_with(value, &separators)
+    }
+
+    /// Format `value` with the given separators.
+    pub fn format_with(&self, value: f64, separators: &NumberSeparators) -> String {
+        let digits = format!("{:.*}", self.decimals, value.abs());
+        let (whole, fraction) = digits.split_once('.').unwrap_or((&digits, ""));
+        let negative = value < 0.0 && digits.bytes().any(|b| b.is_ascii_digit() && b != b'0');
+
+        let mut text = self.prefix.clone();
+        if negative {
+            text.push('-');
+        }
+        for (i, digit) in whole.chars().enumerate() {
+            if self.grouped && i > 0 && (whole.len() - i) % 3 == 0 {
+                text.push_str(&separators.group);
+            }
+            text.push(digit);
+        }
+        if !fraction.is_empty() {
+            text.push_str(&separators.decimal);
+            text.push_str(fraction);
+        }
+        text.push_str(&self.suffix);
+        text
+    }
+}
+
+/// Digit group and decimal separators of a locale.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NumberSeparators {
+    pub group: String,
+    pub decimal: String,
+}
+
+impl Default for NumberSeparators {
+    fn default() -> Self {
+        Self {
+            group: ",".into(),
+            decimal: ".".into(),
+        }
+    }
+}
+
+impl NumberSeparators {
+    /// Separators for a Godot locale code such as `"de"` or `"pt_BR"`. Languages
+    /// not listed get the English `1,234.5`.
+    pub fn for_locale(locale: &str) -> Self {
+        let language = locale.split(['_', '-']).next().unwrap_or(locale);
+        let (group, decimal) = match language {
+            "de" | "es" | "it" | "nl" | "pt" | "id" | "tr" | "da" | "el" | "ro" | "hr" | "sl"
+            | "sr" => (".", ","),
+            // Narrow no-break space, so a number never wraps mid-group.
+            "fr" | "ru" | "pl" | "cs" | "sk" | "uk" | "fi" | "sv" | "nb" | "no" | "hu" | "bg"
+            | "lt" | "lv" | "et" => ("\u{202f}", ","),
+            _ => return Self::default(),
+        };
+        Self {
+            group: group.into(),
+            decimal: decimal.into(),
+        }
+    }
+
+    /// Separators for `TranslationServer`'s current locale. Main thread only.
+    pub fn current() -> Self {
+        Self::for_locale(&TranslationServer::singleton().get_locale().to_string())
+    }
+}
+
+#[derive(Default)]
+struct BoundNode {
+    instance_id: Option<InstanceId>,
+    warned_wrong_type: bool,
+}
+
+fn resolve<N: Inherits<Node>>(
+    scene_tree: &mut SceneTreeRef,
+    path: &str,
+    bound: &mut BoundNode,
+) -> Option<Gd<N>> {
+    let root = scene_tree.get().get_root()?.upcast::<Node>();
+    let node = find_node_by_pattern(&root, path)?;
+    match node.try_cast::<N>() {
+        Ok(node) => Some(node),
+        Err(node) => {
+            if !bound.warned_wrong_type {
+                warn!(
+                    "UI binding: node at {path} is a {}, not a {}",
+                    node.get_class(),
+                    std::any::type_name::<N>()
+                );
+                bound.warned_wrong_type = true;
+            }
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn numbers_are_grouped_and_rounded() {
+        let english = NumberSeparators::default();
+        let format = NumberFormat::new().grouped();
+        assert_eq!(format.format_with(1234567.0, &english), "1,234,567");
+        assert_eq!(format.format_with(999.0, &english), "999");
+        assert_eq!(format.format_with(-1000.4, &english), "-1,000");
+        assert_eq!(format.format_with(-0.2, &english), "0");
+
+        let money = NumberFormat::new().decimals(2).grouped().prefix("$");
+        assert_eq!(money.format_with(12345.678, &english), "$12,345.68");
+        assert_eq!(
+            NumberFormat::new()
+                .decimals(1)
+                .format_with(1234.56, &english),
+            "1234.6"
+        );
+    }
+
+    #[test]
+    fn separators_follow_the_locale() {
+        let format = NumberFormat::new().decimals(1).grouped().suffix(" pts");
+        let german = NumberSeparators::for_locale("de_DE");
+        assert_eq!(format.format_with(1234.5, &german), "1.234,5 pts");
+        let french = NumberSeparators::for_locale("fr");
+        assert_eq!(format.format_with(1234.5, &french), "1\u{202f}234,5 pts");
+        assert_eq!(
+            NumberSeparators::for_locale("en_US"),
+            NumberSeparators::default()
+        );
+    }
+}
@@ -0,0 +1,311 @@
+//! Interaction events for Godot `Control` nodes, targeted at their entities.
+//!
+//! With [`GodotUiEventsPlugin`], every mirrored button, text field, range and
+//! item list gets its common signals connected once, when its entity appears:
+//!
+//! | Node                           | Signal          | Event              |
+//! |--------------------------------|-----------------|--------------------|
+//! | `BaseButton`                   | `pressed`       | [`ButtonPressed`]  |
+//! | `BaseButton`                   | `toggled`       | [`ButtonToggled`]  |
+//! | `LineEdit`, `TextEdit`         | `text_changed`  | [`TextChanged`]    |
+//! | `Range` (sliders, spin boxes)  | `value_changed` | [`ValueChanged`]   |
+//! | `OptionButton`, `ItemList`     | `item_selected` | [`ItemSelected`]   |
+//! | `Control`                      | `focus_entered` | [`FocusEntered`]   |
+//! | `Control`                      | `focus_exited`  | [`FocusExited`]    |
+//!
+//! The events are [`EntityEvent`]s, so a menu can observe one button directly
+//! or all of them globally:
+//!
+//! ```ignore
+//! commands.entity(quit_button).observe(|_: On<ButtonPressed>, mut exit: MessageWriter<AppExit>| {
+//!     exit.write(AppExit::Success);
+//! });
+//!
+//! app.add_observer(|changed: On<ValueChanged>, sliders: Query<&VolumeSlider>| {
+//!     if let Ok(slider) = sliders.get(changed.entity) {
+//!         info!("{:?} volume: {}", slider.bus, changed.value);
+//!     }
+//! });
+//! ```
+//!
+//! Like other Godot signals, they reach observers on the next `First`.
+//!
+//! # Focus
+//!
+//! The focused control's entity also carries the [`Focused`] marker, and
+//! [`FocusCommand`] moves focus from a system. Menu navigation can then be plain
+//! ECS logic, testable without a running engine:
+//!
+//! ```ignore
+//! fn highlight(focused: Query<&MenuItem, Added<Focused>>) {
+//!     for item in &focused {
+//!         info!("hovering {}", item.label);
+//!     }
+//! }
+//!
+//! fn open_menu(mut focus: MessageWriter<FocusCommand>, first: Single<Entity, With<FirstMenuItem>>) {
+//!     focus.write(FocusCommand::Grab(*first));
+//! }
+//! ```
+
+use bevy_app::{App, Last, Plugin};
+use bevy_ecs::component::Component;
+use bevy_ecs::entity::Entity;
+use bevy_ecs::event::EntityEvent;
+use bevy_ecs::message::{Message, MessageReader};
+use bevy_ecs::observer::On;
+use bevy_ecs::query::Added;
+use bevy_ecs::schedule::IntoScheduleConfigs;
+use bevy_ecs::system::{Commands, Query, Res};
+use godot::builtin::{GString, Variant};
+use godot::classes::{Control, Node, TextEdit};
+use godot::global::Error;
+use godot::meta::FromGodot;
+use godot::obj::Gd;
+use godot::prelude::Callable;
+use tracing::warn;
+
+use crate::interop::{GodotAccess, GodotNodeHandle};
+use crate::plugins::event_bridge::GodotEventSender;
+
+/// Plugin connecting `Control` signals to entity events. See the [module docs](self).
+#[derive(Default)]
+pub struct GodotUiEventsPlugin;
+
+impl Plugin for GodotUiEventsPlugin {
+    fn build(&self, app: &mut App) {
+        // `Last`, with the other deferred connections: by then this frame's scene
+        // tree changes have entities, and nothing can be clicked before next frame.
+        app.add_message::<FocusCommand>()
+            .add_observer(on_focus_entered)
+            .add_observer(on_focus_exited)
+            .add_systems(Last, (apply_focus_commands, connect_ui_signals).chain());
+    }
+}
+
+/// Marks the entity of the `Control` that has keyboard/gamepad focus.
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Focused;
+
+/// Move UI focus to or away from an entity's `Control`. Applied in `Last`; the
+/// [`Focused`] marker follows once Godot reports the change.
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FocusCommand {
+    Grab(Entity),
+    Release(Entity),
+}
+
+/// A `BaseButton` was pressed (for toggle buttons, whenever it flips).
+#[derive(EntityEvent, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ButtonPressed {
+    pub entity: Entity,
+}
+
+/// A toggle-mode `BaseButton` (check box, check button, ...) changed state.
+#[derive(EntityEvent, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ButtonToggled {
+    pub entity: Entity,
+    pub pressed: bool,
+}
+
+/// The text of a `LineEdit` or `TextEdit` was edited.
+#[derive(EntityEvent, Debug, Clone, PartialEq, Eq)]
+pub struct TextChanged {
+    pub entity: Entity,
+    pub text: String,
+}
+
+/// The value of a `Range` changed, from the user or from code.
+#[derive(EntityEvent, Debug, Clone, Copy, PartialEq)]
+pub struct ValueChanged {
+    pub entity: Entity,
+    pub value: f64,
+}
+
+/// An item of an `OptionButton` or `ItemList` was selected.
+#[derive(EntityEvent, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ItemSelected {
+    pub entity: Entity,
+    pub index: i64,
+}
+
+/// A `Control` gained focus.
+#[derive(EntityEvent, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FocusEntered {
+    pub entity: Entity,
+}
+
+/// A `Control` lost focus.
+#[derive(EntityEvent, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FocusExited {
+    pub entity: Entity,
+}
+
+fn on_focus_entered(focus: On<FocusEntered>, mut commands: Commands) {
+    if let Ok(mut entity) = commands.get_entity(focus.entity) {
+        entity.try_insert(Focused);
+    }
+}
+
+fn on_focus_exited(focus: On<FocusExited>, mut commands: Commands) {
+    if let Ok(mut entity) = commands.get_entity(focus.entity) {
+        entity.try_remove::<Focused>();
+    }
+}
+
+fn apply_focus_commands(
+    mut requests: MessageReader<FocusCommand>,
+    handles: Query<&GodotNodeHandle>,
+    mut godot: GodotAccess,
+) {
+    for request in requests.read() {
+        let (FocusCommand::Grab(entity) | FocusCommand::Release(entity)) = *request;
+        let Some(mut control) = handles
+            .get(entity)
+            .ok()
+            .and_then(|handle| godot.try_get::<Control>(*handle))
+        else {
+            warn!("GodotUiEventsPlugin: {request:?} targets an entity without a Control node");
+            continue;
+        };
+        match request {
+            FocusCommand::Grab(_) => control.grab_focus(),
+            FocusCommand::Release(_) => control.release_focus(),
+        }
+    }
+}
+
+fn connect_ui_signals(
+    added: Query<(Entity, &GodotNodeHandle), Added<GodotNodeHandle>>,
+    sender: Res<GodotEventSender>,
+    mut godot: GodotAccess,
+) {
+    for (entity, handle) in added.iter() {
+        let Some(mut node) = godot.try_get::<Node>(*handle) else {
+            continue;
+        };
+
+        if node.is_class("BaseButton") {
+            let tx = sender.clone();
+            connect(&mut node, entity, "pressed", move |_| {
+                tx.send(ButtonPressed { entity });
+            });
+            let tx = sender.clone();
+            connect(&mut node, entity, "toggled", move |args| {
+                if let Some(pressed) = arg::<bool>(args, 0) {
+                    tx.send(ButtonToggled { entity, pressed });
+                }
+            });
+        }
+
+        if node.is_class("LineEdit") {
+            let tx = sender.clone();
+            connect(&mut node, entity, "text_changed", move |args| {
+                if let Some(text) = arg::<GString>(args, 0) {
+                    tx.send(TextChanged {
+                        entity,
+                        text: text.to_string(),
+                    });
+                }
+            });
+        } else if node.is_class("TextEdit") {
+            // `TextEdit.text_changed` has no arguments; read the text back.
+            let tx = sender.clone();
+            let instance_id = handle.instance_id();
+            connect(&mut node, entity, "text_changed", move |_| {
+                if let Ok(text_edit) = Gd::<TextEdit>::try_from_instance_id(instance_id) {
+                    tx.send(TextChanged {
+                        entity,
+                        text: text_edit.get_text().to_string(),
+                    });
+                }
+            });
+        }
+
+        if node.is_class("Range") {
+            let tx = sender.clone();
+            connect(&mut node, entity, "value_changed", move |args| {
+                if let Some(value) = arg::<f64>(args, 0) {
+                    tx.send(ValueChanged { entity, value });
+                }
+            });
+        }
+
+        if let Ok(control) = node.clone().try_cast::<Control>() {
+            let tx = sender.clone();
+            connect(&mut node, entity, "focus_entered", move |_| {
+                tx.send(FocusEntered { entity });
+            });
+            let tx = sender.clone();
+            connect(&mut node, entity, "focus_exited", move |_| {
+                tx.send(FocusExited { entity });
+            });
+            // Focused before the signals were connected, e.g. grabbed in `_ready`.
+            if control.has_focus() {
+                sender.send(FocusEntered { entity });
+            }
+        }
+
+        if node.is_class("OptionButton") || node.is_class("ItemList") {
+            let tx = sender.clone();
+            connect(&mut node, entity, "item_selected", move |args| {
+                if let Some(index) = arg::<i64>(args, 0) {
+                    tx.send(ItemSelected { entity, index });
+                }
+            });
+        }
+    }
+}
+
+fn connect(
+    node: &mut Gd<Node>,
+    entity: Entity,
+    signal: &'static str,
+    handler: impl Fn(&[&Variant]) + 'static,
+) {
+    let callable = Callable::from_fn(format!("ui_event_{signal}"), move |args| {
+        handler(args);
+        Variant::nil()
+    });
+    let result = node.connect(signal, &callable);
+    if result != Error::OK {
+        warn!(
+            "GodotUiEventsPlugin: failed to connect '{signal}' on {} (entity {entity}): {result:?}",
+            node.get_class()
+        );
+    }
+}
+
+fn arg<T: FromGodot>(args: &[&Variant], index: usize) -> Option<T> {
+    args.get(index)?.try_to::<T>().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_ecs::world::World;
+
+    #[test]
+    fn focus_events_maintain_focused_marker() {
+        let mut world = World::new();
+        world.add_observer(on_focus_entered);
+        world.add_observer(on_focus_exited);
+        let first = world.spawn_empty().id();
+        let second = world.spawn_empty().id();
+
+        world.trigger(FocusEntered { entity: first });
+        world.flush();
+        assert!(world.entity(first).contains::<Focused>());
+
+        world.trigger(FocusExited { entity: first });
+        world.trigger(FocusEntered { entity: second });
+        world.flush();
+        assert!(!world.entity(first).contains::<Focused>());
+        assert!(world.entity(second).contains::<Focused>());
+
+        // The signal can arrive after the entity is gone.
+        world.despawn(second);
+        world.trigger(FocusExited { entity: second });
+        world.flush();
+    }
+}
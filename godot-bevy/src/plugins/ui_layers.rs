@@ -0,0 +1,100 @@
+//! Declare which `CanvasLayer` a piece of UI draws on, per entity.
+//!
+//! With [`GodotUiLayersPlugin`], giving a `Control`'s entity a [`UiLayer`] moves
+//! the control -- and its whole subtree -- under a `CanvasLayer` with that index.
+//! Layers are created under the root the first time an index is used and kept in
+//! [`UiLayers`]; changing the component moves the control again.
+//!
+//! ```ignore
+//! const HUD: i32 = 10;
+//! const PAUSE_MENU: i32 = 20;
+//! const TOASTS: i32 = 30;
+//!
+//! commands.entity(hud).insert(UiLayer(HUD));
+//! commands.entity(pause_menu).insert(UiLayer(PAUSE_MENU));
+//! ```
+//!
+//! The layers are ordinary mirrored nodes, so they have entities of their own.
+//! Removing the component leaves the control where it is.
+
+use std::collections::HashMap;
+
+use bevy_app::{App, Last, Plugin};
+use bevy_ecs::component::Component;
+use bevy_ecs::prelude::Resource;
+use bevy_ecs::query::{Added, Changed, Or};
+use bevy_ecs::system::{Query, ResMut};
+use godot::classes::{CanvasItem, CanvasLayer, Node};
+use godot::obj::{Gd, InstanceId, NewAlloc};
+use tracing::warn;
+
+use crate::interop::{GodotAccess, GodotNodeHandle};
+use crate::plugins::scene_tree::SceneTreeRef;
+
+/// Plugin managing [`UiLayer`]s. See the [module docs](self).
+#[derive(Default)]
+pub struct GodotUiLayersPlugin;
+
+impl Plugin for GodotUiLayersPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<UiLayers>()
+            .add_systems(Last, place_ui_layers);
+    }
+}
+
+/// Draw the entity's `Control` on the `CanvasLayer` with this index; higher
+/// indices draw on top.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct UiLayer(pub i32);
+
+/// The `CanvasLayer`s created for [`UiLayer`] indices.
+#[derive(Resource, Debug, Default)]
+pub struct UiLayers {
+    layers: HashMap<i32, InstanceId>,
+}
+
+impl UiLayers {
+    /// The layer node for `index`, if a [`UiLayer`] has used it and it wasn't freed.
+    pub fn get(&self, index: i32) -> Option<GodotNodeHandle> {
+        Gd::<Node>::try_from_instance_id(*self.layers.get(&index)?)
+            .ok()
+            .map(GodotNodeHandle::new)
+    }
+}
+
+fn place_ui_layers(
+    placed: Query<(&UiLayer, &GodotNodeHandle), Or<(Changed<UiLayer>, Added<GodotNodeHandle>)>>,
+    mut layers: ResMut<UiLayers>,
+    mut scene_tree: SceneTreeRef,
+    mut godot: GodotAccess,
+) {
+    for (&UiLayer(index), handle) in &placed {
+        let Some(mut item) = godot.try_get::<CanvasItem>(*handle) else {
+            warn!("GodotUiLayersPlugin: UiLayer({index}) on a node that isn't a CanvasItem");
+            continue;
+        };
+        let layer = layers
+            .layers
+            .get(&index)
+            .and_then(|id| Gd::<CanvasLayer>::try_from_instance_id(*id).ok());
+        let layer = match layer {
+            Some(layer) => layer,
+            None => {
+                let Some(mut root) = scene_tree.get().get_root() else {
+                    continue;
+                };
+                let mut layer = CanvasLayer::new_alloc();
+                layer.set_name(&format!("UiLayer{index}"));
+                layer.set_layer(index);
+                root.add_child(&layer);
+                layers.layers.insert(index, layer.instance_id());
+                layer
+            }
+        };
+        if item.get_parent().map(|parent| parent.instance_id()) == Some(layer.instance_id()) {
+            continue;
+        }
+        // Anchors and offsets stay as authored, now relative to the layer.
+        item.reparent_ex(&layer).keep_global_transform(false).done();
+    }
+}
@@ -0,0 +1,119 @@
+//! Hot reload for content under `user://`: mods, or levels written by an
+//! external editor while the game runs.
+//!
+//! ```ignore
+//! app.add_plugins(UserFileWatcherPlugin::new(["user://mods", "user://levels"]))
+//!     .add_observer(|changed: On<UserFileChanged>| {
+//!         let path = &changed.event().0;
+//!         if path.extension().is_some_and(|ext| ext == "json") {
+//!             info!("reloading {}", path.display());
+//!         }
+//!     });
+//! ```
+//!
+//! The directories are polled, not watched: every `poll_interval` the plugin walks
+//! them on the main thread and compares modification times. That is cheap for a
+//! few hundred files, but meant for development rather than shipping builds.
+
+use std::path::PathBuf;
+use std::time::Duration;
+
+use bevy_app::{App, Plugin, PreUpdate};
+use bevy_ecs::event::Event;
+use bevy_ecs::prelude::Resource;
+use bevy_ecs::system::{Commands, Res, ResMut};
+use bevy_time::{Real, Time};
+use godot::classes::ProjectSettings;
+use tracing::debug;
+
+use crate::interop::GodotAccess;
+use crate::watchers::user_file_watcher::UserFileWatcher;
+
+/// Plugin emitting [`UserFileChanged`]. See the [module docs](self).
+///
+/// There is no default: name the directories your content lives in. All of
+/// `user://` also holds Godot's own `logs/` and `shader_cache/`, which change
+/// constantly and would make every poll walk them.
+#[derive(Debug, Clone)]
+pub struct UserFileWatcherPlugin {
+    /// Godot paths (`user://...`, or absolute) watched recursively.
+    pub directories: Vec<String>,
+    pub poll_interval: Duration,
+}
+
+impl UserFileWatcherPlugin {
+    pub fn new<S: Into<String>>(directories: impl IntoIterator<Item = S>) -> Self {
+        Self {
+            directories: directories.into_iter().map(Into::into).collect(),
+            poll_interval: Duration::from_millis(500),
+        }
+    }
+
+    pub fn with_poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+}
+
+impl Plugin for UserFileWatcherPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(UserFileWatchers {
+            directories: self.directories.clone(),
+            poll_interval: self.poll_interval,
+            since_poll: Duration::ZERO,
+            watchers: None,
+        })
+        .add_systems(PreUpdate, poll_user_files);
+    }
+}
+
+/// Triggered for each file under a watched directory that was created, modified
+/// or removed. The path is absolute, ready for `std::fs`; a removed file no
+/// longer exists.
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+pub struct UserFileChanged(pub PathBuf);
+
+#[derive(Resource)]
+struct UserFileWatchers {
+    directories: Vec<String>,
+    poll_interval: Duration,
+    since_poll: Duration,
+    /// Created on the first run, once `ProjectSettings` can resolve `user://`.
+    watchers: Option<Vec<UserFileWatcher>>,
+}
+
+fn poll_user_files(
+    mut commands: Commands,
+    mut state: ResMut<UserFileWatchers>,
+    time: Res<Time<Real>>,
+    mut godot: GodotAccess,
+) {
+    let state = &mut *state;
+    state.since_poll += time.delta();
+    if state.watchers.is_some() && state.since_poll < state.poll_interval {
+        return;
+    }
+    state.since_poll = Duration::ZERO;
+
+    let watchers = state.watchers.get_or_insert_with(|| {
+        let settings = godot.singleton::<ProjectSettings>();
+        state
+            .directories
+            .iter()
+            .map(|dir| {
+                let path = PathBuf::from(settings.globalize_path(dir.as_str()).to_string());
+                debug!(
+                    "UserFileWatcherPlugin: watching {dir} at {}",
+                    path.display()
+                );
+                UserFileWatcher::new(path)
+            })
+            .collect()
+    });
+
+    for watcher in watchers {
+        for path in watcher.poll() {
+            commands.trigger(UserFileChanged(path));
+        }
+    }
+}
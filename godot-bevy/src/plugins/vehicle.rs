@@ -0,0 +1,133 @@
+//! Arcade vehicles on `VehicleBody3D`, driven from systems.
+//!
+//! With [`GodotVehiclePlugin`], every mirrored `VehicleBody3D` gets
+//! [`VehicleControls`], read from the node, and every `VehicleWheel3D` gets a
+//! [`VehicleWheelState`]. Each physics tick the wheels are read in
+//! `FixedPreUpdate`, and changed controls are written to the body in
+//! `FixedPostUpdate`, so `FixedUpdate` systems see this tick's wheels and steer
+//! for the next one:
+//!
+//! ```ignore
+//! fn drive(
+//!     actions: Res<GodotActions>,
+//!     mut cars: Query<(&mut VehicleControls, &GodotChildren), With<PlayerCar>>,
+//!     wheels: Query<&VehicleWheelState>,
+//! ) {
+//!     for (mut controls, children) in &mut cars {
+//!         controls.engine_force = 400.0 * actions.strength("accelerate");
+//!         controls.steering = 0.4 * actions.axis("steer_right", "steer_left");
+//!         let sliding = wheels.iter_many(children.iter()).any(|wheel| wheel.skid < 0.5);
+//!         controls.brake = if sliding { 0.0 } else { 20.0 * actions.strength("brake") };
+//!     }
+//! }
+//! ```
+//!
+//! The body's controls apply to every wheel Godot uses for traction, steering
+//! or braking; set a wheel's own `engine_force` on its node for per-wheel drive.
+
+use bevy_app::{App, FixedPostUpdate, FixedPreUpdate, Plugin};
+use bevy_ecs::change_detection::DetectChangesMut;
+use bevy_ecs::component::Component;
+use bevy_ecs::entity::Entity;
+use bevy_ecs::query::{Added, Changed, Without};
+use bevy_ecs::schedule::IntoScheduleConfigs;
+use bevy_ecs::system::{Commands, Query};
+use godot::classes::{VehicleBody3D, VehicleWheel3D};
+
+use crate::interop::{GodotAccess, GodotNodeHandle};
+
+/// Plugin bridging vehicle bodies and wheels. See the [module docs](self).
+#[derive(Default)]
+pub struct GodotVehiclePlugin;
+
+impl Plugin for GodotVehiclePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            FixedPreUpdate,
+            (insert_vehicle_components, read_wheel_states).chain(),
+        )
+        .add_systems(FixedPostUpdate, apply_vehicle_controls);
+    }
+}
+
+/// What a `VehicleBody3D` is told to do.
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq)]
+pub struct VehicleControls {
+    /// Force pushing the vehicle forward, negative for reverse.
+    pub engine_force: f32,
+    /// Steering angle in radians, positive to the left.
+    pub steering: f32,
+    /// Braking force.
+    pub brake: f32,
+}
+
+/// How a `VehicleWheel3D` met the ground this physics tick.
+#[derive(Component, Debug, Default, Clone, Copy, PartialEq)]
+pub struct VehicleWheelState {
+    /// Whether the wheel touches anything.
+    pub in_contact: bool,
+    /// Grip, from 0 (skidding) to 1 (full grip).
+    pub skid: f32,
+    /// Revolutions per minute.
+    pub rpm: f32,
+}
+
+fn insert_vehicle_components(
+    added: Query<
+        (Entity, &GodotNodeHandle),
+        (
+            Added<GodotNodeHandle>,
+            Without<VehicleControls>,
+            Without<VehicleWheelState>,
+        ),
+    >,
+    mut commands: Commands,
+    mut godot: GodotAccess,
+) {
+    for (entity, handle) in &added {
+        if let Some(body) = godot.try_get::<VehicleBody3D>(*handle) {
+            // Controls inserted from ECS are kept and written to the node.
+            commands.entity(entity).insert_if_new(VehicleControls {
+                engine_force: body.get_engine_force(),
+                steering: body.get_steering(),
+                brake: body.get_brake(),
+            });
+        } else if godot.try_get::<VehicleWheel3D>(*handle).is_some() {
+            commands.entity(entity).insert(VehicleWheelState::default());
+        }
+    }
+}
+
+fn read_wheel_states(
+    mut wheels: Query<(&GodotNodeHandle, &mut VehicleWheelState)>,
+    mut godot: GodotAccess,
+) {
+    for (handle, mut state) in &mut wheels {
+        if let Some(wheel) = godot.try_get::<VehicleWheel3D>(*handle) {
+            state.set_if_neq(VehicleWheelState {
+                in_contact: wheel.is_in_contact(),
+                skid: wheel.get_skidinfo(),
+                rpm: wheel.get_rpm(),
+            });
+        }
+    }
+}
+
+fn apply_vehicle_controls(
+    bodies: Query<(&GodotNodeHandle, &VehicleControls), Changed<VehicleControls>>,
+    mut godot: GodotAccess,
+) {
+    for (handle, controls) in &bodies {
+        if let Some(mut body) = godot.try_get::<VehicleBody3D>(*handle) {
+            if body.get_engine_force() != controls.engine_force {
+                body.set_engine_force(controls.engine_force);
+            }
+            if body.get_steering() != controls.steering {
+                body.set_steering(controls.steering);
+            }
+            if body.get_brake() != controls.brake {
+                body.set_brake(controls.brake);
+            }
+        }
+    }
+}
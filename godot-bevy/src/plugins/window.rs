@@ -0,0 +1,357 @@
+//! Window settings and extra windows/viewports, from ECS.
+//!
+//! With [`GodotWindowPlugin`], the main window's mode, vsync, size, 3D resolution
+//! scale and content scale live in the [`GodotWindowSettings`] resource. A
+//! settings menu edits the resource; the changes are applied in `Last`. Changes
+//! made outside Bevy (the player maximizing or resizing the window) flow back
+//! into it.
+//!
+//! ```ignore
+//! fn toggle_fullscreen(input: Res<ButtonInput<KeyCode>>, mut window: ResMut<GodotWindowSettings>) {
+//!     if input.just_pressed(KeyCode::F11) {
+//!         window.mode = match window.mode {
+//!             WindowMode::FULLSCREEN => WindowMode::WINDOWED,
+//!             _ => WindowMode::FULLSCREEN,
+//!         };
+//!     }
+//! }
+//! ```
+//!
+//! # More windows and viewports
+//!
+//! Spawning an entity with a [`GodotWindow`] creates a Godot `Window` (its own OS
+//! window, unless the project embeds subwindows) or an off-screen `SubViewport`,
+//! optionally with a scene inside. Both are viewports, so their render target is
+//! the node's `get_texture()`, e.g. for a `TextureRect` showing a minimap:
+//!
+//! ```ignore
+//! commands.spawn(
+//!     GodotWindow::sub_viewport(UVec2::new(256, 256))
+//!         .with_scene("res://minimap.tscn")
+//!         .with_own_world_3d(),
+//! );
+//! commands.spawn(GodotWindow::window("Inspector", UVec2::new(480, 640)));
+//! ```
+//!
+//! Despawning the entity or removing the component frees the node. Closing a
+//! window triggers [`WindowCloseRequested`] and, unless the window was created
+//! [`keep_open`](GodotWindow::keep_open), despawns its entity.
+
+use bevy_app::{App, Last, Plugin, PreStartup};
+use bevy_ecs::change_detection::{DetectChanges, DetectChangesMut};
+use bevy_ecs::component::Component;
+use bevy_ecs::entity::Entity;
+use bevy_ecs::event::EntityEvent;
+use bevy_ecs::lifecycle::Remove;
+use bevy_ecs::observer::On;
+use bevy_ecs::prelude::Resource;
+use bevy_ecs::query::{Added, Changed, Without};
+use bevy_ecs::schedule::IntoScheduleConfigs;
+use bevy_ecs::system::{Commands, Query, Res, ResMut};
+use bevy_math::UVec2;
+use godot::builtin::{Variant, Vector2i};
+use godot::classes::display_server::{VSyncMode, WindowMode};
+use godot::classes::window::{ContentScaleAspect, ContentScaleMode};
+use godot::classes::{DisplayServer, Node, PackedScene, ResourceLoader, SubViewport, Window};
+use godot::obj::{Gd, NewAlloc};
+use godot::prelude::Callable;
+use tracing::warn;
+
+use crate::interop::{GodotAccess, GodotNodeHandle};
+use crate::plugins::event_bridge::GodotEventSender;
+use crate::plugins::scene_tree::SceneTreeRef;
+
+/// Plugin syncing [`GodotWindowSettings`] and creating [`GodotWindow`] nodes. See
+/// the [module docs](self).
+#[derive(Default)]
+pub struct GodotWindowPlugin;
+
+impl Plugin for GodotWindowPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_observer(on_close_requested)
+            .add_observer(on_window_removed)
+            .add_systems(PreStartup, read_window_settings)
+            .add_systems(
+                Last,
+                (sync_window_settings, spawn_windows, apply_window_changes).chain(),
+            );
+    }
+}
+
+#[cfg(debug_assertions)]
+crate::inventory::submit! {
+    crate::watchdog::PluginRequirement::new::<GodotWindow, GodotWindowPlugin>()
+}
+
+/// The main window's display settings. Read from Godot before `Startup`.
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct GodotWindowSettings {
+    pub mode: WindowMode,
+    pub vsync: VSyncMode,
+    /// Size of the window when windowed, in pixels.
+    pub size: UVec2,
+    /// 3D render resolution relative to the window's, e.g. `0.5` renders 3D at
+    /// half resolution. UI and 2D are unaffected.
+    pub scaling_3d_scale: f32,
+    /// Extra scale for 2D and UI, on top of `content_scale_mode`.
+    pub content_scale_factor: f32,
+    pub content_scale_mode: ContentScaleMode,
+    pub content_scale_aspect: ContentScaleAspect,
+}
+
+impl GodotWindowSettings {
+    fn read(display: &Gd<DisplayServer>, root: &Gd<Window>) -> Self {
+        let size = display.window_get_size();
+        Self {
+            mode: display.window_get_mode(),
+            vsync: display.window_get_vsync_mode(),
+            size: UVec2::new(size.x.max(0) as u32, size.y.max(0) as u32),
+            scaling_3d_scale: root.get_scaling_3d_scale(),
+            content_scale_factor: root.get_content_scale_factor(),
+            content_scale_mode: root.get_content_scale_mode(),
+            content_scale_aspect: root.get_content_scale_aspect(),
+        }
+    }
+
+    /// Write the fields that differ from `current`.
+    fn apply(&self, current: &Self, display: &mut Gd<DisplayServer>, root: &mut Gd<Window>) {
+        if self.mode != current.mode {
+            display.window_set_mode(self.mode);
+        }
+        if self.vsync != current.vsync {
+            display.window_set_vsync_mode(self.vsync);
+        }
+        if self.size != current.size {
+            display.window_set_size(to_vector2i(self.size));
+        }
+        if self.scaling_3d_scale != current.scaling_3d_scale {
+            root.set_scaling_3d_scale(self.scaling_3d_scale);
+        }
+        if self.content_scale_factor != current.content_scale_factor {
+            root.set_content_scale_factor(self.content_scale_factor);
+        }
+        if self.content_scale_mode != current.content_scale_mode {
+            root.set_content_scale_mode(self.content_scale_mode);
+        }
+        if self.content_scale_aspect != current.content_scale_aspect {
+            root.set_content_scale_aspect(self.content_scale_aspect);
+        }
+    }
+}
+
+/// Whether a [`GodotWindow`] is a `Window` or an off-screen `SubViewport`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GodotWindowKind {
+    Window,
+    SubViewport,
+}
+
+/// An extra `Window` or `SubViewport` owned by this entity. See the
+/// [module docs](self).
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct GodotWindow {
+    pub kind: GodotWindowKind,
+    /// Shown in the title bar of a `Window`.
+    pub title: String,
+    pub size: UVec2,
+    /// Scene instantiated inside the window when it is created.
+    pub scene: Option<String>,
+    /// Render 3D in a world of its own instead of the main one.
+    pub own_world_3d: bool,
+    /// Despawn the entity when the window's close button is pressed.
+    pub close_on_request: bool,
+}
+
+impl GodotWindow {
+    pub fn window(title: impl Into<String>, size: UVec2) -> Self {
+        Self {
+            kind: GodotWindowKind::Window,
+            title: title.into(),
+            size,
+            scene: None,
+            own_world_3d: false,
+            close_on_request: true,
+        }
+    }
+
+    pub fn sub_viewport(size: UVec2) -> Self {
+        Self {
+            kind: GodotWindowKind::SubViewport,
+            title: String::new(),
+            size,
+            scene: None,
+            own_world_3d: false,
+            close_on_request: true,
+        }
+    }
+
+    pub fn with_scene(mut self, path: impl Into<String>) -> Self {
+        self.scene = Some(path.into());
+        self
+    }
+
+    pub fn with_own_world_3d(mut self) -> Self {
+        self.own_world_3d = true;
+        self
+    }
+
+    /// Only trigger [`WindowCloseRequested`] when the close button is pressed.
+    pub fn keep_open(mut self) -> Self {
+        self.close_on_request = false;
+        self
+    }
+}
+
+/// The close button of a [`GodotWindow`] was pressed.
+#[derive(EntityEvent, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WindowCloseRequested {
+    pub entity: Entity,
+}
+
+fn to_vector2i(size: UVec2) -> Vector2i {
+    Vector2i::new(size.x as i32, size.y as i32)
+}
+
+fn read_window_settings(
+    mut commands: Commands,
+    mut scene_tree: SceneTreeRef,
+    mut godot: GodotAccess,
+) {
+    let Some(root) = scene_tree.get().get_root() else {
+        warn!("GodotWindowPlugin: no root window; GodotWindowSettings is unavailable");
+        return;
+    };
+    let display = godot.singleton::<DisplayServer>();
+    commands.insert_resource(GodotWindowSettings::read(&display, &root));
+}
+
+fn sync_window_settings(
+    settings: Option<ResMut<GodotWindowSettings>>,
+    mut scene_tree: SceneTreeRef,
+    mut godot: GodotAccess,
+) {
+    let (Some(mut settings), Some(mut root)) = (settings, scene_tree.get().get_root()) else {
+        return;
+    };
+    let mut display = godot.singleton::<DisplayServer>();
+    let current = GodotWindowSettings::read(&display, &root);
+    if settings.is_changed() && !settings.is_added() {
+        settings.apply(&current, &mut display, &mut root);
+    } else {
+        settings.set_if_neq(current);
+    }
+}
+
+fn spawn_windows(
+    added: Query<(Entity, &GodotWindow), (Added<GodotWindow>, Without<GodotNodeHandle>)>,
+    sender: Res<GodotEventSender>,
+    mut commands: Commands,
+    mut scene_tree: SceneTreeRef,
+    mut godot: GodotAccess,
+) {
+    for (entity, window) in &added {
+        let Some(mut root) = scene_tree.get().get_root() else {
+            return;
+        };
+
+        let mut node: Gd<Node> = match window.kind {
+            GodotWindowKind::Window => {
+                let mut node = Window::new_alloc();
+                node.set_title(&window.title);
+                node.set_size(to_vector2i(window.size));
+                node.set_use_own_world_3d(window.own_world_3d);
+                let tx = sender.clone();
+                node.connect(
+                    "close_requested",
+                    &Callable::from_fn("window_close_requested", move |_| {
+                        tx.send(WindowCloseRequested { entity });
+                        Variant::nil()
+                    }),
+                );
+                node.upcast()
+            }
+            GodotWindowKind::SubViewport => {
+                let mut node = SubViewport::new_alloc();
+                node.set_size(to_vector2i(window.size));
+                node.set_update_mode(godot::classes::sub_viewport::UpdateMode::ALWAYS);
+                node.set_use_own_world_3d(window.own_world_3d);
+                node.upcast()
+            }
+        };
+        node.set_name(&format!("GodotWindow{entity}"));
+
+        if let Some(path) = &window.scene {
+            match godot
+                .singleton::<ResourceLoader>()
+                .load(path)
+                .and_then(|resource| resource.try_cast::<PackedScene>().ok())
+                .and_then(|packed| packed.instantiate())
+            {
+                Some(instance) => node.add_child(&instance),
+                None => warn!("GodotWindow: can't instantiate {path} for {entity}"),
+            }
+        }
+
+        root.add_child(&node);
+        commands.entity(entity).insert(GodotNodeHandle::new(node));
+    }
+}
+
+fn apply_window_changes(
+    changed: Query<(&GodotWindow, &GodotNodeHandle), Changed<GodotWindow>>,
+    mut godot: GodotAccess,
+) {
+    for (window, handle) in &changed {
+        if let Some(mut node) = godot.try_get::<Window>(*handle) {
+            node.set_title(&window.title);
+            node.set_size(to_vector2i(window.size));
+        } else if let Some(mut node) = godot.try_get::<SubViewport>(*handle) {
+            node.set_size(to_vector2i(window.size));
+        }
+    }
+}
+
+fn on_close_requested(
+    requested: On<WindowCloseRequested>,
+    windows: Query<&GodotWindow>,
+    mut commands: Commands,
+) {
+    if windows
+        .get(requested.entity)
+        .is_ok_and(|window| window.close_on_request)
+    {
+        commands.entity(requested.entity).despawn();
+    }
+}
+
+/// Dropping the handle frees the node (see the core plugin's removal observer).
+fn on_window_removed(removed: On<Remove, GodotWindow>, mut commands: Commands) {
+    if let Ok(mut entity) = commands.get_entity(removed.entity) {
+        entity.remove::<GodotNodeHandle>();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_ecs::world::World;
+
+    #[test]
+    fn close_requests_despawn_unless_kept_open() {
+        let mut world = World::new();
+        world.add_observer(on_close_requested);
+        let closing = world
+            .spawn(GodotWindow::window("Tool", UVec2::new(320, 240)))
+            .id();
+        let kept = world
+            .spawn(GodotWindow::window("Tool", UVec2::new(320, 240)).keep_open())
+            .id();
+
+        world.trigger(WindowCloseRequested { entity: closing });
+        world.trigger(WindowCloseRequested { entity: kept });
+        world.flush();
+
+        assert!(world.get_entity(closing).is_err());
+        assert!(world.get_entity(kept).is_ok());
+    }
+}
@@ -1,45 +1,161 @@
 pub use crate::GodotPlugin;
 pub use crate::interop::*;
 pub use crate::node_tree_view::{NodeTreeView, NodeTreeViewError};
+#[cfg(feature = "trace_tracy")]
+pub use crate::plugins::core::GodotLookupStats;
 #[cfg(feature = "godot_bevy_log")]
-pub use crate::plugins::godot_bevy_logger::GodotBevyLogPlugin;
+pub use crate::plugins::godot_bevy_logger::{GodotBevyLogPlugin, GodotLogBuffer, GodotLogFilters};
+#[cfg(feature = "multiplayer")]
+pub use crate::plugins::multiplayer::{
+    AddLateJoinAppExt, AddPredictionAppExt, AddRpcAppExt, ConnectedToServer, ConnectionFailed,
+    GodotMultiplayerPlugin, HasAuthority, LateJoin, LateJoinCompleted, LateJoinProgress,
+    LateJoinSnapshotSent, MultiplayerAuthority, MultiplayerDespawned, MultiplayerSpawned, NetStats,
+    NetworkCommand, NetworkOwner, NetworkState, PeerConnected, PeerDisconnected, PeerId, Predicted,
+    PredictedInput, Rollback, RpcReceived, RpcTarget, RpcTransfer, SendRpc, ServerAuthoritative,
+    ServerDisconnected, has_authority, resimulating,
+};
+#[cfg(feature = "navigation")]
+pub use crate::plugins::navigation::{
+    DynamicObstacle, GodotNavigationPlugin, NavAgent, NavTarget, NavTargetReached, NavVelocity,
+    NavigationFinished, NextPathPosition, PathUpdated, SafeVelocity,
+};
+#[cfg(feature = "editor_badges")]
+pub use crate::plugins::node_badges::GodotNodeBadgesPlugin;
+#[cfg(not(feature = "api-4-2"))]
+pub use crate::plugins::ragdoll::{
+    ActivateRagdoll, DeactivateRagdoll, GodotRagdollPlugin, RagdollActivated, RagdollDeactivated,
+    Ragdolling,
+};
+#[cfg(feature = "persistence")]
+pub use crate::plugins::region_persistence::{
+    AddRegionComponentAppExt, Persistent, Region, RegionLoaded, RegionPersistence,
+    RegionPersistencePlugin, RegionSaved, SaveRegions,
+};
+#[cfg(not(feature = "api-4-2"))]
+pub use crate::plugins::tilemap::{GodotTileMapPlugin, TileCell, TileCellsChanged, TileMapAccess};
 pub use crate::plugins::{
     GodotCorePlugins,
     GodotDefaultPlugins,
+    app_signals::{AddStateSignalAppExt, ExposeEventAppExt},
+    area_physics::{
+        AreaDampOverride, AreaGravityOverride, AreaOverrideMode, EnteredArea, ExitedArea,
+        GodotAreaPhysicsPlugin,
+    },
     assets::{GodotAssetsPlugin, GodotResource},
     audio::{
-        Audio, AudioApp, AudioChannel, AudioChannelMarker, AudioEasing, AudioError, AudioOutput,
-        AudioPlayerType, AudioSettings, AudioTween, GodotAudioChannels, GodotAudioPlugin,
-        MainAudioTrack, PlayAudioCommand, SoundId,
+        Audio, AudioApp, AudioChannel, AudioChannelMarker, AudioEasing, AudioError, AudioListener,
+        AudioOutput, AudioPlayerType, AudioSettings, AudioTween, GodotAudioChannels,
+        GodotAudioPlugin, MainAudioTrack, PlayAudioCommand, SoundId, SplitScreenPanning,
+    },
+    between_frames::{
+        BetweenFrames, BetweenFramesPlugin, MainThreadCommandError, MainThreadCommands,
+    },
+    camera::{ActiveCamera, CameraFollow, CameraShake, CameraZoom, GodotCameraPlugin},
+    chunk_streaming::{
+        ChunkLoaded, ChunkMember, ChunkStreamingPlugin, ChunkStreamingPolicy, ChunkUnloaded,
+        DistancePolicy, StreamingChunk, StreamingFocus, StreamingPolicy,
     },
     collisions::{
         AREA_ENTERED, AREA_EXITED, BODY_ENTERED, BODY_EXITED, COLLISION_START_SIGNALS,
         CollisionEnded, CollisionStarted, Collisions, GodotCollisionsPlugin,
     },
-    core::FindEntityByNameExt,
+    console::{GodotConsole, GodotConsolePlugin},
+    core::{FindEntityByNameExt, GodotSyncSet, PluginToggles, ScheduleTimings, SyncStats},
     // Debugger
     debugger::{DebuggerConfig, GodotDebuggerPlugin},
+    deferred_calls::{DeferredCallSet, DeferredFlush, GodotDeferredCalls},
+    determinism::{
+        DeterminismAudit, DeterminismAuditAppExt, DeterminismAuditPlugin, DeterminismDivergence,
+        DeterminismLog, DeterminismPlugin, DeterministicRng, hash_transform,
+    },
     // Event bridge
     event_bridge::{AddGodotEventAppExt, EventBridgeSet, GodotEventSender, send_event},
+    frame_budget::{FrameBudget, FrameBudgetExceeded, FrameBudgetPlugin},
+    gridmap::{GodotGridMapPlugin, GridCell, GridCellsChanged, GridMapAccess},
+    http::{
+        GodotHttpClient, GodotHttpPlugin, HttpError, HttpReply, HttpRequestId, HttpRequestSpec,
+        HttpResponse,
+    },
     // Collisions
     input::{
-        Action, ActionInput, BevyInputBridgePlugin, GodotActions, GodotActionsPlugin,
-        GodotInputEventPlugin, GodotInputSet, GodotKeyboardInput, GodotMouseButtonInput,
-        GodotMouseMotion,
+        Action, ActionAxisAppExt, ActionBinding, ActionInput, ActionSetAppExt, AxisTuning,
+        BevyInputBridgePlugin, GamepadFamily, GodotActionAxis, GodotActionSet, GodotActions,
+        GodotActionsPlugin, GodotInputEventPlugin, GodotInputReactPlugin, GodotInputSet,
+        GodotKeyboardInput, GodotMouseButtonInput, GodotMouseMotion, InputReact, InputTuning,
+        key_display_name,
     },
+    layers::{GodotLayerNamesPlugin, LayerNames, LayerTable, UnknownLayer},
+    meta_sync::{GodotMetaSyncAppExt, GodotMetaSyncPlugin, ReflectSyncToGodotMeta},
+    metrics::{ExposeResourceAppExt, GodotMetricsPlugin},
+    multimesh::{GodotMultiMeshPlugin, InMultiMesh, MultiMeshBatch, MultiMeshColor, MultiMeshKind},
+    node_pool::{HelperNodePool, HelperPoolMetrics},
     // Core functionality
-    packed_scene::{GodotPackedScenePlugin, GodotScene},
+    packed_scene::{
+        GodotPackedScenePlugin, GodotScene, GodotScenePrewarm, PrewarmGodotSceneAppExt,
+    },
+    particles::{GodotParticlesPlugin, ParticleCommand, ParticleEmitter, ParticleParam},
+    physics_material::{GodotPhysicsMaterialPlugin, PhysicsMaterialOverride},
+    platformer::{
+        Platformer2DControllerPlugin, PlatformerActions, PlatformerController2D, PlatformerInput,
+        PlatformerJumped, PlatformerSet, PlatformerState,
+    },
+    presence::{
+        GodotPresence, GodotPresencePlugin, OnScreen, PresenceKind, PresenceScreenEntered,
+        PresenceScreenExited, PresenceVisibility,
+    },
+    remote_inspector::RemoteInspectorPlugin,
+    resource_bridge::{BridgeResourceAppExt, BridgeResourceSet, GodotResourceBridgePlugin},
+    rich_text::{BbCode, GodotRichTextPlugin, TypewriterFinished, TypewriterText, escape_bbcode},
+    scene_manager::{
+        ChangeSceneRequest, SceneChangeFailed, SceneChangeFinished, SceneChangeStarted,
+        SceneManager, SceneManagerPlugin, SceneScoped, SceneTarget, SceneTransitionAppExt,
+        SceneTransitionConfig, SceneTransitionEffect, SceneTransitionState,
+    },
+    scene_preview::{
+        PreviewScene, ScenePreviewCaptured, ScenePreviewError, ScenePreviewId, ScenePreviewPlugin,
+        ScenePreviewRequest, ScenePreviews,
+    },
     // Input
     scene_tree::{
-        AutoSyncBundleRegistry, GodotChildOf, GodotChildren, GodotRequiredComponents,
-        GodotSceneTreePlugin, Groups, NodeEntityIndex, SceneTreeConfig, SceneTreeRef,
+        AutoRegisteredType, AutoSyncBundleRegistry, DanglingEntityReaped, EntityByPath,
+        GodotChildOf, GodotChildren, GodotDespawnExt, GodotMoveToIndexExt, GodotNodeIcon,
+        GodotReparentExt, GodotRequiredComponents, GodotSceneTreePlugin, GodotTreeOrderPlugin,
+        Groups, HandleRebound, NodeAdded, NodeEntityIndex, NodeReaperConfig, NodeRemoved,
+        NodeRenamed, RebindByPath, SceneTreeConfig, SceneTreeOrder, SceneTreeRef, SiblingIndex,
+        TagIndex, Tags, TreeDepth,
+    },
+    script_calls::{GodotCall, ScriptCallCompleted, ScriptCallError, ScriptCallId},
+    signals::{DeferredSignalConnections, GodotSignals, GodotSignalsPlugin, SignalConnectOptions},
+    soft_body::{
+        GodotSoftBodyPlugin, SampleSoftBodyVertices, SoftBodyPin, SoftBodyPins, SoftBodySimulation,
+        SoftBodyVertices,
+    },
+    split_screen::{
+        PlayerGamepadAxis, PlayerGamepadButton, SplitScreenLayout, SplitScreenPlayer,
+        SplitScreenPlugin, SplitScreenSettings,
+    },
+    theme::{GodotThemePlugin, ThemeTypeStyle, UiStyle, UiStyleChanged},
+    timer::{
+        GodotTimer, GodotTimerPlugin, SpawnGodotTimerExt, TimerCommand, TimerFinished, TimerSpawn,
     },
-    signals::{DeferredSignalConnections, GodotSignals, GodotSignalsPlugin},
     // Scene tree
     transforms::{
-        DisableGodotTransformRead, GodotTransformConfig, GodotTransformSyncPlugin,
-        GodotTransformSyncPluginExt, NO_TRANSFORM_READ_GROUP, TransformSyncMetadata,
-        TransformSyncMode, add_transform_sync_systems,
+        DisableGodotTransformRead, GlobalTransformSync, GodotTransformConfig,
+        GodotTransformSyncPlugin, GodotTransformSyncPluginExt, NO_TRANSFORM_READ_GROUP,
+        QuantizedTransform, SyncThreshold, SyncTransform, TransformQuantization,
+        TransformSyncMetadata, TransformSyncMode, add_transform_sync_systems,
+    },
+    tween::{GodotTweenPlugin, TweenEasing, TweenFinished, TweenProperty, TweenValue},
+    ui_binding::{NumberFormat, NumberSeparators, UiBindingAppExt},
+    ui_events::{
+        ButtonPressed, ButtonToggled, FocusCommand, FocusEntered, FocusExited, Focused,
+        GodotUiEventsPlugin, ItemSelected, TextChanged, ValueChanged,
+    },
+    ui_layers::{GodotUiLayersPlugin, UiLayer, UiLayers},
+    user_files::{UserFileChanged, UserFileWatcherPlugin},
+    vehicle::{GodotVehiclePlugin, VehicleControls, VehicleWheelState},
+    window::{
+        GodotWindow, GodotWindowKind, GodotWindowPlugin, GodotWindowSettings, WindowCloseRequested,
     },
 };
 pub use bevy_ecs::prelude as bevy_ecs_prelude;
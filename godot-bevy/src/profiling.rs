@@ -10,7 +10,7 @@ use once_cell::sync::Lazy;
 /// Tracy client instance (only exists when tracy feature is enabled)
 #[cfg(feature = "trace_tracy")]
 static TRACY_CLIENT: Lazy<tracing_tracy::client::Client> =
-    Lazy::new(|| tracing_tracy::client::Client::start());
+    Lazy::new(tracing_tracy::client::Client::start);
 
 /// Initialize the profiling system
 /// Called by the #[bevy_app] macro during library initialization
@@ -100,6 +100,65 @@ pub fn secondary_frame_mark(name: &str) {
     }
 }
 
+/// Plot the frame's bridge counters in Tracy, next to the spans.
+#[cfg(feature = "trace_tracy")]
+pub(crate) fn plot_sync_stats(stats: &crate::plugins::core::SyncStats) {
+    use tracing_tracy::client::plot_name;
+    let plots = [
+        (plot_name!("godot_bevy/nodes_added"), stats.nodes_added),
+        (plot_name!("godot_bevy/nodes_removed"), stats.nodes_removed),
+        (
+            plot_name!("godot_bevy/transforms_read"),
+            stats.transforms_read,
+        ),
+        (
+            plot_name!("godot_bevy/transforms_written"),
+            stats.transforms_written,
+        ),
+        (
+            plot_name!("godot_bevy/signals_forwarded"),
+            stats.signals_forwarded,
+        ),
+        (
+            plot_name!("godot_bevy/inputs_drained"),
+            stats.inputs_drained,
+        ),
+        (
+            plot_name!("godot_bevy/deferred_commands"),
+            stats.deferred_commands,
+        ),
+    ];
+    for (name, count) in plots {
+        TRACY_CLIENT.plot(name, f64::from(count));
+    }
+}
+
+/// Plot the frame's total Godot lookups, and the counts of the systems making
+/// the most, in Tracy.
+#[cfg(feature = "trace_tracy")]
+pub(crate) fn plot_godot_lookups(lookups: &[(String, u32)], top: usize) {
+    use tracing_tracy::client::{PlotName, plot_name};
+
+    // Tracy wants plot names for the life of the program; each system's is
+    // leaked once.
+    static NAMES: std::sync::Mutex<Option<std::collections::HashMap<String, PlotName>>> =
+        std::sync::Mutex::new(None);
+
+    let total: u32 = lookups.iter().map(|(_, count)| count).sum();
+    TRACY_CLIENT.plot(plot_name!("godot_bevy/godot_lookups"), f64::from(total));
+
+    let mut names = NAMES
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    let names = names.get_or_insert_with(Default::default);
+    for (system, count) in lookups.iter().take(top) {
+        let name = *names
+            .entry(system.clone())
+            .or_insert_with(|| PlotName::new_leak(format!("godot_bevy/godot_lookups/{system}")));
+        TRACY_CLIENT.plot(name, f64::from(*count));
+    }
+}
+
 /// Check if profiler is running
 #[inline]
 pub fn is_profiler_running() -> bool {
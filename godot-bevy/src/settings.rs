@@ -0,0 +1,120 @@
+//! Integration settings edited in Godot, without recompiling.
+//!
+//! Create a `GodotBevySettings` resource in the FileSystem dock (*New Resource…*),
+//! save it as a `.tres`, and assign it to the `settings` property of the
+//! `BevyApp` node (`bevy_app_singleton.tscn` for the autoload). Once the app is
+//! built, its values override what the Rust code set up:
+//!
+//! - `auto_despawn_children` sets [`SceneTreeConfig`].
+//! - `transform_sync_mode` sets [`GodotTransformConfig::sync_mode`], when the
+//!   app has `GodotTransformSyncPlugin`.
+//! - `collisions`, `audio` and `transform_sync` set [`PluginToggles`].
+//! - `log_levels` maps a module path to a level (`"trace"` to `"error"`, or
+//!   `"off"`) in `GodotLogFilters`, with the `godot_bevy_log` feature.
+//!
+//! Systems can still change the resources afterwards; the settings are only
+//! applied once, at startup.
+
+use bevy_app::App;
+use godot::prelude::*;
+
+use crate::plugins::core::PluginToggles;
+#[cfg(feature = "godot_bevy_log")]
+use crate::plugins::godot_bevy_logger::GodotLogFilters;
+use crate::plugins::scene_tree::SceneTreeConfig;
+use crate::plugins::transforms::{GodotTransformConfig, TransformSyncMode};
+
+/// godot-bevy settings for the editor. See the [module docs](self).
+#[derive(GodotClass)]
+#[class(tool, base=Resource)]
+pub struct GodotBevySettings {
+    base: Base<Resource>,
+    /// Despawn an entity's Godot children along with it.
+    #[export]
+    auto_despawn_children: bool,
+    #[export(enum = (Disabled, OneWay, TwoWay))]
+    transform_sync_mode: i32,
+    #[export]
+    collisions: bool,
+    #[export]
+    audio: bool,
+    #[export]
+    transform_sync: bool,
+    /// Module path to log level, e.g. `{"my_game::ai": "warn"}`.
+    #[export]
+    log_levels: VarDictionary,
+}
+
+#[godot_api]
+impl IResource for GodotBevySettings {
+    fn init(base: Base<Resource>) -> Self {
+        Self {
+            base,
+            auto_despawn_children: true,
+            transform_sync_mode: 1,
+            collisions: true,
+            audio: true,
+            transform_sync: true,
+            log_levels: VarDictionary::new(),
+        }
+    }
+}
+
+impl GodotBevySettings {
+    /// Override the built app's configuration. Called by `BevyApp`.
+    pub(crate) fn apply(&self, app: &mut App) {
+        let world = app.world_mut();
+        if let Some(mut config) = world.get_resource_mut::<SceneTreeConfig>() {
+            config.auto_despawn_children = self.auto_despawn_children;
+        }
+        if let Some(mut config) = world.get_resource_mut::<GodotTransformConfig>() {
+            config.sync_mode = sync_mode_from_ord(self.transform_sync_mode);
+        }
+        if let Some(mut toggles) = world.get_resource_mut::<PluginToggles>() {
+            *toggles = PluginToggles {
+                collisions: self.collisions,
+                audio: self.audio,
+                transform_sync: self.transform_sync,
+            };
+        }
+
+        #[cfg(feature = "godot_bevy_log")]
+        if let Some(filters) = world.get_resource::<GodotLogFilters>() {
+            use bevy_log::tracing_subscriber::filter::LevelFilter;
+
+            for (module, level) in self.log_levels.iter_shared() {
+                match level.to_string().parse::<LevelFilter>() {
+                    Ok(level) => filters.set(module.to_string(), level),
+                    Err(_) => {
+                        godot_warn!(
+                            "GodotBevySettings: log level {level} for {module} isn't one of \
+                             trace, debug, info, warn, error or off"
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// The `transform_sync_mode` enum, in the order the inspector lists it.
+fn sync_mode_from_ord(ord: i32) -> TransformSyncMode {
+    match ord {
+        0 => TransformSyncMode::Disabled,
+        2 => TransformSyncMode::TwoWay,
+        _ => TransformSyncMode::OneWay,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sync_modes_follow_the_inspector_order() {
+        assert_eq!(sync_mode_from_ord(0), TransformSyncMode::Disabled);
+        assert_eq!(sync_mode_from_ord(1), TransformSyncMode::OneWay);
+        assert_eq!(sync_mode_from_ord(2), TransformSyncMode::TwoWay);
+        assert_eq!(sync_mode_from_ord(7), TransformSyncMode::OneWay);
+    }
+}
@@ -1,7 +1,7 @@
 #[cfg(test)]
 mod tests {
     use crate::plugins::scene_tree::{
-        GodotChildOf, GodotChildren, ProtectedNodeEntity, SceneTreeConfig,
+        GodotChildOf, GodotChildren, GodotReparentExt, ProtectedNodeEntity, SceneTreeConfig,
     };
     use bevy_ecs::entity::Entity;
     use bevy_ecs::prelude::World;
@@ -83,4 +83,20 @@ mod tests {
         assert!(world.get_entity(child_unprotected).is_err());
         assert!(world.get_entity(child_protected).is_ok());
     }
+
+    #[test]
+    fn test_reparent_godot_without_nodes_keeps_relationship() {
+        let mut world = World::new();
+        let old_parent = world.spawn_empty().id();
+        let new_parent = world.spawn_empty().id();
+        let child = world.spawn(GodotChildOf(old_parent)).id();
+
+        world.commands().entity(child).reparent_godot(new_parent);
+        world.flush();
+
+        assert_eq!(
+            world.get::<GodotChildOf>(child),
+            Some(&GodotChildOf(old_parent))
+        );
+    }
 }
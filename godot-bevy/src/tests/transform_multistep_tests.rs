@@ -34,7 +34,8 @@ use crate::plugins::fixed_schedule::{
 };
 use crate::plugins::transforms::sync_systems::{merge_godot_into_bevy, write_needed};
 use crate::plugins::transforms::{
-    GodotTransformConfig, GodotTransformSyncPlugin, TransformSyncMetadata, TransformSyncMode,
+    GlobalTransformSync, GodotTransformConfig, GodotTransformSyncPlugin, SyncThreshold,
+    TransformSyncMetadata, TransformSyncMode,
 };
 
 const DT: Duration = Duration::from_nanos(16_666_667); // ~1/60 s
@@ -97,7 +98,7 @@ fn write_stub(
     mut q: Query<(&Transform, &mut GodotNode, &mut TransformSyncMetadata), Changed<Transform>>,
 ) {
     for (t, mut node, mut meta) in q.iter_mut() {
-        if write_needed(t, &meta.shadow) {
+        if write_needed(t, &meta.shadow, &SyncThreshold::default()) {
             node.0 = *t;
             meta.shadow = *t;
         }
@@ -121,7 +122,10 @@ fn wired_app(mode: TransformSyncMode, with_fixed_first: bool) -> (App, Entity) {
     let mut app = App::new();
     app.add_plugins(TimePlugin);
     host_fixed_main_loop(&mut app);
-    app.insert_resource(GodotTransformConfig { sync_mode: mode });
+    app.insert_resource(GodotTransformConfig {
+        sync_mode: mode,
+        ..Default::default()
+    });
     app.init_resource::<ReadCount>();
 
     app.add_systems(
@@ -426,6 +430,7 @@ fn read_registered_in_preupdate_and_fixedfirst_write_in_fixedlast() {
     app.add_plugins(GodotTransformSyncPlugin {
         sync_mode: TransformSyncMode::TwoWay,
         auto_sync: true,
+        ..Default::default()
     });
     // Ensure the schedules exist so a missing registration is a clean assertion
     // failure rather than an `expect` panic on a never-created schedule.
@@ -446,3 +451,35 @@ fn read_registered_in_preupdate_and_fixedfirst_write_in_fixedlast() {
         "Bevy->Godot write must be registered in FixedLast"
     );
 }
+
+/// The global-transform read sits next to the local read in both schedules, and
+/// its write comes after the local write in `FixedLast`.
+#[test]
+fn global_transform_sync_registered_next_to_local_sync() {
+    let mut app = App::new();
+    app.add_plugins(TimePlugin);
+    host_fixed_main_loop(&mut app);
+    app.add_plugins(GodotTransformSyncPlugin {
+        global_transform: GlobalTransformSync::ReadWrite,
+        ..Default::default()
+    });
+    app.init_schedule(PreUpdate);
+    app.init_schedule(FixedFirst);
+    app.init_schedule(FixedLast);
+
+    assert!(schedule_has_system(
+        &mut app,
+        PreUpdate,
+        "read_godot_global_transforms"
+    ));
+    assert!(schedule_has_system(
+        &mut app,
+        FixedFirst,
+        "read_godot_global_transforms"
+    ));
+    assert!(schedule_has_system(
+        &mut app,
+        FixedLast,
+        "write_godot_global_transforms"
+    ));
+}
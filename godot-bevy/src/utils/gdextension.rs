@@ -0,0 +1,243 @@
+//! Generating `.gdextension` files.
+//!
+//! The editor addon scaffolds a desktop-only `rust.gdextension`. Projects that also
+//! export to the web or to mobile can generate the file from a build script instead,
+//! so the library paths follow the crate name and target directory:
+//!
+//! ```ignore
+//! // build.rs
+//! use godot_bevy::utils::{GdExtensionFile, WebThreads};
+//!
+//! fn main() {
+//!     GdExtensionFile::new(env!("CARGO_PKG_NAME"))
+//!         .compatibility_minimum("4.3")
+//!         .web(WebThreads::Both)
+//!         .android()
+//!         .ios()
+//!         .write("../godot/rust.gdextension")
+//!         .expect("failed to write rust.gdextension");
+//! }
+//! ```
+//!
+//! Web builds are named `<name>.wasm` (threads) and `<name>.nothreads.wasm`; both
+//! come out of `wasm32-unknown-emscripten` as `<name>.wasm`, so copy the
+//! no-threads build to its own name after building it.
+
+use std::fmt::Write as _;
+use std::io;
+use std::path::Path;
+
+/// Which web builds a [`GdExtensionFile`] lists. Godot picks the `threads`
+/// entry when the export has thread support enabled, the other one otherwise.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WebThreads {
+    Threads,
+    NoThreads,
+    Both,
+}
+
+/// Builder for the contents of a `.gdextension` file. Desktop entries (Linux,
+/// Windows, macOS) are always listed; web, Android and iOS are opt-in.
+#[derive(Debug, Clone)]
+pub struct GdExtensionFile {
+    library_name: String,
+    target_dir: String,
+    entry_symbol: String,
+    compatibility_minimum: String,
+    reloadable: bool,
+    web: Option<WebThreads>,
+    android: bool,
+    ios: bool,
+}
+
+impl GdExtensionFile {
+    /// `library_name` is the crate name; dashes become underscores, as cargo
+    /// does for the built library.
+    pub fn new(library_name: impl Into<String>) -> Self {
+        Self {
+            library_name: library_name.into().replace('-', "_"),
+            target_dir: "res://rust/target".to_string(),
+            entry_symbol: "gdext_rust_init".to_string(),
+            compatibility_minimum: "4.1".to_string(),
+            reloadable: true,
+            web: None,
+            android: false,
+            ios: false,
+        }
+    }
+
+    /// Cargo's target directory as a Godot path. Defaults to `res://rust/target`.
+    pub fn target_dir(mut self, target_dir: impl Into<String>) -> Self {
+        self.target_dir = target_dir.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Defaults to `gdext_rust_init`, the symbol `#[gdextension]` exports.
+    pub fn entry_symbol(mut self, entry_symbol: impl Into<String>) -> Self {
+        self.entry_symbol = entry_symbol.into();
+        self
+    }
+
+    /// The oldest Godot version that may load the extension. Defaults to `4.1`.
+    pub fn compatibility_minimum(mut self, version: impl Into<String>) -> Self {
+        self.compatibility_minimum = version.into();
+        self
+    }
+
+    /// Whether the editor hot-reloads the library when it's rebuilt. Defaults to `true`.
+    pub fn reloadable(mut self, reloadable: bool) -> Self {
+        self.reloadable = reloadable;
+        self
+    }
+
+    /// List `wasm32-unknown-emscripten` builds.
+    pub fn web(mut self, threads: WebThreads) -> Self {
+        self.web = Some(threads);
+        self
+    }
+
+    /// List `aarch64-linux-android` and `x86_64-linux-android` builds.
+    pub fn android(mut self) -> Self {
+        self.android = true;
+        self
+    }
+
+    /// List `aarch64-apple-ios` builds.
+    pub fn ios(mut self) -> Self {
+        self.ios = true;
+        self
+    }
+
+    /// The file contents.
+    pub fn build(&self) -> String {
+        let mut out = String::new();
+        let _ = write!(
+            out,
+            "[configuration]\nentry_symbol = \"{}\"\ncompatibility_minimum = {}\nreloadable = {}\n\n[libraries]\n",
+            self.entry_symbol, self.compatibility_minimum, self.reloadable
+        );
+
+        let name = &self.library_name;
+        // (key without the profile, target triple, library file)
+        let mut libraries = vec![
+            ("linux.{}.x86_64", None, format!("lib{name}.so")),
+            ("windows.{}.x86_64", None, format!("{name}.dll")),
+            ("macos.{}", None, format!("lib{name}.dylib")),
+            ("macos.{}.arm64", None, format!("lib{name}.dylib")),
+        ];
+        let web = Some("wasm32-unknown-emscripten");
+        if matches!(self.web, Some(WebThreads::Threads | WebThreads::Both)) {
+            libraries.push(("web.{}.threads.wasm32", web, format!("{name}.wasm")));
+        }
+        if matches!(self.web, Some(WebThreads::NoThreads | WebThreads::Both)) {
+            libraries.push(("web.{}.wasm32", web, format!("{name}.nothreads.wasm")));
+        }
+        if self.android {
+            libraries.push((
+                "android.{}.arm64",
+                Some("aarch64-linux-android"),
+                format!("lib{name}.so"),
+            ));
+            libraries.push((
+                "android.{}.x86_64",
+                Some("x86_64-linux-android"),
+                format!("lib{name}.so"),
+            ));
+        }
+        if self.ios {
+            libraries.push((
+                "ios.{}",
+                Some("aarch64-apple-ios"),
+                format!("lib{name}.dylib"),
+            ));
+        }
+
+        for (key, target, file) in &libraries {
+            for profile in ["debug", "release"] {
+                let key = key.replace("{}", profile);
+                let dir = match target {
+                    Some(target) => format!("{}/{target}/{profile}", self.target_dir),
+                    None => format!("{}/{profile}", self.target_dir),
+                };
+                let _ = writeln!(out, "{key} = \"{dir}/{file}\"");
+            }
+        }
+
+        out
+    }
+
+    /// Write [`build`](Self::build) to `path`, leaving the file untouched when the
+    /// contents haven't changed so the editor doesn't reload the extension.
+    pub fn write(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let path = path.as_ref();
+        let contents = self.build();
+        if std::fs::read_to_string(path).is_ok_and(|existing| existing == contents) {
+            return Ok(());
+        }
+        std::fs::write(path, contents)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn desktop_entries_match_the_scaffolded_file() {
+        let file = GdExtensionFile::new("my-game").build();
+        assert!(file.starts_with(
+            "[configuration]\nentry_symbol = \"gdext_rust_init\"\ncompatibility_minimum = 4.1\nreloadable = true\n"
+        ));
+        assert!(file.contains("linux.debug.x86_64 = \"res://rust/target/debug/libmy_game.so\"\n"));
+        assert!(
+            file.contains("windows.release.x86_64 = \"res://rust/target/release/my_game.dll\"\n")
+        );
+        assert!(
+            file.contains("macos.release.arm64 = \"res://rust/target/release/libmy_game.dylib\"\n")
+        );
+        assert!(!file.contains("web.") && !file.contains("android.") && !file.contains("ios."));
+    }
+
+    #[test]
+    fn optional_platforms_use_their_target_dirs() {
+        let file = GdExtensionFile::new("game")
+            .target_dir("res://../rust/target/")
+            .compatibility_minimum("4.3")
+            .reloadable(false)
+            .web(WebThreads::Both)
+            .android()
+            .ios()
+            .build();
+        assert!(file.contains("compatibility_minimum = 4.3\nreloadable = false\n"));
+        assert!(file.contains(
+            "web.debug.threads.wasm32 = \"res://../rust/target/wasm32-unknown-emscripten/debug/game.wasm\"\n"
+        ));
+        assert!(file.contains(
+            "web.release.wasm32 = \"res://../rust/target/wasm32-unknown-emscripten/release/game.nothreads.wasm\"\n"
+        ));
+        assert!(file.contains(
+            "android.debug.arm64 = \"res://../rust/target/aarch64-linux-android/debug/libgame.so\"\n"
+        ));
+        assert!(file.contains(
+            "android.release.x86_64 = \"res://../rust/target/x86_64-linux-android/release/libgame.so\"\n"
+        ));
+        assert!(file.contains(
+            "ios.release = \"res://../rust/target/aarch64-apple-ios/release/libgame.dylib\"\n"
+        ));
+    }
+
+    #[test]
+    fn web_threads_selects_the_variants() {
+        let threads = GdExtensionFile::new("game")
+            .web(WebThreads::Threads)
+            .build();
+        assert!(threads.contains("web.debug.threads.wasm32"));
+        assert!(!threads.contains("nothreads"));
+
+        let no_threads = GdExtensionFile::new("game")
+            .web(WebThreads::NoThreads)
+            .build();
+        assert!(!no_threads.contains(".threads."));
+        assert!(no_threads.contains("web.debug.wasm32"));
+    }
+}
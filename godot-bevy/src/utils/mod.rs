@@ -5,10 +5,14 @@
 //! within their respective domains (e.g., audio validation in audio module).
 
 pub mod debug;
+pub mod gdextension;
 pub mod math;
 
 // Re-export commonly used shared functions
 pub use math::{clamp_to_range, is_reasonable_float, lerp, move_toward, normalize_angle};
 
+// Re-export the .gdextension builder
+pub use gdextension::{GdExtensionFile, WebThreads};
+
 // Re-export debug functions
 pub use debug::{print_scene_tree, print_tree_structure};
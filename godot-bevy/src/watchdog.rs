@@ -0,0 +1,213 @@
+//! Startup checks for common misconfigurations, reported with what to change
+//! instead of surfacing later as systems that silently do nothing.
+//!
+//! - A second `BevyApp` node running the same app (`#[bevy_app]`, or one named
+//!   app) is refused: two copies of one world would fight over every node.
+//! - A plugin added twice still panics, as in Bevy, but first names the usual cause.
+//! - In dev builds, a component whose plugin was never added is reported the
+//!   first time it shows up on an entity.
+
+use std::any::Any;
+use std::sync::Mutex;
+
+#[cfg(debug_assertions)]
+use bevy_app::{App, Last, Plugin};
+#[cfg(debug_assertions)]
+use bevy_ecs::{
+    component::{Component, ComponentId},
+    entity::Entity,
+    prelude::Resource,
+    query::{QueryBuilder, QueryState},
+    world::{Mut, World},
+};
+use godot::global::godot_error;
+use godot::obj::{Gd, InstanceId};
+
+use crate::app::BevyApp;
+
+/// The `BevyApp` node running each app, by `app_name` (empty for `#[bevy_app]`).
+static APP_NODES: Mutex<Vec<(String, InstanceId)>> = Mutex::new(Vec::new());
+
+/// Let `node` run the app named `app_name`, unless another live `BevyApp`
+/// already does.
+pub(crate) fn claim_app(app_name: &str, node: &Gd<BevyApp>) -> bool {
+    let mut owners = APP_NODES.lock().unwrap_or_else(|e| e.into_inner());
+    if let Some((_, id)) = owners.iter().find(|(name, _)| name == app_name)
+        && *id != node.instance_id()
+        && let Ok(running) = Gd::<BevyApp>::try_from_instance_id(*id)
+    {
+        if app_name.is_empty() {
+            godot_error!(
+                "[godot-bevy] A second BevyApp node at {} was not started: {} already runs the app. \
+                 BevyApp belongs in one place, the BevyAppSingleton autoload; remove the BevyApp \
+                 node from your scenes, or give it an app_name to run a #[bevy_app(name = ...)] app.",
+                node.get_path(),
+                running.get_path(),
+            );
+        } else {
+            godot_error!(
+                "[godot-bevy] A second BevyApp node at {} was not started: {} already runs the \
+                 app named \"{app_name}\".",
+                node.get_path(),
+                running.get_path(),
+            );
+        }
+        return false;
+    }
+    owners.retain(|(name, _)| name != app_name);
+    owners.push((app_name.to_owned(), node.instance_id()));
+    true
+}
+
+/// Name the usual cause of Bevy's duplicate plugin panic, which escapes `ready`
+/// without saying how to fix it.
+pub(crate) fn explain_build_panic(payload: &(dyn Any + Send)) {
+    let message = payload
+        .downcast_ref::<String>()
+        .map(String::as_str)
+        .or_else(|| payload.downcast_ref::<&str>().copied())
+        .unwrap_or_default();
+    if let Some(hint) = duplicate_plugin_hint(message) {
+        godot_error!("[godot-bevy] {message}. {hint}");
+    }
+}
+
+fn duplicate_plugin_hint(message: &str) -> Option<&'static str> {
+    if !message.contains("plugin was already added") {
+        return None;
+    }
+    Some(
+        if message.contains("GodotBaseCorePlugin") || message.contains("GodotSceneTreePlugin") {
+            "BevyApp adds GodotBaseCorePlugin and GodotSceneTreePlugin itself; \
+             don't add GodotPlugin or GodotCorePlugins in #[bevy_app]."
+        } else {
+            "To configure a plugin that is part of a group, replace it in the group instead \
+             of adding it again: GodotDefaultPlugins.set(GodotTransformSyncPlugin { .. })."
+        },
+    )
+}
+
+/// A component that does nothing without its plugin. Each plugin module submits
+/// its own with `inventory::submit!`; [`check_plugin_requirements`] collects them.
+#[cfg(debug_assertions)]
+pub(crate) struct PluginRequirement {
+    component: fn(&mut World) -> ComponentId,
+    plugin_added: fn(&App) -> bool,
+    component_name: fn() -> &'static str,
+    plugin_name: fn() -> &'static str,
+}
+
+#[cfg(debug_assertions)]
+impl PluginRequirement {
+    /// `C` needs `P` to be added.
+    pub(crate) const fn new<C: Component, P: Plugin>() -> Self {
+        Self {
+            component: World::register_component::<C>,
+            plugin_added: App::is_plugin_added::<P>,
+            component_name: short_name::<C>,
+            plugin_name: short_name::<P>,
+        }
+    }
+}
+
+#[cfg(debug_assertions)]
+crate::inventory::collect!(PluginRequirement);
+
+/// The requirements whose plugin is missing, each with a query for its
+/// component, until an entity with it is reported.
+#[cfg(debug_assertions)]
+#[derive(Resource)]
+struct MissingPlugins(Vec<(QueryState<Entity>, &'static PluginRequirement)>);
+
+/// Report components used without their plugin. Runs after the app is built.
+#[cfg(debug_assertions)]
+pub(crate) fn check_plugin_requirements(app: &mut App) {
+    let missing: Vec<_> = crate::inventory::iter::<PluginRequirement>()
+        .filter(|requirement| !(requirement.plugin_added)(app))
+        .collect();
+    if missing.is_empty() {
+        return;
+    }
+    let world = app.world_mut();
+    let missing = missing
+        .into_iter()
+        .map(|requirement| {
+            let component = (requirement.component)(world);
+            let query = QueryBuilder::<Entity>::new(world)
+                .with_id(component)
+                .build();
+            (query, requirement)
+        })
+        .collect();
+    app.insert_resource(MissingPlugins(missing))
+        .add_systems(Last, report_missing_plugins);
+}
+
+#[cfg(debug_assertions)]
+fn report_missing_plugins(world: &mut World) {
+    world.resource_scope(|world, mut missing: Mut<MissingPlugins>| {
+        missing.0.retain_mut(|(query, requirement)| {
+            let Some(entity) = query.iter(world).next() else {
+                return true;
+            };
+            let (component, plugin) = ((requirement.component_name)(), (requirement.plugin_name)());
+            godot_error!(
+                "[godot-bevy] {component} on {entity} does nothing without {plugin}. \
+                 Add it in #[bevy_app]: app.add_plugins({plugin}::default())."
+            );
+            false
+        });
+    });
+}
+
+#[cfg(debug_assertions)]
+fn short_name<T>() -> &'static str {
+    let name = std::any::type_name::<T>();
+    name.rsplit("::").next().unwrap_or(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn duplicate_plugin_panics_get_a_hint() {
+        let core = "Error adding plugin godot_bevy::plugins::core::GodotBaseCorePlugin in group \
+                    godot_bevy::plugins::GodotCorePlugins: plugin was already added in application";
+        assert!(
+            duplicate_plugin_hint(core)
+                .unwrap()
+                .contains("GodotCorePlugins")
+        );
+
+        let sync = "Error adding plugin godot_bevy::plugins::transforms::plugin::\
+                    GodotTransformSyncPlugin: : plugin was already added in application";
+        assert!(duplicate_plugin_hint(sync).unwrap().contains(".set("));
+
+        assert_eq!(duplicate_plugin_hint("index out of bounds"), None);
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    fn missing_plugins_are_collected_from_their_modules() {
+        let mut app = App::new();
+        check_plugin_requirements(&mut app);
+        let missing = app.world().resource::<MissingPlugins>();
+        let names: Vec<_> = missing
+            .0
+            .iter()
+            .map(|(_, requirement)| ((requirement.component_name)(), (requirement.plugin_name)()))
+            .collect();
+        assert!(names.contains(&("GodotTimer", "GodotTimerPlugin")));
+        assert!(names.contains(&("CameraZoom", "GodotCameraPlugin")));
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    fn short_names_drop_the_path() {
+        assert_eq!(
+            short_name::<crate::plugins::GodotCameraPlugin>(),
+            "GodotCameraPlugin"
+        );
+    }
+}
@@ -1,11 +1,12 @@
 use crossbeam_channel::Sender;
 use godot::classes::Node;
-use godot::obj::{Gd, InstanceId};
+use godot::obj::{Gd, Inherits, InstanceId};
 use godot::prelude::*;
 
 use crate::{
     interop::GodotNodeHandle,
     plugins::scene_tree::{SceneTreeMessage, SceneTreeMessageType},
+    plugins::transforms::conversions::bevy_transform_from_variant,
 };
 
 #[derive(GodotClass)]
@@ -45,6 +46,7 @@ impl SceneTreeWatcher {
                 parent_id: None,
                 collision_mask: None,
                 groups: None,
+                transform: None,
             });
         }
     }
@@ -65,6 +67,7 @@ impl SceneTreeWatcher {
                 parent_id: None,
                 collision_mask: None,
                 groups: None,
+                transform: None,
             });
         }
     }
@@ -105,6 +108,7 @@ impl SceneTreeWatcher {
                 parent_id,
                 collision_mask,
                 groups: None,
+                transform: None,
             });
         }
     }
@@ -120,6 +124,34 @@ impl SceneTreeWatcher {
         parent_id: i64,
         collision_mask: i64,
         groups: PackedStringArray,
+    ) {
+        self.scene_tree_event_typed_metadata_transform(
+            node,
+            message_type,
+            node_type,
+            node_name,
+            parent_id,
+            collision_mask,
+            groups,
+            Variant::nil(),
+        );
+    }
+
+    /// Like `scene_tree_event_typed_metadata_groups`, plus the local `transform`
+    /// of a `Node2D`/`Node3D` (`null` for other nodes), so the entity's
+    /// `Transform` is set without reading it back from the node.
+    #[func]
+    #[allow(clippy::too_many_arguments)] // FFI boundary function - arguments match GDScript call
+    pub fn scene_tree_event_typed_metadata_transform(
+        &self,
+        node: Gd<Node>,
+        message_type: SceneTreeMessageType,
+        node_type: String,
+        node_name: String,
+        parent_id: i64,
+        collision_mask: i64,
+        groups: PackedStringArray,
+        transform: Variant,
     ) {
         let node_type = if node_type.is_empty() {
             None
@@ -152,6 +184,7 @@ impl SceneTreeWatcher {
                 parent_id,
                 collision_mask,
                 groups: Some(groups),
+                transform: bevy_transform_from_variant(&transform),
             });
         }
     }
@@ -178,11 +211,22 @@ impl SceneTreeWatcher {
                 parent_id: None,
                 collision_mask: None,
                 groups: None,
+                transform: None,
             });
         }
     }
 }
 
+/// Node meta that keeps a node and its subtree out of the ECS mirror.
+pub(crate) const EXCLUDE_META: &str = "_bevy_exclude";
+
+/// Mark `node` (and everything added under it) as plumbing rather than game
+/// content, so it never gets an entity. Set it before adding the node to the tree.
+pub(crate) fn exclude_from_mirror<T: Inherits<Node>>(node: &mut Gd<T>) {
+    node.upcast_mut::<Node>()
+        .set_meta(EXCLUDE_META, &true.to_variant());
+}
+
 /// True if `node` or any ancestor carries the `_bevy_exclude` meta. Exclusion is
 /// subtree-wide -- a node under an excluded root is never mirrored -- and only the
 /// mirror-in decision (`NodeAdded`) consults it; removals stay unconditional so an
@@ -190,7 +234,7 @@ impl SceneTreeWatcher {
 pub(crate) fn is_excluded_from_mirror(node: &Gd<Node>) -> bool {
     let mut current = Some(node.clone());
     while let Some(n) = current {
-        if n.has_meta("_bevy_exclude") {
+        if n.has_meta(EXCLUDE_META) {
             return true;
         }
         current = n.get_parent();
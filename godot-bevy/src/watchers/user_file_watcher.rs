@@ -0,0 +1,118 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+/// Polls a directory tree for files that were created, modified or removed.
+///
+/// Godot has no file system notifications outside the editor, so this compares
+/// modification times and sizes between calls to [`poll`](Self::poll). Used by
+/// the `UserFileWatcherPlugin` for `user://` directories, but works on any path.
+#[derive(Debug)]
+pub struct UserFileWatcher {
+    root: PathBuf,
+    /// `None` until the first poll, which only records what is there.
+    known: Option<HashMap<PathBuf, FileStamp>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct FileStamp {
+    modified: Option<SystemTime>,
+    len: u64,
+}
+
+impl UserFileWatcher {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self {
+            root: root.into(),
+            known: None,
+        }
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Paths that changed since the previous poll, sorted. Removed files are
+    /// included; they simply no longer exist. The first poll returns nothing.
+    ///
+    /// A missing root counts as an empty directory, so mods dropped into a
+    /// folder that didn't exist at startup are still picked up.
+    pub fn poll(&mut self) -> Vec<PathBuf> {
+        let mut current = HashMap::new();
+        scan(&self.root, &mut current);
+
+        let Some(previous) = self.known.replace(current) else {
+            return Vec::new();
+        };
+        let current = self.known.as_ref().expect("just set");
+
+        let mut changed: Vec<PathBuf> = current
+            .iter()
+            .filter(|(path, stamp)| previous.get(*path) != Some(stamp))
+            .map(|(path, _)| path.clone())
+            .chain(
+                previous
+                    .keys()
+                    .filter(|path| !current.contains_key(*path))
+                    .cloned(),
+            )
+            .collect();
+        changed.sort();
+        changed
+    }
+}
+
+fn scan(dir: &Path, files: &mut HashMap<PathBuf, FileStamp>) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        match entry.metadata() {
+            Ok(metadata) if metadata.is_dir() => scan(&path, files),
+            Ok(metadata) => {
+                files.insert(
+                    path,
+                    FileStamp {
+                        modified: metadata.modified().ok(),
+                        len: metadata.len(),
+                    },
+                );
+            }
+            // Deleted between `read_dir` and `metadata`; the next poll reports it.
+            Err(_) => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn reports_created_modified_and_removed_files() {
+        let root = std::env::temp_dir().join(format!("godot-bevy-watch-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("levels")).unwrap();
+        let level = root.join("levels/one.json");
+        fs::write(&level, "{}").unwrap();
+
+        let mut watcher = UserFileWatcher::new(&root);
+        assert!(watcher.poll().is_empty(), "first poll is the baseline");
+        assert!(watcher.poll().is_empty());
+
+        let added = root.join("mod.txt");
+        fs::write(&added, "hi").unwrap();
+        let file = fs::File::options().write(true).open(&level).unwrap();
+        file.set_modified(SystemTime::now() + Duration::from_secs(5))
+            .unwrap();
+        assert_eq!(watcher.poll(), vec![level.clone(), added.clone()]);
+
+        fs::remove_file(&added).unwrap();
+        assert_eq!(watcher.poll(), vec![added]);
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -431,6 +431,7 @@ fn create_node_added_messages(nodes: &[Gd<Node>]) -> Vec<SceneTreeMessage> {
                 parent_id: node.get_parent().map(|p| p.instance_id()),
                 collision_mask: Some(0), // No collision signals
                 groups: Some(vec![]),    // No groups
+                transform: None,
             }
         })
         .collect()
@@ -553,6 +554,7 @@ fn scene_tree_process_node_added_fallback() -> i32 {
             parent_id: None, // Force FFI-based parent lookup
             collision_mask: None,
             groups: None,
+            transform: None,
         })
         .collect();
 
@@ -645,6 +647,7 @@ fn scene_tree_process_node_renamed_sparse_updates() -> i32 {
                 parent_id: None,
                 collision_mask: None,
                 groups: None,
+                transform: None,
             })
             .expect("Send should succeed");
 
@@ -742,6 +745,7 @@ fn run_autosync_node_added(node_count: usize, matching: bool) -> i32 {
             parent_id: node.get_parent().map(|p| p.instance_id()),
             collision_mask: Some(0),
             groups: Some(vec![]),
+            transform: None,
         })
         .collect();
 
@@ -846,6 +850,7 @@ fn create_collision_body_messages(nodes: &[Gd<Node>]) -> Vec<SceneTreeMessage> {
             parent_id: node.get_parent().map(|p| p.instance_id()),
             collision_mask: Some(full_mask),
             groups: Some(vec![]),
+            transform: None,
         })
         .collect()
 }
@@ -923,6 +928,7 @@ fn scene_tree_process_collision_bodies_fallback() -> i32 {
             parent_id: None,
             collision_mask: None, // Force FFI-based collision mask detection
             groups: None,
+            transform: None,
         })
         .collect();
 
@@ -999,6 +1005,7 @@ fn create_collision_processing_node_added_messages(nodes: &[Gd<Node>]) -> Vec<Sc
             parent_id: node.get_parent().map(|parent| parent.instance_id()),
             collision_mask: Some(0),
             groups: Some(vec![]),
+            transform: None,
         })
         .collect()
 }
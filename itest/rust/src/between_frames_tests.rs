@@ -0,0 +1,63 @@
+/*
+ * BetweenFrames itests
+ *
+ * Between frames the world is on the BetweenFrames thread. Access from outside a
+ * frame must never see the empty world left behind: `get_app` reports no app and
+ * `get_app_mut` waits for the schedule to hand the world back.
+ */
+
+use bevy::prelude::*;
+use godot_bevy::prelude::*;
+use godot_bevy_test::prelude::*;
+
+#[derive(Resource, Default)]
+struct WorkerRuns(u32);
+
+fn count_worker_run(mut runs: ResMut<WorkerRuns>) {
+    runs.0 += 1;
+}
+
+/// `get_app` returns `None` while BetweenFrames holds the world, and the world is
+/// whole again once `get_app_mut` has joined the worker.
+#[itest(async)]
+fn test_get_app_hides_world_while_between_frames_runs(
+    ctx: &TestContext,
+) -> godot::task::TaskHandle {
+    let ctx_clone = ctx.clone();
+
+    godot::task::spawn(async move {
+        let mut app = TestApp::new(&ctx_clone, |app| {
+            app.add_plugins(BetweenFramesPlugin::default())
+                .init_resource::<WorkerRuns>()
+                .add_systems(BetweenFrames, count_worker_run);
+        })
+        .await;
+
+        app.update().await;
+
+        let node = app.app_node();
+        assert!(
+            node.bind().get_app().is_none(),
+            "get_app must not expose the swapped-out world while BetweenFrames runs"
+        );
+
+        let runs = node
+            .clone()
+            .bind_mut()
+            .get_app_mut()
+            .expect("get_app_mut waits for the world")
+            .world()
+            .get_resource::<WorkerRuns>()
+            .map(|runs| runs.0);
+        assert!(
+            runs.is_some_and(|runs| runs >= 1),
+            "the joined world should carry BetweenFrames's writes, got {runs:?}"
+        );
+        assert!(
+            node.bind().get_app().is_some(),
+            "get_app returns the app again once the world is back"
+        );
+
+        app.cleanup().await;
+    })
+}
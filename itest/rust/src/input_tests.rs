@@ -16,7 +16,8 @@ use godot::obj::{NewGd, Singleton};
 use godot::prelude::*;
 use godot_bevy::plugins::input::{
     Action, ActionInput, BevyInputBridgePlugin, GodotActions, GodotActionsPlugin,
-    GodotInputEventPlugin, GodotInputSet, GodotKeyboardInput, GodotMouseMotion,
+    GodotInputEventPlugin, GodotInputReactPlugin, GodotInputSet, GodotKeyboardInput,
+    GodotMouseMotion, InputReact,
 };
 use godot_bevy_test::prelude::*;
 
@@ -525,3 +526,34 @@ fn test_godot_actions_typed_handle_matches_str(ctx: &TestContext) -> godot::task
         println!("✓ Typed Action and &str agree across {} frames", log.len());
     })
 }
+
+#[derive(Resource)]
+struct InputReactRuns(Counter);
+
+/// One key press runs `InputReact` exactly once, even though Godot also
+/// delivers the unhandled event afterwards.
+#[itest(async)]
+fn test_input_react_runs_once_per_event(ctx: &TestContext) -> godot::task::TaskHandle {
+    let ctx_clone = ctx.clone();
+
+    godot::task::spawn(async move {
+        let runs = Counter::new();
+        let runs_clone = runs.clone();
+        let mut app = TestApp::new(&ctx_clone, move |app| {
+            app.add_plugins(GodotInputReactPlugin)
+                .insert_resource(InputReactRuns(runs_clone))
+                .add_systems(InputReact, |runs: Res<InputReactRuns>| runs.0.increment());
+        })
+        .await;
+        app.updates(1).await;
+
+        parse_key_event(Key::J, true);
+        app.updates(2).await;
+
+        assert_eq!(runs.get(), 1, "one event should run InputReact once");
+
+        parse_key_event(Key::J, false);
+        app.updates(2).await;
+        app.cleanup().await;
+    })
+}
@@ -12,19 +12,32 @@ godot_bevy_test::declare_test_runner!();
 mod asset_reader_tests;
 mod autosync_match_tests;
 mod benchmarks;
+mod between_frames_tests;
 mod collision_tests;
 mod event_bridge_tests;
 mod input_ecosystem_tests;
 mod input_tests;
 #[cfg(feature = "autosync-tests")]
 mod macro_redesign_tests;
+mod meta_sync_tests;
+mod named_app_tests;
+mod navigation_tests;
+mod node_tree_view_tests;
 mod pause_tests;
+mod physics_material_tests;
+mod ragdoll_tests;
 mod real_frame_tests;
+mod resource_bridge_tests;
+mod scene_manager_tests;
 mod scene_tree_tests;
 mod scene_tree_watcher_init_tests;
+mod script_call_tests;
 mod signal_tests;
+mod soft_body_tests;
+mod test_app_tests;
 mod time_scale_tests;
 mod transform_sync_tests;
+mod vehicle_tests;
 
 #[gdextension(entry_symbol = godot_bevy_itest)]
 unsafe impl ExtensionLibrary for IntegrationTests {}
@@ -0,0 +1,72 @@
+/*
+ * Component metadata sync tests
+ *
+ * A `#[reflect(SyncToGodotMeta)]` component is written into its node's metadata,
+ * and edits to that metadata are applied back to the component.
+ */
+
+use bevy::prelude::*;
+use godot::builtin::VarDictionary;
+use godot::prelude::*;
+use godot_bevy::prelude::*;
+use godot_bevy_test::prelude::*;
+
+#[derive(Component, Reflect, Default, Debug, PartialEq)]
+#[reflect(Component, SyncToGodotMeta)]
+struct Stamina {
+    current: f32,
+    regen: i32,
+}
+
+#[itest(async)]
+fn test_component_round_trips_through_node_meta(ctx: &TestContext) -> godot::task::TaskHandle {
+    let ctx_clone = ctx.clone();
+
+    godot::task::spawn(async move {
+        let mut app = TestApp::new(&ctx_clone, |app| {
+            app.add_plugins(GodotMetaSyncPlugin)
+                .register_type::<Stamina>();
+        })
+        .await;
+
+        let (node, entity) = app.add_node::<godot::classes::Node2D>("Runner").await;
+        app.with_world_mut(|world| {
+            world.entity_mut(entity).insert(Stamina {
+                current: 80.0,
+                regen: 2,
+            });
+        });
+        app.update().await;
+
+        let meta = node
+            .get_meta("bevy_Stamina")
+            .try_to::<VarDictionary>()
+            .expect("Stamina should be written to the node's metadata");
+        assert_eq!(meta.get("current"), Some(80.0f32.to_variant()));
+        assert_eq!(meta.get("regen"), Some(2.to_variant()));
+
+        // Edit the dictionary in place, as a script would.
+        let mut meta = meta;
+        meta.set("current", 100.0f32);
+        app.update().await;
+
+        app.with_world(|world| {
+            assert_eq!(
+                world.get::<Stamina>(entity),
+                Some(&Stamina {
+                    current: 100.0,
+                    regen: 2,
+                })
+            );
+        });
+
+        app.with_world_mut(|world| {
+            world.entity_mut(entity).remove::<Stamina>();
+        });
+        app.update().await;
+        assert!(!node.has_meta("bevy_Stamina"));
+
+        app.cleanup().await;
+        node.free();
+    })
+}
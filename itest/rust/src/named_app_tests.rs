@@ -0,0 +1,90 @@
+/*
+ * Named app itests
+ *
+ * A `BevyApp` node's `app_name` picks among the `#[bevy_app(name = "...")]` apps;
+ * a name nothing registered leaves the node without an app instead of falling
+ * back to another one.
+ */
+
+use bevy::prelude::*;
+use godot::obj::NewAlloc;
+use godot::prelude::*;
+use godot_bevy::BevyApp;
+use godot_bevy::prelude::*;
+use godot_bevy_test::prelude::*;
+
+#[derive(Resource)]
+struct BuiltBy(&'static str);
+
+#[bevy_app(name = "itest_alpha")]
+fn build_alpha(app: &mut App) {
+    app.insert_resource(BuiltBy("itest_alpha"));
+}
+
+#[bevy_app(name = "itest_beta")]
+fn build_beta(app: &mut App) {
+    app.insert_resource(BuiltBy("itest_beta"));
+}
+
+/// Add a `BevyApp` node running the app named `app_name`; `ready` builds it.
+fn add_named_app(ctx: &TestContext, app_name: &str) -> Gd<BevyApp> {
+    let mut node = BevyApp::new_alloc();
+    node.set_name(&format!("NamedApp_{app_name}"));
+    node.set("app_name", &app_name.to_variant());
+    ctx.scene_tree
+        .clone()
+        .add_child(&node.clone().upcast::<godot::classes::Node>());
+    node
+}
+
+fn remove_app(ctx: &TestContext, mut node: Gd<BevyApp>) {
+    node.bind_mut().teardown();
+    ctx.scene_tree
+        .clone()
+        .remove_child(&node.clone().upcast::<godot::classes::Node>());
+    node.upcast::<godot::classes::Node>().free();
+}
+
+/// With two named apps registered, each node runs the one its `app_name` picks.
+#[itest(async)]
+fn test_app_name_picks_among_registered_apps(ctx: &TestContext) -> godot::task::TaskHandle {
+    let ctx_clone = ctx.clone();
+
+    godot::task::spawn(async move {
+        let beta = add_named_app(&ctx_clone, "itest_beta");
+        let alpha = add_named_app(&ctx_clone, "itest_alpha");
+
+        let built_by = |node: &Gd<BevyApp>| {
+            node.bind()
+                .get_app()
+                .and_then(|app| app.world().get_resource::<BuiltBy>().map(|built| built.0))
+        };
+        assert_eq!(built_by(&beta), Some("itest_beta"));
+        assert_eq!(built_by(&alpha), Some("itest_alpha"));
+
+        remove_app(&ctx_clone, beta);
+        remove_app(&ctx_clone, alpha);
+    })
+}
+
+/// An `app_name` nothing registered builds no app, rather than the default one.
+#[itest(async)]
+fn test_unknown_app_name_builds_no_app(ctx: &TestContext) -> godot::task::TaskHandle {
+    let ctx_clone = ctx.clone();
+
+    godot::task::spawn(async move {
+        let mut node = add_named_app(&ctx_clone, "itest_missing");
+        assert!(
+            node.bind().get_app().is_none(),
+            "an unknown app_name must not fall back to another app"
+        );
+
+        node.bind_mut().initialize();
+        assert!(
+            node.bind().get_app().is_none(),
+            "initialize() must not build an app for an unknown app_name either"
+        );
+
+        remove_app(&ctx_clone, node);
+    })
+}
@@ -0,0 +1,58 @@
+/*
+ * Navigation tests
+ *
+ * DynamicObstacle entities get a NavigationObstacle2D/3D child sized from their
+ * collision shapes, whose velocity follows the entity.
+ */
+
+use godot::classes::{CircleShape2D, CollisionShape2D, NavigationObstacle2D, Node2D};
+use godot::prelude::*;
+use godot_bevy::prelude::*;
+use godot_bevy_test::prelude::*;
+
+#[itest(async)]
+fn test_dynamic_obstacle_fits_shape_and_tracks_velocity(
+    ctx: &TestContext,
+) -> godot::task::TaskHandle {
+    let ctx_clone = ctx.clone();
+
+    godot::task::spawn(async move {
+        let mut app = TestApp::new(&ctx_clone, |app| {
+            app.add_plugins(GodotNavigationPlugin);
+        })
+        .await;
+
+        let mut circle = CircleShape2D::new_gd();
+        circle.set_radius(8.0);
+        let mut shape = CollisionShape2D::new_alloc();
+        shape.set_shape(&circle);
+        let mut node = Node2D::new_alloc();
+        node.add_child(&shape);
+        let (mut node, entity) = app.add_prebuilt_node(node, "Boulder").await;
+
+        app.with_world_mut(|world| {
+            world.entity_mut(entity).insert(DynamicObstacle::new());
+        });
+        app.physics_update().await;
+
+        let obstacle_id = app
+            .with_world(|world| {
+                world
+                    .get::<DynamicObstacle>(entity)
+                    .and_then(|o| o.obstacle())
+            })
+            .expect("An obstacle node should be created");
+        let obstacle = Gd::<NavigationObstacle2D>::from_instance_id(obstacle_id);
+        assert_eq!(obstacle.get_radius(), 8.0, "Radius should fit the circle");
+
+        node.set_global_position(Vector2::new(10.0, 0.0));
+        app.physics_update().await;
+
+        assert!(
+            obstacle.get_velocity().x > 0.0,
+            "Velocity should follow the node's movement"
+        );
+
+        app.cleanup().await;
+    })
+}
@@ -0,0 +1,113 @@
+/*
+ * NodeTreeView integration tests
+ *
+ * Tests the derive's typed `Gd<T>` fields, optional fields and wildcard `Vec` fields
+ * against a real node tree.
+ */
+
+use godot::classes::{Label, Node, Node2D};
+use godot::obj::NewAlloc;
+use godot::prelude::*;
+use godot_bevy::prelude::*;
+use godot_bevy_test::prelude::*;
+
+#[derive(NodeTreeView)]
+struct TypedView {
+    #[node("Sprite")]
+    sprite: Gd<Label>,
+}
+
+#[derive(NodeTreeView)]
+struct OptionalView {
+    #[node("Trail")]
+    trail: Option<Gd<Node2D>>,
+}
+
+#[derive(NodeTreeView)]
+struct EnemiesView {
+    #[node("Enemies/*")]
+    enemies: Vec<Gd<Node2D>>,
+}
+
+/// Builds `ViewRoot` under the test scene tree with the given children.
+fn root_with(ctx: &TestContext, children: &[Gd<Node>]) -> Gd<Node> {
+    let mut root = Node::new_alloc();
+    root.set_name("ViewRoot");
+    for child in children {
+        root.add_child(child);
+    }
+    ctx.scene_tree.clone().add_child(&root);
+    root
+}
+
+fn named<T: GodotClass + NewAlloc + Inherits<Node>>(name: &str) -> Gd<Node> {
+    let mut node = T::new_alloc().upcast::<Node>();
+    node.set_name(name);
+    node
+}
+
+/// A `Gd<T>` field whose node is a different class fails with `TypeMismatch`
+/// naming the path and both classes.
+#[itest(async)]
+fn test_typed_field_reports_type_mismatch(ctx: &TestContext) -> godot::task::TaskHandle {
+    let ctx_clone = ctx.clone();
+
+    godot::task::spawn(async move {
+        let root = root_with(&ctx_clone, &[named::<Node2D>("Sprite")]);
+
+        match TypedView::from_node(root.clone()) {
+            Err(NodeTreeViewError::TypeMismatch {
+                path,
+                expected,
+                actual,
+            }) => {
+                assert_eq!(path, "Sprite");
+                assert_eq!(expected, "Label");
+                assert_eq!(actual, "Node2D");
+            }
+            Err(other) => panic!("expected TypeMismatch, got {other}"),
+            Ok(view) => panic!("a Node2D resolved as Gd<Label>: {}", view.sprite),
+        }
+
+        root.free();
+    })
+}
+
+/// An `Option<Gd<T>>` field is `None` when the node is missing, and the view still builds.
+#[itest(async)]
+fn test_optional_field_missing_is_none(ctx: &TestContext) -> godot::task::TaskHandle {
+    let ctx_clone = ctx.clone();
+
+    godot::task::spawn(async move {
+        let root = root_with(&ctx_clone, &[named::<Node2D>("Sprite")]);
+
+        let view = OptionalView::from_node(root.clone()).expect("missing optional node is ok");
+        assert!(view.trail.is_none(), "missing Trail should be None");
+
+        root.free();
+    })
+}
+
+/// A `Vec<Gd<T>>` field collects every wildcard match in tree (child index) order.
+#[itest(async)]
+fn test_vec_field_collects_matches_in_tree_order(ctx: &TestContext) -> godot::task::TaskHandle {
+    let ctx_clone = ctx.clone();
+
+    godot::task::spawn(async move {
+        let mut enemies = named::<Node>("Enemies");
+        for name in ["Zed", "Alpha", "Mid"] {
+            enemies.add_child(&named::<Node2D>(name));
+        }
+        let root = root_with(&ctx_clone, &[enemies]);
+
+        let view = EnemiesView::from_node(root.clone()).expect("enemies view");
+        let names: Vec<String> = view
+            .enemies
+            .iter()
+            .map(|enemy| enemy.get_name().to_string())
+            .collect();
+        assert_eq!(names, ["Zed", "Alpha", "Mid"]);
+
+        root.free();
+    })
+}
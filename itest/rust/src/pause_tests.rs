@@ -280,3 +280,42 @@ fn test_transform_read_frozen_under_pause(ctx: &TestContext) -> godot::task::Tas
         node.free();
     })
 }
+
+/// `pause_bevy` skips whole Bevy frames while Godot keeps running, `step_frames(n)` runs
+/// exactly `n` more and stays paused, and `resume_bevy` lets frames run again.
+#[itest(async)]
+fn test_bevy_app_pause_and_step(ctx: &TestContext) -> godot::task::TaskHandle {
+    let ctx = ctx.clone();
+    godot::task::spawn(async move {
+        let (mut app, _fixed, update) = app_with_counters(&ctx).await;
+        let mut node = app.app_node();
+        app.updates(3).await;
+
+        node.call("pause_bevy", &[]);
+        assert!(node.call("is_paused", &[]).to::<bool>());
+        let at_pause = update.get();
+        await_frames(5).await;
+        assert_eq!(update.get(), at_pause, "no Bevy frame may run while paused");
+
+        node.call("step_frames", &[2.to_variant()]);
+        await_frames(5).await;
+        assert_eq!(
+            update.get(),
+            at_pause + 2,
+            "step_frames(2) runs exactly two frames"
+        );
+        assert!(
+            node.call("is_paused", &[]).to::<bool>(),
+            "stepping leaves the app paused"
+        );
+
+        node.call("resume_bevy", &[]);
+        app.updates(3).await;
+        assert!(
+            update.get() > at_pause + 2,
+            "Update must run again after resume_bevy"
+        );
+
+        app.cleanup().await;
+    })
+}
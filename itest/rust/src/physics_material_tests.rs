@@ -0,0 +1,71 @@
+/*
+ * Physics material tests
+ *
+ * Bodies get a PhysicsMaterialOverride read from their material, and editing
+ * it gives the body its own material instead of changing a shared one.
+ */
+
+use godot::classes::{PhysicsMaterial, StaticBody2D};
+use godot::prelude::*;
+use godot_bevy::prelude::*;
+use godot_bevy_test::prelude::*;
+
+#[itest(async)]
+fn test_edit_does_not_touch_shared_material(ctx: &TestContext) -> godot::task::TaskHandle {
+    let ctx_clone = ctx.clone();
+
+    godot::task::spawn(async move {
+        let mut app = TestApp::new(&ctx_clone, |app| {
+            app.add_plugins(GodotPhysicsMaterialPlugin);
+        })
+        .await;
+
+        let mut shared = PhysicsMaterial::new_gd();
+        shared.set_friction(0.8);
+        let mut floors = [StaticBody2D::new_alloc(), StaticBody2D::new_alloc()];
+        for floor in &mut floors {
+            floor.set_physics_material_override(&shared);
+        }
+        let [ice, stone] = floors;
+        let (ice, ice_entity) = app.add_prebuilt_node(ice, "IceFloor").await;
+        let (stone, _) = app.add_prebuilt_node(stone, "StoneFloor").await;
+        app.update().await;
+
+        let read =
+            app.with_world(|world| *world.get::<PhysicsMaterialOverride>(ice_entity).unwrap());
+        assert_eq!(read.friction, 0.8);
+
+        app.with_world_mut(|world| {
+            world
+                .get_mut::<PhysicsMaterialOverride>(ice_entity)
+                .unwrap()
+                .friction = 0.05;
+        });
+        app.update().await;
+
+        let ice_material = ice.get_physics_material_override().unwrap();
+        assert_ne!(
+            ice_material, shared,
+            "Edited body should get its own material"
+        );
+        assert_eq!(ice_material.get_friction(), 0.05);
+        assert_eq!(shared.get_friction(), 0.8);
+        assert_eq!(stone.get_physics_material_override(), Some(shared.clone()));
+
+        app.with_world_mut(|world| {
+            world
+                .get_mut::<PhysicsMaterialOverride>(ice_entity)
+                .unwrap()
+                .bounce = 0.5;
+        });
+        app.update().await;
+        let edited = ice.get_physics_material_override().unwrap();
+        assert_eq!(
+            edited, ice_material,
+            "Later edits should reuse the body's material"
+        );
+        assert_eq!(edited.get_bounce(), 0.5);
+
+        app.cleanup().await;
+    })
+}
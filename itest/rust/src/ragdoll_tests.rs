@@ -0,0 +1,84 @@
+/*
+ * Ragdoll tests
+ *
+ * ActivateRagdoll and DeactivateRagdoll toggle a PhysicalBoneSimulator3D found
+ * below the entity's node, and trigger their completion events.
+ */
+
+use bevy::prelude::*;
+use godot::classes::{PhysicalBoneSimulator3D, Skeleton3D};
+use godot::prelude::*;
+use godot_bevy::prelude::*;
+use godot_bevy_test::prelude::*;
+
+#[derive(Resource, Default)]
+struct RagdollEvents(Vec<&'static str>);
+
+#[itest(async)]
+fn test_ragdoll_commands_toggle_the_simulator(ctx: &TestContext) -> godot::task::TaskHandle {
+    let ctx_clone = ctx.clone();
+
+    godot::task::spawn(async move {
+        let mut app = TestApp::new(&ctx_clone, |app| {
+            app.add_plugins(GodotRagdollPlugin)
+                .init_resource::<RagdollEvents>()
+                .add_observer(
+                    |_: On<RagdollActivated>, mut events: ResMut<RagdollEvents>| {
+                        events.0.push("activated");
+                    },
+                )
+                .add_observer(
+                    |_: On<RagdollDeactivated>, mut events: ResMut<RagdollEvents>| {
+                        events.0.push("deactivated");
+                    },
+                );
+        })
+        .await;
+
+        let mut skeleton = Skeleton3D::new_alloc();
+        let mut simulator = PhysicalBoneSimulator3D::new_alloc();
+        simulator.set_active(false);
+        skeleton.add_child(&simulator);
+        let (_skeleton, entity) = app.add_prebuilt_node(skeleton, "Skeleton").await;
+
+        app.with_world_mut(|world| {
+            world
+                .commands()
+                .entity(entity)
+                .queue(ActivateRagdoll::all());
+            world.flush();
+        });
+        app.update().await;
+
+        assert!(
+            simulator.is_active(),
+            "Activating should enable the simulator"
+        );
+        assert!(
+            app.with_world(|world| world.get::<Ragdolling>(entity).is_some()),
+            "The entity should be marked while ragdolling"
+        );
+
+        app.with_world_mut(|world| {
+            world
+                .commands()
+                .entity(entity)
+                .queue(DeactivateRagdoll::immediate());
+            world.flush();
+        });
+        app.update().await;
+
+        assert!(
+            !simulator.is_active(),
+            "Deactivating should disable the simulator"
+        );
+        assert!(
+            app.with_world(|world| world.get::<Ragdolling>(entity).is_none()),
+            "The marker should be removed once deactivated"
+        );
+        let events = app.with_world(|world| world.resource::<RagdollEvents>().0.clone());
+        assert_eq!(events, ["activated", "deactivated"]);
+
+        app.cleanup().await;
+    })
+}
@@ -0,0 +1,74 @@
+/*
+ * Resource bridge tests
+ *
+ * A bridged resource is readable through `GodotBridge.get_resource`, and
+ * `set_resource` changes it in the next frame.
+ */
+
+use bevy::prelude::*;
+use godot::builtin::VarDictionary;
+use godot::prelude::*;
+use godot_bevy::plugins::resource_bridge::GodotBridge;
+use godot_bevy::prelude::*;
+use godot_bevy_test::prelude::*;
+
+#[derive(Resource, Reflect, Default, Debug, PartialEq)]
+struct Score {
+    value: i64,
+    combo: i32,
+}
+
+#[derive(Resource, Default)]
+struct SetsSeen(Vec<&'static str>);
+
+#[itest(async)]
+fn test_bridge_reads_and_queues_writes(ctx: &TestContext) -> godot::task::TaskHandle {
+    let ctx_clone = ctx.clone();
+
+    godot::task::spawn(async move {
+        let mut app = TestApp::new(&ctx_clone, |app| {
+            app.add_plugins(GodotResourceBridgePlugin)
+                .insert_resource(Score {
+                    value: 1200,
+                    combo: 3,
+                })
+                .init_resource::<SetsSeen>()
+                .bridge_resource::<Score>()
+                .add_observer(|set: On<BridgeResourceSet>, mut seen: ResMut<SetsSeen>| {
+                    seen.0.push(set.resource);
+                });
+        })
+        .await;
+        app.update().await;
+
+        let mut bridge = GodotBridge::new_alloc();
+        let score = bridge
+            .call("get_resource", &["Score".to_variant()])
+            .try_to::<VarDictionary>()
+            .expect("Score should be bridged");
+        assert_eq!(score.get("value"), Some(1200i64.to_variant()));
+        assert_eq!(score.get("combo"), Some(3.to_variant()));
+
+        let mut fields = VarDictionary::new();
+        fields.set("combo", 0);
+        let queued = bridge.call("set_resource", &["Score".to_variant(), fields.to_variant()]);
+        assert_eq!(queued, true.to_variant());
+        let unknown = bridge.call("set_resource", &["Nope".to_variant(), fields.to_variant()]);
+        assert_eq!(unknown, false.to_variant());
+
+        app.update().await;
+        app.with_world(|world| {
+            assert_eq!(
+                world.resource::<Score>(),
+                &Score {
+                    value: 1200,
+                    combo: 0,
+                }
+            );
+            assert_eq!(world.resource::<SetsSeen>().0, vec!["Score"]);
+        });
+
+        bridge.free();
+        app.cleanup().await;
+    })
+}
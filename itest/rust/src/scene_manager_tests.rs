@@ -0,0 +1,63 @@
+//! SceneManager transitions that fail must say so, not report a finished change.
+
+use bevy::prelude::*;
+use godot_bevy::prelude::*;
+use godot_bevy_test::prelude::*;
+
+#[derive(Resource, Default)]
+struct SceneChangeOutcomes {
+    finished: usize,
+    failed: Vec<String>,
+}
+
+/// A change to a scene file that doesn't exist triggers `SceneChangeFailed` with the
+/// reason, never `SceneChangeFinished`, and the manager goes back to idle.
+#[itest(async)]
+fn test_missing_scene_triggers_failed(ctx: &TestContext) -> godot::task::TaskHandle {
+    let ctx = ctx.clone();
+    godot::task::spawn(async move {
+        let mut app = TestApp::new(&ctx, |app| {
+            app.add_plugins(SceneManagerPlugin::default());
+            app.init_resource::<SceneChangeOutcomes>();
+            app.add_observer(
+                |_finished: On<SceneChangeFinished>, mut outcomes: ResMut<SceneChangeOutcomes>| {
+                    outcomes.finished += 1;
+                },
+            );
+            app.add_observer(
+                |failed: On<SceneChangeFailed>, mut outcomes: ResMut<SceneChangeOutcomes>| {
+                    outcomes.failed.push(failed.reason.clone());
+                },
+            );
+        })
+        .await;
+
+        app.with_world_mut(|world| {
+            world
+                .resource_mut::<SceneManager>()
+                .change_to("res://does_not_exist.tscn");
+        });
+        app.updates(5).await;
+
+        let (finished, failed, idle) = app.with_world(|world| {
+            let outcomes = world.resource::<SceneChangeOutcomes>();
+            (
+                outcomes.finished,
+                outcomes.failed.clone(),
+                !world.resource::<SceneManager>().is_transitioning(),
+            )
+        });
+        assert_eq!(
+            finished, 0,
+            "a failed load must not report SceneChangeFinished"
+        );
+        assert_eq!(
+            failed.len(),
+            1,
+            "expected one SceneChangeFailed, got {failed:?}"
+        );
+        assert!(idle, "the manager should be idle after the failed change");
+
+        app.cleanup().await;
+    })
+}
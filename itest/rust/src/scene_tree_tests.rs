@@ -5,6 +5,7 @@
  * ProtectedNodeEntity, GodotNodeHandle validity, and NodeEntityIndex.
  */
 
+use bevy::prelude::{Add, Entity, Name, On, Query, Res, ResMut, Resource};
 use godot::obj::NewAlloc;
 use godot::prelude::*;
 use godot_bevy::plugins::scene_tree::ProtectedNodeEntity;
@@ -165,6 +166,80 @@ fn test_reparent_to_root_clears_godot_child_of(ctx: &TestContext) -> godot::task
     })
 }
 
+#[derive(Resource, Default)]
+struct ReparentProbe {
+    /// The child entity to move, and its new parent entity and node.
+    request: Option<(Entity, Entity, InstanceId)>,
+    /// Whether the node had its new parent by the end of the frame that asked.
+    moved_same_frame: Option<bool>,
+}
+
+/// With the default `DeferredFlush::EndOfFrame`, a `reparent_godot` made in `Update`
+/// has moved the node by the end of that same Bevy frame, not a frame later.
+#[itest(async)]
+fn test_reparent_godot_flushes_end_of_frame(ctx: &TestContext) -> godot::task::TaskHandle {
+    use bevy::ecs::schedule::IntoScheduleConfigs;
+    use bevy::prelude::{Commands, Last, Res, Update};
+
+    let ctx_clone = ctx.clone();
+
+    godot::task::spawn(async move {
+        let mut app = TestApp::new(&ctx_clone, |app| {
+            app.init_resource::<ReparentProbe>()
+                .add_systems(
+                    Update,
+                    |probe: Res<ReparentProbe>, mut commands: Commands| {
+                        if let Some((child, parent, _)) = probe.request {
+                            commands.entity(child).reparent_godot(parent);
+                        }
+                    },
+                )
+                .add_systems(
+                    Last,
+                    (|mut probe: ResMut<ReparentProbe>,
+                      handles: Query<&GodotNodeHandle>,
+                      mut godot: GodotAccess| {
+                        let Some((child, _, parent_id)) = probe.request.take() else {
+                            return;
+                        };
+                        let parent = handles
+                            .get(child)
+                            .ok()
+                            .and_then(|handle| godot.get::<Node>(*handle).get_parent());
+                        probe.moved_same_frame =
+                            Some(parent.map(|parent| parent.instance_id()) == Some(parent_id));
+                    })
+                    .after(DeferredCallSet::Flush),
+                );
+        })
+        .await;
+
+        let (from, _) = app.add_node::<godot::classes::Node>("DeferFrom").await;
+        let (to, to_entity) = app.add_node::<godot::classes::Node>("DeferTo").await;
+        let child = Node::new_alloc();
+        let child_id = child.instance_id();
+        from.clone().add_child(&child);
+        app.updates(2).await;
+        let child_entity = app.entity_for_node(child_id).expect("entity for child");
+
+        app.with_world_mut(|w| {
+            w.resource_mut::<ReparentProbe>().request =
+                Some((child_entity, to_entity, to.instance_id()));
+        });
+        app.update().await;
+
+        assert_eq!(
+            app.with_world(|w| w.resource::<ReparentProbe>().moved_same_frame),
+            Some(true),
+            "reparent_godot must run before the end of the frame that asked for it"
+        );
+
+        app.cleanup().await;
+        from.free();
+        to.free();
+    })
+}
+
 /// Test that removing a node generates appropriate events/cleanup
 #[itest(async)]
 fn test_node_removed_cleanup(ctx: &TestContext) -> godot::task::TaskHandle {
@@ -217,6 +292,56 @@ fn test_node_renamed_event(ctx: &TestContext) -> godot::task::TaskHandle {
     })
 }
 
+/// NodeAdded, NodeRenamed and NodeRemoved are triggered on the node's entity, in
+/// order, with NodeRemoved still able to read the entity.
+#[itest(async)]
+fn test_node_lifecycle_observer_events(ctx: &TestContext) -> godot::task::TaskHandle {
+    let ctx_clone = ctx.clone();
+
+    godot::task::spawn(async move {
+        #[derive(Resource, Default)]
+        struct Lifecycle(Vec<(Entity, String)>);
+
+        let mut app = TestApp::new(&ctx_clone, |app| {
+            app.init_resource::<Lifecycle>()
+                .add_observer(|added: On<NodeAdded>, mut log: ResMut<Lifecycle>| {
+                    log.0.push((added.entity, "added".into()));
+                })
+                .add_observer(|renamed: On<NodeRenamed>, mut log: ResMut<Lifecycle>| {
+                    log.0
+                        .push((renamed.entity, format!("renamed {}", renamed.name)));
+                })
+                .add_observer(
+                    |removed: On<NodeRemoved>, names: Query<&Name>, mut log: ResMut<Lifecycle>| {
+                        let name = names.get(removed.entity).map(|n| n.to_string());
+                        log.0
+                            .push((removed.entity, format!("removed {}", name.unwrap())));
+                    },
+                );
+        })
+        .await;
+
+        let (mut node, entity) = app.add_node::<godot::classes::Node2D>("Lifecycle").await;
+        node.set_name("Renamed");
+        app.updates(2).await;
+        node.queue_free();
+        app.updates(2).await;
+
+        let events: Vec<String> = app.with_world(|world| {
+            world
+                .resource::<Lifecycle>()
+                .0
+                .iter()
+                .filter(|(e, _)| *e == entity)
+                .map(|(_, event)| event.clone())
+                .collect()
+        });
+        assert_eq!(events, ["added", "renamed Renamed", "removed Renamed"]);
+
+        app.cleanup().await;
+    })
+}
+
 /// Test that ProtectedNodeEntity prevents despawn when node is freed
 #[itest(async)]
 fn test_protected_node_entity(ctx: &TestContext) -> godot::task::TaskHandle {
@@ -265,6 +390,69 @@ fn test_protected_node_entity(ctx: &TestContext) -> godot::task::TaskHandle {
     })
 }
 
+/// A RebindByPath entity outlives its node and takes over the next node added
+/// at the same path, triggering HandleRebound.
+#[itest(async)]
+fn test_rebind_by_path_rebinds_reloaded_node(ctx: &TestContext) -> godot::task::TaskHandle {
+    let ctx_clone = ctx.clone();
+
+    godot::task::spawn(async move {
+        #[derive(Resource, Default)]
+        struct Rebounds(Vec<HandleRebound>);
+
+        let mut app = TestApp::new(&ctx_clone, |app| {
+            app.init_resource::<Rebounds>().add_observer(
+                |rebound: On<HandleRebound>, mut log: ResMut<Rebounds>| {
+                    log.0.push(*rebound.event());
+                },
+            );
+        })
+        .await;
+
+        let (mut old_node, entity) = app.add_node::<godot::classes::Node2D>("HudLabel").await;
+        let old_id = old_node.instance_id();
+        app.with_world_mut(|world| {
+            world.entity_mut(entity).insert(RebindByPath::default());
+        });
+        app.update().await;
+
+        let path = app.with_world(|world| {
+            world
+                .get::<RebindByPath>(entity)
+                .and_then(|rebind| rebind.path().map(str::to_owned))
+        });
+        assert!(
+            path.is_some_and(|path| path.ends_with("/HudLabel")),
+            "RebindByPath should record the node's path"
+        );
+
+        old_node.queue_free();
+        app.updates(2).await;
+
+        let waiting = app.with_world(|world| {
+            world.get_entity(entity).is_ok() && world.get::<GodotNodeHandle>(entity).is_none()
+        });
+        assert!(
+            waiting,
+            "Entity should wait without a handle once its node is freed"
+        );
+
+        let (new_node, new_entity) = app.add_node::<godot::classes::Node2D>("HudLabel").await;
+        assert_eq!(
+            new_entity, entity,
+            "New node should be bound to the waiting entity"
+        );
+
+        let rebounds = app.with_world(|world| world.resource::<Rebounds>().0.clone());
+        assert_eq!(rebounds.len(), 1);
+        assert_eq!(rebounds[0].entity, entity);
+        assert_eq!(rebounds[0].old.instance_id(), old_id);
+        assert_eq!(rebounds[0].new.instance_id(), new_node.instance_id());
+
+        app.cleanup().await;
+    })
+}
+
 /// Test that GodotNodeHandle points to correct node
 #[itest(async)]
 fn test_node_handle_validity(ctx: &TestContext) -> godot::task::TaskHandle {
@@ -608,3 +796,70 @@ fn test_packed_scene_spawn_reconciles_to_single_entity(
         app.cleanup().await;
     })
 }
+
+#[derive(Resource, Default)]
+struct PrewarmSeen(Vec<(String, bool)>);
+
+/// Prewarming mirrors the scene into throwaway entities and despawns them in the same
+/// flush: observers see them, flagged by `is_prewarm_entity`, and none linger.
+#[itest(async)]
+fn test_prewarmed_scene_leaves_no_entities(ctx: &TestContext) -> godot::task::TaskHandle {
+    let ctx_clone = ctx.clone();
+
+    godot::task::spawn(async move {
+        let mut app = TestApp::new(&ctx_clone, |app| {
+            app.add_plugins(GodotAssetsPlugin);
+            app.add_plugins(GodotPackedScenePlugin);
+            app.init_resource::<PrewarmSeen>();
+            app.add_observer(
+                |add: On<Add, GodotNodeHandle>,
+                 names: Query<&Name>,
+                 prewarm: Res<GodotScenePrewarm>,
+                 mut seen: ResMut<PrewarmSeen>| {
+                    if let Ok(name) = names.get(add.entity) {
+                        seen.0
+                            .push((name.to_string(), prewarm.is_prewarm_entity(add.entity)));
+                    }
+                },
+            );
+            app.prewarm_godot_scene("res://fixtures/room.tscn");
+        })
+        .await;
+
+        let count_room_entities = |app: &mut TestApp| {
+            app.with_world_mut(|world| {
+                let mut q = world.query::<&Name>();
+                q.iter(world)
+                    .filter(|name| ["Room", "Floor", "Door"].contains(&name.as_str()))
+                    .count()
+            })
+        };
+
+        app.updates(3).await;
+
+        assert!(
+            app.with_world(|world| {
+                world
+                    .resource::<GodotScenePrewarm>()
+                    .is_prewarmed("res://fixtures/room.tscn")
+            }),
+            "room.tscn should be prewarmed"
+        );
+
+        let seen = app.with_world(|world| world.resource::<PrewarmSeen>().0.clone());
+        for name in ["Room", "Floor", "Door"] {
+            assert!(
+                seen.iter()
+                    .any(|(seen_name, prewarm)| seen_name == name && *prewarm),
+                "{name} should have been observed as a prewarm entity, saw {seen:?}"
+            );
+        }
+        assert_eq!(
+            count_room_entities(&mut app),
+            0,
+            "prewarm entities must be despawned"
+        );
+
+        app.cleanup().await;
+    })
+}
@@ -0,0 +1,118 @@
+/*
+ * Script call tests
+ *
+ * `GodotCall` converts a script method's result to the type asked for, reports
+ * bad calls as errors, and `call_async` waits out an `await` before triggering
+ * `ScriptCallCompleted`.
+ */
+
+use bevy::ecs::system::RunSystemOnce;
+use bevy::prelude::*;
+use godot::classes::{GDScript, Node2D};
+use godot::prelude::*;
+use godot_bevy::plugins::script_calls::{GodotCall, ScriptCallCompleted, ScriptCallError};
+use godot_bevy::prelude::*;
+use godot_bevy_test::prelude::*;
+
+const SCRIPT: &str = "extends Node2D
+
+func get_score():
+\treturn 42
+
+func add(a, b):
+\treturn a + b
+
+func open_slowly():
+\tawait get_tree().process_frame
+\treturn 3
+";
+
+fn scripted_node() -> Gd<Node2D> {
+    let mut script = GDScript::new_gd();
+    script.set_source_code(SCRIPT);
+    script.reload();
+    let mut node = Node2D::new_alloc();
+    node.set_script(&script);
+    node
+}
+
+#[derive(Resource, Default)]
+struct Completed(Vec<Result<i64, ScriptCallError>>);
+
+#[itest(async)]
+fn test_call_typed_converts_and_reports_errors(ctx: &TestContext) -> godot::task::TaskHandle {
+    let ctx_clone = ctx.clone();
+
+    godot::task::spawn(async move {
+        let mut app = TestApp::new(&ctx_clone, |_app| {}).await;
+        let (node, entity) = app.add_prebuilt_node(scripted_node(), "Scored").await;
+
+        let results = app.with_world_mut(|world| {
+            let handle = *world.get::<GodotNodeHandle>(entity).unwrap();
+            world
+                .run_system_once(move |mut calls: GodotCall| {
+                    (
+                        calls.call_typed::<i64>(handle, "get_score", &[]),
+                        calls.call_typed::<i64>(handle, "add", &[2.to_variant(), 5.to_variant()]),
+                        calls.call_typed::<GString>(handle, "get_score", &[]),
+                        calls.call_typed::<i64>(handle, "no_such_method", &[]),
+                    )
+                })
+                .unwrap()
+        });
+
+        assert_eq!(results.0, Ok(42));
+        assert_eq!(results.1, Ok(7));
+        assert!(matches!(
+            results.2,
+            Err(ScriptCallError::Conversion {
+                got: VariantType::INT,
+                ..
+            })
+        ));
+        assert_eq!(
+            results.3,
+            Err(ScriptCallError::MissingMethod("no_such_method".into()))
+        );
+
+        node.free();
+        app.cleanup().await;
+    })
+}
+
+#[itest(async)]
+fn test_call_async_waits_for_await(ctx: &TestContext) -> godot::task::TaskHandle {
+    let ctx_clone = ctx.clone();
+
+    godot::task::spawn(async move {
+        let mut app = TestApp::new(&ctx_clone, |app| {
+            app.init_resource::<Completed>().add_observer(
+                |done: On<ScriptCallCompleted<i64>>, mut completed: ResMut<Completed>| {
+                    completed.0.push(done.result.clone());
+                },
+            );
+        })
+        .await;
+        let (node, entity) = app.add_prebuilt_node(scripted_node(), "Chest").await;
+
+        app.with_world_mut(|world| {
+            let handle = *world.get::<GodotNodeHandle>(entity).unwrap();
+            world
+                .run_system_once(move |mut calls: GodotCall| {
+                    calls.call_async::<i64>(handle, "open_slowly", &[]);
+                })
+                .unwrap();
+        });
+        assert!(
+            app.with_world(|world| world.resource::<Completed>().0.is_empty()),
+            "The suspended call should not complete in the frame it started"
+        );
+
+        app.updates(3).await;
+        let completed = app.with_world(|world| world.resource::<Completed>().0.clone());
+        assert_eq!(completed, vec![Ok(3)]);
+
+        node.free();
+        app.cleanup().await;
+    })
+}
@@ -148,6 +148,154 @@ fn test_connect_object_signal(ctx: &TestContext) -> godot::task::TaskHandle {
     })
 }
 
+#[derive(Event, Debug, Clone)]
+struct ScopedPressed;
+
+/// Test that scoped connections are torn down when their entity is despawned,
+/// and one-shot connections after their first emission.
+#[itest(async)]
+fn test_scoped_and_one_shot_connections(ctx: &TestContext) -> godot::task::TaskHandle {
+    let ctx_clone = ctx.clone();
+
+    godot::task::spawn(async move {
+        #[derive(Resource, Default)]
+        struct PressCount(u32);
+
+        let mut app = TestApp::new(&ctx_clone, |app| {
+            app.add_plugins(GodotSignalsPlugin::<ScopedPressed>::default());
+            app.init_resource::<PressCount>();
+            app.add_observer(
+                |_trigger: On<ScopedPressed>, mut count: ResMut<PressCount>| {
+                    count.0 += 1;
+                },
+            );
+        })
+        .await;
+
+        let mut scoped_button = godot::classes::Button::new_alloc();
+        let mut one_shot_button = godot::classes::Button::new_alloc();
+
+        let owner = app.with_world_mut(|world| {
+            let owner = world.spawn_empty().id();
+            let mut system_state: bevy::ecs::system::SystemState<GodotSignals<ScopedPressed>> =
+                bevy::ecs::system::SystemState::new(world);
+            let signals = system_state
+                .get(world)
+                .expect("system params should be valid in test");
+
+            signals.connect_object_with(
+                scoped_button.clone(),
+                "pressed",
+                SignalConnectOptions::new().scoped_to(owner),
+                |_args| Some(ScopedPressed),
+            );
+            signals.connect_object_with(
+                one_shot_button.clone(),
+                "pressed",
+                SignalConnectOptions::new().one_shot(),
+                |_args| Some(ScopedPressed),
+            );
+
+            system_state.apply(world);
+            owner
+        });
+        app.update().await;
+
+        scoped_button.emit_signal("pressed", &[]);
+        one_shot_button.emit_signal("pressed", &[]);
+        one_shot_button.emit_signal("pressed", &[]);
+        app.update().await;
+        assert_eq!(
+            app.with_world(|world| world.resource::<PressCount>().0),
+            2,
+            "the scoped connection should fire and the one-shot connection only once"
+        );
+
+        // Last disconnects the scoped connection once its owner is gone
+        app.with_world_mut(|world| {
+            world.despawn(owner);
+        });
+        app.update().await;
+
+        scoped_button.emit_signal("pressed", &[]);
+        app.update().await;
+        assert_eq!(
+            app.with_world(|world| world.resource::<PressCount>().0),
+            2,
+            "the scoped connection should be gone after its owner despawned"
+        );
+        assert!(
+            scoped_button
+                .get_signal_connection_list("pressed")
+                .is_empty()
+        );
+
+        app.cleanup().await;
+        scoped_button.free();
+        one_shot_button.free();
+    })
+}
+
+/// Test that a `scoped_while` connection is torn down when its marker is removed,
+/// even though the owning entity lives on.
+#[itest(async)]
+fn test_scoped_while_disconnects_on_marker_removal(ctx: &TestContext) -> godot::task::TaskHandle {
+    let ctx_clone = ctx.clone();
+
+    godot::task::spawn(async move {
+        #[derive(Component)]
+        struct Listening;
+
+        let mut app = TestApp::new(&ctx_clone, |app| {
+            app.add_plugins(GodotSignalsPlugin::<ScopedPressed>::default());
+        })
+        .await;
+
+        let button = godot::classes::Button::new_alloc();
+
+        let owner = app.with_world_mut(|world| {
+            let owner = world.spawn(Listening).id();
+            let mut system_state: bevy::ecs::system::SystemState<GodotSignals<ScopedPressed>> =
+                bevy::ecs::system::SystemState::new(world);
+            let signals = system_state
+                .get(world)
+                .expect("system params should be valid in test");
+
+            signals.connect_object_with(
+                button.clone(),
+                "pressed",
+                SignalConnectOptions::new().scoped_while::<Listening>(owner),
+                |_args| Some(ScopedPressed),
+            );
+
+            system_state.apply(world);
+            owner
+        });
+        app.update().await;
+        assert_eq!(
+            button.get_signal_connection_list("pressed").len(),
+            1,
+            "the scoped connection should be made"
+        );
+
+        app.with_world_mut(|world| {
+            world.entity_mut(owner).remove::<Listening>();
+        });
+        app.update().await;
+        assert!(
+            button.get_signal_connection_list("pressed").is_empty(),
+            "removing the marker should disconnect the callable"
+        );
+        assert!(
+            app.with_world(|world| world.get_entity(owner).is_ok()),
+            "the owner itself should be untouched"
+        );
+
+        app.cleanup().await;
+        button.free();
+    })
+}
+
 /// Test that multiple signal connections work correctly
 #[itest(async)]
 fn test_multiple_signal_connections(ctx: &TestContext) -> godot::task::TaskHandle {
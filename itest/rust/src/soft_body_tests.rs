@@ -0,0 +1,68 @@
+/*
+ * Soft body tests
+ *
+ * SoftBody3D entities get SoftBodyPins written to the node, and
+ * SampleSoftBodyVertices copies the vertices into SoftBodyVertices.
+ */
+
+use bevy::prelude::*;
+use godot::classes::mesh::ArrayType;
+use godot::classes::{PlaneMesh, SoftBody3D};
+use godot::obj::EngineEnum;
+use godot::prelude::*;
+use godot_bevy::prelude::*;
+use godot_bevy_test::prelude::*;
+
+#[itest(async)]
+fn test_soft_body_pins_and_vertices(ctx: &TestContext) -> godot::task::TaskHandle {
+    let ctx_clone = ctx.clone();
+
+    godot::task::spawn(async move {
+        let mut app = TestApp::new(&ctx_clone, |app| {
+            app.add_plugins(GodotSoftBodyPlugin);
+        })
+        .await;
+
+        let mut mesh = PlaneMesh::new_gd();
+        mesh.set_subdivide_width(2);
+        mesh.set_subdivide_depth(2);
+        let mut body = SoftBody3D::new_alloc();
+        body.set_mesh(&mesh);
+        let (body, entity) = app.add_prebuilt_node(body, "Cloth").await;
+        app.update().await;
+
+        assert!(
+            app.with_world(|world| world.get::<SoftBodySimulation>(entity).is_some()),
+            "Simulation should be read from the body"
+        );
+
+        app.with_world_mut(|world| {
+            world.entity_mut(entity).insert((
+                SoftBodyPins(vec![SoftBodyPin::fixed(0)]),
+                SampleSoftBodyVertices::every(1),
+            ));
+        });
+        app.update().await;
+        assert!(body.is_point_pinned(0), "Point 0 should be pinned");
+        assert!(!body.is_point_pinned(1), "Point 1 should not be pinned");
+
+        app.physics_update().await;
+        let vertices = app.with_world(|world| {
+            world
+                .get::<SoftBodyVertices>(entity)
+                .map(|vertices| vertices.0.len())
+        });
+        assert_eq!(
+            vertices,
+            Some(
+                mesh.surface_get_arrays(0)
+                    .at(ArrayType::VERTEX.ord() as usize)
+                    .to::<PackedVector3Array>()
+                    .len()
+            ),
+            "Every point should be sampled"
+        );
+
+        app.cleanup().await;
+    })
+}
@@ -0,0 +1,113 @@
+//! The `TestApp` helpers themselves: component assertions, query helpers,
+//! `wait_until`, golden-file snapshots, and scene fixtures.
+
+use bevy::prelude::*;
+use godot_bevy_test::prelude::*;
+
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+struct Score(u32);
+
+#[derive(Component)]
+struct Leader;
+
+/// Adds one to every score each frame, stopping at 5.
+fn count_up(mut scores: Query<&mut Score>) {
+    for mut score in &mut scores {
+        score.0 = (score.0 + 1).min(5);
+    }
+}
+
+#[itest(async)]
+fn test_wait_until_and_component_assertions(ctx: &TestContext) -> godot::task::TaskHandle {
+    let ctx = ctx.clone();
+    godot::task::spawn(async move {
+        let mut app = TestApp::new(&ctx, |app| {
+            app.add_systems(Update, count_up);
+        })
+        .await;
+
+        let leader = app.with_world_mut(|world| {
+            world.spawn((Name::new("beta"), Score(0)));
+            world.spawn((Name::new("alpha"), Score(0), Leader)).id()
+        });
+
+        // Already true: returns before advancing a frame.
+        let waited = app
+            .wait_until(|world| world.get::<Score>(leader).is_some(), 0)
+            .await;
+        assert_eq!(waited, 0);
+
+        let waited = app
+            .wait_until(|world| world.get::<Score>(leader) == Some(&Score(5)), 30)
+            .await;
+        assert!(
+            waited >= 5,
+            "counting to 5 takes at least 5 frames, took {waited}"
+        );
+
+        app.assert_component::<Score>(leader, |score| score.0 == 5);
+        let leader_score = app.query_single::<(&Score, &Name, &Leader), _>(|(score, name, _)| {
+            assert_eq!(name.as_str(), "alpha");
+            score.0
+        });
+        assert_eq!(leader_score, 5);
+        let mut names = app.query_collect::<(&Name, &Score), _>(|(name, _)| name.to_string());
+        names.sort();
+        assert_eq!(names, ["alpha", "beta"]);
+
+        app.cleanup().await;
+    })
+}
+
+#[itest(async)]
+fn test_snapshot_matches_golden_file(ctx: &TestContext) -> godot::task::TaskHandle {
+    let ctx = ctx.clone();
+    godot::task::spawn(async move {
+        let mut app = TestApp::new(&ctx, |_app| {}).await;
+
+        // Spawned out of order: rows are sorted by name.
+        app.with_world_mut(|world| {
+            world.spawn((Name::new("beta"), Score(5)));
+            world.spawn((Name::new("alpha"), Score(3)));
+        });
+        app.update().await;
+
+        let snapshot = Snapshot::new().component::<Score>();
+        assert_eq!(
+            app.snapshot(&snapshot),
+            "[Score]\nalpha: Score(3)\nbeta: Score(5)\n"
+        );
+        app.assert_snapshot(
+            &snapshot,
+            concat!(env!("CARGO_MANIFEST_DIR"), "/golden/scores.txt"),
+        );
+
+        app.cleanup().await;
+    })
+}
+
+#[itest(async)]
+fn test_scene_fixtures_are_isolated_and_freed(ctx: &TestContext) -> godot::task::TaskHandle {
+    let ctx = ctx.clone();
+    godot::task::spawn(async move {
+        let mut app = TestApp::new(&ctx, |_app| {}).await;
+
+        // Two copies of one scene don't collide: each gets its own holder.
+        let first = app.with_scene("res://fixtures/room.tscn").await;
+        let second = app.with_scene("res://fixtures/room.tscn").await;
+        assert_ne!(first.entity, second.entity);
+        let door = first.node::<godot::classes::Node2D>("Door").instance_id();
+        let door_entity = app
+            .entity_for_node(door)
+            .expect("the fixture's children should be mirrored too");
+        app.assert_component::<Name>(door_entity, |name| name.as_str() == "Door");
+
+        app.free_scene(first).await;
+        assert!(!app.has_entity_for_node(door));
+        assert!(app.has_entity_for_node(second.root.instance_id()));
+
+        // `second` is freed by cleanup.
+        app.cleanup().await;
+        assert!(!second.root.is_instance_valid());
+    })
+}
@@ -491,6 +491,79 @@ fn test_spawn_resets_physics_interpolation(ctx: &TestContext) -> godot::task::Ta
     })
 }
 
+/// Test that a node added at runtime has its entity's Transform set from the node
+/// in the frame it is mirrored, even in OneWay mode where Godot is never read again
+#[itest(async)]
+fn test_spawned_node_transform_initialized(ctx: &TestContext) -> godot::task::TaskHandle {
+    let ctx_clone = ctx.clone();
+    godot::task::spawn(async move {
+        let mut app = TestApp::new(&ctx_clone, |app| {
+            app.add_plugins(GodotTransformSyncPlugin::default());
+            app.insert_resource(GodotTransformConfig::one_way());
+        })
+        .await;
+
+        let mut node = godot::classes::Node2D::new_alloc();
+        node.set_position(Vector2::new(120.0, -40.0));
+        node.set_rotation(0.5);
+        let (node, entity) = app.add_prebuilt_node(node, "PlacedInEditor").await;
+
+        let transform = app.with_world(|world| *world.get::<Transform>(entity).unwrap());
+        assert!(
+            (transform.translation.x - 120.0).abs() < 0.01
+                && (transform.translation.y + 40.0).abs() < 0.01,
+            "expected translation (120, -40) on the first frame, got {:?}",
+            transform.translation
+        );
+        let (_, _, angle) = transform.rotation.to_euler(EulerRot::XYZ);
+        assert!(
+            (angle - 0.5).abs() < 0.01,
+            "expected rotation 0.5, got {angle}"
+        );
+
+        app.cleanup().await;
+        node.free();
+    })
+}
+
+/// Test that a node moved after `add_child`, before its entity is created, spawns
+/// with where it is now rather than where it was when it entered the tree
+#[itest(async)]
+fn test_node_moved_after_add_child_spawns_at_current_transform(
+    ctx: &TestContext,
+) -> godot::task::TaskHandle {
+    let ctx_clone = ctx.clone();
+    godot::task::spawn(async move {
+        let mut app = TestApp::new(&ctx_clone, |app| {
+            app.add_plugins(GodotTransformSyncPlugin::default());
+            app.insert_resource(GodotTransformConfig::one_way());
+        })
+        .await;
+
+        let mut node = godot::classes::Node2D::new_alloc();
+        node.set_name("MovedAfterAdd");
+        node.set_position(Vector2::new(10.0, 10.0));
+        ctx_clone.scene_tree.clone().add_child(&node);
+        // node_added has fired with (10, 10); the entity is created next frame.
+        node.set_position(Vector2::new(300.0, 75.0));
+
+        let id = node.instance_id();
+        app.wait_until(|world| world.resource::<NodeEntityIndex>().contains(id), 5)
+            .await;
+        let entity = app.entity_for_node(id).expect("entity for MovedAfterAdd");
+        let transform = app.with_world(|world| *world.get::<Transform>(entity).unwrap());
+        assert!(
+            (transform.translation.x - 300.0).abs() < 0.01
+                && (transform.translation.y - 75.0).abs() < 0.01,
+            "expected translation (300, 75) from the moved node, got {:?}",
+            transform.translation
+        );
+
+        app.cleanup().await;
+        node.free();
+    })
+}
+
 /// Test that sync can be disabled
 #[itest(async)]
 fn test_transform_sync_disabled(ctx: &TestContext) -> godot::task::TaskHandle {
@@ -715,3 +788,66 @@ fn test_freeing_synced_node_is_non_fatal(ctx: &TestContext) -> godot::task::Task
         survivor.free();
     })
 }
+
+/// With `GlobalTransformSync::ReadWrite`, a `GlobalTransform` set in Bevy moves the
+/// node in world space, while one only refreshed by the read (the parent moved in
+/// Godot) is not written back over the node.
+#[itest(async)]
+fn test_global_transform_write_only_pushes_bevy_edits(
+    ctx: &TestContext,
+) -> godot::task::TaskHandle {
+    let ctx_clone = ctx.clone();
+
+    godot::task::spawn(async move {
+        let mut parent = godot::classes::Node2D::new_alloc();
+        parent.set_name("GlobalParent");
+        parent.set_position(Vector2::new(100.0, 0.0));
+        let mut child = godot::classes::Node2D::new_alloc();
+        child.set_name("GlobalChild");
+        parent.add_child(&child);
+        ctx_clone.scene_tree.clone().add_child(&parent);
+
+        let mut app = TestApp::new(&ctx_clone, |app| {
+            app.add_plugins(GodotTransformSyncPlugin::default());
+            app.insert_resource(
+                GodotTransformConfig::two_way()
+                    .with_global_transform(GlobalTransformSync::ReadWrite),
+            );
+        })
+        .await;
+        app.physics_update().await;
+
+        let entity = app
+            .entity_for_node(child.instance_id())
+            .expect("entity for GlobalChild");
+        app.with_world_mut(|world| {
+            *world.get_mut::<GlobalTransform>(entity).unwrap() =
+                GlobalTransform::from_xyz(250.0, 40.0, 0.0);
+        });
+        app.physics_update().await;
+
+        let global = child.get_global_position();
+        assert!(
+            (global.x - 250.0).abs() < 0.01 && (global.y - 40.0).abs() < 0.01,
+            "the Bevy edit should move the node to (250, 40), got {global:?}"
+        );
+        let local = app.with_world(|world| world.get::<Transform>(entity).unwrap().translation);
+        assert!(
+            (local.x - 150.0).abs() < 0.01 && (local.y - 40.0).abs() < 0.01,
+            "Transform should hold the new local offset (150, 40), got {local:?}"
+        );
+
+        parent.set_position(Vector2::ZERO);
+        app.physics_update().await;
+        app.physics_update().await;
+
+        let global = child.get_global_position();
+        assert!(
+            (global.x - 150.0).abs() < 0.01 && (global.y - 40.0).abs() < 0.01,
+            "the child should follow its parent to (150, 40), got {global:?}"
+        );
+
+        app.cleanup().await;
+        parent.free();
+    })
+}
@@ -0,0 +1,66 @@
+/*
+ * Vehicle tests
+ *
+ * VehicleBody3D entities get VehicleControls written to the node each physics
+ * tick, and VehicleWheel3D entities get a VehicleWheelState.
+ */
+
+use bevy::prelude::*;
+use godot::classes::{VehicleBody3D, VehicleWheel3D};
+use godot::prelude::*;
+use godot_bevy::prelude::*;
+use godot_bevy_test::prelude::*;
+
+#[itest(async)]
+fn test_vehicle_controls_reach_the_body(ctx: &TestContext) -> godot::task::TaskHandle {
+    let ctx_clone = ctx.clone();
+
+    godot::task::spawn(async move {
+        let mut app = TestApp::new(&ctx_clone, |app| {
+            app.add_plugins(GodotVehiclePlugin);
+        })
+        .await;
+
+        let mut body = VehicleBody3D::new_alloc();
+        body.set_brake(2.0);
+        let mut wheel = VehicleWheel3D::new_alloc();
+        wheel.set_name("FrontLeft");
+        body.add_child(&wheel);
+        let (body, body_entity) = app.add_prebuilt_node(body, "Kart").await;
+        app.physics_update().await;
+
+        let controls = app.with_world(|world| world.get::<VehicleControls>(body_entity).copied());
+        assert_eq!(
+            controls,
+            Some(VehicleControls {
+                brake: 2.0,
+                ..default()
+            }),
+            "Controls should be read from the body"
+        );
+        let wheel_entity = app
+            .entity_for_node(wheel.instance_id())
+            .expect("Wheel should have an entity");
+        let airborne =
+            app.with_world(|world| world.get::<VehicleWheelState>(wheel_entity).copied());
+        assert_eq!(
+            airborne.map(|state| state.in_contact),
+            Some(false),
+            "A wheel with nothing below it is not in contact"
+        );
+
+        app.with_world_mut(|world| {
+            let mut controls = world.get_mut::<VehicleControls>(body_entity).unwrap();
+            controls.engine_force = 150.0;
+            controls.steering = 0.3;
+            controls.brake = 0.0;
+        });
+        app.physics_update().await;
+
+        assert_eq!(body.get_engine_force(), 150.0);
+        assert_eq!(body.get_steering(), 0.3);
+        assert_eq!(body.get_brake(), 0.0);
+
+        app.cleanup().await;
+    })
+}